@@ -32,6 +32,10 @@ impl AssetManager {
             "design_cozy",
             // Building Elements
             "building_exterior",
+            "building_exterior_modern",
+            "building_exterior_industrial",
+            "building_exterior_victorian",
+            "building_exterior_garden",
             "hallway",
             "apartment_door",
             "window_street",
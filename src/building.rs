@@ -5,12 +5,46 @@
 //! - `Building`: The container for apartments and shared spaces (hallways).
 //! - `Upgrades`: Systems for improving building and apartment quality.
 //! - `Ownership`: Logic for selling units as condos.
+//! - `Amenities`: Shared rooms (gym, pool, ...) that lift every tenant's mood.
+//! - `Parking`: Rentable spots billed alongside rent.
+//! - `Renovation`: Bringing a condemned building back to a livable state.
+//! - `Merge`: Combining two adjacent vacant units into one larger unit.
+//! - `Storage`: Rentable storage lockers billed per occupied unit.
+//! - `Art`: The hallway art installation.
+//! - `WaterDamage`: Leaks that spread down through the floors until repaired.
+//! - `Squatters`: Unleased occupants who move into long-vacant units.
+//! - `Landmark`: City landmark designation for historic buildings.
+//! - `VacancyTax`: The city's tax on units left empty too long.
+//! - `PropertyManager`: A hired manager who automates routine decisions.
 
+mod amenities;
 mod apartment;
+mod art;
 mod building;
+mod landmark;
+mod merge;
 pub mod ownership;
+mod parking;
+mod property_manager;
+mod renovation;
+mod squatters;
+mod storage;
 pub mod upgrades;
+mod vacancy_tax;
+mod water_damage;
 
-pub use apartment::{Apartment, ApartmentSize, DesignType, NoiseLevel};
-pub use building::{Building, MarketingType};
-pub use upgrades::{apply_upgrade, UpgradeAction};
+pub use amenities::{AmenityType, SharedAmenity};
+pub use apartment::{
+    Apartment, ApartmentSize, DesignType, NoiseLevel, PET_NOISE_RESTRICTED_FLAG, RENT_STRIKE_FLAG,
+};
+pub use building::{
+    default_construction_year, Building, ExteriorTheme, GreenCertificationLevel, MarketingType,
+    GAME_START_YEAR,
+};
+pub use landmark::LANDMARK_PROPOSED_FLAG;
+pub use parking::ParkingSpot;
+pub use property_manager::{AutomationLevel, PropertyManager};
+pub use renovation::Renovation;
+pub use squatters::SQUATTER_EVICTION_FLAG;
+pub use upgrades::{apply_upgrade, green_certification_level, UpgradeAction};
+pub use water_damage::WATER_DAMAGE_FLAG;
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Shared rooms every tenant in the building can use.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AmenityType {
+    Gym,
+    Pool,
+    CommunityRoom,
+    Laundry,
+}
+
+impl AmenityType {
+    pub const ALL: [AmenityType; 4] = [
+        AmenityType::Gym,
+        AmenityType::Pool,
+        AmenityType::CommunityRoom,
+        AmenityType::Laundry,
+    ];
+
+    /// Key used in `amenities_config` and upgrade definitions.
+    pub fn key(&self) -> &'static str {
+        match self {
+            AmenityType::Gym => "gym",
+            AmenityType::Pool => "pool",
+            AmenityType::CommunityRoom => "community_room",
+            AmenityType::Laundry => "laundry",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|amenity| amenity.key() == key)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmenityType::Gym => "Gym",
+            AmenityType::Pool => "Pool",
+            AmenityType::CommunityRoom => "Community Room",
+            AmenityType::Laundry => "Laundry",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SharedAmenity {
+    pub amenity_type: AmenityType,
+    pub condition: i32, // 0-100
+}
+
+impl SharedAmenity {
+    pub fn new(amenity_type: AmenityType) -> Self {
+        Self {
+            amenity_type,
+            condition: 100,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amenity_keys_round_trip() {
+        for amenity in AmenityType::ALL {
+            assert_eq!(AmenityType::from_key(amenity.key()), Some(amenity));
+        }
+        assert_eq!(AmenityType::from_key("sauna"), None);
+    }
+}
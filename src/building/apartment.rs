@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+/// Apartment flag set by the window insulation upgrade.
+pub const WINDOW_INSULATION_FLAG: &str = "window_insulation";
+/// Apartment flag set while its tenant is withholding rent on strike.
+pub const RENT_STRIKE_FLAG: &str = "rent_strike";
+/// Apartment flag: the tenant's pet was approved on condition it stays quiet.
+pub const PET_NOISE_RESTRICTED_FLAG: &str = "pet_noise_restricted";
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum DesignType {
     Bare,
@@ -44,6 +51,29 @@ pub enum ApartmentSize {
 }
 
 impl ApartmentSize {
+    /// Parse a size name as used in templates and upgrade definitions.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "small" => Some(ApartmentSize::Small),
+            "medium" => Some(ApartmentSize::Medium),
+            "large" => Some(ApartmentSize::Large),
+            "penthouse" => Some(ApartmentSize::Penthouse),
+            _ => None,
+        }
+    }
+
+    /// Starting rent for a unit converted into this size. Penthouses ask a
+    /// multiple of a large unit's rent.
+    pub fn converted_rent(&self) -> i32 {
+        let config = crate::data::config::active().apartment;
+        match self {
+            ApartmentSize::Penthouse => {
+                (config.base_rent_large as f32 * config.penthouse_rent_multiplier).round() as i32
+            }
+            _ => self.base_rent(),
+        }
+    }
+
     pub fn base_rent(&self) -> i32 {
         let config = crate::data::config::active().apartment;
         match self {
@@ -104,6 +134,24 @@ pub struct Apartment {
     // Leasing
     pub is_listed_for_lease: bool,
     pub preferred_archetype: Option<TenantArchetype>,
+
+    // Squatters
+    #[serde(default)]
+    pub vacant_ticks: u32,
+    #[serde(default)]
+    pub squatter_since: Option<u32>,
+
+    // Vacancy tax
+    #[serde(default)]
+    pub vacant_since_tick: Option<u32>,
+
+    // Move-in inspection
+    /// Condition recorded when the current tenant moved in.
+    #[serde(default)]
+    pub move_in_condition: Option<i32>,
+    /// Rent the current tenant moved in at.
+    #[serde(default)]
+    pub move_in_rent: i32,
 }
 
 impl Apartment {
@@ -130,6 +178,11 @@ impl Apartment {
             flags: HashSet::new(),
             is_listed_for_lease: false,
             preferred_archetype: None,
+            vacant_ticks: 0,
+            squatter_since: None,
+            vacant_since_tick: None,
+            move_in_condition: None,
+            move_in_rent: 0,
         }
     }
 
@@ -144,11 +197,38 @@ impl Apartment {
         }
     }
 
+    /// Window insulation shuts out street noise, but not noise made inside
+    /// the building (that takes full soundproofing).
+    pub fn street_noise_insulated(&self) -> bool {
+        self.flags.contains(WINDOW_INSULATION_FLAG)
+            && self.base_noise == NoiseLevel::High
+            && !self.flags.contains("high_noise")
+    }
+
+    /// Is this unit's tenant withholding rent?
+    pub fn on_rent_strike(&self) -> bool {
+        self.flags.contains(RENT_STRIKE_FLAG)
+    }
+
+    /// Has someone moved in without a lease?
+    pub fn has_squatter(&self) -> bool {
+        self.flags.contains(super::squatters::SQUATTER_FLAG)
+    }
+
     /// Is the apartment currently vacant?
     pub fn is_vacant(&self) -> bool {
         self.tenant_id.is_none()
     }
 
+    /// What it costs to help this unit's tenant move out: `months` of rent,
+    /// or nothing if the unit is empty.
+    pub fn relocation_assistance(&self, months: u32) -> i32 {
+        if self.is_vacant() {
+            return 0;
+        }
+        self.rent_price * months as i32
+    }
+
     /// Calculate overall apartment quality score (0-100)
     pub fn quality_score(&self) -> i32 {
         let base = self.condition;
@@ -168,6 +248,7 @@ impl Apartment {
     /// Repair the apartment
     pub fn repair(&mut self, amount: i32) {
         self.condition = (self.condition + amount).min(100);
+        self.flags.remove(super::water_damage::WATER_DAMAGE_FLAG);
     }
 
     /// Upgrade design to next level
@@ -185,11 +266,25 @@ impl Apartment {
         self.tenant_id = Some(tenant_id);
         self.is_listed_for_lease = false;
         self.preferred_archetype = None;
+        self.vacant_since_tick = None;
+        self.move_in_condition = Some(self.condition);
+        self.move_in_rent = self.rent_price;
+    }
+
+    /// Condition lost since the move-in inspection (negative if the tenant
+    /// left it better than they found it).
+    pub fn condition_change(&self) -> Option<i32> {
+        self.move_in_condition
+            .map(|at_move_in| at_move_in - self.condition)
     }
 
     /// Move tenant out
-    pub fn move_out(&mut self) {
+    pub fn move_out(&mut self, current_tick: u32) {
         self.tenant_id = None;
+        self.vacant_since_tick = Some(current_tick);
+        self.move_in_condition = None;
+        // The pet leaves with its owner.
+        self.flags.remove(PET_NOISE_RESTRICTED_FLAG);
     }
 
     /// Calculate market value for selling the unit
@@ -279,6 +374,21 @@ mod tests {
         assert_eq!(apt.effective_noise(), NoiseLevel::Low);
     }
 
+    #[test]
+    fn test_window_insulation_only_blocks_street_noise() {
+        let mut apt = Apartment::new(0, "1A", 1, ApartmentSize::Small, NoiseLevel::High);
+        apt.flags.insert(WINDOW_INSULATION_FLAG.to_string());
+        assert!(apt.street_noise_insulated());
+
+        // Noise from inside the building gets through
+        apt.flags.insert("high_noise".to_string());
+        assert!(!apt.street_noise_insulated());
+
+        let mut quiet = Apartment::new(1, "3B", 3, ApartmentSize::Small, NoiseLevel::Low);
+        quiet.flags.insert(WINDOW_INSULATION_FLAG.to_string());
+        assert!(!quiet.street_noise_insulated());
+    }
+
     #[test]
     fn test_design_upgrade() {
         let mut apt = Apartment::new(0, "1A", 1, ApartmentSize::Small, NoiseLevel::Low);
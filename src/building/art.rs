@@ -0,0 +1,32 @@
+use super::Building;
+
+/// Building flag set by the hallway art installation upgrade.
+pub(super) const HALLWAY_ART_FLAG: &str = "hallway_art";
+
+/// Appeal the art adds on top of the usual 100-point ceiling.
+pub(super) const HALLWAY_ART_APPEAL: i32 = 5;
+
+impl Building {
+    pub fn has_hallway_art(&self) -> bool {
+        self.flags.contains(HALLWAY_ART_FLAG)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn art_lifts_appeal_past_the_cap() {
+        let mut building = Building::new("Test", 1, 2);
+        building.hallway_condition = 100;
+        for apt in &mut building.apartments {
+            apt.condition = 100;
+        }
+        building.has_laundry = true;
+        assert_eq!(building.building_appeal(), 100);
+
+        building.flags.insert(HALLWAY_ART_FLAG.to_string());
+        assert_eq!(building.building_appeal(), 100 + HALLWAY_ART_APPEAL);
+    }
+}
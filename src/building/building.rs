@@ -1,9 +1,20 @@
 use super::ownership::OwnershipType;
-use super::{Apartment, ApartmentSize, NoiseLevel};
+use super::{
+    AmenityType, Apartment, ApartmentSize, NoiseLevel, ParkingSpot, Renovation, SharedAmenity,
+};
 use crate::data::config::MarketingConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
+/// Calendar year the game opens in; tick 0 is January of this year.
+pub const GAME_START_YEAR: u32 = 2025;
+
+/// Construction year assumed when a save, template or listing doesn't give
+/// one.
+pub fn default_construction_year() -> u32 {
+    2000
+}
+
 /// Marketing campaign types with different costs and target demographics
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub enum MarketingType {
@@ -34,6 +45,88 @@ impl MarketingType {
     }
 }
 
+/// Facade style; picks the exterior art in the building view.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ExteriorTheme {
+    #[default]
+    Classic,
+    Modern,
+    Industrial,
+    Victorian,
+    Garden,
+}
+
+impl ExteriorTheme {
+    pub const ALL: [ExteriorTheme; 5] = [
+        ExteriorTheme::Classic,
+        ExteriorTheme::Modern,
+        ExteriorTheme::Industrial,
+        ExteriorTheme::Victorian,
+        ExteriorTheme::Garden,
+    ];
+
+    /// Key used by upgrade definitions.
+    pub fn key(&self) -> &'static str {
+        match self {
+            ExteriorTheme::Classic => "classic",
+            ExteriorTheme::Modern => "modern",
+            ExteriorTheme::Industrial => "industrial",
+            ExteriorTheme::Victorian => "victorian",
+            ExteriorTheme::Garden => "garden",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|theme| theme.key() == key)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExteriorTheme::Classic => "Classic",
+            ExteriorTheme::Modern => "Modern",
+            ExteriorTheme::Industrial => "Industrial",
+            ExteriorTheme::Victorian => "Victorian",
+            ExteriorTheme::Garden => "Garden",
+        }
+    }
+
+    pub fn texture_id(&self) -> &'static str {
+        match self {
+            ExteriorTheme::Classic => "building_exterior",
+            ExteriorTheme::Modern => "building_exterior_modern",
+            ExteriorTheme::Industrial => "building_exterior_industrial",
+            ExteriorTheme::Victorian => "building_exterior_victorian",
+            ExteriorTheme::Garden => "building_exterior_garden",
+        }
+    }
+}
+
+/// Green building certification earned by stacking eco upgrades.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum GreenCertificationLevel {
+    #[default]
+    None,
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl GreenCertificationLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GreenCertificationLevel::None => "Uncertified",
+            GreenCertificationLevel::Bronze => "Bronze",
+            GreenCertificationLevel::Silver => "Silver",
+            GreenCertificationLevel::Gold => "Gold",
+        }
+    }
+
+    /// 0 for uncertified up to 3 for Gold.
+    pub fn tier(&self) -> i32 {
+        *self as i32
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct Building {
     pub name: String,
@@ -51,6 +144,43 @@ pub struct Building {
     pub marketing_strategy: MarketingType, // Current marketing approach
     pub open_house_remaining: u32,         // Months of open house bonus remaining
     pub flags: HashSet<String>,
+    #[serde(default = "default_construction_year")]
+    pub construction_year: u32,
+    #[serde(default)]
+    pub amenities: Vec<SharedAmenity>,
+    #[serde(default)]
+    pub parking: Vec<ParkingSpot>,
+    /// Bought condemned: no applications until a renovation finishes.
+    #[serde(default)]
+    pub renovation_required: bool,
+    #[serde(default)]
+    pub renovation: Option<Renovation>,
+    #[serde(default)]
+    pub exterior_theme: ExteriorTheme,
+    /// Player-set storage locker rent; `None` charges the standard rate.
+    #[serde(default)]
+    pub storage_locker_rent: Option<i32>,
+    /// Tick an ongoing leak started, until every damaged unit is repaired
+    /// or the repair window runs out.
+    #[serde(default)]
+    pub water_damage_since: Option<u32>,
+    /// Tick the player last turned down landmark designation.
+    #[serde(default)]
+    pub landmark_declined_at: Option<u32>,
+    /// Appeal the building keeps as a landmark, fixed at designation.
+    #[serde(default)]
+    pub landmark_appeal: i32,
+    /// Applicants for units that already had a full slate of applications,
+    /// waiting for a spot to open up.
+    #[serde(default)]
+    pub waitlisted_applications: Vec<crate::tenant::TenantApplication>,
+    /// Appeal gained or lost to recent press coverage; fades month by month.
+    #[serde(default)]
+    pub press_coverage: i32,
+    /// Tenants living here while another building is active; they rejoin the
+    /// live tenant list when this building is switched back to.
+    #[serde(default)]
+    pub resident_tenants: Vec<crate::tenant::Tenant>,
 }
 
 impl Building {
@@ -97,6 +227,19 @@ impl Building {
             marketing_strategy: MarketingType::None,
             open_house_remaining: 0,
             flags: HashSet::new(),
+            construction_year: default_construction_year(),
+            amenities: Vec::new(),
+            parking: Vec::new(),
+            renovation_required: false,
+            renovation: None,
+            exterior_theme: ExteriorTheme::Classic,
+            storage_locker_rent: None,
+            water_damage_since: None,
+            landmark_declined_at: None,
+            landmark_appeal: 0,
+            waitlisted_applications: Vec::new(),
+            press_coverage: 0,
+            resident_tenants: Vec::new(),
         }
     }
 
@@ -132,9 +275,27 @@ impl Building {
             marketing_strategy: MarketingType::None,
             open_house_remaining: 0,
             flags: HashSet::new(),
+            construction_year: template.construction_year,
+            amenities: Vec::new(),
+            parking: Vec::new(),
+            renovation_required: false,
+            renovation: None,
+            exterior_theme: ExteriorTheme::Classic,
+            storage_locker_rent: None,
+            water_damage_since: None,
+            landmark_declined_at: None,
+            landmark_appeal: 0,
+            waitlisted_applications: Vec::new(),
+            press_coverage: 0,
+            resident_tenants: Vec::new(),
         }
     }
 
+    /// Years since construction as of `current_tick`.
+    pub fn age(&self, current_tick: u32) -> u32 {
+        (GAME_START_YEAR + current_tick / 12).saturating_sub(self.construction_year)
+    }
+
     /// Get apartment by ID
     pub fn get_apartment(&self, id: u32) -> Option<&Apartment> {
         self.apartments.iter().find(|a| a.id == id)
@@ -145,6 +306,32 @@ impl Building {
         self.apartments.iter_mut().find(|a| a.id == id)
     }
 
+    /// Give a unit a custom number. Numbers must be non-empty and unique
+    /// within the building.
+    pub fn rename_unit(&mut self, apartment_id: u32, new_number: &str) -> Result<(), String> {
+        let new_number = new_number.trim();
+        if new_number.is_empty() {
+            return Err("Unit number can't be empty".to_string());
+        }
+        if self
+            .apartments
+            .iter()
+            .any(|a| a.id != apartment_id && a.unit_number.eq_ignore_ascii_case(new_number))
+        {
+            return Err(format!("Unit {} already exists", new_number));
+        }
+        let apt = self
+            .get_apartment_mut(apartment_id)
+            .ok_or_else(|| "No such unit".to_string())?;
+        apt.unit_number = new_number.to_string();
+        Ok(())
+    }
+
+    /// Highest floor with any units on it.
+    pub fn top_floor(&self) -> u32 {
+        self.apartments.iter().map(|a| a.floor).max().unwrap_or(1)
+    }
+
     /// Get all vacant apartments
     pub fn vacant_apartments(&self) -> Vec<&Apartment> {
         self.apartments.iter().filter(|a| a.is_vacant()).collect()
@@ -176,7 +363,19 @@ impl Building {
             score += 10;
         }
 
-        score.min(100)
+        // Art and landmark status are the only things that can push a
+        // building past the ceiling.
+        let art = if self.has_hallway_art() {
+            super::art::HALLWAY_ART_APPEAL
+        } else {
+            0
+        };
+        let landmark = if self.is_landmark() {
+            self.landmark_appeal
+        } else {
+            0
+        };
+        (score + self.press_coverage).clamp(0, 100) + art + landmark
     }
 
     /// Repair hallway
@@ -189,6 +388,84 @@ impl Building {
         self.hallway_condition = (self.hallway_condition - amount).max(0);
     }
 
+    pub fn has_amenity(&self, amenity_type: AmenityType) -> bool {
+        self.amenities
+            .iter()
+            .any(|amenity| amenity.amenity_type == amenity_type)
+            || (amenity_type == AmenityType::Laundry && self.has_laundry)
+    }
+
+    /// Add a shared amenity at full condition (no-op if already installed).
+    pub fn install_amenity(&mut self, amenity_type: AmenityType) {
+        if amenity_type == AmenityType::Laundry {
+            self.has_laundry = true;
+        }
+        if !self
+            .amenities
+            .iter()
+            .any(|amenity| amenity.amenity_type == amenity_type)
+        {
+            self.amenities.push(SharedAmenity::new(amenity_type));
+        }
+    }
+
+    pub fn repair_amenity(&mut self, amenity_type: AmenityType, amount: i32) {
+        if let Some(amenity) = self
+            .amenities
+            .iter_mut()
+            .find(|amenity| amenity.amenity_type == amenity_type)
+        {
+            amenity.condition = (amenity.condition + amount).min(100);
+        }
+    }
+
+    /// Add `count` new parking spots at the given monthly fee.
+    pub fn add_parking_spots(&mut self, count: u32, monthly_fee: i32) {
+        for _ in 0..count {
+            let id = self.parking.len() as u32;
+            self.parking.push(ParkingSpot::new(id, monthly_fee));
+        }
+    }
+
+    /// Give a vacant spot to a tenant. A tenant holds at most one spot.
+    pub fn assign_parking(&mut self, spot_id: u32, tenant_id: u32) -> bool {
+        if self.parking_spot_of(tenant_id).is_some() {
+            return false;
+        }
+        match self.parking.iter_mut().find(|spot| spot.id == spot_id) {
+            Some(spot) if spot.is_vacant() => {
+                spot.assigned_tenant_id = Some(tenant_id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn revoke_parking(&mut self, spot_id: u32) {
+        if let Some(spot) = self.parking.iter_mut().find(|spot| spot.id == spot_id) {
+            spot.assigned_tenant_id = None;
+        }
+    }
+
+    pub fn parking_spot_of(&self, tenant_id: u32) -> Option<&ParkingSpot> {
+        self.parking
+            .iter()
+            .find(|spot| spot.assigned_tenant_id == Some(tenant_id))
+    }
+
+    pub fn has_vacant_parking(&self) -> bool {
+        self.parking.iter().any(|spot| spot.is_vacant())
+    }
+
+    /// Monthly fees from every assigned spot.
+    pub fn parking_income(&self) -> i32 {
+        self.parking
+            .iter()
+            .filter(|spot| !spot.is_vacant())
+            .map(|spot| spot.monthly_fee)
+            .sum()
+    }
+
     /// Apply decay to all apartments and hallway using configured rates.
     pub fn apply_monthly_decay(&mut self, apartment_decay: i32, hallway_decay: i32) {
         for apt in &mut self.apartments {
@@ -247,6 +524,43 @@ impl Building {
             _ => false, // Can't convert from Coop/Social easily yet
         }
     }
+    /// Sell a unit to the tenant living in it. They become a delighted owner
+    /// and their HOA is paid to the landlord from then on.
+    pub fn sell_unit_to_tenant(
+        &mut self,
+        apartment_id: u32,
+        tenant_id: u32,
+        owner_name: &str,
+        sale_price: i32,
+    ) -> bool {
+        if !self.convert_unit_to_condo(apartment_id, owner_name, sale_price) {
+            return false;
+        }
+        if let OwnershipType::MixedOwnership(board) | OwnershipType::FullCondo(board) =
+            &mut self.ownership_model
+        {
+            if let Some(unit) = board
+                .units
+                .iter_mut()
+                .find(|u| u.apartment_id == apartment_id)
+            {
+                unit.former_tenant_id = Some(tenant_id);
+                unit.owner_satisfaction = 100;
+            }
+        }
+        true
+    }
+
+    /// HOA fees the landlord collects from tenants who bought their units.
+    pub fn owner_occupier_fees(&self) -> i32 {
+        match &self.ownership_model {
+            OwnershipType::MixedOwnership(board) | OwnershipType::FullCondo(board) => {
+                board.owner_occupier_fees()
+            }
+            _ => 0,
+        }
+    }
+
     pub fn update_ownership(&mut self, current_month: u32) -> bool {
         match &mut self.ownership_model {
             OwnershipType::MixedOwnership(board) | OwnershipType::FullCondo(board) => {
@@ -361,4 +675,62 @@ mod tests {
         assert_eq!(building.hallway_condition, initial_hallway - 1);
         assert_eq!(building.apartments[0].condition, initial_apt_condition - 3);
     }
+
+    #[test]
+    fn test_amenity_install_and_repair() {
+        let mut building = Building::new("Test", 1, 1);
+        assert!(!building.has_amenity(AmenityType::Gym));
+
+        building.install_amenity(AmenityType::Gym);
+        building.install_amenity(AmenityType::Gym);
+        assert_eq!(building.amenities.len(), 1);
+
+        building.amenities[0].condition = 70;
+        building.repair_amenity(AmenityType::Gym, 40);
+        assert_eq!(building.amenities[0].condition, 100);
+
+        building.install_amenity(AmenityType::Laundry);
+        assert!(building.has_laundry);
+    }
+
+    #[test]
+    fn test_parking_assignment() {
+        let mut building = Building::new("Test", 1, 1);
+        building.add_parking_spots(2, 75);
+        assert_eq!(building.parking[1].spot_number, "P2");
+
+        assert!(building.assign_parking(0, 7));
+        assert!(!building.assign_parking(0, 8), "spot already taken");
+        assert!(!building.assign_parking(1, 7), "one spot per tenant");
+        assert_eq!(building.parking_income(), 75);
+
+        building.revoke_parking(0);
+        assert_eq!(building.parking_income(), 0);
+        assert!(building.has_vacant_parking());
+    }
+
+    #[test]
+    fn exterior_theme_keys_round_trip() {
+        for theme in ExteriorTheme::ALL {
+            assert_eq!(ExteriorTheme::from_key(theme.key()), Some(theme));
+        }
+        assert_eq!(
+            Building::new("Test", 1, 1).exterior_theme,
+            ExteriorTheme::Classic
+        );
+    }
+
+    #[test]
+    fn rename_unit_rejects_blank_and_duplicate_numbers() {
+        let mut building = Building::new("Test", 2, 2);
+        let first = building.apartments[0].id;
+        let taken = building.apartments[1].unit_number.clone();
+
+        assert!(building.rename_unit(first, "  ").is_err());
+        assert!(building.rename_unit(first, &taken).is_err());
+        assert!(building.rename_unit(first, "101").is_ok());
+        assert_eq!(building.apartments[0].unit_number, "101");
+        // Keeping its own number is not a duplicate.
+        assert!(building.rename_unit(first, "101").is_ok());
+    }
 }
@@ -0,0 +1,79 @@
+use super::Building;
+use crate::data::config::LandmarkConfig;
+
+/// Building flag set once the city designates the building a landmark.
+pub const LANDMARK_FLAG: &str = "landmark";
+/// Building flag set while a designation proposal awaits an answer.
+pub const LANDMARK_PROPOSED_FLAG: &str = "landmark_proposed";
+
+impl Building {
+    pub fn is_landmark(&self) -> bool {
+        self.flags.contains(LANDMARK_FLAG)
+    }
+
+    /// Old and handsome enough for the city to want it preserved, and not
+    /// already asked recently. The caller checks the neighborhood.
+    pub fn landmark_eligible(&self, current_tick: u32, config: &LandmarkConfig) -> bool {
+        !self.is_landmark()
+            && !self.flags.contains(LANDMARK_PROPOSED_FLAG)
+            && self.building_appeal() >= config.min_appeal
+            && self.age(current_tick) > config.min_age
+            && self
+                .landmark_declined_at
+                .is_none_or(|tick| current_tick >= tick + config.retry_ticks)
+    }
+
+    /// Settle a designation proposal either way. A landmark keeps the
+    /// configured appeal bonus it was designated with.
+    pub fn answer_landmark_proposal(
+        &mut self,
+        accepted: bool,
+        current_tick: u32,
+        config: &LandmarkConfig,
+    ) {
+        self.flags.remove(LANDMARK_PROPOSED_FLAG);
+        if accepted {
+            self.flags.insert(LANDMARK_FLAG.to_string());
+            self.landmark_appeal = config.appeal;
+        } else {
+            self.landmark_declined_at = Some(current_tick);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handsome_old_building() -> Building {
+        let mut building = Building::new("Test", 1, 2);
+        building.construction_year = 1900;
+        building.hallway_condition = 100;
+        for apt in &mut building.apartments {
+            apt.condition = 100;
+        }
+        building
+    }
+
+    #[test]
+    fn declining_holds_off_the_next_proposal() {
+        let config = LandmarkConfig::default();
+        let mut building = handsome_old_building();
+        assert!(building.landmark_eligible(0, &config));
+
+        building.answer_landmark_proposal(false, 5, &config);
+        assert!(!building.landmark_eligible(5 + config.retry_ticks - 1, &config));
+        assert!(building.landmark_eligible(5 + config.retry_ticks, &config));
+    }
+
+    #[test]
+    fn landmarks_keep_extra_appeal() {
+        let config = LandmarkConfig::default();
+        let mut building = handsome_old_building();
+        let before = building.building_appeal();
+        building.answer_landmark_proposal(true, 0, &config);
+        assert!(building.is_landmark());
+        assert!(!building.landmark_eligible(0, &config));
+        assert_eq!(building.building_appeal(), before + config.appeal);
+    }
+}
@@ -0,0 +1,137 @@
+use super::{Apartment, ApartmentSize, Building};
+
+impl Building {
+    /// The vacant unit directly beside `apartment_id` on the same floor, if
+    /// the pair could be merged.
+    pub fn mergeable_neighbor(&self, apartment_id: u32) -> Option<u32> {
+        let apt = self.get_apartment(apartment_id)?;
+        let floor: Vec<u32> = self
+            .apartments
+            .iter()
+            .filter(|a| a.floor == apt.floor)
+            .map(|a| a.id)
+            .collect();
+        let index = floor.iter().position(|&id| id == apartment_id)?;
+        let before = index.checked_sub(1).and_then(|i| floor.get(i));
+        let after = floor.get(index + 1);
+        after
+            .into_iter()
+            .chain(before)
+            .copied()
+            .find(|&id| self.can_merge(apartment_id, id).is_ok())
+    }
+
+    /// Both units must be vacant, unsold, on the same floor and side by side.
+    pub fn can_merge(&self, apt_a_id: u32, apt_b_id: u32) -> Result<(), String> {
+        let (Some(a), Some(b)) = (self.get_apartment(apt_a_id), self.get_apartment(apt_b_id))
+        else {
+            return Err("No such unit".to_string());
+        };
+        if apt_a_id == apt_b_id {
+            return Err("Can't merge a unit with itself".to_string());
+        }
+        if self.is_landmark() {
+            return Err("Landmark walls can't come down".to_string());
+        }
+        if !a.is_vacant() || !b.is_vacant() {
+            return Err("Both units must be vacant".to_string());
+        }
+        if self.is_unit_sold(apt_a_id) || self.is_unit_sold(apt_b_id) {
+            return Err("Sold condos can't be merged".to_string());
+        }
+        if a.floor != b.floor {
+            return Err("Units must be on the same floor".to_string());
+        }
+        let floor_ids: Vec<u32> = self
+            .apartments
+            .iter()
+            .filter(|apt| apt.floor == a.floor)
+            .map(|apt| apt.id)
+            .collect();
+        let adjacent = floor_ids
+            .windows(2)
+            .any(|pair| pair == [apt_a_id, apt_b_id] || pair == [apt_b_id, apt_a_id]);
+        if !adjacent {
+            return Err("Units must be next to each other".to_string());
+        }
+        Ok(())
+    }
+
+    /// Knock through the wall between two units. The merged unit takes the
+    /// place and number of whichever came first on the floor. Returns its id.
+    pub fn merge_units(&mut self, apt_a_id: u32, apt_b_id: u32) -> Result<u32, String> {
+        self.can_merge(apt_a_id, apt_b_id)?;
+        let index_a = self.apartments.iter().position(|a| a.id == apt_a_id);
+        let index_b = self.apartments.iter().position(|a| a.id == apt_b_id);
+        let (Some(index_a), Some(index_b)) = (index_a, index_b) else {
+            return Err("No such unit".to_string());
+        };
+        let (first_index, second_index) = if index_a < index_b {
+            (index_a, index_b)
+        } else {
+            (index_b, index_a)
+        };
+
+        // Removing the later unit leaves the earlier one's index intact.
+        let second = self.apartments.remove(second_index);
+        let first = &self.apartments[first_index];
+        let size = match (&first.size, &second.size) {
+            (ApartmentSize::Large, ApartmentSize::Large) => ApartmentSize::Penthouse,
+            _ => ApartmentSize::Large,
+        };
+        let new_id = self
+            .apartments
+            .iter()
+            .map(|a| a.id)
+            .max()
+            .unwrap_or(0)
+            .max(second.id)
+            + 1;
+        let mut merged = Apartment::new(
+            new_id,
+            &first.unit_number,
+            first.floor,
+            size,
+            first.base_noise.clone(),
+        );
+        merged.condition = first.condition.min(second.condition);
+        merged.rent_price = first.rent_price + second.rent_price;
+
+        self.apartments[first_index] = merged;
+        Ok(new_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merging_neighbors_combines_rent_and_keeps_worst_condition() {
+        let mut building = Building::new("Test", 2, 2);
+        let (a, b) = (building.apartments[0].id, building.apartments[1].id);
+        building.apartments[0].condition = 80;
+        building.apartments[1].condition = 40;
+        let rent = building.apartments[0].rent_price + building.apartments[1].rent_price;
+        let count = building.apartments.len();
+
+        let merged = building.merge_units(a, b).unwrap();
+        let apt = building.get_apartment(merged).unwrap();
+        assert_eq!(apt.condition, 40);
+        assert_eq!(apt.rent_price, rent);
+        assert_eq!(building.apartments.len(), count - 1);
+        assert!(building.get_apartment(a).is_none());
+    }
+
+    #[test]
+    fn occupied_or_distant_units_cannot_merge() {
+        let mut building = Building::new("Test", 2, 2);
+        let (a, b) = (building.apartments[0].id, building.apartments[1].id);
+        let upstairs = building.apartments[2].id;
+        assert!(building.can_merge(a, upstairs).is_err());
+
+        building.apartments[1].tenant_id = Some(1);
+        assert!(building.can_merge(a, b).is_err());
+        assert_eq!(building.mergeable_neighbor(a), None);
+    }
+}
@@ -18,6 +18,10 @@ pub struct CondoUnit {
     pub owner_satisfaction: i32, // 0-100
     pub voting_power: i32,       // Usually based on SqFt or equal
     pub purchase_price: i32,
+    /// The tenant who bought the unit they were renting, if it was sold that
+    /// way. Their HOA goes to the landlord rather than the reserve fund.
+    #[serde(default)]
+    pub former_tenant_id: Option<u32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -62,16 +66,33 @@ impl CondoBoard {
             owner_satisfaction: 50, // Start neutral
             voting_power: 1,        // Default 1 vote per unit
             purchase_price,
+            former_tenant_id: None,
         });
     }
 
-    /// Collect HOA fees from all units
+    /// Collect HOA fees into the reserve, except from owners who bought out
+    /// their own lease (see `owner_occupier_fees`).
     pub fn collect_fees(&mut self) -> i32 {
-        let total: i32 = self.units.iter().map(|u| u.monthly_hoa).sum();
+        let total: i32 = self
+            .units
+            .iter()
+            .filter(|u| u.former_tenant_id.is_none())
+            .map(|u| u.monthly_hoa)
+            .sum();
         self.reserve_fund += total;
         total
     }
 
+    /// Monthly HOA owed to the landlord by former tenants who bought their
+    /// units.
+    pub fn owner_occupier_fees(&self) -> i32 {
+        self.units
+            .iter()
+            .filter(|u| u.former_tenant_id.is_some())
+            .map(|u| u.monthly_hoa)
+            .sum()
+    }
+
     /// Resolve votes that have reached deadline
     pub fn resolve_votes(&mut self, current_month: u32) -> Vec<String> {
         let mut results = Vec::new();
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// A rentable parking spot; its fee is billed on top of the tenant's rent.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ParkingSpot {
+    pub id: u32,
+    pub monthly_fee: i32,
+    pub assigned_tenant_id: Option<u32>,
+    pub spot_number: String,
+}
+
+impl ParkingSpot {
+    pub fn new(id: u32, monthly_fee: i32) -> Self {
+        Self {
+            id,
+            monthly_fee,
+            assigned_tenant_id: None,
+            spot_number: format!("P{}", id + 1),
+        }
+    }
+
+    pub fn is_vacant(&self) -> bool {
+        self.assigned_tenant_id.is_none()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::data::config::PropertyManagerConfig;
+use serde::{Deserialize, Serialize};
+
+/// How much of the day-to-day running a property manager takes on. Each
+/// level does everything the one before it does.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AutomationLevel {
+    /// Repairs run-down units
+    Basic,
+    /// Also fills vacancies with the best-scoring applicant
+    Advanced,
+    /// Also keeps rents at market rate
+    Full,
+}
+
+impl AutomationLevel {
+    pub const ALL: [AutomationLevel; 3] = [
+        AutomationLevel::Basic,
+        AutomationLevel::Advanced,
+        AutomationLevel::Full,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AutomationLevel::Basic => "Basic",
+            AutomationLevel::Advanced => "Advanced",
+            AutomationLevel::Full => "Full",
+        }
+    }
+
+    pub fn salary(&self, config: &PropertyManagerConfig) -> i32 {
+        match self {
+            AutomationLevel::Basic => config.basic_salary,
+            AutomationLevel::Advanced => config.advanced_salary,
+            AutomationLevel::Full => config.full_salary,
+        }
+    }
+
+    pub fn accepts_applications(&self) -> bool {
+        *self >= AutomationLevel::Advanced
+    }
+
+    pub fn sets_rents(&self) -> bool {
+        *self == AutomationLevel::Full
+    }
+}
+
+/// A hired manager who runs the building between the player's decisions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PropertyManager {
+    pub name: String,
+    pub monthly_salary: i32,
+    pub automation_level: AutomationLevel,
+}
+
+impl PropertyManager {
+    pub fn hire(automation_level: AutomationLevel, config: &PropertyManagerConfig) -> Self {
+        Self {
+            name: "Dana Whitfield".to_string(),
+            monthly_salary: automation_level.salary(config),
+            automation_level,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn higher_levels_do_more_for_more_pay() {
+        let config = PropertyManagerConfig::default();
+        let basic = PropertyManager::hire(AutomationLevel::Basic, &config);
+        let full = PropertyManager::hire(AutomationLevel::Full, &config);
+        assert!(full.monthly_salary > basic.monthly_salary);
+        assert!(!basic.automation_level.accepts_applications());
+        assert!(full.automation_level.accepts_applications());
+        assert!(full.automation_level.sets_rents());
+        assert!(!AutomationLevel::Advanced.sets_rents());
+    }
+}
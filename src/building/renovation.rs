@@ -0,0 +1,108 @@
+use super::Building;
+use serde::{Deserialize, Serialize};
+
+/// Condition every unit is brought up to by a renovation.
+pub const RENOVATED_CONDITION: i32 = 60;
+/// Months a renovation takes at the standard budget.
+const STANDARD_MONTHS: u32 = 6;
+const MIN_MONTHS: u32 = 2;
+const MAX_MONTHS: u32 = 12;
+
+/// An in-progress gut renovation of a condemned building. A bigger budget
+/// finishes sooner.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Renovation {
+    pub budget: i32,
+    pub total_months: u32,
+    pub months_remaining: u32,
+}
+
+impl Renovation {
+    /// `standard_cost` is what the renovation costs at the standard pace.
+    pub fn new(budget: i32, standard_cost: i32) -> Self {
+        let months =
+            (standard_cost as f32 * STANDARD_MONTHS as f32 / budget.max(1) as f32).ceil() as u32;
+        let months = months.clamp(MIN_MONTHS, MAX_MONTHS);
+        Self {
+            budget,
+            total_months: months,
+            months_remaining: months,
+        }
+    }
+
+    /// Fraction complete, 0.0 - 1.0.
+    pub fn progress(&self) -> f32 {
+        1.0 - self.months_remaining as f32 / self.total_months.max(1) as f32
+    }
+}
+
+impl Building {
+    /// Cost of renovating this building at the standard pace.
+    pub fn renovation_standard_cost(&self, cost_per_unit: i32) -> i32 {
+        self.apartments.len() as i32 * cost_per_unit
+    }
+
+    pub fn begin_renovation(&mut self, budget: i32, cost_per_unit: i32) {
+        let standard_cost = self.renovation_standard_cost(cost_per_unit);
+        self.renovation = Some(Renovation::new(budget, standard_cost));
+    }
+
+    /// Work one month of an active renovation. Returns true when it finishes.
+    pub fn advance_renovation(&mut self) -> bool {
+        let Some(renovation) = self.renovation.as_mut() else {
+            return false;
+        };
+        let months = renovation.months_remaining.max(1) as i32;
+        renovation.months_remaining = renovation.months_remaining.saturating_sub(1);
+        let finished = renovation.months_remaining == 0;
+
+        // Close an even share of each unit's gap to the target every month.
+        let step = |condition: i32| {
+            let gap = (RENOVATED_CONDITION - condition).max(0);
+            condition + (gap + months - 1) / months
+        };
+        for apt in &mut self.apartments {
+            apt.condition = step(apt.condition);
+        }
+        self.hallway_condition = step(self.hallway_condition);
+
+        if finished {
+            self.renovation = None;
+            self.renovation_required = false;
+        }
+        finished
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renovation_length_scales_with_budget() {
+        assert_eq!(Renovation::new(10_000, 10_000).total_months, 6);
+        assert_eq!(Renovation::new(20_000, 10_000).total_months, 3);
+        assert_eq!(Renovation::new(1_000, 10_000).total_months, MAX_MONTHS);
+    }
+
+    #[test]
+    fn finished_renovation_clears_the_requirement() {
+        let mut building = Building::new("Test", 2, 2);
+        building.renovation_required = true;
+        for apt in &mut building.apartments {
+            apt.condition = 5;
+        }
+        building.begin_renovation(40_000, 2_500);
+        let months = building.renovation.as_ref().unwrap().total_months;
+
+        for _ in 1..months {
+            assert!(!building.advance_renovation());
+        }
+        assert!(building.advance_renovation());
+        assert!(!building.renovation_required);
+        assert!(building
+            .apartments
+            .iter()
+            .all(|apt| apt.condition >= RENOVATED_CONDITION));
+    }
+}
@@ -0,0 +1,134 @@
+use super::Building;
+use crate::data::config::SquatterConfig;
+use macroquad_toolkit::rng;
+
+/// Apartment flag marking a unit someone has moved into without a lease.
+pub const SQUATTER_FLAG: &str = "squatter";
+/// Apartment flag set once the player has paid to have a squatter removed.
+pub const SQUATTER_EVICTION_FLAG: &str = "squatter_eviction";
+
+impl Building {
+    /// Count another month for every empty unit, and reset occupied ones.
+    pub fn track_vacancies(&mut self) {
+        for apt in &mut self.apartments {
+            if apt.is_vacant() {
+                apt.vacant_ticks += 1;
+            } else {
+                apt.vacant_ticks = 0;
+            }
+        }
+    }
+
+    /// Long-empty units may pick up a squatter. Returns the unit number of
+    /// the first one that did.
+    pub fn settle_squatter(
+        &mut self,
+        current_tick: u32,
+        config: &SquatterConfig,
+    ) -> Option<String> {
+        let apt = self.apartments.iter_mut().find(|apt| {
+            apt.is_vacant()
+                && !apt.has_squatter()
+                && apt.vacant_ticks > config.squat_after_vacant_ticks
+                && rng::gen_range(0, 100) < config.squat_chance
+        })?;
+        apt.flags.insert(SQUATTER_FLAG.to_string());
+        apt.squatter_since = Some(current_tick);
+        Some(apt.unit_number.clone())
+    }
+
+    /// Squatters nobody is dealing with wear their unit down every few
+    /// ticks. Returns the unit numbers damaged this tick.
+    pub fn squatter_damage(
+        &mut self,
+        current_tick: u32,
+        config: &SquatterConfig,
+    ) -> Vec<(String, i32)> {
+        let mut damaged = Vec::new();
+        for apt in &mut self.apartments {
+            let Some(since) = apt.squatter_since else {
+                continue;
+            };
+            let ticks = current_tick.saturating_sub(since);
+            if apt.flags.contains(SQUATTER_EVICTION_FLAG)
+                || ticks == 0
+                || !ticks.is_multiple_of(config.damage_interval_ticks)
+            {
+                continue;
+            }
+            apt.condition = (apt.condition - config.damage).max(0);
+            damaged.push((apt.unit_number.clone(), config.damage));
+        }
+        damaged
+    }
+
+    /// Squatters with an eviction under way are gone by the next tick.
+    /// Returns the unit numbers cleared.
+    pub fn complete_squatter_evictions(&mut self) -> Vec<String> {
+        let mut cleared = Vec::new();
+        for apt in &mut self.apartments {
+            if apt.flags.remove(SQUATTER_EVICTION_FLAG) {
+                apt.flags.remove(SQUATTER_FLAG);
+                apt.squatter_since = None;
+                apt.vacant_ticks = 0;
+                cleared.push(apt.unit_number.clone());
+            }
+        }
+        cleared
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn squatted_building() -> Building {
+        let mut building = Building::new("Test", 1, 1);
+        let apt = &mut building.apartments[0];
+        apt.condition = 80;
+        apt.flags.insert(SQUATTER_FLAG.to_string());
+        apt.squatter_since = Some(10);
+        building
+    }
+
+    #[test]
+    fn only_long_vacancies_get_squatters() {
+        let config = SquatterConfig::default();
+        let mut building = Building::new("Test", 1, 1);
+        for _ in 0..config.squat_after_vacant_ticks {
+            building.track_vacancies();
+        }
+        for _ in 0..100 {
+            assert!(building.settle_squatter(1, &config).is_none());
+        }
+
+        building.track_vacancies();
+        let settled = (0..1000).find_map(|_| building.settle_squatter(1, &config));
+        assert!(settled.is_some());
+        assert!(building.apartments[0].has_squatter());
+    }
+
+    #[test]
+    fn ignored_squatter_damages_the_unit() {
+        let config = SquatterConfig::default();
+        let mut building = squatted_building();
+        assert!(building.squatter_damage(12, &config).is_empty());
+        assert_eq!(building.squatter_damage(13, &config).len(), 1);
+        assert_eq!(building.apartments[0].condition, 80 - config.damage);
+    }
+
+    #[test]
+    fn eviction_clears_the_squatter() {
+        let mut building = squatted_building();
+        building.apartments[0]
+            .flags
+            .insert(SQUATTER_EVICTION_FLAG.to_string());
+
+        // No damage while the eviction is under way
+        assert!(building
+            .squatter_damage(13, &SquatterConfig::default())
+            .is_empty());
+        assert_eq!(building.complete_squatter_evictions().len(), 1);
+        assert!(!building.apartments[0].has_squatter());
+    }
+}
@@ -0,0 +1,42 @@
+use super::Building;
+
+/// Building flag set by the storage locker upgrade.
+pub const STORAGE_LOCKERS_FLAG: &str = "storage_lockers";
+
+impl Building {
+    pub fn has_storage_lockers(&self) -> bool {
+        self.flags.contains(STORAGE_LOCKERS_FLAG)
+    }
+
+    /// Monthly locker rent, or `standard_rent` until the player sets one.
+    pub fn storage_locker_rent(&self, standard_rent: i32) -> i32 {
+        self.storage_locker_rent.unwrap_or(standard_rent)
+    }
+
+    /// Monthly locker takings: every occupied unit rents one.
+    pub fn storage_income(&self, standard_rent: i32) -> i32 {
+        if !self.has_storage_lockers() {
+            return 0;
+        }
+        self.storage_locker_rent(standard_rent) * self.occupancy_count() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lockers_earn_per_occupied_unit_at_the_set_rent() {
+        let mut building = Building::new("Test", 1, 3);
+        building.apartments[0].tenant_id = Some(1);
+        building.apartments[1].tenant_id = Some(2);
+        assert_eq!(building.storage_income(20), 0);
+
+        building.flags.insert(STORAGE_LOCKERS_FLAG.to_string());
+        assert_eq!(building.storage_income(20), 40);
+
+        building.storage_locker_rent = Some(35);
+        assert_eq!(building.storage_income(20), 70);
+    }
+}
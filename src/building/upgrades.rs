@@ -1,4 +1,7 @@
-use super::{Apartment, ApartmentSize, Building, DesignType};
+use super::{
+    AmenityType, Apartment, ApartmentSize, Building, DesignType, ExteriorTheme,
+    GreenCertificationLevel, NoiseLevel,
+};
 use crate::data::config::{
     EconomyConfig, UiConfig, UpgradeDefinition, UpgradeRequirement, UpgradeTarget,
 };
@@ -17,6 +20,10 @@ pub enum UpgradeAction {
     RepairHallway {
         amount: i32,
     },
+    RepairAmenity {
+        amenity: AmenityType,
+        amount: i32,
+    },
     // Generic upgrade identified by ID (from config.json)
     Apply {
         upgrade_id: String,
@@ -66,6 +73,15 @@ impl UpgradeAction {
                     .unwrap_or("Repair Hallway +{}");
                 fmt.replace("{}", &amount.to_string())
             }
+            UpgradeAction::RepairAmenity { amenity, amount } => {
+                let fmt = config
+                    .upgrade_labels
+                    .get("repair_amenity_fmt")
+                    .map(|s| s.as_str())
+                    .unwrap_or("Repair {name} +{}");
+                fmt.replace("{name}", amenity.name())
+                    .replace("{}", &amount.to_string())
+            }
             UpgradeAction::Apply { upgrade_id, .. } => upgrades
                 .get(upgrade_id)
                 .map(|u| u.name.clone())
@@ -98,6 +114,9 @@ impl UpgradeAction {
             UpgradeAction::RepairHallway { amount } => {
                 Some(amount * config.hallway_repair_cost_per_point)
             }
+            UpgradeAction::RepairAmenity { amount, .. } => {
+                Some(amount * config.repair_cost_per_point)
+            }
             UpgradeAction::Apply {
                 upgrade_id,
                 target_id,
@@ -146,6 +165,10 @@ pub fn apply_upgrade(
             building.repair_hallway(*amount);
             Some(())
         }
+        UpgradeAction::RepairAmenity { amenity, amount } => {
+            building.repair_amenity(*amenity, *amount);
+            Some(())
+        }
         UpgradeAction::Apply {
             upgrade_id,
             target_id,
@@ -181,6 +204,11 @@ pub fn apply_upgrade(
                                     _ => {}
                                 }
                             }
+                            crate::data::config::UpgradeEffect::SetSize(size_str) => {
+                                let size = ApartmentSize::from_name(size_str)?;
+                                apt.rent_price = apt.rent_price.max(size.converted_rent());
+                                apt.size = size;
+                            }
                             _ => {}
                         }
                     }
@@ -195,6 +223,18 @@ pub fn apply_upgrade(
                             crate::data::config::UpgradeEffect::RemoveFlag(flag) => {
                                 building.flags.remove(flag);
                             }
+                            crate::data::config::UpgradeEffect::InstallAmenity(key) => {
+                                building.install_amenity(AmenityType::from_key(key)?);
+                            }
+                            crate::data::config::UpgradeEffect::AddParkingSpots {
+                                count,
+                                monthly_fee,
+                            } => {
+                                building.add_parking_spots(*count, *monthly_fee);
+                            }
+                            crate::data::config::UpgradeEffect::SetExteriorTheme(key) => {
+                                building.exterior_theme = ExteriorTheme::from_key(key)?;
+                            }
                             _ => {}
                         }
                     }
@@ -205,9 +245,13 @@ pub fn apply_upgrade(
     }
 }
 
+/// `unlocked` lists New Game+ upgrades carried over from a completed building;
+/// those skip their unit-size gate.
 pub fn available_apartment_upgrades(
     apt: &Apartment,
+    building: &Building,
     upgrades: &HashMap<String, UpgradeDefinition>,
+    unlocked: &[String],
 ) -> Vec<UpgradeAction> {
     let mut actions = Vec::new();
 
@@ -222,8 +266,15 @@ pub fn available_apartment_upgrades(
 
     // 2. Generic Upgrades (includes Design upgrades now)
     for (id, def) in upgrades {
+        let size_gate_lifted = unlocked.contains(id);
+        let requirements: Vec<UpgradeRequirement> = def
+            .requirements
+            .iter()
+            .filter(|req| !(size_gate_lifted && matches!(req, UpgradeRequirement::MinSize(_))))
+            .cloned()
+            .collect();
         if def.target == UpgradeTarget::Apartment
-            && check_requirements(&def.requirements, apt, None)
+            && check_requirements(&requirements, apt, Some(building))
         {
             actions.push(UpgradeAction::Apply {
                 upgrade_id: id.clone(),
@@ -246,6 +297,14 @@ pub fn available_building_upgrades(
         let amount = (100 - building.hallway_condition).min(10);
         actions.push(UpgradeAction::RepairHallway { amount });
     }
+    for amenity in &building.amenities {
+        if amenity.condition < 100 {
+            actions.push(UpgradeAction::RepairAmenity {
+                amenity: amenity.amenity_type,
+                amount: (100 - amenity.condition).min(10),
+            });
+        }
+    }
 
     // 2. Generic Upgrades
     for (id, def) in upgrades {
@@ -265,7 +324,7 @@ pub fn available_building_upgrades(
 fn check_requirements(
     reqs: &[UpgradeRequirement],
     apt: &Apartment,
-    _building: Option<&Building>,
+    building: Option<&Building>,
 ) -> bool {
     for req in reqs {
         match req {
@@ -333,6 +392,17 @@ fn check_requirements(
                     return false;
                 }
             }
+            UpgradeRequirement::HasSize(size_str)
+                if ApartmentSize::from_name(size_str).as_ref() != Some(&apt.size) =>
+            {
+                return false;
+            }
+            UpgradeRequirement::TopFloor if building.is_none_or(|b| apt.floor < b.top_floor()) => {
+                return false;
+            }
+            UpgradeRequirement::StreetFacing if apt.base_noise != NoiseLevel::High => {
+                return false;
+            }
             _ => {} // Implement generic stat checks later if needed
         }
     }
@@ -357,12 +427,37 @@ fn check_requirements_building(reqs: &[UpgradeRequirement], building: &Building)
                     return false;
                 }
             }
+            UpgradeRequirement::MissingAmenity(key)
+                if AmenityType::from_key(key).is_some_and(|a| building.has_amenity(a)) =>
+            {
+                return false;
+            }
+            UpgradeRequirement::MissingExteriorTheme(key)
+                if building.exterior_theme.key() == key =>
+            {
+                return false;
+            }
             _ => {}
         }
     }
     true
 }
 
+/// Certification earned from eco upgrades: solar panels plus a community
+/// garden is Bronze, either HVAC or bicycle storage on top is Silver, and all
+/// four is Gold.
+pub fn green_certification_level(building: &Building) -> GreenCertificationLevel {
+    let has = |flag: &str| building.flags.contains(flag);
+    if !has("solar_panels") || !has("community_garden") {
+        return GreenCertificationLevel::None;
+    }
+    match (has("hvac"), has("bicycle_storage")) {
+        (true, true) => GreenCertificationLevel::Gold,
+        (true, false) | (false, true) => GreenCertificationLevel::Silver,
+        (false, false) => GreenCertificationLevel::Bronze,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,4 +496,107 @@ mod tests {
         assert_eq!(cost, Some(())); // Returns Option<()>
         assert_eq!(building.apartments[0].condition, initial_condition + 20);
     }
+
+    #[test]
+    fn test_unlocked_upgrade_skips_size_gate() {
+        let mut apt = Apartment::new(
+            0,
+            "1A",
+            1,
+            ApartmentSize::Small,
+            crate::building::NoiseLevel::Low,
+        );
+        apt.design = DesignType::Cozy;
+        let building = Building::new("Test", 1, 1);
+        let mut upgrades = HashMap::new();
+        upgrades.insert(
+            "upgrade_to_luxury".to_string(),
+            UpgradeDefinition {
+                id: "upgrade_to_luxury".to_string(),
+                name: "Luxury".to_string(),
+                cost: 25000,
+                target: UpgradeTarget::Apartment,
+                effects: vec![],
+                requirements: vec![
+                    UpgradeRequirement::HasDesign("Cozy".to_string()),
+                    UpgradeRequirement::MinSize("Penthouse".to_string()),
+                ],
+            },
+        );
+
+        let offers_luxury = |unlocked: &[String]| {
+            available_apartment_upgrades(&apt, &building, &upgrades, unlocked)
+                .iter()
+                .any(|a| matches!(a, UpgradeAction::Apply { upgrade_id, .. } if upgrade_id == "upgrade_to_luxury"))
+        };
+        assert!(!offers_luxury(&[]));
+        assert!(offers_luxury(&["upgrade_to_luxury".to_string()]));
+    }
+
+    #[test]
+    fn penthouse_conversion_needs_a_large_top_floor_unit() {
+        let mut building = Building::new("Test", 2, 2);
+        let mut upgrades = HashMap::new();
+        upgrades.insert(
+            "convert_to_penthouse".to_string(),
+            UpgradeDefinition {
+                id: "convert_to_penthouse".to_string(),
+                name: "Penthouse".to_string(),
+                cost: 30000,
+                target: UpgradeTarget::Apartment,
+                effects: vec![crate::data::config::UpgradeEffect::SetSize(
+                    "Penthouse".to_string(),
+                )],
+                requirements: vec![
+                    UpgradeRequirement::HasSize("Large".to_string()),
+                    UpgradeRequirement::TopFloor,
+                ],
+            },
+        );
+        let offers_penthouse = |building: &Building, index: usize| {
+            available_apartment_upgrades(&building.apartments[index], building, &upgrades, &[])
+                .iter()
+                .any(|a| matches!(a, UpgradeAction::Apply { upgrade_id, .. } if upgrade_id == "convert_to_penthouse"))
+        };
+        building.apartments[0].size = ApartmentSize::Large;
+        building.apartments[2].size = ApartmentSize::Large;
+        assert!(!offers_penthouse(&building, 0), "ground floor");
+        assert!(offers_penthouse(&building, 2));
+
+        let action = UpgradeAction::Apply {
+            upgrade_id: "convert_to_penthouse".to_string(),
+            target_id: Some(building.apartments[2].id),
+        };
+        apply_upgrade(&mut building, &action, &upgrades);
+        assert_eq!(building.apartments[2].size, ApartmentSize::Penthouse);
+        assert!(building.apartments[2].rent_price > ApartmentSize::Large.base_rent());
+    }
+
+    #[test]
+    fn green_certification_stacks_eco_upgrades() {
+        let mut building = Building::new("Test", 1, 1);
+        building.flags.insert("solar_panels".to_string());
+        assert_eq!(
+            green_certification_level(&building),
+            GreenCertificationLevel::None
+        );
+
+        building.flags.insert("community_garden".to_string());
+        assert_eq!(
+            green_certification_level(&building),
+            GreenCertificationLevel::Bronze
+        );
+
+        building.flags.insert("bicycle_storage".to_string());
+        assert_eq!(
+            green_certification_level(&building),
+            GreenCertificationLevel::Silver
+        );
+
+        building.flags.insert("hvac".to_string());
+        assert_eq!(
+            green_certification_level(&building),
+            GreenCertificationLevel::Gold
+        );
+    }
 }
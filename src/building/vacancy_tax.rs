@@ -0,0 +1,73 @@
+use super::{Apartment, Building};
+use crate::data::config::VacancyTaxConfig;
+
+impl Apartment {
+    /// Ticks this unit has been empty, if it's vacant.
+    pub fn ticks_vacant(&self, current_tick: u32) -> Option<u32> {
+        if !self.is_vacant() {
+            return None;
+        }
+        self.vacant_since_tick
+            .map(|since| current_tick.saturating_sub(since))
+    }
+
+    pub fn vacancy_taxed(&self, current_tick: u32, config: &VacancyTaxConfig) -> bool {
+        self.ticks_vacant(current_tick)
+            .is_some_and(|ticks| ticks >= config.vacancy_tax_trigger_ticks)
+    }
+}
+
+impl Building {
+    /// Total vacancy tax owed this tick. Units that were never moved out of
+    /// (empty since the building was bought) start their clock now. Units
+    /// sold as condos are their owners' business, not the landlord's.
+    pub fn vacancy_tax_due(&mut self, current_tick: u32, config: &VacancyTaxConfig) -> i32 {
+        let sold: Vec<u32> = self
+            .apartments
+            .iter()
+            .filter(|apt| self.is_unit_sold(apt.id))
+            .map(|apt| apt.id)
+            .collect();
+        let mut taxed = 0;
+        for apt in &mut self.apartments {
+            if !apt.is_vacant() || sold.contains(&apt.id) {
+                continue;
+            }
+            if apt.vacant_since_tick.is_none() {
+                apt.vacant_since_tick = Some(current_tick);
+            }
+            if apt.vacancy_taxed(current_tick, config) {
+                taxed += 1;
+            }
+        }
+        taxed * config.vacancy_tax_per_unit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tax_applies_once_a_unit_has_been_empty_long_enough() {
+        let config = VacancyTaxConfig {
+            vacancy_tax_trigger_ticks: 3,
+            vacancy_tax_per_unit: 100,
+        };
+        let mut building = Building::new("Test", 1, 2);
+        building.apartments[0].move_in(1);
+        building.apartments[1].move_in(2);
+        building.apartments[1].move_out(5);
+
+        assert_eq!(building.vacancy_tax_due(7, &config), 0);
+        assert_eq!(building.vacancy_tax_due(8, &config), 100);
+
+        building.apartments[1].move_in(3);
+        assert_eq!(building.vacancy_tax_due(9, &config), 0);
+
+        building.apartments[1].move_out(9);
+        let sold_id = building.apartments[1].id;
+        assert!(building.convert_unit_to_condo(sold_id, "Owner", 100_000));
+        assert_eq!(building.vacancy_tax_due(20, &config), 0);
+    }
+}
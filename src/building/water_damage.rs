@@ -0,0 +1,151 @@
+use super::Building;
+use crate::data::config::WaterDamageConfig;
+use macroquad_toolkit::rng;
+
+/// Apartment flag marking a unit with unrepaired water damage.
+pub const WATER_DAMAGE_FLAG: &str = "water_damage";
+
+impl Building {
+    /// Is a leak currently working its way through the building?
+    pub fn has_water_damage(&self) -> bool {
+        self.water_damage_since.is_some()
+    }
+
+    /// Number of distinct floors with unrepaired water damage.
+    pub fn water_damaged_floors(&self) -> u32 {
+        let mut floors: Vec<u32> = self
+            .apartments
+            .iter()
+            .filter(|apt| apt.flags.contains(WATER_DAMAGE_FLAG))
+            .map(|apt| apt.floor)
+            .collect();
+        floors.sort_unstable();
+        floors.dedup();
+        floors.len() as u32
+    }
+
+    /// Start a leak in a random unit above the ground floor. Returns the unit
+    /// number, or `None` for a single-storey building.
+    pub fn start_water_damage(
+        &mut self,
+        current_tick: u32,
+        config: &WaterDamageConfig,
+    ) -> Option<String> {
+        let upper: Vec<usize> = (0..self.apartments.len())
+            .filter(|&i| self.apartments[i].floor > 1)
+            .collect();
+        if upper.is_empty() {
+            return None;
+        }
+        let apt = &mut self.apartments[upper[rng::gen_range(0, upper.len())]];
+        apt.condition = (apt.condition - config.initial_damage).max(0);
+        apt.flags.insert(WATER_DAMAGE_FLAG.to_string());
+        self.water_damage_since = Some(current_tick);
+        Some(apt.unit_number.clone())
+    }
+
+    /// Each damaged unit may leak into the one directly below it. Returns the
+    /// unit numbers newly damaged this tick.
+    pub fn spread_water_damage(&mut self, config: &WaterDamageConfig) -> Vec<String> {
+        let below: Vec<usize> = (0..self.apartments.len())
+            .filter(|&i| self.apartments[i].flags.contains(WATER_DAMAGE_FLAG))
+            .filter_map(|i| self.unit_directly_below(i))
+            .filter(|&i| !self.apartments[i].flags.contains(WATER_DAMAGE_FLAG))
+            .collect();
+
+        let mut spread = Vec::new();
+        for i in below {
+            if rng::gen_range(0, 100) >= config.spread_chance {
+                continue;
+            }
+            let apt = &mut self.apartments[i];
+            if apt.flags.insert(WATER_DAMAGE_FLAG.to_string()) {
+                apt.condition = (apt.condition - config.spread_damage).max(0);
+                spread.push(apt.unit_number.clone());
+            }
+        }
+        spread
+    }
+
+    /// True once, the tick the `repair_window` runs out with damaged units
+    /// still unrepaired. Also closes out a leak once everything is fixed.
+    pub fn water_damage_overdue(&mut self, current_tick: u32, repair_window: u32) -> bool {
+        let Some(since) = self.water_damage_since else {
+            return false;
+        };
+        if self.water_damaged_floors() == 0 {
+            self.water_damage_since = None;
+            return false;
+        }
+        if current_tick < since + repair_window {
+            return false;
+        }
+        self.water_damage_since = None;
+        true
+    }
+
+    /// The unit in the same position on the floor below, if there is one.
+    fn unit_directly_below(&self, index: usize) -> Option<usize> {
+        let apt = &self.apartments[index];
+        if apt.floor <= 1 {
+            return None;
+        }
+        let position = self.apartments[..index]
+            .iter()
+            .filter(|a| a.floor == apt.floor)
+            .count();
+        (0..self.apartments.len())
+            .filter(|&i| self.apartments[i].floor == apt.floor - 1)
+            .nth(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leak_starts_above_the_ground_floor() {
+        let mut building = Building::new("Test", 3, 2);
+        let unit = building
+            .start_water_damage(4, &WaterDamageConfig::default())
+            .expect("building has upper floors");
+
+        let apt = building
+            .apartments
+            .iter()
+            .find(|a| a.unit_number == unit)
+            .unwrap();
+        assert!(apt.floor > 1);
+        assert!(apt.flags.contains(WATER_DAMAGE_FLAG));
+        assert_eq!(building.water_damaged_floors(), 1);
+
+        assert!(Building::new("Bungalow", 1, 2)
+            .start_water_damage(4, &WaterDamageConfig::default())
+            .is_none());
+    }
+
+    #[test]
+    fn unrepaired_damage_is_overdue_once() {
+        let window = WaterDamageConfig::default().repair_window;
+        let mut building = Building::new("Test", 2, 2);
+        building.start_water_damage(10, &WaterDamageConfig::default());
+
+        assert!(!building.water_damage_overdue(12, window));
+        assert!(building.water_damage_overdue(10 + window, window));
+        assert!(!building.water_damage_overdue(20, window));
+    }
+
+    #[test]
+    fn repairing_every_unit_closes_the_leak() {
+        let window = WaterDamageConfig::default().repair_window;
+        let mut building = Building::new("Test", 2, 2);
+        building.start_water_damage(10, &WaterDamageConfig::default());
+        for apt in &mut building.apartments {
+            apt.repair(10);
+        }
+
+        assert!(!building.water_damage_overdue(10 + window, window));
+        assert!(!building.has_water_damage());
+    }
+}
@@ -4,11 +4,20 @@
 //! - `City`: The container for all neighborhoods and buildings.
 //! - `Neighborhood`: Specific districts with unique modifiers and demographics.
 //! - `Market`: The real estate market for buying new properties.
+//! - `Rivals`: Competing landlords' buildings shown on the city map.
+//! - `MarketAnalyst`: Regular rent recommendations for each neighborhood.
 
+mod analyst;
 mod city;
 mod market;
 mod neighborhood;
+mod rivals;
 
+pub use analyst::MarketAnalyst;
 pub use city::City;
-pub use market::{PropertyListing, PropertyMarket};
+pub use market::{
+    appraise_building, BuildingCondition, FinancingOption, MarketFilter, PropertyListing,
+    PropertyMarket,
+};
 pub use neighborhood::{Neighborhood, NeighborhoodType};
+pub use rivals::RivalBuilding;
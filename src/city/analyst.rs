@@ -0,0 +1,163 @@
+use super::Neighborhood;
+use crate::building::{ApartmentSize, Building};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Sizes the analyst quotes, smallest first.
+const QUOTED_SIZES: [ApartmentSize; 4] = [
+    ApartmentSize::Small,
+    ApartmentSize::Medium,
+    ApartmentSize::Large,
+    ApartmentSize::Penthouse,
+];
+/// A rent within this share of market is reported as "at market".
+const AT_MARKET_BAND: f32 = 0.05;
+
+/// Market rent for a unit of `size` where demand runs at `rent_demand`.
+pub fn market_rent(size: &ApartmentSize, rent_demand: f32) -> i32 {
+    (size.base_rent() as f32 * rent_demand).round() as i32
+}
+
+fn size_label(size: &ApartmentSize) -> &'static str {
+    match size {
+        ApartmentSize::Small => "Small",
+        ApartmentSize::Medium => "Medium",
+        ApartmentSize::Large => "Large",
+        ApartmentSize::Penthouse => "Penthouse",
+    }
+}
+
+/// A real estate analyst who sends regular rent recommendations.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MarketAnalyst {
+    pub name: String,
+    pub available_since_tick: u32,
+    /// Market rent for a medium unit, by neighborhood ID. Refreshed monthly.
+    #[serde(default)]
+    pub optimal_rent_by_neighborhood: HashMap<u32, i32>,
+}
+
+impl MarketAnalyst {
+    pub fn new(available_since_tick: u32) -> Self {
+        Self {
+            name: "Priya Raman, Raman Realty Analytics".to_string(),
+            available_since_tick,
+            optimal_rent_by_neighborhood: HashMap::new(),
+        }
+    }
+
+    /// Re-price every neighborhood from its current rent demand.
+    pub fn update(&mut self, neighborhoods: &[Neighborhood]) {
+        self.optimal_rent_by_neighborhood = neighborhoods
+            .iter()
+            .map(|n| {
+                (
+                    n.id,
+                    market_rent(&ApartmentSize::Medium, n.stats.rent_demand),
+                )
+            })
+            .collect();
+    }
+
+    /// A report goes out every `interval` months once the analyst is on board.
+    pub fn report_due(&self, current_tick: u32, interval: u32) -> bool {
+        current_tick > self.available_since_tick
+            && (current_tick - self.available_since_tick).is_multiple_of(interval.max(1))
+    }
+
+    /// The market rent for each unit in `building`, as (apartment ID, rent).
+    pub fn recommended_rents(building: &Building, rent_demand: f32) -> Vec<(u32, i32)> {
+        building
+            .apartments
+            .iter()
+            .map(|apt| (apt.id, market_rent(&apt.size, rent_demand)))
+            .collect()
+    }
+
+    /// The report body: rents by size for every neighborhood, then how the
+    /// player's own units compare.
+    pub fn report(
+        &self,
+        neighborhoods: &[Neighborhood],
+        building: &Building,
+        rent_demand: f32,
+    ) -> String {
+        let mut ranked: Vec<&Neighborhood> = neighborhoods.iter().collect();
+        ranked.sort_by_key(|n| {
+            std::cmp::Reverse(
+                self.optimal_rent_by_neighborhood
+                    .get(&n.id)
+                    .copied()
+                    .unwrap_or_default(),
+            )
+        });
+
+        let mut body = String::from("Recommended rents by neighborhood, priciest first:\n");
+        for neighborhood in ranked {
+            let quotes: Vec<String> = QUOTED_SIZES
+                .iter()
+                .map(|size| {
+                    format!(
+                        "{} ${}",
+                        size_label(size),
+                        market_rent(size, neighborhood.stats.rent_demand)
+                    )
+                })
+                .collect();
+            body.push_str(&format!("  {}: {}\n", neighborhood.name, quotes.join(", ")));
+        }
+
+        body.push_str(&format!("\nYour units at {}:\n", building.name));
+        for apt in &building.apartments {
+            let market = market_rent(&apt.size, rent_demand);
+            let gap = (apt.rent_price - market) as f32 / market.max(1) as f32;
+            let verdict = if gap > AT_MARKET_BAND {
+                "above market"
+            } else if gap < -AT_MARKET_BAND {
+                "below market"
+            } else {
+                "at market"
+            };
+            body.push_str(&format!(
+                "  Unit {}: ${} vs ${} ({})\n",
+                apt.unit_number, apt.rent_price, market, verdict
+            ));
+        }
+        body.push_str(&format!("\n{}", self.name));
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::city::NeighborhoodType;
+
+    #[test]
+    fn recommendations_follow_rent_demand() {
+        let building = Building::new("Test", 1, 2);
+        let calm = MarketAnalyst::recommended_rents(&building, 1.0);
+        let hot = MarketAnalyst::recommended_rents(&building, 1.5);
+        for ((_, calm_rent), (_, hot_rent)) in calm.iter().zip(&hot) {
+            assert!(hot_rent > calm_rent);
+        }
+
+        let mut analyst = MarketAnalyst::new(3);
+        let mut neighborhood = Neighborhood::new(7, NeighborhoodType::Downtown, "Downtown");
+        neighborhood.stats.rent_demand = 1.2;
+        analyst.update(&[neighborhood]);
+        assert_eq!(
+            analyst.optimal_rent_by_neighborhood[&7],
+            market_rent(&ApartmentSize::Medium, 1.2)
+        );
+    }
+
+    #[test]
+    fn reports_come_every_interval_after_hiring() {
+        let analyst = MarketAnalyst::new(3);
+        assert!(!analyst.report_due(3, 6));
+        assert!(!analyst.report_due(6, 6));
+        assert!(analyst.report_due(9, 6));
+        assert!(analyst.report_due(15, 6));
+    }
+}
@@ -1,5 +1,6 @@
-use super::{Neighborhood, NeighborhoodType, PropertyMarket};
+use super::{Neighborhood, NeighborhoodType, PropertyMarket, RivalBuilding};
 use crate::building::Building;
+use crate::data::config::{GameConfig, RivalsConfig};
 use macroquad_toolkit::rng;
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,9 @@ pub struct City {
     pub neighborhoods: Vec<Neighborhood>,
     pub buildings: Vec<Building>,
     pub market: PropertyMarket,
+    /// Competing landlords' buildings, at most one per neighborhood
+    #[serde(default)]
+    pub rivals: Vec<RivalBuilding>,
 
     /// Currently selected building index
     pub active_building_index: usize,
@@ -26,19 +30,21 @@ pub struct City {
 
 impl City {
     /// Create a new city with default neighborhoods
-    pub fn new(name: &str) -> Self {
+    pub fn new(name: &str, rivals_config: &RivalsConfig) -> Self {
         let neighborhoods = vec![
             Neighborhood::new(0, NeighborhoodType::Downtown, "Central District"),
             Neighborhood::new(1, NeighborhoodType::Suburbs, "Greenfield Heights"),
             Neighborhood::new(2, NeighborhoodType::Industrial, "Old Docks"),
             Neighborhood::new(3, NeighborhoodType::Historic, "Heritage Row"),
         ];
+        let rivals = RivalBuilding::generate_for(&neighborhoods, rivals_config);
 
         Self {
             name: name.to_string(),
             neighborhoods,
             buildings: Vec::new(),
             market: PropertyMarket::new(),
+            rivals,
             active_building_index: 0,
             economy_health: 1.0,
             interest_rate: 0.05,
@@ -51,7 +57,7 @@ impl City {
     /// Create a city with a starter building already assigned to a neighborhood.
     #[cfg(test)]
     pub fn with_starter_building(name: &str, neighborhood_id: u32) -> (Self, u32) {
-        let mut city = Self::new(name);
+        let mut city = Self::new(name, &RivalsConfig::default());
         let building = Building::new("Starter Building", 2, 2);
         let building_id = city
             .add_building(building, neighborhood_id)
@@ -115,6 +121,14 @@ impl City {
         Ok(building_id)
     }
 
+    /// Rival buildings located in a neighborhood
+    pub fn rivals_in(&self, neighborhood_id: u32) -> Vec<&RivalBuilding> {
+        self.rivals
+            .iter()
+            .filter(|r| r.neighborhood_id == neighborhood_id)
+            .collect()
+    }
+
     /// Get all buildings as a vector of (index, building, neighborhood_name)
     pub fn buildings_with_info(&self) -> Vec<(usize, &Building, String)> {
         self.buildings
@@ -131,7 +145,7 @@ impl City {
     }
 
     /// Monthly tick for all city systems
-    pub fn tick(&mut self) {
+    pub fn tick(&mut self, config: &GameConfig) {
         self.total_months += 1;
 
         // Update neighborhoods
@@ -139,6 +153,13 @@ impl City {
             neighborhood.tick();
         }
 
+        for rival in &mut self.rivals {
+            rival.tick(&config.rivals);
+        }
+
+        // Unsold listings slowly come down in price
+        self.market.tick(&config.economy);
+
         // Refresh market listings periodically
         if self.total_months.is_multiple_of(3) {
             self.market.refresh_listings(&self.neighborhoods);
@@ -176,9 +197,10 @@ mod tests {
 
     #[test]
     fn test_city_creation() {
-        let city = City::new("Test City");
+        let city = City::new("Test City", &RivalsConfig::default());
         assert_eq!(city.neighborhoods.len(), 4);
         assert_eq!(city.buildings.len(), 0);
+        assert_eq!(city.rivals_in(2).len(), 1);
     }
 
     #[test]
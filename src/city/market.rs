@@ -1,10 +1,12 @@
 use super::{Neighborhood, NeighborhoodType};
-use crate::building::Building;
+use crate::building::{default_construction_year, Building, ExteriorTheme, GAME_START_YEAR};
+use crate::data::config::{EconomyConfig, GameConfig};
 use macroquad_toolkit::rng;
 use serde::{Deserialize, Serialize};
 
-/// Condition of a building on the market
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+/// Condition of a building on the market (declared worst to best, so the
+/// derived ordering ranks condition).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum BuildingCondition {
     /// Needs demolition/major renovation
     Condemned,
@@ -39,6 +41,18 @@ impl BuildingCondition {
         }
     }
 
+    /// Bucket an average apartment condition (0-100) into a market category;
+    /// the inverse of `starting_apartment_condition`.
+    pub fn from_average_condition(condition: i32) -> Self {
+        match condition {
+            i32::MIN..=19 => BuildingCondition::Condemned,
+            20..=39 => BuildingCondition::Poor,
+            40..=59 => BuildingCondition::Fair,
+            60..=79 => BuildingCondition::Good,
+            _ => BuildingCondition::Excellent,
+        }
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             BuildingCondition::Condemned => "Condemned",
@@ -55,12 +69,18 @@ impl BuildingCondition {
 pub enum FinancingOption {
     /// Pay full price upfront
     Cash,
-    /// Bank mortgage with monthly payments
+    /// Bank mortgage with monthly payments; the rate follows the city economy
     Mortgage {
         down_payment_percent: f32,
         interest_rate: f32,
         term_months: u32,
     },
+    /// Mortgage whose rate is locked in at purchase
+    FixedRateMortgage {
+        down_payment_percent: f32,
+        interest_rate: f32,
+        term_months: u32,
+    },
     /// Investor partner (takes % of profits)
     Investor {
         investment_percent: f32,
@@ -68,17 +88,18 @@ pub enum FinancingOption {
     },
 }
 
-impl FinancingOption {}
-
 impl FinancingOption {
     /// Cash needed at purchase time.
-    #[cfg(test)]
     pub fn upfront_cost(&self, asking_price: i32) -> i32 {
         match self {
             FinancingOption::Cash => asking_price,
             FinancingOption::Mortgage {
                 down_payment_percent,
                 ..
+            }
+            | FinancingOption::FixedRateMortgage {
+                down_payment_percent,
+                ..
             } => (asking_price as f32 * down_payment_percent).round() as i32,
             FinancingOption::Investor {
                 investment_percent, ..
@@ -95,23 +116,27 @@ impl FinancingOption {
                 down_payment_percent,
                 interest_rate,
                 term_months,
+            }
+            | FinancingOption::FixedRateMortgage {
+                down_payment_percent,
+                interest_rate,
+                term_months,
             } => {
-                if *term_months == 0 {
-                    return 0;
-                }
-
                 let principal = asking_price as f32 * (1.0 - down_payment_percent);
-                let monthly_rate = interest_rate / 12.0;
-                if monthly_rate <= 0.0 {
-                    return (principal / *term_months as f32).round() as i32;
-                }
-
-                let months = *term_months as f32;
-                let factor = (1.0 + monthly_rate).powf(months);
-                (principal * monthly_rate * factor / (factor - 1.0)).round() as i32
+                crate::economy::amortized_payment(principal, *interest_rate, *term_months)
             }
         }
     }
+
+    /// Short label for the listing's financing buttons.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FinancingOption::Cash => "Buy",
+            FinancingOption::Mortgage { .. } => "Mortgage",
+            FinancingOption::FixedRateMortgage { .. } => "Fixed",
+            FinancingOption::Investor { .. } => "Investor",
+        }
+    }
 }
 
 /// A property listing on the market
@@ -129,6 +154,13 @@ pub struct PropertyListing {
     pub available_financing: Vec<FinancingOption>,
     /// Special features or issues
     pub notes: Vec<String>,
+    /// Earlier asking prices, oldest first (the current price is not included).
+    #[serde(default)]
+    pub price_history: Vec<i32>,
+    #[serde(default = "default_construction_year")]
+    pub construction_year: u32,
+    #[serde(default)]
+    pub exterior_theme: ExteriorTheme,
 }
 
 impl PropertyListing {
@@ -149,12 +181,7 @@ impl PropertyListing {
         };
 
         // Calculate base price
-        let base_unit_price = match neighborhood.neighborhood_type {
-            NeighborhoodType::Downtown => 80000,
-            NeighborhoodType::Suburbs => 60000,
-            NeighborhoodType::Industrial => 40000,
-            NeighborhoodType::Historic => 70000,
-        };
+        let base_unit_price = base_unit_price(&neighborhood.neighborhood_type);
 
         let asking_price = (base_unit_price as f32
             * total_units as f32
@@ -170,6 +197,11 @@ impl PropertyListing {
 
         // Generate name
         let name = generate_building_name(&neighborhood.neighborhood_type);
+        let construction_year = random_construction_year(&neighborhood.neighborhood_type);
+        let exterior_theme = match neighborhood.neighborhood_type {
+            NeighborhoodType::Historic => ExteriorTheme::Victorian,
+            _ => ExteriorTheme::Classic,
+        };
 
         // Available financing based on price
         let mut financing = vec![FinancingOption::Cash];
@@ -179,6 +211,11 @@ impl PropertyListing {
                 interest_rate: 0.06,
                 term_months: 120,
             });
+            financing.push(FinancingOption::FixedRateMortgage {
+                down_payment_percent: 0.2,
+                interest_rate: 0.07,
+                term_months: 120,
+            });
         }
         if asking_price > 100000 {
             financing.push(FinancingOption::Investor {
@@ -201,6 +238,7 @@ impl PropertyListing {
         if matches!(neighborhood.neighborhood_type, NeighborhoodType::Historic) {
             notes.push("🏛️ Historic preservation restrictions apply".to_string());
         }
+        notes.push(format!("🏗️ Built {}", construction_year));
         if neighborhood.stats.gentrification > 70 {
             notes.push("📈 Area rapidly gentrifying".to_string());
         }
@@ -217,6 +255,9 @@ impl PropertyListing {
             months_on_market: 0,
             available_financing: financing,
             notes,
+            price_history: Vec::new(),
+            construction_year,
+            exterior_theme,
         }
     }
 
@@ -231,6 +272,9 @@ impl PropertyListing {
             apt.condition = apt.condition.clamp(0, 100);
         }
         building.hallway_condition = target_condition;
+        building.construction_year = self.construction_year;
+        building.exterior_theme = self.exterior_theme;
+        building.renovation_required = self.condition == BuildingCondition::Condemned;
 
         building
     }
@@ -239,6 +283,158 @@ impl PropertyListing {
     pub fn total_units(&self) -> u32 {
         self.num_floors * self.units_per_floor
     }
+
+    /// Age the listing a month. Every `listing_price_cut_interval` months
+    /// unsold, the seller cuts the asking price; the old price is kept in
+    /// `price_history`.
+    pub fn tick(&mut self, config: &EconomyConfig) {
+        self.months_on_market += 1;
+        if self
+            .months_on_market
+            .is_multiple_of(config.listing_price_cut_interval.max(1))
+        {
+            self.price_history.push(self.asking_price);
+            self.asking_price = (self.asking_price as f32 * config.listing_price_cut_factor) as i32;
+        }
+    }
+
+    /// Percent change from the original asking price (negative = cheaper).
+    pub fn percent_change_since_listed(&self) -> f32 {
+        match self.price_history.first() {
+            Some(&original) if original > 0 => {
+                (self.asking_price - original) as f32 / original as f32 * 100.0
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Year a listed building went up. Historic-quarter stock is 60+ years old;
+/// industrial conversions are older than downtown and suburban builds.
+fn random_construction_year(neighborhood_type: &NeighborhoodType) -> u32 {
+    let age = match neighborhood_type {
+        NeighborhoodType::Historic => rng::gen_range(60, 110),
+        NeighborhoodType::Industrial => rng::gen_range(30, 70),
+        NeighborhoodType::Downtown => rng::gen_range(5, 45),
+        NeighborhoodType::Suburbs => rng::gen_range(5, 35),
+    };
+    GAME_START_YEAR - age
+}
+
+/// Market value of a single unit before condition and demand adjustments.
+fn base_unit_price(neighborhood_type: &NeighborhoodType) -> i32 {
+    match neighborhood_type {
+        NeighborhoodType::Downtown => 80000,
+        NeighborhoodType::Suburbs => 60000,
+        NeighborhoodType::Industrial => 40000,
+        NeighborhoodType::Historic => 70000,
+    }
+}
+
+/// Current market value of an owned building, priced the same way as a
+/// market listing (per-unit base × condition × local demand) and then
+/// nudged by the building's appeal.
+pub fn appraise_building(
+    building: &Building,
+    neighborhood: &Neighborhood,
+    config: &GameConfig,
+) -> i32 {
+    if building.apartments.is_empty() {
+        return 0;
+    }
+    let avg_condition = building.apartments.iter().map(|a| a.condition).sum::<i32>()
+        / building.apartments.len() as i32;
+    let condition = BuildingCondition::from_average_condition(avg_condition);
+    let appeal_modifier = 1.0
+        + (building.building_appeal() - 50) as f32 / 100.0 * config.economy.appraisal_appeal_weight;
+
+    (base_unit_price(&neighborhood.neighborhood_type) as f32
+        * building.apartments.len() as f32
+        * condition.price_multiplier()
+        * neighborhood.stats.rent_demand
+        * appeal_modifier) as i32
+}
+
+/// How the market panel orders listings.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MarketSort {
+    #[default]
+    PriceAscending,
+    ConditionDescending,
+    UnitsDescending,
+}
+
+impl MarketSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            MarketSort::PriceAscending => "Price ↑",
+            MarketSort::ConditionDescending => "Condition ↓",
+            MarketSort::UnitsDescending => "Units ↓",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            MarketSort::PriceAscending => MarketSort::ConditionDescending,
+            MarketSort::ConditionDescending => MarketSort::UnitsDescending,
+            MarketSort::UnitsDescending => MarketSort::PriceAscending,
+        }
+    }
+}
+
+/// Player-chosen filter and sort for the property market panel.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarketFilter {
+    pub max_price: Option<i32>,
+    pub neighborhood_type: Option<NeighborhoodType>,
+    pub min_condition: Option<BuildingCondition>,
+    pub sort: MarketSort,
+}
+
+impl MarketFilter {
+    /// Whether a listing passes every active filter.
+    pub fn matches(&self, listing: &PropertyListing, neighborhoods: &[Neighborhood]) -> bool {
+        if self.max_price.is_some_and(|max| listing.asking_price > max) {
+            return false;
+        }
+        if self
+            .min_condition
+            .as_ref()
+            .is_some_and(|min| listing.condition < *min)
+        {
+            return false;
+        }
+        if let Some(wanted) = &self.neighborhood_type {
+            let listing_type = neighborhoods
+                .iter()
+                .find(|n| n.id == listing.neighborhood_id)
+                .map(|n| &n.neighborhood_type);
+            if listing_type != Some(wanted) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// The listings that pass the filter, in the chosen sort order.
+    pub fn apply<'a>(
+        &self,
+        listings: &'a [PropertyListing],
+        neighborhoods: &[Neighborhood],
+    ) -> Vec<&'a PropertyListing> {
+        let mut shown: Vec<&PropertyListing> = listings
+            .iter()
+            .filter(|listing| self.matches(listing, neighborhoods))
+            .collect();
+        match self.sort {
+            MarketSort::PriceAscending => shown.sort_by_key(|l| l.asking_price),
+            MarketSort::ConditionDescending => shown.sort_by(|a, b| b.condition.cmp(&a.condition)),
+            MarketSort::UnitsDescending => {
+                shown.sort_by_key(|l| std::cmp::Reverse(l.total_units()))
+            }
+        }
+        shown
+    }
 }
 
 /// Property market managing available listings
@@ -256,6 +452,13 @@ impl PropertyMarket {
         }
     }
 
+    /// Age every listing by a month.
+    pub fn tick(&mut self, config: &EconomyConfig) {
+        for listing in &mut self.listings {
+            listing.tick(config);
+        }
+    }
+
     /// Generate new listings based on neighborhoods
     pub fn refresh_listings(&mut self, neighborhoods: &[Neighborhood]) {
         // Add 1-2 new listings per refresh
@@ -350,4 +553,84 @@ mod tests {
         let monthly = mortgage.monthly_payment(100000);
         assert!(monthly > 0 && monthly < 2000); // Reasonable range
     }
+
+    #[test]
+    fn appraisal_rises_with_condition() {
+        let neighborhood = Neighborhood::new(0, NeighborhoodType::Downtown, "Test");
+        let config = GameConfig::default();
+        let mut building = Building::new("Test", 2, 2);
+        for apt in &mut building.apartments {
+            apt.condition = 30;
+        }
+        let rundown = appraise_building(&building, &neighborhood, &config);
+        for apt in &mut building.apartments {
+            apt.condition = 90;
+        }
+        building.hallway_condition = 90;
+        let renovated = appraise_building(&building, &neighborhood, &config);
+
+        assert!(rundown > 0);
+        assert!(renovated > rundown);
+    }
+
+    #[test]
+    fn listing_price_drops_every_two_months_and_records_history() {
+        let neighborhood = Neighborhood::new(0, NeighborhoodType::Downtown, "Test");
+        let mut listing = PropertyListing::generate(0, &neighborhood);
+        listing.asking_price = 100_000;
+        let config = GameConfig::default();
+
+        listing.tick(&config.economy);
+        assert!(listing.price_history.is_empty());
+        listing.tick(&config.economy);
+        assert_eq!(listing.price_history, vec![100_000]);
+        assert_eq!(listing.asking_price, 98_000);
+        assert!((listing.percent_change_since_listed() + 2.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn market_filter_drops_and_sorts_listings() {
+        let neighborhoods = vec![
+            Neighborhood::new(0, NeighborhoodType::Downtown, "A"),
+            Neighborhood::new(1, NeighborhoodType::Suburbs, "B"),
+        ];
+        let mut cheap = PropertyListing::generate(0, &neighborhoods[0]);
+        cheap.asking_price = 50_000;
+        cheap.condition = BuildingCondition::Poor;
+        let mut pricey = PropertyListing::generate(1, &neighborhoods[0]);
+        pricey.asking_price = 400_000;
+        pricey.condition = BuildingCondition::Good;
+        let mut suburban = PropertyListing::generate(2, &neighborhoods[1]);
+        suburban.asking_price = 90_000;
+        suburban.condition = BuildingCondition::Excellent;
+        let listings = vec![pricey, suburban, cheap];
+
+        let by_price = MarketFilter::default().apply(&listings, &neighborhoods);
+        let ids: Vec<u32> = by_price.iter().map(|l| l.id).collect();
+        assert_eq!(ids, vec![0, 2, 1]);
+
+        let filter = MarketFilter {
+            max_price: Some(100_000),
+            neighborhood_type: Some(NeighborhoodType::Downtown),
+            ..MarketFilter::default()
+        };
+        let ids: Vec<u32> = filter
+            .apply(&listings, &neighborhoods)
+            .iter()
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(ids, vec![0]);
+
+        let filter = MarketFilter {
+            min_condition: Some(BuildingCondition::Good),
+            sort: MarketSort::ConditionDescending,
+            ..MarketFilter::default()
+        };
+        let ids: Vec<u32> = filter
+            .apply(&listings, &neighborhoods)
+            .iter()
+            .map(|l| l.id)
+            .collect();
+        assert_eq!(ids, vec![2, 1]);
+    }
 }
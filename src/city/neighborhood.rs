@@ -47,8 +47,10 @@ pub struct NeighborhoodStats {
     pub transit_access: i32,
     /// Walkability score (0-100)
     pub walkability: i32,
-    /// School quality (0-100) - matters for families
-    pub school_quality: i32,
+    /// School rating (0-100) - above 70 draws Family applicants, below 50
+    /// drives them away
+    #[serde(default = "default_school_rating")]
+    pub school_rating: i32,
     /// Local services (shops, cafes, etc.) (0-100)
     pub services: i32,
     /// Current rent demand (affects application rate)
@@ -57,6 +59,10 @@ pub struct NeighborhoodStats {
     pub gentrification: i32,
 }
 
+fn default_school_rating() -> i32 {
+    50
+}
+
 impl NeighborhoodStats {
     pub fn for_type(neighborhood_type: &NeighborhoodType) -> Self {
         // Load config (lazy/cached would be better but this is only called at startup)
@@ -78,7 +84,7 @@ impl NeighborhoodStats {
                     crime_level: 40,
                     transit_access: 95,
                     walkability: 90,
-                    school_quality: 50,
+                    school_rating: 50,
                     services: 95,
                     rent_demand: 1.2,
                     gentrification: 80,
@@ -87,7 +93,7 @@ impl NeighborhoodStats {
                     crime_level: 15,
                     transit_access: 40,
                     walkability: 30,
-                    school_quality: 85,
+                    school_rating: 85,
                     services: 60,
                     rent_demand: 1.0,
                     gentrification: 20,
@@ -96,7 +102,7 @@ impl NeighborhoodStats {
                     crime_level: 50,
                     transit_access: 60,
                     walkability: 50,
-                    school_quality: 35,
+                    school_rating: 35,
                     services: 45,
                     rent_demand: 0.9,
                     gentrification: 60,
@@ -105,7 +111,7 @@ impl NeighborhoodStats {
                     crime_level: 25,
                     transit_access: 70,
                     walkability: 75,
-                    school_quality: 65,
+                    school_rating: 65,
                     services: 80,
                     rent_demand: 1.1,
                     gentrification: 40,
@@ -114,6 +120,21 @@ impl NeighborhoodStats {
         }
     }
 
+    /// Reputation lost to local crime (`-crime_level / 5`).
+    pub fn crime_penalty(&self) -> i32 {
+        -self.crime_level / 5
+    }
+
+    /// Reputation gained from good transit access.
+    pub fn transit_bonus(&self) -> i32 {
+        self.transit_access / 10
+    }
+
+    /// Reputation gained from an up-and-coming area's buzz.
+    pub fn gentrification_contribution(&self) -> i32 {
+        self.gentrification / 20
+    }
+
     /// Apply monthly changes to neighborhood (gentrification, crime changes, etc.)
     pub fn tick(&mut self, neighborhood_type: &NeighborhoodType) {
         // Gentrification slowly increases in industrial areas
@@ -147,7 +168,8 @@ pub struct Neighborhood {
     pub building_ids: Vec<u32>,
     /// Number of available property slots
     pub available_slots: u32,
-    /// Player's reputation in this neighborhood (0-100)
+    /// Player's standing in this neighborhood (0-100), earned through their
+    /// choices; local conditions are added on top by `effective_reputation`
     pub reputation: i32,
 }
 
@@ -182,6 +204,22 @@ impl Neighborhood {
         matches!(self.neighborhood_type, NeighborhoodType::Historic)
     }
 
+    /// Reputation gained from the landlord's visible presence (+2 per building).
+    pub fn building_count_modifier(&self) -> i32 {
+        self.building_ids.len() as i32 * 2
+    }
+
+    /// Reputation as applicants and the city see it (0-100): the player's
+    /// standing plus crime, transit, gentrification and presence.
+    pub fn effective_reputation(&self) -> i32 {
+        (self.reputation
+            + self.stats.crime_penalty()
+            + self.stats.transit_bonus()
+            + self.stats.gentrification_contribution()
+            + self.building_count_modifier())
+        .clamp(0, 100)
+    }
+
     /// Apply monthly tick
     pub fn tick(&mut self) {
         self.stats.tick(&self.neighborhood_type);
@@ -217,4 +255,24 @@ mod tests {
         // Allow for config values
         assert!(stats.crime_level <= 50);
     }
+
+    #[test]
+    fn reputation_breakdown_sums_to_reputation() {
+        let mut neighborhood = Neighborhood::new(0, NeighborhoodType::Industrial, "Docks");
+        neighborhood.stats.crime_level = 50;
+        neighborhood.add_building(0);
+        assert_eq!(neighborhood.stats.crime_penalty(), -10);
+        assert_eq!(neighborhood.building_count_modifier(), 2);
+
+        let total = neighborhood.reputation
+            + neighborhood.stats.crime_penalty()
+            + neighborhood.stats.transit_bonus()
+            + neighborhood.stats.gentrification_contribution()
+            + neighborhood.building_count_modifier();
+        assert_eq!(total.clamp(0, 100), neighborhood.effective_reputation());
+
+        neighborhood.reputation = 0;
+        neighborhood.stats.crime_level = 100;
+        assert_eq!(neighborhood.effective_reputation(), 0);
+    }
 }
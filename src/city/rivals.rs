@@ -0,0 +1,110 @@
+use super::Neighborhood;
+use crate::data::config::RivalsConfig;
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+/// The competing landlords and their building names, as authored in
+/// `assets/rivals.json`.
+#[derive(Clone, Debug, Deserialize, Default)]
+struct RivalRoster {
+    #[serde(default)]
+    owners: Vec<String>,
+    #[serde(default)]
+    building_suffixes: Vec<String>,
+}
+
+/// A competing landlord's building. Rivals aren't simulated unit-by-unit;
+/// they carry just enough (appeal, rent) to compare against on the map.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RivalBuilding {
+    pub name: String,
+    pub owner: String,
+    pub neighborhood_id: u32,
+    /// Building appeal on the same 0-100 scale as `Building::building_appeal`.
+    pub appeal: i32,
+    pub average_rent: i32,
+}
+
+impl RivalBuilding {
+    /// Seed one rival building per neighborhood, priced off local demand.
+    pub fn generate_for(neighborhoods: &[Neighborhood], config: &RivalsConfig) -> Vec<Self> {
+        let roster = load_rival_roster();
+
+        neighborhoods
+            .iter()
+            .map(|neighborhood| {
+                let owner = rng::choose(&roster.owners)
+                    .map(String::as_str)
+                    .unwrap_or("Rival Holdings");
+                let suffix = rng::choose(&roster.building_suffixes)
+                    .map(String::as_str)
+                    .unwrap_or("Tower");
+                let prefix = owner.split_whitespace().next().unwrap_or("Rival");
+                Self {
+                    name: format!("{} {}", prefix, suffix),
+                    owner: owner.to_string(),
+                    neighborhood_id: neighborhood.id,
+                    appeal: rng::gen_range(
+                        config.starting_appeal_min,
+                        config.starting_appeal_max + 1,
+                    ),
+                    average_rent: (rng::gen_range(
+                        config.starting_rent_min,
+                        config.starting_rent_max + 1,
+                    ) as f32
+                        * neighborhood.stats.rent_demand) as i32,
+                }
+            })
+            .collect()
+    }
+
+    /// React to press about a player building in the same neighborhood:
+    /// bad press is a chance to poach tenants with a marketing push, good
+    /// press forces a rent cut to stay competitive.
+    pub fn react_to_press(&mut self, appeal_change: i32, config: &RivalsConfig) {
+        if appeal_change < 0 {
+            self.appeal = (self.appeal - appeal_change).clamp(config.min_appeal, 100);
+        } else {
+            let cut = 1.0 - config.press_rent_cut_per_appeal * appeal_change as f32;
+            self.average_rent = ((self.average_rent as f32 * cut) as i32).max(config.min_rent);
+        }
+    }
+
+    /// Monthly drift: rivals slowly renovate or neglect, and follow the market.
+    pub fn tick(&mut self, config: &RivalsConfig) {
+        let drift = config.monthly_appeal_drift;
+        self.appeal =
+            (self.appeal + rng::gen_range(-drift, drift + 1)).clamp(config.min_appeal, 100);
+        self.average_rent = (self.average_rent
+            + rng::gen_range(-config.monthly_rent_drop, config.monthly_rent_rise + 1))
+        .max(config.min_rent);
+    }
+}
+
+fn load_rival_roster() -> RivalRoster {
+    #[cfg(target_arch = "wasm32")]
+    let json = include_str!("../../assets/rivals.json").to_string();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let json = std::fs::read_to_string("assets/rivals.json")
+        .unwrap_or_else(|_| include_str!("../../assets/rivals.json").to_string());
+
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        eprintln!("Failed to parse rivals.json: {}", e);
+        RivalRoster::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rival_roster_loads_without_the_tutorial_rival() {
+        let roster = load_rival_roster();
+        assert!(!roster.owners.is_empty());
+        assert!(!roster.building_suffixes.is_empty());
+        // Magnuson Corp is the tutorial's named rival, not a map landlord.
+        assert!(!roster.owners.iter().any(|owner| owner == "Magnuson Corp"));
+    }
+}
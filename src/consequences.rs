@@ -4,12 +4,63 @@
 //! - `Relationships`: Tenant-tenant and tenant-landlord social networks.
 //! - `Regulations`: City ordinances and compliance checks.
 //! - `Gentrification`: Tracking neighborhood change over time.
+//! - `RentStrike`: Union strikes and the demands that end them.
+//! - Voucher program: fines for turning away housing voucher holders.
+//! - Energy mandate: a city-required upgrade with a deadline.
+//! - Affordable mandate: a minimum share of units at affordable rents.
+//! - `ComplaintLog`: tenant complaints that force an inspection.
+//! - Fire safety: monthly wear, the annual check, and fires.
+//! - `EvictionReason`: the documented causes a just-cause law accepts.
+//! - `RepairDeadline`: health-cited units that must be repaired in time.
+//! - `PendingPermit`: building permits filed for major renovations.
+//! - Landlord license: annual renewal, lapsing if the building isn't in order.
+//! - Fair housing: spotting rejections that single out one kind of tenant.
+//! - `PendingRentIncrease`: large rent increases held for a notice period.
+//! - First refusal: long-term tenants' right to buy before a building is sold.
+//! - `BuildingInspector`: scheduled visits that grade the building A to F.
+//! - `PropertyAssessment`: yearly reassessment that scales property tax.
+//! - `ChallengedAssessment`: special assessment bills contested at a hearing.
+//! - Building sale: dropping a sold building's records and re-indexing the rest.
 
+mod affordable_mandate;
+mod building_sale;
+mod complaints;
+mod energy_mandate;
+mod fair_housing;
+mod fire_safety;
+mod first_refusal;
 mod gentrification;
+mod inspector;
+mod just_cause;
+mod landlord_license;
+mod permits;
 mod regulations;
+mod relationship_cameras;
+mod relationship_city;
 mod relationship_dilemma;
+mod relationship_vote;
 mod relationships;
+mod rent_notice;
+mod rent_strike;
+mod repair_deadlines;
+mod special_assessment;
+mod tax_assessment;
+mod voucher_program;
 
+pub use building_sale::building_id_after_sale;
+pub use complaints::ComplaintLog;
+pub use fair_housing::{ApplicationDecision, ApplicationDecisionLog, FairHousingRecord};
+pub use fire_safety::FireSafetyOutcome;
+pub use first_refusal::ContestedSale;
 pub use gentrification::GentrificationTracker;
+pub use inspector::{BuildingInspector, InspectionReport};
+pub use just_cause::EvictionReason;
+pub use permits::{PendingPermit, PermitQueue, PermitType};
 pub use regulations::{ComplianceSystem, InspectionTrigger};
+pub use relationship_cameras::SECURITY_CAMERAS_FLAG;
 pub use relationships::{RelationshipType, TenantNetwork};
+pub use rent_notice::{PendingRentIncrease, RentNoticeRules};
+pub use rent_strike::{RentStrike, StrikeDemand};
+pub use repair_deadlines::RepairDeadline;
+pub use special_assessment::{ChallengedAssessment, FinancedAssessment, SpecialAssessments};
+pub use tax_assessment::{AssessmentRoll, PropertyAssessment};
@@ -0,0 +1,95 @@
+//! City affordable unit mandate: once enacted, a share of every building's
+//! units must rent at or below the affordable threshold, and each unit short
+//! is fined monthly.
+
+use super::ComplianceSystem;
+use crate::building::Apartment;
+use crate::data::config::{GentrificationConfig, RegulationsConfig};
+
+impl ComplianceSystem {
+    pub fn enact_affordable_mandate(&mut self, config: &GentrificationConfig) {
+        self.required_affordable_percent = config.affordable_mandate_percent;
+        self.affordable_rent_threshold = config.affordable_threshold;
+    }
+
+    pub fn affordable_mandate_active(&self) -> bool {
+        self.required_affordable_percent > 0.0
+    }
+
+    /// Affordable units and the number the mandate requires, or `None` while
+    /// the mandate isn't in force.
+    pub fn affordable_mandate_status(&self, apartments: &[Apartment]) -> Option<(u32, u32)> {
+        if !self.affordable_mandate_active() {
+            return None;
+        }
+        let affordable = apartments
+            .iter()
+            .filter(|a| a.rent_price <= self.affordable_rent_threshold)
+            .count() as u32;
+        let required = (apartments.len() as f32 * self.required_affordable_percent).ceil() as u32;
+        Some((affordable, required))
+    }
+
+    /// Cite the building if it falls short. The fine scales with the number
+    /// of units missing and is billed with the other unpaid fines.
+    pub fn cite_affordable_shortfall(
+        &mut self,
+        apartments: &[Apartment],
+        config: &RegulationsConfig,
+    ) -> i32 {
+        let Some((affordable, required)) = self.affordable_mandate_status(apartments) else {
+            return 0;
+        };
+        let shortfall = required.saturating_sub(affordable) as i32;
+        if shortfall == 0 {
+            return 0;
+        }
+        let fine = shortfall * config.affordable_mandate_fine_per_unit;
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        fine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::building::Building;
+
+    #[test]
+    fn fine_scales_with_the_shortfall() {
+        let regulations = RegulationsConfig::default();
+        let gentrification = GentrificationConfig {
+            affordable_mandate_percent: 0.5,
+            affordable_threshold: 700,
+            ..GentrificationConfig::default()
+        };
+        let mut building = Building::new("Test", 2, 2);
+        for apt in &mut building.apartments {
+            apt.rent_price = 1000;
+        }
+        let mut compliance = ComplianceSystem::new();
+        assert_eq!(
+            compliance.cite_affordable_shortfall(&building.apartments, &regulations),
+            0
+        );
+
+        compliance.enact_affordable_mandate(&gentrification);
+        assert_eq!(
+            compliance.affordable_mandate_status(&building.apartments),
+            Some((0, 2))
+        );
+        let per_unit = regulations.affordable_mandate_fine_per_unit;
+        assert_eq!(
+            compliance.cite_affordable_shortfall(&building.apartments, &regulations),
+            2 * per_unit
+        );
+
+        building.apartments[0].rent_price = 600;
+        assert_eq!(
+            compliance.cite_affordable_shortfall(&building.apartments, &regulations),
+            per_unit
+        );
+    }
+}
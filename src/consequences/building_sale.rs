@@ -0,0 +1,106 @@
+//! Forgetting a sold building. Compliance records refer to buildings by their
+//! index in `city.buildings`, so when one is sold its records are dropped and
+//! those of every later building move down one place.
+
+use std::collections::HashMap;
+
+use super::ComplianceSystem;
+
+/// Where `building_id` sits once building `sold` has left the portfolio:
+/// `None` for the sold building itself, one place down for those after it.
+pub fn building_id_after_sale(building_id: u32, sold: u32) -> Option<u32> {
+    match building_id.cmp(&sold) {
+        std::cmp::Ordering::Less => Some(building_id),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some(building_id - 1),
+    }
+}
+
+/// Re-key a map by building ID after building `sold` has gone.
+fn rekey<V>(map: &mut HashMap<u32, V>, sold: u32) {
+    *map = std::mem::take(map)
+        .into_iter()
+        .filter_map(|(id, value)| building_id_after_sale(id, sold).map(|id| (id, value)))
+        .collect();
+}
+
+/// Drop the entries of a list that belong to building `sold` and move the
+/// later buildings' entries down one place.
+fn shift<T>(items: &mut Vec<T>, sold: u32, building_id: impl Fn(&mut T) -> &mut u32) {
+    items.retain_mut(|item| {
+        let id = building_id(item);
+        match building_id_after_sale(*id, sold) {
+            Some(new_id) => {
+                *id = new_id;
+                true
+            }
+            None => false,
+        }
+    });
+}
+
+impl ComplianceSystem {
+    /// Drop every record of building `sold` and re-index the buildings after it.
+    pub fn forget_building(&mut self, sold: u32) {
+        rekey(&mut self.building_regulations, sold);
+        rekey(&mut self.fire_safety_scores, sold);
+        rekey(&mut self.complaints.complaint_counts, sold);
+        rekey(&mut self.complaints.complaint_inspections, sold);
+        rekey(&mut self.tax_assessments.property_assessments, sold);
+        self.ada_required = self
+            .ada_required
+            .iter()
+            .filter_map(|&id| building_id_after_sale(id, sold))
+            .collect();
+
+        shift(&mut self.inspection_history, sold, |i| &mut i.building_id);
+        shift(&mut self.pending_fixes, sold, |fix| &mut fix.0);
+        shift(&mut self.repair_deadlines, sold, |d| &mut d.building_id);
+        shift(&mut self.permits.pending_permits, sold, |p| {
+            &mut p.building_id
+        });
+        shift(&mut self.rent_notice.pending_rent_increases, sold, |r| {
+            &mut r.building_id
+        });
+        shift(&mut self.contested_sales, sold, |s| &mut s.building_id);
+
+        if self.energy_mandate_deadline.is_some() {
+            match building_id_after_sale(self.energy_mandate_building_id, sold) {
+                Some(id) => self.energy_mandate_building_id = id,
+                None => {
+                    self.energy_mandate_deadline = None;
+                    self.energy_mandate_required_upgrade.clear();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sold_building_records_go_and_later_ones_move_down() {
+        let mut compliance = ComplianceSystem::new();
+        compliance.fire_safety_scores.insert(0, 90);
+        compliance.fire_safety_scores.insert(1, 40);
+        compliance.fire_safety_scores.insert(2, 70);
+        let config = crate::data::config::RegulationsConfig::default();
+        compliance.contest_sale(1, 100_000, 0, &config);
+        compliance.contest_sale(2, 80_000, 0, &config);
+        compliance.issue_energy_mandate(2, 0, "solar_panels", 12);
+
+        compliance.forget_building(1);
+
+        assert_eq!(compliance.fire_safety_scores.get(&0), Some(&90));
+        assert_eq!(compliance.fire_safety_scores.get(&1), Some(&70));
+        assert_eq!(compliance.fire_safety_scores.len(), 2);
+        assert_eq!(compliance.contested_sales.len(), 1);
+        assert_eq!(compliance.contested_sales[0].building_id, 1);
+        assert_eq!(compliance.energy_mandate_building_id, 1);
+
+        compliance.forget_building(1);
+        assert!(compliance.energy_mandate_deadline.is_none());
+    }
+}
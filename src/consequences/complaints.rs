@@ -0,0 +1,99 @@
+//! Tenant complaints: counted per unit since the building's last inspection,
+//! and enough of them against one unit forces an inspection the next month.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::ComplianceSystem;
+
+/// Complaint counts and the inspections they've forced.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ComplaintLog {
+    /// Tenant complaints since the last inspection: building ID ->
+    /// apartment ID -> count
+    #[serde(default)]
+    pub complaint_counts: HashMap<u32, HashMap<u32, u32>>,
+    /// Inspections forced by complaints: building ID -> month they're due
+    #[serde(default)]
+    pub complaint_inspections: HashMap<u32, u32>,
+}
+
+impl ComplianceSystem {
+    /// Count a tenant complaint against an apartment. Returns true when it
+    /// pushes the apartment to `threshold` and schedules an inspection of the
+    /// building for next month (unless one is already pending).
+    pub fn record_complaint(
+        &mut self,
+        building_id: u32,
+        apartment_id: u32,
+        threshold: u32,
+        current_month: u32,
+    ) -> bool {
+        let count = self
+            .complaints
+            .complaint_counts
+            .entry(building_id)
+            .or_default()
+            .entry(apartment_id)
+            .or_insert(0);
+        *count += 1;
+        if *count < threshold
+            || self
+                .complaints
+                .complaint_inspections
+                .contains_key(&building_id)
+        {
+            return false;
+        }
+        self.complaints
+            .complaint_inspections
+            .insert(building_id, current_month + 1);
+        true
+    }
+
+    /// Take a complaint-driven inspection that has come due for the building.
+    pub fn take_complaint_inspection(&mut self, building_id: u32, current_month: u32) -> bool {
+        if self
+            .complaints
+            .complaint_inspections
+            .get(&building_id)
+            .is_some_and(|due| current_month >= *due)
+        {
+            self.complaints.complaint_inspections.remove(&building_id);
+            return true;
+        }
+        false
+    }
+
+    /// An inspection of the building has been and gone; its complaints
+    /// start counting afresh.
+    pub fn reset_complaints(&mut self, building_id: u32) {
+        self.complaints.complaint_counts.remove(&building_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_complaints_schedule_one_inspection() {
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+
+        assert!(!system.record_complaint(0, 4, 3, 10));
+        assert!(!system.record_complaint(0, 4, 3, 10));
+        assert!(system.record_complaint(0, 4, 3, 10));
+        assert!(!system.record_complaint(0, 4, 3, 10));
+
+        assert!(!system.take_complaint_inspection(0, 10));
+        assert!(system.take_complaint_inspection(0, 11));
+        assert!(!system.take_complaint_inspection(0, 12));
+
+        // Unit 4 of another building keeps its own count.
+        assert!(!system.record_complaint(1, 4, 3, 12));
+        system.reset_complaints(0);
+        assert!(!system.complaints.complaint_counts.contains_key(&0));
+        assert_eq!(system.complaints.complaint_counts[&1][&4], 1);
+    }
+}
@@ -0,0 +1,62 @@
+//! City energy efficiency mandate: a required upgrade for one building with a
+//! deadline, then a fine every month until that building complies.
+
+use super::ComplianceSystem;
+
+impl ComplianceSystem {
+    /// Require `upgrade` (a building flag) of `building_id` within `months`.
+    /// Returns the deadline.
+    pub fn issue_energy_mandate(
+        &mut self,
+        building_id: u32,
+        current_month: u32,
+        upgrade: &str,
+        months: u32,
+    ) -> u32 {
+        let deadline = current_month + months;
+        self.energy_mandate_building_id = building_id;
+        self.energy_mandate_deadline = Some(deadline);
+        self.energy_mandate_required_upgrade = upgrade.to_string();
+        deadline
+    }
+
+    /// Months `building_id` has left to comply (negative once overdue), or
+    /// `None` without a mandate in force on it.
+    pub fn energy_mandate_months_left(&self, building_id: u32, current_month: u32) -> Option<i64> {
+        self.energy_mandate_deadline
+            .filter(|_| self.energy_mandate_building_id == building_id)
+            .map(|deadline| deadline as i64 - current_month as i64)
+    }
+
+    /// Past the deadline: the monthly fine applies.
+    pub fn energy_mandate_overdue(&self, current_month: u32) -> bool {
+        self.energy_mandate_deadline
+            .is_some_and(|deadline| current_month > deadline)
+    }
+
+    pub fn clear_energy_mandate(&mut self) {
+        self.energy_mandate_deadline = None;
+        self.energy_mandate_required_upgrade.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mandate_is_overdue_only_after_its_deadline() {
+        let mut compliance = ComplianceSystem::new();
+        assert!(!compliance.energy_mandate_overdue(50));
+
+        let deadline = compliance.issue_energy_mandate(1, 10, "hvac", 12);
+        assert_eq!(deadline, 22);
+        assert_eq!(compliance.energy_mandate_months_left(1, 20), Some(2));
+        assert_eq!(compliance.energy_mandate_months_left(0, 20), None);
+        assert!(!compliance.energy_mandate_overdue(22));
+        assert!(compliance.energy_mandate_overdue(23));
+
+        compliance.clear_energy_mandate();
+        assert_eq!(compliance.energy_mandate_months_left(1, 23), None);
+    }
+}
@@ -0,0 +1,186 @@
+//! Fair housing: a rolling log of application decisions, checked for a
+//! landlord who keeps turning away one kind of tenant. A suspicious pattern
+//! opens an investigation, after which the next qualified applicant of that
+//! archetype has to be accepted.
+
+use serde::{Deserialize, Serialize};
+
+use super::ComplianceSystem;
+use crate::data::config::RegulationsConfig;
+use crate::tenant::TenantArchetype;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ApplicationDecision {
+    Accepted,
+    Rejected,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApplicationDecisionLog {
+    pub tick: u32,
+    pub apartment_id: u32,
+    pub archetype: TenantArchetype,
+    pub decision: ApplicationDecision,
+}
+
+/// Recent decisions and any investigation they've opened.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FairHousingRecord {
+    /// Recent application decisions, checked for discriminatory patterns
+    #[serde(default)]
+    pub application_decisions: Vec<ApplicationDecisionLog>,
+    /// Archetype a fair housing investigation requires be accepted next
+    #[serde(default)]
+    pub fair_housing_required_archetype: Option<TenantArchetype>,
+}
+
+impl ComplianceSystem {
+    /// Record an accept or reject, keeping only the most recent decisions.
+    /// Accepting the archetype an investigation requires satisfies it.
+    pub fn log_application_decision(
+        &mut self,
+        tick: u32,
+        apartment_id: u32,
+        archetype: &TenantArchetype,
+        decision: ApplicationDecision,
+        config: &RegulationsConfig,
+    ) {
+        if decision == ApplicationDecision::Accepted
+            && self.fair_housing.fair_housing_required_archetype.as_ref() == Some(archetype)
+        {
+            self.fair_housing.fair_housing_required_archetype = None;
+        }
+        self.fair_housing
+            .application_decisions
+            .push(ApplicationDecisionLog {
+                tick,
+                apartment_id,
+                archetype: archetype.clone(),
+                decision,
+            });
+        let excess = self
+            .fair_housing
+            .application_decisions
+            .len()
+            .saturating_sub(config.fair_housing_log_len);
+        self.fair_housing.application_decisions.drain(..excess);
+    }
+
+    /// The archetype the recent rejections single out, if any.
+    pub fn rejection_pattern(
+        &self,
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) -> Option<TenantArchetype> {
+        let rejections: Vec<&ApplicationDecisionLog> = self
+            .fair_housing
+            .application_decisions
+            .iter()
+            .filter(|d| d.decision == ApplicationDecision::Rejected)
+            .filter(|d| d.tick + config.fair_housing_pattern_window_months > current_month)
+            .collect();
+        let count = |archetype: &TenantArchetype| {
+            rejections
+                .iter()
+                .filter(|d| d.archetype == *archetype)
+                .count()
+        };
+        let archetype = &rejections
+            .iter()
+            .max_by_key(|d| count(&d.archetype))?
+            .archetype;
+        let targeted: Vec<&&ApplicationDecisionLog> = rejections
+            .iter()
+            .filter(|d| d.archetype == *archetype)
+            .collect();
+        let share = targeted.len() as f32 / rejections.len() as f32;
+        let mut units: Vec<u32> = targeted.iter().map(|d| d.apartment_id).collect();
+        units.sort_unstable();
+        units.dedup();
+        (share > config.fair_housing_pattern_share
+            && units.len() >= config.fair_housing_pattern_min_units)
+            .then(|| archetype.clone())
+    }
+
+    /// Open an investigation into rejections of `archetype`. The log is
+    /// cleared so the same decisions aren't counted twice.
+    pub fn open_fair_housing_investigation(&mut self, archetype: TenantArchetype) {
+        self.fair_housing.fair_housing_required_archetype = Some(archetype);
+        self.fair_housing.application_decisions.clear();
+    }
+
+    /// Fine turning away a qualified applicant the investigation requires
+    /// be accepted. Returns the fine, or 0 if no requirement applies.
+    pub fn cite_fair_housing_violation(
+        &mut self,
+        archetype: &TenantArchetype,
+        config: &RegulationsConfig,
+    ) -> i32 {
+        if self.fair_housing.fair_housing_required_archetype.as_ref() != Some(archetype) {
+            return 0;
+        }
+        let fine = config.fair_housing_fine;
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        fine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reject(system: &mut ComplianceSystem, apartment_id: u32, archetype: TenantArchetype) {
+        system.log_application_decision(
+            0,
+            apartment_id,
+            &archetype,
+            ApplicationDecision::Rejected,
+            &RegulationsConfig::default(),
+        );
+    }
+
+    #[test]
+    fn rejecting_one_archetype_across_units_is_flagged() {
+        let config = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        reject(&mut system, 1, TenantArchetype::Family);
+        reject(&mut system, 1, TenantArchetype::Family);
+        assert_eq!(system.rejection_pattern(0, &config), None);
+
+        reject(&mut system, 2, TenantArchetype::Family);
+        reject(&mut system, 3, TenantArchetype::Family);
+        assert_eq!(
+            system.rejection_pattern(0, &config),
+            Some(TenantArchetype::Family)
+        );
+        assert_eq!(
+            system.rejection_pattern(config.fair_housing_pattern_window_months, &config),
+            None
+        );
+
+        reject(&mut system, 4, TenantArchetype::Student);
+        assert_eq!(system.rejection_pattern(0, &config), None);
+    }
+
+    #[test]
+    fn accepting_the_required_archetype_closes_the_investigation() {
+        let config = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.open_fair_housing_investigation(TenantArchetype::Artist);
+        assert_eq!(
+            system.cite_fair_housing_violation(&TenantArchetype::Artist, &config),
+            config.fair_housing_fine
+        );
+
+        system.log_application_decision(
+            1,
+            1,
+            &TenantArchetype::Artist,
+            ApplicationDecision::Accepted,
+            &config,
+        );
+        assert_eq!(system.fair_housing.fair_housing_required_archetype, None);
+    }
+}
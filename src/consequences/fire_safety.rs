@@ -0,0 +1,160 @@
+//! Fire safety: each building's score wears down month by month, the annual
+//! check cites buildings that have fallen below code, and a building whose
+//! score runs out burns.
+
+use super::regulations::{Inspection, InspectionResult, InspectionTrigger, RegulationType};
+use super::ComplianceSystem;
+use crate::data::config::RegulationsConfig;
+
+/// Fire safety score a building starts at.
+pub(super) const FIRE_SAFETY_START: i32 = 50;
+/// Months between annual fire safety checks.
+const FIRE_SAFETY_CHECK_INTERVAL: u32 = 12;
+
+/// Something the monthly fire safety upkeep wants the player to hear about.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FireSafetyOutcome {
+    /// The annual check found the building short of code.
+    Cited { building_id: u32, fine: i32 },
+    /// Fire safety ran out entirely and the building burned.
+    Fire { building_id: u32 },
+}
+
+impl ComplianceSystem {
+    /// Current fire safety score (0-100) for a building.
+    pub fn fire_safety_score(&self, building_id: u32) -> i32 {
+        self.fire_safety_scores
+            .get(&building_id)
+            .copied()
+            .unwrap_or(FIRE_SAFETY_START)
+    }
+
+    /// Raise a building's fire safety (extinguishers, sprinklers). Clears an
+    /// outstanding fire safety fix once the building is back up to code.
+    pub fn improve_fire_safety(
+        &mut self,
+        building_id: u32,
+        amount: i32,
+        config: &RegulationsConfig,
+    ) {
+        let score = (self.fire_safety_score(building_id) + amount).clamp(0, 100);
+        self.fire_safety_scores.insert(building_id, score);
+        if score >= config.fire_safety_pass_threshold {
+            self.pending_fixes.retain(|(id, reg_type, _)| {
+                *id != building_id || *reg_type != RegulationType::FireSafety
+            });
+        }
+    }
+
+    /// Monthly fire safety upkeep: every building's score wears down, once a
+    /// year buildings below the threshold are cited and fined by how far short
+    /// they fall, and a building whose score hits zero burns.
+    pub(super) fn tick_fire_safety(
+        &mut self,
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) -> Vec<FireSafetyOutcome> {
+        let annual_check =
+            current_month > 0 && current_month.is_multiple_of(FIRE_SAFETY_CHECK_INTERVAL);
+        let mut outcomes = Vec::new();
+        let mut building_ids: Vec<u32> = self.building_regulations.keys().copied().collect();
+        building_ids.sort_unstable();
+
+        for building_id in building_ids {
+            let before = self.fire_safety_score(building_id);
+            let score = (before - config.fire_safety_decay).max(0);
+            self.fire_safety_scores.insert(building_id, score);
+
+            if before > 0 && score == 0 {
+                outcomes.push(FireSafetyOutcome::Fire { building_id });
+            }
+            if !annual_check || score >= config.fire_safety_pass_threshold {
+                continue;
+            }
+
+            let fine =
+                (config.fire_safety_pass_threshold - score) * config.fire_safety_fine_per_point;
+            if let Some(reg) = self
+                .building_regulations
+                .get_mut(&building_id)
+                .and_then(|regs| {
+                    regs.iter_mut()
+                        .find(|r| r.regulation_type == RegulationType::FireSafety)
+                })
+            {
+                reg.add_violation();
+            }
+            self.pending_fixes.push((
+                building_id,
+                RegulationType::FireSafety,
+                current_month + config.fire_safety_fix_deadline_months,
+            ));
+            self.unpaid_fines += fine;
+            self.compliance_reputation =
+                (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+            self.inspection_history.push(Inspection {
+                building_id,
+                month: current_month,
+                results: vec![InspectionResult {
+                    regulation_type: RegulationType::FireSafety,
+                    passed: false,
+                    issues_found: vec![format!("Fire safety at {}%", score)],
+                    fine_amount: fine,
+                    deadline_months: config.fire_safety_fix_deadline_months,
+                    required_fixes: vec!["Service extinguishers or install sprinklers".to_string()],
+                }],
+                total_fines: fine,
+                triggered_by: InspectionTrigger::Scheduled,
+            });
+            outcomes.push(FireSafetyOutcome::Cited { building_id, fine });
+        }
+        outcomes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annual_fire_check_cites_a_neglected_building() {
+        let cfg = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+
+        for month in 1..12 {
+            assert!(system.tick(month, &cfg).is_empty());
+        }
+        let outcomes = system.tick(12, &cfg);
+
+        let score = FIRE_SAFETY_START - 12 * cfg.fire_safety_decay;
+        let fine = (cfg.fire_safety_pass_threshold - score) * cfg.fire_safety_fine_per_point;
+        assert_eq!(
+            outcomes,
+            vec![FireSafetyOutcome::Cited {
+                building_id: 0,
+                fine
+            }]
+        );
+        assert_eq!(system.unpaid_fines, fine);
+        assert!(system.has_violations(0));
+
+        // Bringing the building back up to code clears the pending fix.
+        system.improve_fire_safety(0, 50, &cfg);
+        assert!(system.pending_fixes.is_empty());
+    }
+
+    #[test]
+    fn fire_breaks_out_when_fire_safety_runs_out() {
+        let cfg = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+        system.fire_safety_scores.insert(0, cfg.fire_safety_decay);
+
+        assert_eq!(
+            system.tick(1, &cfg),
+            vec![FireSafetyOutcome::Fire { building_id: 0 }]
+        );
+        assert!(system.tick(2, &cfg).is_empty());
+    }
+}
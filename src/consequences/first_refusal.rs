@@ -0,0 +1,115 @@
+//! Tenant right of first refusal. Once the city grants it, a long-standing,
+//! reliable tenant can claim the chance to buy a building before it's sold
+//! to anyone else. The landlord may contest the claim in court, which holds
+//! up the sale for a few months.
+
+use serde::{Deserialize, Serialize};
+
+use super::ComplianceSystem;
+use crate::building::Building;
+use crate::data::config::RegulationsConfig;
+use crate::tenant::Tenant;
+
+/// A sale held up while the landlord contests a tenant's claim.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ContestedSale {
+    pub building_id: u32,
+    pub price: i32,
+    pub resolves_tick: u32,
+}
+
+impl ComplianceSystem {
+    /// The tenant of `building` who can claim first refusal on its sale, if
+    /// the law is in force and anyone qualifies.
+    pub fn first_refusal_tenant<'a>(
+        &self,
+        building: &Building,
+        tenants: &'a [Tenant],
+        config: &RegulationsConfig,
+    ) -> Option<&'a Tenant> {
+        if !self.right_of_first_refusal_active {
+            return None;
+        }
+        tenants
+            .iter()
+            .filter(|t| {
+                t.months_residing >= config.first_refusal_min_months
+                    && t.rent_reliability >= config.first_refusal_min_reliability
+                    && t.apartment_id.is_some_and(|id| {
+                        building
+                            .apartments
+                            .iter()
+                            .any(|a| a.id == id && a.tenant_id == Some(t.id))
+                    })
+            })
+            .max_by_key(|t| t.months_residing)
+    }
+
+    /// Hold up the sale of a building while the claim is fought in court.
+    pub fn contest_sale(
+        &mut self,
+        building_id: u32,
+        price: i32,
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) {
+        self.contested_sales.push(ContestedSale {
+            building_id,
+            price,
+            resolves_tick: current_month + config.first_refusal_contest_months,
+        });
+    }
+
+    /// The contested sales the court has ruled on, in favor of the landlord.
+    pub fn take_resolved_sales(&mut self, current_month: u32) -> Vec<ContestedSale> {
+        let (resolved, pending) = std::mem::take(&mut self.contested_sales)
+            .into_iter()
+            .partition(|sale| current_month >= sale.resolves_tick);
+        self.contested_sales = pending;
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::TenantArchetype;
+
+    #[test]
+    fn only_long_reliable_tenants_hold_the_right() {
+        let config = RegulationsConfig::default();
+        let mut building = Building::new("Test", 1, 2);
+        let mut compliance = ComplianceSystem::new();
+        let mut tenant = Tenant::new(1, "Dana", TenantArchetype::Professional);
+        tenant.apartment_id = Some(building.apartments[0].id);
+        tenant.months_residing = config.first_refusal_min_months;
+        tenant.rent_reliability = config.first_refusal_min_reliability;
+        building.apartments[0].tenant_id = Some(1);
+        let mut tenants = vec![tenant];
+
+        assert!(compliance
+            .first_refusal_tenant(&building, &tenants, &config)
+            .is_none());
+        compliance.right_of_first_refusal_active = true;
+        assert!(compliance
+            .first_refusal_tenant(&building, &tenants, &config)
+            .is_some());
+        tenants[0].months_residing -= 1;
+        assert!(compliance
+            .first_refusal_tenant(&building, &tenants, &config)
+            .is_none());
+    }
+
+    #[test]
+    fn contested_sale_resolves_after_the_delay() {
+        let config = RegulationsConfig::default();
+        let mut compliance = ComplianceSystem::new();
+        compliance.contest_sale(0, 100_000, 5, &config);
+        compliance.contest_sale(1, 80_000, 6, &config);
+        assert!(compliance.take_resolved_sales(7).is_empty());
+        let sales = compliance.take_resolved_sales(5 + config.first_refusal_contest_months);
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].price, 100_000);
+        assert_eq!(compliance.contested_sales.len(), 1);
+    }
+}
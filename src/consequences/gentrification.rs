@@ -36,6 +36,10 @@ pub struct GentrificationTracker {
     pub gentrification_score: i32,
     pub tenants_preserved: u32,
     pub affordable_units: u32,
+    /// Set for good once the landlord recognizes the tenant union: smaller
+    /// rent hikes start counting against the neighborhood.
+    #[serde(default)]
+    pub union_recognized: bool,
 }
 
 /// Snapshot of tenant demographics
@@ -78,6 +82,7 @@ impl GentrificationTracker {
             gentrification_score: 0,
             tenants_preserved: 0,
             affordable_units: 0,
+            union_recognized: false,
         }
     }
 
@@ -96,7 +101,12 @@ impl GentrificationTracker {
         // If significant rent increase, add to gentrification score
         if old_avg > 0 {
             let increase_percent = ((new_avg - old_avg) as f32 / old_avg as f32 * 100.0) as i32;
-            if increase_percent > config.rent_increase_threshold_percent {
+            let threshold = if self.union_recognized {
+                config.union_rent_increase_threshold_percent
+            } else {
+                config.rent_increase_threshold_percent
+            };
+            if increase_percent > threshold {
                 self.gentrification_score = (self.gentrification_score
                     + increase_percent / config.rent_increase_score_divisor)
                     .min(config.max_gentrification_score);
@@ -104,6 +114,12 @@ impl GentrificationTracker {
         }
     }
 
+    /// Nudge the score directly (mediation, outside pressure).
+    pub fn adjust_score(&mut self, change: i32, config: &GentrificationConfig) {
+        self.gentrification_score =
+            (self.gentrification_score + change).clamp(0, config.max_gentrification_score);
+    }
+
     /// Update affordable unit count
     pub fn update_affordable_units(
         &mut self,
@@ -137,6 +153,21 @@ mod tests {
         assert!(tracker.gentrification_score > 0);
     }
 
+    #[test]
+    fn test_recognized_union_lowers_rent_hike_threshold() {
+        let config = GentrificationConfig::default();
+        let mut tracker = GentrificationTracker::new();
+
+        // An 8% hike slips under the usual bar...
+        tracker.record_rent_change(0, 1, 1000, 1080, &config);
+        assert_eq!(tracker.gentrification_score, 0);
+
+        // ...but not once the union has been recognized
+        tracker.union_recognized = true;
+        tracker.record_rent_change(0, 2, 1000, 1080, &config);
+        assert!(tracker.gentrification_score > 0);
+    }
+
     #[test]
     fn test_demographic_diversity() {
         let snapshot = DemographicSnapshot {
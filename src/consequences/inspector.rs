@@ -0,0 +1,217 @@
+use crate::building::Building;
+use crate::data::config::InspectorConfig;
+use crate::tenant::Tenant;
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+/// Points knocked off the grade for each violation written up.
+const VIOLATION_PENALTY: i32 = 10;
+/// Condition, and tenant happiness, the inspector commends.
+const COMMENDABLE_SCORE: i32 = 80;
+/// Tenant happiness below which the inspector notes the complaints.
+const UNHAPPY_SCORE: i32 = 40;
+/// Lowest score for each grade, best first; anything lower is an F.
+const GRADE_FLOORS: [(char, i32); 4] = [('A', 85), ('B', 70), ('C', 55), ('D', 40)];
+
+/// One visit's report card.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InspectionReport {
+    pub tick: u32,
+    pub grade: char,
+    pub violations: Vec<String>,
+    pub commendations: Vec<String>,
+    /// Month the inspector plans to come back.
+    pub next_visit: u32,
+}
+
+impl InspectionReport {
+    /// A or B: insurers knock a little off the premium.
+    pub fn earns_discount(&self) -> bool {
+        matches!(self.grade, 'A' | 'B')
+    }
+
+    /// D or F: the city fines the landlord.
+    pub fn is_failing(&self) -> bool {
+        matches!(self.grade, 'D' | 'F')
+    }
+
+    /// The report as a letter, for the mailbox.
+    pub fn letter(&self, building_name: &str, inspector_name: &str) -> String {
+        let mut body = format!("Report card for {}: grade {}\n", building_name, self.grade);
+        if !self.commendations.is_empty() {
+            body.push_str("\nCommendations:\n");
+            for line in &self.commendations {
+                body.push_str(&format!("  + {}\n", line));
+            }
+        }
+        if !self.violations.is_empty() {
+            body.push_str("\nViolations:\n");
+            for line in &self.violations {
+                body.push_str(&format!("  - {}\n", line));
+            }
+        }
+        body.push_str(&format!(
+            "\nI'll be back around month {}.\n\n{}",
+            self.next_visit, inspector_name
+        ));
+        body
+    }
+}
+
+/// The city inspector who drops by every few months and grades the building.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BuildingInspector {
+    pub name: String,
+    pub next_visit_tick: u32,
+    pub reports: Vec<InspectionReport>,
+}
+
+impl Default for BuildingInspector {
+    fn default() -> Self {
+        Self::new(InspectorConfig::default().visit_interval)
+    }
+}
+
+impl BuildingInspector {
+    pub fn new(first_visit_tick: u32) -> Self {
+        Self {
+            name: "Inspector Dolores Vance".to_string(),
+            next_visit_tick: first_visit_tick,
+            reports: Vec::new(),
+        }
+    }
+
+    pub fn is_due(&self, current_tick: u32) -> bool {
+        current_tick >= self.next_visit_tick
+    }
+
+    /// Walk the building, grade it, and book the next visit. `open_codes` are
+    /// the regulations it's currently cited under.
+    pub fn inspect(
+        &mut self,
+        current_tick: u32,
+        building: &Building,
+        tenants: &[Tenant],
+        open_codes: &[&str],
+        poor_condition: i32,
+        config: &InspectorConfig,
+    ) -> &InspectionReport {
+        let mut violations = Vec::new();
+        let mut commendations = Vec::new();
+
+        for apt in &building.apartments {
+            if apt.condition < poor_condition {
+                violations.push(format!(
+                    "Unit {} in poor repair ({}%)",
+                    apt.unit_number, apt.condition
+                ));
+            }
+        }
+        if building.hallway_condition < poor_condition {
+            violations.push(format!(
+                "Hallway in poor repair ({}%)",
+                building.hallway_condition
+            ));
+        }
+        for code in open_codes {
+            violations.push(format!("Outstanding {} citation", code));
+        }
+
+        let condition = building.average_condition();
+        if condition >= COMMENDABLE_SCORE {
+            commendations.push("Units are well maintained".to_string());
+        }
+        if building.hallway_condition >= COMMENDABLE_SCORE {
+            commendations.push("Clean, well-kept hallway".to_string());
+        }
+        if open_codes.is_empty() {
+            commendations.push("Fully up to code".to_string());
+        }
+
+        // An empty building is graded on its bricks alone.
+        let happiness = if tenants.is_empty() {
+            condition
+        } else {
+            tenants.iter().map(|t| t.happiness).sum::<i32>() / tenants.len() as i32
+        };
+        if !tenants.is_empty() && happiness >= COMMENDABLE_SCORE {
+            commendations.push("Tenants speak well of the management".to_string());
+        } else if !tenants.is_empty() && happiness < UNHAPPY_SCORE {
+            violations.push("Tenants report widespread complaints".to_string());
+        }
+
+        let score = (condition + building.hallway_condition + happiness) / 3
+            - violations.len() as i32 * VIOLATION_PENALTY;
+        let grade = GRADE_FLOORS
+            .iter()
+            .find(|(_, floor)| score >= *floor)
+            .map(|(grade, _)| *grade)
+            .unwrap_or('F');
+
+        let jitter = config.visit_jitter as i32;
+        let scheduled =
+            (current_tick + config.visit_interval) as i32 + rng::gen_range(-jitter, jitter + 1);
+        let next_visit = scheduled.max(current_tick as i32 + 1) as u32;
+        self.next_visit_tick = next_visit;
+        self.reports.push(InspectionReport {
+            tick: current_tick,
+            grade,
+            violations,
+            commendations,
+            next_visit,
+        });
+        self.reports.last().expect("report just pushed")
+    }
+
+    /// Visits in a row, most recent back, that scored a C or better.
+    pub fn passing_streak(&self) -> u32 {
+        self.reports
+            .iter()
+            .rev()
+            .take_while(|report| !report.is_failing())
+            .count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn building_at(condition: i32) -> Building {
+        let mut building = Building::new("Test", 2, 2);
+        for apt in &mut building.apartments {
+            apt.condition = condition;
+        }
+        building.hallway_condition = condition;
+        building
+    }
+
+    #[test]
+    fn grade_follows_condition_and_citations() {
+        let config = InspectorConfig::default();
+        let mut inspector = BuildingInspector::new(0);
+
+        let report = inspector.inspect(6, &building_at(95), &[], &[], 40, &config);
+        assert_eq!(report.grade, 'A');
+        assert!(report.earns_discount());
+
+        let report = inspector.inspect(12, &building_at(30), &[], &["Fire Safety"], 40, &config);
+        assert_eq!(report.grade, 'F');
+        assert!(report.is_failing());
+        assert_eq!(inspector.passing_streak(), 0);
+    }
+
+    #[test]
+    fn next_visit_lands_within_the_jitter_window() {
+        let config = InspectorConfig::default();
+        let mut inspector = BuildingInspector::new(0);
+        for tick in [6, 12, 18, 24] {
+            let next = inspector
+                .inspect(tick, &building_at(70), &[], &[], 40, &config)
+                .next_visit;
+            assert!(next >= tick + config.visit_interval - config.visit_jitter);
+            assert!(next <= tick + config.visit_interval + config.visit_jitter);
+        }
+        assert_eq!(inspector.passing_streak(), 4);
+    }
+}
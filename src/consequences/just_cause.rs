@@ -0,0 +1,116 @@
+//! Tenant displacement law. Under just-cause eviction every eviction needs a
+//! documented reason that holds up, or it's blocked and fined; under the
+//! relocation law, displacing a tenant without assistance is fined.
+
+use super::ComplianceSystem;
+use crate::building::{Apartment, Building};
+use crate::data::config::RegulationsConfig;
+use crate::tenant::Tenant;
+use serde::{Deserialize, Serialize};
+
+/// A tenant with behavior this low has a documented history of violations.
+const LEASE_VIOLATION_BEHAVIOR: i32 = 40;
+/// A unit this run down needs to be emptied for rehabilitation.
+const REHABILITATION_CONDITION: i32 = 30;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvictionReason {
+    NonPayment,
+    LeaseViolation,
+    OwnerMoveIn,
+    RehabilitationRequired,
+}
+
+impl EvictionReason {
+    pub const ALL: [EvictionReason; 4] = [
+        EvictionReason::NonPayment,
+        EvictionReason::LeaseViolation,
+        EvictionReason::OwnerMoveIn,
+        EvictionReason::RehabilitationRequired,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EvictionReason::NonPayment => "Non-payment",
+            EvictionReason::LeaseViolation => "Lease violation",
+            EvictionReason::OwnerMoveIn => "Owner move-in",
+            EvictionReason::RehabilitationRequired => "Rehabilitation",
+        }
+    }
+
+    /// What the law expects on file for this reason.
+    pub fn documentation(&self) -> &'static str {
+        match self {
+            EvictionReason::NonPayment => "Record of unpaid rent",
+            EvictionReason::LeaseViolation => "Documented violations (behavior under 40)",
+            EvictionReason::OwnerMoveIn => "No other unit free for the owner",
+            EvictionReason::RehabilitationRequired => "Unit condition under 30%",
+        }
+    }
+
+    pub fn is_valid(&self, tenant: &Tenant, apartment: &Apartment, building: &Building) -> bool {
+        match self {
+            EvictionReason::NonPayment => tenant.outstanding_balance > 0,
+            EvictionReason::LeaseViolation => tenant.behavior_score < LEASE_VIOLATION_BEHAVIOR,
+            EvictionReason::OwnerMoveIn => !building
+                .apartments
+                .iter()
+                .any(|a| a.id != apartment.id && a.is_vacant()),
+            EvictionReason::RehabilitationRequired => {
+                apartment.condition < REHABILITATION_CONDITION
+            }
+        }
+    }
+}
+
+impl ComplianceSystem {
+    /// An eviction attempted without valid cause under the law. Returns the
+    /// fine added to the unpaid balance.
+    pub fn cite_invalid_eviction(&mut self, config: &RegulationsConfig) -> i32 {
+        let fine = config.just_cause_violation_fine;
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        fine
+    }
+
+    /// A tenant displaced without relocation assistance. Returns the fine,
+    /// or 0 when the law doesn't require assistance.
+    pub fn cite_relocation_bypass(&mut self, config: &RegulationsConfig) -> i32 {
+        if !self.relocation_assistance_required {
+            return 0;
+        }
+        let fine = config.relocation_bypass_fine;
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        fine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::TenantArchetype;
+
+    #[test]
+    fn non_payment_needs_an_outstanding_balance() {
+        let building = Building::new("Test", 1, 2);
+        let apt = &building.apartments[0];
+        let mut tenant = Tenant::new(1, "Late", TenantArchetype::Student);
+        assert!(!EvictionReason::NonPayment.is_valid(&tenant, apt, &building));
+        tenant.outstanding_balance = 500;
+        assert!(EvictionReason::NonPayment.is_valid(&tenant, apt, &building));
+    }
+
+    #[test]
+    fn owner_move_in_is_invalid_while_another_unit_is_free() {
+        let mut building = Building::new("Test", 1, 2);
+        let tenant = Tenant::new(1, "Settled", TenantArchetype::Family);
+        building.apartments[0].move_in(tenant.id);
+        let apt = building.apartments[0].clone();
+        assert!(!EvictionReason::OwnerMoveIn.is_valid(&tenant, &apt, &building));
+        building.apartments[1].move_in(2);
+        assert!(EvictionReason::OwnerMoveIn.is_valid(&tenant, &apt, &building));
+    }
+}
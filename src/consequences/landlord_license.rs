@@ -0,0 +1,105 @@
+//! Annual landlord license: renewed each year for a fee, provided the
+//! building has no outstanding violations, the books are in the black and
+//! fire safety is up to standard. Otherwise the license lapses.
+
+use super::ComplianceSystem;
+use crate::building::Apartment;
+use crate::data::config::RegulationsConfig;
+
+/// Months a landlord license is good for.
+pub const LICENSE_TERM_MONTHS: u32 = 12;
+
+impl ComplianceSystem {
+    pub fn license_renewal_due(&self, current_month: u32) -> bool {
+        current_month >= self.landlord_license_renewal_tick
+    }
+
+    /// Months until renewal; negative once past due.
+    pub fn license_months_left(&self, current_month: u32) -> i64 {
+        self.landlord_license_renewal_tick as i64 - current_month as i64
+    }
+
+    /// Open citations still waiting on a fix: a regulation out of
+    /// compliance or a unit under a repair order.
+    pub fn has_outstanding_violations(&self, building_id: u32) -> bool {
+        let noncompliant = self
+            .building_regulations
+            .get(&building_id)
+            .is_some_and(|regs| regs.iter().any(|r| r.active && !r.compliant));
+        noncompliant
+            || self
+                .repair_deadlines
+                .iter()
+                .any(|d| d.building_id == building_id)
+    }
+
+    /// Why the license can't be renewed, or `None` if it can.
+    pub fn license_renewal_blocker(
+        &self,
+        building_id: u32,
+        balance: i32,
+        config: &RegulationsConfig,
+    ) -> Option<&'static str> {
+        if self.has_outstanding_violations(building_id) {
+            Some("outstanding violations")
+        } else if balance <= 0 {
+            Some("a negative balance")
+        } else if self.fire_safety_score(building_id) <= config.license_min_fire_safety {
+            Some("substandard fire safety")
+        } else {
+            None
+        }
+    }
+
+    /// Renew for another term, clearing any lapse.
+    pub fn renew_license(&mut self, current_month: u32) {
+        self.landlord_license_renewal_tick =
+            self.landlord_license_renewal_tick.max(current_month) + LICENSE_TERM_MONTHS;
+        self.license_lapsed = false;
+    }
+
+    /// Let the license lapse and cite the building for operating without
+    /// one, ordering `poor_units` repaired. Returns the fine.
+    pub fn lapse_license(
+        &mut self,
+        building_id: u32,
+        poor_units: &[&Apartment],
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) -> i32 {
+        self.license_lapsed = true;
+        self.cite_health_violation(
+            building_id,
+            poor_units,
+            current_month,
+            "Operating without a landlord license",
+            config,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn license_renews_only_without_outstanding_violations() {
+        let config = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+        assert!(system.license_renewal_due(LICENSE_TERM_MONTHS));
+        assert_eq!(system.license_renewal_blocker(0, 1000, &config), None);
+        assert!(system.license_renewal_blocker(0, 0, &config).is_some());
+
+        system.lapse_license(0, &[], LICENSE_TERM_MONTHS, &config);
+        assert!(system.license_lapsed);
+        assert_eq!(
+            system.license_renewal_blocker(0, 1000, &config),
+            Some("outstanding violations")
+        );
+
+        system.renew_license(LICENSE_TERM_MONTHS);
+        assert!(!system.license_lapsed);
+        assert_eq!(system.license_months_left(LICENSE_TERM_MONTHS), 12);
+    }
+}
@@ -0,0 +1,135 @@
+//! Building permits: major unit renovations need an approved permit first,
+//! and the city takes a few months to process one unless it's fast-tracked.
+
+use serde::{Deserialize, Serialize};
+
+use super::ComplianceSystem;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PermitType {
+    /// Processed in the city's usual time.
+    Standard,
+    /// Double the fee, approved next month.
+    FastTrack,
+}
+
+/// A permit application for one upgrade to one unit.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingPermit {
+    /// Index in `city.buildings` of the building the unit is in.
+    #[serde(default)]
+    pub building_id: u32,
+    pub permit_type: PermitType,
+    pub submitted_tick: u32,
+    pub approved_tick: u32,
+    pub upgrade_id: String,
+    pub apartment_id: u32,
+}
+
+impl PendingPermit {
+    pub fn is_approved(&self, current_month: u32) -> bool {
+        current_month >= self.approved_tick
+    }
+
+    fn is_for(&self, building_id: u32, upgrade_id: &str, apartment_id: u32) -> bool {
+        self.building_id == building_id
+            && self.upgrade_id == upgrade_id
+            && self.apartment_id == apartment_id
+    }
+}
+
+/// Permits filed with the city and not yet used.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PermitQueue {
+    /// Building permits filed for major renovations
+    #[serde(default)]
+    pub pending_permits: Vec<PendingPermit>,
+}
+
+impl ComplianceSystem {
+    /// File a permit for `upgrade_id` on a unit. Returns the month it's
+    /// approved.
+    pub fn submit_permit(
+        &mut self,
+        building_id: u32,
+        upgrade_id: &str,
+        apartment_id: u32,
+        permit_type: PermitType,
+        current_month: u32,
+        processing_months: u32,
+    ) -> u32 {
+        let approved_tick = match permit_type {
+            PermitType::Standard => current_month + processing_months,
+            PermitType::FastTrack => current_month + 1,
+        };
+        self.permits.pending_permits.push(PendingPermit {
+            building_id,
+            permit_type,
+            submitted_tick: current_month,
+            approved_tick,
+            upgrade_id: upgrade_id.to_string(),
+            apartment_id,
+        });
+        approved_tick
+    }
+
+    /// The permit on file for an upgrade to a unit, if any.
+    pub fn permit_for(
+        &self,
+        building_id: u32,
+        upgrade_id: &str,
+        apartment_id: u32,
+    ) -> Option<&PendingPermit> {
+        self.permits
+            .pending_permits
+            .iter()
+            .find(|p| p.is_for(building_id, upgrade_id, apartment_id))
+    }
+
+    /// Every permit on file for units in one building.
+    pub fn permits_in(&self, building_id: u32) -> Vec<PendingPermit> {
+        self.permits
+            .pending_permits
+            .iter()
+            .filter(|p| p.building_id == building_id)
+            .cloned()
+            .collect()
+    }
+
+    /// Use up an approved permit. Returns false if none is approved yet.
+    pub fn take_approved_permit(
+        &mut self,
+        building_id: u32,
+        upgrade_id: &str,
+        apartment_id: u32,
+        current_month: u32,
+    ) -> bool {
+        let Some(index) = self.permits.pending_permits.iter().position(|p| {
+            p.is_for(building_id, upgrade_id, apartment_id) && p.is_approved(current_month)
+        }) else {
+            return false;
+        };
+        self.permits.pending_permits.remove(index);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fast_track_permit_is_approved_next_month() {
+        let mut system = ComplianceSystem::new();
+        system.submit_permit(0, "kitchen", 3, PermitType::Standard, 10, 3);
+        system.submit_permit(0, "bath", 3, PermitType::FastTrack, 10, 3);
+
+        assert!(!system.take_approved_permit(0, "kitchen", 3, 11));
+        assert!(!system.take_approved_permit(1, "bath", 3, 11));
+        assert!(system.take_approved_permit(0, "bath", 3, 11));
+        assert!(system.permit_for(0, "bath", 3).is_none());
+
+        assert!(system.take_approved_permit(0, "kitchen", 3, 13));
+        assert!(system.permits.pending_permits.is_empty());
+    }
+}
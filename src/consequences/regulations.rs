@@ -1,3 +1,5 @@
+use super::fire_safety::FIRE_SAFETY_START;
+use crate::building::Apartment;
 use crate::data::config::RegulationsConfig;
 use serde::{Deserialize, Serialize};
 
@@ -163,6 +165,76 @@ pub struct ComplianceSystem {
     pub unpaid_fines: i32,
     /// Player's overall compliance reputation (affects inspection frequency)
     pub compliance_reputation: i32,
+    /// Fire safety score (0-100) by building ID
+    #[serde(default)]
+    pub fire_safety_scores: std::collections::HashMap<u32, i32>,
+    /// Buildings that must be ADA compliant (by age or neighborhood)
+    #[serde(default)]
+    pub ada_required: std::collections::HashSet<u32>,
+    /// Tenant complaints and the inspections they've forced
+    #[serde(default)]
+    pub complaints: super::ComplaintLog,
+    /// City voucher program: qualifying voucher holders can't be turned away
+    #[serde(default)]
+    pub voucher_program_active: bool,
+    /// Month a city energy mandate must be met by, if one is in force
+    #[serde(default)]
+    pub energy_mandate_deadline: Option<u32>,
+    /// Building flag the energy mandate requires
+    #[serde(default)]
+    pub energy_mandate_required_upgrade: String,
+    /// Building (index in `city.buildings`) the energy mandate applies to
+    #[serde(default)]
+    pub energy_mandate_building_id: u32,
+    /// Share of units the affordable mandate requires; 0 while not enacted
+    #[serde(default)]
+    pub required_affordable_percent: f32,
+    /// Rent at or below which a unit counts as affordable for the mandate
+    #[serde(default)]
+    pub affordable_rent_threshold: i32,
+    /// Tenant evictions need a valid documented cause
+    #[serde(default)]
+    pub just_cause_eviction_active: bool,
+    /// Displacing a tenant without relocation assistance is fined
+    #[serde(default)]
+    pub relocation_assistance_required: bool,
+    /// Open repair orders for units cited for health violations
+    #[serde(default)]
+    pub repair_deadlines: Vec<super::RepairDeadline>,
+    /// Last violation ID handed to a repair deadline
+    #[serde(default)]
+    pub next_violation_id: u32,
+    /// Building permits filed for major renovations
+    #[serde(default)]
+    pub permits: super::PermitQueue,
+    /// Month the landlord license is next up for renewal
+    #[serde(default = "default_license_renewal_tick")]
+    pub landlord_license_renewal_tick: u32,
+    /// The license lapsed at renewal; no new tenants until it's renewed
+    #[serde(default)]
+    pub license_lapsed: bool,
+    /// Application decisions and any fair housing investigation
+    #[serde(default)]
+    pub fair_housing: super::FairHousingRecord,
+    /// The rent increase notice law and increases held by it
+    #[serde(default)]
+    pub rent_notice: super::RentNoticeRules,
+    /// Long-term tenants may claim the right to buy before a building sale
+    #[serde(default)]
+    pub right_of_first_refusal_active: bool,
+    /// Sales held up while first refusal claims are contested
+    #[serde(default)]
+    pub contested_sales: Vec<super::ContestedSale>,
+    /// Property tax assessments on file
+    #[serde(default)]
+    pub tax_assessments: super::AssessmentRoll,
+    /// Special assessment bills awaiting a hearing or being paid off
+    #[serde(default)]
+    pub special_assessments: super::SpecialAssessments,
+}
+
+fn default_license_renewal_tick() -> u32 {
+    super::landlord_license::LICENSE_TERM_MONTHS
 }
 
 impl ComplianceSystem {
@@ -173,6 +245,28 @@ impl ComplianceSystem {
             pending_fixes: Vec::new(),
             unpaid_fines: 0,
             compliance_reputation: 100,
+            fire_safety_scores: std::collections::HashMap::new(),
+            ada_required: std::collections::HashSet::new(),
+            complaints: super::ComplaintLog::default(),
+            voucher_program_active: false,
+            energy_mandate_deadline: None,
+            energy_mandate_required_upgrade: String::new(),
+            energy_mandate_building_id: 0,
+            required_affordable_percent: 0.0,
+            affordable_rent_threshold: 0,
+            just_cause_eviction_active: false,
+            relocation_assistance_required: false,
+            repair_deadlines: Vec::new(),
+            next_violation_id: 0,
+            permits: super::PermitQueue::default(),
+            landlord_license_renewal_tick: default_license_renewal_tick(),
+            license_lapsed: false,
+            fair_housing: super::FairHousingRecord::default(),
+            rent_notice: super::RentNoticeRules::default(),
+            right_of_first_refusal_active: false,
+            contested_sales: Vec::new(),
+            tax_assessments: super::AssessmentRoll::default(),
+            special_assessments: super::SpecialAssessments::default(),
         }
     }
 
@@ -191,6 +285,17 @@ impl ComplianceSystem {
         }
 
         self.building_regulations.insert(building_id, regulations);
+        self.fire_safety_scores
+            .insert(building_id, FIRE_SAFETY_START);
+    }
+
+    /// Record whether a building must meet accessibility requirements.
+    pub fn set_ada_required(&mut self, building_id: u32, required: bool) {
+        if required {
+            self.ada_required.insert(building_id);
+        } else {
+            self.ada_required.remove(&building_id);
+        }
     }
 
     /// Get regulations for a building.
@@ -214,14 +319,19 @@ impl ComplianceSystem {
     /// metric the inspector grades against (typically the min of average unit
     /// condition and hallway condition). A `Scheduled` trigger only grades the
     /// regulations that are actually due; any other trigger grades all of them.
+    /// A building in `ada_required` is also graded on accessibility whenever
+    /// anything else is, passing only if `ada_compliant`.
     ///
     /// Mutates regulation state, accrues fines into `unpaid_fines`, records fix
     /// deadlines, adjusts `compliance_reputation`, and returns the `Inspection`
-    /// (also pushed to `inspection_history`).
+    /// (also pushed to `inspection_history`). A health and sanitation failure
+    /// opens a repair deadline for each of `poor_units`.
     pub fn run_inspection(
         &mut self,
         building_id: u32,
         inspection_score: i32,
+        ada_compliant: bool,
+        poor_units: &[&Apartment],
         current_month: u32,
         trigger: InspectionTrigger,
         config: &RegulationsConfig,
@@ -230,6 +340,7 @@ impl ComplianceSystem {
         let mut new_pending = Vec::new();
         let mut total_fines = 0;
         let mut citations = 0;
+        let mut health_violation = false;
 
         if let Some(regs) = self.building_regulations.get_mut(&building_id) {
             for reg in regs.iter_mut() {
@@ -256,6 +367,7 @@ impl ComplianceSystem {
                     });
                 } else {
                     reg.add_violation();
+                    health_violation |= reg.regulation_type == RegulationType::HealthSanitation;
                     let fine =
                         (reg.regulation_type.base_fine() as f32 * config.fine_multiplier) as i32;
                     total_fines += fine;
@@ -283,8 +395,42 @@ impl ComplianceSystem {
             }
         }
 
+        if !results.is_empty() && self.ada_required.contains(&building_id) {
+            let accessibility = RegulationType::Accessibility;
+            if ada_compliant {
+                results.push(InspectionResult {
+                    regulation_type: accessibility,
+                    passed: true,
+                    issues_found: Vec::new(),
+                    fine_amount: 0,
+                    deadline_months: 0,
+                    required_fixes: Vec::new(),
+                });
+            } else {
+                let fine = (accessibility.base_fine() as f32 * config.fine_multiplier) as i32;
+                total_fines += fine;
+                citations += 1;
+                new_pending.push((
+                    building_id,
+                    accessibility.clone(),
+                    current_month + config.fix_deadline_months,
+                ));
+                results.push(InspectionResult {
+                    regulation_type: accessibility,
+                    passed: false,
+                    issues_found: vec!["No accessible entrance or ramps".to_string()],
+                    fine_amount: fine,
+                    deadline_months: config.fix_deadline_months,
+                    required_fixes: vec!["Install ADA accessibility upgrades".to_string()],
+                });
+            }
+        }
+
         // Apply cross-field mutations now that the `regs` borrow has ended.
         self.pending_fixes.extend(new_pending);
+        if health_violation {
+            self.open_repair_deadlines(building_id, poor_units, current_month, config);
+        }
         if citations > 0 {
             self.unpaid_fines += total_fines;
             self.compliance_reputation = (self.compliance_reputation
@@ -317,8 +463,56 @@ impl ComplianceSystem {
             })
     }
 
-    /// Monthly tick - decrement inspection timers, check deadlines
-    pub fn tick(&mut self, current_month: u32) {
+    /// Cite a building for a health hazard left unfixed, such as water
+    /// damage, and open a repair deadline for each of `cited_units`. Returns
+    /// the fine.
+    pub fn cite_health_violation(
+        &mut self,
+        building_id: u32,
+        cited_units: &[&Apartment],
+        current_month: u32,
+        issue: &str,
+        config: &RegulationsConfig,
+    ) -> i32 {
+        let fine = RegulationType::HealthSanitation.base_fine();
+        if let Some(reg) = self
+            .building_regulations
+            .get_mut(&building_id)
+            .and_then(|regs| {
+                regs.iter_mut()
+                    .find(|r| r.regulation_type == RegulationType::HealthSanitation)
+            })
+        {
+            reg.add_violation();
+        }
+        self.open_repair_deadlines(building_id, cited_units, current_month, config);
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        self.inspection_history.push(Inspection {
+            building_id,
+            month: current_month,
+            results: vec![InspectionResult {
+                regulation_type: RegulationType::HealthSanitation,
+                passed: false,
+                issues_found: vec![issue.to_string()],
+                fine_amount: fine,
+                deadline_months: 0,
+                required_fixes: vec!["Repair the affected units".to_string()],
+            }],
+            total_fines: fine,
+            triggered_by: InspectionTrigger::TenantComplaint,
+        });
+        fine
+    }
+
+    /// Monthly tick - decrement inspection timers, check deadlines, wear down
+    /// fire safety
+    pub fn tick(
+        &mut self,
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) -> Vec<FireSafetyOutcome> {
         // Decrement inspection timers
         for regulations in self.building_regulations.values_mut() {
             for reg in regulations.iter_mut() {
@@ -345,6 +539,8 @@ impl ComplianceSystem {
             self.unpaid_fines += reg_type.base_fine();
             self.compliance_reputation = (self.compliance_reputation - 15).max(0);
         }
+
+        self.tick_fire_safety(current_month, config)
     }
 }
 
@@ -378,6 +574,24 @@ mod tests {
         assert!(!system.has_violations(0));
     }
 
+    #[test]
+    fn health_citation_records_a_sanitation_violation() {
+        let cfg = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+
+        let mut building = crate::building::Building::new("Test", 1, 1);
+        building.apartments[0].condition = 10;
+        let damaged = &building.apartments[0];
+        let fine = system.cite_health_violation(0, &[damaged], 8, "Unrepaired water damage", &cfg);
+
+        assert_eq!(fine, RegulationType::HealthSanitation.base_fine());
+        assert_eq!(system.unpaid_fines, fine);
+        assert!(system.has_violations(0));
+        assert_eq!(system.repair_deadlines.len(), 1);
+        assert_eq!(system.repair_deadlines[0].apartment_id, damaged.id);
+    }
+
     #[test]
     fn failed_inspection_cites_and_fines_a_neglected_building() {
         let cfg = RegulationsConfig::default();
@@ -385,9 +599,20 @@ mod tests {
         system.init_building_regulations(0, false);
 
         // A condition well below the pass threshold cites every regulation.
-        let inspection = system.run_inspection(0, 10, 6, InspectionTrigger::Random, &cfg);
+        let mut building = crate::building::Building::new("Test", 1, 1);
+        building.apartments[0].condition = 10;
+        let inspection = system.run_inspection(
+            0,
+            10,
+            true,
+            &[&building.apartments[0]],
+            6,
+            InspectionTrigger::Random,
+            &cfg,
+        );
 
         assert!(inspection.total_fines > 0);
+        assert_eq!(system.repair_deadlines.len(), 1);
         assert!(inspection.results.iter().all(|r| !r.passed));
         assert_eq!(system.unpaid_fines, inspection.total_fines);
         assert!(system.compliance_reputation < 100);
@@ -401,7 +626,8 @@ mod tests {
         let mut system = ComplianceSystem::new();
         system.init_building_regulations(0, false);
 
-        let inspection = system.run_inspection(0, 90, 6, InspectionTrigger::Random, &cfg);
+        let inspection =
+            system.run_inspection(0, 90, true, &[], 6, InspectionTrigger::Random, &cfg);
 
         assert_eq!(inspection.total_fines, 0);
         assert!(inspection.results.iter().all(|r| r.passed));
@@ -417,9 +643,31 @@ mod tests {
 
         // Nothing is due on a freshly initialised building, so a scheduled
         // inspection grades nothing and levies no fine.
-        let inspection = system.run_inspection(0, 10, 1, InspectionTrigger::Scheduled, &cfg);
+        let inspection =
+            system.run_inspection(0, 10, true, &[], 1, InspectionTrigger::Scheduled, &cfg);
 
         assert!(inspection.results.is_empty());
         assert_eq!(system.unpaid_fines, 0);
     }
+
+    #[test]
+    fn inspection_cites_a_required_building_without_ada_access() {
+        let cfg = RegulationsConfig::default();
+        let mut system = ComplianceSystem::new();
+        system.init_building_regulations(0, false);
+
+        let clean = system.run_inspection(0, 90, false, &[], 6, InspectionTrigger::Random, &cfg);
+        assert_eq!(clean.total_fines, 0);
+
+        system.set_ada_required(0, true);
+        let cited = system.run_inspection(0, 90, false, &[], 7, InspectionTrigger::Random, &cfg);
+        assert!(cited
+            .results
+            .iter()
+            .any(|r| r.regulation_type == RegulationType::Accessibility && !r.passed));
+        assert!(cited.total_fines > 0);
+
+        let compliant = system.run_inspection(0, 90, true, &[], 8, InspectionTrigger::Random, &cfg);
+        assert_eq!(compliant.total_fines, 0);
+    }
 }
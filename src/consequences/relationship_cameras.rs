@@ -0,0 +1,98 @@
+//! Security cameras: footage now and then settles a feud between neighbors,
+//! turning a hostile relationship neutral.
+
+use crate::building::Building;
+use crate::consequences::relationships::{RelationshipType, TenantNetwork};
+use crate::narrative::events::NarrativeEventType;
+use crate::narrative::NarrativeEvent;
+use crate::tenant::Tenant;
+use macroquad_toolkit::rng;
+
+/// Building flag set by the security camera upgrade.
+pub const SECURITY_CAMERAS_FLAG: &str = "security_cameras";
+
+const FOOTAGE_NOTE: &str = "Camera footage resolved dispute";
+
+impl TenantNetwork {
+    /// Roll for camera footage settling one feud between `tenants`. Needs the
+    /// building to have cameras; `chance` is the monthly percent chance.
+    pub(crate) fn maybe_resolve_dispute_on_camera(
+        &mut self,
+        tenants: &[Tenant],
+        building: &Building,
+        chance: i32,
+        current_month: u32,
+    ) -> Option<NarrativeEvent> {
+        if !building.flags.contains(SECURITY_CAMERAS_FLAG) || rng::gen_range(0, 100) >= chance {
+            return None;
+        }
+        let name_of = |id: u32| tenants.iter().find(|t| t.id == id).map(|t| t.name.clone());
+        let feuds: Vec<usize> = self
+            .relationships
+            .iter()
+            .enumerate()
+            .filter(|(_, rel)| {
+                rel.relationship_type == RelationshipType::Hostile
+                    && name_of(rel.tenant_a_id).is_some()
+                    && name_of(rel.tenant_b_id).is_some()
+            })
+            .map(|(index, _)| index)
+            .collect();
+        if feuds.is_empty() {
+            return None;
+        }
+
+        let rel = &mut self.relationships[feuds[rng::gen_range(0, feuds.len())]];
+        rel.relationship_type = RelationshipType::Neutral;
+        rel.strength = 50;
+        rel.recent_events.push(FOOTAGE_NOTE.to_string());
+        rel.record(current_month, FOOTAGE_NOTE, Some(RelationshipType::Neutral));
+
+        let mut event = NarrativeEvent::news(
+            0,
+            current_month,
+            FOOTAGE_NOTE,
+            &format!(
+                "The hallway cameras caught what really happened between {} and {}. \
+                 With the facts on tape, the two have agreed to move on.",
+                name_of(rel.tenant_a_id)?,
+                name_of(rel.tenant_b_id)?
+            ),
+        );
+        event.event_type = NarrativeEventType::RelationshipEvent;
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consequences::relationships::TenantRelationship;
+    use crate::tenant::TenantArchetype;
+
+    #[test]
+    fn footage_settles_a_feud_only_with_cameras() {
+        let mut building = Building::new("Test", 1, 2);
+        let tenants = vec![
+            Tenant::new(1, "Ana", TenantArchetype::Student),
+            Tenant::new(2, "Ben", TenantArchetype::Elderly),
+        ];
+        let mut network = TenantNetwork::new();
+        network
+            .relationships
+            .push(TenantRelationship::new(1, 2, RelationshipType::Hostile));
+
+        assert!(network
+            .maybe_resolve_dispute_on_camera(&tenants, &building, 100, 3)
+            .is_none());
+
+        building.flags.insert(SECURITY_CAMERAS_FLAG.to_string());
+        assert!(network
+            .maybe_resolve_dispute_on_camera(&tenants, &building, 100, 3)
+            .is_some());
+        assert_eq!(
+            network.relationships[0].relationship_type,
+            RelationshipType::Neutral
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! City-wide relationship processing. Tenants across every owned building
+//! share one `TenantNetwork`, so friendships survive switching the active
+//! building. Neighbors in the same building form relationships as usual;
+//! tenants in different buildings meet far less often and only ever as
+//! friends, family or partners — never as feuding neighbors.
+
+use crate::city::City;
+use crate::consequences::relationships::{RelationshipType, TenantNetwork};
+use crate::data::config::RelationshipsConfig;
+use crate::narrative::{NarrativeEvent, RelationshipChange, RelationshipEventsConfig};
+use crate::tenant::{Tenant, TenantArchetype};
+use macroquad_toolkit::rng;
+
+/// Index into `city.buildings` of the building a tenant lives in.
+fn building_of(city: &City, tenant: &Tenant) -> Option<usize> {
+    city.buildings.iter().position(|building| {
+        building
+            .apartments
+            .iter()
+            .any(|apt| apt.tenant_id == Some(tenant.id))
+    })
+}
+
+/// Initial type for tenants who met across buildings. Only the warm types.
+fn cross_building_relationship(tenant_a: &Tenant, tenant_b: &Tenant) -> RelationshipType {
+    if tenant_a.archetype == TenantArchetype::Family
+        && tenant_b.archetype == TenantArchetype::Family
+    {
+        RelationshipType::Family
+    } else if rng::gen_range(0, 100) < 15 {
+        RelationshipType::Romantic
+    } else {
+        RelationshipType::Friendly
+    }
+}
+
+impl TenantNetwork {
+    /// Process monthly relationship dynamics, forming relationships among the
+    /// tenants of every building in the city. Relationship events still play
+    /// out in the active building only.
+    pub fn tick_all_buildings(
+        &mut self,
+        all_tenants: &[Tenant],
+        city: &City,
+        config: &RelationshipsConfig,
+        events_config: &RelationshipEventsConfig,
+        current_month: u32,
+    ) -> (Vec<RelationshipChange>, Vec<NarrativeEvent>) {
+        let mut changes = Vec::new();
+        self.age_relationships(config, current_month);
+
+        let homes: Vec<Option<usize>> = all_tenants.iter().map(|t| building_of(city, t)).collect();
+        for (i, tenant_a) in all_tenants.iter().enumerate() {
+            let Some(home_a) = homes[i] else { continue };
+            for (j, tenant_b) in all_tenants.iter().enumerate().skip(i + 1) {
+                let Some(home_b) = homes[j] else { continue };
+
+                let rel_type = if home_a == home_b {
+                    if rng::gen_range(0, 100) >= config.formation_chance {
+                        continue;
+                    }
+                    self.determine_initial_relationship(
+                        tenant_a,
+                        tenant_b,
+                        &city.buildings[home_a],
+                        config,
+                    )
+                } else {
+                    if rng::gen_range(0, 100) >= config.cross_building_formation_chance {
+                        continue;
+                    }
+                    cross_building_relationship(tenant_a, tenant_b)
+                };
+                changes.extend(self.form_relationship(tenant_a, tenant_b, rel_type, current_month));
+            }
+        }
+
+        let mut events = Vec::new();
+        if let Some(building) = city.active_building() {
+            let active_tenants: Vec<Tenant> = all_tenants
+                .iter()
+                .zip(&homes)
+                .filter(|(_, home)| **home == Some(city.active_building_index))
+                .map(|(tenant, _)| tenant.clone())
+                .collect();
+            events = self.generate_relationship_events(
+                &active_tenants,
+                building,
+                config,
+                events_config,
+                current_month,
+            );
+        }
+
+        (changes, events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_building_relationships_are_never_hostile() {
+        let a = Tenant::new(1, "A", TenantArchetype::Student);
+        let b = Tenant::new(2, "B", TenantArchetype::Professional);
+        for _ in 0..50 {
+            assert_ne!(
+                cross_building_relationship(&a, &b),
+                RelationshipType::Hostile
+            );
+        }
+
+        let family_a = Tenant::new(3, "C", TenantArchetype::Family);
+        let family_b = Tenant::new(4, "D", TenantArchetype::Family);
+        assert_eq!(
+            cross_building_relationship(&family_a, &family_b),
+            RelationshipType::Family
+        );
+    }
+}
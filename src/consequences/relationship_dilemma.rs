@@ -279,8 +279,8 @@ mod tests {
         building.apartments[0].rent_price = 900; // Sarah pays a premium
 
         let mut network = TenantNetwork::new();
-        network.apply_relationship_change(1, 2, -40); // Sarah-Alex hostile
-        network.apply_relationship_change(1, 3, -40); // Sarah-Kim hostile
+        network.apply_relationship_change(1, 2, -40, 0); // Sarah-Alex hostile
+        network.apply_relationship_change(1, 3, -40, 0); // Sarah-Kim hostile
 
         (
             network,
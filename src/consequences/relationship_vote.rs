@@ -0,0 +1,87 @@
+//! Tenant votes on selling the building. Each tenant's stance comes from what
+//! they think of the landlord, both on their own and as recorded in their
+//! relationships with neighbors.
+
+use crate::consequences::relationships::TenantNetwork;
+use crate::tenant::Tenant;
+
+/// Head count on each side of a buyout vote.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuyoutVote {
+    pub support: usize,
+    pub oppose: usize,
+}
+
+impl BuyoutVote {
+    /// A tie keeps the building off the market.
+    pub fn passed(&self) -> bool {
+        self.support > self.oppose
+    }
+}
+
+impl TenantNetwork {
+    /// How a tenant feels about the landlord: their own opinion averaged with
+    /// the opinions carried in each of their relationships.
+    pub fn landlord_opinion_of(&self, tenant: &Tenant) -> i32 {
+        let mut total = tenant.landlord_opinion;
+        let mut count = 1;
+        for rel in &self.relationships {
+            if rel.tenant_a_id == tenant.id {
+                total += rel.landlord_opinion_a;
+                count += 1;
+            } else if rel.tenant_b_id == tenant.id {
+                total += rel.landlord_opinion_b;
+                count += 1;
+            }
+        }
+        total / count
+    }
+
+    /// Tenants who resent the landlord vote to let the developer have the
+    /// building; the rest would rather not be displaced.
+    pub fn buyout_vote(&self, tenants: &[Tenant]) -> BuyoutVote {
+        let support = tenants
+            .iter()
+            .filter(|t| self.landlord_opinion_of(t) < 0)
+            .count();
+        BuyoutVote {
+            support,
+            oppose: tenants.len() - support,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consequences::relationships::{RelationshipType, TenantRelationship};
+    use crate::tenant::TenantArchetype;
+
+    #[test]
+    fn relationships_sway_the_vote() {
+        let mut network = TenantNetwork::new();
+        let mut tenants: Vec<Tenant> = (0..3)
+            .map(|id| Tenant::new(id, "Voter", TenantArchetype::Family))
+            .collect();
+        for tenant in &mut tenants {
+            tenant.landlord_opinion = 10;
+        }
+        assert!(!network.buyout_vote(&tenants).passed());
+
+        // Bitter history with the landlord, shared between neighbors
+        let mut rel = TenantRelationship::new(0, 1, RelationshipType::Friendly);
+        rel.landlord_opinion_a = -60;
+        rel.landlord_opinion_b = -60;
+        network.relationships.push(rel);
+
+        let vote = network.buyout_vote(&tenants);
+        assert_eq!(
+            vote,
+            BuyoutVote {
+                support: 2,
+                oppose: 1
+            }
+        );
+        assert!(vote.passed());
+    }
+}
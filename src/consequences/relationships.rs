@@ -1,3 +1,4 @@
+use super::relationship_cameras::SECURITY_CAMERAS_FLAG;
 use crate::data::config::RelationshipsConfig;
 use crate::narrative::events::{NarrativeChoice, NarrativeEffect, NarrativeEventType};
 use crate::narrative::relationship_config::RelationshipEventTemplate;
@@ -49,6 +50,24 @@ pub struct TenantRelationship {
     // Phase 4C: Landlord opinions
     pub landlord_opinion_a: i32, // How tenant A views landlord (-100 to 100)
     pub landlord_opinion_b: i32, // How tenant B views landlord
+
+    /// Significant changes over the relationship's life, oldest first
+    #[serde(default)]
+    pub history: Vec<RelationshipHistoryEntry>,
+}
+
+/// Strength at which a relationship counts as a strong bond.
+const STRONG_BOND_STRENGTH: i32 = 80;
+/// Oldest history entries are dropped past this many.
+const MAX_HISTORY: usize = 20;
+
+/// One timestamped turning point in a relationship
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RelationshipHistoryEntry {
+    pub tick: u32,
+    pub description: String,
+    /// The type the relationship became, when this entry changed it
+    pub relationship_delta: Option<RelationshipType>,
 }
 
 /// Dynamic tension between apartments (e.g., noise complaints)
@@ -71,11 +90,41 @@ impl TenantRelationship {
             recent_events: Vec::new(),
             landlord_opinion_a: 0,
             landlord_opinion_b: 0,
+            history: Vec::new(),
+        }
+    }
+
+    pub fn record(
+        &mut self,
+        tick: u32,
+        description: impl Into<String>,
+        relationship_delta: Option<RelationshipType>,
+    ) {
+        self.history.push(RelationshipHistoryEntry {
+            tick,
+            description: description.into(),
+            relationship_delta,
+        });
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    /// Log a type change or a newly strong bond since `before`.
+    fn record_changes(&mut self, tick: u32, before: (RelationshipType, i32), reason: &str) {
+        let (old_type, old_strength) = before;
+        if self.relationship_type != old_type {
+            let description = format!("{}: now {:?}", reason, self.relationship_type);
+            self.record(tick, description, Some(self.relationship_type.clone()));
+        }
+        if old_strength < STRONG_BOND_STRENGTH && self.strength >= STRONG_BOND_STRENGTH {
+            self.record(tick, "Strong bond", None);
         }
     }
 
     /// Apply monthly relationship dynamics
-    pub fn tick(&mut self, config: &RelationshipsConfig) {
+    pub fn tick(&mut self, config: &RelationshipsConfig, current_month: u32) {
+        let before = (self.relationship_type.clone(), self.strength);
         self.duration_months += 1;
 
         // Long-term relationships tend to strengthen
@@ -99,6 +148,8 @@ impl TenantRelationship {
         while self.recent_events.len() > 5 {
             self.recent_events.remove(0);
         }
+
+        self.record_changes(current_month, before, "Conflict cooled down");
     }
 
     /// Can these tenants potentially form this relationship?
@@ -165,15 +216,41 @@ impl TenantNetwork {
         })
     }
 
+    /// Landlord-brokered truce: a hostile pair becomes neutral at middling
+    /// strength. Returns false if the two aren't feuding.
+    pub fn mediate(&mut self, tenant_a: u32, tenant_b: u32, current_month: u32) -> bool {
+        let Some(rel) = self.relationship_between_mut(tenant_a, tenant_b) else {
+            return false;
+        };
+        if rel.relationship_type != RelationshipType::Hostile {
+            return false;
+        }
+        rel.relationship_type = RelationshipType::Neutral;
+        rel.strength = 50;
+        rel.recent_events.push("Mediated by landlord".to_string());
+        rel.record(
+            current_month,
+            "Mediated by landlord",
+            Some(RelationshipType::Neutral),
+        );
+        true
+    }
+
     /// Create a new relationship
     fn add_relationship(
         &mut self,
         tenant_a: u32,
         tenant_b: u32,
         rel_type: RelationshipType,
+        current_month: u32,
     ) -> Option<RelationshipType> {
         if self.relationship_between(tenant_a, tenant_b).is_none() {
-            let rel = TenantRelationship::new(tenant_a, tenant_b, rel_type.clone());
+            let mut rel = TenantRelationship::new(tenant_a, tenant_b, rel_type.clone());
+            rel.record(
+                current_month,
+                format!("Met as {:?}", rel_type),
+                Some(rel_type.clone()),
+            );
             self.relationships.push(rel);
             Some(rel_type)
         } else {
@@ -210,17 +287,28 @@ impl TenantNetwork {
     }
 
     /// Apply a direct strength change between two tenants, creating a relationship if needed.
-    pub fn apply_relationship_change(&mut self, tenant_a: u32, tenant_b: u32, change: i32) {
+    pub fn apply_relationship_change(
+        &mut self,
+        tenant_a: u32,
+        tenant_b: u32,
+        change: i32,
+        current_month: u32,
+    ) {
         if tenant_a == tenant_b || change == 0 {
             return;
         }
 
         if let Some(relationship) = self.relationship_between_mut(tenant_a, tenant_b) {
+            let before = (
+                relationship.relationship_type.clone(),
+                relationship.strength,
+            );
             relationship.strength = (relationship.strength + change).clamp(0, 100);
             relationship
                 .recent_events
                 .push(format!("Dialogue changed relationship by {:+}", change));
             update_relationship_type_from_strength(relationship, change);
+            relationship.record_changes(current_month, before, "Landlord's choice");
             return;
         }
 
@@ -234,70 +322,60 @@ impl TenantNetwork {
         relationship
             .recent_events
             .push(format!("Dialogue created relationship at {:+}", change));
+        relationship.record(
+            current_month,
+            format!("Met as {:?}", relationship.relationship_type),
+            Some(relationship.relationship_type.clone()),
+        );
         self.relationships.push(relationship);
     }
 
-    /// Process monthly relationship dynamics
-    pub fn tick(
+    /// Monthly dynamics for every existing relationship.
+    pub(super) fn age_relationships(&mut self, config: &RelationshipsConfig, current_month: u32) {
+        for relationship in &mut self.relationships {
+            relationship.tick(config, current_month);
+        }
+    }
+
+    /// Record a new relationship between two unrelated tenants, returning the
+    /// change to announce. `None` if they already know each other or can't
+    /// form one.
+    pub(super) fn form_relationship(
+        &mut self,
+        tenant_a: &crate::tenant::Tenant,
+        tenant_b: &crate::tenant::Tenant,
+        rel_type: RelationshipType,
+        current_month: u32,
+    ) -> Option<RelationshipChange> {
+        if self
+            .relationship_between(tenant_a.id, tenant_b.id)
+            .is_some()
+            || !TenantRelationship::can_form(tenant_a, tenant_b)
+        {
+            return None;
+        }
+        let actual_type =
+            self.add_relationship(tenant_a.id, tenant_b.id, rel_type, current_month)?;
+        Some(RelationshipChange::NewRelationship {
+            tenant_a_name: tenant_a.name.clone(),
+            tenant_b_name: tenant_b.name.clone(),
+            relationship_type: format!("{:?}", actual_type),
+            is_positive: !matches!(actual_type, RelationshipType::Hostile),
+        })
+    }
+
+    /// Roll this month's relationship events (and the emergent dilemma) for
+    /// the tenants of one building.
+    pub(super) fn generate_relationship_events(
         &mut self,
         tenants: &[crate::tenant::Tenant],
         building: &crate::building::Building,
         config: &RelationshipsConfig,
         events_config: &RelationshipEventsConfig,
         current_month: u32,
-    ) -> (Vec<RelationshipChange>, Vec<NarrativeEvent>) {
-        let mut changes = Vec::new();
-        let mut events = Vec::new(); // Phase 4
-
-        // Update existing relationships
-        for relationship in &mut self.relationships {
-            relationship.tick(config);
-
-            // Phase 4D: Detect relationship changes (e.g. Hostile -> Neutral)
-            // This would require tracking old state, which we can add later
-        }
-
-        // Chance to form new relationships between neighbors
-        for tenant_a in tenants {
-            for tenant_b in tenants {
-                if tenant_a.id >= tenant_b.id {
-                    continue; // Avoid duplicates
-                }
+    ) -> Vec<NarrativeEvent> {
+        let mut events = Vec::new();
 
-                // Check if they're already related
-                if self
-                    .relationship_between(tenant_a.id, tenant_b.id)
-                    .is_some()
-                {
-                    continue;
-                }
-
-                // Check if they can form a relationship
-                if !TenantRelationship::can_form(tenant_a, tenant_b) {
-                    continue;
-                }
-
-                // Chance per month for new relationship
-                if rng::gen_range(0, 100) < config.formation_chance {
-                    let rel_type =
-                        self.determine_initial_relationship(tenant_a, tenant_b, building, config);
-                    if let Some(actual_type) =
-                        self.add_relationship(tenant_a.id, tenant_b.id, rel_type.clone())
-                    {
-                        let is_positive = !matches!(actual_type, RelationshipType::Hostile);
-
-                        changes.push(RelationshipChange::NewRelationship {
-                            tenant_a_name: tenant_a.name.clone(),
-                            tenant_b_name: tenant_b.name.clone(),
-                            relationship_type: format!("{:?}", actual_type),
-                            is_positive,
-                        });
-                    }
-                }
-            }
-        }
-
-        // Phase 4: Generate relationship events
         for rel in &self.relationships {
             let possible_events = match rel.relationship_type {
                 RelationshipType::Hostile => &events_config.hostile,
@@ -344,8 +422,16 @@ impl TenantNetwork {
         ) {
             events.push(event);
         }
+        if let Some(event) = self.maybe_resolve_dispute_on_camera(
+            tenants,
+            building,
+            config.camera_dispute_resolution_chance,
+            current_month,
+        ) {
+            events.push(event);
+        }
 
-        (changes, events)
+        events
     }
 
     fn generate_event_from_template(
@@ -483,7 +569,7 @@ impl TenantNetwork {
     }
 
     /// Determine what kind of relationship forms between two tenants
-    fn determine_initial_relationship(
+    pub(super) fn determine_initial_relationship(
         &self,
         tenant_a: &crate::tenant::Tenant,
         tenant_b: &crate::tenant::Tenant,
@@ -510,13 +596,20 @@ impl TenantNetwork {
             TenantArchetype::Professional | TenantArchetype::Elderly | TenantArchetype::Family
         );
 
+        // Neighbors behave better on camera.
+        let hostile_chance = if building.flags.contains(SECURITY_CAMERAS_FLAG) {
+            config.adjacent_hostile_chance / 2
+        } else {
+            config.adjacent_hostile_chance
+        };
+
         if (noisy_type_a && quiet_type_b)
             || (!noisy_type_a && !quiet_type_b && rng::gen_range(0, 100) < 20)
         {
             // Check if apartments are adjacent (same floor or floor ±1)
             if let (Some(a), Some(b)) = (apt_a, apt_b) {
                 if (a.floor as i32 - b.floor as i32).abs() <= 1
-                    && rng::gen_range(0, 100) < config.adjacent_hostile_chance
+                    && rng::gen_range(0, 100) < hostile_chance
                 {
                     return RelationshipType::Hostile;
                 }
@@ -654,10 +747,39 @@ mod tests {
     #[test]
     fn test_network_basics() {
         let mut network = TenantNetwork::new();
-        network.add_relationship(1, 2, RelationshipType::Friendly);
+        network.add_relationship(1, 2, RelationshipType::Friendly, 0);
 
         assert!(network.relationship_between(1, 2).is_some());
         assert!(network.relationship_between(2, 1).is_some());
         assert!(network.relationship_between(1, 3).is_none());
     }
+
+    #[test]
+    fn test_mediate_only_settles_feuds() {
+        let mut network = TenantNetwork::new();
+        network.add_relationship(1, 2, RelationshipType::Hostile, 0);
+        network.add_relationship(1, 3, RelationshipType::Friendly, 0);
+
+        assert!(!network.mediate(1, 3, 4));
+        assert!(network.mediate(2, 1, 4));
+        let rel = network.relationship_between(1, 2).unwrap();
+        assert_eq!(rel.relationship_type, RelationshipType::Neutral);
+        assert_eq!(rel.strength, 50);
+        assert_eq!(rel.recent_events.last().unwrap(), "Mediated by landlord");
+        let entry = rel.history.last().unwrap();
+        assert_eq!(entry.tick, 4);
+        assert_eq!(entry.relationship_delta, Some(RelationshipType::Neutral));
+    }
+
+    #[test]
+    fn test_history_records_strong_bond() {
+        let mut network = TenantNetwork::new();
+        network.add_relationship(1, 2, RelationshipType::Friendly, 0);
+        network.apply_relationship_change(1, 2, 35, 3);
+
+        let rel = network.relationship_between(1, 2).unwrap();
+        let descriptions: Vec<&str> = rel.history.iter().map(|e| e.description.as_str()).collect();
+        assert_eq!(descriptions, ["Met as Friendly", "Strong bond"]);
+        assert_eq!(rel.history[1].tick, 3);
+    }
 }
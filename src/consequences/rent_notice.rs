@@ -0,0 +1,140 @@
+//! Rent increase notice: under the notice law, a large increase on an
+//! occupied unit only takes effect after a notice period, during which the
+//! tenant keeps paying the old rent and may decide to leave.
+
+use serde::{Deserialize, Serialize};
+
+use super::ComplianceSystem;
+
+/// An announced rent increase waiting out its notice period.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingRentIncrease {
+    /// Index in `city.buildings` of the building the unit is in.
+    #[serde(default)]
+    pub building_id: u32,
+    pub apartment_id: u32,
+    pub new_rent: i32,
+    pub effective_tick: u32,
+}
+
+/// The notice law and the increases it's holding back.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RentNoticeRules {
+    /// Large rent increases on occupied units need advance notice
+    #[serde(default)]
+    pub rent_increase_notice_required: bool,
+    /// Months of notice a large rent increase needs
+    #[serde(default = "default_rent_increase_notice_ticks")]
+    pub rent_increase_notice_ticks: u32,
+    /// Announced rent increases still in their notice period
+    #[serde(default)]
+    pub pending_rent_increases: Vec<PendingRentIncrease>,
+}
+
+fn default_rent_increase_notice_ticks() -> u32 {
+    2
+}
+
+impl Default for RentNoticeRules {
+    fn default() -> Self {
+        Self {
+            rent_increase_notice_required: false,
+            rent_increase_notice_ticks: default_rent_increase_notice_ticks(),
+            pending_rent_increases: Vec::new(),
+        }
+    }
+}
+
+impl ComplianceSystem {
+    /// Whether raising `current_rent` to `new_rent` needs notice: the law is
+    /// active and the increase is larger than `max_immediate_increase`.
+    pub fn rent_notice_needed(
+        &self,
+        current_rent: i32,
+        new_rent: i32,
+        max_immediate_increase: f32,
+    ) -> bool {
+        self.rent_notice.rent_increase_notice_required
+            && new_rent as f32 > current_rent as f32 * (1.0 + max_immediate_increase)
+    }
+
+    /// Announce an increase on a unit, replacing any earlier one. Returns the
+    /// month it takes effect.
+    pub fn schedule_rent_increase(
+        &mut self,
+        building_id: u32,
+        apartment_id: u32,
+        new_rent: i32,
+        current_month: u32,
+    ) -> u32 {
+        let effective_tick = current_month + self.rent_notice.rent_increase_notice_ticks;
+        self.cancel_rent_increase(building_id, apartment_id);
+        self.rent_notice
+            .pending_rent_increases
+            .push(PendingRentIncrease {
+                building_id,
+                apartment_id,
+                new_rent,
+                effective_tick,
+            });
+        effective_tick
+    }
+
+    pub fn pending_rent_increase(
+        &self,
+        building_id: u32,
+        apartment_id: u32,
+    ) -> Option<&PendingRentIncrease> {
+        self.rent_notice
+            .pending_rent_increases
+            .iter()
+            .find(|p| p.building_id == building_id && p.apartment_id == apartment_id)
+    }
+
+    /// Drop the announced increase for a unit, e.g. once its tenant leaves.
+    pub fn cancel_rent_increase(&mut self, building_id: u32, apartment_id: u32) {
+        self.rent_notice
+            .pending_rent_increases
+            .retain(|p| p.building_id != building_id || p.apartment_id != apartment_id);
+    }
+
+    /// Remove and return one building's increases whose notice period is
+    /// over. Other buildings' increases wait until their building is active.
+    pub fn take_due_rent_increases(
+        &mut self,
+        building_id: u32,
+        current_month: u32,
+    ) -> Vec<PendingRentIncrease> {
+        let (due, pending) = std::mem::take(&mut self.rent_notice.pending_rent_increases)
+            .into_iter()
+            .partition(|p| p.building_id == building_id && current_month >= p.effective_tick);
+        self.rent_notice.pending_rent_increases = pending;
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_increase_waits_out_the_notice_period() {
+        let mut compliance = ComplianceSystem::new();
+        assert!(!compliance.rent_notice_needed(1000, 1500, 0.05));
+
+        compliance.rent_notice.rent_increase_notice_required = true;
+        assert!(!compliance.rent_notice_needed(1000, 1050, 0.05));
+        assert!(compliance.rent_notice_needed(1000, 1100, 0.05));
+
+        let effective = compliance.schedule_rent_increase(0, 7, 1100, 4);
+        assert_eq!(
+            effective,
+            4 + compliance.rent_notice.rent_increase_notice_ticks
+        );
+        assert!(compliance.take_due_rent_increases(0, 5).is_empty());
+        assert!(compliance.take_due_rent_increases(1, effective).is_empty());
+        let due = compliance.take_due_rent_increases(0, effective);
+        assert_eq!(due.len(), 1);
+        assert!(compliance.pending_rent_increase(0, 7).is_none());
+    }
+}
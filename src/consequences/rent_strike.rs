@@ -0,0 +1,125 @@
+use crate::building::Building;
+use crate::data::config::GentrificationConfig;
+use serde::{Deserialize, Serialize};
+
+/// One thing the striking tenants want before they pay again.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum StrikeDemand {
+    /// No unit rents for more than this
+    ReduceRent { max_rent: i32 },
+    /// Every unit and the hallway repaired to at least this condition
+    RepairBuilding { min_condition: i32 },
+    /// No more tenants pushed out
+    StopEvictions,
+}
+
+impl StrikeDemand {
+    pub fn label(&self) -> String {
+        match self {
+            StrikeDemand::ReduceRent { max_rent } => format!("Cap rents at ${}", max_rent),
+            StrikeDemand::RepairBuilding { min_condition } => {
+                format!("Repair everything to {}% condition", min_condition)
+            }
+            StrikeDemand::StopEvictions => "Stop all evictions".to_string(),
+        }
+    }
+}
+
+/// A union rent strike: the striking units pay nothing until the demands are
+/// met or the strike runs out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RentStrike {
+    /// Index in `city.buildings` of the building on strike.
+    #[serde(default)]
+    pub building_id: u32,
+    pub demands: Vec<StrikeDemand>,
+    pub ticks_until_resolution: u32,
+}
+
+impl RentStrike {
+    /// Draw up demands that fit the building's actual grievances.
+    pub fn against(building: &Building, building_id: u32, config: &GentrificationConfig) -> Self {
+        let mut demands = Vec::new();
+
+        let rents: Vec<i32> = building
+            .apartments
+            .iter()
+            .filter(|a| !a.is_vacant())
+            .map(|a| a.rent_price)
+            .collect();
+        if !rents.is_empty() {
+            let average = rents.iter().sum::<i32>() / rents.len() as i32;
+            demands.push(StrikeDemand::ReduceRent {
+                max_rent: average * config.strike_rent_cap_percent / 100,
+            });
+        }
+
+        let min_condition = config.strike_min_condition;
+        let run_down = building.hallway_condition < min_condition
+            || building
+                .apartments
+                .iter()
+                .any(|a| a.condition < min_condition);
+        if run_down {
+            demands.push(StrikeDemand::RepairBuilding { min_condition });
+        }
+
+        demands.push(StrikeDemand::StopEvictions);
+
+        Self {
+            building_id,
+            demands,
+            ticks_until_resolution: config.rent_strike_duration,
+        }
+    }
+
+    /// Count down a month. Returns true once the strike has run its course.
+    pub fn tick(&mut self) -> bool {
+        self.ticks_until_resolution = self.ticks_until_resolution.saturating_sub(1);
+        self.ticks_until_resolution == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demands_follow_the_buildings_grievances() {
+        let mut building = Building::new("Test", 1, 2);
+        for apt in &mut building.apartments {
+            apt.condition = 90;
+            apt.rent_price = 1000;
+        }
+        building.hallway_condition = 90;
+        building.apartments[0].move_in(1);
+        let config = GentrificationConfig::default();
+
+        let strike = RentStrike::against(&building, 0, &config);
+        assert_eq!(
+            strike.demands,
+            vec![
+                StrikeDemand::ReduceRent { max_rent: 900 },
+                StrikeDemand::StopEvictions
+            ]
+        );
+
+        building.hallway_condition = 30;
+        let strike = RentStrike::against(&building, 0, &config);
+        assert!(strike.demands.contains(&StrikeDemand::RepairBuilding {
+            min_condition: config.strike_min_condition
+        }));
+    }
+
+    #[test]
+    fn strike_runs_its_course() {
+        let building = Building::new("Test", 1, 1);
+        let config = GentrificationConfig {
+            rent_strike_duration: 2,
+            ..GentrificationConfig::default()
+        };
+        let mut strike = RentStrike::against(&building, 0, &config);
+        assert!(!strike.tick());
+        assert!(strike.tick());
+    }
+}
@@ -0,0 +1,159 @@
+//! Habitability repair deadlines: a unit cited for a health violation must be
+//! brought back above poor condition in time, or it draws a fine every month
+//! it stays overdue. The fine doubles for every few months left unrepaired.
+
+use serde::{Deserialize, Serialize};
+
+use super::ComplianceSystem;
+use crate::building::Apartment;
+use crate::data::config::RegulationsConfig;
+
+/// Overdue months after which the monthly repair fine doubles.
+const FINE_DOUBLING_TICKS: u32 = 3;
+
+/// An open order to repair one unit cited for a health violation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RepairDeadline {
+    pub violation_id: u32,
+    pub building_id: u32,
+    pub apartment_id: u32,
+    pub unit_number: String,
+    pub deadline_tick: u32,
+    /// Fine for the first overdue month, before any doubling.
+    pub fine_per_tick: i32,
+    /// Fines charged so far.
+    pub total_fines: i32,
+}
+
+impl RepairDeadline {
+    /// Fine charged for being overdue at `current_month`, or 0 before the
+    /// deadline.
+    pub fn fine_due(&self, current_month: u32) -> i32 {
+        if current_month <= self.deadline_tick {
+            return 0;
+        }
+        let doublings = (current_month - self.deadline_tick - 1) / FINE_DOUBLING_TICKS;
+        self.fine_per_tick.saturating_mul(1 << doublings.min(16))
+    }
+
+    /// Months until the deadline; negative once overdue.
+    pub fn months_left(&self, current_month: u32) -> i64 {
+        self.deadline_tick as i64 - current_month as i64
+    }
+}
+
+impl ComplianceSystem {
+    /// Order a cited unit repaired within the configured window. Returns the
+    /// violation ID.
+    pub fn open_repair_deadline(
+        &mut self,
+        building_id: u32,
+        apt: &Apartment,
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) -> u32 {
+        self.next_violation_id += 1;
+        let violation_id = self.next_violation_id;
+        self.repair_deadlines.push(RepairDeadline {
+            violation_id,
+            building_id,
+            apartment_id: apt.id,
+            unit_number: apt.unit_number.clone(),
+            deadline_tick: current_month + config.repair_deadline_months,
+            fine_per_tick: config.repair_fine_per_tick,
+            total_fines: 0,
+        });
+        violation_id
+    }
+
+    /// Open a deadline for every cited unit that doesn't already have one.
+    pub(super) fn open_repair_deadlines(
+        &mut self,
+        building_id: u32,
+        cited_units: &[&Apartment],
+        current_month: u32,
+        config: &RegulationsConfig,
+    ) {
+        for apt in cited_units {
+            let already_open = self
+                .repair_deadlines
+                .iter()
+                .any(|d| d.building_id == building_id && d.apartment_id == apt.id);
+            if !already_open {
+                self.open_repair_deadline(building_id, apt, current_month, config);
+            }
+        }
+    }
+
+    /// Close deadlines whose units are back above `poor_condition` and fine
+    /// the rest that are overdue. Only `building_id`'s deadlines are checked,
+    /// against its `apartments`. Returns (resolved, fined this month).
+    pub fn tick_repair_deadlines(
+        &mut self,
+        building_id: u32,
+        apartments: &[Apartment],
+        current_month: u32,
+        poor_condition: i32,
+    ) -> (u32, i32) {
+        let mut resolved = 0;
+        self.repair_deadlines.retain(|deadline| {
+            if deadline.building_id != building_id {
+                return true;
+            }
+            let repaired = apartments
+                .iter()
+                .find(|a| a.id == deadline.apartment_id)
+                .is_none_or(|a| a.condition > poor_condition);
+            if repaired {
+                resolved += 1;
+            }
+            !repaired
+        });
+
+        let mut fined = 0;
+        for deadline in &mut self.repair_deadlines {
+            if deadline.building_id != building_id {
+                continue;
+            }
+            let fine = deadline.fine_due(current_month);
+            deadline.total_fines += fine;
+            fined += fine;
+        }
+        self.unpaid_fines += fined;
+        (resolved, fined)
+    }
+
+    /// A building's open repair deadlines, most urgent first.
+    pub fn repair_deadlines_by_urgency(&self, building_id: u32) -> Vec<&RepairDeadline> {
+        let mut deadlines: Vec<&RepairDeadline> = self
+            .repair_deadlines
+            .iter()
+            .filter(|d| d.building_id == building_id)
+            .collect();
+        deadlines.sort_by_key(|d| (d.deadline_tick, d.violation_id));
+        deadlines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overdue_repair_fine_doubles_every_three_months() {
+        let deadline = RepairDeadline {
+            violation_id: 1,
+            building_id: 0,
+            apartment_id: 0,
+            unit_number: "1A".to_string(),
+            deadline_tick: 10,
+            fine_per_tick: 100,
+            total_fines: 0,
+        };
+        assert_eq!(deadline.fine_due(10), 0);
+        assert_eq!(deadline.fine_due(11), 100);
+        assert_eq!(deadline.fine_due(13), 100);
+        assert_eq!(deadline.fine_due(14), 200);
+        assert_eq!(deadline.fine_due(17), 400);
+    }
+}
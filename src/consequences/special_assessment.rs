@@ -0,0 +1,127 @@
+//! Neighborhood special assessments the landlord hasn't settled yet: bills
+//! under challenge, held until the hearing rules on them, and bills being
+//! paid off in monthly installments.
+
+use super::ComplianceSystem;
+use serde::{Deserialize, Serialize};
+
+/// A special assessment bill the landlord is contesting.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChallengedAssessment {
+    pub neighborhood_id: u32,
+    pub amount: i32,
+    pub resolves_tick: u32,
+}
+
+/// A special assessment bill being paid off month by month, interest-free.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FinancedAssessment {
+    pub neighborhood_id: u32,
+    pub balance: i32,
+    pub monthly_payment: i32,
+    pub months_remaining: u32,
+}
+
+/// Special assessment bills awaiting a hearing or being paid off.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SpecialAssessments {
+    #[serde(default)]
+    pub challenged_assessments: Vec<ChallengedAssessment>,
+    #[serde(default)]
+    pub financed_assessments: Vec<FinancedAssessment>,
+}
+
+impl ComplianceSystem {
+    pub fn challenge_assessment(
+        &mut self,
+        neighborhood_id: u32,
+        amount: i32,
+        current_month: u32,
+        hearing_months: u32,
+    ) {
+        self.special_assessments
+            .challenged_assessments
+            .push(ChallengedAssessment {
+                neighborhood_id,
+                amount,
+                resolves_tick: current_month + hearing_months,
+            });
+    }
+
+    /// Challenges whose hearing has come, removed from the docket.
+    pub fn take_resolved_assessments(&mut self, current_month: u32) -> Vec<ChallengedAssessment> {
+        let (resolved, pending) = self
+            .special_assessments
+            .challenged_assessments
+            .drain(..)
+            .partition(|challenge| current_month >= challenge.resolves_tick);
+        self.special_assessments.challenged_assessments = pending;
+        resolved
+    }
+
+    /// Spread `amount` evenly over `months` installments.
+    pub fn finance_assessment(&mut self, neighborhood_id: u32, amount: i32, months: u32) {
+        let months = months.max(1);
+        self.special_assessments
+            .financed_assessments
+            .push(FinancedAssessment {
+                neighborhood_id,
+                balance: amount,
+                monthly_payment: (amount as f32 / months as f32).round() as i32,
+                months_remaining: months,
+            });
+    }
+
+    /// This month's installment on every financed assessment, as
+    /// `(neighborhood_id, payment)`. Paid-off assessments are closed; the
+    /// last installment picks up any rounding left on the balance.
+    pub fn take_assessment_installments(&mut self) -> Vec<(u32, i32)> {
+        let financed = &mut self.special_assessments.financed_assessments;
+        let installments = financed
+            .iter_mut()
+            .map(|assessment| {
+                let payment = if assessment.months_remaining <= 1 {
+                    assessment.balance
+                } else {
+                    assessment.monthly_payment.min(assessment.balance)
+                };
+                assessment.balance -= payment;
+                assessment.months_remaining = assessment.months_remaining.saturating_sub(1);
+                (assessment.neighborhood_id, payment)
+            })
+            .collect();
+        financed.retain(|assessment| assessment.months_remaining > 0);
+        installments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn challenges_wait_for_their_hearing() {
+        let mut compliance = ComplianceSystem::new();
+        compliance.challenge_assessment(1, 3_000, 6, 3);
+        assert!(compliance.take_resolved_assessments(8).is_empty());
+
+        let resolved = compliance.take_resolved_assessments(9);
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].amount, 3_000);
+        assert!(compliance
+            .special_assessments
+            .challenged_assessments
+            .is_empty());
+    }
+
+    #[test]
+    fn financed_assessments_are_paid_off_in_installments() {
+        let mut compliance = ComplianceSystem::new();
+        compliance.finance_assessment(1, 1_000, 3);
+
+        assert_eq!(compliance.take_assessment_installments(), vec![(1, 333)]);
+        assert_eq!(compliance.take_assessment_installments(), vec![(1, 333)]);
+        assert_eq!(compliance.take_assessment_installments(), vec![(1, 334)]);
+        assert!(compliance.take_assessment_installments().is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//! Annual property tax reassessment: each year the city re-appraises the
+//! building, and property tax scales with how far the assessed value has
+//! moved from the first assessment. A steep jump can be appealed once.
+
+use super::ComplianceSystem;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A building's assessed value, as the tax office has it on file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PropertyAssessment {
+    /// Value at the first assessment; tax is scaled against it.
+    pub base_value: i32,
+    pub value: i32,
+    /// Value before the latest reassessment, restored if an appeal wins.
+    pub previous_value: i32,
+    /// The latest increase was steep enough to appeal and hasn't been yet.
+    pub appeal_open: bool,
+}
+
+/// The tax office's assessment roll.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AssessmentRoll {
+    /// Month of the last property tax reassessment
+    #[serde(default)]
+    pub last_assessment_tick: u32,
+    /// Assessed values on file, by building ID
+    #[serde(default)]
+    pub property_assessments: HashMap<u32, PropertyAssessment>,
+}
+
+impl ComplianceSystem {
+    /// Whether the yearly reassessment of every building has come round.
+    pub fn assessment_due(&self, current_month: u32, interval_months: u32) -> bool {
+        current_month > 0
+            && current_month >= self.tax_assessments.last_assessment_tick + interval_months
+    }
+
+    /// Record `value` as the building's new assessment. An increase of at
+    /// least `appeal_threshold` (0.2 = 20%) can be appealed.
+    pub fn reassess(
+        &mut self,
+        building_id: u32,
+        value: i32,
+        current_month: u32,
+        appeal_threshold: f32,
+    ) -> PropertyAssessment {
+        self.tax_assessments.last_assessment_tick = current_month;
+        let assessment = self
+            .tax_assessments
+            .property_assessments
+            .entry(building_id)
+            .and_modify(|a| {
+                a.previous_value = a.value;
+                a.value = value;
+            })
+            .or_insert(PropertyAssessment {
+                base_value: value,
+                value,
+                previous_value: value,
+                appeal_open: false,
+            });
+        assessment.appeal_open =
+            assessment.value as f32 >= assessment.previous_value as f32 * (1.0 + appeal_threshold);
+        assessment.clone()
+    }
+
+    /// Multiplier on property tax for the building: assessed value over
+    /// its first assessment. 1.0 until it's been assessed.
+    pub fn property_tax_factor(&self, building_id: u32) -> f32 {
+        self.tax_assessments
+            .property_assessments
+            .get(&building_id)
+            .map_or(1.0, |a| a.value as f32 / a.base_value.max(1) as f32)
+    }
+
+    /// Close an open appeal; if `won`, the old value is restored. Returns
+    /// `None` when there was nothing to appeal.
+    pub fn settle_assessment_appeal(&mut self, building_id: u32, won: bool) -> Option<i32> {
+        let assessment = self
+            .tax_assessments
+            .property_assessments
+            .get_mut(&building_id)
+            .filter(|a| a.appeal_open)?;
+        assessment.appeal_open = false;
+        if won {
+            assessment.value = assessment.previous_value;
+        }
+        Some(assessment.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tax_tracks_value_and_steep_rises_can_be_appealed() {
+        let mut compliance = ComplianceSystem::new();
+        assert!(!compliance.assessment_due(6, 12));
+        assert!(compliance.assessment_due(12, 12));
+
+        let first = compliance.reassess(0, 100_000, 12, 0.2);
+        assert!(!first.appeal_open);
+        assert_eq!(compliance.property_tax_factor(0), 1.0);
+        assert!(!compliance.assessment_due(18, 12));
+
+        let second = compliance.reassess(0, 130_000, 24, 0.2);
+        assert!(second.appeal_open);
+        assert!((compliance.property_tax_factor(0) - 1.3).abs() < 0.001);
+
+        assert_eq!(compliance.settle_assessment_appeal(0, true), Some(100_000));
+        assert_eq!(compliance.settle_assessment_appeal(0, true), None);
+        assert_eq!(compliance.property_tax_factor(0), 1.0);
+    }
+}
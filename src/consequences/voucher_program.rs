@@ -0,0 +1,38 @@
+//! City housing voucher program: while it runs, turning away a qualifying
+//! voucher holder is source-of-income discrimination.
+
+use super::ComplianceSystem;
+use crate::data::config::RegulationsConfig;
+
+impl ComplianceSystem {
+    /// Fine the landlord for rejecting a qualifying voucher applicant.
+    /// Returns the fine added to the unpaid balance, or 0 when the program
+    /// isn't running.
+    pub fn cite_voucher_discrimination(&mut self, config: &RegulationsConfig) -> i32 {
+        if !self.voucher_program_active {
+            return 0;
+        }
+        let fine = config.voucher_discrimination_fine;
+        self.unpaid_fines += fine;
+        self.compliance_reputation =
+            (self.compliance_reputation - config.compliance_penalty_per_violation).max(0);
+        fine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discrimination_is_only_fined_during_the_program() {
+        let config = RegulationsConfig::default();
+        let mut compliance = ComplianceSystem::new();
+        assert_eq!(compliance.cite_voucher_discrimination(&config), 0);
+
+        compliance.voucher_program_active = true;
+        let fine = compliance.cite_voucher_discrimination(&config);
+        assert_eq!(fine, config.voucher_discrimination_fine);
+        assert_eq!(compliance.unpaid_fines, fine);
+    }
+}
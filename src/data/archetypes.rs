@@ -27,6 +27,14 @@ pub struct ArchetypePreferencesData {
     pub ideal_rent_max: i32,
     pub min_acceptable_condition: i32,
     pub prefers_quiet: bool,
+    #[serde(default)]
+    pub wants_parking: bool,
+    #[serde(default)]
+    pub wants_laundry: bool,
+    #[serde(default)]
+    pub wants_elevator: bool,
+    #[serde(default)]
+    pub storage_interest: f32,
     pub preferred_design: Option<String>,
     pub hates_design: Option<String>,
 }
@@ -83,6 +91,10 @@ impl ArchetypeRegistry {
             ideal_rent_max: prefs.ideal_rent_max,
             min_acceptable_condition: prefs.min_acceptable_condition,
             prefers_quiet: prefs.prefers_quiet,
+            wants_parking: prefs.wants_parking,
+            wants_laundry: prefs.wants_laundry,
+            wants_elevator: prefs.wants_elevator,
+            storage_interest: prefs.storage_interest,
             preferred_design: prefs
                 .preferred_design
                 .as_ref()
@@ -6,29 +6,44 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{OnceLock, RwLock};
 
+mod amenities;
 mod apartment;
+mod assessments;
 mod consequences;
 mod difficulty;
+mod narrative;
+mod npcs;
 mod presentation;
+mod rivals;
 mod rules;
+mod seasonal;
 mod social;
 mod tenants;
 mod upgrades;
 
+pub use amenities::{default_amenities_config, AmenityConfig};
 pub use apartment::ApartmentPropertiesConfig;
+pub use assessments::SpecialAssessmentConfig;
 pub use consequences::{
-    CriticalFailureConfig, GentrificationConfig, PortfolioConfig, RegulationsConfig,
+    CriticalFailureConfig, GentrificationConfig, LandmarkConfig, PortfolioConfig,
+    RegulationsConfig, WaterDamageConfig,
 };
-pub use difficulty::DifficultyModifiers;
+pub use difficulty::{DifficultyModifiers, DifficultyPreset};
+pub use narrative::{EventProbabilityConfig, EventRoll, UtilitySpikeConfig};
+pub use npcs::{InspectorConfig, JournalistConfig, MarketAnalystConfig, PropertyManagerConfig};
 pub use presentation::{LayoutConfig, ThemeConfig, UiThresholdsConfig};
+pub use rivals::RivalsConfig;
 pub use rules::{
-    ApplicationConfig, DecayConfig, EconomyConfig, HappinessConfig, OperatingCostsConfig,
-    StartingConditions, ThresholdsConfig, WinConditions,
+    default_archetype_rent_caps, default_elevator_happiness_bonus,
+    default_hallway_art_happiness_bonus, ApplicationConfig, DecayConfig, EconomyConfig,
+    HappinessConfig, MortgageConfig, OperatingCostsConfig, SquatterConfig, StartingConditions,
+    ThresholdsConfig, VacancyTaxConfig, WinConditions,
 };
+pub use seasonal::SeasonalModifiers;
 pub use social::{CohesionConfig, DilemmaConfig, RelationshipsConfig};
 pub use tenants::{
     LeaseAcceptanceConfig, LeaseDefaultsConfig, LifeEventsConfig, MarketingConfig, MatchingConfig,
-    StaffEffectsConfig, TenantRiskConfig, VettingConfig,
+    PowerOutageConfig, StaffEffectsConfig, TenantRiskConfig, VettingConfig,
 };
 pub use upgrades::{UiConfig, UpgradeDefinition, UpgradeEffect, UpgradeRequirement, UpgradeTarget};
 
@@ -38,6 +53,8 @@ pub struct GameConfig {
     pub starting_conditions: StartingConditions,
     pub economy: EconomyConfig,
     pub decay: DecayConfig,
+    #[serde(default)]
+    pub seasonal_modifiers: SeasonalModifiers,
     pub happiness: HappinessConfig,
     pub win_conditions: WinConditions,
     pub applications: ApplicationConfig,
@@ -51,6 +68,12 @@ pub struct GameConfig {
     #[serde(default)]
     pub operating_costs: OperatingCostsConfig,
     #[serde(default)]
+    pub vacancy_tax: VacancyTaxConfig,
+    #[serde(default)]
+    pub squatters: SquatterConfig,
+    #[serde(default)]
+    pub mortgage: MortgageConfig,
+    #[serde(default)]
     pub staff_effects: StaffEffectsConfig,
     #[serde(default)]
     pub tenant_risk: TenantRiskConfig,
@@ -69,9 +92,31 @@ pub struct GameConfig {
     #[serde(default)]
     pub life_events: LifeEventsConfig,
     #[serde(default)]
+    pub power_outages: PowerOutageConfig,
+    #[serde(default)]
     pub critical_failures: CriticalFailureConfig,
     #[serde(default)]
+    pub water_damage: WaterDamageConfig,
+    #[serde(default)]
+    pub landmarks: LandmarkConfig,
+    #[serde(default)]
     pub portfolio: PortfolioConfig,
+    #[serde(default)]
+    pub special_assessments: SpecialAssessmentConfig,
+    #[serde(default)]
+    pub probability_scaling: EventProbabilityConfig,
+    #[serde(default)]
+    pub utility_spikes: UtilitySpikeConfig,
+    #[serde(default)]
+    pub inspector: InspectorConfig,
+    #[serde(default)]
+    pub market_analyst: MarketAnalystConfig,
+    #[serde(default)]
+    pub property_manager: PropertyManagerConfig,
+    #[serde(default)]
+    pub journalist: JournalistConfig,
+    #[serde(default)]
+    pub rivals: RivalsConfig,
     /// Per-difficulty rule modifiers, keyed by the building template's
     /// `difficulty` ("Easy"/"Medium"/"Hard"). Empty map → no adjustment.
     #[serde(default)]
@@ -84,6 +129,9 @@ pub struct GameConfig {
     pub ui_thresholds: UiThresholdsConfig,
     #[serde(default)]
     pub apartment: ApartmentPropertiesConfig,
+    /// Shared amenity tuning, keyed by `AmenityType::key()`.
+    #[serde(default = "default_amenities_config")]
+    pub amenities_config: HashMap<String, AmenityConfig>,
 }
 
 /// Process-wide "currently loaded" config, consulted by call sites that would
@@ -138,3 +186,28 @@ pub fn load_config() -> GameConfig {
     set_active(&config);
     config
 }
+
+/// Development helper that notices edits to `assets/config.json` so tuning
+/// changes apply without restarting. Only polled in native debug builds.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigWatcher {
+    #[cfg_attr(any(not(debug_assertions), target_arch = "wasm32"), allow(dead_code))]
+    last_modified: Option<std::time::SystemTime>,
+}
+
+impl ConfigWatcher {
+    /// Returns a freshly loaded config when the file changed since the last
+    /// check. The first check only records the timestamp, since the config
+    /// was just loaded.
+    #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+    pub fn check_and_reload(&mut self) -> Option<GameConfig> {
+        let modified = std::fs::metadata("assets/config.json")
+            .and_then(|m| m.modified())
+            .ok()?;
+        let previous = self.last_modified.replace(modified);
+        match previous {
+            Some(previous) if previous != modified => Some(load_config()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Tuning for shared amenity rooms, keyed by `AmenityType::key()`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AmenityConfig {
+    /// Happiness every tenant gets from the amenity at full condition; scales
+    /// down with the amenity's condition.
+    #[serde(default = "default_amenity_happiness_bonus")]
+    pub happiness_bonus: i32,
+    /// Condition lost each month.
+    #[serde(default = "default_amenity_decay_per_tick")]
+    pub decay_per_tick: i32,
+}
+
+fn default_amenity_happiness_bonus() -> i32 {
+    4
+}
+
+fn default_amenity_decay_per_tick() -> i32 {
+    2
+}
+
+pub fn default_amenities_config() -> HashMap<String, AmenityConfig> {
+    [
+        ("gym", 4, 3),
+        ("pool", 6, 4),
+        ("community_room", 5, 2),
+        ("laundry", 3, 2),
+    ]
+    .into_iter()
+    .map(|(key, happiness_bonus, decay_per_tick)| {
+        (
+            key.to_string(),
+            AmenityConfig {
+                happiness_bonus,
+                decay_per_tick,
+            },
+        )
+    })
+    .collect()
+}
@@ -46,6 +46,14 @@ pub struct ApartmentPropertiesConfig {
     pub market_soundproofing_bonus: i32,
     pub market_high_noise_penalty: i32,
     pub market_value_floor: i32,
+
+    /// Rent a converted penthouse asks, as a multiple of `base_rent_large`.
+    #[serde(default = "default_penthouse_rent_multiplier")]
+    pub penthouse_rent_multiplier: f32,
+}
+
+fn default_penthouse_rent_multiplier() -> f32 {
+    2.5
 }
 
 impl Default for ApartmentPropertiesConfig {
@@ -86,6 +94,8 @@ impl Default for ApartmentPropertiesConfig {
             market_soundproofing_bonus: 3_000,
             market_high_noise_penalty: -5_000,
             market_value_floor: 10_000,
+
+            penthouse_rent_multiplier: default_penthouse_rent_multiplier(),
         }
     }
 }
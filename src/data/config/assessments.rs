@@ -0,0 +1,98 @@
+//! Tuning for neighborhood special assessments: when the city bills owners
+//! for an improvement project, how large the bill is, and what the project
+//! gives back.
+
+use serde::{Deserialize, Serialize};
+
+/// Neighborhood special assessments: when the city bills owners for an
+/// improvement project, and how paying it off or fighting it plays out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpecialAssessmentConfig {
+    /// Month of the year (0-11) the city bills for improvement projects.
+    #[serde(default = "default_special_assessment_month_of_year")]
+    pub month_of_year: u32,
+    /// Gentrification a neighborhood needs before the city bills owners.
+    #[serde(default = "default_special_assessment_min_gentrification")]
+    pub min_gentrification: i32,
+    /// Months an assessment can be paid off over.
+    #[serde(default = "default_special_assessment_finance_months")]
+    pub finance_months: u32,
+    /// Months a challenge takes to be heard.
+    #[serde(default = "default_special_assessment_challenge_months")]
+    pub challenge_months: u32,
+    /// Odds a challenge gets the bill reduced.
+    #[serde(default = "default_special_assessment_challenge_success_chance")]
+    pub challenge_success_chance: f32,
+    /// What's left of the bill after a successful challenge.
+    #[serde(default = "default_special_assessment_reduced_fraction")]
+    pub reduced_fraction: f32,
+    /// Each building's share of a project before gentrification is counted.
+    #[serde(default = "default_special_assessment_base_amount")]
+    pub base_amount: i32,
+    /// Extra per building for each point of neighborhood gentrification.
+    #[serde(default = "default_special_assessment_amount_per_gentrification")]
+    pub amount_per_gentrification: i32,
+    /// Transit score the neighborhood gains once the project is paid for.
+    #[serde(default = "default_special_assessment_transit_gain")]
+    pub transit_gain: i32,
+    /// Reputation the neighborhood gains once the project is paid for.
+    #[serde(default = "default_special_assessment_reputation_gain")]
+    pub reputation_gain: i32,
+}
+
+fn default_special_assessment_month_of_year() -> u32 {
+    6
+}
+
+fn default_special_assessment_min_gentrification() -> i32 {
+    50
+}
+
+fn default_special_assessment_finance_months() -> u32 {
+    6
+}
+
+fn default_special_assessment_challenge_months() -> u32 {
+    3
+}
+
+fn default_special_assessment_challenge_success_chance() -> f32 {
+    0.4
+}
+
+fn default_special_assessment_reduced_fraction() -> f32 {
+    0.5
+}
+
+fn default_special_assessment_base_amount() -> i32 {
+    1_000
+}
+
+fn default_special_assessment_amount_per_gentrification() -> i32 {
+    20
+}
+
+fn default_special_assessment_transit_gain() -> i32 {
+    5
+}
+
+fn default_special_assessment_reputation_gain() -> i32 {
+    3
+}
+
+impl Default for SpecialAssessmentConfig {
+    fn default() -> Self {
+        Self {
+            month_of_year: default_special_assessment_month_of_year(),
+            min_gentrification: default_special_assessment_min_gentrification(),
+            finance_months: default_special_assessment_finance_months(),
+            challenge_months: default_special_assessment_challenge_months(),
+            challenge_success_chance: default_special_assessment_challenge_success_chance(),
+            reduced_fraction: default_special_assessment_reduced_fraction(),
+            base_amount: default_special_assessment_base_amount(),
+            amount_per_gentrification: default_special_assessment_amount_per_gentrification(),
+            transit_gain: default_special_assessment_transit_gain(),
+            reputation_gain: default_special_assessment_reputation_gain(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Tuning for the systems that push back on the player: gentrification,
-//! inspections, aging-building failures, and the passive portfolio.
+//! inspections, aging-building failures, landmark designation, and the
+//! passive portfolio.
 
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +25,41 @@ pub struct GentrificationConfig {
     /// decision rather than a flat, purposeless payout.
     #[serde(default = "default_condo_sale_boom_bonus")]
     pub condo_sale_boom_bonus: f32,
+    /// One-off neighborhood reputation gain for installing solar panels.
+    #[serde(default = "default_green_building_reputation_bonus")]
+    pub green_building_reputation_bonus: i32,
+    /// Rent hike (percent) that counts toward gentrification once the
+    /// landlord has recognized the tenant union.
+    #[serde(default = "default_union_rent_increase_threshold_percent")]
+    pub union_rent_increase_threshold_percent: i32,
+    /// Cost of bringing in a mediator to talk with the union.
+    #[serde(default = "default_union_dialogue_cost")]
+    pub union_dialogue_cost: i32,
+    /// Cost of meeting the union's monthly demands.
+    #[serde(default = "default_union_demand_cost")]
+    pub union_demand_cost: i32,
+    /// Share of units the city's affordable mandate requires at or below
+    /// `affordable_threshold`, once the mandate is enacted.
+    #[serde(default = "default_affordable_mandate_percent")]
+    pub affordable_mandate_percent: f32,
+    /// Union demands ignored in a row before the tenants strike.
+    #[serde(default = "default_strike_after_ignored_demands")]
+    pub strike_after_ignored_demands: u32,
+    /// Months a rent strike lasts if nobody gives in.
+    #[serde(default = "default_rent_strike_duration")]
+    pub rent_strike_duration: u32,
+    /// Months added each time the landlord rejects the strike's demands.
+    #[serde(default = "default_rent_strike_extension")]
+    pub rent_strike_extension: u32,
+    /// Happiness every tenant gains when the strike's demands are met.
+    #[serde(default = "default_strike_settlement_happiness")]
+    pub strike_settlement_happiness: i32,
+    /// Rent cap the strikers ask for, as a percent of the current average.
+    #[serde(default = "default_strike_rent_cap_percent")]
+    pub strike_rent_cap_percent: i32,
+    /// Condition the strikers want every unit and the hallway brought up to.
+    #[serde(default = "default_strike_min_condition")]
+    pub strike_min_condition: i32,
 }
 
 fn default_council_rent_rollback() -> f32 {
@@ -38,6 +74,50 @@ fn default_condo_sale_boom_bonus() -> f32 {
     0.5
 }
 
+fn default_green_building_reputation_bonus() -> i32 {
+    3
+}
+
+fn default_union_rent_increase_threshold_percent() -> i32 {
+    5
+}
+
+fn default_union_dialogue_cost() -> i32 {
+    1500
+}
+
+fn default_union_demand_cost() -> i32 {
+    400
+}
+
+fn default_affordable_mandate_percent() -> f32 {
+    0.2
+}
+
+fn default_strike_after_ignored_demands() -> u32 {
+    2
+}
+
+fn default_rent_strike_duration() -> u32 {
+    3
+}
+
+fn default_rent_strike_extension() -> u32 {
+    2
+}
+
+fn default_strike_settlement_happiness() -> i32 {
+    20
+}
+
+fn default_strike_rent_cap_percent() -> i32 {
+    90
+}
+
+fn default_strike_min_condition() -> i32 {
+    60
+}
+
 impl Default for GentrificationConfig {
     fn default() -> Self {
         Self {
@@ -50,6 +130,17 @@ impl Default for GentrificationConfig {
             council_rent_rollback: default_council_rent_rollback(),
             council_solidarity_happiness: default_council_solidarity_happiness(),
             condo_sale_boom_bonus: default_condo_sale_boom_bonus(),
+            green_building_reputation_bonus: default_green_building_reputation_bonus(),
+            union_rent_increase_threshold_percent: default_union_rent_increase_threshold_percent(),
+            union_dialogue_cost: default_union_dialogue_cost(),
+            union_demand_cost: default_union_demand_cost(),
+            affordable_mandate_percent: default_affordable_mandate_percent(),
+            strike_after_ignored_demands: default_strike_after_ignored_demands(),
+            rent_strike_duration: default_rent_strike_duration(),
+            rent_strike_extension: default_rent_strike_extension(),
+            strike_settlement_happiness: default_strike_settlement_happiness(),
+            strike_rent_cap_percent: default_strike_rent_cap_percent(),
+            strike_min_condition: default_strike_min_condition(),
         }
     }
 }
@@ -74,6 +165,294 @@ pub struct RegulationsConfig {
     pub neighborhood_reputation_penalty: i32,
     /// Visible neighborhood reputation gained on a fully clean inspection.
     pub neighborhood_reputation_gain: i32,
+
+    // Fire safety
+    /// Fire safety lost every month as equipment ages.
+    #[serde(default = "default_fire_safety_decay")]
+    pub fire_safety_decay: i32,
+    /// Score the annual fire safety check requires.
+    #[serde(default = "default_fire_safety_pass_threshold")]
+    pub fire_safety_pass_threshold: i32,
+    /// Fine per point the building falls short of the threshold.
+    #[serde(default = "default_fire_safety_fine_per_point")]
+    pub fire_safety_fine_per_point: i32,
+    /// Months given to fix a failed fire safety check.
+    #[serde(default = "default_fire_safety_fix_deadline_months")]
+    pub fire_safety_fix_deadline_months: u32,
+
+    // Accessibility
+    /// Buildings built after this year must be ADA compliant.
+    #[serde(default = "default_ada_required_built_after")]
+    pub ada_required_built_after: u32,
+    /// Neighborhood types (by name) where every building must be ADA compliant.
+    #[serde(default = "default_ada_required_neighborhoods")]
+    pub ada_required_neighborhoods: Vec<String>,
+
+    // Housing vouchers
+    /// Percent chance per month the city launches its voucher program.
+    #[serde(default = "default_voucher_program_chance_percent")]
+    pub voucher_program_chance_percent: i32,
+    /// Fine for turning away a qualifying voucher holder while the program runs.
+    #[serde(default = "default_voucher_discrimination_fine")]
+    pub voucher_discrimination_fine: i32,
+
+    // Energy mandate
+    /// Neighborhood types (by name) where the city can mandate an upgrade.
+    #[serde(default = "default_energy_mandate_neighborhoods")]
+    pub energy_mandate_neighborhoods: Vec<String>,
+    /// Building flag the mandate requires.
+    #[serde(default = "default_energy_mandate_upgrade")]
+    pub energy_mandate_upgrade: String,
+    /// Percent chance per month a mandate is issued for an eligible building.
+    #[serde(default = "default_energy_mandate_chance_percent")]
+    pub energy_mandate_chance_percent: i32,
+    /// Months given to comply.
+    #[serde(default = "default_energy_mandate_months")]
+    pub energy_mandate_months: u32,
+    /// Fine charged every month past the deadline until the building complies.
+    #[serde(default = "default_energy_mandate_monthly_fine")]
+    pub energy_mandate_monthly_fine: i32,
+
+    // Affordable unit mandate
+    /// Percent chance per month the city enacts its affordable unit mandate.
+    #[serde(default = "default_affordable_mandate_chance_percent")]
+    pub affordable_mandate_chance_percent: i32,
+    /// Monthly fine per unit the building is short of the mandate.
+    #[serde(default = "default_affordable_mandate_fine_per_unit")]
+    pub affordable_mandate_fine_per_unit: i32,
+
+    // Just-cause eviction
+    /// Percent chance per month the city passes a just-cause eviction law.
+    #[serde(default = "default_just_cause_chance_percent")]
+    pub just_cause_chance_percent: i32,
+    /// Fine for attempting an eviction without a valid cause under the law.
+    #[serde(default = "default_just_cause_violation_fine")]
+    pub just_cause_violation_fine: i32,
+
+    // Relocation assistance
+    /// Percent chance per month the city makes relocation assistance mandatory.
+    #[serde(default = "default_relocation_law_chance_percent")]
+    pub relocation_law_chance_percent: i32,
+    /// Fine for displacing a tenant without relocation assistance under the law.
+    #[serde(default = "default_relocation_bypass_fine")]
+    pub relocation_bypass_fine: i32,
+
+    // Rent freeze
+    /// Percent chance per month the city declares an emergency rent freeze.
+    #[serde(default = "default_rent_freeze_chance_percent")]
+    pub rent_freeze_chance_percent: i32,
+    /// Months a rent freeze lasts.
+    #[serde(default = "default_rent_freeze_duration")]
+    pub rent_freeze_duration: u32,
+    /// Rent increase offered once the freeze lifts (0.1 = 10%).
+    #[serde(default = "default_rent_freeze_catch_up_percent")]
+    pub rent_freeze_catch_up_percent: f32,
+
+    // Repair deadlines
+    /// Months to repair a unit cited for a health violation.
+    #[serde(default = "default_repair_deadline_months")]
+    pub repair_deadline_months: u32,
+    /// Monthly fine once a repair deadline passes; doubles every 3 months.
+    #[serde(default = "default_repair_fine_per_tick")]
+    pub repair_fine_per_tick: i32,
+
+    // Landlord license
+    /// Fire safety score a building must exceed to renew its license.
+    #[serde(default = "default_license_min_fire_safety")]
+    pub license_min_fire_safety: i32,
+
+    // Fair housing
+    /// Settlement for a fair housing investigation, and the fine for turning
+    /// away the archetype it requires be accepted.
+    #[serde(default = "default_fair_housing_fine")]
+    pub fair_housing_fine: i32,
+    /// Application decisions the fair housing check looks back over.
+    #[serde(default = "default_fair_housing_log_len")]
+    pub fair_housing_log_len: usize,
+    /// Share of rejections one archetype must exceed to look deliberate.
+    #[serde(default = "default_fair_housing_pattern_share")]
+    pub fair_housing_pattern_share: f32,
+    /// Distinct units the rejections must span, so one picky listing isn't
+    /// a pattern.
+    #[serde(default = "default_fair_housing_pattern_min_units")]
+    pub fair_housing_pattern_min_units: usize,
+    /// Decisions older than this many months no longer count toward a pattern.
+    #[serde(default = "default_fair_housing_pattern_window_months")]
+    pub fair_housing_pattern_window_months: u32,
+
+    // Rent increase notice
+    /// Percent chance per month the city passes the rent increase notice law.
+    #[serde(default = "default_rent_notice_law_chance_percent")]
+    pub rent_notice_law_chance_percent: i32,
+    /// Largest increase allowed without notice under the law (0.05 = 5%).
+    #[serde(default = "default_max_immediate_rent_increase")]
+    pub max_immediate_rent_increase: f32,
+    /// Percent chance per month a tenant priced out by an announced increase
+    /// moves out before it takes effect.
+    #[serde(default = "default_rent_notice_leave_chance_percent")]
+    pub rent_notice_leave_chance_percent: i32,
+
+    /// Percent chance per month the city grants tenants a right of first
+    /// refusal on building sales.
+    #[serde(default = "default_first_refusal_law_chance_percent")]
+    pub first_refusal_law_chance_percent: i32,
+    /// Months a tenant must have lived in the building to hold the right.
+    #[serde(default = "default_first_refusal_min_months")]
+    pub first_refusal_min_months: u32,
+    /// Rent reliability a tenant needs to be able to finance the purchase.
+    #[serde(default = "default_first_refusal_min_reliability")]
+    pub first_refusal_min_reliability: i32,
+    /// Months a contested claim holds up the sale.
+    #[serde(default = "default_first_refusal_contest_months")]
+    pub first_refusal_contest_months: u32,
+}
+
+fn default_first_refusal_law_chance_percent() -> i32 {
+    2
+}
+
+fn default_first_refusal_min_months() -> u32 {
+    36
+}
+
+fn default_first_refusal_min_reliability() -> i32 {
+    70
+}
+
+fn default_first_refusal_contest_months() -> u32 {
+    3
+}
+
+fn default_fair_housing_fine() -> i32 {
+    2500
+}
+
+fn default_fair_housing_log_len() -> usize {
+    10
+}
+
+fn default_fair_housing_pattern_share() -> f32 {
+    0.8
+}
+
+fn default_fair_housing_pattern_min_units() -> usize {
+    3
+}
+
+fn default_fair_housing_pattern_window_months() -> u32 {
+    12
+}
+
+fn default_rent_notice_law_chance_percent() -> i32 {
+    2
+}
+
+fn default_max_immediate_rent_increase() -> f32 {
+    0.05
+}
+
+fn default_rent_notice_leave_chance_percent() -> i32 {
+    25
+}
+
+fn default_license_min_fire_safety() -> i32 {
+    40
+}
+
+fn default_repair_deadline_months() -> u32 {
+    3
+}
+
+fn default_repair_fine_per_tick() -> i32 {
+    100
+}
+
+fn default_rent_freeze_chance_percent() -> i32 {
+    2
+}
+
+fn default_rent_freeze_duration() -> u32 {
+    6
+}
+
+fn default_rent_freeze_catch_up_percent() -> f32 {
+    0.1
+}
+
+fn default_relocation_law_chance_percent() -> i32 {
+    2
+}
+
+fn default_relocation_bypass_fine() -> i32 {
+    1500
+}
+
+fn default_just_cause_chance_percent() -> i32 {
+    2
+}
+
+fn default_just_cause_violation_fine() -> i32 {
+    500
+}
+
+fn default_affordable_mandate_chance_percent() -> i32 {
+    2
+}
+
+fn default_affordable_mandate_fine_per_unit() -> i32 {
+    300
+}
+
+fn default_energy_mandate_neighborhoods() -> Vec<String> {
+    vec!["Downtown".to_string(), "Suburbs".to_string()]
+}
+
+fn default_energy_mandate_upgrade() -> String {
+    "hvac".to_string()
+}
+
+fn default_energy_mandate_chance_percent() -> i32 {
+    3
+}
+
+fn default_energy_mandate_months() -> u32 {
+    12
+}
+
+fn default_energy_mandate_monthly_fine() -> i32 {
+    500
+}
+
+fn default_voucher_program_chance_percent() -> i32 {
+    2
+}
+
+fn default_voucher_discrimination_fine() -> i32 {
+    2000
+}
+
+fn default_ada_required_built_after() -> u32 {
+    1990
+}
+
+fn default_ada_required_neighborhoods() -> Vec<String> {
+    vec!["Downtown".to_string()]
+}
+
+fn default_fire_safety_decay() -> i32 {
+    2
+}
+
+fn default_fire_safety_pass_threshold() -> i32 {
+    60
+}
+
+fn default_fire_safety_fine_per_point() -> i32 {
+    100
+}
+
+fn default_fire_safety_fix_deadline_months() -> u32 {
+    3
 }
 
 impl Default for RegulationsConfig {
@@ -87,6 +466,139 @@ impl Default for RegulationsConfig {
             compliance_gain_on_pass: 5,
             neighborhood_reputation_penalty: 4,
             neighborhood_reputation_gain: 1,
+            fire_safety_decay: default_fire_safety_decay(),
+            fire_safety_pass_threshold: default_fire_safety_pass_threshold(),
+            fire_safety_fine_per_point: default_fire_safety_fine_per_point(),
+            fire_safety_fix_deadline_months: default_fire_safety_fix_deadline_months(),
+            ada_required_built_after: default_ada_required_built_after(),
+            ada_required_neighborhoods: default_ada_required_neighborhoods(),
+            voucher_program_chance_percent: default_voucher_program_chance_percent(),
+            voucher_discrimination_fine: default_voucher_discrimination_fine(),
+            energy_mandate_neighborhoods: default_energy_mandate_neighborhoods(),
+            energy_mandate_upgrade: default_energy_mandate_upgrade(),
+            energy_mandate_chance_percent: default_energy_mandate_chance_percent(),
+            energy_mandate_months: default_energy_mandate_months(),
+            energy_mandate_monthly_fine: default_energy_mandate_monthly_fine(),
+            affordable_mandate_chance_percent: default_affordable_mandate_chance_percent(),
+            affordable_mandate_fine_per_unit: default_affordable_mandate_fine_per_unit(),
+            just_cause_chance_percent: default_just_cause_chance_percent(),
+            just_cause_violation_fine: default_just_cause_violation_fine(),
+            relocation_law_chance_percent: default_relocation_law_chance_percent(),
+            relocation_bypass_fine: default_relocation_bypass_fine(),
+            rent_freeze_chance_percent: default_rent_freeze_chance_percent(),
+            rent_freeze_duration: default_rent_freeze_duration(),
+            rent_freeze_catch_up_percent: default_rent_freeze_catch_up_percent(),
+            repair_deadline_months: default_repair_deadline_months(),
+            repair_fine_per_tick: default_repair_fine_per_tick(),
+            license_min_fire_safety: default_license_min_fire_safety(),
+            fair_housing_fine: default_fair_housing_fine(),
+            fair_housing_log_len: default_fair_housing_log_len(),
+            fair_housing_pattern_share: default_fair_housing_pattern_share(),
+            fair_housing_pattern_min_units: default_fair_housing_pattern_min_units(),
+            fair_housing_pattern_window_months: default_fair_housing_pattern_window_months(),
+            rent_notice_law_chance_percent: default_rent_notice_law_chance_percent(),
+            max_immediate_rent_increase: default_max_immediate_rent_increase(),
+            rent_notice_leave_chance_percent: default_rent_notice_leave_chance_percent(),
+            first_refusal_law_chance_percent: default_first_refusal_law_chance_percent(),
+            first_refusal_min_months: default_first_refusal_min_months(),
+            first_refusal_min_reliability: default_first_refusal_min_reliability(),
+            first_refusal_contest_months: default_first_refusal_contest_months(),
+        }
+    }
+}
+
+/// Leaks that start in an upper unit and work their way down the floors.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WaterDamageConfig {
+    /// Monthly chance (percent) a leak starts while none is running.
+    #[serde(default = "default_water_damage_start_chance")]
+    pub start_chance: i32,
+    /// Condition knocked off the unit where the leak starts.
+    #[serde(default = "default_water_damage_initial_damage")]
+    pub initial_damage: i32,
+    /// Monthly chance (percent) a damaged unit leaks into the one below.
+    #[serde(default = "default_water_damage_spread_chance")]
+    pub spread_chance: i32,
+    /// Condition knocked off each unit the leak spreads to.
+    #[serde(default = "default_water_damage_spread_damage")]
+    pub spread_damage: i32,
+    /// Ticks to repair every damaged unit before it's a health violation.
+    #[serde(default = "default_water_damage_repair_window")]
+    pub repair_window: u32,
+}
+
+fn default_water_damage_start_chance() -> i32 {
+    1
+}
+
+fn default_water_damage_initial_damage() -> i32 {
+    20
+}
+
+fn default_water_damage_spread_chance() -> i32 {
+    50
+}
+
+fn default_water_damage_spread_damage() -> i32 {
+    10
+}
+
+fn default_water_damage_repair_window() -> u32 {
+    5
+}
+
+impl Default for WaterDamageConfig {
+    fn default() -> Self {
+        Self {
+            start_chance: default_water_damage_start_chance(),
+            initial_damage: default_water_damage_initial_damage(),
+            spread_chance: default_water_damage_spread_chance(),
+            spread_damage: default_water_damage_spread_damage(),
+            repair_window: default_water_damage_repair_window(),
+        }
+    }
+}
+
+/// The city asking to preserve an old, handsome historic building.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LandmarkConfig {
+    /// Building appeal needed before the city proposes designation.
+    #[serde(default = "default_landmark_min_appeal")]
+    pub min_appeal: i32,
+    /// Building age (years) past which the city proposes designation.
+    #[serde(default = "default_landmark_min_age")]
+    pub min_age: u32,
+    /// Ticks after a declined proposal before the city asks again.
+    #[serde(default = "default_landmark_retry_ticks")]
+    pub retry_ticks: u32,
+    /// Appeal a landmark keeps on top of the usual 100-point ceiling.
+    #[serde(default = "default_landmark_appeal")]
+    pub appeal: i32,
+}
+
+fn default_landmark_min_appeal() -> i32 {
+    80
+}
+
+fn default_landmark_min_age() -> u32 {
+    30
+}
+
+fn default_landmark_retry_ticks() -> u32 {
+    12
+}
+
+fn default_landmark_appeal() -> i32 {
+    10
+}
+
+impl Default for LandmarkConfig {
+    fn default() -> Self {
+        Self {
+            min_appeal: default_landmark_min_appeal(),
+            min_age: default_landmark_min_age(),
+            retry_ticks: default_landmark_retry_ticks(),
+            appeal: default_landmark_appeal(),
         }
     }
 }
@@ -106,6 +618,41 @@ pub struct CriticalFailureConfig {
     pub structural_repair_cost: i32,
     /// Extra repair cost added per full year of aging (applied to both types).
     pub aging_cost_per_year: i32,
+    /// Building age (years) past which an inspection can turn up lead paint.
+    #[serde(default = "default_lead_paint_age")]
+    pub lead_paint_age: u32,
+    /// Cost of abating lead paint throughout the building.
+    #[serde(default = "default_lead_paint_remediation_cost")]
+    pub lead_paint_remediation_cost: i32,
+    /// Building age (years) past which the structure starts to weaken.
+    #[serde(default = "default_structural_weakening_age")]
+    pub structural_weakening_age: u32,
+    /// Cost of reinforcing a weakened structure.
+    #[serde(default = "default_structural_reinforcement_cost")]
+    pub structural_reinforcement_cost: i32,
+    /// Percent cut to failure probability when security cameras deter thieves.
+    #[serde(default = "default_security_camera_reduction_percent")]
+    pub security_camera_reduction_percent: i32,
+}
+
+fn default_security_camera_reduction_percent() -> i32 {
+    25
+}
+
+fn default_lead_paint_age() -> u32 {
+    30
+}
+
+fn default_lead_paint_remediation_cost() -> i32 {
+    6000
+}
+
+fn default_structural_weakening_age() -> u32 {
+    40
+}
+
+fn default_structural_reinforcement_cost() -> i32 {
+    9000
 }
 
 impl Default for CriticalFailureConfig {
@@ -116,6 +663,11 @@ impl Default for CriticalFailureConfig {
             boiler_repair_cost: 1500,
             structural_repair_cost: 2500,
             aging_cost_per_year: 350,
+            lead_paint_age: default_lead_paint_age(),
+            lead_paint_remediation_cost: default_lead_paint_remediation_cost(),
+            structural_weakening_age: default_structural_weakening_age(),
+            structural_reinforcement_cost: default_structural_reinforcement_cost(),
+            security_camera_reduction_percent: default_security_camera_reduction_percent(),
         }
     }
 }
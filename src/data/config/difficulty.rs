@@ -1,5 +1,6 @@
 //! Per-difficulty rule modifiers and the one-time application of a tier's
-//! rules when a game is created from a building template.
+//! rules when a game is created from a building template, plus the
+//! player-chosen difficulty preset layered on top.
 
 use serde::{Deserialize, Serialize};
 
@@ -23,7 +24,79 @@ pub struct DifficultyModifiers {
     pub operating_cost_multiplier: f32,
 }
 
+/// Difficulty the player picks on the menu before choosing a building. It
+/// stacks on top of the building tier's `DifficultyModifiers`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DifficultyPreset {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    /// Normal rules, but no manual saves: only the monthly autosave.
+    Ironman,
+}
+
+impl DifficultyPreset {
+    pub const ALL: [DifficultyPreset; 4] = [
+        DifficultyPreset::Easy,
+        DifficultyPreset::Normal,
+        DifficultyPreset::Hard,
+        DifficultyPreset::Ironman,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DifficultyPreset::Easy => "Easy",
+            DifficultyPreset::Normal => "Normal",
+            DifficultyPreset::Hard => "Hard",
+            DifficultyPreset::Ironman => "Ironman",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            DifficultyPreset::Easy => "Double funds, slower decay, more applicants",
+            DifficultyPreset::Normal => "The building as designed",
+            DifficultyPreset::Hard => "Half funds, faster decay, more failures",
+            DifficultyPreset::Ironman => "Normal rules, autosave only",
+        }
+    }
+
+    pub fn allows_manual_save(self) -> bool {
+        self != DifficultyPreset::Ironman
+    }
+
+    /// Adjust the building tier's starting funds for this preset.
+    pub fn starting_funds(self, tier_funds: i32) -> i32 {
+        match self {
+            DifficultyPreset::Easy => tier_funds * 2,
+            DifficultyPreset::Hard => tier_funds / 2,
+            DifficultyPreset::Normal | DifficultyPreset::Ironman => tier_funds,
+        }
+    }
+}
+
 impl GameConfig {
+    /// Overlay `preset`'s rule changes in place. Decay is halved rounding up so
+    /// it never stops entirely. Applied after `apply_difficulty`, and again
+    /// whenever the config is reloaded.
+    pub fn apply_preset(&mut self, preset: DifficultyPreset) {
+        match preset {
+            DifficultyPreset::Easy => {
+                self.decay.apartment_per_tick = (self.decay.apartment_per_tick + 1) / 2;
+                self.decay.hallway_per_tick = (self.decay.hallway_per_tick + 1) / 2;
+                self.applications.base_per_vacancy *= 3.0;
+            }
+            DifficultyPreset::Hard => {
+                self.decay.apartment_per_tick *= 2;
+                self.decay.hallway_per_tick *= 2;
+                self.critical_failures.base_probability_per_1000 *= 2;
+                self.critical_failures.aging_probability_per_year *= 2;
+            }
+            DifficultyPreset::Normal | DifficultyPreset::Ironman => {}
+        }
+    }
+
     /// Apply the modifiers for `difficulty` (case-insensitive) in place and
     /// return the tier's starting funds (falling back to 5000 if the tier is
     /// not configured). Called once when a game is created from a template.
@@ -76,6 +149,23 @@ mod tests {
         assert!(easy.regulations.fine_multiplier < medium.regulations.fine_multiplier);
     }
 
+    #[test]
+    fn easy_preset_doubles_funds_and_eases_decay() {
+        let mut config = GameConfig::default();
+        let base_decay = config.decay.apartment_per_tick;
+        let base_applications = config.applications.base_per_vacancy;
+        config.apply_preset(DifficultyPreset::Easy);
+        assert!(config.decay.apartment_per_tick < base_decay);
+        assert!(config.decay.hallway_per_tick >= 1);
+        assert_eq!(
+            config.applications.base_per_vacancy,
+            base_applications * 3.0
+        );
+        assert_eq!(DifficultyPreset::Easy.starting_funds(5000), 10000);
+        assert_eq!(DifficultyPreset::Hard.starting_funds(5000), 2500);
+        assert!(!DifficultyPreset::Ironman.allows_manual_save());
+    }
+
     #[test]
     fn unknown_difficulty_defaults_to_5000_without_changes() {
         let mut config = GameConfig::default();
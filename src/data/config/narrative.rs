@@ -0,0 +1,189 @@
+//! Tuning for how often narrative events fire over the course of a game.
+
+use serde::{Deserialize, Serialize};
+
+/// The separate random rolls `NarrativeEventSystem::generate_events` makes
+/// each month.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventRoll {
+    NeighborhoodNews,
+    CityNews,
+    Transit,
+    Seasonal,
+    Offer,
+    Protest,
+}
+
+/// Phase- and state-based multipliers on the monthly event rolls. Early games
+/// lean on gentle local news, while late games and gentrification crises
+/// escalate with developer offers and protests.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventProbabilityConfig {
+    /// Ticks before this count as the early game.
+    #[serde(default = "default_probability_early_game_until_tick")]
+    pub early_game_until_tick: u32,
+    /// Ticks from this on count as the late game.
+    #[serde(default = "default_probability_late_game_from_tick")]
+    pub late_game_from_tick: u32,
+    /// Applied to neighborhood news in the early game.
+    #[serde(default = "default_probability_early_news_multiplier")]
+    pub early_news_multiplier: f32,
+    /// Applied to every other roll in the early game.
+    #[serde(default = "default_probability_early_game_multiplier")]
+    pub early_game_multiplier: f32,
+    /// Applied to offers and protests in the late game.
+    #[serde(default = "default_probability_late_game_multiplier")]
+    pub late_game_multiplier: f32,
+    /// Gentrification score above which the city is in crisis.
+    #[serde(default = "default_probability_crisis_gentrification_threshold")]
+    pub crisis_gentrification_threshold: i32,
+    /// Applied to offers and protests during a crisis (stacks with late game).
+    #[serde(default = "default_probability_crisis_multiplier")]
+    pub crisis_multiplier: f32,
+}
+
+fn default_probability_early_game_until_tick() -> u32 {
+    12
+}
+
+fn default_probability_late_game_from_tick() -> u32 {
+    36
+}
+
+fn default_probability_early_news_multiplier() -> f32 {
+    1.5
+}
+
+fn default_probability_early_game_multiplier() -> f32 {
+    0.5
+}
+
+fn default_probability_late_game_multiplier() -> f32 {
+    1.5
+}
+
+fn default_probability_crisis_gentrification_threshold() -> i32 {
+    70
+}
+
+fn default_probability_crisis_multiplier() -> f32 {
+    1.5
+}
+
+impl Default for EventProbabilityConfig {
+    fn default() -> Self {
+        Self {
+            early_game_until_tick: default_probability_early_game_until_tick(),
+            late_game_from_tick: default_probability_late_game_from_tick(),
+            early_news_multiplier: default_probability_early_news_multiplier(),
+            early_game_multiplier: default_probability_early_game_multiplier(),
+            late_game_multiplier: default_probability_late_game_multiplier(),
+            crisis_gentrification_threshold: default_probability_crisis_gentrification_threshold(),
+            crisis_multiplier: default_probability_crisis_multiplier(),
+        }
+    }
+}
+
+impl EventProbabilityConfig {
+    pub fn multiplier(&self, roll: EventRoll, month: u32, gentrification_score: i32) -> f32 {
+        let escalates = matches!(roll, EventRoll::Offer | EventRoll::Protest);
+        let mut multiplier = 1.0;
+
+        if month < self.early_game_until_tick {
+            multiplier *= if roll == EventRoll::NeighborhoodNews {
+                self.early_news_multiplier
+            } else {
+                self.early_game_multiplier
+            };
+        }
+        if escalates && month >= self.late_game_from_tick {
+            multiplier *= self.late_game_multiplier;
+        }
+        if escalates && gentrification_score > self.crisis_gentrification_threshold {
+            multiplier *= self.crisis_multiplier;
+        }
+        multiplier
+    }
+
+    /// Scale a base percent chance for this month, capped at 100.
+    pub fn scaled_chance(
+        &self,
+        base_percent: i32,
+        roll: EventRoll,
+        month: u32,
+        gentrification_score: i32,
+    ) -> i32 {
+        ((base_percent as f32 * self.multiplier(roll, month, gentrification_score)).round() as i32)
+            .clamp(0, 100)
+    }
+}
+
+/// Summer heatwaves and winter storms pushing up utility rates.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UtilitySpikeConfig {
+    /// Odds per month, in summer and winter, of a rate spike.
+    #[serde(default = "default_utility_spike_chance_percent")]
+    pub chance_percent: i32,
+    /// Utility cost multiplier during a summer heatwave.
+    #[serde(default = "default_heatwave_multiplier")]
+    pub heatwave_multiplier: f32,
+    /// Utility cost multiplier during a winter storm.
+    #[serde(default = "default_winter_storm_multiplier")]
+    pub winter_storm_multiplier: f32,
+    /// Months a spike lasts before rates return to normal.
+    #[serde(default = "default_utility_spike_duration_ticks")]
+    pub duration_ticks: u32,
+}
+
+fn default_utility_spike_chance_percent() -> i32 {
+    15
+}
+
+fn default_heatwave_multiplier() -> f32 {
+    1.5
+}
+
+fn default_winter_storm_multiplier() -> f32 {
+    2.0
+}
+
+fn default_utility_spike_duration_ticks() -> u32 {
+    2
+}
+
+impl Default for UtilitySpikeConfig {
+    fn default() -> Self {
+        Self {
+            chance_percent: default_utility_spike_chance_percent(),
+            heatwave_multiplier: default_heatwave_multiplier(),
+            winter_storm_multiplier: default_winter_storm_multiplier(),
+            duration_ticks: default_utility_spike_duration_ticks(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn early_game_favors_local_news() {
+        let config = EventProbabilityConfig::default();
+        assert_eq!(
+            config.scaled_chance(20, EventRoll::NeighborhoodNews, 3, 0),
+            30
+        );
+        assert_eq!(config.scaled_chance(10, EventRoll::CityNews, 3, 0), 5);
+        assert_eq!(config.scaled_chance(10, EventRoll::CityNews, 20, 0), 10);
+    }
+
+    #[test]
+    fn late_crisis_escalates_offers_and_protests() {
+        let config = EventProbabilityConfig::default();
+        let calm = config.multiplier(EventRoll::Offer, 40, 10);
+        let crisis = config.multiplier(EventRoll::Offer, 40, 80);
+        assert!((calm - 1.5).abs() < f32::EPSILON);
+        assert!((crisis - 2.25).abs() < f32::EPSILON);
+        assert!((config.multiplier(EventRoll::Seasonal, 40, 80) - 1.0).abs() < f32::EPSILON);
+    }
+}
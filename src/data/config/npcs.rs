@@ -0,0 +1,169 @@
+//! Tuning for the NPCs who call on the building on their own schedule.
+
+use serde::{Deserialize, Serialize};
+
+/// The city's building inspector and the report cards they hand out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InspectorConfig {
+    /// Months between visits.
+    #[serde(default = "default_visit_interval")]
+    pub visit_interval: u32,
+    /// A visit can land up to this many months early or late.
+    #[serde(default = "default_visit_jitter")]
+    pub visit_jitter: u32,
+    /// Fine for a D or F report card.
+    #[serde(default = "default_failing_grade_fine")]
+    pub failing_grade_fine: i32,
+}
+
+fn default_visit_interval() -> u32 {
+    6
+}
+
+fn default_visit_jitter() -> u32 {
+    2
+}
+
+fn default_failing_grade_fine() -> i32 {
+    750
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        Self {
+            visit_interval: default_visit_interval(),
+            visit_jitter: default_visit_jitter(),
+            failing_grade_fine: default_failing_grade_fine(),
+        }
+    }
+}
+
+/// The market analyst and their rent reports.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MarketAnalystConfig {
+    /// Month the analyst starts work.
+    #[serde(default = "default_available_from_tick")]
+    pub available_from_tick: u32,
+    /// Months between reports.
+    #[serde(default = "default_report_interval")]
+    pub report_interval: u32,
+}
+
+fn default_available_from_tick() -> u32 {
+    3
+}
+
+fn default_report_interval() -> u32 {
+    6
+}
+
+impl Default for MarketAnalystConfig {
+    fn default() -> Self {
+        Self {
+            available_from_tick: default_available_from_tick(),
+            report_interval: default_report_interval(),
+        }
+    }
+}
+
+/// Salaries and repair rules for a hired property manager.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PropertyManagerConfig {
+    /// Monthly salary at each automation level.
+    #[serde(default = "default_basic_salary")]
+    pub basic_salary: i32,
+    #[serde(default = "default_advanced_salary")]
+    pub advanced_salary: i32,
+    #[serde(default = "default_full_salary")]
+    pub full_salary: i32,
+    /// Units below this condition get repaired automatically.
+    #[serde(default = "default_repair_below_condition")]
+    pub repair_below_condition: i32,
+    /// Condition the manager repairs those units up to.
+    #[serde(default = "default_repair_to_condition")]
+    pub repair_to_condition: i32,
+}
+
+fn default_basic_salary() -> i32 {
+    400
+}
+
+fn default_advanced_salary() -> i32 {
+    700
+}
+
+fn default_full_salary() -> i32 {
+    1000
+}
+
+fn default_repair_below_condition() -> i32 {
+    50
+}
+
+fn default_repair_to_condition() -> i32 {
+    70
+}
+
+impl Default for PropertyManagerConfig {
+    fn default() -> Self {
+        Self {
+            basic_salary: default_basic_salary(),
+            advanced_salary: default_advanced_salary(),
+            full_salary: default_full_salary(),
+            repair_below_condition: default_repair_below_condition(),
+            repair_to_condition: default_repair_to_condition(),
+        }
+    }
+}
+
+/// How often the local journalist files, and what makes a story.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JournalistConfig {
+    /// Months between articles.
+    #[serde(default = "default_article_interval")]
+    pub article_interval: u32,
+    /// Gentrification score at which the paper runs a displacement story.
+    #[serde(default = "default_displacement_gentrification")]
+    pub displacement_gentrification: i32,
+    /// Average tenant happiness a full building needs for a glowing profile.
+    #[serde(default = "default_model_landlord_happiness")]
+    pub model_landlord_happiness: i32,
+    /// Appeal a story moves before the journalist's disposition is counted.
+    #[serde(default = "default_story_appeal")]
+    pub story_appeal: i32,
+    /// Disposition gained from an exclusive interview.
+    #[serde(default = "default_interview_disposition")]
+    pub interview_disposition: i32,
+}
+
+fn default_article_interval() -> u32 {
+    6
+}
+
+fn default_displacement_gentrification() -> i32 {
+    60
+}
+
+fn default_model_landlord_happiness() -> i32 {
+    75
+}
+
+fn default_story_appeal() -> i32 {
+    6
+}
+
+fn default_interview_disposition() -> i32 {
+    15
+}
+
+impl Default for JournalistConfig {
+    fn default() -> Self {
+        Self {
+            article_interval: default_article_interval(),
+            displacement_gentrification: default_displacement_gentrification(),
+            model_landlord_happiness: default_model_landlord_happiness(),
+            story_appeal: default_story_appeal(),
+            interview_disposition: default_interview_disposition(),
+        }
+    }
+}
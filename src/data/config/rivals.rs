@@ -0,0 +1,96 @@
+//! Tuning for the competing landlords' buildings on the city map.
+
+use serde::{Deserialize, Serialize};
+
+/// Where rival buildings start out and how they drift month to month.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RivalsConfig {
+    /// Lowest appeal a rival building is seeded with.
+    #[serde(default = "default_rival_starting_appeal_min")]
+    pub starting_appeal_min: i32,
+    /// Highest appeal a rival building is seeded with.
+    #[serde(default = "default_rival_starting_appeal_max")]
+    pub starting_appeal_max: i32,
+    /// Lowest base rent before neighborhood demand is applied.
+    #[serde(default = "default_rival_starting_rent_min")]
+    pub starting_rent_min: i32,
+    /// Highest base rent before neighborhood demand is applied.
+    #[serde(default = "default_rival_starting_rent_max")]
+    pub starting_rent_max: i32,
+    /// Appeal a rival never falls below.
+    #[serde(default = "default_rival_min_appeal")]
+    pub min_appeal: i32,
+    /// Rent a rival never drops below.
+    #[serde(default = "default_rival_min_rent")]
+    pub min_rent: i32,
+    /// Largest monthly swing in appeal, either way.
+    #[serde(default = "default_rival_monthly_appeal_drift")]
+    pub monthly_appeal_drift: i32,
+    /// Largest monthly rent cut.
+    #[serde(default = "default_rival_monthly_rent_drop")]
+    pub monthly_rent_drop: i32,
+    /// Largest monthly rent rise.
+    #[serde(default = "default_rival_monthly_rent_rise")]
+    pub monthly_rent_rise: i32,
+    /// Share of rent a rival cuts for each point of appeal a player's good
+    /// press earns.
+    #[serde(default = "default_rival_press_rent_cut_per_appeal")]
+    pub press_rent_cut_per_appeal: f32,
+}
+
+fn default_rival_starting_appeal_min() -> i32 {
+    40
+}
+
+fn default_rival_starting_appeal_max() -> i32 {
+    80
+}
+
+fn default_rival_starting_rent_min() -> i32 {
+    700
+}
+
+fn default_rival_starting_rent_max() -> i32 {
+    1300
+}
+
+fn default_rival_min_appeal() -> i32 {
+    10
+}
+
+fn default_rival_min_rent() -> i32 {
+    300
+}
+
+fn default_rival_monthly_appeal_drift() -> i32 {
+    2
+}
+
+fn default_rival_monthly_rent_drop() -> i32 {
+    15
+}
+
+fn default_rival_monthly_rent_rise() -> i32 {
+    20
+}
+
+fn default_rival_press_rent_cut_per_appeal() -> f32 {
+    0.01
+}
+
+impl Default for RivalsConfig {
+    fn default() -> Self {
+        Self {
+            starting_appeal_min: default_rival_starting_appeal_min(),
+            starting_appeal_max: default_rival_starting_appeal_max(),
+            starting_rent_min: default_rival_starting_rent_min(),
+            starting_rent_max: default_rival_starting_rent_max(),
+            min_appeal: default_rival_min_appeal(),
+            min_rent: default_rival_min_rent(),
+            monthly_appeal_drift: default_rival_monthly_appeal_drift(),
+            monthly_rent_drop: default_rival_monthly_rent_drop(),
+            monthly_rent_rise: default_rival_monthly_rent_rise(),
+            press_rent_cut_per_appeal: default_rival_press_rent_cut_per_appeal(),
+        }
+    }
+}
@@ -22,18 +22,259 @@ pub struct EconomyConfig {
     pub soundproofing_cost: i32,
     #[serde(default)]
     pub staff_costs: HashMap<String, i32>,
+    /// How strongly building appeal swings an appraisal: each appeal point
+    /// away from 50 moves the value by `weight / 100` (0.5 → ±25% at 0/100).
+    #[serde(default = "default_appraisal_appeal_weight")]
+    pub appraisal_appeal_weight: f32,
+    /// Months between price cuts on an unsold market listing.
+    #[serde(default = "default_listing_price_cut_interval")]
+    pub listing_price_cut_interval: u32,
+    /// What's left of an unsold listing's asking price after each cut.
+    #[serde(default = "default_listing_price_cut_factor")]
+    pub listing_price_cut_factor: f32,
+    /// Cost of stepping in to settle a feud between two tenants.
+    #[serde(default = "default_mediation_cost")]
+    pub mediation_cost: i32,
+    /// Per-unit cost of a standard-pace renovation of a condemned building.
+    #[serde(default = "default_renovation_cost_per_unit")]
+    pub renovation_cost_per_unit: i32,
+    /// Construction cost of knocking two adjacent units into one.
+    #[serde(default = "default_unit_merge_cost")]
+    pub unit_merge_cost: i32,
+    /// Monthly laundry room takings per occupied unit.
+    #[serde(default = "default_laundry_income_per_occupied_unit")]
+    pub laundry_income_per_occupied_unit: i32,
+    /// Monthly utility savings per unit once solar panels are installed.
+    #[serde(default = "default_solar_reduction_per_unit")]
+    pub solar_reduction_per_unit: i32,
+    /// Standard monthly storage locker rent per occupied unit.
+    #[serde(default = "default_storage_locker_income")]
+    pub storage_locker_income: i32,
+    /// Months a tenant must have lived in a unit before they can buy it.
+    #[serde(default = "default_lease_to_own_min_months")]
+    pub lease_to_own_min_months: u32,
+    /// Fraction of the condo sale price a long-term tenant pays.
+    #[serde(default = "default_lease_to_own_price_factor")]
+    pub lease_to_own_price_factor: f32,
+    /// Cost of having a squatter removed from a vacant unit.
+    #[serde(default = "default_squatter_removal_cost")]
+    pub squatter_removal_cost: i32,
+    /// Monthly preservation grant paid to a designated landmark.
+    #[serde(default = "default_landmark_subsidy")]
+    pub landmark_subsidy: i32,
+    /// Cost of fighting a sale the tenants voted for (legal fees, relocation
+    /// offers to buy back their support).
+    #[serde(default = "default_relocation_resistance_cost")]
+    pub relocation_resistance_cost: i32,
+    /// Legal fees for evicting a tenant.
+    #[serde(default = "default_tenant_eviction_cost")]
+    pub tenant_eviction_cost: i32,
+    /// Share of their rent (percent) a paying tenant puts toward unpaid rent
+    /// each month until the balance is cleared.
+    #[serde(default = "default_arrears_repayment_percent")]
+    pub arrears_repayment_percent: i32,
+    /// Months of rent paid to a tenant displaced by eviction or a condo sale.
+    #[serde(default = "default_relocation_assistance_months")]
+    pub relocation_assistance_months: u32,
+    /// Unit upgrades costing more than this need a building permit.
+    #[serde(default = "default_permit_cost_threshold")]
+    pub permit_cost_threshold: i32,
+    /// Fee for a standard permit application; fast-tracking costs double.
+    #[serde(default = "default_permit_fee")]
+    pub permit_fee: i32,
+    /// Months the city takes to approve a standard permit.
+    #[serde(default = "default_permit_processing_months")]
+    pub permit_processing_months: u32,
+    /// Annual landlord license renewal fee.
+    #[serde(default = "default_license_renewal_fee")]
+    pub license_renewal_fee: i32,
+    /// Deposit asked of a tenant whose pet is approved on that condition.
+    #[serde(default = "default_pet_deposit")]
+    pub pet_deposit: i32,
+    /// Rent increase proposed in a lease renewal offer (0.03 = 3%).
+    #[serde(default = "default_lease_renewal_increase")]
+    pub lease_renewal_increase: f32,
+    /// Months before a lease ends that the renewal offer is mailed.
+    #[serde(default = "default_lease_renewal_notice_months")]
+    pub lease_renewal_notice_months: u32,
+    /// Added to the monthly rent of tenants whose lease lapsed.
+    #[serde(default = "default_month_to_month_premium")]
+    pub month_to_month_premium: i32,
+    /// Legal fees for contesting a tenant's right of first refusal.
+    #[serde(default = "default_legal_contest_cost")]
+    pub legal_contest_cost: i32,
+    /// Deducted from a departing tenant's deposit per point of condition
+    /// lost since the move-in inspection.
+    #[serde(default = "default_deposit_deduction_per_point")]
+    pub deposit_deduction_per_point: i32,
+    /// Cost of boxing up and storing what an abandoning tenant left behind.
+    #[serde(default = "default_belongings_storage_cost")]
+    pub belongings_storage_cost: i32,
+}
+
+fn default_belongings_storage_cost() -> i32 {
+    150
+}
+
+fn default_deposit_deduction_per_point() -> i32 {
+    20
+}
+
+fn default_legal_contest_cost() -> i32 {
+    5000
+}
+
+fn default_month_to_month_premium() -> i32 {
+    50
+}
+
+fn default_lease_renewal_increase() -> f32 {
+    0.03
+}
+
+fn default_lease_renewal_notice_months() -> u32 {
+    3
+}
+
+fn default_pet_deposit() -> i32 {
+    300
+}
+
+fn default_license_renewal_fee() -> i32 {
+    200
+}
+
+fn default_permit_cost_threshold() -> i32 {
+    1000
+}
+
+fn default_permit_fee() -> i32 {
+    250
+}
+
+fn default_permit_processing_months() -> u32 {
+    3
+}
+
+fn default_relocation_assistance_months() -> u32 {
+    2
+}
+
+fn default_tenant_eviction_cost() -> i32 {
+    800
+}
+
+fn default_arrears_repayment_percent() -> i32 {
+    25
+}
+
+fn default_relocation_resistance_cost() -> i32 {
+    8000
+}
+
+fn default_landmark_subsidy() -> i32 {
+    400
+}
+
+fn default_squatter_removal_cost() -> i32 {
+    300
+}
+
+fn default_lease_to_own_min_months() -> u32 {
+    24
+}
+
+fn default_lease_to_own_price_factor() -> f32 {
+    0.8
+}
+
+fn default_appraisal_appeal_weight() -> f32 {
+    0.5
+}
+
+fn default_listing_price_cut_interval() -> u32 {
+    2
+}
+
+fn default_listing_price_cut_factor() -> f32 {
+    0.98
+}
+
+fn default_mediation_cost() -> i32 {
+    250
+}
+
+fn default_renovation_cost_per_unit() -> i32 {
+    2500
+}
+
+fn default_unit_merge_cost() -> i32 {
+    5000
+}
+
+fn default_laundry_income_per_occupied_unit() -> i32 {
+    15
+}
+
+fn default_solar_reduction_per_unit() -> i32 {
+    25
+}
+
+fn default_storage_locker_income() -> i32 {
+    20
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DecayConfig {
     pub apartment_per_tick: i32,
     pub hallway_per_tick: i32,
+    /// Apartment decay an HVAC-equipped building sheds each month.
+    #[serde(default = "default_hvac_decay_reduction")]
+    pub hvac_decay_reduction: i32,
+    /// Buildings older than this many years decay faster.
+    #[serde(default = "default_aging_threshold_years")]
+    pub aging_threshold_years: u32,
+    /// Extra decay per year past the threshold (0.02 = 2% each).
+    #[serde(default = "default_aging_decay_per_year")]
+    pub aging_decay_per_year: f32,
+}
+
+fn default_hvac_decay_reduction() -> i32 {
+    1
+}
+
+fn default_aging_threshold_years() -> u32 {
+    25
+}
+
+fn default_aging_decay_per_year() -> f32 {
+    0.02
+}
+
+impl DecayConfig {
+    /// This month's decay after a seasonal multiplier, rounded.
+    pub fn scaled(&self, multiplier: f32) -> DecayConfig {
+        DecayConfig {
+            apartment_per_tick: (self.apartment_per_tick as f32 * multiplier).round() as i32,
+            hallway_per_tick: (self.hallway_per_tick as f32 * multiplier).round() as i32,
+            ..self.clone()
+        }
+    }
+
+    /// Decay multiplier for a building of the given age.
+    pub fn age_multiplier(&self, age: u32) -> f32 {
+        1.0 + age.saturating_sub(self.aging_threshold_years) as f32 * self.aging_decay_per_year
+    }
 }
 
 fn default_leave_chance_percent() -> i32 {
     35
 }
 
+fn default_ada_elderly_leave_reduction_percent() -> i32 {
+    20
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HappinessConfig {
     pub base: i32,
@@ -43,6 +284,10 @@ pub struct HappinessConfig {
     /// Monthly chance (percent) that a tenant at/below `leave_threshold` actually leaves.
     #[serde(default = "default_leave_chance_percent")]
     pub leave_chance_percent: i32,
+    /// How much less likely (percent) elderly tenants are to leave an ADA
+    /// compliant building.
+    #[serde(default = "default_ada_elderly_leave_reduction_percent")]
+    pub ada_elderly_leave_reduction_percent: i32,
     pub unhappy_threshold: i32,
     pub tenure_bonus_max: i32,
 
@@ -71,16 +316,149 @@ pub struct HappinessConfig {
     // Hallway
     pub hallway_condition_base: i32,
     pub hallway_condition_multiplier: f32,
+
+    // Affordability
+    /// Highest rent each archetype (by id) can pay at all; applicants never
+    /// apply above it and lease offers over it are heavily penalized.
+    #[serde(default = "default_archetype_rent_caps")]
+    pub archetype_rent_caps: HashMap<String, i32>,
+
+    // Climate (summer and winter only)
+    #[serde(default = "default_hvac_comfort_bonus")]
+    pub hvac_comfort_bonus: i32,
+    /// Applied instead of the HVAC bonus when the building has none.
+    #[serde(default = "default_extreme_weather_penalty")]
+    pub extreme_weather_penalty: i32,
+
+    // Elevator
+    /// Happiness each archetype (by id) gets from living with an elevator.
+    #[serde(default = "default_elevator_happiness_bonus")]
+    pub elevator_happiness_bonus: HashMap<String, i32>,
+
+    /// Happiness every tenant gets per green certification tier.
+    #[serde(default = "default_green_certification_bonus")]
+    pub green_certification_bonus: i32,
+
+    // Storage lockers (scaled by each archetype's storage interest); the
+    // standard locker rent in the economy config is what tenants call fair.
+    /// Happiness from fairly priced lockers.
+    #[serde(default = "default_storage_happiness_bonus")]
+    pub storage_happiness_bonus: i32,
+    /// Every this many dollars over the standard rent costs a point of happiness.
+    #[serde(default = "default_storage_overcharge_step")]
+    pub storage_overcharge_step: i32,
+
+    /// Happiness each archetype (by id) gets from hallway art.
+    #[serde(default = "default_hallway_art_happiness_bonus")]
+    pub hallway_art_happiness_bonus: HashMap<String, i32>,
+    /// Monthly chance (percent) tenants talk up the hallway art.
+    #[serde(default = "default_hallway_art_praise_chance")]
+    pub hallway_art_praise_chance: i32,
+    /// The art only gets noticed when the hallway is better kept than this.
+    #[serde(default = "default_hallway_art_praise_min_hallway")]
+    pub hallway_art_praise_min_hallway: i32,
+
+    /// Applied to tenants renting month-to-month, who can be asked to leave
+    /// at any time.
+    #[serde(default = "default_month_to_month_penalty")]
+    pub month_to_month_penalty: i32,
+
+    /// Lost by every remaining tenant when a neighbor is evicted.
+    #[serde(default = "default_eviction_penalty")]
+    pub eviction_penalty: i32,
+
+    /// Lost by a quiet-hours pet owner when a neighbor complains about noise.
+    #[serde(default = "default_pet_noise_complaint_penalty")]
+    pub pet_noise_complaint_penalty: i32,
+}
+
+fn default_eviction_penalty() -> i32 {
+    5
+}
+
+fn default_pet_noise_complaint_penalty() -> i32 {
+    5
+}
+
+fn default_month_to_month_penalty() -> i32 {
+    -5
+}
+
+fn default_green_certification_bonus() -> i32 {
+    2
+}
+
+fn default_storage_happiness_bonus() -> i32 {
+    4
+}
+
+fn default_storage_overcharge_step() -> i32 {
+    5
+}
+
+pub fn default_elevator_happiness_bonus() -> HashMap<String, i32> {
+    [("elderly", 8), ("family", 5)]
+        .into_iter()
+        .map(|(id, bonus)| (id.to_string(), bonus))
+        .collect()
+}
+
+pub fn default_hallway_art_happiness_bonus() -> HashMap<String, i32> {
+    [("artist".to_string(), 3)].into_iter().collect()
+}
+
+fn default_hallway_art_praise_chance() -> i32 {
+    10
+}
+
+fn default_hallway_art_praise_min_hallway() -> i32 {
+    80
+}
+
+fn default_hvac_comfort_bonus() -> i32 {
+    5
+}
+
+fn default_extreme_weather_penalty() -> i32 {
+    -3
+}
+
+pub fn default_archetype_rent_caps() -> HashMap<String, i32> {
+    [
+        ("student", 900),
+        ("artist", 1100),
+        ("elderly", 1000),
+        ("family", 1400),
+        ("professional", 2000),
+    ]
+    .into_iter()
+    .map(|(id, cap)| (id.to_string(), cap))
+    .collect()
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WinConditions {
     pub full_occupancy_required: bool,
-    pub min_ticks_for_victory: u32,
     /// Game duration in ticks (months). After this many ticks, the game ends with a score.
     /// Defaults to 36 (3 years) if not specified.
     #[serde(default)]
     pub game_duration_ticks: Option<u32>,
+    /// Share of the final balance carried into the next building as New Game+
+    /// starting funds.
+    #[serde(default = "default_new_game_plus_funds_share")]
+    pub new_game_plus_funds_share: f32,
+    /// Share of reputation earned above neutral (50) carried into the next
+    /// building's neighborhood.
+    #[serde(default = "default_new_game_plus_reputation_share")]
+    pub new_game_plus_reputation_share: f32,
+}
+
+fn default_new_game_plus_funds_share() -> f32 {
+    0.1
+}
+
+fn default_new_game_plus_reputation_share() -> f32 {
+    0.5
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -93,6 +471,41 @@ pub struct ApplicationConfig {
     /// `1 - influence`, and at 100 it is `1 + influence`.
     #[serde(default = "default_reputation_influence")]
     pub reputation_influence: f32,
+    /// Pending applications a unit takes before new applicants are waitlisted.
+    #[serde(default = "default_max_per_unit")]
+    pub max_per_unit: usize,
+    /// Percent of applicants who want to meet the landlord before signing.
+    #[serde(default = "default_interview_chance_percent")]
+    pub interview_chance_percent: u32,
+    /// Percent of student applicants who arrive with a guarantor.
+    #[serde(default = "default_student_guarantor_chance_percent")]
+    pub student_guarantor_chance_percent: u32,
+    /// Percent of applicants who hold a housing voucher.
+    #[serde(default = "default_voucher_holder_chance_percent")]
+    pub voucher_holder_chance_percent: u32,
+    /// Share of a voucher holder's rent budget the city guarantees.
+    #[serde(default = "default_voucher_share")]
+    pub voucher_share: f32,
+}
+
+fn default_max_per_unit() -> usize {
+    3
+}
+
+fn default_interview_chance_percent() -> u32 {
+    20
+}
+
+fn default_student_guarantor_chance_percent() -> u32 {
+    60
+}
+
+fn default_voucher_holder_chance_percent() -> u32 {
+    12
+}
+
+fn default_voucher_share() -> f32 {
+    0.6
 }
 
 fn default_reputation_influence() -> f32 {
@@ -104,6 +517,13 @@ pub struct ThresholdsConfig {
     pub poor_condition: i32,
     pub critical_condition: i32,
     pub all_left_check_tick: u32,
+    /// Complaints from one apartment that force a city inspection.
+    #[serde(default = "default_inspection_trigger_threshold")]
+    pub inspection_trigger_threshold: u32,
+}
+
+fn default_inspection_trigger_threshold() -> u32 {
+    3
 }
 
 impl Default for ThresholdsConfig {
@@ -112,6 +532,7 @@ impl Default for ThresholdsConfig {
             poor_condition: 40,
             critical_condition: 20,
             all_left_check_tick: 3,
+            inspection_trigger_threshold: default_inspection_trigger_threshold(),
         }
     }
 }
@@ -129,6 +550,62 @@ pub struct OperatingCostsConfig {
     pub insurance_base_rate: i32,
     pub insurance_good_condition_discount: i32,
     pub insurance_good_condition_threshold: i32,
+    /// Monthly insurance discount while the last inspector's grade is A or B.
+    #[serde(default = "default_insurance_inspection_discount")]
+    pub insurance_inspection_discount: i32,
+    /// Monthly insurance discount after a policy year without claims.
+    #[serde(default = "default_insurance_claims_discount")]
+    pub insurance_claims_discount: i32,
+    /// Monthly insurance surcharge while any unit is in critical condition.
+    #[serde(default = "default_insurance_risk_surcharge")]
+    pub insurance_risk_surcharge: i32,
+    /// Yearly rise in taxes, utilities, insurance and staff pay (0.03 = 3%).
+    #[serde(default = "default_annual_inflation_rate")]
+    pub annual_inflation_rate: f32,
+    /// Months between property tax reassessments.
+    #[serde(default = "default_assessment_interval_months")]
+    pub assessment_interval_months: u32,
+    /// A reassessment at least this much higher (0.2 = 20%) can be appealed.
+    #[serde(default = "default_assessment_appeal_threshold")]
+    pub assessment_appeal_threshold: f32,
+    /// Filing fee for a tax assessment appeal.
+    #[serde(default = "default_assessment_appeal_fee")]
+    pub assessment_appeal_fee: i32,
+    /// Chance an appeal restores the previous assessment.
+    #[serde(default = "default_assessment_appeal_success_chance")]
+    pub assessment_appeal_success_chance: f32,
+}
+
+fn default_insurance_inspection_discount() -> i32 {
+    25
+}
+
+fn default_insurance_claims_discount() -> i32 {
+    30
+}
+
+fn default_insurance_risk_surcharge() -> i32 {
+    60
+}
+
+fn default_annual_inflation_rate() -> f32 {
+    0.03
+}
+
+fn default_assessment_interval_months() -> u32 {
+    12
+}
+
+fn default_assessment_appeal_threshold() -> f32 {
+    0.2
+}
+
+fn default_assessment_appeal_fee() -> i32 {
+    200
+}
+
+fn default_assessment_appeal_success_chance() -> f32 {
+    0.3
 }
 
 impl Default for OperatingCostsConfig {
@@ -141,6 +618,137 @@ impl Default for OperatingCostsConfig {
             insurance_base_rate: 150,
             insurance_good_condition_discount: 50,
             insurance_good_condition_threshold: 80,
+            insurance_inspection_discount: default_insurance_inspection_discount(),
+            insurance_claims_discount: default_insurance_claims_discount(),
+            insurance_risk_surcharge: default_insurance_risk_surcharge(),
+            annual_inflation_rate: default_annual_inflation_rate(),
+            assessment_interval_months: default_assessment_interval_months(),
+            assessment_appeal_threshold: default_assessment_appeal_threshold(),
+            assessment_appeal_fee: default_assessment_appeal_fee(),
+            assessment_appeal_success_chance: default_assessment_appeal_success_chance(),
+        }
+    }
+}
+
+/// City tax on units left empty too long, to push landlords to fill them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct VacancyTaxConfig {
+    /// Ticks a unit can sit empty before the tax applies.
+    #[serde(default = "default_vacancy_tax_trigger_ticks")]
+    pub vacancy_tax_trigger_ticks: u32,
+    /// Charged per long-vacant unit every tick.
+    #[serde(default = "default_vacancy_tax_per_unit")]
+    pub vacancy_tax_per_unit: i32,
+}
+
+fn default_vacancy_tax_trigger_ticks() -> u32 {
+    3
+}
+
+fn default_vacancy_tax_per_unit() -> i32 {
+    150
+}
+
+impl Default for VacancyTaxConfig {
+    fn default() -> Self {
+        Self {
+            vacancy_tax_trigger_ticks: default_vacancy_tax_trigger_ticks(),
+            vacancy_tax_per_unit: default_vacancy_tax_per_unit(),
         }
     }
 }
+
+/// Squatters moving into units left empty too long.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SquatterConfig {
+    /// A unit has to sit empty longer than this before squatters find it.
+    #[serde(default = "default_squat_after_vacant_ticks")]
+    pub squat_after_vacant_ticks: u32,
+    /// Monthly chance (percent) a long-empty unit picks up a squatter.
+    #[serde(default = "default_squat_chance")]
+    pub squat_chance: i32,
+    /// A squatter left alone this many ticks damages the unit again.
+    #[serde(default = "default_squatter_damage_interval_ticks")]
+    pub damage_interval_ticks: u32,
+    /// Condition a squatter knocks off the unit each time.
+    #[serde(default = "default_squatter_damage")]
+    pub damage: i32,
+}
+
+fn default_squat_after_vacant_ticks() -> u32 {
+    6
+}
+
+fn default_squat_chance() -> i32 {
+    5
+}
+
+fn default_squatter_damage_interval_ticks() -> u32 {
+    3
+}
+
+fn default_squatter_damage() -> i32 {
+    15
+}
+
+impl Default for SquatterConfig {
+    fn default() -> Self {
+        Self {
+            squat_after_vacant_ticks: default_squat_after_vacant_ticks(),
+            squat_chance: default_squat_chance(),
+            damage_interval_ticks: default_squatter_damage_interval_ticks(),
+            damage: default_squatter_damage(),
+        }
+    }
+}
+
+/// How mortgage rates track the city economy.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MortgageConfig {
+    /// Rate when the economy is at full health (1.0).
+    #[serde(default = "default_mortgage_base_interest_rate")]
+    pub base_interest_rate: f32,
+    /// Rate added per point of economy health below 1.0.
+    #[serde(default = "default_mortgage_interest_rate_spread")]
+    pub interest_rate_spread: f32,
+    /// Smallest move in the market rate that reprices variable loans.
+    #[serde(default = "default_mortgage_rate_change_threshold")]
+    pub rate_change_threshold: f32,
+    /// Extra a bank charges to lock a rate in.
+    #[serde(default = "default_mortgage_fixed_rate_premium")]
+    pub fixed_rate_premium: f32,
+}
+
+fn default_mortgage_base_interest_rate() -> f32 {
+    0.06
+}
+
+fn default_mortgage_interest_rate_spread() -> f32 {
+    0.05
+}
+
+fn default_mortgage_rate_change_threshold() -> f32 {
+    0.005
+}
+
+fn default_mortgage_fixed_rate_premium() -> f32 {
+    0.01
+}
+
+impl Default for MortgageConfig {
+    fn default() -> Self {
+        Self {
+            base_interest_rate: default_mortgage_base_interest_rate(),
+            interest_rate_spread: default_mortgage_interest_rate_spread(),
+            rate_change_threshold: default_mortgage_rate_change_threshold(),
+            fixed_rate_premium: default_mortgage_fixed_rate_premium(),
+        }
+    }
+}
+
+impl MortgageConfig {
+    /// Market rate for the city's current economy health.
+    pub fn rate_for(&self, economy_health: f32) -> f32 {
+        (self.base_interest_rate + (1.0 - economy_health) * self.interest_rate_spread).max(0.0)
+    }
+}
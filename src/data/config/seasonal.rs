@@ -0,0 +1,39 @@
+//! Tuning for the seasons: how each one weighs on decay, demand and bills.
+
+use serde::{Deserialize, Serialize};
+
+/// Per-season multipliers, indexed 0=spring, 1=summer, 2=fall, 3=winter.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SeasonalModifiers {
+    /// Applied to `DecayConfig` (winter weather is hard on a building).
+    #[serde(default = "default_seasonal_decay_multipliers")]
+    pub decay_multipliers: [f32; 4],
+    /// Applied to `ApplicationConfig::base_per_vacancy` (people move in summer).
+    #[serde(default = "default_seasonal_application_multipliers")]
+    pub application_multipliers: [f32; 4],
+    /// Applied to utility bills (summer AC, winter heating).
+    #[serde(default = "default_seasonal_cost_multipliers")]
+    pub cost_multipliers: [f32; 4],
+}
+
+fn default_seasonal_decay_multipliers() -> [f32; 4] {
+    [1.0, 1.0, 1.1, 1.4]
+}
+
+fn default_seasonal_application_multipliers() -> [f32; 4] {
+    [1.2, 1.3, 1.0, 0.6]
+}
+
+fn default_seasonal_cost_multipliers() -> [f32; 4] {
+    [0.9, 1.3, 1.0, 1.5]
+}
+
+impl Default for SeasonalModifiers {
+    fn default() -> Self {
+        Self {
+            decay_multipliers: default_seasonal_decay_multipliers(),
+            application_multipliers: default_seasonal_application_multipliers(),
+            cost_multipliers: default_seasonal_cost_multipliers(),
+        }
+    }
+}
@@ -13,10 +13,24 @@ pub struct RelationshipsConfig {
     pub hostile_transition_threshold: i32,
     pub same_archetype_friendly_chance: i32,
     pub adjacent_hostile_chance: i32,
+    /// Monthly percent chance two tenants in different buildings meet
+    #[serde(default = "default_cross_building_formation_chance")]
+    pub cross_building_formation_chance: i32,
+    /// Monthly percent chance security camera footage settles a feud
+    #[serde(default = "default_camera_dispute_resolution_chance")]
+    pub camera_dispute_resolution_chance: i32,
     #[serde(default)]
     pub dilemma: DilemmaConfig,
 }
 
+fn default_cross_building_formation_chance() -> i32 {
+    1
+}
+
+fn default_camera_dispute_resolution_chance() -> i32 {
+    10
+}
+
 impl Default for RelationshipsConfig {
     fn default() -> Self {
         let mut happiness_modifiers = HashMap::new();
@@ -34,6 +48,8 @@ impl Default for RelationshipsConfig {
             hostile_transition_threshold: 20,
             same_archetype_friendly_chance: 60,
             adjacent_hostile_chance: 30,
+            cross_building_formation_chance: default_cross_building_formation_chance(),
+            camera_dispute_resolution_chance: default_camera_dispute_resolution_chance(),
             dilemma: DilemmaConfig::default(),
         }
     }
@@ -1,5 +1,6 @@
 //! Tuning for everything tenant-facing: matching applicants to units, lease
-//! terms, hidden risk, vetting, marketing reach, staff, and life events.
+//! terms, hidden risk, vetting, marketing reach, staff, life events, and
+//! power outages.
 
 use serde::{Deserialize, Serialize};
 
@@ -22,10 +23,59 @@ pub struct MatchingConfig {
     pub noise_loud_penalty: i32,
     pub design_preferred_bonus: i32,
     pub size_medium_bonus: i32,
+    /// Added for archetypes that want parking when a spot is free.
+    #[serde(default = "default_parking_bonus")]
+    pub parking_bonus: i32,
+    /// Added for archetypes that want laundry when the building has it.
+    #[serde(default = "default_laundry_bonus")]
+    pub laundry_bonus: i32,
+    /// Scaled by storage interest when the building rents lockers.
+    #[serde(default = "default_storage_bonus")]
+    pub storage_bonus: i32,
+    /// Luxury premium every applicant gives a penthouse.
+    #[serde(default = "default_penthouse_bonus")]
+    pub penthouse_bonus: i32,
+    /// Added for archetypes that want an elevator when the building has one.
+    #[serde(default = "default_elevator_bonus")]
+    pub elevator_bonus: i32,
+    /// Added for elderly applicants when the building is ADA compliant.
+    #[serde(default = "default_ada_elderly_bonus")]
+    pub ada_elderly_bonus: i32,
+    /// Added for student applicants with nobody to co-sign.
+    #[serde(default = "default_no_guarantor_penalty")]
+    pub no_guarantor_penalty: i32,
     pub lease_defaults: LeaseDefaultsConfig,
     pub lease_acceptance: LeaseAcceptanceConfig,
 }
 
+fn default_parking_bonus() -> i32 {
+    8
+}
+
+fn default_laundry_bonus() -> i32 {
+    6
+}
+
+fn default_storage_bonus() -> i32 {
+    10
+}
+
+fn default_penthouse_bonus() -> i32 {
+    25
+}
+
+fn default_elevator_bonus() -> i32 {
+    10
+}
+
+fn default_ada_elderly_bonus() -> i32 {
+    15
+}
+
+fn default_no_guarantor_penalty() -> i32 {
+    -10
+}
+
 impl Default for MatchingConfig {
     fn default() -> Self {
         Self {
@@ -46,6 +96,13 @@ impl Default for MatchingConfig {
             noise_loud_penalty: 15,
             design_preferred_bonus: 18,
             size_medium_bonus: 5,
+            parking_bonus: default_parking_bonus(),
+            laundry_bonus: default_laundry_bonus(),
+            storage_bonus: default_storage_bonus(),
+            penthouse_bonus: default_penthouse_bonus(),
+            elevator_bonus: default_elevator_bonus(),
+            ada_elderly_bonus: default_ada_elderly_bonus(),
+            no_guarantor_penalty: default_no_guarantor_penalty(),
             lease_defaults: LeaseDefaultsConfig::default(),
             lease_acceptance: LeaseAcceptanceConfig::default(),
         }
@@ -77,6 +134,13 @@ pub struct LeaseAcceptanceConfig {
     pub long_lease_penalty: f32,
     pub good_deal_bonus: f32,
     pub expensive_penalty: f32,
+    /// Subtracted when the rent is over the archetype's `max_affordable_rent`.
+    #[serde(default = "default_over_rent_cap_penalty")]
+    pub over_rent_cap_penalty: f32,
+}
+
+fn default_over_rent_cap_penalty() -> f32 {
+    0.6
 }
 
 impl Default for LeaseAcceptanceConfig {
@@ -88,6 +152,7 @@ impl Default for LeaseAcceptanceConfig {
             long_lease_penalty: 0.15,
             good_deal_bonus: 0.1,
             expensive_penalty: 0.1,
+            over_rent_cap_penalty: default_over_rent_cap_penalty(),
         }
     }
 }
@@ -145,6 +210,10 @@ pub struct TenantRiskConfig {
     /// has nothing to catch and tenant selection doesn't matter.
     #[serde(default = "default_problem_applicant_chance_percent")]
     pub problem_applicant_chance_percent: i32,
+    /// Monthly chance (percent) a tenant whose rent takes more of their
+    /// verified income than vetting's limit allows misses rent.
+    #[serde(default = "default_rent_burdened_skip_chance_percent")]
+    pub rent_burdened_skip_chance_percent: i32,
 }
 
 fn default_risky_rent_premium_percent() -> i32 {
@@ -155,6 +224,10 @@ fn default_problem_applicant_chance_percent() -> i32 {
     18
 }
 
+fn default_rent_burdened_skip_chance_percent() -> i32 {
+    15
+}
+
 impl Default for TenantRiskConfig {
     fn default() -> Self {
         Self {
@@ -166,6 +239,7 @@ impl Default for TenantRiskConfig {
             hallway_disturbance_amount: 3,
             risky_rent_premium_percent: default_risky_rent_premium_percent(),
             problem_applicant_chance_percent: default_problem_applicant_chance_percent(),
+            rent_burdened_skip_chance_percent: default_rent_burdened_skip_chance_percent(),
         }
     }
 }
@@ -174,15 +248,63 @@ impl Default for TenantRiskConfig {
 pub struct VettingConfig {
     pub credit_check_cost: i32,
     pub background_check_cost: i32,
+    /// Cost of calling an applicant's previous landlord.
+    #[serde(default = "default_reference_check_cost")]
+    pub reference_check_cost: i32,
+    /// Percent of applicants who forge their credit history.
+    #[serde(default = "default_fraud_chance_percent")]
+    pub fraud_chance_percent: u32,
+    /// A forged credit report reads at least this well.
+    #[serde(default = "default_fraud_credit_score_min")]
+    pub fraud_credit_score_min: i32,
+    /// The rental history behind a forged report scores below this.
+    #[serde(default = "default_fraud_behavior_score_max")]
+    pub fraud_behavior_score_max: i32,
+    /// Cost of verifying an applicant's income with their employer.
+    #[serde(default = "default_income_check_cost")]
+    pub income_check_cost: i32,
+    /// Highest share of income the rent should take.
+    #[serde(default = "default_max_acceptable_ratio")]
+    pub max_acceptable_ratio: f32,
     pub credit_thresholds: VettingThresholds,
     pub behavior_thresholds: VettingThresholds,
 }
 
+fn default_reference_check_cost() -> i32 {
+    15
+}
+
+fn default_fraud_chance_percent() -> u32 {
+    5
+}
+
+fn default_fraud_credit_score_min() -> i32 {
+    75
+}
+
+fn default_fraud_behavior_score_max() -> i32 {
+    30
+}
+
+fn default_income_check_cost() -> i32 {
+    20
+}
+
+fn default_max_acceptable_ratio() -> f32 {
+    0.4
+}
+
 impl Default for VettingConfig {
     fn default() -> Self {
         Self {
             credit_check_cost: 25,
             background_check_cost: 10,
+            reference_check_cost: default_reference_check_cost(),
+            fraud_chance_percent: default_fraud_chance_percent(),
+            fraud_credit_score_min: default_fraud_credit_score_min(),
+            fraud_behavior_score_max: default_fraud_behavior_score_max(),
+            income_check_cost: default_income_check_cost(),
+            max_acceptable_ratio: default_max_acceptable_ratio(),
             credit_thresholds: VettingThresholds::default(),
             behavior_thresholds: VettingThresholds::default(),
         }
@@ -257,3 +379,39 @@ impl Default for LifeEventsConfig {
         }
     }
 }
+
+/// Monthly happiness tenants lose while the power is out.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PowerOutageConfig {
+    /// Happiness every tenant loses each month of an outage.
+    #[serde(default = "default_outage_happiness_penalty")]
+    pub happiness_penalty: i32,
+    /// The smaller loss with a backup generator running.
+    #[serde(default = "default_generator_happiness_penalty")]
+    pub generator_happiness_penalty: i32,
+    /// Extra loss for professionals who can't work from home without power.
+    #[serde(default = "default_work_from_home_penalty")]
+    pub work_from_home_penalty: i32,
+}
+
+fn default_outage_happiness_penalty() -> i32 {
+    10
+}
+
+fn default_generator_happiness_penalty() -> i32 {
+    2
+}
+
+fn default_work_from_home_penalty() -> i32 {
+    5
+}
+
+impl Default for PowerOutageConfig {
+    fn default() -> Self {
+        Self {
+            happiness_penalty: default_outage_happiness_penalty(),
+            generator_happiness_penalty: default_generator_happiness_penalty(),
+            work_from_home_penalty: default_work_from_home_penalty(),
+        }
+    }
+}
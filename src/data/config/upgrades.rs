@@ -25,8 +25,21 @@ pub enum UpgradeTarget {
 pub enum UpgradeEffect {
     SetFlag(String),
     RemoveFlag(String),
-    ModifyStat { stat: String, amount: i32 },
+    ModifyStat {
+        stat: String,
+        amount: i32,
+    },
     SetDesign(String),
+    /// Add a shared amenity, by `AmenityType::key()`
+    InstallAmenity(String),
+    AddParkingSpots {
+        count: u32,
+        monthly_fee: i32,
+    },
+    /// Repaint the facade, by `ExteriorTheme::key()`
+    SetExteriorTheme(String),
+    /// Convert the unit to another `ApartmentSize`, by name
+    SetSize(String),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -34,11 +47,26 @@ pub enum UpgradeEffect {
 pub enum UpgradeRequirement {
     MissingFlag(String),
     HasFlag(String),
-    MinStat { stat: String, value: i32 },
-    MaxStat { stat: String, value: i32 },
+    MinStat {
+        stat: String,
+        value: i32,
+    },
+    MaxStat {
+        stat: String,
+        value: i32,
+    },
     HasDesign(String),
     MissingDesign(String),
     MinSize(String),
+    MissingAmenity(String),
+    /// Building isn't already using this `ExteriorTheme::key()`
+    MissingExteriorTheme(String),
+    /// Unit is exactly this size
+    HasSize(String),
+    /// Unit is on the building's highest floor
+    TopFloor,
+    /// Unit hears the street (its base noise is High)
+    StreetFacing,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
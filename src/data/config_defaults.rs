@@ -10,18 +10,28 @@ impl Default for GameConfig {
             decay: DecayConfig {
                 apartment_per_tick: 3,
                 hallway_per_tick: 1,
+                hvac_decay_reduction: 1,
+                aging_threshold_years: 25,
+                aging_decay_per_year: 0.02,
             },
+            seasonal_modifiers: SeasonalModifiers::default(),
             happiness: default_happiness(),
             win_conditions: WinConditions {
                 full_occupancy_required: true,
-                min_ticks_for_victory: 6,
                 game_duration_ticks: Some(36),
+                new_game_plus_funds_share: 0.1,
+                new_game_plus_reputation_share: 0.5,
             },
             applications: ApplicationConfig {
                 expire_after_ticks: 3,
                 base_per_vacancy: 0.5,
                 appeal_bonus_divisor: 50,
                 reputation_influence: 0.5,
+                max_per_unit: 3,
+                interview_chance_percent: 20,
+                student_guarantor_chance_percent: 60,
+                voucher_holder_chance_percent: 12,
+                voucher_share: 0.6,
             },
             ui: UiConfig {
                 upgrade_labels: default_upgrade_labels(),
@@ -30,6 +40,9 @@ impl Default for GameConfig {
             matching: MatchingConfig::default(),
             thresholds: ThresholdsConfig::default(),
             operating_costs: OperatingCostsConfig::default(),
+            vacancy_tax: VacancyTaxConfig::default(),
+            squatters: SquatterConfig::default(),
+            mortgage: MortgageConfig::default(),
             staff_effects: StaffEffectsConfig::default(),
             tenant_risk: TenantRiskConfig::default(),
             vetting: VettingConfig::default(),
@@ -39,13 +52,25 @@ impl Default for GameConfig {
             gentrification: GentrificationConfig::default(),
             regulations: RegulationsConfig::default(),
             life_events: LifeEventsConfig::default(),
+            power_outages: PowerOutageConfig::default(),
             critical_failures: CriticalFailureConfig::default(),
+            water_damage: WaterDamageConfig::default(),
+            landmarks: LandmarkConfig::default(),
             portfolio: PortfolioConfig::default(),
+            special_assessments: SpecialAssessmentConfig::default(),
+            probability_scaling: EventProbabilityConfig::default(),
+            utility_spikes: UtilitySpikeConfig::default(),
+            inspector: InspectorConfig::default(),
+            market_analyst: MarketAnalystConfig::default(),
+            property_manager: PropertyManagerConfig::default(),
+            journalist: JournalistConfig::default(),
+            rivals: RivalsConfig::default(),
             difficulty: default_difficulty_modifiers(),
             theme: ThemeConfig::default(),
             layout: LayoutConfig::default(),
             ui_thresholds: UiThresholdsConfig::default(),
             apartment: ApartmentPropertiesConfig::default(),
+            amenities_config: default_amenities_config(),
         }
     }
 }
@@ -68,6 +93,34 @@ fn default_economy() -> EconomyConfig {
         laundry_installation_cost: 2000,
         soundproofing_cost: 300,
         staff_costs: default_staff_costs(),
+        appraisal_appeal_weight: 0.5,
+        listing_price_cut_interval: 2,
+        listing_price_cut_factor: 0.98,
+        mediation_cost: 250,
+        renovation_cost_per_unit: 2500,
+        unit_merge_cost: 5000,
+        laundry_income_per_occupied_unit: 15,
+        solar_reduction_per_unit: 25,
+        storage_locker_income: 20,
+        lease_to_own_min_months: 24,
+        lease_to_own_price_factor: 0.8,
+        squatter_removal_cost: 300,
+        landmark_subsidy: 400,
+        relocation_resistance_cost: 8000,
+        tenant_eviction_cost: 800,
+        arrears_repayment_percent: 25,
+        relocation_assistance_months: 2,
+        permit_cost_threshold: 1000,
+        permit_fee: 250,
+        permit_processing_months: 3,
+        license_renewal_fee: 200,
+        pet_deposit: 300,
+        lease_renewal_increase: 0.03,
+        lease_renewal_notice_months: 3,
+        month_to_month_premium: 50,
+        legal_contest_cost: 5000,
+        deposit_deduction_per_point: 20,
+        belongings_storage_cost: 150,
     }
 }
 
@@ -127,6 +180,7 @@ fn default_happiness() -> HappinessConfig {
         min_for_victory: 60,
         leave_threshold: 15,
         leave_chance_percent: 35,
+        ada_elderly_leave_reduction_percent: 20,
         unhappy_threshold: 30,
         tenure_bonus_max: 12,
         rent_bonus_multiplier: 0.02,
@@ -145,6 +199,19 @@ fn default_happiness() -> HappinessConfig {
         design_style_modifiers: default_design_style_modifiers(),
         hallway_condition_base: 50,
         hallway_condition_multiplier: 0.2,
+        archetype_rent_caps: default_archetype_rent_caps(),
+        hvac_comfort_bonus: 5,
+        extreme_weather_penalty: -3,
+        elevator_happiness_bonus: default_elevator_happiness_bonus(),
+        green_certification_bonus: 2,
+        storage_happiness_bonus: 4,
+        storage_overcharge_step: 5,
+        hallway_art_happiness_bonus: default_hallway_art_happiness_bonus(),
+        hallway_art_praise_chance: 10,
+        hallway_art_praise_min_hallway: 80,
+        month_to_month_penalty: -5,
+        eviction_penalty: 5,
+        pet_noise_complaint_penalty: 5,
     }
 }
 
@@ -169,6 +236,10 @@ fn default_upgrade_labels() -> HashMap<String, String> {
         "upgrade_design_fmt".to_string(),
         "Upgrade to {}".to_string(),
     );
+    labels.insert(
+        "repair_amenity_fmt".to_string(),
+        "Repair {name} +{}".to_string(),
+    );
     labels.insert("max_design".to_string(), "Max Design".to_string());
     labels.insert("soundproofing".to_string(), "Add Soundproofing".to_string());
     labels.insert(
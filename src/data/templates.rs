@@ -1,5 +1,7 @@
-use crate::building::{ApartmentSize, DesignType, NoiseLevel};
+use crate::building::{default_construction_year, ApartmentSize, DesignType, NoiseLevel};
+use crate::tenant::TenantArchetype;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs;
 
@@ -26,10 +28,115 @@ pub struct BuildingTemplate {
     pub floors: u32,
     pub units_per_floor: u32,
     pub hallway_condition: i32,
+    #[serde(default = "default_construction_year")]
+    pub construction_year: u32,
     pub apartments: Vec<ApartmentTemplate>,
     pub initial_tenant: Option<InitialTenantData>,
 }
 
+impl BuildingTemplate {
+    /// A plain building of the requested size for sandbox games. Units
+    /// alternate small/medium, with the ground floor and street-facing units
+    /// noisy, as in `Building::new`.
+    pub fn sandbox(floors: u32, units_per_floor: u32) -> Self {
+        let mut apartments = Vec::new();
+        for floor in 1..=floors {
+            for unit in 0..units_per_floor {
+                let size = if (floor + unit) % 2 == 0 {
+                    ApartmentSize::Small
+                } else {
+                    ApartmentSize::Medium
+                };
+                let noisy = floor == 1 || unit == 0;
+                apartments.push(ApartmentTemplate {
+                    unit_number: format!("{}{}", floor, (b'A' + unit as u8) as char),
+                    floor,
+                    size_str: if size == ApartmentSize::Small {
+                        "small"
+                    } else {
+                        "medium"
+                    }
+                    .to_string(),
+                    base_noise_str: if noisy { "high" } else { "low" }.to_string(),
+                    initial_condition: 60,
+                    initial_design: "bare".to_string(),
+                    initial_rent: size.base_rent(),
+                });
+            }
+        }
+        Self {
+            id: "sandbox".to_string(),
+            name: "Sandbox".to_string(),
+            unlock_order: 0,
+            difficulty: "Easy".to_string(),
+            neighborhood_id: 1,
+            description: "Free play with no win or loss.".to_string(),
+            floors,
+            units_per_floor,
+            hallway_condition: 60,
+            construction_year: default_construction_year(),
+            apartments,
+            initial_tenant: None,
+        }
+    }
+
+    /// A small four-unit building for tests that run without the template file.
+    #[cfg(test)]
+    pub fn starter() -> Self {
+        Self {
+            id: "starter".to_string(),
+            name: "Starter Building".to_string(),
+            unlock_order: 0,
+            difficulty: "easy".to_string(),
+            neighborhood_id: 1,
+            description: "A small starter property.".to_string(),
+            floors: 2,
+            units_per_floor: 2,
+            hallway_condition: 60,
+            construction_year: 2000,
+            apartments: vec![
+                ApartmentTemplate {
+                    unit_number: "1A".to_string(),
+                    floor: 1,
+                    size_str: "small".to_string(),
+                    base_noise_str: "high".to_string(),
+                    initial_condition: 55,
+                    initial_design: "bare".to_string(),
+                    initial_rent: 600,
+                },
+                ApartmentTemplate {
+                    unit_number: "1B".to_string(),
+                    floor: 1,
+                    size_str: "medium".to_string(),
+                    base_noise_str: "low".to_string(),
+                    initial_condition: 60,
+                    initial_design: "bare".to_string(),
+                    initial_rent: 800,
+                },
+                ApartmentTemplate {
+                    unit_number: "2A".to_string(),
+                    floor: 2,
+                    size_str: "small".to_string(),
+                    base_noise_str: "low".to_string(),
+                    initial_condition: 65,
+                    initial_design: "practical".to_string(),
+                    initial_rent: 650,
+                },
+                ApartmentTemplate {
+                    unit_number: "2B".to_string(),
+                    floor: 2,
+                    size_str: "medium".to_string(),
+                    base_noise_str: "low".to_string(),
+                    initial_condition: 65,
+                    initial_design: "practical".to_string(),
+                    initial_rent: 850,
+                },
+            ],
+            initial_tenant: None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ApartmentTemplate {
     pub unit_number: String,
@@ -45,13 +152,7 @@ pub struct ApartmentTemplate {
 
 impl ApartmentTemplate {
     pub fn size(&self) -> ApartmentSize {
-        match self.size_str.to_lowercase().as_str() {
-            "small" => ApartmentSize::Small,
-            "medium" => ApartmentSize::Medium,
-            "large" => ApartmentSize::Large,
-            "penthouse" => ApartmentSize::Penthouse,
-            _ => ApartmentSize::Medium,
-        }
+        ApartmentSize::from_name(&self.size_str).unwrap_or(ApartmentSize::Medium)
     }
 
     pub fn base_noise(&self) -> NoiseLevel {
@@ -93,7 +194,10 @@ pub fn load_templates() -> Option<BuildingTemplates> {
     };
 
     match serde_json::from_str::<BuildingTemplates>(&json) {
-        Ok(templates) => Some(templates),
+        Ok(mut templates) => {
+            templates.templates = validated(templates.templates);
+            Some(templates)
+        }
         Err(e) => {
             eprintln!("Failed to parse building_templates.json: {}", e);
             None
@@ -101,9 +205,86 @@ pub fn load_templates() -> Option<BuildingTemplates> {
     }
 }
 
+/// Problems with a single template that would break a game started from it.
+/// Id uniqueness spans the whole file, so `load_templates` checks that.
+pub fn validate_template(template: &BuildingTemplate) -> Vec<String> {
+    let mut errors = Vec::new();
+    if template.id.trim().is_empty() {
+        errors.push(format!("template '{}' has an empty id", template.name));
+    }
+    if template.floors == 0 {
+        errors.push(format!("template '{}' has no floors", template.id));
+    }
+    if template.units_per_floor == 0 {
+        errors.push(format!("template '{}' has no units per floor", template.id));
+    }
+    if let Some(tenant) = &template.initial_tenant {
+        if TenantArchetype::from_id(&tenant.archetype).is_none() {
+            errors.push(format!(
+                "template '{}' has unknown initial tenant archetype '{}'",
+                template.id, tenant.archetype
+            ));
+        }
+    }
+    errors
+}
+
+/// Drop invalid or duplicate-id templates, logging why. Debug builds panic
+/// instead so a broken template file is caught before it ships.
+fn validated(templates: Vec<BuildingTemplate>) -> Vec<BuildingTemplate> {
+    let mut seen_ids = HashSet::new();
+    let mut all_errors = Vec::new();
+    let mut valid = Vec::with_capacity(templates.len());
+
+    for template in templates {
+        let mut errors = validate_template(&template);
+        if !seen_ids.insert(template.id.clone()) {
+            errors.push(format!("duplicate template id '{}'", template.id));
+        }
+        if errors.is_empty() {
+            valid.push(template);
+        } else {
+            for error in &errors {
+                eprintln!("building_templates.json: {}", error);
+            }
+            eprintln!("Warning: skipping invalid template '{}'", template.id);
+            all_errors.extend(errors);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    if !all_errors.is_empty() {
+        panic!("invalid building templates: {}", all_errors.join("; "));
+    }
+
+    valid
+}
+
 #[cfg(test)]
 mod tests {
-    use super::load_templates;
+    use super::{load_templates, validate_template, BuildingTemplate};
+
+    #[test]
+    fn validate_template_flags_broken_fields() {
+        let mut template = load_templates().unwrap().templates[0].clone();
+        assert!(validate_template(&template).is_empty());
+
+        template.id.clear();
+        template.floors = 0;
+        if let Some(tenant) = template.initial_tenant.as_mut() {
+            tenant.archetype = "wizard".to_string();
+        }
+        let errors = validate_template(&template);
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn sandbox_template_has_the_requested_size() {
+        let template = BuildingTemplate::sandbox(3, 4);
+        assert!(validate_template(&template).is_empty());
+        assert_eq!(template.apartments.len(), 12);
+        assert_eq!(template.apartments[11].unit_number, "3D");
+    }
 
     #[test]
     fn campaign_roster_is_a_contiguous_unlock_chain() {
@@ -5,13 +5,19 @@
 //! - `Rent`: Collection logic and rent setting.
 //! - `Costs`: Operating expenses, taxes, utilities.
 //! - `Ledger`: Monthly financial reporting.
+//! - `Mortgage`: Loans on purchased buildings and their repayment.
 
 mod costs;
 mod ledger;
 mod money;
+mod mortgage;
 mod rent;
 
-pub use costs::{process_upgrade, OperatingCosts};
+pub use costs::{
+    process_upgrade, InsuranceQuote, OperatingCosts, CLAIMS_FREE_FLAG, INSPECTION_DISCOUNT_FLAG,
+    SOLAR_PANELS_FLAG,
+};
 pub use ledger::FinancialLedger;
 pub use money::{PlayerFunds, Transaction, TransactionType};
+pub use mortgage::{amortized_payment, ActiveMortgage};
 pub use rent::collect_rent;
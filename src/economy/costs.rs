@@ -2,6 +2,35 @@ use super::{PlayerFunds, Transaction, TransactionType};
 use crate::building::{apply_upgrade, Building, UpgradeAction};
 use crate::data::config::OperatingCostsConfig;
 
+/// Building flag set by the solar panel upgrade.
+pub const SOLAR_PANELS_FLAG: &str = "solar_panels";
+/// Solar panels can offset at most this share of the utility bill.
+const SOLAR_MAX_UTILITY_SHARE: f32 = 0.8;
+/// Solar panels take this much off a utility rate spike.
+const SOLAR_RATE_SPIKE_RELIEF: f32 = 0.3;
+/// Building flag held while the inspector's last grade was an A or B.
+pub const INSPECTION_DISCOUNT_FLAG: &str = "inspection_discount";
+/// Building flag held after a policy year without an insurance claim.
+pub const CLAIMS_FREE_FLAG: &str = "insurance_claims_free";
+
+/// The pieces of a monthly insurance premium.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsuranceQuote {
+    pub base_rate: i32,
+    pub condition_discount: i32,
+    pub inspection_discount: i32,
+    pub claims_discount: i32,
+    pub risk_surcharge: i32,
+}
+
+impl InsuranceQuote {
+    pub fn premium(&self) -> i32 {
+        (self.base_rate - self.condition_discount - self.inspection_discount - self.claims_discount
+            + self.risk_surcharge)
+            .max(0)
+    }
+}
+
 /// Calculate operating costs
 pub struct OperatingCosts;
 
@@ -37,20 +66,84 @@ impl OperatingCosts {
         occupied * config.utility_cost_per_unit
     }
 
-    /// Calculate monthly insurance
-    pub fn calculate_insurance(building: &Building, config: &OperatingCostsConfig) -> i32 {
+    /// Utility rate multiplier the building actually pays. Solar panels
+    /// soften a spike, but never push rates below normal.
+    pub fn effective_utility_rate(building: &Building, multiplier: f32) -> f32 {
+        if multiplier > 1.0 && building.flags.contains(SOLAR_PANELS_FLAG) {
+            (multiplier - SOLAR_RATE_SPIKE_RELIEF).max(1.0)
+        } else {
+            multiplier
+        }
+    }
+
+    /// Calculate monthly insurance. `critical_condition` is the condition
+    /// below which a unit counts as a risk.
+    pub fn calculate_insurance(
+        building: &Building,
+        config: &OperatingCostsConfig,
+        critical_condition: i32,
+    ) -> i32 {
         if !building.insurance_active {
             return 0;
         }
+        Self::insurance_quote(building, config, critical_condition).premium()
+    }
 
-        // Discount for good condition
-        let discount = if building.hallway_condition > config.insurance_good_condition_threshold {
-            config.insurance_good_condition_discount
-        } else {
-            0
-        };
+    /// Itemized insurance premium: a well-kept building with a clean claims
+    /// record pays less, and units in critical condition cost extra.
+    pub fn insurance_quote(
+        building: &Building,
+        config: &OperatingCostsConfig,
+        critical_condition: i32,
+    ) -> InsuranceQuote {
+        let discount_if = |earned: bool, amount: i32| if earned { amount } else { 0 };
+        InsuranceQuote {
+            base_rate: config.insurance_base_rate,
+            condition_discount: discount_if(
+                building.average_condition() > config.insurance_good_condition_threshold,
+                config.insurance_good_condition_discount,
+            ),
+            // Insurers trust a good report card from the inspector
+            inspection_discount: discount_if(
+                building.flags.contains(INSPECTION_DISCOUNT_FLAG),
+                config.insurance_inspection_discount,
+            ),
+            claims_discount: discount_if(
+                building.flags.contains(CLAIMS_FREE_FLAG),
+                config.insurance_claims_discount,
+            ),
+            risk_surcharge: discount_if(
+                building
+                    .apartments
+                    .iter()
+                    .any(|apt| apt.condition < critical_condition),
+                config.insurance_risk_surcharge,
+            ),
+        }
+    }
+
+    /// Monthly utility savings from solar panels, given this month's bill.
+    /// Panels never cover more than `SOLAR_MAX_UTILITY_SHARE` of the bill.
+    pub fn calculate_solar_savings(
+        building: &Building,
+        utilities: i32,
+        config: &crate::data::config::EconomyConfig,
+    ) -> i32 {
+        if !building.flags.contains(SOLAR_PANELS_FLAG) {
+            return 0;
+        }
+        Self::potential_solar_savings(building, utilities, config)
+    }
 
-        config.insurance_base_rate - discount
+    /// What solar panels would save on this bill, installed or not.
+    pub fn potential_solar_savings(
+        building: &Building,
+        utilities: i32,
+        config: &crate::data::config::EconomyConfig,
+    ) -> i32 {
+        let savings = config.solar_reduction_per_unit * building.apartments.len() as i32;
+        let cap = (utilities as f32 * SOLAR_MAX_UTILITY_SHARE) as i32;
+        savings.min(cap)
     }
 
     /// Calculate monthly staff salaries
@@ -105,6 +198,16 @@ pub fn process_upgrade(
                 return Err("Hallway already at max condition".to_string());
             }
         }
+        UpgradeAction::RepairAmenity { amenity, .. } => {
+            let shared = building
+                .amenities
+                .iter()
+                .find(|a| a.amenity_type == *amenity)
+                .ok_or("Amenity not installed")?;
+            if shared.condition >= 100 {
+                return Err(format!("{} already at max condition", amenity.name()));
+            }
+        }
         UpgradeAction::Apply {
             upgrade_id,
             target_id,
@@ -168,6 +271,20 @@ pub fn process_upgrade(
                                     ));
                                 }
                             }
+                            crate::data::config::UpgradeRequirement::HasSize(size_str)
+                                if crate::building::ApartmentSize::from_name(size_str).as_ref()
+                                    != Some(&apt.size) =>
+                            {
+                                return Err(format!(
+                                    "Requirement failed: Unit must be {}",
+                                    size_str
+                                ));
+                            }
+                            crate::data::config::UpgradeRequirement::TopFloor
+                                if apt.floor < building.top_floor() =>
+                            {
+                                return Err("Requirement failed: Top floor only".to_string());
+                            }
                             _ => {}
                         }
                     }
@@ -181,6 +298,17 @@ pub fn process_upgrade(
                             {
                                 return Err(format!("Requirement failed: {}", flag));
                             }
+                            crate::data::config::UpgradeRequirement::MissingAmenity(key)
+                                if crate::building::AmenityType::from_key(key)
+                                    .is_some_and(|a| building.has_amenity(a)) =>
+                            {
+                                return Err(format!("Requirement failed: {} already built", key));
+                            }
+                            crate::data::config::UpgradeRequirement::MissingExteriorTheme(key)
+                                if building.exterior_theme.key() == key =>
+                            {
+                                return Err(format!("Requirement failed: already {}", key));
+                            }
                             // ... check other reqs
                             _ => {}
                         }
@@ -224,6 +352,9 @@ pub fn process_upgrade(
         UpgradeAction::RepairHallway { amount } => {
             format!("Hallway repair (+{} condition)", amount)
         }
+        UpgradeAction::RepairAmenity { amenity, amount } => {
+            format!("{} repair (+{} condition)", amenity.name(), amount)
+        }
         UpgradeAction::Apply {
             upgrade_id,
             target_id,
@@ -250,6 +381,7 @@ pub fn process_upgrade(
             UpgradeAction::RepairApartment { .. } => TransactionType::RepairCost,
             UpgradeAction::UpgradeDesign { .. } => TransactionType::UpgradeCost,
             UpgradeAction::RepairHallway { .. } => TransactionType::HallwayRepair,
+            UpgradeAction::RepairAmenity { .. } => TransactionType::AmenityRepair,
             UpgradeAction::Apply { .. } => TransactionType::UpgradeCost,
         },
         cost,
@@ -271,7 +403,38 @@ pub fn process_upgrade(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::data::config::OperatingCostsConfig;
+    use crate::data::config::{OperatingCostsConfig, ThresholdsConfig};
+
+    #[test]
+    fn solar_savings_are_capped_at_most_of_the_bill() {
+        let mut building = Building::new("Test", 2, 2);
+        let economy = crate::data::config::GameConfig::default().economy;
+        assert_eq!(
+            OperatingCosts::calculate_solar_savings(&building, 1000, &economy),
+            0
+        );
+
+        building.flags.insert(SOLAR_PANELS_FLAG.to_string());
+        let uncapped = economy.solar_reduction_per_unit * 4;
+        assert_eq!(
+            OperatingCosts::calculate_solar_savings(&building, 10_000, &economy),
+            uncapped
+        );
+        assert_eq!(
+            OperatingCosts::calculate_solar_savings(&building, 100, &economy),
+            80
+        );
+    }
+
+    #[test]
+    fn solar_panels_soften_utility_rate_spikes() {
+        let mut building = Building::new("Test", 1, 1);
+        assert_eq!(OperatingCosts::effective_utility_rate(&building, 2.0), 2.0);
+
+        building.flags.insert(SOLAR_PANELS_FLAG.to_string());
+        assert!((OperatingCosts::effective_utility_rate(&building, 2.0) - 1.7).abs() < 0.001);
+        assert_eq!(OperatingCosts::effective_utility_rate(&building, 1.0), 1.0);
+    }
 
     #[test]
     fn base_overhead_scales_with_unit_count() {
@@ -283,6 +446,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn good_inspection_grade_discounts_insurance() {
+        let mut building = Building::new("Test", 1, 1);
+        building.insurance_active = true;
+        building.hallway_condition = 50;
+        let config = OperatingCostsConfig::default();
+        let critical = ThresholdsConfig::default().critical_condition;
+        let base = OperatingCosts::calculate_insurance(&building, &config, critical);
+
+        building.flags.insert(INSPECTION_DISCOUNT_FLAG.to_string());
+        assert_eq!(
+            OperatingCosts::calculate_insurance(&building, &config, critical),
+            base - config.insurance_inspection_discount
+        );
+    }
+
+    #[test]
+    fn upkeep_and_a_clean_record_lower_the_premium() {
+        let mut building = Building::new("Test", 1, 2);
+        building.insurance_active = true;
+        let config = OperatingCostsConfig::default();
+        let critical = ThresholdsConfig::default().critical_condition;
+        for apt in &mut building.apartments {
+            apt.condition = 90;
+        }
+        building.flags.insert(CLAIMS_FREE_FLAG.to_string());
+        let quote = OperatingCosts::insurance_quote(&building, &config, critical);
+        assert_eq!(
+            quote.premium(),
+            config.insurance_base_rate
+                - config.insurance_good_condition_discount
+                - config.insurance_claims_discount
+        );
+
+        building.flags.remove(CLAIMS_FREE_FLAG);
+        building.apartments[0].condition = 10;
+        let quote = OperatingCosts::insurance_quote(&building, &config, critical);
+        assert_eq!(quote.condition_discount, 0);
+        assert_eq!(quote.claims_discount, 0);
+        assert_eq!(
+            quote.premium(),
+            config.insurance_base_rate + config.insurance_risk_surcharge
+        );
+    }
+
     #[test]
     fn property_tax_escalates_each_year() {
         let building = Building::new("Test", 1, 1);
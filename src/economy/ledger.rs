@@ -6,6 +6,12 @@ use serde::{Deserialize, Serialize};
 pub struct MonthlyReport {
     pub tick: u32,
     pub rent_income: i32,
+    /// Coin laundry takings, reported apart from rent.
+    #[serde(default)]
+    pub laundry_income: i32,
+    /// Storage locker rent, reported apart from rent.
+    #[serde(default)]
+    pub storage_income: i32,
     pub repair_costs: i32,
     pub upgrade_costs: i32,
     pub net: i32,
@@ -33,15 +39,25 @@ impl FinancialLedger {
         ending_balance: i32,
     ) -> MonthlyReport {
         let mut rent_income = 0;
+        let mut laundry_income = 0;
+        let mut storage_income = 0;
         let mut repair_costs = 0;
         let mut upgrade_costs = 0;
 
         for t in transactions {
             match t.transaction_type {
-                TransactionType::RentIncome | TransactionType::Grant => {
-                    rent_income += t.amount.abs()
-                }
-                TransactionType::RepairCost | TransactionType::HallwayRepair => {
+                TransactionType::RentIncome
+                | TransactionType::ParkingIncome
+                | TransactionType::HoaIncome
+                | TransactionType::Grant
+                | TransactionType::SecurityDeposit => rent_income += t.amount.abs(),
+                // Held for the tenant until they move out, so not income.
+                TransactionType::PetDeposit => {}
+                TransactionType::LaundryIncome => laundry_income += t.amount.abs(),
+                TransactionType::StorageIncome => storage_income += t.amount.abs(),
+                TransactionType::RepairCost
+                | TransactionType::HallwayRepair
+                | TransactionType::AmenityRepair => {
                     repair_costs += t.amount.abs();
                 }
                 TransactionType::UpgradeCost => upgrade_costs += t.amount.abs(),
@@ -55,7 +71,16 @@ impl FinancialLedger {
                 | TransactionType::CriticalFailure
                 | TransactionType::Marketing
                 | TransactionType::Vetting
-                | TransactionType::InspectionFine => {
+                | TransactionType::InspectionFine
+                | TransactionType::ComplianceFine
+                | TransactionType::VacancyTax
+                | TransactionType::RelocationAssistance
+                | TransactionType::PermitFee
+                | TransactionType::LicenseFee
+                | TransactionType::LegalFees
+                | TransactionType::SquatterRemoval
+                | TransactionType::DepositRefund
+                | TransactionType::Mediation => {
                     // These are all operating expenses, count them in repair_costs for simplicity
                     repair_costs += t.amount.abs();
                 }
@@ -65,9 +90,11 @@ impl FinancialLedger {
         let report = MonthlyReport {
             tick,
             rent_income,
+            laundry_income,
+            storage_income,
             repair_costs,
             upgrade_costs,
-            net: rent_income - repair_costs - upgrade_costs,
+            net: rent_income + laundry_income + storage_income - repair_costs - upgrade_costs,
             ending_balance,
         };
 
@@ -81,3 +108,46 @@ impl Default for FinancialLedger {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn laundry_income_is_its_own_line_item() {
+        let rent = Transaction::income(TransactionType::RentIncome, 1000, "Rent", 1);
+        let laundry = Transaction::income(TransactionType::LaundryIncome, 60, "Laundry", 1);
+        let mut ledger = FinancialLedger::new();
+
+        let report = ledger.generate_report(1, &[&rent, &laundry], 0);
+
+        assert_eq!(report.rent_income, 1000);
+        assert_eq!(report.laundry_income, 60);
+        assert_eq!(report.net, 1060);
+    }
+
+    #[test]
+    fn storage_income_is_its_own_line_item() {
+        let rent = Transaction::income(TransactionType::RentIncome, 1000, "Rent", 1);
+        let storage = Transaction::income(TransactionType::StorageIncome, 80, "Lockers", 1);
+        let mut ledger = FinancialLedger::new();
+
+        let report = ledger.generate_report(1, &[&rent, &storage], 0);
+
+        assert_eq!(report.rent_income, 1000);
+        assert_eq!(report.storage_income, 80);
+        assert_eq!(report.net, 1080);
+    }
+
+    #[test]
+    fn pet_deposits_are_held_not_earned() {
+        let rent = Transaction::income(TransactionType::RentIncome, 1000, "Rent", 1);
+        let deposit = Transaction::income(TransactionType::PetDeposit, 300, "Pet deposit", 1);
+        let mut ledger = FinancialLedger::new();
+
+        let report = ledger.generate_report(1, &[&rent, &deposit], 0);
+
+        assert_eq!(report.rent_income, 1000);
+        assert_eq!(report.net, 1000);
+    }
+}
@@ -6,6 +6,7 @@ pub enum TransactionType {
     RepairCost,
     UpgradeCost,
     HallwayRepair,
+    AmenityRepair, // Restoring a worn shared amenity
     BuildingPurchase,
     AssetSale,
     PropertyTax,
@@ -17,7 +18,22 @@ pub enum TransactionType {
     Marketing,
     Vetting,
     InspectionFine,
-    Grant, // Mission rewards, grants, bonuses
+    ComplianceFine,       // Recurring fines for missing a city mandate
+    VacancyTax,           // City tax on long-empty units
+    RelocationAssistance, // Paid to tenants displaced by eviction or condo sale
+    PermitFee,            // Building permit applications
+    LicenseFee,           // Annual landlord license renewal
+    LegalFees,            // Contesting tenant claims in court
+    SquatterRemoval,      // Clearing squatters out of a vacant unit
+    SecurityDeposit,      // Deposits collected from tenants
+    PetDeposit,           // Extra deposit held against pet damage
+    DepositRefund,        // Deposits returned to departing tenants
+    Mediation,
+    ParkingIncome,
+    LaundryIncome,
+    StorageIncome,
+    HoaIncome, // HOA from tenants who bought their units
+    Grant,     // Mission rewards, grants, bonuses
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+
+/// Monthly payment that pays off `principal` over `term_months` at
+/// `annual_rate` (0.06 = 6%).
+pub fn amortized_payment(principal: f32, annual_rate: f32, term_months: u32) -> i32 {
+    if term_months == 0 {
+        return 0;
+    }
+    let monthly_rate = annual_rate / 12.0;
+    if monthly_rate <= 0.0 {
+        return (principal / term_months as f32).round() as i32;
+    }
+    let factor = (1.0 + monthly_rate).powf(term_months as f32);
+    (principal * monthly_rate * factor / (factor - 1.0)).round() as i32
+}
+
+/// A loan taken out to buy one of the player's buildings.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActiveMortgage {
+    pub building_name: String,
+    pub principal_remaining: f32,
+    pub interest_rate: f32,
+    pub months_remaining: u32,
+    pub monthly_payment: i32,
+    /// Fixed-rate loans ignore changes in the city's interest rate.
+    pub fixed_rate: bool,
+}
+
+impl ActiveMortgage {
+    pub fn new(
+        building_name: &str,
+        principal: f32,
+        interest_rate: f32,
+        term_months: u32,
+        fixed_rate: bool,
+    ) -> Self {
+        Self {
+            building_name: building_name.to_string(),
+            principal_remaining: principal,
+            interest_rate,
+            months_remaining: term_months,
+            monthly_payment: amortized_payment(principal, interest_rate, term_months),
+            fixed_rate,
+        }
+    }
+
+    /// Move a variable-rate loan to `rate`, re-spreading what's left over
+    /// the remaining term. Returns whether the payment changed.
+    pub fn reprice(&mut self, rate: f32) -> bool {
+        if self.fixed_rate || self.months_remaining == 0 {
+            return false;
+        }
+        self.interest_rate = rate;
+        let payment = amortized_payment(self.principal_remaining, rate, self.months_remaining);
+        let changed = payment != self.monthly_payment;
+        self.monthly_payment = payment;
+        changed
+    }
+
+    /// Take one month's payment off the balance and return what's due.
+    pub fn make_payment(&mut self) -> i32 {
+        if self.months_remaining == 0 {
+            return 0;
+        }
+        let interest = self.principal_remaining * self.interest_rate / 12.0;
+        let payment = if self.months_remaining == 1 {
+            (self.principal_remaining + interest).round() as i32
+        } else {
+            self.monthly_payment
+        };
+        self.principal_remaining = (self.principal_remaining + interest - payment as f32).max(0.0);
+        self.months_remaining -= 1;
+        payment
+    }
+
+    pub fn is_paid_off(&self) -> bool {
+        self.months_remaining == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loan_is_paid_off_over_its_term() {
+        let mut mortgage = ActiveMortgage::new("Test", 80_000.0, 0.06, 120, false);
+        for _ in 0..120 {
+            mortgage.make_payment();
+        }
+        assert!(mortgage.is_paid_off());
+        assert!(mortgage.principal_remaining < 1.0);
+    }
+
+    #[test]
+    fn only_variable_loans_follow_the_rate() {
+        let mut variable = ActiveMortgage::new("Test", 80_000.0, 0.06, 120, false);
+        let mut fixed = ActiveMortgage::new("Test", 80_000.0, 0.06, 120, true);
+        let payment = variable.monthly_payment;
+
+        assert!(variable.reprice(0.09));
+        assert!(variable.monthly_payment > payment);
+        assert!(!fixed.reprice(0.09));
+        assert_eq!(fixed.monthly_payment, payment);
+    }
+}
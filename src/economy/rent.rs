@@ -21,6 +21,7 @@ pub struct RentPayment {
 
 #[derive(Clone, Debug)]
 pub struct MissedPayment {
+    pub tenant_id: u32,
     pub tenant_name: String,
     pub _apartment_unit: String,
     pub amount: i32,
@@ -34,6 +35,8 @@ pub fn collect_rent(
     funds: &mut PlayerFunds,
     current_tick: u32,
     risk: &TenantRiskConfig,
+    month_to_month_premium: i32,
+    max_rent_to_income: f32,
 ) -> RentCollection {
     let mut collection = RentCollection {
         total_collected: 0,
@@ -44,33 +47,76 @@ pub fn collect_rent(
     for tenant in tenants {
         if let Some(apt_id) = tenant.apartment_id {
             if let Some(apartment) = building.get_apartment(apt_id) {
-                // Very unhappy tenants might miss payment
-                if tenant.happiness < 20 && rng::gen_range(0, 100) < 30 {
-                    collection.missed_payments.push(MissedPayment {
-                        tenant_name: tenant.name.clone(),
-                        _apartment_unit: apartment.unit_number.clone(),
-                        amount: apartment.rent_price,
-                        _reason: "Tenant too unhappy".to_string(),
-                    });
+                // Owner-occupiers pay HOA, not rent.
+                if building.is_unit_sold(apt_id) {
                     continue;
                 }
 
-                // Unreliable tenants may skip rent even when otherwise content —
-                // this is the cost of accepting an applicant who failed vetting.
-                if tenant.rent_reliability < risk.unreliable_threshold
+                // A lapsed lease costs the tenant a premium on top of rent.
+                let rent = if tenant.is_month_to_month {
+                    apartment.rent_price + month_to_month_premium
+                } else {
+                    apartment.rent_price
+                };
+
+                let missed_reason = if apartment.on_rent_strike() {
+                    Some("On rent strike")
+                } else if tenant.is_fraudulent {
+                    Some("Fraudulent tenant never pays")
+                } else if tenant.happiness < 20 && rng::gen_range(0, 100) < 30 {
+                    // Very unhappy tenants might miss payment
+                    Some("Tenant too unhappy")
+                } else if tenant.rent_reliability < risk.unreliable_threshold
                     && rng::gen_range(0, 100) < risk.skip_rent_chance_percent
                 {
+                    // Unreliable tenants may skip rent even when otherwise content —
+                    // this is the cost of accepting an applicant who failed vetting.
+                    Some("Unreliable tenant skipped rent")
+                } else if tenant
+                    .rent_to_income_ratio(rent)
+                    .is_some_and(|ratio| ratio > max_rent_to_income)
+                    && rng::gen_range(0, 100) < risk.rent_burdened_skip_chance_percent
+                {
+                    // A verified income the rent has outgrown.
+                    Some("Rent is more than their income can bear")
+                } else {
+                    None
+                };
+
+                if let Some(reason) = missed_reason {
+                    // The city pays a voucher holder's guaranteed portion directly.
+                    let covered = if tenant.has_housing_voucher {
+                        tenant.voucher_amount.clamp(0, apartment.rent_price)
+                    } else {
+                        0
+                    };
+                    if covered > 0 {
+                        funds.add_income(Transaction::income(
+                            TransactionType::RentIncome,
+                            covered,
+                            &format!(
+                                "Housing voucher for {} (Unit {})",
+                                tenant.name, apartment.unit_number
+                            ),
+                            current_tick,
+                        ));
+                        collection.payments.push(RentPayment {
+                            tenant_name: tenant.name.clone(),
+                            _apartment_unit: apartment.unit_number.clone(),
+                            amount: covered,
+                        });
+                        collection.total_collected += covered;
+                    }
                     collection.missed_payments.push(MissedPayment {
+                        tenant_id: tenant.id,
                         tenant_name: tenant.name.clone(),
                         _apartment_unit: apartment.unit_number.clone(),
-                        amount: apartment.rent_price,
-                        _reason: "Unreliable tenant skipped rent".to_string(),
+                        amount: rent - covered,
+                        _reason: reason.to_string(),
                     });
                     continue;
                 }
 
-                let rent = apartment.rent_price;
-
                 funds.add_income(Transaction::income(
                     TransactionType::RentIncome,
                     rent,
@@ -97,6 +143,8 @@ mod tests {
     use super::*;
     use crate::tenant::{Tenant, TenantArchetype};
 
+    const NO_RATIO_LIMIT: f32 = f32::INFINITY;
+
     #[test]
     fn unreliable_tenant_skips_rent() {
         let building = Building::new("Test", 1, 1);
@@ -115,7 +163,86 @@ mod tests {
             ..TenantRiskConfig::default()
         };
 
-        let collection = collect_rent(&tenants, &building, &mut funds, 1, &risk);
+        let collection = collect_rent(&tenants, &building, &mut funds, 1, &risk, 0, NO_RATIO_LIMIT);
+        assert_eq!(collection.total_collected, 0);
+        assert_eq!(collection.missed_payments.len(), 1);
+    }
+
+    #[test]
+    fn fraudulent_tenant_never_pays() {
+        let building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        let mut funds = PlayerFunds::new(1000);
+
+        let mut tenant = Tenant::new(1, "Forger", TenantArchetype::Professional);
+        tenant.happiness = 80;
+        tenant.rent_reliability = 95;
+        tenant.is_fraudulent = true;
+        tenant.apartment_id = Some(apt_id);
+
+        let risk = TenantRiskConfig::default();
+        let collection = collect_rent(
+            &[tenant],
+            &building,
+            &mut funds,
+            1,
+            &risk,
+            0,
+            NO_RATIO_LIMIT,
+        );
+        assert_eq!(collection.total_collected, 0);
+        assert_eq!(collection.missed_payments.len(), 1);
+    }
+
+    #[test]
+    fn voucher_covers_part_of_missed_rent() {
+        let building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        let rent = building.apartments[0].rent_price;
+        let mut funds = PlayerFunds::new(1000);
+
+        let mut tenant = Tenant::new(1, "Covered", TenantArchetype::Student);
+        tenant.happiness = 80;
+        tenant.rent_reliability = 10;
+        tenant.apartment_id = Some(apt_id);
+        tenant.has_housing_voucher = true;
+        tenant.voucher_amount = rent / 2;
+        let tenants = vec![tenant];
+
+        let risk = TenantRiskConfig {
+            unreliable_threshold: 100,
+            skip_rent_chance_percent: 100,
+            ..TenantRiskConfig::default()
+        };
+
+        let collection = collect_rent(&tenants, &building, &mut funds, 1, &risk, 0, NO_RATIO_LIMIT);
+        assert_eq!(collection.total_collected, rent / 2);
+        assert_eq!(collection.missed_payments[0].amount, rent - rent / 2);
+    }
+
+    #[test]
+    fn striking_tenant_pays_nothing() {
+        let mut building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        building.apartments[0]
+            .flags
+            .insert(crate::building::RENT_STRIKE_FLAG.to_string());
+        let mut funds = PlayerFunds::new(1000);
+
+        let mut tenant = Tenant::new(1, "Organizer", TenantArchetype::Artist);
+        tenant.happiness = 80;
+        tenant.rent_reliability = 95;
+        tenant.apartment_id = Some(apt_id);
+
+        let collection = collect_rent(
+            &[tenant],
+            &building,
+            &mut funds,
+            1,
+            &TenantRiskConfig::default(),
+            0,
+            NO_RATIO_LIMIT,
+        );
         assert_eq!(collection.total_collected, 0);
         assert_eq!(collection.missed_payments.len(), 1);
     }
@@ -138,8 +265,60 @@ mod tests {
             &mut funds,
             1,
             &TenantRiskConfig::default(),
+            0,
+            NO_RATIO_LIMIT,
         );
         assert_eq!(collection.missed_payments.len(), 0);
         assert!(collection.total_collected > 0);
     }
+
+    #[test]
+    fn month_to_month_tenant_pays_premium() {
+        let building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        let rent = building.apartments[0].rent_price;
+        let mut funds = PlayerFunds::new(1000);
+
+        let mut tenant = Tenant::new(1, "Lapsed", TenantArchetype::Professional);
+        tenant.happiness = 80;
+        tenant.rent_reliability = 95;
+        tenant.apartment_id = Some(apt_id);
+        tenant.go_month_to_month();
+
+        let collection = collect_rent(
+            &[tenant],
+            &building,
+            &mut funds,
+            1,
+            &TenantRiskConfig::default(),
+            50,
+            NO_RATIO_LIMIT,
+        );
+        assert_eq!(collection.total_collected, rent + 50);
+    }
+
+    #[test]
+    fn rent_burdened_tenant_may_skip_rent() {
+        let building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        let rent = building.apartments[0].rent_price;
+        let mut funds = PlayerFunds::new(1000);
+
+        let mut tenant = Tenant::new(1, "Stretched", TenantArchetype::Professional);
+        tenant.happiness = 80;
+        tenant.rent_reliability = 95;
+        tenant.apartment_id = Some(apt_id);
+        tenant.verified_income = Some(rent * 2);
+        let tenants = vec![tenant];
+        let risk = TenantRiskConfig {
+            rent_burdened_skip_chance_percent: 100,
+            ..TenantRiskConfig::default()
+        };
+
+        let collection = collect_rent(&tenants, &building, &mut funds, 1, &risk, 0, 0.4);
+        assert_eq!(collection.missed_payments.len(), 1);
+
+        let collection = collect_rent(&tenants, &building, &mut funds, 1, &risk, 0, 0.6);
+        assert!(collection.missed_payments.is_empty());
+    }
 }
@@ -1,6 +1,6 @@
 use crate::assets::AssetManager;
 use crate::data::config::{load_config, GameConfig};
-use crate::state::{GameState, MenuState, StateTransition};
+use crate::state::{GameState, MenuState, SlotSelectState, StateTransition};
 
 pub struct Game {
     pub state: GameState,
@@ -25,6 +25,7 @@ impl Game {
     pub fn update(&mut self) {
         let transition = match &mut self.state {
             GameState::Menu(s) => s.update(&self.assets, &self.config),
+            GameState::SlotSelect(s) => s.update(&self.config),
             GameState::Gameplay(s) => s.update(&self.assets),
         };
 
@@ -36,6 +37,7 @@ impl Game {
     pub fn draw(&mut self) {
         match &mut self.state {
             GameState::Menu(s) => s.draw(&self.assets),
+            GameState::SlotSelect(s) => s.draw(),
             GameState::Gameplay(s) => s.draw(&self.assets),
         }
     }
@@ -43,7 +45,14 @@ impl Game {
     fn transition(&mut self, transition: StateTransition) {
         self.state = match transition {
             StateTransition::ToMenu => GameState::Menu(MenuState::new()),
+            StateTransition::ToSlotSelect(template) => {
+                GameState::SlotSelect(SlotSelectState::new(template))
+            }
             StateTransition::ToGameplay(s) => GameState::Gameplay(s),
+            StateTransition::ToGameplayFromSlot(slot) => match crate::save::load_game_slot(slot) {
+                Some(s) => GameState::Gameplay(s),
+                None => GameState::Menu(MenuState::new()),
+            },
         };
     }
 
@@ -62,6 +71,7 @@ impl Game {
                         let state = crate::state::GameplayState::new_with_template(
                             self.config.clone(),
                             template,
+                            crate::data::config::DifficultyPreset::Normal,
                         );
                         self.state = GameState::Gameplay(state);
                     }
@@ -6,23 +6,57 @@
 //! - `Mail`: In-game messaging system.
 //! - `Tutorial`: Guided introduction flow.
 //! - `Missions`: Quests and objectives.
+//! - `Scenarios`: Preset challenges with objectives and a deadline.
 //! - `Notifications`: Game hints and relationship change pop-ups.
+//! - `Journalist`: A local reporter whose stories move the building's appeal.
 
+mod abandonment_events;
+mod aging_events;
+mod assessment_events;
 pub mod dialogue; // Make public so DialogueEffect is accessible
 pub mod events;
+mod fair_housing_events;
+mod fire_events;
+mod first_refusal_events;
+mod journalist;
+mod landmark_events;
 mod mail;
 pub(crate) mod missions;
 pub mod notifications;
+mod rent_freeze_events;
+mod request_conditions;
+pub mod scenarios;
 mod stories;
 mod tutorial;
+mod union_events;
+mod utility_events;
 
+pub use abandonment_events::belongings_left_behind;
+pub use aging_events::{lead_paint_discovery, structural_weakening};
+pub use assessment_events::{special_assessment_improvements, AssessmentResponse};
 pub use dialogue::DialogueSystem;
 pub use events::{NarrativeEvent, NarrativeEventSystem};
-pub use mail::Mailbox;
-pub use missions::{ActiveTaxBreak, MissionGoal, MissionManager, MissionReward, MissionStatus};
+pub use fair_housing_events::fair_housing_investigation;
+pub use fire_events::catastrophic_fire;
+pub use first_refusal_events::first_refusal_invoked;
+pub use journalist::{Journalist, JournalistArticle, PressSnapshot};
+pub use landmark_events::landmark_proposal;
+pub use mail::{MailAction, MailItem, Mailbox};
+pub use missions::{
+    ActiveTaxBreak, GrantRequirement, MissionGoal, MissionManager, MissionReward, MissionStatus,
+};
 pub use notifications::{NotificationCategory, NotificationManager, RelationshipChange};
+pub use rent_freeze_events::{rent_freeze_declared, rent_freeze_lifted};
+pub use request_conditions::{
+    ApprovedCondition, RequestCondition, COUNTER_OFFER_HOURS, FULL_BUSINESS_HOURS,
+    PET_TRIAL_MONTHS, REJECTED_COUNTER_HAPPINESS_HIT,
+};
+pub use scenarios::{load_scenarios, ActiveScenario, Scenario};
 pub use stories::{LifeChangeType, StoryImpact, TenantRequest, TenantStory};
 pub use tutorial::{TutorialManager, TutorialMilestone};
+pub use union_events::{
+    buyout_approved, buyout_rejected, council_demand, rent_strike, union_formation,
+};
 pub mod achievements;
 pub use achievements::AchievementSystem;
 pub mod events_config;
@@ -0,0 +1,41 @@
+//! A tenant walked out and left their belongings in the unit.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// The landlord can pay to box up and store what was left, or donate it.
+pub fn belongings_left_behind(
+    month: u32,
+    tenant_name: &str,
+    unit_number: &str,
+    storage_cost: i32,
+) -> NarrativeEvent {
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::CityEvent,
+        month,
+        "Belongings Left Behind",
+        &format!(
+            "{} left Unit {} without notice. Furniture, clothes and boxes of \
+             papers are still inside.",
+            tenant_name, unit_number
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Store them".to_string(),
+                description: format!("Pay ${} to keep them safe for collection", storage_cost),
+                effect: NarrativeEffect::Money {
+                    amount: -storage_cost,
+                },
+                reputation_change: 2,
+            },
+            NarrativeChoice {
+                label: "Donate them".to_string(),
+                description: "Clear the unit for free".to_string(),
+                effect: NarrativeEffect::None,
+                reputation_change: 0,
+            },
+        ],
+    );
+    event.default_effect = NarrativeEffect::None;
+    event
+}
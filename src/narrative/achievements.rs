@@ -2,20 +2,88 @@ use crate::building::Building;
 use crate::city::City;
 use crate::data::config::GameConfig;
 use crate::economy::PlayerFunds;
-use crate::tenant::Tenant;
+use crate::simulation::LifetimeStats;
+use crate::tenant::{Tenant, TenantArchetype};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AchievementCondition {
-    TotalTenants { min: usize },
-    Funds { min: i32 },
-    AvgHappiness { max: i32 },     // average happiness at or below a value
-    HappinessAtLeast { min: i32 }, // average happiness at or above a value
-    MaxReputation { min: i32 },
+    TotalTenants {
+        min: usize,
+    },
+    Funds {
+        min: i32,
+    },
+    /// Average happiness at or below a value.
+    AvgHappiness {
+        max: i32,
+    },
+    /// Average happiness at or above a value.
+    HappinessAtLeast {
+        min: i32,
+    },
+    MaxReputation {
+        min: i32,
+    },
     FullOccupancy,
     GameComplete,
+    /// At least one tenant of every archetype at once.
+    AllArchetypes,
+    /// Every tenant gone and every unit standing empty.
+    EmptyBuilding,
+    /// Rent collected over the whole career.
+    LifetimeRent {
+        min: i32,
+    },
+    /// Tenants moved in over the whole career.
+    LifetimeTenantsHoused {
+        min: u32,
+    },
+    /// Upgrades installed over the whole career.
+    LifetimeUpgrades {
+        min: u32,
+    },
+    /// Inspector visits in a row graded C or better.
+    InspectionStreak {
+        min: u32,
+    },
+}
+
+impl AchievementCondition {
+    /// Player-facing unlock requirement, for the achievements view.
+    pub fn describe(&self) -> String {
+        match self {
+            AchievementCondition::TotalTenants { min } => format!("House {} tenants", min),
+            AchievementCondition::Funds { min } => format!("Reach ${} in funds", min),
+            AchievementCondition::AvgHappiness { max } => {
+                format!("Average tenant happiness at or below {}%", max)
+            }
+            AchievementCondition::HappinessAtLeast { min } => {
+                format!("Average tenant happiness at or above {}%", min)
+            }
+            AchievementCondition::MaxReputation { min } => {
+                format!("Any neighborhood reputation of {}", min)
+            }
+            AchievementCondition::FullOccupancy => "Fill every apartment".to_string(),
+            AchievementCondition::GameComplete => "Finish the game".to_string(),
+            AchievementCondition::AllArchetypes => "House every kind of tenant at once".to_string(),
+            AchievementCondition::EmptyBuilding => "End with every unit empty".to_string(),
+            AchievementCondition::LifetimeRent { min } => {
+                format!("Collect ${} in rent over your career", min)
+            }
+            AchievementCondition::LifetimeTenantsHoused { min } => {
+                format!("Move in {} tenants over your career", min)
+            }
+            AchievementCondition::LifetimeUpgrades { min } => {
+                format!("Install {} upgrades over your career", min)
+            }
+            AchievementCondition::InspectionStreak { min } => {
+                format!("Pass {} inspector visits in a row", min)
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,6 +92,9 @@ pub struct Achievement {
     pub name: String,
     pub description: String,
     pub condition: AchievementCondition,
+    /// Hidden achievements show as "???" until unlocked.
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -44,16 +115,35 @@ impl AchievementSystem {
         self.unlocked.insert(id.to_string());
     }
 
+    pub fn get(&self, id: &str) -> Option<&Achievement> {
+        self.list.iter().find(|a| a.id == id)
+    }
+
     pub fn is_unlocked(&self, id: &str) -> bool {
         self.unlocked.contains(id)
     }
 
+    /// Inspection streak achievements earned by a run of `streak` passing
+    /// visits, so they unlock on the visit rather than at game end.
+    pub fn inspection_streak_unlocks(&self, streak: u32) -> Vec<String> {
+        self.list
+            .iter()
+            .filter(|a| !self.is_unlocked(&a.id))
+            .filter(|a| match a.condition {
+                AchievementCondition::InspectionStreak { min } => streak >= min,
+                _ => false,
+            })
+            .map(|a| a.id.clone())
+            .collect()
+    }
+
     pub fn check_new_unlocks(
         &self,
         city: &City,
         building: &Building,
         tenants: &[Tenant],
         funds: &PlayerFunds,
+        lifetime: &LifetimeStats,
         current_tick: u32,
         config: &GameConfig,
     ) -> Vec<String> {
@@ -88,7 +178,9 @@ impl AchievementSystem {
                 }
                 AchievementCondition::MaxReputation { min } => {
                     // Check all neighborhoods
-                    city.neighborhoods.iter().any(|n| n.reputation >= *min)
+                    city.neighborhoods
+                        .iter()
+                        .any(|n| n.effective_reputation() >= *min)
                 }
                 AchievementCondition::FullOccupancy => {
                     // Check if all apartments have a tenant
@@ -103,6 +195,25 @@ impl AchievementSystem {
                 AchievementCondition::GameComplete => {
                     current_tick >= config.win_conditions.game_duration_ticks.unwrap_or(36)
                 }
+                AchievementCondition::AllArchetypes => {
+                    let housed: HashSet<_> = tenants.iter().map(|t| &t.archetype).collect();
+                    housed.len() >= TenantArchetype::COUNT
+                }
+                AchievementCondition::EmptyBuilding => {
+                    tenants.is_empty()
+                        && !building.apartments.is_empty()
+                        && building.apartments.iter().all(|a| a.is_vacant())
+                }
+                AchievementCondition::LifetimeRent { min } => lifetime.total_rent_collected >= *min,
+                AchievementCondition::LifetimeTenantsHoused { min } => {
+                    lifetime.total_tenants_housed >= *min
+                }
+                AchievementCondition::LifetimeUpgrades { min } => {
+                    lifetime.total_upgrades_applied >= *min
+                }
+                AchievementCondition::InspectionStreak { min } => {
+                    lifetime.best_inspection_streak >= *min
+                }
             };
 
             if condition_met {
@@ -150,5 +261,7 @@ mod tests {
             u.len()
         };
         assert_eq!(ids.len(), unique, "duplicate achievement ids");
+        // Some achievements are kept secret until earned.
+        assert!(system.list.iter().any(|a| a.hidden));
     }
 }
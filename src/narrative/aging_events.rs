@@ -0,0 +1,105 @@
+//! Events that only an old building can produce. Each one asks for an
+//! expensive fix; ignoring it costs tenant goodwill and property value.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// An inspector finds lead paint under the old layers.
+pub fn lead_paint_discovery(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    cost: i32,
+) -> NarrativeEvent {
+    let ignore = NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::BuildingHappiness {
+                building_id,
+                change: -15,
+            },
+            NarrativeEffect::TriggerInspection { building_id },
+        ],
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::AgingBuilding,
+        month,
+        "Lead Paint Discovery",
+        &format!(
+            "The inspector scraped a sample from {}'s stairwell: decades-old lead paint. \
+             Tenants with children are asking what you plan to do.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Full Abatement".to_string(),
+                description: format!("Strip and repaint every unit (${})", cost),
+                effect: NarrativeEffect::Multiple {
+                    effects: vec![
+                        NarrativeEffect::Money { amount: -cost },
+                        NarrativeEffect::BuildingHappiness {
+                            building_id,
+                            change: 5,
+                        },
+                    ],
+                },
+                reputation_change: 5,
+            },
+            NarrativeChoice {
+                label: "Paint Over It".to_string(),
+                description: "Cheap, and the inspector will be back".to_string(),
+                effect: ignore.clone(),
+                reputation_change: -10,
+            },
+        ],
+    );
+    event.default_effect = ignore;
+    event
+}
+
+/// Cracks in the load-bearing walls of a building past its prime.
+pub fn structural_weakening(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    cost: i32,
+) -> NarrativeEvent {
+    let ignore = NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::PropertyValue {
+                building_id,
+                change_percent: -15.0,
+            },
+            NarrativeEffect::BuildingHappiness {
+                building_id,
+                change: -10,
+            },
+        ],
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::AgingBuilding,
+        month,
+        "Structural Weakening",
+        &format!(
+            "An engineer reports hairline cracks spreading through {}'s foundation. \
+             The building has stood a long time, but not without help.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Reinforce".to_string(),
+                description: format!("Steel bracing and underpinning (${})", cost),
+                effect: NarrativeEffect::Money { amount: -cost },
+                reputation_change: 3,
+            },
+            NarrativeChoice {
+                label: "Monitor It".to_string(),
+                description: "Save the money; the building loses value".to_string(),
+                effect: ignore.clone(),
+                reputation_change: -5,
+            },
+        ],
+    );
+    event.default_effect = ignore;
+    event
+}
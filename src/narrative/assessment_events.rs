@@ -0,0 +1,164 @@
+//! Special assessments: a gentrifying neighborhood billing its property
+//! owners for a public improvement project.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+use crate::city::Neighborhood;
+use crate::data::config::SpecialAssessmentConfig;
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+/// How the player answered a special assessment.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AssessmentResponse {
+    Pay,
+    Finance,
+    Challenge,
+}
+
+/// Is this the month the city bills `neighborhood` for improvements?
+pub fn special_assessment_due(
+    month: u32,
+    neighborhood: &Neighborhood,
+    config: &SpecialAssessmentConfig,
+) -> bool {
+    month % 12 == config.month_of_year
+        && neighborhood.stats.gentrification > config.min_gentrification
+        && !neighborhood.building_ids.is_empty()
+}
+
+/// What the project does for the neighborhood once it's paid for.
+pub fn special_assessment_improvements(
+    neighborhood_id: u32,
+    config: &SpecialAssessmentConfig,
+) -> NarrativeEffect {
+    NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::TransitImprovement {
+                neighborhood_id,
+                change: config.transit_gain,
+            },
+            NarrativeEffect::NeighborhoodReputation {
+                neighborhood_id,
+                change: config.reputation_gain,
+            },
+        ],
+    }
+}
+
+/// The city bills every building the player owns in the neighborhood for
+/// its share of one of `projects`.
+pub fn special_assessment(
+    month: u32,
+    neighborhood: &Neighborhood,
+    projects: &[String],
+    config: &SpecialAssessmentConfig,
+) -> NarrativeEvent {
+    let neighborhood_id = neighborhood.id;
+    let amount_per_building =
+        config.base_amount + neighborhood.stats.gentrification * config.amount_per_gentrification;
+    let project_description = rng::choose(projects)
+        .cloned()
+        .unwrap_or_else(|| "neighborhood improvements".to_string());
+    let amount = amount_per_building * neighborhood.building_ids.len() as i32;
+    let response = |response| NarrativeEffect::SpecialAssessmentResponse {
+        neighborhood_id,
+        amount,
+        response,
+    };
+
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::SpecialAssessment {
+            neighborhood_id,
+            amount_per_building,
+            project_description: project_description.clone(),
+        },
+        month,
+        &format!("Special Assessment for {}", neighborhood.name),
+        &format!(
+            "The city is billing property owners in {} for {}. Your share is ${} \
+             per building, ${} in all.",
+            neighborhood.name, project_description, amount_per_building, amount
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Pay now".to_string(),
+                description: format!("${} today; the project goes ahead", amount),
+                effect: response(AssessmentResponse::Pay),
+                reputation_change: 0,
+            },
+            NarrativeChoice {
+                label: "Finance it".to_string(),
+                description: format!("Spread the bill over {} months", config.finance_months),
+                effect: response(AssessmentResponse::Finance),
+                reputation_change: 0,
+            },
+            NarrativeChoice {
+                label: "Challenge it".to_string(),
+                description: format!(
+                    "A {}-month hearing that may reduce the bill",
+                    config.challenge_months
+                ),
+                effect: response(AssessmentResponse::Challenge),
+                reputation_change: -2,
+            },
+        ],
+    );
+    // Ignored bills are collected anyway.
+    event.default_effect = response(AssessmentResponse::Pay);
+    event.related_neighborhood_id = Some(neighborhood_id);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::city::NeighborhoodType;
+
+    #[test]
+    fn only_gentrified_neighborhoods_with_player_buildings_are_billed() {
+        let config = SpecialAssessmentConfig::default();
+        let billing_month = config.month_of_year;
+        let mut neighborhood = Neighborhood::new(0, NeighborhoodType::Downtown, "Test");
+        neighborhood.stats.gentrification = 60;
+        assert!(!special_assessment_due(
+            billing_month,
+            &neighborhood,
+            &config
+        ));
+
+        neighborhood.building_ids = vec![0, 1];
+        assert!(special_assessment_due(
+            billing_month,
+            &neighborhood,
+            &config
+        ));
+        assert!(!special_assessment_due(
+            billing_month + 1,
+            &neighborhood,
+            &config
+        ));
+
+        let projects = vec!["a pocket park".to_string()];
+        let event = special_assessment(billing_month, &neighborhood, &projects, &config);
+        let NarrativeEventType::SpecialAssessment {
+            amount_per_building,
+            ..
+        } = event.event_type
+        else {
+            panic!("expected a special assessment");
+        };
+        assert_eq!(
+            amount_per_building,
+            config.base_amount + 60 * config.amount_per_gentrification
+        );
+        assert_eq!(event.choices.len(), 3);
+
+        neighborhood.stats.gentrification = config.min_gentrification;
+        assert!(!special_assessment_due(
+            billing_month,
+            &neighborhood,
+            &config
+        ));
+    }
+}
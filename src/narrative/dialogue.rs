@@ -9,6 +9,8 @@ pub enum DialogueType {
     ConflictMediation,
     /// Rent change conversations
     RentNegotiation,
+    /// Meeting an applicant before the lease is signed
+    ApplicantInterview,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -296,6 +298,33 @@ impl DialogueSystem {
         }
     }
 
+    /// Open an interview with an applicant. Their personality traits are
+    /// shown up front; how each approach lands depends on them.
+    pub fn add_interview(
+        &mut self,
+        applicant: &crate::tenant::Tenant,
+        story: &super::TenantStory,
+    ) -> u32 {
+        let traits = if story.personality_traits.is_empty() {
+            "hard to read".to_string()
+        } else {
+            story.personality_traits.join(" and ")
+        };
+        let description = format!(
+            "{} ({}, from {}) would like to meet before signing. They come across as {}.",
+            applicant.name, story.job_title, story.hometown, traits
+        );
+        self.add_dialogue(
+            DialogueType::ApplicantInterview,
+            applicant.id,
+            None,
+            &format!("Interview: {}", applicant.name),
+            &description,
+            interview_choices(applicant.id, &story.personality_traits),
+            None,
+        )
+    }
+
     /// Handle expiring dialogues
     pub fn tick(&mut self, current_month: u32) {
         // Remove expired dialogues
@@ -412,6 +441,61 @@ fn build_choices(template: &DialogueBodyTemplate, ctx: &DialogueContext) -> Vec<
         .collect()
 }
 
+/// Interview approaches: the label, the traits that warm to it, and whether
+/// it moves the applicant's happiness (true) or their opinion of you.
+const INTERVIEW_APPROACHES: [(&str, &[&str], bool); 3] = [
+    (
+        "Chat about the neighbors",
+        &["friendly", "social", "chatty", "easygoing"],
+        false,
+    ),
+    (
+        "Walk through the house rules",
+        &["quiet", "neat", "punctual", "strict"],
+        true,
+    ),
+    (
+        "Keep it short and businesslike",
+        &["private", "reserved", "flexible", "messy"],
+        false,
+    ),
+];
+
+/// Each approach is worth +10 with an applicant whose traits suit it and -10
+/// otherwise. Skipping the interview changes nothing.
+fn interview_choices(applicant_id: u32, traits: &[String]) -> Vec<DialogueChoice> {
+    let mut choices: Vec<DialogueChoice> = INTERVIEW_APPROACHES
+        .iter()
+        .map(|(text, liked, moves_happiness)| {
+            let amount = if traits.iter().any(|t| liked.contains(&t.as_str())) {
+                10
+            } else {
+                -10
+            };
+            let effect = if *moves_happiness {
+                DialogueEffect::HappinessChange {
+                    tenant_id: applicant_id,
+                    amount,
+                }
+            } else {
+                DialogueEffect::OpinionChange {
+                    tenant_id: applicant_id,
+                    amount,
+                }
+            };
+            DialogueChoice {
+                text: text.to_string(),
+                effects: vec![effect],
+            }
+        })
+        .collect();
+    choices.push(DialogueChoice {
+        text: "Skip Interview".to_string(),
+        effects: Vec::new(),
+    });
+    choices
+}
+
 fn load_dialogue_bodies() -> DialogueBodies {
     #[cfg(target_arch = "wasm32")]
     let json = include_str!("../../assets/dialogue_bodies.json").to_string();
@@ -463,7 +547,7 @@ mod tests {
         ];
         let mut network = TenantNetwork::new();
         // A strong negative change with no prior relationship creates a Hostile one.
-        network.apply_relationship_change(1, 2, -60);
+        network.apply_relationship_change(1, 2, -60, 0);
 
         let mut system = DialogueSystem::new();
         let bodies = load_dialogue_bodies();
@@ -507,6 +591,24 @@ mod tests {
         assert!(bodies.rent_negotiation.is_some());
     }
 
+    #[test]
+    fn interview_approaches_land_according_to_personality() {
+        let choices = interview_choices(7, &["chatty".to_string()]);
+        assert_eq!(choices.len(), 4);
+        assert!(matches!(
+            choices[0].effects[..],
+            [DialogueEffect::OpinionChange {
+                tenant_id: 7,
+                amount: 10
+            }]
+        ));
+        assert!(matches!(
+            choices[1].effects[..],
+            [DialogueEffect::HappinessChange { amount: -10, .. }]
+        ));
+        assert!(choices[3].effects.is_empty());
+    }
+
     #[test]
     fn test_dialogue_resolution() {
         let mut system = DialogueSystem::new();
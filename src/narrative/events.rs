@@ -1,3 +1,8 @@
+use super::assessment_events::{special_assessment, special_assessment_due, AssessmentResponse};
+use super::utility_events::utility_rate_change;
+use crate::data::config::{
+    EventProbabilityConfig, EventRoll, SpecialAssessmentConfig, UtilitySpikeConfig,
+};
 use macroquad_toolkit::rng;
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +26,18 @@ pub enum NarrativeEventType {
     SeasonalEvent,
     /// Relationship event (hostile/friendly interaction)
     RelationshipEvent,
+    /// Problems that only surface in old buildings (lead paint, weakening)
+    AgingBuilding,
+    /// Fires and other catastrophes
+    Disaster,
+    /// The tenant union organizing, making demands, or striking
+    TenantCouncilDemand,
+    /// The city billing owners for a neighborhood improvement project
+    SpecialAssessment {
+        neighborhood_id: u32,
+        amount_per_building: i32,
+        project_description: String,
+    },
 }
 
 /// A narrative event with context and choices
@@ -81,8 +98,14 @@ pub enum NarrativeEffect {
     EconomyChange { economy_health_change: f32 },
     /// Rent demand change
     RentDemand { neighborhood_id: u32, change: f32 },
+    /// Transit line built (or cut) in a neighborhood
+    TransitImprovement { neighborhood_id: u32, change: i32 },
+    /// Local schools improve or decline
+    SchoolRating { neighborhood_id: u32, change: i32 },
     /// Trigger an inspection
     TriggerInspection { building_id: u32 },
+    /// Press coverage raising or lowering a building's appeal
+    BuildingAppealChange { building_id: u32, change: i32 },
     /// Property value change
     PropertyValue {
         building_id: u32,
@@ -100,6 +123,37 @@ pub enum NarrativeEffect {
     MoveOut { tenant_id: u32 },
     /// Sell the building (Game Over / Victory)
     SellBuilding { building_id: u32 },
+    /// Recognize the tenant union (lowers the rent hike bar for good)
+    RecognizeTenantUnion,
+    /// Gentrification score change
+    GentrificationChange { change: i32 },
+    /// Accept or decline city landmark designation
+    LandmarkDesignation { building_id: u32, accepted: bool },
+    /// Put a developer's offer to a tenant vote
+    TenantBuyoutVote { building_id: u32, offer: i32 },
+    /// The landlord met or ignored a tenant union demand
+    UnionDemandAnswered { met: bool },
+    /// Raise every unit's rent by a fraction (0.1 = 10%)
+    RaiseAllRents { percent: f32 },
+    /// Sell the whole building to a tenant who claimed first refusal
+    SellToTenant {
+        building_id: u32,
+        tenant_id: u32,
+        price: i32,
+    },
+    /// Fight a tenant's first refusal claim, delaying the original sale
+    ContestFirstRefusal { building_id: u32, price: i32 },
+    /// Utility rates multiplied for a few months
+    UtilityRateChange {
+        multiplier: f32,
+        duration_ticks: u32,
+    },
+    /// Pay, finance or challenge a neighborhood special assessment
+    SpecialAssessmentResponse {
+        neighborhood_id: u32,
+        amount: i32,
+        response: AssessmentResponse,
+    },
     /// Multiple effects
     Multiple { effects: Vec<NarrativeEffect> },
 }
@@ -237,6 +291,44 @@ impl NarrativeEventSystem {
             .collect()
     }
 
+    /// Roll for a utility rate spike. The news is logged here; its effect is
+    /// returned so it can take hold this month rather than when it's read.
+    pub fn generate_utility_rate_event(
+        &mut self,
+        month: u32,
+        config: &UtilitySpikeConfig,
+    ) -> Option<NarrativeEffect> {
+        if rng::gen_range(0, 100) >= config.chance_percent {
+            return None;
+        }
+        let mut event = utility_rate_change(month, config)?;
+        let effect = std::mem::replace(&mut event.default_effect, NarrativeEffect::None);
+        self.add_event(event);
+        Some(effect)
+    }
+
+    /// Bill the player's share of each gentrified neighborhood's yearly
+    /// improvement project.
+    pub fn generate_special_assessments(
+        &mut self,
+        month: u32,
+        neighborhoods: &[crate::city::Neighborhood],
+        config: &SpecialAssessmentConfig,
+    ) {
+        // Project copy lives alongside the news banks (assets/news_events.json).
+        let news = load_news_events();
+        for neighborhood in neighborhoods {
+            if special_assessment_due(month, neighborhood, config) {
+                self.add_event(special_assessment(
+                    month,
+                    neighborhood,
+                    &news.improvement_projects,
+                    config,
+                ));
+            }
+        }
+    }
+
     /// Generate random events based on game state
     pub fn generate_events(
         &mut self,
@@ -244,12 +336,16 @@ impl NarrativeEventSystem {
         neighborhoods: &[crate::city::Neighborhood],
         buildings: &[crate::building::Building],
         _tenants: &[crate::tenant::Tenant],
+        gentrification_score: i32,
+        scaling: &EventProbabilityConfig,
+        union_building: Option<u32>,
     ) {
         // News event copy + effects are data-driven (assets/news_events.json).
         let news = load_news_events();
+        let chance = |base, roll| scaling.scaled_chance(base, roll, month, gentrification_score);
 
         // Chance for neighborhood news
-        if rng::gen_range(0, 100) < 20 {
+        if rng::gen_range(0, 100) < chance(20, EventRoll::NeighborhoodNews) {
             if let Some(neighborhood) = rng::choose(neighborhoods) {
                 let event = Self::neighborhood_event(&news, month, neighborhood);
                 self.add_event(event);
@@ -257,26 +353,48 @@ impl NarrativeEventSystem {
         }
 
         // Chance for city-wide event
-        if rng::gen_range(0, 100) < 10 {
+        if rng::gen_range(0, 100) < chance(10, EventRoll::CityNews) {
             let event = Self::city_event(&news, month);
             self.add_event(event);
         }
 
+        // Transit proposals (uncommon)
+        if rng::gen_range(0, 100) < chance(6, EventRoll::Transit) {
+            if let Some(neighborhood) = rng::choose(neighborhoods) {
+                let event = Self::transit_event(month, neighborhood);
+                self.add_event(event);
+            }
+        }
+
         // Seasonal events
-        let season = (month % 12) / 3; // 0=spring, 1=summer, 2=fall, 3=winter
-        if rng::gen_range(0, 100) < 15 {
+        let season = crate::simulation::Season::from_tick(month).index() as u32;
+        if rng::gen_range(0, 100) < chance(15, EventRoll::Seasonal) {
             let event = Self::seasonal_event(&news, month, season);
             self.add_event(event);
         }
 
         // Developer/investor offers (rare)
-        if rng::gen_range(0, 100) < 5 && !buildings.is_empty() {
+        if rng::gen_range(0, 100) < chance(5, EventRoll::Offer) && !buildings.is_empty() {
             if let Some(building) = rng::choose(buildings) {
                 let building_id = buildings
                     .iter()
                     .position(|b| std::ptr::eq(b, building))
                     .unwrap_or(0) as u32;
-                let event = self.generate_offer_event(month, building_id, building);
+                let union_active = union_building == Some(building_id);
+                let event = self.generate_offer_event(month, building_id, building, union_active);
+                self.add_event(event);
+            }
+        }
+
+        // Protests (escalate late in the game and during gentrification crises)
+        if rng::gen_range(0, 100) < chance(3, EventRoll::Protest) {
+            if let (Some(template), Some(neighborhood)) =
+                (rng::choose(&news.protest), rng::choose(neighborhoods))
+            {
+                let mut event =
+                    NarrativeEvent::news(0, month, &template.headline, &template.description);
+                event.default_effect = template.effect.to_effect(neighborhood.id);
+                event.related_neighborhood_id = Some(neighborhood.id);
                 self.add_event(event);
             }
         }
@@ -329,6 +447,44 @@ impl NarrativeEventSystem {
         event
     }
 
+    fn transit_event(month: u32, neighborhood: &crate::city::Neighborhood) -> NarrativeEvent {
+        let neighborhood_id = neighborhood.id;
+        let mut event = NarrativeEvent::with_choices(
+            0,
+            NarrativeEventType::CityEvent,
+            month,
+            &format!("Transit Line Proposed for {}", neighborhood.name),
+            "The city wants to run a new transit line through the neighborhood. \
+             Residents are split over the construction and the changes it will bring.",
+            vec![
+                NarrativeChoice {
+                    label: "Support the project".to_string(),
+                    description: "Longtime residents resent you, but the line gets built"
+                        .to_string(),
+                    effect: NarrativeEffect::TransitImprovement {
+                        neighborhood_id,
+                        change: 15,
+                    },
+                    reputation_change: -5,
+                },
+                NarrativeChoice {
+                    label: "Oppose the project".to_string(),
+                    description: "Preserve the neighborhood's character; no transit bonus"
+                        .to_string(),
+                    effect: NarrativeEffect::None,
+                    reputation_change: 3,
+                },
+            ],
+        );
+        // Without landlord pressure either way, a scaled-back line goes ahead.
+        event.default_effect = NarrativeEffect::TransitImprovement {
+            neighborhood_id,
+            change: 5,
+        };
+        event.related_neighborhood_id = Some(neighborhood_id);
+        event
+    }
+
     fn seasonal_event(news: &NewsEventsConfig, month: u32, season: u32) -> NarrativeEvent {
         // Pick at random among the templates tagged for the current season, so
         // the same seasonal beat doesn't recur every single year.
@@ -355,6 +511,7 @@ impl NarrativeEventSystem {
         month: u32,
         building_id: u32,
         building: &crate::building::Building,
+        union_active: bool,
     ) -> NarrativeEvent {
         let base_value = 50000 * building.apartments.len() as i32;
         // Increased offer multiplier to 2.5x - 4.0x base value to be "worth it"
@@ -379,7 +536,7 @@ impl NarrativeEventSystem {
             NarrativeEffect::None
         };
 
-        NarrativeEvent::with_choices(
+        let mut event = NarrativeEvent::with_choices(
             0,
             NarrativeEventType::ExternalOffer,
             month,
@@ -413,7 +570,17 @@ impl NarrativeEventSystem {
                     reputation_change: 5,
                 },
             ],
-        )
+        );
+        // An organized building gets a say in whether it is sold.
+        if union_active {
+            event.choices.push(NarrativeChoice {
+                label: "Let Tenants Decide".to_string(),
+                description: "Put the offer to a tenant vote".to_string(),
+                effect: NarrativeEffect::TenantBuyoutVote { building_id, offer },
+                reputation_change: 3,
+            });
+        }
+        event
     }
 }
 
@@ -456,6 +623,14 @@ impl NewsEffectSpec {
                 neighborhood_id,
                 change: self.amount,
             },
+            "transit_improvement" => NarrativeEffect::TransitImprovement {
+                neighborhood_id,
+                change: self.amount as i32,
+            },
+            "school_rating" => NarrativeEffect::SchoolRating {
+                neighborhood_id,
+                change: self.amount as i32,
+            },
             "economy_change" => NarrativeEffect::EconomyChange {
                 economy_health_change: self.amount,
             },
@@ -474,6 +649,12 @@ struct NewsEventsConfig {
     /// Indexed by season (0=spring, 1=summer, 2=fall, 3=winter).
     #[serde(default)]
     seasonal: Vec<NewsTemplate>,
+    /// Neighborhood-scoped protests, rolled separately so they can escalate.
+    #[serde(default)]
+    protest: Vec<NewsTemplate>,
+    /// Public works a special assessment can bill owners for.
+    #[serde(default)]
+    improvement_projects: Vec<String>,
 }
 
 fn load_news_events() -> NewsEventsConfig {
@@ -513,6 +694,7 @@ mod tests {
         let news = load_news_events();
         assert!(news.neighborhood.len() >= 8);
         assert!(news.city.len() >= 8);
+        assert!(!news.improvement_projects.is_empty());
         // Every season (0..=3) must have at least one seasonal template.
         for season in 0..4 {
             assert!(
@@ -551,6 +733,22 @@ mod tests {
         assert_eq!(event.related_neighborhood_id, Some(7));
     }
 
+    #[test]
+    fn transit_event_support_improves_its_neighborhood() {
+        use crate::city::{Neighborhood, NeighborhoodType};
+        let neighborhood = Neighborhood::new(2, NeighborhoodType::Industrial, "Docks");
+        let event = NarrativeEventSystem::transit_event(4, &neighborhood);
+        assert_eq!(event.related_neighborhood_id, Some(2));
+        assert!(matches!(
+            event.choices[0].effect,
+            NarrativeEffect::TransitImprovement {
+                neighborhood_id: 2,
+                change
+            } if change > 0
+        ));
+        assert!(matches!(event.choices[1].effect, NarrativeEffect::None));
+    }
+
     #[test]
     fn expired_event_returns_default_effect() {
         let mut system = NarrativeEventSystem::new();
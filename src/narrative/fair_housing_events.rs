@@ -0,0 +1,42 @@
+//! A fair housing investigation into a landlord's rejection pattern.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// The city has noticed rejections falling on one archetype: settle, or
+/// contest it and have inspectors go over the building.
+pub fn fair_housing_investigation(
+    month: u32,
+    building_id: u32,
+    archetype: &str,
+    fine: i32,
+) -> NarrativeEvent {
+    let settle = NarrativeEffect::Money { amount: -fine };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::CityEvent,
+        month,
+        "Fair Housing Investigation",
+        &format!(
+            "The fair housing office has opened an investigation: your recent \
+             rejections fell almost entirely on {} applicants. Until you accept \
+             the next qualified one, turning them away will be fined.",
+            archetype.to_lowercase()
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Settle".to_string(),
+                description: format!("Pay ${} and cooperate", fine),
+                effect: settle.clone(),
+                reputation_change: -3,
+            },
+            NarrativeChoice {
+                label: "Contest the findings".to_string(),
+                description: "No settlement, but inspectors go over everything".to_string(),
+                effect: NarrativeEffect::TriggerInspection { building_id },
+                reputation_change: -8,
+            },
+        ],
+    );
+    event.default_effect = settle;
+    event
+}
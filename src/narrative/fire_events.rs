@@ -0,0 +1,32 @@
+//! Disasters that follow from letting fire safety lapse.
+
+use super::events::{NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// Fire safety ran out and a fire tore through the building. There is no
+/// choice to make; the damage is done.
+pub fn catastrophic_fire(month: u32, building_id: u32, building_name: &str) -> NarrativeEvent {
+    let mut event = NarrativeEvent::news(
+        0,
+        month,
+        "Fire Guts Apartment Building",
+        &format!(
+            "A fire broke out in {} overnight. With dead extinguishers and no \
+             sprinklers, it spread through several units before crews arrived.",
+            building_name
+        ),
+    );
+    event.event_type = NarrativeEventType::Disaster;
+    event.default_effect = NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::BuildingHappiness {
+                building_id,
+                change: -25,
+            },
+            NarrativeEffect::PropertyValue {
+                building_id,
+                change_percent: -20.0,
+            },
+        ],
+    };
+    event
+}
@@ -0,0 +1,76 @@
+//! A tenant claiming their right of first refusal on a building sale.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// Negotiated price, as a share of the listed price.
+const NEGOTIATED_PRICE_FACTOR: f32 = 0.9;
+
+/// The landlord honors the claim at the appraised value, negotiates off the
+/// listed price, or pays to contest it and delay the original sale.
+#[allow(clippy::too_many_arguments)]
+pub fn first_refusal_invoked(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    tenant_id: u32,
+    tenant_name: &str,
+    appraised_value: i32,
+    listed_price: i32,
+    contest_cost: i32,
+) -> NarrativeEvent {
+    let honor = NarrativeEffect::SellToTenant {
+        building_id,
+        tenant_id,
+        price: appraised_value,
+    };
+    let negotiated_price = (listed_price as f32 * NEGOTIATED_PRICE_FACTOR) as i32;
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::CityEvent,
+        month,
+        "Tenant Invokes Right of First Refusal",
+        &format!(
+            "{} has lived in {} long enough to claim the right to buy it before \
+             anyone else. The sale is on hold until you respond.",
+            tenant_name, building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Honor it".to_string(),
+                description: format!(
+                    "{} buys the whole building at the appraised ${}",
+                    tenant_name, appraised_value
+                ),
+                effect: honor.clone(),
+                reputation_change: 5,
+            },
+            NarrativeChoice {
+                label: "Negotiate".to_string(),
+                description: format!(
+                    "{} buys the whole building at ${}",
+                    tenant_name, negotiated_price
+                ),
+                effect: NarrativeEffect::SellToTenant {
+                    building_id,
+                    tenant_id,
+                    price: negotiated_price,
+                },
+                reputation_change: 0,
+            },
+            NarrativeChoice {
+                label: "Contest legally".to_string(),
+                description: format!(
+                    "Pay ${} in legal fees; the original sale goes ahead after a delay",
+                    contest_cost
+                ),
+                effect: NarrativeEffect::ContestFirstRefusal {
+                    building_id,
+                    price: listed_price,
+                },
+                reputation_change: -10,
+            },
+        ],
+    );
+    event.default_effect = honor;
+    event
+}
@@ -0,0 +1,189 @@
+use super::events::NarrativeEffect;
+use crate::data::config::JournalistConfig;
+use serde::{Deserialize, Serialize};
+
+/// Disposition runs from hostile (-100) to friendly (100).
+const MAX_DISPOSITION: i32 = 100;
+/// Disposition points per point of appeal a story gains or loses.
+const DISPOSITION_PER_APPEAL: i32 = 20;
+/// A displacement story sours the journalist on the landlord a little more.
+const DISPLACEMENT_DISPOSITION_HIT: i32 = 5;
+
+/// One story the journalist filed about the building.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JournalistArticle {
+    pub tick: u32,
+    pub headline: String,
+    pub body: String,
+    /// Appeal the building gains (or loses, if negative) from the story.
+    pub appeal_change: i32,
+}
+
+impl JournalistArticle {
+    pub fn is_positive(&self) -> bool {
+        self.appeal_change > 0
+    }
+
+    pub fn effect(&self, building_id: u32) -> NarrativeEffect {
+        NarrativeEffect::BuildingAppealChange {
+            building_id,
+            change: self.appeal_change,
+        }
+    }
+}
+
+/// What the building looks like from the newsroom.
+pub struct PressSnapshot<'a> {
+    pub building_name: &'a str,
+    pub gentrification_score: i32,
+    pub fully_occupied: bool,
+    pub average_happiness: i32,
+}
+
+/// A local reporter who covers the landlord's choices, for better or worse.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Journalist {
+    pub name: String,
+    /// How kindly they write about the player, -100 to 100.
+    pub disposition: i32,
+    pub articles: Vec<JournalistArticle>,
+    /// Tick of the last exclusive interview; one per article cycle.
+    #[serde(default)]
+    pub last_interview_tick: Option<u32>,
+}
+
+impl Default for Journalist {
+    fn default() -> Self {
+        Self {
+            name: "Marcus Bell, The City Ledger".to_string(),
+            disposition: 0,
+            articles: Vec::new(),
+            last_interview_tick: None,
+        }
+    }
+}
+
+impl Journalist {
+    pub fn article_due(&self, current_tick: u32, interval: u32) -> bool {
+        current_tick > 0 && current_tick.is_multiple_of(interval.max(1))
+    }
+
+    /// An interview is only worth granting once between articles.
+    pub fn can_interview(&self, current_tick: u32, interval: u32) -> bool {
+        self.last_interview_tick
+            .is_none_or(|tick| current_tick >= tick + interval.max(1))
+    }
+
+    pub fn grant_interview(&mut self, current_tick: u32, config: &JournalistConfig) {
+        self.last_interview_tick = Some(current_tick);
+        self.disposition = (self.disposition + config.interview_disposition)
+            .clamp(-MAX_DISPOSITION, MAX_DISPOSITION);
+    }
+
+    /// File a story if there's one to tell: displacement when gentrification
+    /// runs high, a glowing profile when a full building is happy. A friendly
+    /// journalist plays up good news and softens bad news.
+    pub fn file_article(
+        &mut self,
+        current_tick: u32,
+        snapshot: &PressSnapshot,
+        config: &JournalistConfig,
+    ) -> Option<&JournalistArticle> {
+        let slant = self.disposition / DISPOSITION_PER_APPEAL;
+        let (headline, body, appeal_change) =
+            if snapshot.gentrification_score >= config.displacement_gentrification {
+                self.disposition = (self.disposition - DISPLACEMENT_DISPOSITION_HIT)
+                    .clamp(-MAX_DISPOSITION, MAX_DISPOSITION);
+                (
+                    "Developer Displaces Long-Term Residents",
+                    format!(
+                        "Longtime neighbors say they can no longer afford to live near {}. \
+                         Critics blame rising rents and upscale renovations for pushing out \
+                         the families who built the neighborhood.",
+                        snapshot.building_name
+                    ),
+                    -(config.story_appeal - slant).max(1),
+                )
+            } else if snapshot.fully_occupied
+                && snapshot.average_happiness >= config.model_landlord_happiness
+            {
+                (
+                    "Model Landlord Sets Standard for City",
+                    format!(
+                        "Every unit at {} is let, and tenants have nothing but praise for \
+                         how the building is run. Other landlords could take notes.",
+                        snapshot.building_name
+                    ),
+                    (config.story_appeal + slant).max(1),
+                )
+            } else {
+                return None;
+            };
+
+        self.articles.push(JournalistArticle {
+            tick: current_tick,
+            headline: headline.to_string(),
+            body,
+            appeal_change,
+        });
+        self.articles.last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(gentrification_score: i32, average_happiness: i32) -> PressSnapshot<'static> {
+        PressSnapshot {
+            building_name: "Test",
+            gentrification_score,
+            fully_occupied: true,
+            average_happiness,
+        }
+    }
+
+    #[test]
+    fn stories_follow_the_building() {
+        let config = JournalistConfig::default();
+        let mut journalist = Journalist::default();
+
+        let article = journalist
+            .file_article(6, &snapshot(80, 90), &config)
+            .unwrap();
+        assert!(!article.is_positive());
+        assert_eq!(article.headline, "Developer Displaces Long-Term Residents");
+
+        let article = journalist
+            .file_article(12, &snapshot(0, 90), &config)
+            .unwrap();
+        assert!(article.is_positive());
+
+        assert!(journalist
+            .file_article(18, &snapshot(0, 50), &config)
+            .is_none());
+        assert_eq!(journalist.articles.len(), 2);
+    }
+
+    #[test]
+    fn interviews_buy_kinder_coverage() {
+        let config = JournalistConfig::default();
+        let mut cold = Journalist::default();
+        let mut warm = Journalist::default();
+        for _ in 0..4 {
+            warm.grant_interview(0, &config);
+        }
+        assert!(!warm.can_interview(3, config.article_interval));
+        assert!(warm.can_interview(6, config.article_interval));
+
+        let cold_hit = cold
+            .file_article(6, &snapshot(80, 50), &config)
+            .unwrap()
+            .appeal_change;
+        let warm_hit = warm
+            .file_article(6, &snapshot(80, 50), &config)
+            .unwrap()
+            .appeal_change;
+        assert!(warm_hit > cold_hit);
+    }
+}
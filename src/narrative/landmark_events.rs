@@ -0,0 +1,51 @@
+//! The city asking to preserve a historic building as a landmark.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// The city wants to designate the building a landmark: a monthly grant and
+/// lasting prestige, but no more selling units or modernizing it.
+pub fn landmark_proposal(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    subsidy: i32,
+) -> NarrativeEvent {
+    let decline = NarrativeEffect::LandmarkDesignation {
+        building_id,
+        accepted: false,
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::CityEvent,
+        month,
+        "City Proposes Landmark Designation",
+        &format!(
+            "The historic preservation board wants to list {} as a city landmark. \
+             The building would be protected for good, and so would its layout.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Accept".to_string(),
+                description: format!(
+                    "${}/month grant and lasting appeal; no condo sales or modern makeovers",
+                    subsidy
+                ),
+                effect: NarrativeEffect::LandmarkDesignation {
+                    building_id,
+                    accepted: true,
+                },
+                reputation_change: 5,
+            },
+            NarrativeChoice {
+                label: "Decline".to_string(),
+                description: "Keep your options open; the city won't ask again for a year"
+                    .to_string(),
+                effect: decline.clone(),
+                reputation_change: 0,
+            },
+        ],
+    );
+    event.default_effect = decline;
+    event
+}
@@ -18,6 +18,16 @@ pub enum MailType {
     Personal,
     /// Official document
     Official,
+    /// Renewal terms for a tenant's expiring lease
+    LeaseRenewal { tenant_id: u32 },
+    /// Letter to the emergency contact of a tenant who walked out
+    TenantAbandonment { tenant_id: u32 },
+    /// Notice from the bank about a mortgage
+    Mortgage,
+    /// The city's yearly reassessment of a building's value
+    TaxAssessment,
+    /// The insurer's yearly premium renewal
+    InsuranceRenewal,
 }
 
 impl MailType {
@@ -30,6 +40,11 @@ impl MailType {
             MailType::News => "📰",
             MailType::Personal => "💌",
             MailType::Official => "📋",
+            MailType::LeaseRenewal { .. } => "📝",
+            MailType::TenantAbandonment { .. } => "📦",
+            MailType::Mortgage => "🏦",
+            MailType::TaxAssessment => "🏛️",
+            MailType::InsuranceRenewal => "🛡️",
         }
     }
 
@@ -37,8 +52,10 @@ impl MailType {
     pub fn priority(&self) -> i32 {
         match self {
             MailType::CityNotice => 100,
-            MailType::Official => 90,
-            MailType::Financial => 70,
+            MailType::Official | MailType::TaxAssessment => 90,
+            MailType::LeaseRenewal { .. } => 80,
+            MailType::TenantAbandonment { .. } => 75,
+            MailType::Financial | MailType::Mortgage | MailType::InsuranceRenewal => 70,
             MailType::TenantLetter { .. } => 60,
             MailType::Personal => 40,
             MailType::News => 20,
@@ -77,6 +94,26 @@ pub enum MailAction {
     ScheduleInspection { building_id: u32 },
     /// Accept or reject an offer
     Offer { amount: i32, expires_month: u32 },
+    /// Set every unit of a building (index in `city.buildings`) to the
+    /// market analyst's recommended rent, as (apartment ID, rent)
+    ApplyRecommendedRents {
+        #[serde(default)]
+        building_id: u32,
+        rents: Vec<(u32, i32)>,
+    },
+    /// Sit down with the journalist for an exclusive interview
+    GrantExclusiveInterview,
+    /// Appeal a steep property tax reassessment for a filing fee
+    ContestTaxAssessment { building_id: u32, fee: i32 },
+    /// Renew a tenant's lease at the proposed rent
+    RenewLease {
+        /// Index in `city.buildings` of the tenant's building
+        #[serde(default)]
+        building_id: u32,
+        tenant_id: u32,
+        apartment_id: u32,
+        proposed_rent: i32,
+    },
     /// Just acknowledge
     Acknowledge,
 }
@@ -144,6 +181,292 @@ impl MailItem {
         }
     }
 
+    /// Create a notice from the city that requires the player's attention
+    pub fn city_notice(
+        id: u32,
+        month: u32,
+        subject: &str,
+        body: &str,
+        action: Option<MailAction>,
+    ) -> Self {
+        Self {
+            id,
+            mail_type: MailType::CityNotice,
+            month_received: month,
+            sender: "City Housing Department".to_string(),
+            subject: subject.to_string(),
+            body: body.to_string(),
+            read: false,
+            action,
+            requires_attention: true,
+        }
+    }
+
+    /// Report card left by the building inspector after a visit
+    pub fn inspection_report(
+        id: u32,
+        month: u32,
+        inspector: &str,
+        building_name: &str,
+        report: &crate::consequences::InspectionReport,
+    ) -> Self {
+        Self {
+            id,
+            mail_type: MailType::Official,
+            month_received: month,
+            sender: inspector.to_string(),
+            subject: format!("Inspection Report Card: Grade {}", report.grade),
+            body: report.letter(building_name, inspector),
+            read: false,
+            action: None,
+            requires_attention: report.is_failing(),
+        }
+    }
+
+    /// Rent recommendations from the market analyst
+    pub fn market_analysis(
+        id: u32,
+        month: u32,
+        analyst: &str,
+        body: String,
+        building_id: u32,
+        rents: Vec<(u32, i32)>,
+    ) -> Self {
+        Self {
+            id,
+            mail_type: MailType::Official,
+            month_received: month,
+            sender: analyst.to_string(),
+            subject: "Market Analysis Report".to_string(),
+            body,
+            read: false,
+            action: Some(MailAction::ApplyRecommendedRents { building_id, rents }),
+            requires_attention: false,
+        }
+    }
+
+    /// The city's reassessment of a building, old value against new
+    pub fn tax_assessment(
+        id: u32,
+        month: u32,
+        building_id: u32,
+        building_name: &str,
+        assessment: &crate::consequences::PropertyAssessment,
+        appeal_fee: i32,
+    ) -> Self {
+        let change = assessment.value - assessment.previous_value;
+        let mut body = format!(
+            "The city has reassessed {}.\n\nPrevious assessment: ${}\nNew assessment: ${} \
+             ({}{})\n\nProperty tax will follow the new value from next month.",
+            building_name,
+            assessment.previous_value,
+            assessment.value,
+            if change >= 0 { "+$" } else { "-$" },
+            change.abs()
+        );
+        let action = if assessment.appeal_open {
+            body.push_str(&format!(
+                "\n\nIncreases this steep may be appealed for a ${} filing fee.",
+                appeal_fee
+            ));
+            Some(MailAction::ContestTaxAssessment {
+                building_id,
+                fee: appeal_fee,
+            })
+        } else {
+            None
+        };
+        Self {
+            id,
+            mail_type: MailType::TaxAssessment,
+            month_received: month,
+            sender: "City Assessor's Office".to_string(),
+            subject: "Property Tax Assessment".to_string(),
+            body,
+            read: false,
+            action,
+            requires_attention: assessment.appeal_open,
+        }
+    }
+
+    /// The insurer's yearly renewal, itemizing the new premium
+    pub fn insurance_renewal(
+        id: u32,
+        month: u32,
+        building_name: &str,
+        quote: &crate::economy::InsuranceQuote,
+        premium: i32,
+        claims_last_year: u32,
+        years_claim_free: u32,
+    ) -> Self {
+        let record = if claims_last_year > 0 {
+            format!("{} claim(s) this past year", claims_last_year)
+        } else {
+            format!("Claim-free for {} year(s)", years_claim_free)
+        };
+        Self {
+            id,
+            mail_type: MailType::InsuranceRenewal,
+            month_received: month,
+            sender: "Harbor Mutual Insurance".to_string(),
+            subject: "Your Policy Renewal".to_string(),
+            body: format!(
+                "Your policy on {} has renewed. {}.\n\nBase rate: ${}\n\
+                 Good condition discount: -${}\nInspection discount: -${}\n\
+                 Claims-free discount: -${}\nCritical condition surcharge: +${}\n\n\
+                 Monthly premium: ${}",
+                building_name,
+                record,
+                quote.base_rate,
+                quote.condition_discount,
+                quote.inspection_discount,
+                quote.claims_discount,
+                quote.risk_surcharge,
+                premium
+            ),
+            read: false,
+            action: None,
+            requires_attention: false,
+        }
+    }
+
+    /// The bank's notice that variable mortgage rates have moved
+    pub fn mortgage_rate_change(id: u32, month: u32, rate: f32, new_payments: i32) -> Self {
+        Self {
+            id,
+            mail_type: MailType::Mortgage,
+            month_received: month,
+            sender: "First City Bank".to_string(),
+            subject: format!(
+                "Your mortgage rate has been adjusted to {:.1}%",
+                rate * 100.0
+            ),
+            body: format!(
+                "Following changes in the local economy, the rate on your variable \
+                 mortgages is now {:.1}%. Your combined monthly payment is now ${}.\n\n\
+                 Fixed-rate mortgages are unaffected.",
+                rate * 100.0,
+                new_payments
+            ),
+            read: false,
+            action: None,
+            requires_attention: false,
+        }
+    }
+
+    /// A newspaper story about the player's building
+    pub fn press_article(
+        id: u32,
+        month: u32,
+        journalist: &str,
+        article: &super::JournalistArticle,
+    ) -> Self {
+        Self {
+            id,
+            mail_type: MailType::News,
+            month_received: month,
+            sender: journalist.to_string(),
+            subject: article.headline.clone(),
+            body: article.body.clone(),
+            read: false,
+            action: Some(MailAction::GrantExclusiveInterview),
+            requires_attention: !article.is_positive(),
+        }
+    }
+
+    /// The property manager's summary of what they handled this month
+    pub fn manager_report(id: u32, month: u32, manager: &str, activity: &[String]) -> Self {
+        let mut body = String::from("Here's what I took care of this month:\n");
+        for line in activity {
+            body.push_str(&format!("  - {}\n", line));
+        }
+        body.push_str(&format!("\n{}", manager));
+        Self {
+            id,
+            mail_type: MailType::Financial,
+            month_received: month,
+            sender: manager.to_string(),
+            subject: "Property Manager's Report".to_string(),
+            body,
+            read: false,
+            action: None,
+            requires_attention: false,
+        }
+    }
+
+    /// Renewal terms for a lease ending at `lease_end_month`. Left alone,
+    /// the lease goes month-to-month when it ends.
+    pub fn lease_renewal(
+        id: u32,
+        month: u32,
+        tenant: &crate::tenant::Tenant,
+        building_id: u32,
+        apartment_id: u32,
+        unit_number: &str,
+        current_rent: i32,
+        proposed_rent: i32,
+        lease_end_month: u32,
+    ) -> Self {
+        let body = format!(
+            "{}'s lease on Unit {} ends in month {}.\n\n\
+             Current rent: ${}\n\
+             Proposed renewal rent: ${}\n\n\
+             If the lease isn't renewed by then, it converts to month-to-month.",
+            tenant.name, unit_number, lease_end_month, current_rent, proposed_rent
+        );
+        Self {
+            id,
+            mail_type: MailType::LeaseRenewal {
+                tenant_id: tenant.id,
+            },
+            month_received: month,
+            sender: "Property Management Office".to_string(),
+            subject: format!("Lease Renewal Offer - Unit {}", unit_number),
+            body,
+            read: false,
+            action: Some(MailAction::RenewLease {
+                building_id,
+                tenant_id: tenant.id,
+                apartment_id,
+                proposed_rent,
+            }),
+            requires_attention: true,
+        }
+    }
+
+    /// Copy of the letter sent to an abandoning tenant's emergency contact.
+    pub fn tenant_abandonment(
+        id: u32,
+        month: u32,
+        abandonment: &crate::tenant::Abandonment,
+        contact: &crate::tenant::EmergencyContact,
+    ) -> Self {
+        let body = format!(
+            "To: {} ({}), {}\n\n\
+             You are listed as the emergency contact for {}, who has left Unit {} \
+             without notice. Their belongings are still in the unit.\n\n\
+             Please get in touch to arrange collection.",
+            contact.name,
+            contact.relationship,
+            contact.phone,
+            abandonment.tenant_name,
+            abandonment.unit_number
+        );
+        Self {
+            id,
+            mail_type: MailType::TenantAbandonment {
+                tenant_id: abandonment.tenant_id,
+            },
+            month_received: month,
+            sender: "Property Management Office".to_string(),
+            subject: format!("Belongings left in Unit {}", abandonment.unit_number),
+            body,
+            read: false,
+            action: None,
+            requires_attention: false,
+        }
+    }
+
     /// Get age in months
     pub fn age(&self, current_month: u32) -> u32 {
         current_month.saturating_sub(self.month_received)
@@ -195,6 +518,42 @@ impl Mailbox {
         }
     }
 
+    /// Close out a tenant's renewal offer once it's been renewed or lapsed.
+    pub fn close_lease_renewal(&mut self, tenant_id: u32) {
+        for item in &mut self.items {
+            if item.mail_type != (MailType::LeaseRenewal { tenant_id }) || item.action.is_none() {
+                continue;
+            }
+            item.action = None;
+            item.requires_attention = false;
+            if !item.read {
+                item.read = true;
+                self.unread_count = self.unread_count.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Withdraw the actions on building `sold` once it's been sold, and point
+    /// the actions on later buildings at their new index.
+    pub fn forget_building(&mut self, sold: u32) {
+        for item in &mut self.items {
+            let building_id = match &mut item.action {
+                Some(MailAction::ScheduleInspection { building_id })
+                | Some(MailAction::ApplyRecommendedRents { building_id, .. })
+                | Some(MailAction::ContestTaxAssessment { building_id, .. })
+                | Some(MailAction::RenewLease { building_id, .. }) => building_id,
+                _ => continue,
+            };
+            match crate::consequences::building_id_after_sale(*building_id, sold) {
+                Some(id) => *building_id = id,
+                None => {
+                    item.action = None;
+                    item.requires_attention = false;
+                }
+            }
+        }
+    }
+
     /// Delete old read mail (cleanup)
     pub fn cleanup(&mut self, current_month: u32, max_age_months: u32) {
         self.items.retain(|m| {
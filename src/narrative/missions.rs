@@ -1,8 +1,11 @@
+use crate::building::Building;
 use serde::{Deserialize, Serialize};
 
 /// Status of a mission
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum MissionStatus {
+    /// Waiting on an earlier mission in its chain
+    Locked,
     Available,
     Active,
     Completed,
@@ -47,6 +50,9 @@ pub struct Mission {
     pub status: MissionStatus,
     pub deadline: Option<u32>, // Month deadline
     pub started_month: Option<u32>,
+    /// Next mission in a chain, started automatically when this one completes
+    #[serde(default)]
+    pub follow_up_mission_id: Option<u32>,
 }
 
 /// Goals for missions
@@ -71,6 +77,78 @@ pub enum MissionGoal {
     FullRepair { building_id: u32 },
     /// Acquire a new building
     AcquireBuilding,
+    /// Keep the business running for X months after accepting
+    SurviveMonths { months: u32 },
+    /// Install the building upgrade that sets this building flag
+    InstallUpgrade { flag: String },
+    /// Meet every requirement of a government grant before the deadline
+    ApplyForGrant {
+        grant_type: GrantType,
+        requirements: Vec<GrantRequirement>,
+    },
+}
+
+/// Government housing subsidy programs
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub enum GrantType {
+    AffordableHousingGrant,
+    HistoricPreservation,
+    EnergyEfficiency,
+}
+
+/// A condition the building must satisfy to qualify for a grant
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum GrantRequirement {
+    /// At least this many let rental units at or below the affordable threshold
+    MinAffordableUnits(u32),
+    /// Average asking rent across the building at or below this amount
+    MaxAvgRent(i32),
+    /// The upgrade that sets this flag is installed on the building, or on
+    /// any unit for apartment upgrades
+    HasUpgrade(String),
+}
+
+impl GrantRequirement {
+    pub fn label(&self) -> String {
+        match self {
+            GrantRequirement::MinAffordableUnits(count) => {
+                format!("{} affordable units", count)
+            }
+            GrantRequirement::MaxAvgRent(rent) => format!("Average rent at most ${}", rent),
+            GrantRequirement::HasUpgrade(flag) => format!("Install {}", flag.replace('_', " ")),
+        }
+    }
+
+    pub fn is_met(&self, building: &Building, affordable_threshold: i32) -> bool {
+        match self {
+            GrantRequirement::MinAffordableUnits(count) => {
+                let affordable = building
+                    .apartments
+                    .iter()
+                    .filter(|a| {
+                        !a.is_vacant()
+                            && !building.is_unit_sold(a.id)
+                            && a.rent_price <= affordable_threshold
+                    })
+                    .count();
+                affordable as u32 >= *count
+            }
+            GrantRequirement::MaxAvgRent(max) => {
+                if building.apartments.is_empty() {
+                    return false;
+                }
+                let total: i32 = building.apartments.iter().map(|a| a.rent_price).sum();
+                total / building.apartments.len() as i32 <= *max
+            }
+            GrantRequirement::HasUpgrade(flag) => {
+                building.flags.contains(flag.as_str())
+                    || building
+                        .apartments
+                        .iter()
+                        .any(|a| a.flags.contains(flag.as_str()))
+            }
+        }
+    }
 }
 
 impl Mission {
@@ -93,6 +171,7 @@ impl Mission {
             status: MissionStatus::Available,
             deadline,
             started_month: None,
+            follow_up_mission_id: None,
         }
     }
 
@@ -112,6 +191,13 @@ impl Mission {
         self.status = MissionStatus::Failed;
     }
 
+    /// Months left before the deadline (negative once past it), or `None`
+    /// for open-ended missions.
+    pub fn months_remaining(&self, current_month: u32) -> Option<i64> {
+        self.deadline
+            .map(|deadline| deadline as i64 - current_month as i64)
+    }
+
     /// Check if mission has expired
     pub fn check_expired(&mut self, current_month: u32) -> bool {
         if let Some(deadline) = self.deadline {
@@ -187,6 +273,14 @@ impl MissionManager {
             .collect()
     }
 
+    /// Missions that ran out of time or failed outright
+    pub fn failed_missions(&self) -> Vec<&Mission> {
+        self.missions
+            .iter()
+            .filter(|m| matches!(m.status, MissionStatus::Failed | MissionStatus::Expired))
+            .collect()
+    }
+
     /// Get completed missions
     pub fn completed_missions(&self) -> Vec<&Mission> {
         self.missions
@@ -206,6 +300,22 @@ impl MissionManager {
         false
     }
 
+    /// Start the follow-up of a just-completed mission, if it has one.
+    /// Returns the follow-up's title for the player-facing announcement.
+    pub fn unlock_follow_up(&mut self, mission_id: u32, current_month: u32) -> Option<String> {
+        let follow_up_id = self
+            .missions
+            .iter()
+            .find(|m| m.id == mission_id && m.status == MissionStatus::Completed)?
+            .follow_up_mission_id?;
+        let follow_up = self
+            .missions
+            .iter_mut()
+            .find(|m| m.id == follow_up_id && m.status == MissionStatus::Locked)?;
+        follow_up.start(current_month);
+        Some(follow_up.title.clone())
+    }
+
     /// Check all active missions for expiration
     pub fn check_expirations(&mut self, current_month: u32) {
         for mission in &mut self.missions {
@@ -290,9 +400,20 @@ impl MissionManager {
     /// arrived and that isn't already present. Called at game start (month 0)
     /// and each month, this replaces the old hardcoded starter/late-game
     /// generators — mission content now lives in data, not Rust.
+    ///
+    /// A template named as another's `follow_up` is never offered on its own:
+    /// the whole chain is added with its head, later links `Locked` until the
+    /// mission before them completes. Chained links run without a deadline.
     pub fn generate_available_missions(&mut self, current_month: u32) {
-        for template in load_mission_templates() {
-            if template.min_month > current_month {
+        let templates = load_mission_templates();
+        let is_follow_up = |title: &str| {
+            templates
+                .iter()
+                .any(|t| t.follow_up.as_deref() == Some(title))
+        };
+
+        for template in &templates {
+            if template.min_month > current_month || is_follow_up(&template.title) {
                 continue;
             }
             if self.missions.iter().any(|m| m.title == template.title) {
@@ -302,16 +423,26 @@ impl MissionManager {
             let deadline = template
                 .deadline_months
                 .map(|months| template.min_month + months);
-            let mission = Mission::new(
-                0,
-                &template.title,
-                &template.description,
-                template.giver_npc_id,
-                template.goal,
-                template.reward,
-                deadline,
-            );
-            self.add_mission(mission);
+            let mut previous_id = self.add_mission(template.to_mission(deadline));
+
+            let mut next = template.follow_up.as_deref();
+            while let Some(title) = next {
+                let Some(link) = templates.iter().find(|t| t.title == title) else {
+                    eprintln!("missions.json: unknown follow_up '{}'", title);
+                    break;
+                };
+                if self.missions.iter().any(|m| m.title == link.title) {
+                    break;
+                }
+                let mut mission = link.to_mission(None);
+                mission.status = MissionStatus::Locked;
+                let id = self.add_mission(mission);
+                if let Some(previous) = self.missions.iter_mut().find(|m| m.id == previous_id) {
+                    previous.follow_up_mission_id = Some(id);
+                }
+                previous_id = id;
+                next = link.follow_up.as_deref();
+            }
         }
     }
 }
@@ -329,6 +460,23 @@ struct MissionTemplate {
     deadline_months: Option<u32>,
     goal: MissionGoal,
     reward: MissionReward,
+    /// Title of the mission that unlocks when this one completes
+    #[serde(default)]
+    follow_up: Option<String>,
+}
+
+impl MissionTemplate {
+    fn to_mission(&self, deadline: Option<u32>) -> Mission {
+        Mission::new(
+            0,
+            &self.title,
+            &self.description,
+            self.giver_npc_id,
+            self.goal.clone(),
+            self.reward.clone(),
+            deadline,
+        )
+    }
 }
 
 fn load_mission_templates() -> Vec<MissionTemplate> {
@@ -377,6 +525,72 @@ mod tests {
         assert_eq!(manager.available_missions().len(), 0);
     }
 
+    #[test]
+    fn test_follow_up_starts_on_completion() {
+        let mut manager = MissionManager::new();
+        let first = manager.add_mission(Mission::new(
+            0,
+            "First",
+            "",
+            0,
+            MissionGoal::AcquireBuilding,
+            MissionReward::Money(100),
+            None,
+        ));
+        let mut second = Mission::new(
+            0,
+            "Second",
+            "",
+            0,
+            MissionGoal::SurviveMonths { months: 12 },
+            MissionReward::Money(100),
+            None,
+        );
+        second.status = MissionStatus::Locked;
+        let second = manager.add_mission(second);
+        manager.missions[first as usize].follow_up_mission_id = Some(second);
+
+        manager.accept_mission(first, 0);
+        assert_eq!(manager.unlock_follow_up(first, 2), None);
+
+        manager.missions[first as usize].complete();
+        assert_eq!(
+            manager.unlock_follow_up(first, 2).as_deref(),
+            Some("Second")
+        );
+        assert_eq!(
+            manager.missions[second as usize].status,
+            MissionStatus::Active
+        );
+        assert_eq!(manager.missions[second as usize].started_month, Some(2));
+    }
+
+    #[test]
+    fn test_months_remaining() {
+        let timed = Mission::new(
+            0,
+            "Timed",
+            "",
+            0,
+            MissionGoal::AcquireBuilding,
+            MissionReward::Money(100),
+            Some(10),
+        );
+        assert_eq!(timed.months_remaining(4), Some(6));
+        assert_eq!(timed.months_remaining(12), Some(-2));
+
+        let open = Mission::new(
+            0,
+            "Open",
+            "",
+            0,
+            MissionGoal::AcquireBuilding,
+            MissionReward::Money(100),
+            None,
+        );
+        assert_eq!(open.months_remaining(4), None);
+    }
+
     #[test]
     fn test_mission_expiration() {
         let mut manager = MissionManager::new();
@@ -429,6 +643,33 @@ mod tests {
         assert_eq!(full_house, 1, "missions must not duplicate across calls");
     }
 
+    #[test]
+    fn grant_requirements_check_the_building() {
+        let mut building = Building::new("Test", 2, 2);
+        for (id, apt) in building.apartments.iter_mut().enumerate() {
+            apt.rent_price = 800;
+            apt.tenant_id = Some(id as u32);
+        }
+        assert!(GrantRequirement::MinAffordableUnits(4).is_met(&building, 900));
+        assert!(!GrantRequirement::MinAffordableUnits(4).is_met(&building, 700));
+        building.apartments[0].tenant_id = None;
+        assert!(!GrantRequirement::MinAffordableUnits(4).is_met(&building, 900));
+        assert!(GrantRequirement::MaxAvgRent(800).is_met(&building, 900));
+        assert!(!GrantRequirement::MaxAvgRent(799).is_met(&building, 900));
+
+        let upgrade = GrantRequirement::HasUpgrade("solar_panels".to_string());
+        assert!(!upgrade.is_met(&building, 900));
+        building.flags.insert("solar_panels".to_string());
+        assert!(upgrade.is_met(&building, 900));
+
+        let insulation = GrantRequirement::HasUpgrade("window_insulation".to_string());
+        assert!(!insulation.is_met(&building, 900));
+        building.apartments[1]
+            .flags
+            .insert("window_insulation".to_string());
+        assert!(insulation.is_met(&building, 900));
+    }
+
     #[test]
     fn test_legacy_system() {
         let mut manager = MissionManager::new();
@@ -16,6 +16,12 @@ pub struct GameNotification {
     pub description: Option<String>,
     /// Category for styling
     pub category: NotificationCategory,
+    /// Display order; lower shows first
+    #[serde(default)]
+    pub priority: u32,
+    /// Tick the notification was queued
+    #[serde(default)]
+    pub tick_received: u32,
 }
 
 /// Categories of notifications affect visual styling
@@ -31,6 +37,18 @@ pub enum NotificationCategory {
     Hint,
 }
 
+impl NotificationCategory {
+    /// Warnings jump the queue; hints wait for everything else.
+    pub fn priority(&self) -> u32 {
+        match self {
+            NotificationCategory::Warning => 1,
+            NotificationCategory::Info => 2,
+            NotificationCategory::Positive => 3,
+            NotificationCategory::Hint => 4,
+        }
+    }
+}
+
 impl GameNotification {
     pub fn positive(icon: &str, message: &str) -> Self {
         Self {
@@ -38,6 +56,8 @@ impl GameNotification {
             message: message.to_string(),
             description: None,
             category: NotificationCategory::Positive,
+            priority: NotificationCategory::Positive.priority(),
+            tick_received: 0,
         }
     }
 
@@ -47,6 +67,8 @@ impl GameNotification {
             message: message.to_string(),
             description: None,
             category: NotificationCategory::Warning,
+            priority: NotificationCategory::Warning.priority(),
+            tick_received: 0,
         }
     }
 
@@ -56,6 +78,8 @@ impl GameNotification {
             message: message.to_string(),
             description: None,
             category: NotificationCategory::Hint,
+            priority: NotificationCategory::Hint.priority(),
+            tick_received: 0,
         }
     }
 }
@@ -222,10 +246,22 @@ pub fn load_hints_config() -> HintsConfig {
     })
 }
 
+/// Dismissed notifications kept for the history view.
+const HISTORY_CAP: usize = 50;
+
 /// Manages pending game notifications
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct NotificationManager {
     pub pending: Vec<GameNotification>,
+    /// Put off until the tick alongside them.
+    #[serde(default)]
+    pub snoozed: Vec<(GameNotification, u32)>,
+    /// Dismissed notifications, oldest first.
+    #[serde(default)]
+    pub history: Vec<GameNotification>,
+    /// History entries added since the history view was last opened.
+    #[serde(default)]
+    pub unread_history: usize,
     pub last_hint_month: u32,
     #[serde(skip)]
     pub hints_config: Option<HintsConfig>,
@@ -235,16 +271,23 @@ impl NotificationManager {
     pub fn new() -> Self {
         Self {
             pending: Vec::new(),
+            snoozed: Vec::new(),
+            history: Vec::new(),
+            unread_history: 0,
             last_hint_month: 0,
             hints_config: Some(load_hints_config()),
         }
     }
 
     /// Add relationship changes as notifications
-    pub fn add_relationship_changes(&mut self, changes: Vec<RelationshipChange>) {
+    pub fn add_relationship_changes(
+        &mut self,
+        changes: Vec<RelationshipChange>,
+        current_tick: u32,
+    ) {
         let config = self.hints_config.clone().unwrap_or_default();
         for change in changes {
-            self.pending.push(change.to_notification(&config));
+            self.push(change.to_notification(&config), current_tick);
         }
     }
 
@@ -328,20 +371,63 @@ impl NotificationManager {
             if let Some(hint) = config.context_hints.get(hint_key) {
                 if !hint.messages.is_empty() {
                     let idx = rng::gen_range(0, hint.messages.len());
-                    self.pending
-                        .push(GameNotification::hint(&hint.messages[idx]));
+                    self.push(GameNotification::hint(&hint.messages[idx]), current_month);
                     self.last_hint_month = current_month;
                 }
             }
         }
     }
 
-    /// Get the next pending notification (if any)
+    /// Queue a notification received at `current_tick`.
+    pub fn push(&mut self, mut notification: GameNotification, current_tick: u32) {
+        notification.tick_received = current_tick;
+        self.enqueue(notification);
+    }
+
+    /// Queue behind any pending notification of equal or higher priority.
+    fn enqueue(&mut self, notification: GameNotification) {
+        let index = self
+            .pending
+            .partition_point(|pending| pending.priority <= notification.priority);
+        self.pending.insert(index, notification);
+    }
+
+    /// Get the next pending notification (if any), filing it in history.
     pub fn pop(&mut self) -> Option<GameNotification> {
         if self.pending.is_empty() {
-            None
-        } else {
-            Some(self.pending.remove(0))
+            return None;
+        }
+        let notification = self.pending.remove(0);
+        self.history.push(notification.clone());
+        if self.history.len() > HISTORY_CAP {
+            self.history.remove(0);
+        }
+        self.unread_history = (self.unread_history + 1).min(self.history.len());
+        Some(notification)
+    }
+
+    /// The history view was opened; everything in it has been seen.
+    pub fn mark_history_read(&mut self) {
+        self.unread_history = 0;
+    }
+
+    /// Put the current notification off until next turn.
+    pub fn snooze_current(&mut self, current_tick: u32) {
+        if !self.pending.is_empty() {
+            let notification = self.pending.remove(0);
+            self.snoozed.push((notification, current_tick + 1));
+        }
+    }
+
+    /// Requeue snoozed notifications that are due by `current_tick`. They
+    /// keep the tick they were first received.
+    pub fn restore_snoozed(&mut self, current_tick: u32) {
+        let (due, later): (Vec<_>, Vec<_>) = std::mem::take(&mut self.snoozed)
+            .into_iter()
+            .partition(|(_, display_again_tick)| *display_again_tick <= current_tick);
+        self.snoozed = later;
+        for (notification, _) in due {
+            self.enqueue(notification);
         }
     }
 
@@ -350,3 +436,57 @@ impl NotificationManager {
         !self.pending.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_are_shown_before_earlier_good_news() {
+        let mut manager = NotificationManager::default();
+        manager.push(GameNotification::positive("🎉", "Happy Tenant!"), 0);
+        manager.push(GameNotification::hint("Tip"), 0);
+        manager.push(GameNotification::warning("🔥", "Fire Hazard!"), 0);
+        manager.push(GameNotification::positive("🏆", "Achievement"), 0);
+
+        let order: Vec<_> = std::iter::from_fn(|| manager.pop())
+            .map(|n| n.message)
+            .collect();
+        assert_eq!(
+            order,
+            ["Fire Hazard!", "Happy Tenant!", "Achievement", "Tip"]
+        );
+    }
+
+    #[test]
+    fn dismissed_notifications_are_kept_in_a_capped_history() {
+        let mut manager = NotificationManager::default();
+        for i in 0..HISTORY_CAP + 5 {
+            manager.push(GameNotification::hint(&format!("Tip {}", i)), 7);
+            manager.pop();
+        }
+        assert_eq!(manager.history.len(), HISTORY_CAP);
+        assert_eq!(manager.history[0].message, "Tip 5");
+        assert_eq!(manager.history[0].tick_received, 7);
+        assert_eq!(manager.unread_history, HISTORY_CAP);
+
+        manager.mark_history_read();
+        assert_eq!(manager.unread_history, 0);
+    }
+
+    #[test]
+    fn snoozed_notification_returns_next_turn() {
+        let mut manager = NotificationManager::default();
+        manager.push(GameNotification::warning("🔥", "Fire Hazard!"), 4);
+        manager.snooze_current(4);
+        assert!(!manager.has_pending());
+
+        manager.restore_snoozed(4);
+        assert!(!manager.has_pending());
+        manager.restore_snoozed(5);
+        let restored = manager.pop().unwrap();
+        assert_eq!(restored.message, "Fire Hazard!");
+        assert_eq!(restored.tick_received, 4);
+        assert!(manager.snoozed.is_empty());
+    }
+}
@@ -0,0 +1,64 @@
+//! The city's emergency rent freeze and the decision waiting when it lifts.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// News that rent increases are frozen until `until_month`.
+pub fn rent_freeze_declared(month: u32, until_month: u32) -> NarrativeEvent {
+    let mut event = NarrativeEvent::news(
+        0,
+        month,
+        "Emergency Rent Freeze",
+        &format!(
+            "The city council has frozen rents citywide. No increases are \
+             allowed until month {}.",
+            until_month
+        ),
+    );
+    event.event_type = NarrativeEventType::CityEvent;
+    event
+}
+
+/// The freeze is over: catch up on rent now, or hold steady and keep the
+/// goodwill.
+pub fn rent_freeze_lifted(month: u32, building_id: u32, raise_percent: f32) -> NarrativeEvent {
+    let hold = NarrativeEffect::BuildingHappiness {
+        building_id,
+        change: 5,
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::CityEvent,
+        month,
+        "Rent Freeze Lifted",
+        "The emergency freeze has ended. Tenants are waiting to see what you do.",
+        vec![
+            NarrativeChoice {
+                label: "Raise rents now".to_string(),
+                description: format!(
+                    "Every unit goes up {:.0}%; tenants and neighbors take note",
+                    raise_percent * 100.0
+                ),
+                effect: NarrativeEffect::Multiple {
+                    effects: vec![
+                        NarrativeEffect::RaiseAllRents {
+                            percent: raise_percent,
+                        },
+                        NarrativeEffect::BuildingHappiness {
+                            building_id,
+                            change: -10,
+                        },
+                    ],
+                },
+                reputation_change: -5,
+            },
+            NarrativeChoice {
+                label: "Hold steady".to_string(),
+                description: "Keep rents where they are for now".to_string(),
+                effect: hold.clone(),
+                reputation_change: 3,
+            },
+        ],
+    );
+    event.default_effect = hold;
+    event
+}
@@ -0,0 +1,126 @@
+//! Negotiating tenant requests instead of a flat yes or no: conditions
+//! attached to an approved pet, and counter offers on a home business.
+
+use serde::{Deserialize, Serialize};
+
+use super::{StoryImpact, TenantRequest};
+
+/// Months a trial pet approval lasts.
+pub const PET_TRIAL_MONTHS: u32 = 6;
+
+/// Weekly hours a home business runs when the landlord sets no limit.
+pub const FULL_BUSINESS_HOURS: i32 = 40;
+
+/// Weekly hour limits the landlord can counter a home business request with.
+pub const COUNTER_OFFER_HOURS: [u32; 2] = [20, 10];
+
+/// Happiness a tenant loses when they turn down a counter offer.
+pub const REJECTED_COUNTER_HAPPINESS_HIT: i32 = 5;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RequestCondition {
+    /// An extra deposit held against pet damage.
+    PetDeposit(i32),
+    /// Quiet hours: neighbors' noise complaints count against the tenant.
+    NoiseRestriction,
+    /// The approval lapses after this many months.
+    TimeLimit(u32),
+}
+
+impl RequestCondition {
+    /// Happiness the tenant gives up, off what a plain approval would give.
+    pub fn happiness_cost(&self) -> i32 {
+        match self {
+            RequestCondition::PetDeposit(_) => 5,
+            RequestCondition::NoiseRestriction => 3,
+            RequestCondition::TimeLimit(_) => 0,
+        }
+    }
+
+    /// Approval effect of `request` once this condition is attached.
+    pub fn approval_effect(&self, request: &TenantRequest) -> StoryImpact {
+        StoryImpact::Multiple(vec![
+            request.approval_effect(),
+            StoryImpact::Happiness(-self.happiness_cost()),
+        ])
+    }
+}
+
+impl TenantRequest {
+    /// How much a counter offer cuts back on what was asked, 0.0-1.0.
+    pub fn restrictiveness(&self) -> f32 {
+        match self {
+            TenantRequest::HomeBusiness {
+                weekly_hours: Some(hours),
+                ..
+            } => (1.0 - *hours as f32 / FULL_BUSINESS_HOURS as f32).clamp(0.0, 1.0),
+            _ => 0.0,
+        }
+    }
+
+    /// Chance a tenant takes this request as a counter offer. Tenants who
+    /// like their landlord give more ground.
+    pub fn counter_acceptance_chance(&self, landlord_opinion: i32) -> f32 {
+        (0.5 + landlord_opinion as f32 / 200.0 - self.restrictiveness() * 0.5).clamp(0.05, 0.95)
+    }
+}
+
+/// A condition attached to a request the landlord approved.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApprovedCondition {
+    pub request: TenantRequest,
+    pub condition: RequestCondition,
+    pub approved_tick: u32,
+}
+
+impl ApprovedCondition {
+    /// Whether a time-limited approval has run out.
+    pub fn expired(&self, current_tick: u32) -> bool {
+        match self.condition {
+            RequestCondition::TimeLimit(months) => current_tick >= self.approved_tick + months,
+            _ => false,
+        }
+    }
+
+    /// Short status line for the tenant panel.
+    pub fn status(&self, current_tick: u32) -> String {
+        match self.condition {
+            RequestCondition::PetDeposit(amount) => format!("Pet deposit: ${} held", amount),
+            RequestCondition::NoiseRestriction => "Pet on quiet hours".to_string(),
+            RequestCondition::TimeLimit(months) => format!(
+                "Pet approval expires in {} months",
+                (self.approved_tick + months).saturating_sub(current_tick)
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_limited_approval_expires() {
+        let approved = ApprovedCondition {
+            request: TenantRequest::Pet {
+                pet_type: "cat".to_string(),
+            },
+            condition: RequestCondition::TimeLimit(PET_TRIAL_MONTHS),
+            approved_tick: 10,
+        };
+        assert!(!approved.expired(10 + PET_TRIAL_MONTHS - 1));
+        assert!(approved.expired(10 + PET_TRIAL_MONTHS));
+    }
+
+    #[test]
+    fn tighter_counter_offers_are_harder_to_accept() {
+        let counter = |hours| TenantRequest::HomeBusiness {
+            business_type: "tutoring".to_string(),
+            weekly_hours: Some(hours),
+        };
+        let loose = counter(COUNTER_OFFER_HOURS[0]).counter_acceptance_chance(0);
+        let tight = counter(COUNTER_OFFER_HOURS[1]).counter_acceptance_chance(0);
+        assert!(tight < loose);
+        assert!(counter(10).counter_acceptance_chance(100) > tight);
+    }
+}
@@ -0,0 +1,166 @@
+//! Guided scenarios: preset starting conditions patched onto a fresh game,
+//! plus mission-style objectives to finish before a deadline.
+
+use super::missions::MissionGoal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Scenario objectives are checked exactly like mission goals.
+pub type ScenarioObjective = MissionGoal;
+
+/// Score for each objective met.
+const OBJECTIVE_SCORE: i32 = 1000;
+/// Score for each month left on the clock at completion.
+const MONTH_LEFT_SCORE: i32 = 200;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Scenario {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// Merged into a freshly created game's save JSON (see `merge_json`).
+    #[serde(default)]
+    pub initial_state_patch: Value,
+    pub objectives: Vec<ScenarioObjective>,
+    #[serde(default)]
+    pub time_limit_ticks: Option<u32>,
+}
+
+/// A scenario being played.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ActiveScenario {
+    pub name: String,
+    pub objectives: Vec<ScenarioObjective>,
+    /// Parallel to `objectives`; an objective stays met once met.
+    pub completed: Vec<bool>,
+    pub started_tick: u32,
+    pub time_limit_ticks: Option<u32>,
+}
+
+impl ActiveScenario {
+    pub fn start(scenario: &Scenario, current_tick: u32) -> Self {
+        Self {
+            name: scenario.name.clone(),
+            objectives: scenario.objectives.clone(),
+            completed: vec![false; scenario.objectives.len()],
+            started_tick: current_tick,
+            time_limit_ticks: scenario.time_limit_ticks,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed.iter().all(|done| *done)
+    }
+
+    /// Months left before the deadline, if there is one.
+    pub fn months_left(&self, current_tick: u32) -> Option<u32> {
+        self.time_limit_ticks
+            .map(|limit| (self.started_tick + limit).saturating_sub(current_tick))
+    }
+
+    pub fn is_out_of_time(&self, current_tick: u32) -> bool {
+        self.months_left(current_tick) == Some(0)
+    }
+
+    /// Scenario score: objectives met, plus a bonus for finishing early.
+    pub fn score(&self, current_tick: u32) -> i32 {
+        let met = self.completed.iter().filter(|done| **done).count() as i32;
+        let early_bonus = if self.is_complete() {
+            self.months_left(current_tick).unwrap_or(0) as i32 * MONTH_LEFT_SCORE
+        } else {
+            0
+        };
+        met * OBJECTIVE_SCORE + early_bonus
+    }
+}
+
+/// Deep-merge `patch` into `target`. Objects merge key by key and arrays
+/// element by element (extra patch elements are appended), so a patch can
+/// touch one field of one apartment; anything else is replaced outright.
+pub fn merge_json(target: &mut Value, patch: &Value) {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                match target.get_mut(key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(patch)) => {
+            for (i, value) in patch.iter().enumerate() {
+                match target.get_mut(i) {
+                    Some(existing) => merge_json(existing, value),
+                    None => target.push(value.clone()),
+                }
+            }
+        }
+        (target, patch) => *target = patch.clone(),
+    }
+}
+
+pub fn load_scenarios() -> Vec<Scenario> {
+    #[cfg(target_arch = "wasm32")]
+    let json = include_str!("../../assets/scenarios.json").to_string();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let json = std::fs::read_to_string("assets/scenarios.json")
+        .unwrap_or_else(|_| include_str!("../../assets/scenarios.json").to_string());
+
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        eprintln!("Failed to parse scenarios.json: {}", e);
+        Vec::new()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merge_patches_nested_fields_and_array_elements() {
+        let mut target = json!({
+            "funds": { "balance": 5000, "total_income": 0 },
+            "apartments": [{ "condition": 50 }, { "condition": 60 }],
+        });
+        merge_json(
+            &mut target,
+            &json!({
+                "funds": { "balance": 800 },
+                "apartments": [{ "condition": 10 }],
+            }),
+        );
+        assert_eq!(target["funds"]["balance"], 800);
+        assert_eq!(target["funds"]["total_income"], 0);
+        assert_eq!(target["apartments"][0]["condition"], 10);
+        assert_eq!(target["apartments"][1]["condition"], 60);
+    }
+
+    #[test]
+    fn scenarios_load_from_json() {
+        let scenarios = load_scenarios();
+        assert!(!scenarios.is_empty());
+        assert!(scenarios.iter().all(|s| !s.objectives.is_empty()));
+    }
+
+    #[test]
+    fn finishing_early_scores_more() {
+        let scenario = Scenario {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            description: String::new(),
+            initial_state_patch: Value::Null,
+            objectives: vec![MissionGoal::AcquireBuilding],
+            time_limit_ticks: Some(12),
+        };
+        let mut active = ActiveScenario::start(&scenario, 0);
+        assert_eq!(active.score(3), 0);
+        active.completed[0] = true;
+        assert!(active.is_complete());
+        assert!(active.score(3) > active.score(6));
+        assert!(active.is_out_of_time(12));
+    }
+}
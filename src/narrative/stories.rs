@@ -43,8 +43,13 @@ pub enum TenantRequest {
         guest_name: String,
         duration_months: u32,
     },
-    /// Can I run a small business from home?
-    HomeBusiness { business_type: String },
+    /// Can I run a small business from home? `weekly_hours` is set when the
+    /// landlord countered with limited operating hours.
+    HomeBusiness {
+        business_type: String,
+        #[serde(default)]
+        weekly_hours: Option<u32>,
+    },
     /// Can I modify the apartment?
     Modification { description: String },
     /// Can I sublease part of the unit?
@@ -67,7 +72,12 @@ impl TenantRequest {
         match self {
             TenantRequest::Pet { .. } => StoryImpact::Happiness(15),
             TenantRequest::TemporaryGuest { .. } => StoryImpact::Happiness(10),
-            TenantRequest::HomeBusiness { business_type } => {
+            // Limited hours keep the noise down, and the business smaller.
+            TenantRequest::HomeBusiness {
+                weekly_hours: Some(hours),
+                ..
+            } => StoryImpact::Happiness((15 * *hours as i32 / super::FULL_BUSINESS_HOURS).max(5)),
+            TenantRequest::HomeBusiness { business_type, .. } => {
                 let impacts = if business_type.to_lowercase().contains("music")
                     || business_type.to_lowercase().contains("drum")
                 {
@@ -253,6 +263,9 @@ pub struct TenantStory {
 
     // Active requests
     pub pending_request: Option<TenantRequest>,
+    /// Conditions attached to requests the landlord approved.
+    #[serde(default)]
+    pub approved_conditions: Vec<super::ApprovedCondition>,
 }
 
 impl TenantStory {
@@ -322,6 +335,7 @@ impl TenantStory {
                                 business_type: rng::choose(options)
                                     .cloned()
                                     .unwrap_or_else(|| "consulting".to_string()),
+                                weekly_hours: None,
                             })
                         }
                         RequestTemplate::Modification { options, .. } => {
@@ -417,6 +431,7 @@ impl BackgroundGenerator {
             num_children,
             story_events: Vec::new(),
             pending_request: None,
+            approved_conditions: Vec::new(),
         }
     }
 }
@@ -1,5 +1,14 @@
+use crate::state::GameplayState;
+use crate::ui::Selection;
 use serde::{Deserialize, Serialize};
 
+/// A hint disappears after this many months if the player doesn't act on it.
+const HINT_LIFETIME_TICKS: u32 = 3;
+/// Funds below this trigger the low-funds hint.
+const LOW_FUNDS_HINT_THRESHOLD: i32 = 500;
+/// Apartments below this condition trigger the repair hint.
+const POOR_CONDITION_HINT_THRESHOLD: i32 = 40;
+
 /// An NPC character in the narrative system
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct NarrativeNpc {
@@ -61,6 +70,9 @@ pub struct TutorialManager {
     pub pending_messages: Vec<String>,
     /// Whether the rival has been introduced
     pub rival_introduced: bool,
+    /// Hint currently in the corner and the tick it first appeared.
+    #[serde(skip)]
+    pub shown_hint: Option<(&'static str, u32)>,
 }
 
 impl TutorialManager {
@@ -88,6 +100,7 @@ impl TutorialManager {
                 "First, select the Hallway and repair it to fix up the place.".to_string(),
             ],
             rival_introduced: false,
+            shown_hint: None,
         }
     }
 
@@ -173,8 +186,31 @@ impl TutorialManager {
         }
     }
 
-    /// Get a contextual hint for the current milestone
-    pub fn get_hint(&self) -> Option<&'static str> {
+    /// Get a hint for what the player is looking at, falling back to the
+    /// current tutorial milestone.
+    pub fn get_hint(&self, selection: &Selection, state: &GameplayState) -> Option<&'static str> {
+        match selection {
+            Selection::Apartment(id)
+                if state
+                    .building
+                    .get_apartment(*id)
+                    .is_some_and(|apt| apt.condition < POOR_CONDITION_HINT_THRESHOLD) =>
+            {
+                return Some("This unit needs repairs soon or the tenant may leave");
+            }
+            Selection::Applications(_) if state.applications.is_empty() => {
+                return Some("Try listing units for lease in the Apartment panel");
+            }
+            _ => {}
+        }
+        if state.funds.balance < LOW_FUNDS_HINT_THRESHOLD {
+            return Some(
+                "You're running low on funds — consider setting rents higher or reducing staff",
+            );
+        }
+        if !self.active {
+            return None;
+        }
         match &self.current_milestone {
             Some(TutorialMilestone::InheritedMess) => {
                 Some("Hint: Click the Hallway and repair it to 80+ condition.")
@@ -189,6 +225,20 @@ impl TutorialManager {
         }
     }
 
+    /// Track `hint` as the current one, restarting its clock when it changes.
+    pub fn refresh_hint(&mut self, hint: Option<&'static str>, current_tick: u32) {
+        if self.shown_hint.map(|(shown, _)| shown) != hint {
+            self.shown_hint = hint.map(|hint| (hint, current_tick));
+        }
+    }
+
+    /// The hint to show now, if it hasn't outstayed its welcome.
+    pub fn visible_hint(&self, current_tick: u32) -> Option<&'static str> {
+        self.shown_hint
+            .filter(|(_, since)| current_tick.saturating_sub(*since) < HINT_LIFETIME_TICKS)
+            .map(|(hint, _)| hint)
+    }
+
     /// Check if the player should see the rival introduction
     pub fn should_introduce_rival(&self, month: u32) -> bool {
         // Introduce Magnuson Corp after first 6 months
@@ -235,6 +285,20 @@ mod tests {
         assert!(tutorial.is_complete());
     }
 
+    #[test]
+    fn hints_expire_unless_the_context_changes() {
+        let mut tutorial = TutorialManager::new();
+        tutorial.refresh_hint(Some("a"), 10);
+        tutorial.refresh_hint(Some("a"), 12);
+        assert_eq!(tutorial.visible_hint(12), Some("a"));
+        assert_eq!(tutorial.visible_hint(13), None);
+
+        tutorial.refresh_hint(Some("b"), 13);
+        assert_eq!(tutorial.visible_hint(13), Some("b"));
+        tutorial.refresh_hint(None, 14);
+        assert_eq!(tutorial.visible_hint(14), None);
+    }
+
     #[test]
     fn test_npc_relationship() {
         let mut tutorial = TutorialManager::new();
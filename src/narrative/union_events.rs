@@ -0,0 +1,201 @@
+//! The tenant union: its formation, the demands it keeps making, and the
+//! rent strike that follows when a landlord won't listen.
+
+use super::events::{NarrativeChoice, NarrativeEffect, NarrativeEvent, NarrativeEventType};
+
+/// The tenant council has organized into a union and wants to be heard.
+pub fn union_formation(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    dialogue_cost: i32,
+) -> NarrativeEvent {
+    let ignore = NarrativeEffect::UnionDemandAnswered { met: false };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::TenantCouncilDemand,
+        month,
+        "Tenants Form a Union",
+        &format!(
+            "The tenants of {} have formalized their council into a union and \
+             are asking you to sit down with them.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Recognize the union".to_string(),
+                description: "Rent hikes will be held to a lower bar from now on".to_string(),
+                effect: NarrativeEffect::Multiple {
+                    effects: vec![
+                        NarrativeEffect::RecognizeTenantUnion,
+                        NarrativeEffect::UnionDemandAnswered { met: true },
+                        NarrativeEffect::BuildingHappiness {
+                            building_id,
+                            change: 10,
+                        },
+                    ],
+                },
+                reputation_change: 5,
+            },
+            NarrativeChoice {
+                label: "Engage in dialogue".to_string(),
+                description: format!("Bring in a mediator (${})", dialogue_cost),
+                effect: NarrativeEffect::Multiple {
+                    effects: vec![
+                        NarrativeEffect::Money {
+                            amount: -dialogue_cost,
+                        },
+                        NarrativeEffect::GentrificationChange { change: -10 },
+                        NarrativeEffect::UnionDemandAnswered { met: true },
+                    ],
+                },
+                reputation_change: 2,
+            },
+            NarrativeChoice {
+                label: "Ignore demands".to_string(),
+                description: "Ignore them twice and they stop paying rent".to_string(),
+                effect: ignore.clone(),
+                reputation_change: -5,
+            },
+        ],
+    );
+    event.default_effect = ignore;
+    event
+}
+
+/// The union's monthly list of demands.
+pub fn council_demand(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    cost: i32,
+) -> NarrativeEvent {
+    let refuse = NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::BuildingHappiness {
+                building_id,
+                change: -5,
+            },
+            NarrativeEffect::UnionDemandAnswered { met: false },
+        ],
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::TenantCouncilDemand,
+        month,
+        "Tenant Union Demands",
+        &format!(
+            "The {} tenant union has sent over this month's demands: repairs, \
+             fairer fees, and a say in what changes next.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Meet the demands".to_string(),
+                description: format!("Costs ${}", cost),
+                effect: NarrativeEffect::Multiple {
+                    effects: vec![
+                        NarrativeEffect::Money { amount: -cost },
+                        NarrativeEffect::UnionDemandAnswered { met: true },
+                        NarrativeEffect::BuildingHappiness {
+                            building_id,
+                            change: 5,
+                        },
+                    ],
+                },
+                reputation_change: 2,
+            },
+            NarrativeChoice {
+                label: "Refuse".to_string(),
+                description: "Morale drops and the union loses patience".to_string(),
+                effect: refuse.clone(),
+                reputation_change: -2,
+            },
+        ],
+    );
+    event.response_deadline = Some(month + 1);
+    event.default_effect = refuse;
+    event
+}
+
+/// Ignored once too often, the union walks out until its demands are met.
+pub fn rent_strike(month: u32, building_name: &str, demands: &[String]) -> NarrativeEvent {
+    let mut event = NarrativeEvent::news(
+        0,
+        month,
+        "Rent Strike",
+        &format!(
+            "Tired of being ignored, tenants of {} have stopped paying rent. \
+             They want: {}.",
+            building_name,
+            demands.join("; ")
+        ),
+    );
+    event.event_type = NarrativeEventType::TenantCouncilDemand;
+    event
+}
+
+/// The tenants voted a developer's offer down; it's off the table.
+pub fn buyout_rejected(month: u32, building_id: u32, building_name: &str) -> NarrativeEvent {
+    let mut event = NarrativeEvent::news(
+        0,
+        month,
+        "Tenants Vote Down the Sale",
+        &format!(
+            "The tenants of {} voted against the developer's offer, and it has \
+             been declined. The building is celebrating.",
+            building_name
+        ),
+    );
+    event.event_type = NarrativeEventType::TenantCouncilDemand;
+    event.default_effect = NarrativeEffect::BuildingHappiness {
+        building_id,
+        change: 10,
+    };
+    event
+}
+
+/// The tenants voted to sell. The landlord takes the offer or pays to fight it.
+pub fn buyout_approved(
+    month: u32,
+    building_id: u32,
+    building_name: &str,
+    offer: i32,
+    resistance_cost: i32,
+) -> NarrativeEvent {
+    let sell = NarrativeEffect::Multiple {
+        effects: vec![
+            NarrativeEffect::Money { amount: offer },
+            NarrativeEffect::SellBuilding { building_id },
+        ],
+    };
+    let mut event = NarrativeEvent::with_choices(
+        0,
+        NarrativeEventType::TenantCouncilDemand,
+        month,
+        "Tenants Vote to Sell",
+        &format!(
+            "A majority of {}'s tenants backed the developer's offer. Unless you \
+             fight it, the sale goes ahead.",
+            building_name
+        ),
+        vec![
+            NarrativeChoice {
+                label: "Accept the Sale".to_string(),
+                description: format!("Sell the building for ${}", offer),
+                effect: sell.clone(),
+                reputation_change: 0,
+            },
+            NarrativeChoice {
+                label: "Resist".to_string(),
+                description: format!("Lawyers and relocation offers (${})", resistance_cost),
+                effect: NarrativeEffect::Money {
+                    amount: -resistance_cost,
+                },
+                reputation_change: -5,
+            },
+        ],
+    );
+    event.default_effect = sell;
+    event
+}
@@ -0,0 +1,58 @@
+//! Utility price spikes: summer heatwaves and winter storms driving up what
+//! the building pays for power and heat.
+
+use super::events::{NarrativeEffect, NarrativeEvent, NarrativeEventType};
+use crate::data::config::UtilitySpikeConfig;
+use crate::simulation::Season;
+
+/// The utility company raising rates for the season's extreme weather, or
+/// `None` in the mild seasons.
+pub fn utility_rate_change(month: u32, config: &UtilitySpikeConfig) -> Option<NarrativeEvent> {
+    let (headline, description, multiplier) = match Season::from_tick(month) {
+        Season::Summer => (
+            "Heatwave Sends Power Prices Soaring",
+            "With every air conditioner in the city running flat out, the utility \
+             has raised its rates until the heat breaks.",
+            config.heatwave_multiplier,
+        ),
+        Season::Winter => (
+            "Winter Storm Doubles Heating Costs",
+            "A brutal cold snap has gas and power demand at record highs. Utility \
+             rates are up sharply until the storm passes.",
+            config.winter_storm_multiplier,
+        ),
+        Season::Spring | Season::Fall => return None,
+    };
+    let mut event = NarrativeEvent::news(0, month, headline, description);
+    event.event_type = NarrativeEventType::CityEvent;
+    event.default_effect = NarrativeEffect::UtilityRateChange {
+        multiplier,
+        duration_ticks: config.duration_ticks,
+    };
+    Some(event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn winter_storms_spike_rates_harder_than_heatwaves() {
+        let config = UtilitySpikeConfig::default();
+        let multiplier = |season: Season| {
+            let month = (0..12)
+                .find(|&month| Season::from_tick(month) == season)
+                .unwrap();
+            match utility_rate_change(month, &config).map(|event| event.default_effect) {
+                Some(NarrativeEffect::UtilityRateChange { multiplier, .. }) => Some(multiplier),
+                _ => None,
+            }
+        };
+        assert_eq!(multiplier(Season::Summer), Some(config.heatwave_multiplier));
+        assert_eq!(
+            multiplier(Season::Winter),
+            Some(config.winter_storm_multiplier)
+        );
+        assert_eq!(multiplier(Season::Spring), None);
+    }
+}
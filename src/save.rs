@@ -1,5 +1,7 @@
 pub mod manager;
+mod migration; // Save envelope and version-to-version migrations
 
 pub use manager::{
-    has_save_game, load_game, load_player_progress, save_game, save_player_progress, PlayerProgress,
+    has_save_game, load_game_slot, load_player_progress, save_game, save_player_progress,
+    slot_summary, NewGamePlusModifiers, PlayerProgress, SlotSummary, SAVE_SLOT_COUNT,
 };
@@ -1,16 +1,35 @@
+use super::migration::SaveFileEnvelope;
 use crate::state::GameplayState;
 use macroquad_toolkit::persistence::{json_key_exists, load_json_key, save_json_key};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 const GAME_NAME: &str = "apartment_manager";
-const SAVE_FILE_NAME: &str = "savegame.json";
+/// The single save file used before save slots existed; read as slot 0.
+const LEGACY_SAVE_FILE_NAME: &str = "savegame.json";
 const PROGRESS_FILE_NAME: &str = "player_progress.json";
 
+/// Bonuses a fully completed building grants to the next building's run.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
+pub struct NewGamePlusModifiers {
+    pub starting_funds_bonus: i32,
+    /// Added to the starting neighborhood's reputation.
+    pub reputation_head_start: i32,
+    /// Upgrade ids that skip their unit-size gate.
+    pub unlocked_upgrades: Vec<String>,
+}
+
 /// Player progress - persists across game sessions
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
 pub struct PlayerProgress {
     pub unlocked_buildings: Vec<String>,
     pub completed_buildings: Vec<String>,
+    /// New Game+ modifiers keyed by the template id they apply to.
+    #[serde(default)]
+    pub new_game_plus: HashMap<String, NewGamePlusModifiers>,
+    /// Set once Uncle Artie's tutorial has been finished; unlocks skipping it.
+    #[serde(default)]
+    pub tutorial_completed_before: bool,
 }
 
 impl PlayerProgress {
@@ -18,6 +37,8 @@ impl PlayerProgress {
         Self {
             unlocked_buildings: vec!["mvp_default".to_string()], // First building unlocked by default
             completed_buildings: Vec::new(),
+            new_game_plus: HashMap::new(),
+            tutorial_completed_before: false,
         }
     }
 
@@ -53,25 +74,94 @@ impl PlayerProgress {
     }
 }
 
-/// Save the current game state to disk
+/// Number of save slots offered on the slot selection screen.
+pub const SAVE_SLOT_COUNT: u8 = 3;
+
+/// What the slot selection screen shows for an occupied slot. Stored next to
+/// the save itself so listing slots never has to deserialize a whole game
+/// (and so the timestamp works on wasm, where there is no file metadata).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SlotSummary {
+    pub building_name: String,
+    pub current_tick: u32,
+    /// Wall-clock seconds since the Unix epoch at save time.
+    pub saved_at: f64,
+    #[serde(default)]
+    pub new_game_plus: bool,
+}
+
+/// Storage key for a slot's game state (a localStorage key on wasm).
+fn slot_key(slot: u8) -> String {
+    format!("save_slot_{}.json", slot)
+}
+
+fn slot_summary_key(slot: u8) -> String {
+    format!("save_slot_{}_summary.json", slot)
+}
+
+/// Save the current game state into the slot it was started in or loaded from.
 pub fn save_game(state: &GameplayState) -> std::io::Result<()> {
-    save_json_key(GAME_NAME, SAVE_FILE_NAME, state).map_err(std::io::Error::other)
+    save_game_slot(state, state.save_slot)
+}
+
+/// Save the game state into a specific slot, along with its summary.
+pub fn save_game_slot(state: &GameplayState, slot: u8) -> std::io::Result<()> {
+    let envelope = SaveFileEnvelope::wrap(state).map_err(std::io::Error::other)?;
+    save_json_key(GAME_NAME, &slot_key(slot), &envelope).map_err(std::io::Error::other)?;
+    let summary = SlotSummary {
+        building_name: state.building.name.clone(),
+        current_tick: state.current_tick,
+        saved_at: macroquad::miniquad::date::now(),
+        new_game_plus: state.new_game_plus.is_some(),
+    };
+    save_json_key(GAME_NAME, &slot_summary_key(slot), &summary).map_err(std::io::Error::other)
 }
 
-/// Load the game state from disk
-pub fn load_game() -> std::io::Result<GameplayState> {
-    let mut state: GameplayState =
-        load_json_key(GAME_NAME, SAVE_FILE_NAME).map_err(std::io::Error::other)?;
+/// Load the game in a slot. Slot 0 falls back to the pre-slots single save
+/// file so existing players keep their game.
+pub fn load_game_slot(slot: u8) -> Option<GameplayState> {
+    let key = if json_key_exists(GAME_NAME, &slot_key(slot)) {
+        slot_key(slot)
+    } else if slot == 0 {
+        LEGACY_SAVE_FILE_NAME.to_string()
+    } else {
+        return None;
+    };
+
+    // Read as raw JSON first so older save shapes can be migrated forward.
+    let mut state = load_json_key::<serde_json::Value>(GAME_NAME, &key)
+        .map_err(|e| e.to_string())
+        .and_then(|raw| SaveFileEnvelope::from_raw(raw).into_state())
+        .map_err(|e| eprintln!("Failed to load save slot {}: {}", slot, e))
+        .ok()?;
 
     // Restore non-serialized fields and repair older save shapes.
     state.post_load();
+    state.save_slot = slot;
 
-    Ok(state)
+    Some(state)
+}
+
+/// Summary of a slot's save, or `None` if the slot is empty.
+pub fn slot_summary(slot: u8) -> Option<SlotSummary> {
+    if let Ok(summary) = load_json_key(GAME_NAME, &slot_summary_key(slot)) {
+        return Some(summary);
+    }
+    // A legacy single-file save shows up in slot 0 without a timestamp.
+    if slot == 0 && json_key_exists(GAME_NAME, LEGACY_SAVE_FILE_NAME) {
+        return Some(SlotSummary {
+            building_name: "Saved Game".to_string(),
+            current_tick: 0,
+            saved_at: 0.0,
+            new_game_plus: false,
+        });
+    }
+    None
 }
 
-/// Check if a save file exists
+/// Check if any slot holds a save
 pub fn has_save_game() -> bool {
-    json_key_exists(GAME_NAME, SAVE_FILE_NAME)
+    (0..SAVE_SLOT_COUNT).any(|slot| slot_summary(slot).is_some())
 }
 
 /// Load player progress (persistent unlock state)
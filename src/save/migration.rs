@@ -0,0 +1,117 @@
+//! Versioned save files. Saves are written inside a `SaveFileEnvelope`; on
+//! load the raw JSON is walked forward through one migration per version
+//! before it is deserialized into `GameplayState`.
+
+use crate::state::GameplayState;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Bump this and add a `migrate_vN_to_vN+1` step whenever a save field is
+/// renamed, removed or changes shape. Plain additions only need
+/// `#[serde(default)]`.
+pub const CURRENT_SAVE_VERSION: u32 = 2;
+
+/// What is actually written to a save slot.
+#[derive(Serialize, Deserialize)]
+pub struct SaveFileEnvelope {
+    pub version: u32,
+    pub data: Value,
+}
+
+impl SaveFileEnvelope {
+    pub fn wrap(state: &GameplayState) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            version: CURRENT_SAVE_VERSION,
+            data: serde_json::to_value(state)?,
+        })
+    }
+
+    /// Read any save JSON: an envelope of any known version, or a bare
+    /// pre-envelope `GameplayState` (treated as version 1).
+    pub fn from_raw(raw: Value) -> Self {
+        let is_envelope =
+            raw.get("version").is_some_and(Value::is_u64) && raw.get("data").is_some();
+        if is_envelope {
+            if let Ok(envelope) = serde_json::from_value(raw.clone()) {
+                return envelope;
+            }
+        }
+        Self {
+            version: 1,
+            data: raw,
+        }
+    }
+
+    /// Migrate to the current version and deserialize.
+    pub fn into_state(self) -> Result<GameplayState, String> {
+        if self.version > CURRENT_SAVE_VERSION {
+            return Err(format!(
+                "save version {} is newer than this game supports ({})",
+                self.version, CURRENT_SAVE_VERSION
+            ));
+        }
+
+        let mut data = self.data;
+        let mut version = self.version;
+        while version < CURRENT_SAVE_VERSION {
+            data = match version {
+                1 => migrate_v1_to_v2(data),
+                _ => data,
+            };
+            version += 1;
+        }
+
+        serde_json::from_value(data).map_err(|e| e.to_string())
+    }
+}
+
+/// v2 renamed `NeighborhoodStats::school_quality` to `school_rating`.
+fn migrate_v1_to_v2(mut data: Value) -> Value {
+    let neighborhoods = data
+        .pointer_mut("/city/neighborhoods")
+        .and_then(Value::as_array_mut);
+    for neighborhood in neighborhoods.into_iter().flatten() {
+        let Some(stats) = neighborhood.get_mut("stats").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        if let Some(quality) = stats.remove("school_quality") {
+            stats.entry("school_rating".to_string()).or_insert(quality);
+        }
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_bare_save_is_version_one() {
+        let envelope = SaveFileEnvelope::from_raw(json!({ "current_tick": 3 }));
+        assert_eq!(envelope.version, 1);
+
+        let wrapped = SaveFileEnvelope::from_raw(json!({ "version": 2, "data": {} }));
+        assert_eq!(wrapped.version, 2);
+    }
+
+    #[test]
+    fn test_v1_to_v2_renames_school_quality() {
+        let data = json!({
+            "city": { "neighborhoods": [ { "stats": { "school_quality": 80 } } ] }
+        });
+        let migrated = migrate_v1_to_v2(data);
+        let stats = &migrated["city"]["neighborhoods"][0]["stats"];
+        assert_eq!(stats["school_rating"], 80);
+        assert!(stats.get("school_quality").is_none());
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let envelope = SaveFileEnvelope {
+            version: CURRENT_SAVE_VERSION + 1,
+            data: json!({}),
+        };
+        assert!(envelope.into_state().is_err());
+    }
+}
@@ -16,8 +16,8 @@ use crate::building::{Building, DesignType, UpgradeAction};
 use crate::consequences::{ComplianceSystem, InspectionTrigger};
 use crate::data::config::GameConfig;
 use crate::economy::{process_upgrade, FinancialLedger, PlayerFunds, Transaction, TransactionType};
-use crate::simulation::{advance_tick, EventLog, GameOutcome};
-use crate::tenant::matching::{evaluate_lease_offer, LeaseOffer};
+use crate::simulation::{advance_tick, CostAdjustments, EventLog, GameOutcome};
+use crate::tenant::matching::{evaluate_lease_offer, LeaseOffer, ADA_COMPLIANT_FLAG};
 use crate::tenant::{Tenant, TenantApplication, TenantArchetype};
 use macroquad_toolkit::rng;
 
@@ -140,13 +140,13 @@ impl Sim {
     /// measures the regulatory teeth that punish neglect (the game runs these in
     /// `end_turn`, outside `advance_tick`).
     fn run_inspections_and_fines(&mut self) {
-        self.compliance.tick(self.current_tick);
+        let cfg = self.config.regulations.clone();
+        self.compliance.tick(self.current_tick, &cfg);
 
         let score = self
             .building
             .average_condition()
             .min(self.building.hallway_condition);
-        let cfg = self.config.regulations.clone();
         let due = self.compliance.has_due_inspection(0);
         let random_check = rng::gen_range(0, 100) < cfg.random_inspection_chance_percent;
         if due || random_check {
@@ -155,8 +155,22 @@ impl Sim {
             } else {
                 InspectionTrigger::Random
             };
-            self.compliance
-                .run_inspection(0, score, self.current_tick, trigger, &cfg);
+            let poor = self.config.thresholds.poor_condition;
+            let poor_units: Vec<_> = self
+                .building
+                .apartments
+                .iter()
+                .filter(|apt| apt.condition <= poor)
+                .collect();
+            self.compliance.run_inspection(
+                0,
+                score,
+                self.building.flags.contains(ADA_COMPLIANT_FLAG),
+                &poor_units,
+                self.current_tick,
+                trigger,
+                &cfg,
+            );
         }
 
         if self.compliance.unpaid_fines > 0 {
@@ -222,8 +236,12 @@ impl Sim {
 
             let offer =
                 LeaseOffer::from_config(apt.rent_price, &self.config.matching.lease_defaults);
-            let accept_prob =
-                evaluate_lease_offer(&app.tenant, &offer, &self.config.matching.lease_acceptance);
+            let accept_prob = evaluate_lease_offer(
+                &app.tenant,
+                &offer,
+                &self.config.happiness,
+                &self.config.matching.lease_acceptance,
+            );
             let leverage_penalty = app.tenant.negotiation_leverage() as f32 * 0.002;
             let adjusted = (accept_prob - leverage_penalty).clamp(0.0, 1.0);
 
@@ -379,7 +397,10 @@ impl Sim {
                 &mut self.current_tick,
                 &mut self.next_tenant_id,
                 has_ever_had_tenant,
+                false,
                 1.0, // neutral reputation multiplier: the harness has no city layer
+                50,  // neutral school rating, for the same reason
+                CostAdjustments::default(), // no manager, no inflation
                 &self.config,
             );
 
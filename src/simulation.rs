@@ -2,20 +2,29 @@
 //!
 //! The heartbeat of the game. Handles time and state progression:
 //! - `Tick`: The central game loop processing logic.
+//! - `Operating Costs`: The building's monthly bills and side income.
 //! - `Decay`: Entropy and maintenance mechanics.
+//! - `Season`: The in-game calendar's seasons.
 //! - `Win Conditions`: Victory and failure state checks.
 //! - `Events`: Random events and lucky/unlucky occurrences.
+//! - `Lifetime Stats`: Career-long running totals.
 
 mod decay;
 mod events;
+mod lifetime_stats;
+mod operating_costs;
 mod random_events;
+mod season;
 mod tick;
 mod win_condition;
 
+pub use operating_costs::CostAdjustments;
 pub use tick::{advance_tick, TickResult};
 // pub use decay::apply_decay;
 pub use events::{
     ActiveWorldEvent, ActiveWorldEventKind, EventLog, EventSeverity, GameEvent, NotificationLevel,
 };
+pub use lifetime_stats::LifetimeStats;
 pub use random_events::EventSystem;
+pub use season::Season;
 pub use win_condition::GameOutcome;
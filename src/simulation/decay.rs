@@ -1,6 +1,7 @@
 use super::GameEvent;
 use crate::building::Building;
-use crate::data::config::{DecayConfig, ThresholdsConfig};
+use crate::data::config::{AmenityConfig, DecayConfig, ThresholdsConfig};
+use std::collections::HashMap;
 
 /// Apply monthly decay to all building elements
 /// Returns events for significant decay milestones
@@ -8,8 +9,12 @@ pub fn apply_decay(
     building: &mut Building,
     decay: &DecayConfig,
     thresholds: &ThresholdsConfig,
+    amenities: &HashMap<String, AmenityConfig>,
+    current_tick: u32,
 ) -> Vec<GameEvent> {
     let mut events = Vec::new();
+    let age_multiplier = decay.age_multiplier(building.age(current_tick));
+    let decay = decay.scaled(age_multiplier);
 
     // Track conditions before decay for event generation
     let conditions_before: Vec<_> = building
@@ -20,8 +25,13 @@ pub fn apply_decay(
 
     let hallway_before = building.hallway_condition;
 
-    // Apply decay
-    building.apply_monthly_decay(decay.apartment_per_tick, decay.hallway_per_tick);
+    // Apply decay; climate control takes the edge off wear inside the units.
+    let apartment_decay = if building.flags.contains("hvac") {
+        (decay.apartment_per_tick - decay.hvac_decay_reduction).max(0)
+    } else {
+        decay.apartment_per_tick
+    };
+    building.apply_monthly_decay(apartment_decay, decay.hallway_per_tick);
 
     // Check for significant condition changes in apartments
     for (id, unit, old_condition) in conditions_before {
@@ -47,6 +57,15 @@ pub fn apply_decay(
         }
     }
 
+    // Shared amenities wear out at their own configured rates
+    for amenity in &mut building.amenities {
+        let rate = amenities
+            .get(amenity.amenity_type.key())
+            .map_or(0, |config| config.decay_per_tick);
+        let amount = (rate as f32 * age_multiplier).round() as i32;
+        amenity.condition = (amenity.condition - amount).max(0);
+    }
+
     // Check hallway
     let hallway_after = building.hallway_condition;
     if hallway_before >= thresholds.poor_condition && hallway_after < thresholds.poor_condition {
@@ -57,3 +76,21 @@ pub fn apply_decay(
 
     events
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn old_buildings_decay_faster() {
+        let decay = crate::data::config::GameConfig::default().decay;
+        assert_eq!(decay.age_multiplier(10), 1.0);
+        assert_eq!(decay.age_multiplier(25), 1.0);
+        assert!((decay.age_multiplier(60) - 1.7).abs() < f32::EPSILON);
+
+        let mut building = Building::new("Test", 1, 1);
+        building.construction_year = 2000;
+        assert_eq!(building.age(0), 25);
+        assert_eq!(building.age(24), 27);
+    }
+}
@@ -49,6 +49,8 @@ pub enum GameEvent {
 
     // Complaint events
     NoiseComplaint {
+        #[serde(default)]
+        tenant_id: u32,
         tenant_name: String,
     },
     TenantDamage {
@@ -78,6 +80,9 @@ pub enum GameEvent {
     MonthEnd {
         tick: u32,
         income: i32,
+        laundry_income: i32,
+        #[serde(default)]
+        storage_income: i32,
         expenses: i32,
         balance: i32,
     },
@@ -95,6 +100,26 @@ pub enum GameEvent {
         apartment_unit: String,
         damage: i32,
     },
+    /// A leak damaged a unit; `floors_affected` counts every floor it has
+    /// reached so far.
+    WaterDamage {
+        apartment_unit: String,
+        floors_affected: u32,
+        /// Months the player has to repair every damaged unit.
+        #[serde(default)]
+        repair_window: u32,
+    },
+    PowerOutage {
+        duration_ticks: u32,
+    },
+    /// Someone moved into a long-vacant unit without a lease.
+    SquatterFound {
+        apartment_unit: String,
+    },
+    SquatterDamage {
+        apartment_unit: String,
+        damage: i32,
+    },
     Gentrification {
         tick_duration: u32,
         effect_desc: String,
@@ -103,6 +128,10 @@ pub enum GameEvent {
         result: String,
         fine: i32,
     },
+    PropertyTaxAssessment {
+        old_value: i32,
+        new_value: i32,
+    },
 
     // Critical Failures
     BoilerFailure {
@@ -156,7 +185,7 @@ impl GameEvent {
             } => {
                 format!("{} moved into Unit {}", tenant_name, apartment_unit)
             }
-            GameEvent::NoiseComplaint { tenant_name } => {
+            GameEvent::NoiseComplaint { tenant_name, .. } => {
                 format!("Noise complaint from {}", tenant_name)
             }
             GameEvent::TenantDamage {
@@ -209,22 +238,38 @@ impl GameEvent {
             GameEvent::MonthEnd {
                 tick,
                 income,
+                laundry_income,
+                storage_income,
                 expenses,
                 balance,
             } => {
+                let laundry = if *laundry_income > 0 {
+                    format!(" (laundry ${})", laundry_income)
+                } else {
+                    String::new()
+                };
+                let storage = if *storage_income > 0 {
+                    format!(" (storage ${})", storage_income)
+                } else {
+                    String::new()
+                };
                 format!(
-                    "Month {} ended: +${} -${} = ${}",
-                    tick, income, expenses, balance
+                    "Month {} ended: +${}{}{} -${} = ${}",
+                    tick, income, laundry, storage, expenses, balance
                 )
             }
             GameEvent::GameEnded { outcome } => match outcome {
                 GameOutcome::Victory { .. } => "🎉 Victory!".to_string(),
                 GameOutcome::Bankruptcy { .. } => "💸 Bankrupt!".to_string(),
                 GameOutcome::AllTenantsLeft => "🚪 All tenants left!".to_string(),
+                GameOutcome::ScenarioFailed { .. } => "⏰ Out of time!".to_string(),
             },
             GameEvent::Heatwave { tick_duration } => {
                 format!("☀️ Heatwave! (Duration: {} months)", tick_duration)
             }
+            GameEvent::PowerOutage { duration_ticks } => {
+                format!("🔌 Power outage! (Duration: {} months)", duration_ticks)
+            }
             GameEvent::PipeBurst {
                 apartment_unit,
                 damage,
@@ -234,6 +279,28 @@ impl GameEvent {
                     apartment_unit, damage
                 )
             }
+            GameEvent::WaterDamage {
+                apartment_unit,
+                floors_affected,
+                repair_window,
+            } => {
+                format!(
+                    "💧 Water damage in Unit {}! ({} floor(s) affected, repair within {} months)",
+                    apartment_unit, floors_affected, repair_window
+                )
+            }
+            GameEvent::SquatterFound { apartment_unit } => {
+                format!("🚪 Squatter found in vacant Unit {}", apartment_unit)
+            }
+            GameEvent::SquatterDamage {
+                apartment_unit,
+                damage,
+            } => {
+                format!(
+                    "🚪 Squatter in Unit {} is wrecking the place (-{} condition)",
+                    apartment_unit, damage
+                )
+            }
             GameEvent::Gentrification {
                 tick_duration,
                 effect_desc,
@@ -259,6 +326,12 @@ impl GameEvent {
             GameEvent::StaffAction { role, action } => {
                 format!("👔 {}: {}", role, action)
             }
+            GameEvent::PropertyTaxAssessment {
+                old_value,
+                new_value,
+            } => {
+                format!("🏛️ Property reassessed: ${} → ${}", old_value, new_value)
+            }
             GameEvent::Notification { message, .. } => message.clone(),
         }
     }
@@ -291,7 +364,11 @@ impl GameEvent {
                 _ => EventSeverity::Negative,
             },
             GameEvent::Heatwave { .. } => EventSeverity::Warning,
+            GameEvent::PowerOutage { .. } => EventSeverity::Negative,
             GameEvent::PipeBurst { .. } => EventSeverity::Negative,
+            GameEvent::WaterDamage { .. } => EventSeverity::Negative,
+            GameEvent::SquatterFound { .. } => EventSeverity::Warning,
+            GameEvent::SquatterDamage { .. } => EventSeverity::Negative,
             GameEvent::Gentrification { .. } => EventSeverity::Positive,
             GameEvent::Inspection { fine, .. } => {
                 if *fine > 0 {
@@ -303,6 +380,16 @@ impl GameEvent {
             GameEvent::BoilerFailure { .. } => EventSeverity::Negative,
             GameEvent::StructuralIssue { .. } => EventSeverity::Negative,
             GameEvent::StaffAction { .. } => EventSeverity::Info,
+            GameEvent::PropertyTaxAssessment {
+                old_value,
+                new_value,
+            } => {
+                if new_value > old_value {
+                    EventSeverity::Warning
+                } else {
+                    EventSeverity::Info
+                }
+            }
         }
     }
 }
@@ -325,6 +412,7 @@ pub enum EventSeverity {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ActiveWorldEventKind {
     Heatwave,
+    PowerOutage,
     Gentrification,
 }
 
@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// Running totals across the whole career, for the career summary and
+/// achievements that care about history rather than the current month.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LifetimeStats {
+    pub total_rent_collected: i32,
+    pub total_tenants_housed: u32,
+    /// Month the building was at its fullest.
+    pub highest_occupancy_tick: u32,
+    #[serde(default)]
+    pub highest_occupancy: usize,
+    pub max_buildings_owned: usize,
+    pub total_upgrades_applied: u32,
+    pub worst_gentrification_score: i32,
+    pub missions_completed: u32,
+    /// Longest run of inspector visits graded C or better.
+    #[serde(default)]
+    pub best_inspection_streak: u32,
+}
+
+impl LifetimeStats {
+    /// Fold one month's end-of-turn figures into the running totals.
+    pub fn record_month(
+        &mut self,
+        rent_collected: i32,
+        occupied_units: usize,
+        buildings_owned: usize,
+        gentrification_score: i32,
+        tick: u32,
+    ) {
+        self.total_rent_collected += rent_collected;
+        if occupied_units > self.highest_occupancy {
+            self.highest_occupancy = occupied_units;
+            self.highest_occupancy_tick = tick;
+        }
+        self.max_buildings_owned = self.max_buildings_owned.max(buildings_owned);
+        self.worst_gentrification_score = self.worst_gentrification_score.max(gentrification_score);
+    }
+
+    pub fn record_tenant_housed(&mut self) {
+        self.total_tenants_housed += 1;
+    }
+
+    pub fn record_upgrade(&mut self) {
+        self.total_upgrades_applied += 1;
+    }
+
+    pub fn record_mission_completed(&mut self) {
+        self.missions_completed += 1;
+    }
+
+    pub fn record_inspection_streak(&mut self, streak: u32) {
+        self.best_inspection_streak = self.best_inspection_streak.max(streak);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LifetimeStats;
+
+    #[test]
+    fn record_month_keeps_totals_and_peaks() {
+        let mut stats = LifetimeStats::default();
+        stats.record_month(1000, 4, 1, 20, 1);
+        stats.record_month(800, 6, 2, 35, 2);
+        stats.record_month(900, 5, 1, 10, 3);
+
+        assert_eq!(stats.total_rent_collected, 2700);
+        assert_eq!(stats.highest_occupancy, 6);
+        assert_eq!(stats.highest_occupancy_tick, 2);
+        assert_eq!(stats.max_buildings_owned, 2);
+        assert_eq!(stats.worst_gentrification_score, 35);
+    }
+}
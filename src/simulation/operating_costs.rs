@@ -0,0 +1,299 @@
+// Monthly running costs and side income for the active building: marketing,
+// amenities, taxes, utilities, insurance and staff.
+
+use super::tick::{GameTick, TickResult};
+use super::{GameEvent, Season};
+use crate::building::{AmenityType, Building};
+use crate::economy::{OperatingCosts, PlayerFunds, Transaction, TransactionType};
+
+/// Per-run adjustments to the monthly bills that live outside the config.
+#[derive(Clone, Copy, Debug)]
+pub struct CostAdjustments {
+    /// Salary of a hired property manager; 0 when there isn't one.
+    pub manager_salary: i32,
+    /// Cumulative inflation applied to taxes, utilities, insurance and staff.
+    pub inflation_multiplier: f32,
+    /// Temporary spike in utility prices; 1.0 when rates are normal.
+    pub utility_rate_multiplier: f32,
+    /// Assessed value over the first assessment; scales property tax.
+    pub property_tax_factor: f32,
+}
+
+impl Default for CostAdjustments {
+    fn default() -> Self {
+        Self {
+            manager_salary: 0,
+            inflation_multiplier: 1.0,
+            utility_rate_multiplier: 1.0,
+            property_tax_factor: 1.0,
+        }
+    }
+}
+
+impl CostAdjustments {
+    fn inflate(&self, amount: i32) -> i32 {
+        (amount as f32 * self.inflation_multiplier).round() as i32
+    }
+}
+
+impl GameTick {
+    pub(super) fn process_operating_costs(
+        building: &mut Building,
+        funds: &mut PlayerFunds,
+        current_tick: u32,
+        costs: CostAdjustments,
+        result: &mut TickResult,
+        config: &crate::data::config::GameConfig,
+    ) {
+        // Marketing
+        let marketing_cost = building.marketing_strategy.monthly_cost(&config.marketing);
+        if marketing_cost > 0 {
+            let transaction = Transaction::expense(
+                TransactionType::Marketing,
+                marketing_cost,
+                &format!("{} Marketing Campaign", building.marketing_strategy.name()),
+                current_tick,
+            );
+            if !funds.deduct_expense(transaction) {
+                building.marketing_strategy = crate::building::MarketingType::None;
+                result.events.push(GameEvent::Notification {
+                    message: "Marketing campaign cancelled due to lack of funds.".to_string(),
+                    level: crate::simulation::NotificationLevel::Warning,
+                });
+            }
+        }
+
+        if building.open_house_remaining > 0 {
+            building.open_house_remaining -= 1;
+            if building.open_house_remaining == 0 {
+                result.events.push(GameEvent::Notification {
+                    message: "Open House event has ended.".to_string(),
+                    level: crate::simulation::NotificationLevel::Info,
+                });
+            }
+        }
+
+        // Coin laundry takings scale with how many units are occupied.
+        if building.has_amenity(AmenityType::Laundry) {
+            let laundry_income =
+                config.economy.laundry_income_per_occupied_unit * building.occupancy_count() as i32;
+            if laundry_income > 0 {
+                funds.add_income(Transaction::income(
+                    TransactionType::LaundryIncome,
+                    laundry_income,
+                    "Laundry room",
+                    current_tick,
+                ));
+            }
+        }
+
+        let storage_income = building.storage_income(config.economy.storage_locker_income);
+        if storage_income > 0 {
+            funds.add_income(Transaction::income(
+                TransactionType::StorageIncome,
+                storage_income,
+                "Storage lockers",
+                current_tick,
+            ));
+        }
+
+        let hoa_income = building.owner_occupier_fees();
+        if hoa_income > 0 {
+            funds.add_income(Transaction::income(
+                TransactionType::HoaIncome,
+                hoa_income,
+                "HOA from owner-occupiers",
+                current_tick,
+            ));
+        }
+
+        let vacancy_tax = building.vacancy_tax_due(current_tick, &config.vacancy_tax);
+        if vacancy_tax > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::VacancyTax,
+                vacancy_tax,
+                "Vacancy tax",
+                current_tick,
+            ));
+        }
+
+        // Fixed monthly overhead (mortgage/upkeep) — always-on structural cost.
+        let overhead = OperatingCosts::calculate_base_overhead(building, &config.operating_costs);
+        if overhead > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::Mortgage,
+                overhead,
+                "Mortgage & Upkeep",
+                current_tick,
+            ));
+        }
+
+        // Taxes & Expenses
+        // Reassessments move the tax with the building's value, not just its rent.
+        let tax = OperatingCosts::calculate_property_tax(
+            building,
+            result.rent_collected,
+            &config.operating_costs,
+            current_tick,
+        );
+        let tax = costs.inflate((tax as f32 * costs.property_tax_factor).round() as i32);
+        if tax > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::PropertyTax,
+                tax,
+                "Monthly Property Tax",
+                current_tick,
+            ));
+        }
+
+        // Utilities swing with the season (summer AC, winter heating) and
+        // with any rate spike the city is going through.
+        let season = Season::from_tick(current_tick);
+        let utilities = costs.inflate(
+            (OperatingCosts::calculate_utilities(building, &config.operating_costs) as f32
+                * config.seasonal_modifiers.cost_multipliers[season.index()]
+                * OperatingCosts::effective_utility_rate(building, costs.utility_rate_multiplier))
+            .round() as i32,
+        );
+        let utilities = utilities
+            - OperatingCosts::calculate_solar_savings(building, utilities, &config.economy);
+        if utilities > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::Utilities,
+                utilities,
+                "Utility Bills",
+                current_tick,
+            ));
+        }
+
+        let insurance = costs.inflate(OperatingCosts::calculate_insurance(
+            building,
+            &config.operating_costs,
+            config.thresholds.critical_condition,
+        ));
+        if insurance > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::Insurance,
+                insurance,
+                "Property Insurance",
+                current_tick,
+            ));
+        }
+
+        // Staff Salaries - Data Driven
+        let salaries = costs.inflate(OperatingCosts::calculate_staff_salaries(
+            building,
+            &config.economy,
+        ));
+        if salaries > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::StaffSalary,
+                salaries,
+                "Staff Salaries",
+                current_tick,
+            ));
+        }
+
+        // A hired property manager draws a salary on top of building staff.
+        if costs.manager_salary > 0 {
+            funds.apply_required_expense(Transaction::expense(
+                TransactionType::StaffSalary,
+                costs.manager_salary,
+                "Property Manager",
+                current_tick,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::config::GameConfig;
+
+    fn bills(costs: CostAdjustments) -> i32 {
+        let config = GameConfig::default();
+        let mut building = Building::new("Test", 2, 2);
+        let mut funds = PlayerFunds::new(100_000);
+        let mut result = TickResult {
+            events: Vec::new(),
+            rent_collected: 4_000,
+            tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
+            new_applications: 0,
+            outcome: None,
+        };
+        GameTick::process_operating_costs(
+            &mut building,
+            &mut funds,
+            1,
+            costs,
+            &mut result,
+            &config,
+        );
+        100_000 - funds.balance
+    }
+
+    #[test]
+    fn inflation_raises_the_monthly_bills() {
+        let base = bills(CostAdjustments::default());
+        let inflated = bills(CostAdjustments {
+            inflation_multiplier: 1.5,
+            ..CostAdjustments::default()
+        });
+        assert!(inflated > base);
+
+        let with_manager = bills(CostAdjustments {
+            manager_salary: 500,
+            ..CostAdjustments::default()
+        });
+        assert_eq!(with_manager, base + 500);
+    }
+
+    /// This month's utility bill for a fully let building that pays its
+    /// tenants' utilities.
+    fn utility_bill(costs: CostAdjustments) -> i32 {
+        let config = GameConfig::default();
+        let mut building = Building::new("Test", 2, 2);
+        building.utilities_included = true;
+        for (id, apartment) in building.apartments.iter_mut().enumerate() {
+            apartment.tenant_id = Some(id as u32);
+        }
+        let mut funds = PlayerFunds::new(100_000);
+        let mut result = TickResult {
+            events: Vec::new(),
+            rent_collected: 0,
+            tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
+            new_applications: 0,
+            outcome: None,
+        };
+        GameTick::process_operating_costs(
+            &mut building,
+            &mut funds,
+            1,
+            costs,
+            &mut result,
+            &config,
+        );
+        funds
+            .transactions_for_tick(1)
+            .iter()
+            .filter(|t| t.transaction_type == TransactionType::Utilities)
+            .map(|t| t.amount.abs())
+            .sum()
+    }
+
+    #[test]
+    fn utility_rate_spikes_raise_the_bills() {
+        let base = utility_bill(CostAdjustments::default());
+        let spiked = utility_bill(CostAdjustments {
+            utility_rate_multiplier: 2.0,
+            ..CostAdjustments::default()
+        });
+        assert!(base > 0);
+        assert!(spiked > base);
+    }
+}
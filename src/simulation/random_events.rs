@@ -1,4 +1,5 @@
 use crate::building::Building;
+use crate::data::config::{SquatterConfig, WaterDamageConfig};
 use crate::economy::{PlayerFunds, Transaction, TransactionType};
 use crate::simulation::events::GameEvent;
 use macroquad_toolkit::rng;
@@ -17,6 +18,8 @@ impl EventSystem {
         building: &mut Building,
         funds: &mut PlayerFunds,
         current_tick: u32,
+        squatters: &SquatterConfig,
+        water_damage: &WaterDamageConfig,
     ) -> Vec<GameEvent> {
         let mut events = Vec::new();
 
@@ -29,6 +32,13 @@ impl EventSystem {
             events.push(GameEvent::Heatwave { tick_duration: 3 });
         }
 
+        // 1b. Power Outage (2% chance)
+        if rng::gen_range(0, 100) < 2 {
+            events.push(GameEvent::PowerOutage {
+                duration_ticks: rng::gen_range(1, 3),
+            });
+        }
+
         // 2. Pipe Burst (3% chance per tick to happen in ONE apartment)
         if rng::gen_range(0, 100) < 3 {
             let num_apts = building.apartments.len();
@@ -50,6 +60,45 @@ impl EventSystem {
             }
         }
 
+        // 2b. Water damage: a leak in an upper unit that works its way down a
+        // floor at a time until every damaged unit is repaired.
+        if building.has_water_damage() {
+            for apartment_unit in building.spread_water_damage(water_damage) {
+                events.push(GameEvent::WaterDamage {
+                    apartment_unit,
+                    floors_affected: building.water_damaged_floors(),
+                    repair_window: water_damage.repair_window,
+                });
+            }
+        } else if rng::gen_range(0, 100) < water_damage.start_chance {
+            if let Some(apartment_unit) = building.start_water_damage(current_tick, water_damage) {
+                events.push(GameEvent::WaterDamage {
+                    apartment_unit,
+                    floors_affected: 1,
+                    repair_window: water_damage.repair_window,
+                });
+            }
+        }
+
+        // 2c. Squatters: units left empty too long may be moved into. One
+        // left alone wears the unit down until the player evicts them.
+        for apartment_unit in building.complete_squatter_evictions() {
+            events.push(GameEvent::Notification {
+                message: format!("The squatter in Unit {} has been removed.", apartment_unit),
+                level: crate::simulation::NotificationLevel::Info,
+            });
+        }
+        building.track_vacancies();
+        for (apartment_unit, damage) in building.squatter_damage(current_tick, squatters) {
+            events.push(GameEvent::SquatterDamage {
+                apartment_unit,
+                damage,
+            });
+        }
+        if let Some(apartment_unit) = building.settle_squatter(current_tick, squatters) {
+            events.push(GameEvent::SquatterFound { apartment_unit });
+        }
+
         // 3. Gentrification (Very rare, 0.5% chance)
         if rng::gen_range(0, 1000) < 5 {
             events.push(GameEvent::Gentrification {
@@ -0,0 +1,61 @@
+use macroquad::prelude::Color;
+
+/// The four seasons of the in-game year. A tick is one month; tick 0 is the
+/// first month of spring.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Fall,
+    Winter,
+}
+
+impl Season {
+    pub fn from_tick(tick: u32) -> Self {
+        match (tick % 12) / 3 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Fall,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Index into the per-season arrays in `SeasonalModifiers`
+    /// (0=spring, 1=summer, 2=fall, 3=winter).
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Fall => "Fall",
+            Season::Winter => "Winter",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Season::Spring => Color::from_rgba(120, 200, 110, 255),
+            Season::Summer => Color::from_rgba(245, 200, 70, 255),
+            Season::Fall => Color::from_rgba(220, 130, 60, 255),
+            Season::Winter => Color::from_rgba(150, 200, 240, 255),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seasons_follow_the_calendar() {
+        assert_eq!(Season::from_tick(0), Season::Spring);
+        assert_eq!(Season::from_tick(4), Season::Summer);
+        assert_eq!(Season::from_tick(8), Season::Fall);
+        assert_eq!(Season::from_tick(11), Season::Winter);
+        assert_eq!(Season::from_tick(12), Season::Spring);
+        assert_eq!(Season::Winter.index(), 3);
+    }
+}
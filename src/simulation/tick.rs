@@ -1,13 +1,16 @@
-use super::{decay, win_condition, EventLog, EventSystem, GameEvent, GameOutcome};
-use crate::building::Building;
-use crate::economy::{
-    collect_rent, FinancialLedger, OperatingCosts, PlayerFunds, Transaction, TransactionType,
+use super::{
+    decay, win_condition, CostAdjustments, EventLog, EventSystem, GameEvent, GameOutcome, Season,
 };
+use crate::building::Building;
+use crate::consequences::SECURITY_CAMERAS_FLAG;
+use crate::economy::{collect_rent, FinancialLedger, PlayerFunds, Transaction, TransactionType};
 use crate::tenant::{
-    calculate_happiness, generate_applications, process_departures, Tenant, TenantApplication,
+    calculate_happiness, file_applications, generate_applications, process_departures,
+    promote_waitlisted, Tenant, TenantApplication,
 };
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Result of processing a game tick
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -15,6 +18,11 @@ pub struct TickResult {
     pub events: Vec<GameEvent>,
     pub rent_collected: i32,
     pub tenants_moved_out: Vec<String>,
+    /// Tenants who moved out leaving their unit better than they found it.
+    pub left_unit_improved: Vec<u32>,
+    /// Tenants who left abruptly, leaving belongings behind.
+    #[serde(default)]
+    pub abandoned: Vec<crate::tenant::Abandonment>,
     pub new_applications: usize,
     pub outcome: Option<GameOutcome>,
 }
@@ -35,13 +43,18 @@ impl GameTick {
         current_tick: u32,
         next_tenant_id: &mut u32,
         has_ever_had_tenant: bool,
+        sandbox_mode: bool,
         reputation_multiplier: f32,
+        school_rating: i32,
+        costs: CostAdjustments,
         config: &crate::data::config::GameConfig,
     ) -> TickResult {
         let mut result = TickResult {
             events: Vec::new(),
             rent_collected: 0,
             tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
             new_applications: 0,
             outcome: None,
         };
@@ -50,7 +63,7 @@ impl GameTick {
         Self::collect_rent(building, tenants, funds, current_tick, config, &mut result);
 
         // 2. Operating Costs & Staff
-        Self::process_operating_costs(building, funds, current_tick, &mut result, config);
+        Self::process_operating_costs(building, funds, current_tick, costs, &mut result, config);
         Self::process_critical_failures(
             building,
             tenants,
@@ -62,19 +75,41 @@ impl GameTick {
 
         // 3. Random Events
         let mut event_system = EventSystem::new();
-        let random_events = event_system.check_events(building, funds, current_tick);
+        let random_events = event_system.check_events(
+            building,
+            funds,
+            current_tick,
+            &config.squatters,
+            &config.water_damage,
+        );
         result.events.extend(random_events);
 
         // 4. Decay & Ownership
         if building.update_ownership(current_tick) {
             // Logic for handling ownership updates could go here
         }
-        let decay_events = decay::apply_decay(building, &config.decay, &config.thresholds);
+        let season = Season::from_tick(current_tick);
+        let monthly_decay = config
+            .decay
+            .scaled(config.seasonal_modifiers.decay_multipliers[season.index()]);
+        let decay_events = decay::apply_decay(
+            building,
+            &monthly_decay,
+            &config.thresholds,
+            &config.amenities_config,
+            current_tick,
+        );
         result.events.extend(decay_events);
 
         // 4b. Staff maintenance offsets decay; disruptive tenants add damage.
-        Self::process_janitor_maintenance(building, &mut result, config);
+        Self::process_janitor_maintenance(building, &monthly_decay, &mut result, config);
         Self::process_tenant_risk(building, tenants, config, &mut result);
+        if building.advance_renovation() {
+            result.events.push(GameEvent::Notification {
+                message: format!("Renovation of {} is complete.", building.name),
+                level: crate::simulation::NotificationLevel::Info,
+            });
+        }
 
         // 5. Tenant Happiness & Updates
         Self::update_tenants(
@@ -83,11 +118,23 @@ impl GameTick {
             &mut result,
             &config.happiness,
             &config.staff_effects,
+            &config.amenities_config,
+            Season::from_tick(current_tick),
+            config.economy.storage_locker_income,
         );
 
         // 6. Move-outs
-        let departure_notices = process_departures(tenants, building, &config.happiness);
-        for notice in departure_notices {
+        let departures = process_departures(
+            tenants,
+            building,
+            funds,
+            &config.happiness,
+            config.economy.deposit_deduction_per_point,
+            current_tick,
+        );
+        result.left_unit_improved = departures.left_unit_improved;
+        result.abandoned = departures.abandoned;
+        for notice in departures.notifications {
             result.events.push(GameEvent::TenantMovedOut {
                 message: notice.clone(),
             });
@@ -98,14 +145,35 @@ impl GameTick {
         applications.retain(|app| {
             !app.is_expired_after(current_tick, config.applications.expire_after_ticks)
         });
-        let new_apps = generate_applications(
-            building,
-            applications,
-            current_tick,
-            next_tenant_id,
-            reputation_multiplier,
-            config,
-        );
+        let max_per_unit = config.applications.max_per_unit;
+        for index in promote_waitlisted(applications, building, max_per_unit, current_tick) {
+            let app = &applications[index];
+            result.events.push(GameEvent::Notification {
+                message: format!(
+                    "{} moved up from the waitlist for Unit {}.",
+                    app.tenant.name,
+                    building
+                        .get_apartment(app.apartment_id)
+                        .map(|a| a.unit_number.as_str())
+                        .unwrap_or_default()
+                ),
+                level: crate::simulation::NotificationLevel::Info,
+            });
+        }
+        // Nobody applies to a building that still needs renovating.
+        let new_apps = if building.renovation_required {
+            Vec::new()
+        } else {
+            generate_applications(
+                building,
+                applications,
+                current_tick,
+                next_tenant_id,
+                reputation_multiplier,
+                school_rating,
+                config,
+            )
+        };
         result.new_applications = new_apps.len();
 
         for app in &new_apps {
@@ -118,7 +186,7 @@ impl GameTick {
                     .unwrap_or_default(),
             });
         }
-        applications.extend(new_apps);
+        file_applications(new_apps, applications, building, max_per_unit);
 
         // 8. Monthly Report
         let tick_transactions: Vec<_> = funds.transactions_for_tick(current_tick);
@@ -126,7 +194,9 @@ impl GameTick {
 
         result.events.push(GameEvent::MonthEnd {
             tick: current_tick,
-            income: report.rent_income,
+            income: report.rent_income + report.laundry_income + report.storage_income,
+            laundry_income: report.laundry_income,
+            storage_income: report.storage_income,
             expenses: report.repair_costs + report.upgrade_costs,
             balance: report.ending_balance,
         });
@@ -141,6 +211,7 @@ impl GameTick {
             &config.win_conditions,
             &config.happiness,
             &config.thresholds,
+            sandbox_mode,
         );
 
         if let Some(ref outcome) = result.outcome {
@@ -159,13 +230,21 @@ impl GameTick {
 
     fn collect_rent(
         building: &mut Building,
-        tenants: &[Tenant],
+        tenants: &mut [Tenant],
         funds: &mut PlayerFunds,
         current_tick: u32,
         config: &crate::data::config::GameConfig,
         result: &mut TickResult,
     ) {
-        let rent_result = collect_rent(tenants, building, funds, current_tick, &config.tenant_risk);
+        let rent_result = collect_rent(
+            tenants,
+            building,
+            funds,
+            current_tick,
+            &config.tenant_risk,
+            config.economy.month_to_month_premium,
+            config.vetting.max_acceptable_ratio,
+        );
         result.rent_collected = rent_result.total_collected;
 
         for payment in &rent_result.payments {
@@ -175,105 +254,90 @@ impl GameTick {
             });
         }
 
+        for tenant in tenants.iter_mut() {
+            let missed = rent_result
+                .missed_payments
+                .iter()
+                .any(|m| m.tenant_id == tenant.id);
+            if let (false, Some(guarantor)) = (missed, tenant.guarantor.as_mut()) {
+                guarantor.covered_last_month = false;
+            }
+        }
+
         for missed in &rent_result.missed_payments {
+            if let Some(tenant) = tenants.iter_mut().find(|t| t.id == missed.tenant_id) {
+                let on_strike = tenant
+                    .apartment_id
+                    .and_then(|id| building.get_apartment(id))
+                    .is_some_and(|apt| apt.on_rent_strike());
+                // Rent withheld in a strike isn't owed; it's the strike's leverage.
+                if !on_strike {
+                    if crate::tenant::cover_missed_rent(tenant, missed.amount, funds, current_tick)
+                    {
+                        result.rent_collected += missed.amount;
+                        result.events.push(GameEvent::RentPaid {
+                            tenant_name: missed.tenant_name.clone(),
+                            amount: missed.amount,
+                        });
+                        continue;
+                    }
+                    tenant.outstanding_balance += missed.amount;
+                }
+            }
             result.events.push(GameEvent::RentMissed {
                 tenant_name: missed.tenant_name.clone(),
                 amount: missed.amount,
             });
         }
-    }
 
-    fn process_operating_costs(
-        building: &mut Building,
-        funds: &mut PlayerFunds,
-        current_tick: u32,
-        result: &mut TickResult,
-        config: &crate::data::config::GameConfig,
-    ) {
-        // Marketing
-        let marketing_cost = building.marketing_strategy.monthly_cost(&config.marketing);
-        if marketing_cost > 0 {
-            let transaction = Transaction::expense(
-                TransactionType::Marketing,
-                marketing_cost,
-                &format!("{} Marketing Campaign", building.marketing_strategy.name()),
-                current_tick,
-            );
-            if !funds.deduct_expense(transaction) {
-                building.marketing_strategy = crate::building::MarketingType::None;
-                result.events.push(GameEvent::Notification {
-                    message: "Marketing campaign cancelled due to lack of funds.".to_string(),
-                    level: crate::simulation::NotificationLevel::Warning,
-                });
-            }
-        }
-
-        if building.open_house_remaining > 0 {
-            building.open_house_remaining -= 1;
-            if building.open_house_remaining == 0 {
-                result.events.push(GameEvent::Notification {
-                    message: "Open House event has ended.".to_string(),
-                    level: crate::simulation::NotificationLevel::Info,
-                });
+        // Tenants who paid this month work down what they still owe.
+        for tenant in tenants.iter_mut() {
+            if tenant.outstanding_balance <= 0
+                || rent_result
+                    .missed_payments
+                    .iter()
+                    .any(|m| m.tenant_id == tenant.id)
+            {
+                continue;
             }
-        }
-
-        // Fixed monthly overhead (mortgage/upkeep) — always-on structural cost.
-        let overhead = OperatingCosts::calculate_base_overhead(building, &config.operating_costs);
-        if overhead > 0 {
-            funds.apply_required_expense(Transaction::expense(
-                TransactionType::Mortgage,
-                overhead,
-                "Mortgage & Upkeep",
-                current_tick,
-            ));
-        }
-
-        // Taxes & Expenses
-        let tax = OperatingCosts::calculate_property_tax(
-            building,
-            result.rent_collected,
-            &config.operating_costs,
-            current_tick,
-        );
-        if tax > 0 {
-            funds.apply_required_expense(Transaction::expense(
-                TransactionType::PropertyTax,
-                tax,
-                "Monthly Property Tax",
-                current_tick,
-            ));
-        }
-
-        let utilities = OperatingCosts::calculate_utilities(building, &config.operating_costs);
-        if utilities > 0 {
-            funds.apply_required_expense(Transaction::expense(
-                TransactionType::Utilities,
-                utilities,
-                "Utility Bills",
-                current_tick,
-            ));
-        }
-
-        let insurance = OperatingCosts::calculate_insurance(building, &config.operating_costs);
-        if insurance > 0 {
-            funds.apply_required_expense(Transaction::expense(
-                TransactionType::Insurance,
-                insurance,
-                "Property Insurance",
+            let Some(rent) = tenant
+                .apartment_id
+                .and_then(|id| building.get_apartment(id))
+                .filter(|apt| !building.is_unit_sold(apt.id))
+                .map(|apt| apt.rent_price)
+            else {
+                continue;
+            };
+            let installment = (rent * config.economy.arrears_repayment_percent / 100)
+                .max(1)
+                .min(tenant.outstanding_balance);
+            tenant.outstanding_balance -= installment;
+            funds.add_income(Transaction::income(
+                TransactionType::RentIncome,
+                installment,
+                &format!("Back rent from {}", tenant.name),
                 current_tick,
             ));
+            result.rent_collected += installment;
         }
 
-        // Staff Salaries - Data Driven
-        let salaries = OperatingCosts::calculate_staff_salaries(building, &config.economy);
-        if salaries > 0 {
-            funds.apply_required_expense(Transaction::expense(
-                TransactionType::StaffSalary,
-                salaries,
-                "Staff Salaries",
+        // Parking fees, billed separately. Spots held by tenants who have
+        // since moved out are released first.
+        for spot in &mut building.parking {
+            let Some(tenant_id) = spot.assigned_tenant_id else {
+                continue;
+            };
+            if !tenants.iter().any(|t| t.id == tenant_id) {
+                spot.assigned_tenant_id = None;
+                continue;
+            }
+            funds.add_income(Transaction::income(
+                TransactionType::ParkingIncome,
+                spot.monthly_fee,
+                &format!("Parking spot {}", spot.spot_number),
                 current_tick,
             ));
+            result.rent_collected += spot.monthly_fee;
         }
     }
 
@@ -283,6 +347,7 @@ impl GameTick {
     /// the units the janitor can't cover.
     fn process_janitor_maintenance(
         building: &mut Building,
+        monthly_decay: &crate::data::config::DecayConfig,
         result: &mut TickResult,
         config: &crate::data::config::GameConfig,
     ) {
@@ -290,8 +355,8 @@ impl GameTick {
             return;
         }
 
-        let apt_decay = config.decay.apartment_per_tick;
-        let hallway_decay = config.decay.hallway_per_tick;
+        let apt_decay = monthly_decay.apartment_per_tick;
+        let hallway_decay = monthly_decay.hallway_per_tick;
         let units_maintained = config.staff_effects.janitor_units_maintained;
         let apt_cost = config.economy.repair_cost_per_point;
         let hallway_cost = config.economy.hallway_repair_cost_per_point;
@@ -348,6 +413,10 @@ impl GameTick {
                 .clamp(0, 100);
             prob = prob * (100 - reduction) / 100;
         }
+        if building.flags.contains(SECURITY_CAMERAS_FLAG) {
+            let reduction = failure_cfg.security_camera_reduction_percent.clamp(0, 100);
+            prob = prob * (100 - reduction) / 100;
+        }
         let aging_cost = failure_cfg.aging_cost_per_year * years_aged;
 
         // Boiler Failure (prob out of 1000)
@@ -454,11 +523,23 @@ impl GameTick {
         result: &mut TickResult,
         config: &crate::data::config::HappinessConfig,
         staff: &crate::data::config::StaffEffectsConfig,
+        amenities: &HashMap<String, crate::data::config::AmenityConfig>,
+        season: Season,
+        standard_locker_rent: i32,
     ) {
         for tenant in tenants.iter_mut() {
             if let Some(apt_id) = tenant.apartment_id {
                 if let Some(apartment) = building.get_apartment(apt_id) {
-                    let factors = calculate_happiness(tenant, apartment, building, config, staff);
+                    let factors = calculate_happiness(
+                        tenant,
+                        apartment,
+                        building,
+                        config,
+                        staff,
+                        amenities,
+                        season,
+                        standard_locker_rent,
+                    );
                     let old_happiness = tenant.happiness;
                     let new_happiness = factors.total();
                     tenant.set_happiness(new_happiness);
@@ -472,6 +553,7 @@ impl GameTick {
 
                     if factors.noise_factor < -10 {
                         result.events.push(GameEvent::NoiseComplaint {
+                            tenant_id: tenant.id,
                             tenant_name: tenant.name.clone(),
                         });
                     }
@@ -500,7 +582,10 @@ pub fn advance_tick(
     current_tick: &mut u32,
     next_tenant_id: &mut u32,
     has_ever_had_tenant: bool,
+    sandbox_mode: bool,
     reputation_multiplier: f32,
+    school_rating: i32,
+    costs: CostAdjustments,
     config: &crate::data::config::GameConfig,
 ) -> TickResult {
     *current_tick += 1;
@@ -515,7 +600,10 @@ pub fn advance_tick(
         *current_tick,
         next_tenant_id,
         has_ever_had_tenant,
+        sandbox_mode,
         reputation_multiplier,
+        school_rating,
+        costs,
         config,
     )
 }
@@ -532,6 +620,8 @@ mod tests {
             events: Vec::new(),
             rent_collected: 0,
             tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
             new_applications: 0,
             outcome: None,
         }
@@ -548,7 +638,7 @@ mod tests {
 
         building.apply_monthly_decay(3, 1); // every unit -> 47
         let mut result = empty_result();
-        GameTick::process_janitor_maintenance(&mut building, &mut result, &config);
+        GameTick::process_janitor_maintenance(&mut building, &config.decay, &mut result, &config);
 
         // 5 of 6 units are restored to their pre-decay condition; one is not.
         let restored = building
@@ -590,4 +680,32 @@ mod tests {
             .iter()
             .any(|e| matches!(e, GameEvent::TenantDamage { .. })));
     }
+
+    #[test]
+    fn paying_tenants_work_down_unpaid_rent() {
+        let config = GameConfig::default();
+        let mut building = Building::new("Test", 1, 1);
+        building.apartments[0].rent_price = 800;
+        let mut tenant = Tenant::new(1, "Owes", TenantArchetype::Professional);
+        tenant.apartment_id = Some(building.apartments[0].id);
+        tenant.rent_reliability = 100;
+        tenant.happiness = 80;
+        tenant.outstanding_balance = 300;
+        let mut tenants = vec![tenant];
+        let mut funds = PlayerFunds::new(0);
+
+        let mut result = empty_result();
+        GameTick::collect_rent(
+            &mut building,
+            &mut tenants,
+            &mut funds,
+            0,
+            &config,
+            &mut result,
+        );
+
+        let installment = 800 * config.economy.arrears_repayment_percent / 100;
+        assert_eq!(tenants[0].outstanding_balance, 300 - installment);
+        assert_eq!(funds.balance, 800 + installment);
+    }
 }
@@ -16,6 +16,10 @@ pub enum GameOutcome {
         debt: i32,
     },
     AllTenantsLeft,
+    /// A scenario's time limit ran out with objectives still open.
+    ScenarioFailed {
+        score: i32,
+    },
 }
 
 /// Check current game state for win/lose conditions
@@ -28,7 +32,13 @@ pub fn check_win_condition(
     win_conditions: &WinConditions,
     happiness_config: &HappinessConfig,
     thresholds: &ThresholdsConfig,
+    sandbox_mode: bool,
 ) -> Option<GameOutcome> {
+    // Sandbox games never end.
+    if sandbox_mode {
+        return None;
+    }
+
     // Check for bankruptcy
     if funds.is_bankrupt() {
         return Some(GameOutcome::Bankruptcy {
@@ -67,21 +77,12 @@ pub fn check_win_condition(
         } else {
             0
         };
-        // A defensive floor: a run that (via a very low game_duration_ticks)
-        // ends before min_ticks_for_victory still gets an outcome, just
-        // without this small "played it out" bonus.
-        let maturity_bonus = if current_tick >= win_conditions.min_ticks_for_victory {
-            20
-        } else {
-            0
-        };
         let tenant_count_bonus = (tenants.len() as i32) * 10;
 
         let score = (avg_happiness * 5)  // Happiness contribution
             + (funds.total_income / 100)  // Income contribution
             + occupancy_bonus             // Full building bonus
             + happiness_bonus             // Cleared the victory happiness bar
-            + maturity_bonus              // Played out at least the minimum duration
             + tenant_count_bonus; // Tenant retention bonus
 
         return Some(GameOutcome::Victory {
@@ -113,6 +114,7 @@ mod tests {
             &cfg.win_conditions,
             &cfg.happiness,
             &cfg.thresholds,
+            false,
         )
     }
 
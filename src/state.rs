@@ -1,33 +1,76 @@
 //! # State Module
 //!
 //! Manages the global application state machine:
-//! - `GameState`: The top-level enum for game modes (Menu, Gameplay, Results).
+//! - `GameState`: The top-level enum for game modes (Menu, SlotSelect, Gameplay).
 //! - Transitions between these high-level states.
 //! - Specific state structs for each mode.
 
 mod gameplay;
+mod gameplay_abandonment; // Belongings left by tenants who walk out
 mod gameplay_actions; // UI action dispatch and city action handling
+mod gameplay_aging; // Age-specific building events
+mod gameplay_analyst; // Market analyst rent reports
+mod gameplay_applications; // Accepting and rejecting applications, housing vouchers
+mod gameplay_art; // Tenants noticing the hallway art
 mod gameplay_awards; // Tax breaks, annual awards, tenant council
 mod gameplay_effects; // Narrative event effect application
+mod gameplay_evictions; // Tenant evictions and the just-cause law
+mod gameplay_first_refusal; // Tenants' right to buy before a building sale
+mod gameplay_green; // Green building certification
 mod gameplay_inspections; // Building inspections and regulatory fines
+mod gameplay_inspector; // Scheduled inspector visits and report cards
+mod gameplay_insurance; // Insurance claims and yearly premium renewal
+mod gameplay_interviews; // Meeting applicants before they sign
+mod gameplay_journalist; // Press coverage of the player's building
+mod gameplay_landmark; // City landmark designation
+mod gameplay_lease_to_own; // Condo sales, including to long-term tenants
+mod gameplay_leases; // Lease renewal offers and month-to-month conversion
+mod gameplay_license; // Annual landlord license renewal
 mod gameplay_life_events; // Emergent tenant life events
+mod gameplay_load; // Repairing city, compliance and story records after a load
+mod gameplay_mortgages; // Buying buildings on a mortgage and paying the loans
 mod gameplay_narrative_turn; // Monthly narrative, mail, dialogue, requests
 mod gameplay_neighborhood; // Neighborhood reputation and market conditions
+mod gameplay_new_game_plus; // Carry-over bonuses from completed buildings
+mod gameplay_permits; // Building permits for major renovations
+mod gameplay_progress; // Unlocking buildings in the player's campaign progress
+mod gameplay_property_manager; // Hired manager's repairs, leasing and rents
+mod gameplay_rent_freeze; // Emergency rent freezes
+mod gameplay_rent_notice; // Notice periods for large rent increases
+mod gameplay_rent_strike; // Union rent strikes and their demands
+mod gameplay_requests; // Conditional approval of tenant pet requests
+mod gameplay_sandbox; // Sandbox mode funds
+mod gameplay_scenarios; // Scenario mode setup and objectives
+mod gameplay_special_assessment; // Neighborhood improvement bills and challenges
+mod gameplay_squatters; // Squatters in long-vacant units
+mod gameplay_tax_assessment; // Yearly property reassessment and appeals
+mod gameplay_transfer; // Moving tenants between buildings
 mod gameplay_turn; // Monthly turn advancement
+mod gameplay_union; // Tenant union demands and rent strikes
+mod gameplay_units; // Renaming and merging apartment units
+mod gameplay_upgrades; // Reputation, certification and compliance side effects of upgrades
+mod gameplay_utility_rates; // Utility price spikes from extreme weather
 mod gameplay_views; // Drawing functions (draw, draw_building_mode, etc.)
 mod menu;
 pub mod mission_system;
+mod slot_select; // Save slot picker between menu and gameplay
 pub mod tutorial_system; // Tutorial logic // Mission logic
 
 pub use gameplay::GameplayState;
 pub use menu::MenuState;
+pub use slot_select::{NewGameSetup, SlotSelectState};
 
 pub enum GameState {
     Menu(MenuState),
+    SlotSelect(SlotSelectState),
     Gameplay(GameplayState),
 }
 
 pub enum StateTransition {
     ToMenu,
+    /// Open the slot picker; carries the menu picks when starting a new game.
+    ToSlotSelect(Option<NewGameSetup>),
     ToGameplay(GameplayState),
+    /// Load the save in the given slot.
+    ToGameplayFromSlot(u8),
 }
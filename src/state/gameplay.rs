@@ -1,8 +1,8 @@
 use super::StateTransition;
 use crate::assets::AssetManager;
-use crate::building::Building;
-use crate::data::config::GameConfig;
-use crate::economy::{FinancialLedger, PlayerFunds};
+use crate::building::{Building, PropertyManager};
+use crate::data::config::{DifficultyPreset, GameConfig};
+use crate::economy::{ActiveMortgage, FinancialLedger, PlayerFunds};
 use crate::simulation::{ActiveWorldEvent, EventLog, GameOutcome, TickResult};
 use crate::tenant::{Tenant, TenantApplication};
 use crate::ui::layout::HEADER_HEIGHT;
@@ -12,12 +12,14 @@ use macroquad_toolkit::ui::draw_ui_text_ex;
 use std::collections::HashMap;
 
 // Phase 3 imports
-use crate::city::City;
-use crate::consequences::{ComplianceSystem, GentrificationTracker, TenantNetwork};
+use crate::city::{City, MarketAnalyst};
+use crate::consequences::{
+    BuildingInspector, ComplianceSystem, GentrificationTracker, RentStrike, TenantNetwork,
+};
 use crate::narrative::{
-    load_events_config, load_relationship_config, Mailbox, MissionManager, NarrativeEventSystem,
-    NotificationManager, RelationshipEventsConfig, TenantEventsConfig, TenantStory,
-    TutorialManager,
+    load_events_config, load_relationship_config, Journalist, Mailbox, MissionManager,
+    NarrativeEventSystem, NotificationManager, RelationshipEventsConfig, TenantEventsConfig,
+    TenantStory, TutorialManager,
 };
 
 use serde::{Deserialize, Serialize};
@@ -38,15 +40,33 @@ fn default_floating_text_layer() -> FloatingTextLayer {
     layer
 }
 
+fn default_show_minimap() -> bool {
+    true
+}
+
+fn default_cost_multiplier() -> f32 {
+    1.0
+}
+
+fn default_interest_rate() -> f32 {
+    crate::data::config::MortgageConfig::default().base_interest_rate
+}
+
+fn default_market_analyst() -> MarketAnalyst {
+    MarketAnalyst::new(crate::data::config::MarketAnalystConfig::default().available_from_tick)
+}
+
 /// View mode for the gameplay screen
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub enum ViewMode {
     #[default]
     Building, // Current single-building view
-    CityMap,       // City overview with all neighborhoods
-    Market,        // Property acquisition screen
-    Mail,          // Mailbox view
-    CareerSummary, // Phase 5: Endgame result
+    CityMap,             // City overview with all neighborhoods
+    Market,              // Property acquisition screen
+    Mail,                // Mailbox view
+    CareerSummary,       // Phase 5: Endgame result
+    Achievements,        // Trophy case
+    NotificationHistory, // Dismissed notifications log
 }
 
 #[derive(Serialize, Deserialize)]
@@ -81,6 +101,35 @@ pub struct GameplayState {
     // Phase 3: Consequence systems
     pub tenant_network: TenantNetwork,
     pub compliance: ComplianceSystem,
+    #[serde(default)]
+    pub building_inspector: BuildingInspector,
+    #[serde(default = "default_market_analyst")]
+    pub market_analyst: MarketAnalyst,
+    /// Hired manager who handles routine decisions, if any.
+    #[serde(default)]
+    pub property_manager: Option<PropertyManager>,
+    #[serde(default)]
+    pub journalist: Journalist,
+    /// Cumulative yearly inflation on operating costs; 1.0 at the start.
+    #[serde(default = "default_cost_multiplier")]
+    pub inflation_multiplier: f32,
+    /// Utility price spike from a heatwave or storm; 1.0 when rates are normal.
+    #[serde(default = "default_cost_multiplier")]
+    pub utility_rate_multiplier: f32,
+    /// Month the utility rate spike ends and rates go back to normal.
+    #[serde(default)]
+    pub utility_rate_restore_tick: Option<u32>,
+    /// Renewal year the last insurance claim counted against (0 if none),
+    /// and claims filed since the last renewal.
+    #[serde(default)]
+    pub last_claim_year: u32,
+    #[serde(default)]
+    pub claims_this_year: u32,
+    #[serde(default)]
+    pub active_mortgages: Vec<ActiveMortgage>,
+    /// Market rate the variable-rate mortgages were last priced at.
+    #[serde(default = "default_interest_rate")]
+    pub current_interest_rate: f32,
     pub gentrification: GentrificationTracker,
 
     // Phase 3: Narrative systems
@@ -104,6 +153,16 @@ pub struct GameplayState {
 
     // Phase 5: Achievements
     pub achievements: crate::narrative::AchievementSystem,
+    #[serde(default)]
+    pub lifetime_stats: crate::simulation::LifetimeStats,
+    /// Set when playing a scenario rather than the campaign.
+    #[serde(default)]
+    pub scenario: Option<crate::narrative::ActiveScenario>,
+    /// Free play: no win or loss conditions.
+    #[serde(default)]
+    pub sandbox_mode: bool,
+    #[serde(default)]
+    pub sandbox_infinite_funds: bool,
 
     // UI state - skipped from serialization
     #[serde(skip)]
@@ -114,16 +173,48 @@ pub struct GameplayState {
     pub pending_actions: Vec<UiAction>,
     #[serde(skip, default = "default_floating_text_layer")]
     pub floating_texts: FloatingTextLayer,
+    /// Centered feedback text raised by game logic, placed on screen at the
+    /// next frame so the simulation never needs a window.
+    #[serde(skip)]
+    pub pending_center_texts: Vec<(String, Vec2, Color)>,
     #[serde(skip, default = "default_panel_tween")]
     pub panel_tween: Tween,
     #[serde(skip)]
     pub panel_scroll_offset: f32,
     #[serde(skip)]
+    pub selected_achievement: Option<usize>,
+    #[serde(skip)]
+    pub show_relationship_history: bool,
+    #[serde(skip)]
+    pub sort_applications_by_score: bool,
+    /// Unit whose number is being typed into `text_input_buffer`.
+    #[serde(skip)]
+    pub renaming_unit: Option<u32>,
+    #[serde(skip)]
+    pub text_input_buffer: String,
+    #[serde(skip)]
     pub show_pause_menu: bool,
     #[serde(skip)]
     pub is_fullscreen: bool,
     #[serde(skip)]
     pub pending_quit_to_menu: bool,
+    #[serde(skip)]
+    pub market_filter: crate::city::MarketFilter,
+    #[serde(skip)]
+    pub config_watcher: crate::data::config::ConfigWatcher,
+    /// Save slot this game autosaves into; set on new game or load.
+    #[serde(skip)]
+    pub save_slot: u8,
+    /// Whether the corner city mini-map is shown in the building view.
+    #[serde(default = "default_show_minimap")]
+    pub show_minimap: bool,
+
+    /// Player-chosen difficulty preset for this run.
+    #[serde(default)]
+    pub difficulty: DifficultyPreset,
+    /// Bonuses carried over from a completed building, if this is a New Game+ run.
+    #[serde(default)]
+    pub new_game_plus: Option<crate::save::NewGamePlusModifiers>,
 
     /// Current building template ID (for unlock tracking)
     #[serde(default)]
@@ -141,6 +232,23 @@ pub struct GameplayState {
     #[serde(default)]
     pub council_formed: bool,
 
+    /// The building whose council has organized into a union. It stays (and
+    /// keeps sending monthly demands) until that council disbands.
+    #[serde(default)]
+    pub tenant_union: Option<u32>,
+
+    /// Union demands ignored in a row; two trigger a rent strike.
+    #[serde(default)]
+    pub union_demands_ignored: u32,
+
+    /// The rent strike under way, if any.
+    #[serde(default)]
+    pub rent_strike: Option<RentStrike>,
+
+    /// Rent increases are blocked until this tick by a city rent freeze.
+    #[serde(default)]
+    pub rent_freeze_until_tick: Option<u32>,
+
     /// The run's RNG seed, recorded so a run can be reproduced (bug reports,
     /// daily challenges) and re-applied on load so reloading doesn't reroll
     /// outcomes.
@@ -150,7 +258,7 @@ pub struct GameplayState {
 
 /// Pick a fresh run seed from wall-clock time. Uses macroquad's date source so
 /// it works on both native and wasm (unlike `std::time`, which panics on wasm).
-fn generate_run_seed() -> u64 {
+pub(super) fn generate_run_seed() -> u64 {
     let now = macroquad::miniquad::date::now();
     ((now * 1_000_000.0) as u64) ^ 0x9E37_79B9_7F4A_7C15
 }
@@ -162,9 +270,9 @@ impl GameplayState {
         let config = crate::data::config::load_config();
         let template = crate::data::templates::load_templates()
             .and_then(|templates| templates.templates.into_iter().next())
-            .unwrap_or_else(default_starter_template);
+            .unwrap_or_else(crate::data::templates::BuildingTemplate::starter);
 
-        Self::new_with_template(config, template)
+        Self::new_with_template(config, template, DifficultyPreset::Normal)
     }
 
     /// Create a new game with a specific building template, choosing a fresh
@@ -174,8 +282,26 @@ impl GameplayState {
     pub fn new_with_template(
         config: GameConfig,
         template: crate::data::templates::BuildingTemplate,
+        difficulty: DifficultyPreset,
+    ) -> Self {
+        Self::new_with_chosen_seed(config, template, difficulty, None)
+    }
+
+    /// As `new_with_template`, but with the player's seed when they typed one
+    /// on the menu.
+    pub fn new_with_chosen_seed(
+        config: GameConfig,
+        template: crate::data::templates::BuildingTemplate,
+        difficulty: DifficultyPreset,
+        seed: Option<u64>,
     ) -> Self {
-        Self::new_with_template_seed(config, template, generate_run_seed())
+        let modifiers = Self::take_new_game_plus(&template.id);
+        let seed = seed.unwrap_or_else(generate_run_seed);
+        let mut state = Self::new_with_template_seed(config, template, difficulty, seed);
+        if let Some(modifiers) = modifiers {
+            state.apply_new_game_plus(modifiers);
+        }
+        state
     }
 
     /// Create a new game with a specific building template and an explicit run
@@ -185,6 +311,7 @@ impl GameplayState {
     pub fn new_with_template_seed(
         mut config: GameConfig,
         template: crate::data::templates::BuildingTemplate,
+        difficulty: DifficultyPreset,
         seed: u64,
     ) -> Self {
         use crate::building::Building;
@@ -196,7 +323,9 @@ impl GameplayState {
         // Apply the tier's rule modifiers (fines, inspections, problem tenants,
         // overhead) and derive its starting funds — this is what makes the three
         // property tiers genuinely different games, not just different sizes.
-        let starting_funds = config.apply_difficulty(&template.difficulty);
+        let starting_funds =
+            difficulty.starting_funds(config.apply_difficulty(&template.difficulty));
+        config.apply_preset(difficulty);
 
         // Create building from template
         let building = Building::from_template(&template);
@@ -204,7 +333,7 @@ impl GameplayState {
 
         // Place the building in its campaign neighborhood (falls back to a bare
         // slot if that neighborhood is full/missing).
-        let mut city = City::new("Metropolis");
+        let mut city = City::new("Metropolis", &config.rivals);
         let neighborhood_id = template.neighborhood_id;
         let starter_building_index = city
             .add_building(building.clone(), neighborhood_id)
@@ -228,6 +357,9 @@ impl GameplayState {
         let mut compliance = ComplianceSystem::new();
         compliance.init_building_regulations(starter_building_index, is_historic);
 
+        let first_inspection = config.inspector.visit_interval;
+        let analyst_start = config.market_analyst.available_from_tick;
+        let interest_rate = config.mortgage.rate_for(city.economy_health);
         let mut state = Self {
             city,
             building,
@@ -245,6 +377,17 @@ impl GameplayState {
 
             tenant_network: TenantNetwork::new(),
             compliance,
+            building_inspector: BuildingInspector::new(first_inspection),
+            market_analyst: MarketAnalyst::new(analyst_start),
+            property_manager: None,
+            journalist: Journalist::default(),
+            inflation_multiplier: 1.0,
+            utility_rate_multiplier: 1.0,
+            utility_rate_restore_tick: None,
+            last_claim_year: 0,
+            claims_this_year: 0,
+            active_mortgages: Vec::new(),
+            current_interest_rate: interest_rate,
             gentrification: GentrificationTracker::new(),
             narrative_events: NarrativeEventSystem::new(),
             mailbox: Mailbox::new(),
@@ -258,19 +401,39 @@ impl GameplayState {
             active_tax_breaks: Vec::new(),
             notifications: NotificationManager::new(),
             achievements: crate::narrative::AchievementSystem::new(),
+            lifetime_stats: crate::simulation::LifetimeStats::default(),
+            scenario: None,
+            sandbox_mode: false,
+            sandbox_infinite_funds: false,
 
             view_mode: ViewMode::Building,
             selection: Selection::None,
             pending_actions: Vec::new(),
             floating_texts: default_floating_text_layer(),
+            pending_center_texts: Vec::new(),
             panel_tween: default_panel_tween(),
             panel_scroll_offset: 0.0,
+            selected_achievement: None,
+            show_relationship_history: false,
+            sort_applications_by_score: false,
+            renaming_unit: None,
+            text_input_buffer: String::new(),
             show_pause_menu: false,
             is_fullscreen: false,
             pending_quit_to_menu: false,
+            market_filter: crate::city::MarketFilter::default(),
+            config_watcher: crate::data::config::ConfigWatcher::default(),
+            save_slot: 0,
+            show_minimap: true,
+            new_game_plus: None,
+            difficulty,
             current_building_id: building_id,
             has_ever_had_tenant: false,
             council_formed: false,
+            tenant_union: None,
+            union_demands_ignored: 0,
+            rent_strike: None,
+            rent_freeze_until_tick: None,
             seed,
         };
 
@@ -288,6 +451,10 @@ impl GameplayState {
 
                     let mut tenant = Tenant::new(tenant_id, &data.name, archetype);
                     tenant.move_into(apt.id);
+                    tenant.sign_lease(
+                        0,
+                        state.config.matching.lease_defaults.lease_duration_months,
+                    );
                     apt.move_in(tenant_id);
 
                     let story = TenantStory::generate(tenant_id, &tenant.archetype);
@@ -306,12 +473,14 @@ impl GameplayState {
         }
 
         // Generate initial applications (neutral reputation at game start).
+        let school_rating = state.active_neighborhood_school_rating();
         state.applications = crate::tenant::generate_applications(
             &state.building,
             &[],
             0,
             &mut state.next_tenant_id,
             1.0,
+            school_rating,
             &state.config,
         );
 
@@ -320,11 +489,11 @@ impl GameplayState {
         state
     }
 
-    /// Restore fields that are intentionally skipped from save data.
-    pub fn post_load(&mut self) {
-        self.config = crate::data::config::load_config();
-        // config isn't serialized, so re-apply the building's difficulty
-        // modifiers that were baked in at new-game time.
+    /// Install a freshly loaded config. config isn't serialized, so re-apply
+    /// the building's difficulty modifiers and the player's preset that were
+    /// baked in at new-game time.
+    fn apply_config(&mut self, config: GameConfig) {
+        self.config = config;
         if let Some(templates) = crate::data::templates::load_templates() {
             if let Some(template) = templates
                 .templates
@@ -334,6 +503,12 @@ impl GameplayState {
                 self.config.apply_difficulty(&template.difficulty);
             }
         }
+        self.config.apply_preset(self.difficulty);
+    }
+
+    /// Restore fields that are intentionally skipped from save data.
+    pub fn post_load(&mut self) {
+        self.apply_config(crate::data::config::load_config());
         // Re-seed the shared RNG from the saved run seed so reloading a save
         // doesn't let the player reroll future random outcomes.
         macroquad_toolkit::rng::srand(self.seed);
@@ -343,6 +518,7 @@ impl GameplayState {
         self.selection = Selection::None;
         self.pending_actions.clear();
         self.floating_texts = default_floating_text_layer();
+        self.pending_center_texts.clear();
         self.panel_tween = default_panel_tween();
         self.panel_scroll_offset = 0.0;
         self.show_pause_menu = false;
@@ -363,79 +539,6 @@ impl GameplayState {
         }
     }
 
-    fn ensure_city_integrity(&mut self) {
-        if self.city.buildings.is_empty() {
-            self.city.buildings.push(self.building.clone());
-            self.city.active_building_index = 0;
-        }
-
-        if self.city.active_building_index >= self.city.buildings.len() {
-            self.city.active_building_index = 0;
-        }
-
-        for building_id in 0..self.city.buildings.len() as u32 {
-            let already_linked = self
-                .city
-                .neighborhoods
-                .iter()
-                .any(|neighborhood| neighborhood.building_ids.contains(&building_id));
-
-            if already_linked {
-                continue;
-            }
-
-            if let Some(neighborhood) = self
-                .city
-                .neighborhoods
-                .iter_mut()
-                .find(|neighborhood| neighborhood.can_add_building())
-            {
-                neighborhood.add_building(building_id);
-            }
-        }
-
-        self.city.total_buildings_managed = self
-            .city
-            .total_buildings_managed
-            .max(self.city.buildings.len() as u32);
-    }
-
-    fn ensure_compliance_for_buildings(&mut self) {
-        let missing: Vec<(u32, bool)> = (0..self.city.buildings.len() as u32)
-            .filter(|building_id| {
-                !self
-                    .compliance
-                    .building_regulations
-                    .contains_key(building_id)
-            })
-            .map(|building_id| {
-                let is_historic = self
-                    .city
-                    .neighborhood_for_building(building_id as usize)
-                    .is_some_and(|neighborhood| {
-                        matches!(
-                            neighborhood.neighborhood_type,
-                            crate::city::NeighborhoodType::Historic
-                        )
-                    });
-                (building_id, is_historic)
-            })
-            .collect();
-
-        for (building_id, is_historic) in missing {
-            self.compliance
-                .init_building_regulations(building_id, is_historic);
-        }
-    }
-
-    fn ensure_tenant_stories(&mut self) {
-        for tenant in &self.tenants {
-            self.tenant_stories
-                .entry(tenant.id)
-                .or_insert_with(|| TenantStory::generate(tenant.id, &tenant.archetype));
-        }
-    }
-
     /// Save the current `building` state back to the city
     pub fn save_building_to_city(&mut self) {
         if let Some(city_building) = self.city.active_building_mut() {
@@ -450,59 +553,6 @@ impl GameplayState {
         }
     }
 
-    /// Unlock a specific building (by its template `unlock_order`) in the
-    /// persistent player progress — used by `MissionReward::UnlockBuilding`.
-    pub(super) fn unlock_building_by_order(&self, unlock_order: u32) {
-        use crate::data::templates::load_templates;
-        use crate::save::{load_player_progress, save_player_progress};
-
-        let mut progress = load_player_progress();
-        if let Some(templates) = load_templates() {
-            if let Some(template) = templates
-                .templates
-                .iter()
-                .find(|t| t.unlock_order == unlock_order)
-            {
-                progress.unlock_building(&template.id);
-            }
-        }
-        let _ = save_player_progress(&progress);
-    }
-
-    /// Unlock the next building after completing the current one
-    pub fn unlock_next_building(&self) {
-        use crate::data::templates::load_templates;
-        use crate::save::{load_player_progress, save_player_progress};
-
-        let mut progress = load_player_progress();
-
-        // Mark current building as completed
-        progress.mark_completed(&self.current_building_id);
-
-        // Find the next building to unlock based on unlock_order
-        if let Some(templates) = load_templates() {
-            // Find current building's unlock_order
-            let current_order = templates
-                .templates
-                .iter()
-                .find(|t| t.id == self.current_building_id)
-                .map(|t| t.unlock_order)
-                .unwrap_or(0);
-
-            // Find the next building in sequence
-            if let Some(next_template) = templates
-                .templates
-                .iter()
-                .find(|t| t.unlock_order == current_order + 1)
-            {
-                progress.unlock_building(&next_template.id);
-            }
-        }
-
-        // Save progress
-        let _ = save_player_progress(&progress);
-    }
-
     /// Main update function - handles game logic and input
     pub fn update(&mut self, assets: &AssetManager) -> Option<StateTransition> {
         // Ensure assets are loaded before processing
@@ -516,9 +566,19 @@ impl GameplayState {
             self.process_action(action);
         }
 
+        #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
+        if let Some(config) = self.config_watcher.check_and_reload() {
+            self.apply_config(config);
+            self.spawn_center_text("Config reloaded!", 0.0, 0.0, colors::ACCENT());
+        }
+
         let dt = get_frame_time();
 
         // Update floating texts
+        let center = vec2(screen_width() / 2.0, screen_height() / 2.0);
+        for (text, offset, color) in self.pending_center_texts.drain(..) {
+            self.floating_texts.spawn(text, center + offset, color);
+        }
         self.floating_texts.update(dt);
 
         // Dialogue generation happens in end_turn() via gameplay_actions.rs
@@ -538,17 +598,7 @@ impl GameplayState {
         if self.game_outcome.is_some() && self.view_mode != ViewMode::CareerSummary {
             self.view_mode = ViewMode::CareerSummary;
             // Check final achievements immediately
-            let new_unlocks = self.achievements.check_new_unlocks(
-                &self.city,
-                &self.building,
-                &self.tenants,
-                &self.funds,
-                self.current_tick,
-                &self.config,
-            );
-            for id in new_unlocks {
-                self.achievements.unlock(&id);
-            }
+            self.check_final_achievements();
         }
 
         // Update tutorial
@@ -556,14 +606,19 @@ impl GameplayState {
 
         // Tutorial/notification toasts handle their own dismissal in draw().
 
-        // Handle keyboard input for ending turn (Space)
-        if is_key_pressed(KeyCode::Space) && matches!(self.view_mode, ViewMode::Building) {
-            self.end_turn();
-        }
+        if self.renaming_unit.is_some() {
+            // Typing a unit number swallows the keyboard shortcuts.
+            self.update_unit_rename_input();
+        } else {
+            // Handle keyboard input for ending turn (Space)
+            if is_key_pressed(KeyCode::Space) && matches!(self.view_mode, ViewMode::Building) {
+                self.end_turn();
+            }
 
-        // ESC key toggles pause menu
-        if is_key_pressed(KeyCode::Escape) {
-            self.show_pause_menu = !self.show_pause_menu;
+            // ESC key toggles pause menu
+            if is_key_pressed(KeyCode::Escape) {
+                self.show_pause_menu = !self.show_pause_menu;
+            }
         }
 
         // If pause menu is showing, skip regular game input processing but check for quit
@@ -651,7 +706,7 @@ impl GameplayState {
             ViewMode::CityMap => "[Tab] Building View | [M] Mail",
             ViewMode::Market => "[Tab] City Map | [M] Mail",
             ViewMode::Mail => "[Tab] Return | [Esc] Return",
-            ViewMode::CareerSummary => "",
+            ViewMode::CareerSummary | ViewMode::Achievements | ViewMode::NotificationHistory => "",
         };
 
         draw_ui_text_ex(
@@ -668,59 +723,3 @@ impl GameplayState {
         None
     }
 }
-
-#[cfg(test)]
-fn default_starter_template() -> crate::data::templates::BuildingTemplate {
-    use crate::data::templates::{ApartmentTemplate, BuildingTemplate};
-
-    BuildingTemplate {
-        id: "starter".to_string(),
-        name: "Starter Building".to_string(),
-        unlock_order: 0,
-        difficulty: "easy".to_string(),
-        neighborhood_id: 1,
-        description: "A small starter property.".to_string(),
-        floors: 2,
-        units_per_floor: 2,
-        hallway_condition: 60,
-        apartments: vec![
-            ApartmentTemplate {
-                unit_number: "1A".to_string(),
-                floor: 1,
-                size_str: "small".to_string(),
-                base_noise_str: "high".to_string(),
-                initial_condition: 55,
-                initial_design: "bare".to_string(),
-                initial_rent: 600,
-            },
-            ApartmentTemplate {
-                unit_number: "1B".to_string(),
-                floor: 1,
-                size_str: "medium".to_string(),
-                base_noise_str: "low".to_string(),
-                initial_condition: 60,
-                initial_design: "bare".to_string(),
-                initial_rent: 800,
-            },
-            ApartmentTemplate {
-                unit_number: "2A".to_string(),
-                floor: 2,
-                size_str: "small".to_string(),
-                base_noise_str: "low".to_string(),
-                initial_condition: 65,
-                initial_design: "practical".to_string(),
-                initial_rent: 650,
-            },
-            ApartmentTemplate {
-                unit_number: "2B".to_string(),
-                floor: 2,
-                size_str: "medium".to_string(),
-                base_noise_str: "low".to_string(),
-                initial_condition: 65,
-                initial_design: "practical".to_string(),
-                initial_rent: 850,
-            },
-        ],
-        initial_tenant: None,
-    }
-}
@@ -0,0 +1,55 @@
+// Tenants who walk out abruptly. Their emergency contact gets a letter about
+// the belongings left behind, and the landlord decides what to do with them.
+
+use crate::narrative::{belongings_left_behind, MailItem};
+use crate::tenant::Abandonment;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn handle_abandonments(&mut self, abandoned: &[Abandonment]) {
+        for abandonment in abandoned {
+            if let Some(contact) = &abandonment.contact {
+                self.mailbox.receive(MailItem::tenant_abandonment(
+                    0,
+                    self.current_tick,
+                    abandonment,
+                    contact,
+                ));
+            }
+            self.narrative_events.add_event(belongings_left_behind(
+                self.current_tick,
+                &abandonment.tenant_name,
+                &abandonment.unit_number,
+                self.config.economy.belongings_storage_cost,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::EmergencyContact;
+
+    #[test]
+    fn only_tenants_with_a_contact_get_a_letter() {
+        let mut state = GameplayState::new();
+        let mail_before = state.mailbox.items.len();
+        let events_before = state.narrative_events.events.len();
+        let abandonment = |contact| Abandonment {
+            tenant_id: 1,
+            tenant_name: "Gone".to_string(),
+            unit_number: "101".to_string(),
+            contact,
+        };
+
+        state.handle_abandonments(&[
+            abandonment(Some(EmergencyContact::generate())),
+            abandonment(None),
+        ]);
+
+        assert_eq!(state.mailbox.items.len(), mail_before + 1);
+        assert_eq!(state.narrative_events.events.len(), events_before + 2);
+    }
+}
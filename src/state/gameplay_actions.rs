@@ -1,8 +1,7 @@
 //! Game action processing - split from gameplay.rs for maintainability
 
-use crate::city::NeighborhoodType;
-use crate::economy::process_upgrade;
-use crate::narrative::{StoryImpact, TenantStory};
+use crate::economy::{process_upgrade, SOLAR_PANELS_FLAG};
+use crate::narrative::StoryImpact;
 use crate::simulation::GameEvent;
 use crate::ui::{colors, Selection, UiAction};
 use macroquad::prelude::*;
@@ -42,11 +41,7 @@ impl GameplayState {
                     apt.is_listed_for_lease = true;
                     apt.preferred_archetype = preference;
 
-                    self.floating_texts.spawn(
-                        "Listed for Lease",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::POSITIVE(),
-                    );
+                    self.spawn_center_text("Listed for Lease", 0.0, 0.0, colors::POSITIVE());
                 }
             }
 
@@ -55,11 +50,7 @@ impl GameplayState {
                     apt.is_listed_for_lease = false;
                     apt.preferred_archetype = None;
 
-                    self.floating_texts.spawn(
-                        "Property Unlisted",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::TEXT(),
-                    );
+                    self.spawn_center_text("Property Unlisted", 0.0, 0.0, colors::TEXT());
                 }
             }
 
@@ -67,15 +58,32 @@ impl GameplayState {
                 apartment_id,
                 amount,
             } => {
-                if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
+                if amount > 0 && self.rent_freeze_active() {
+                    self.spawn_center_text("Rent freeze active", 0.0, 0.0, colors::NEGATIVE());
+                } else if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
                     apt.rent_price = (apt.rent_price + amount).max(100); // Minimum rent $100
                 }
             }
+            UiAction::AdjustAllRents { building_id, rents } => {
+                self.apply_recommended_rents(building_id, &rents)
+            }
+            UiAction::SetStorageLockerRent(rent) => {
+                self.building.storage_locker_rent = Some(rent.max(0));
+            }
+            UiAction::HirePropertyManager { level } => self.hire_property_manager(level),
+            UiAction::DismissPropertyManager => self.dismiss_property_manager(),
+            UiAction::GrantExclusiveInterview => self.grant_exclusive_interview(),
+            UiAction::ContestTaxAssessment { building_id } => {
+                self.contest_tax_assessment(building_id)
+            }
 
             UiAction::UpgradeAction(upgrade) => {
                 let description =
                     upgrade.label(&self.building, &self.config.ui, &self.config.upgrades);
-                if let Ok(cost) = process_upgrade(
+                let had_solar = self.building.flags.contains(SOLAR_PANELS_FLAG);
+                if self.upgrade_awaiting_permit(&upgrade) {
+                    self.spawn_center_text("Permit required", 0.0, 0.0, colors::NEGATIVE());
+                } else if let Ok(cost) = process_upgrade(
                     &upgrade,
                     &mut self.building,
                     &mut self.funds,
@@ -86,6 +94,9 @@ impl GameplayState {
                         GameEvent::UpgradeCompleted { description, cost },
                         self.current_tick,
                     );
+                    self.after_upgrade_installed(&upgrade, had_solar);
+                    self.use_upgrade_permit(&upgrade);
+                    self.lifetime_stats.record_upgrade();
 
                     let mouse = mouse_position();
                     self.floating_texts.spawn(
@@ -99,108 +110,30 @@ impl GameplayState {
                 apartment_id,
                 new_rent,
             } => {
-                if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
-                    let old_rent = apt.rent_price;
-                    apt.rent_price = new_rent;
-
-                    if old_rent != new_rent {
-                        self.gentrification.record_rent_change(
-                            0,
-                            self.current_tick,
-                            old_rent,
-                            new_rent,
-                            &self.config.gentrification,
-                        );
-                    }
+                let raising = self
+                    .building
+                    .get_apartment(apartment_id)
+                    .is_some_and(|apt| new_rent > apt.rent_price);
+                if raising && self.rent_freeze_active() {
+                    self.spawn_center_text("Rent freeze active", 0.0, 0.0, colors::NEGATIVE());
+                } else {
+                    self.change_rent(apartment_id, new_rent);
                 }
             }
             UiAction::AcceptApplication { application_index } => {
-                if application_index < self.applications.len() {
-                    let app = self.applications.remove(application_index);
-                    let mut tenant = app.tenant;
-
-                    let Some(apt) = self.building.get_apartment(app.apartment_id) else {
-                        return;
-                    };
-
-                    if !apt.is_vacant() {
-                        self.event_log.log(
-                            GameEvent::Notification {
-                                message: "Application could not be accepted because the unit is occupied."
-                                    .to_string(),
-                                level: crate::simulation::NotificationLevel::Warning,
-                            },
-                            self.current_tick,
-                        );
-                        return;
-                    }
-
-                    let apartment_unit = apt.unit_number.clone();
-                    let offer = crate::tenant::matching::LeaseOffer::from_config(
-                        apt.rent_price,
-                        &self.config.matching.lease_defaults,
-                    );
-                    let accept_probability = crate::tenant::matching::evaluate_lease_offer(
-                        &tenant,
-                        &offer,
-                        &self.config.matching.lease_acceptance,
-                    );
-                    let leverage_penalty = tenant.negotiation_leverage() as f32 * 0.002;
-                    let adjusted_accept_probability =
-                        (accept_probability - leverage_penalty).clamp(0.0, 1.0);
-
-                    if rng::gen_range(0.0, 1.0) > adjusted_accept_probability {
-                        self.event_log.log(
-                            GameEvent::Notification {
-                                message: format!(
-                                    "{} declined the lease offer for Unit {}.",
-                                    tenant.name, apartment_unit
-                                ),
-                                level: crate::simulation::NotificationLevel::Info,
-                            },
-                            self.current_tick,
-                        );
-
-                        let mouse = mouse_position();
-                        self.floating_texts.spawn(
-                            "Offer Declined",
-                            vec2(mouse.0, mouse.1 - 20.0),
-                            colors::WARNING(),
-                        );
-                        return;
-                    }
-
-                    tenant.move_into(app.apartment_id);
-
-                    if let Some(apt) = self.building.get_apartment_mut(app.apartment_id) {
-                        apt.move_in(tenant.id);
-                    }
-
-                    self.event_log.log(
-                        GameEvent::TenantMovedIn {
-                            tenant_name: tenant.name.clone(),
-                            apartment_unit,
-                        },
-                        self.current_tick,
-                    );
-
-                    let mouse = mouse_position();
-                    self.floating_texts.spawn(
-                        "Welcome!",
-                        vec2(mouse.0, mouse.1 - 20.0),
-                        colors::POSITIVE(),
-                    );
-
-                    let story = TenantStory::generate(tenant.id, &tenant.archetype);
-                    self.tenant_stories.insert(tenant.id, story);
-
-                    self.tenants.push(tenant);
-                }
+                self.accept_application(application_index, false)
+            }
+            UiAction::AcceptVoucherApplication { application_index } => {
+                self.accept_application(application_index, true)
+            }
+            UiAction::AcceptBestApplication { apartment_id } => {
+                self.accept_best_application(apartment_id)
+            }
+            UiAction::ToggleApplicationSort => {
+                self.sort_applications_by_score = !self.sort_applications_by_score;
             }
             UiAction::RejectApplication { application_index } => {
-                if application_index < self.applications.len() {
-                    self.applications.remove(application_index);
-                }
+                self.reject_application(application_index)
             }
             UiAction::CreditCheck { application_index } => {
                 if application_index < self.applications.len() {
@@ -211,12 +144,17 @@ impl GameplayState {
                         &self.config.vetting,
                         self.current_tick,
                     ) {
-                        self.floating_texts.spawn(
+                        let guarantor = result
+                            .guarantor_credit_score
+                            .map(|score| format!(" (guarantor {})", score))
+                            .unwrap_or_default();
+                        self.spawn_center_text(
                             format!(
-                                "Credit: {} - {}",
-                                result.reliability_score, result.recommendation
+                                "Credit: {}{} - {}",
+                                result.reliability_score, guarantor, result.recommendation
                             ),
-                            vec2(screen_width() / 2.0, screen_height() / 2.0),
+                            0.0,
+                            0.0,
                             if result.reliability_score >= 75 {
                                 colors::POSITIVE()
                             } else if result.reliability_score >= 50 {
@@ -226,9 +164,10 @@ impl GameplayState {
                             },
                         );
                     } else {
-                        self.floating_texts.spawn(
+                        self.spawn_center_text(
                             "Cannot perform credit check",
-                            vec2(screen_width() / 2.0, screen_height() / 2.0),
+                            0.0,
+                            0.0,
                             colors::NEGATIVE(),
                         );
                     }
@@ -243,12 +182,13 @@ impl GameplayState {
                         &self.config.vetting,
                         self.current_tick,
                     ) {
-                        self.floating_texts.spawn(
+                        self.spawn_center_text(
                             format!(
                                 "Background: {} - {}",
                                 result.behavior_score, result.history_notes
                             ),
-                            vec2(screen_width() / 2.0, screen_height() / 2.0),
+                            0.0,
+                            0.0,
                             if result.behavior_score >= 75 {
                                 colors::POSITIVE()
                             } else if result.behavior_score >= 50 {
@@ -258,14 +198,52 @@ impl GameplayState {
                             },
                         );
                     } else {
-                        self.floating_texts.spawn(
+                        self.spawn_center_text(
                             "Cannot perform background check",
-                            vec2(screen_width() / 2.0, screen_height() / 2.0),
+                            0.0,
+                            0.0,
                             colors::NEGATIVE(),
                         );
                     }
                 }
             }
+            UiAction::ReferenceCheck { application_index } => {
+                if application_index < self.applications.len() {
+                    let app = &mut self.applications[application_index];
+                    if let Some(result) = crate::tenant::vetting::perform_reference_check(
+                        app,
+                        &mut self.funds,
+                        &self.config.vetting,
+                        self.current_tick,
+                    ) {
+                        self.spawn_center_text(
+                            format!("Reference: \"{}\"", result.prev_landlord_note),
+                            0.0,
+                            0.0,
+                            if result.cleanliness_rating >= 50 {
+                                colors::POSITIVE()
+                            } else {
+                                colors::NEGATIVE()
+                            },
+                        );
+                    } else {
+                        self.spawn_center_text(
+                            "Cannot perform reference check",
+                            0.0,
+                            0.0,
+                            colors::NEGATIVE(),
+                        );
+                    }
+                }
+            }
+            UiAction::IncomeCheck { application_index } => self.income_check(application_index),
+            UiAction::CollectEmergencyContact { application_index } => {
+                if let Some(app) = self.applications.get_mut(application_index) {
+                    app.tenant
+                        .emergency_contact
+                        .get_or_insert_with(crate::tenant::EmergencyContact::generate);
+                }
+            }
             UiAction::EndTurn => {
                 self.end_turn();
             }
@@ -294,68 +272,51 @@ impl GameplayState {
             UiAction::CloseMail => {
                 self.view_mode = ViewMode::Building;
             }
-
-            // Phase 3: Multi-building
-            UiAction::SwitchBuilding { index } => {
-                self.save_building_to_city();
-                self.city.switch_building(index);
-                self.sync_building();
-                self.selection = Selection::None;
-
-                self.floating_texts.spawn(
-                    "Building Changed",
-                    vec2(screen_width() / 2.0, screen_height() / 2.0),
-                    colors::ACCENT(),
-                );
+            UiAction::OpenAchievements => {
+                self.view_mode = ViewMode::Achievements;
+                self.selected_achievement = None;
             }
-            UiAction::PurchaseBuilding { listing_id } => {
-                if let Some(listing) = self
-                    .city
-                    .market
-                    .listings
-                    .iter()
-                    .find(|l| l.id == listing_id)
-                    .cloned()
-                {
-                    if self.funds.balance >= listing.asking_price {
-                        let building = listing.to_building();
-                        let neighborhood_id = listing.neighborhood_id;
-
-                        if let Ok(building_id) = self.city.add_building(building, neighborhood_id) {
-                            let transaction = crate::economy::Transaction::expense(
-                                crate::economy::TransactionType::BuildingPurchase,
-                                listing.asking_price,
-                                "Building Purchase",
-                                self.current_tick,
-                            );
-                            self.funds.deduct_expense(transaction);
-
-                            let is_historic = self.city.neighborhoods.iter().any(|n| {
-                                n.id == neighborhood_id
-                                    && matches!(n.neighborhood_type, NeighborhoodType::Historic)
-                            });
-                            self.compliance
-                                .init_building_regulations(building_id, is_historic);
-
-                            self.city.market.listings.retain(|l| l.id != listing_id);
-
-                            self.floating_texts.spawn(
-                                "Building Purchased!",
-                                vec2(screen_width() / 2.0, screen_height() / 2.0),
-                                colors::POSITIVE(),
-                            );
-
-                            self.event_log.log(
-                                GameEvent::UpgradeCompleted {
-                                    description: "Purchased new building".to_string(),
-                                    cost: listing.asking_price,
-                                },
-                                self.current_tick,
-                            );
-                        }
-                    }
-                }
+            UiAction::CloseAchievements => {
+                self.view_mode = ViewMode::Building;
+            }
+            UiAction::ToggleSandboxFunds => self.toggle_sandbox_funds(),
+            UiAction::OpenNotificationHistory => {
+                self.view_mode = ViewMode::NotificationHistory;
+                self.notifications.mark_history_read();
+                self.panel_scroll_offset = 0.0;
             }
+            UiAction::CloseNotificationHistory => {
+                self.view_mode = ViewMode::Building;
+                self.panel_scroll_offset = 0.0;
+            }
+            UiAction::InspectAchievement { index } => {
+                self.selected_achievement = index;
+            }
+            UiAction::ToggleMinimap => {
+                self.show_minimap = !self.show_minimap;
+            }
+            UiAction::ToggleRelationshipHistory => {
+                self.show_relationship_history = !self.show_relationship_history;
+            }
+            UiAction::BeginRenameUnit(apartment_id) => self.begin_unit_rename(apartment_id),
+            UiAction::RenameUnit {
+                apartment_id,
+                new_number,
+            } => self.rename_unit(apartment_id, &new_number),
+            UiAction::MergeUnits { apt_a_id, apt_b_id } => self.merge_units(apt_a_id, apt_b_id),
+
+            // Phase 3: Multi-building
+            UiAction::SwitchBuilding { index } => self.switch_to_building(index),
+            UiAction::TransferTenant {
+                tenant_id,
+                target_building_index,
+                target_apartment_id,
+            } => self.transfer_tenant(tenant_id, target_building_index, target_apartment_id),
+            UiAction::PurchaseBuilding { listing_id } => self.purchase_listing(listing_id, None),
+            UiAction::FinanceBuilding {
+                listing_id,
+                financing,
+            } => self.purchase_listing(listing_id, Some(financing)),
 
             // Phase 3: Tenant requests
             UiAction::ApproveRequest { tenant_id } => {
@@ -375,6 +336,20 @@ impl GameplayState {
                     self.apply_story_impact(tenant_id, effect);
                 }
             }
+            UiAction::ApproveRequestWithCondition {
+                tenant_id,
+                condition,
+            } => self.approve_request_with_condition(tenant_id, condition),
+            UiAction::RenewLease {
+                building_id,
+                tenant_id,
+                apartment_id,
+                new_rent,
+            } => self.renew_lease(building_id, tenant_id, apartment_id, new_rent),
+            UiAction::CounterOffer {
+                tenant_id,
+                modified_request,
+            } => self.counter_offer(tenant_id, modified_request),
             UiAction::DenyRequest { tenant_id } => {
                 let effect = self.tenant_stories.get_mut(&tenant_id).and_then(|story| {
                     story.pending_request.take().map(|request| {
@@ -393,57 +368,92 @@ impl GameplayState {
                 }
             }
 
+            UiAction::MediateTenants {
+                tenant_a_id,
+                tenant_b_id,
+            } => {
+                let cost = self.config.economy.mediation_cost;
+                if self.funds.balance < cost {
+                    self.spawn_center_text("Can't afford mediation", 0.0, 0.0, colors::NEGATIVE());
+                } else if self
+                    .tenant_network
+                    .mediate(tenant_a_id, tenant_b_id, self.current_tick)
+                {
+                    self.funds
+                        .deduct_expense(crate::economy::Transaction::expense(
+                            crate::economy::TransactionType::Mediation,
+                            cost,
+                            "Tenant Mediation",
+                            self.current_tick,
+                        ));
+                    self.spawn_center_text("Feud settled", 0.0, 0.0, colors::POSITIVE());
+                }
+            }
+            UiAction::AssignParking { spot_id, tenant_id } => {
+                if self.building.assign_parking(spot_id, tenant_id) {
+                    self.spawn_center_text("Parking assigned", 0.0, 0.0, colors::POSITIVE());
+                }
+            }
+            UiAction::RevokeParking { spot_id } => {
+                self.building.revoke_parking(spot_id);
+            }
+            UiAction::BeginRenovation {
+                building_id,
+                budget,
+            } => {
+                // Only the active building is simulated, so only it can renovate.
+                if building_id as usize != self.city.active_building_index
+                    || self.building.renovation.is_some()
+                {
+                    return;
+                }
+                if !self
+                    .funds
+                    .deduct_expense(crate::economy::Transaction::expense(
+                        crate::economy::TransactionType::UpgradeCost,
+                        budget,
+                        "Building renovation",
+                        self.current_tick,
+                    ))
+                {
+                    self.spawn_center_text("Can't afford renovation", 0.0, 0.0, colors::NEGATIVE());
+                    return;
+                }
+                self.building
+                    .begin_renovation(budget, self.config.economy.renovation_cost_per_unit);
+                self.spawn_center_text("Renovation started", 0.0, 0.0, colors::POSITIVE());
+            }
+
             // Phase 3: Ownership
             UiAction::SelectOwnership => {
                 self.selection = Selection::Ownership;
             }
+            UiAction::SelectMissions => {
+                self.selection = Selection::Missions;
+            }
             UiAction::VoteOnProposal {
                 proposal_index: _index,
                 vote_yes: _vote,
             } => {
-                self.floating_texts.spawn(
-                    "Vote Cast",
-                    vec2(screen_width() / 2.0, screen_height() / 2.0),
-                    colors::ACCENT(),
-                );
+                self.spawn_center_text("Vote Cast", 0.0, 0.0, colors::ACCENT());
             }
-            UiAction::SellUnitAsCondo { apartment_id } => {
-                let market_multiplier = self.condo_sale_market_multiplier();
-                let base_value = self
-                    .building
-                    .get_apartment(apartment_id)
-                    .map(|apt| apt.market_value())
-                    .unwrap_or(10_000);
-                let sale_price = (base_value as f32 * market_multiplier) as i32;
-
-                if let Some(apt) = self.building.get_apartment(apartment_id) {
-                    if let Some(tenant_id) = apt.tenant_id {
-                        self.tenants.retain(|t| t.id != tenant_id);
-                        self.tenant_stories.remove(&tenant_id);
-                    }
-                }
-
-                if self
-                    .building
-                    .convert_unit_to_condo(apartment_id, "New Owner", sale_price)
-                {
-                    let transaction = crate::economy::Transaction::income(
-                        crate::economy::TransactionType::AssetSale,
-                        sale_price,
-                        "Condo Sale",
-                        self.current_tick,
-                    );
-                    self.funds.add_income(transaction);
-
-                    self.floating_texts.spawn(
-                        format!("+${}", sale_price),
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::POSITIVE(),
-                    );
-
-                    self.save_building_to_city();
-                }
+            UiAction::BeginEviction {
+                apartment_id,
+                eviction_reason,
+            } => self.begin_eviction(apartment_id, eviction_reason),
+            UiAction::MeetStrikeDemands { accept_all } => self.answer_strike_demands(accept_all),
+            UiAction::SubmitPermitApplication {
+                upgrade_id,
+                apartment_id,
+                fast_track,
+            } => self.submit_permit_application(&upgrade_id, apartment_id, fast_track),
+            UiAction::OfferPurchaseOption { apartment_id } => {
+                self.offer_purchase_option(apartment_id)
             }
+            UiAction::SellUnitAsCondo {
+                apartment_id,
+                pay_relocation,
+            } => self.sell_unit_as_condo(apartment_id, pay_relocation),
             UiAction::BuybackCondo { apartment_id } => {
                 if let Some(buyback_cost) = self.building.buyback_condo(apartment_id) {
                     if self.funds.balance >= buyback_cost {
@@ -455,17 +465,14 @@ impl GameplayState {
                         );
                         self.funds.deduct_expense(transaction);
 
-                        self.floating_texts.spawn(
+                        self.spawn_center_text(
                             format!("-${}", buyback_cost),
-                            vec2(screen_width() / 2.0, screen_height() / 2.0),
+                            0.0,
+                            0.0,
                             colors::NEGATIVE(),
                         );
 
-                        self.floating_texts.spawn(
-                            "Unit Repurchased!",
-                            vec2(screen_width() / 2.0, screen_height() / 2.0 + 30.0),
-                            colors::POSITIVE(),
-                        );
+                        self.spawn_center_text("Unit Repurchased!", 0.0, 30.0, colors::POSITIVE());
 
                         self.save_building_to_city();
                     }
@@ -475,6 +482,7 @@ impl GameplayState {
                 dialogue_id,
                 choice_index,
             } => {
+                let interviewed = self.interview_applicant(dialogue_id);
                 if let Some(effects) = self
                     .dialogue_system
                     .resolve_dialogue(dialogue_id, choice_index)
@@ -483,11 +491,10 @@ impl GameplayState {
                         self.apply_dialogue_effect(effect);
                     }
 
-                    self.floating_texts.spawn(
-                        "Dialogue Resolved",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::ACCENT(),
-                    );
+                    self.spawn_center_text("Dialogue Resolved", 0.0, 0.0, colors::ACCENT());
+                    if let Some(applicant_id) = interviewed {
+                        self.finish_interview(applicant_id);
+                    }
                 }
             }
             UiAction::ResolveEventChoice {
@@ -566,10 +573,13 @@ impl GameplayState {
         }
     }
 
-    fn apply_dialogue_effect(&mut self, effect: crate::narrative::dialogue::DialogueEffect) {
+    pub(super) fn apply_dialogue_effect(
+        &mut self,
+        effect: crate::narrative::dialogue::DialogueEffect,
+    ) {
         match effect {
             crate::narrative::dialogue::DialogueEffect::HappinessChange { tenant_id, amount } => {
-                if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+                if let Some(tenant) = self.tenant_or_applicant_mut(tenant_id) {
                     tenant.happiness = (tenant.happiness + amount).clamp(0, 100);
                 }
             }
@@ -589,11 +599,15 @@ impl GameplayState {
                 tenant_b,
                 change,
             } => {
-                self.tenant_network
-                    .apply_relationship_change(tenant_a, tenant_b, change);
+                self.tenant_network.apply_relationship_change(
+                    tenant_a,
+                    tenant_b,
+                    change,
+                    self.current_tick,
+                );
             }
             crate::narrative::dialogue::DialogueEffect::OpinionChange { tenant_id, amount } => {
-                if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+                if let Some(tenant) = self.tenant_or_applicant_mut(tenant_id) {
                     tenant.landlord_opinion = (tenant.landlord_opinion + amount).clamp(-100, 100);
                 }
             }
@@ -627,13 +641,11 @@ impl GameplayState {
                 // Could show neighborhood details
             }
             CityMapAction::SelectBuilding(index) => {
-                self.city.switch_building(index);
-                self.sync_building();
+                self.activate_building(index);
                 // Stay in map view, just update selection
             }
             CityMapAction::EnterBuilding(index) => {
-                self.city.switch_building(index);
-                self.sync_building();
+                self.activate_building(index);
                 self.view_mode = ViewMode::Building;
             }
             CityMapAction::OpenMarket => {
@@ -646,6 +658,15 @@ impl GameplayState {
                 self.pending_actions
                     .push(UiAction::PurchaseBuilding { listing_id });
             }
+            CityMapAction::FinanceBuilding(listing_id, financing) => {
+                self.pending_actions.push(UiAction::FinanceBuilding {
+                    listing_id,
+                    financing,
+                });
+            }
+            CityMapAction::SetMarketFilter(filter) => {
+                self.market_filter = filter;
+            }
         }
     }
 
@@ -0,0 +1,75 @@
+// Age-specific building events: lead paint and structural weakening. Each
+// fires at most once per building, tracked through building flags.
+
+use crate::narrative::{lead_paint_discovery, structural_weakening};
+
+use super::gameplay::GameplayState;
+
+const LEAD_PAINT_FLAG: &str = "lead_paint_discovered";
+const STRUCTURAL_FLAG: &str = "structural_weakening";
+
+impl GameplayState {
+    /// Called after an inspection: an old enough building gives up its lead paint.
+    pub(super) fn check_lead_paint_discovery(&mut self) {
+        let config = &self.config.critical_failures;
+        if self.building.age(self.current_tick) <= config.lead_paint_age
+            || !self.building.flags.insert(LEAD_PAINT_FLAG.to_string())
+        {
+            return;
+        }
+        let event = lead_paint_discovery(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            &self.building.name,
+            config.lead_paint_remediation_cost,
+        );
+        self.narrative_events.add_event(event);
+    }
+
+    /// Monthly check for a building old enough to start weakening.
+    pub(super) fn check_structural_weakening(&mut self) {
+        let config = &self.config.critical_failures;
+        if self.building.age(self.current_tick) <= config.structural_weakening_age
+            || !self.building.flags.insert(STRUCTURAL_FLAG.to_string())
+        {
+            return;
+        }
+        let event = structural_weakening(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            &self.building.name,
+            config.structural_reinforcement_cost,
+        );
+        self.narrative_events.add_event(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structural_weakening_fires_once_for_old_buildings() {
+        let mut state = GameplayState::new();
+        state.building.construction_year = 1950;
+        let before = state.narrative_events.events.len();
+
+        state.check_structural_weakening();
+        state.check_structural_weakening();
+
+        assert_eq!(state.narrative_events.events.len(), before + 1);
+        assert!(state.building.flags.contains(STRUCTURAL_FLAG));
+    }
+
+    #[test]
+    fn young_buildings_skip_aging_events() {
+        let mut state = GameplayState::new();
+        state.building.construction_year = 2020;
+        let before = state.narrative_events.events.len();
+
+        state.check_lead_paint_discovery();
+        state.check_structural_weakening();
+
+        assert_eq!(state.narrative_events.events.len(), before);
+    }
+}
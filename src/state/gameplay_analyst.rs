@@ -0,0 +1,109 @@
+// The market analyst's rent reports, and applying their recommendations.
+
+use crate::city::MarketAnalyst;
+use crate::narrative::MailItem;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Re-price the neighborhoods and, when a report is due, mail the
+    /// analyst's recommendations for the active building.
+    pub(super) fn update_market_analyst(&mut self) {
+        self.market_analyst.update(&self.city.neighborhoods);
+        let interval = self.config.market_analyst.report_interval;
+        if !self.market_analyst.report_due(self.current_tick, interval) {
+            return;
+        }
+        let rent_demand = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+            .map(|neighborhood| neighborhood.stats.rent_demand)
+            .unwrap_or(1.0);
+        let body =
+            self.market_analyst
+                .report(&self.city.neighborhoods, &self.building, rent_demand);
+        let rents = MarketAnalyst::recommended_rents(&self.building, rent_demand);
+        self.mailbox.receive(MailItem::market_analysis(
+            0,
+            self.current_tick,
+            &self.market_analyst.name,
+            body,
+            self.city.active_building_index as u32,
+            rents,
+        ));
+    }
+
+    /// Set each listed unit to its recommended rent. Raises are skipped
+    /// while a rent freeze is on, and still go through the notice law. The
+    /// report only applies while its building is the active one.
+    pub(super) fn apply_recommended_rents(&mut self, building_id: u32, rents: &[(u32, i32)]) {
+        if building_id != self.city.active_building_index as u32 {
+            self.spawn_center_text("Open that building first", 0.0, 0.0, colors::WARNING());
+            return;
+        }
+        let freeze = self.rent_freeze_active();
+        let mut skipped = 0;
+        for &(apartment_id, rent) in rents {
+            let raising = self
+                .building
+                .get_apartment(apartment_id)
+                .is_some_and(|apt| rent > apt.rent_price);
+            if raising && freeze {
+                skipped += 1;
+            } else {
+                self.change_rent(apartment_id, rent);
+            }
+        }
+        if skipped > 0 {
+            self.spawn_center_text("Rent freeze: raises skipped", 0.0, 0.0, colors::WARNING());
+        } else {
+            self.spawn_center_text("Rents set to market", 0.0, 0.0, colors::POSITIVE());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::narrative::MailAction;
+
+    #[test]
+    fn report_offers_rents_that_apply_in_one_click() {
+        let mut state = GameplayState::new();
+        state.current_tick =
+            state.market_analyst.available_since_tick + state.config.market_analyst.report_interval;
+        state.update_market_analyst();
+
+        let mail = state.mailbox.recent(1)[0].clone();
+        assert_eq!(mail.subject, "Market Analysis Report");
+        let Some(MailAction::ApplyRecommendedRents { building_id, rents }) = mail.action else {
+            panic!("report should offer to apply its rents");
+        };
+
+        state.apply_recommended_rents(building_id, &rents);
+        for (apartment_id, rent) in rents {
+            let apt = state.building.get_apartment(apartment_id).unwrap();
+            if apt.tenant_id.is_none() {
+                assert_eq!(apt.rent_price, rent);
+            }
+        }
+    }
+
+    #[test]
+    fn report_is_refused_once_another_building_is_active() {
+        let mut state = GameplayState::new();
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+
+        state.activate_building(1);
+        let apartment_id = state.building.apartments[0].id;
+        let rent = state.building.apartments[0].rent_price;
+        state.apply_recommended_rents(0, &[(apartment_id, rent + 300)]);
+
+        assert_eq!(state.building.apartments[0].rent_price, rent);
+    }
+}
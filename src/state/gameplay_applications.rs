@@ -0,0 +1,322 @@
+// Accepting and rejecting tenant applications, including the city's housing
+// voucher program: voucher holders arrive with part of their rent guaranteed,
+// and while the program runs they can't be turned away. Every decision is
+// logged for the fair housing office.
+
+use crate::consequences::ApplicationDecision;
+use crate::narrative::{fair_housing_investigation, NarrativeEvent, TenantStory};
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+use macroquad::prelude::*;
+use macroquad_toolkit::rng;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Accept the top-ranked application for a unit, taking the voucher
+    /// path when the winner holds one.
+    pub(super) fn accept_best_application(&mut self, apartment_id: u32) {
+        let Some(index) = crate::tenant::best_application(
+            &self.applications,
+            &self.building,
+            apartment_id,
+            &self.config,
+        ) else {
+            return;
+        };
+        let voucher = self.applications[index].has_housing_voucher();
+        self.accept_application(index, voucher);
+    }
+
+    /// Move an applicant in. `voucher` marks the voucher-specific accept: the
+    /// city has already approved the lease, so there's no offer to decline.
+    pub(super) fn accept_application(&mut self, application_index: usize, voucher: bool) {
+        if application_index >= self.applications.len() {
+            return;
+        }
+        if voucher && !self.applications[application_index].has_housing_voucher() {
+            return;
+        }
+        if self.compliance.license_lapsed {
+            self.spawn_center_text(
+                "License lapsed: no new tenants",
+                0.0,
+                0.0,
+                colors::NEGATIVE(),
+            );
+            return;
+        }
+        if self.start_interview(application_index) {
+            return;
+        }
+        let app = self.applications.remove(application_index);
+        let mut tenant = app.tenant;
+        tenant.is_fraudulent = app.fraud_risk;
+
+        let Some(apt) = self.building.get_apartment(app.apartment_id) else {
+            return;
+        };
+
+        if !apt.is_vacant() {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: "Application could not be accepted because the unit is occupied."
+                        .to_string(),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+            self.tenant_stories.remove(&tenant.id);
+            return;
+        }
+
+        let apartment_unit = apt.unit_number.clone();
+        let offer = crate::tenant::matching::LeaseOffer::from_config(
+            apt.rent_price,
+            &self.config.matching.lease_defaults,
+        );
+        let accept_probability = crate::tenant::matching::evaluate_lease_offer(
+            &tenant,
+            &offer,
+            &self.config.happiness,
+            &self.config.matching.lease_acceptance,
+        );
+        let leverage_penalty = tenant.negotiation_leverage() as f32 * 0.002;
+        let adjusted_accept_probability = (accept_probability - leverage_penalty).clamp(0.0, 1.0);
+
+        if !voucher && rng::gen_range(0.0, 1.0) > adjusted_accept_probability {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "{} declined the lease offer for Unit {}.",
+                        tenant.name, apartment_unit
+                    ),
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+
+            let mouse = mouse_position();
+            self.floating_texts.spawn(
+                "Offer Declined",
+                vec2(mouse.0, mouse.1 - 20.0),
+                colors::WARNING(),
+            );
+            self.tenant_stories.remove(&tenant.id);
+            return;
+        }
+
+        // Only a signed lease counts as an acceptance in the fair housing log.
+        self.compliance.log_application_decision(
+            self.current_tick,
+            app.apartment_id,
+            &tenant.archetype,
+            ApplicationDecision::Accepted,
+            &self.config.regulations,
+        );
+        tenant.move_into(app.apartment_id);
+        tenant.sign_lease(self.current_tick, offer.lease_duration_months);
+
+        if let Some(apt) = self.building.get_apartment_mut(app.apartment_id) {
+            apt.move_in(tenant.id);
+        }
+
+        self.event_log.log(
+            GameEvent::TenantMovedIn {
+                tenant_name: tenant.name.clone(),
+                apartment_unit,
+            },
+            self.current_tick,
+        );
+
+        let mouse = mouse_position();
+        self.floating_texts.spawn(
+            "Welcome!",
+            vec2(mouse.0, mouse.1 - 20.0),
+            colors::POSITIVE(),
+        );
+
+        // An interviewed applicant already has the story they told.
+        self.tenant_stories
+            .entry(tenant.id)
+            .or_insert_with(|| TenantStory::generate(tenant.id, &tenant.archetype));
+
+        self.lifetime_stats.record_tenant_housed();
+        self.tenants.push(tenant);
+    }
+
+    pub(super) fn income_check(&mut self, application_index: usize) {
+        let Some(app) = self.applications.get_mut(application_index) else {
+            return;
+        };
+        let rent = self
+            .building
+            .get_apartment(app.apartment_id)
+            .map_or(0, |apt| apt.rent_price);
+        match crate::tenant::vetting::perform_income_verification(
+            app,
+            rent,
+            &mut self.funds,
+            &self.config.vetting,
+            self.current_tick,
+        ) {
+            Some(result) => {
+                let color =
+                    if result.rent_to_income_ratio > self.config.vetting.max_acceptable_ratio {
+                        colors::NEGATIVE()
+                    } else {
+                        colors::POSITIVE()
+                    };
+                self.spawn_center_text(
+                    format!(
+                        "Income: ${}/mo - {}",
+                        result.monthly_income, result.recommendation
+                    ),
+                    0.0,
+                    0.0,
+                    color,
+                );
+            }
+            None => {
+                self.spawn_center_text("Cannot perform income check", 0.0, 0.0, colors::NEGATIVE());
+            }
+        }
+    }
+
+    /// Turning away a qualifying voucher holder while the city program runs
+    /// is source-of-income discrimination.
+    pub(super) fn reject_application(&mut self, application_index: usize) {
+        if application_index >= self.applications.len() {
+            return;
+        }
+        let app = self.applications.remove(application_index);
+        self.tenant_stories.remove(&app.tenant.id);
+        self.compliance.log_application_decision(
+            self.current_tick,
+            app.apartment_id,
+            &app.tenant.archetype,
+            ApplicationDecision::Rejected,
+            &self.config.regulations,
+        );
+        let config = self.config.regulations.clone();
+        if app.match_result.meets_minimum {
+            let fine = self
+                .compliance
+                .cite_fair_housing_violation(&app.tenant.archetype, &config);
+            if fine > 0 {
+                self.event_log.log(
+                    GameEvent::Notification {
+                        message: format!(
+                            "Fair housing violation: {} was qualified. ${} fine.",
+                            app.tenant.name, fine
+                        ),
+                        level: crate::simulation::NotificationLevel::Warning,
+                    },
+                    self.current_tick,
+                );
+            }
+        }
+        if !app.voucher_qualifies() {
+            return;
+        }
+        let fine = self.compliance.cite_voucher_discrimination(&config);
+        if fine == 0 {
+            return;
+        }
+        self.adjust_active_neighborhood_reputation(-config.neighborhood_reputation_penalty);
+        self.event_log.log(
+            GameEvent::Notification {
+                message: format!(
+                    "Voucher discrimination: {} was qualified. ${} fine.",
+                    app.tenant.name, fine
+                ),
+                level: crate::simulation::NotificationLevel::Warning,
+            },
+            self.current_tick,
+        );
+    }
+
+    /// Open a fair housing investigation if recent rejections single out one
+    /// archetype.
+    pub(super) fn check_fair_housing(&mut self) {
+        if self
+            .compliance
+            .fair_housing
+            .fair_housing_required_archetype
+            .is_some()
+        {
+            return;
+        }
+        let Some(archetype) = self
+            .compliance
+            .rejection_pattern(self.current_tick, &self.config.regulations)
+        else {
+            return;
+        };
+        let event = fair_housing_investigation(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            archetype.name(),
+            self.config.regulations.fair_housing_fine,
+        );
+        self.narrative_events.add_event(event);
+        self.compliance.open_fair_housing_investigation(archetype);
+    }
+
+    /// The city may launch its voucher program; once running it stays on.
+    pub(super) fn check_voucher_program(&mut self) {
+        if self.compliance.voucher_program_active
+            || rng::gen_range(0, 100) >= self.config.regulations.voucher_program_chance_percent
+        {
+            return;
+        }
+        self.compliance.voucher_program_active = true;
+        let news = NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "City Launches Housing Voucher Program",
+            &format!(
+                "The city now pays part of the rent for voucher holders. Landlords \
+                 who turn away a qualified voucher applicant face a ${} fine.",
+                self.config.regulations.voucher_discrimination_fine
+            ),
+        );
+        self.narrative_events.add_event(news);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{Tenant, TenantApplication, TenantArchetype};
+
+    fn voucher_application(state: &GameplayState) -> TenantApplication {
+        let mut tenant = Tenant::new(99, "Voucher", TenantArchetype::Family);
+        tenant.has_housing_voucher = true;
+        tenant.voucher_amount = 400;
+        let match_result = crate::tenant::matching::MatchResult {
+            score: 80,
+            meets_minimum: true,
+            reasons: Vec::new(),
+            ..Default::default()
+        };
+        TenantApplication::new(tenant, state.building.apartments[0].id, match_result, 0)
+    }
+
+    #[test]
+    fn rejecting_a_qualified_voucher_holder_is_fined_during_the_program() {
+        let mut state = GameplayState::new();
+        let app = voucher_application(&state);
+        state.applications.push(app.clone());
+        state.reject_application(0);
+        assert_eq!(state.compliance.unpaid_fines, 0);
+
+        state.compliance.voucher_program_active = true;
+        state.applications.push(app);
+        state.reject_application(0);
+        assert_eq!(
+            state.compliance.unpaid_fines,
+            state.config.regulations.voucher_discrimination_fine
+        );
+    }
+}
@@ -0,0 +1,39 @@
+// Hallway art: now and then tenants talk up a well-kept building with art on
+// the walls.
+
+use macroquad_toolkit::rng;
+
+use crate::narrative::events::{NarrativeEffect, NarrativeEventType};
+use crate::narrative::NarrativeEvent;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn check_hallway_art_praise(&mut self) {
+        let config = &self.config.happiness;
+        if !self.building.has_hallway_art()
+            || self.building.hallway_condition <= config.hallway_art_praise_min_hallway
+            || rng::gen_range(0, 100) >= config.hallway_art_praise_chance
+        {
+            return;
+        }
+        let mut event = NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "Tenants Praise the Building's Art",
+            &format!(
+                "Residents of {} have been showing visitors the art in the \
+                 hallway. Word is getting around.",
+                self.building.name
+            ),
+        );
+        event.event_type = NarrativeEventType::BuildingMilestone;
+        let effect = NarrativeEffect::BuildingHappiness {
+            building_id: self.city.active_building_index as u32,
+            change: 2,
+        };
+        event.default_effect = effect.clone();
+        self.narrative_events.add_event(event);
+        self.apply_narrative_effect(&effect);
+    }
+}
@@ -99,9 +99,13 @@ impl GameplayState {
         if forming && !self.council_formed {
             self.council_formed = true;
             self.apply_council_collective_action();
+            self.form_tenant_union();
         } else if !forming {
             // Conditions improved; the council disbands and could re-form later.
             self.council_formed = false;
+            if self.union_building_active() {
+                self.tenant_union = None;
+            }
         }
     }
 
@@ -1,14 +1,20 @@
 //! Narrative effect application for gameplay state.
 
+use crate::consequences::building_id_after_sale;
 use crate::narrative::events::NarrativeEffect;
 use crate::ui::colors;
-use macroquad::prelude::*;
 
 use super::gameplay::{GameplayState, ViewMode};
 
+/// Most appeal press coverage can add or take away.
+const MAX_PRESS_COVERAGE: i32 = 20;
+
 impl GameplayState {
     /// Apply a narrative effect to the current gameplay state.
     pub(super) fn apply_narrative_effect(&mut self, effect: &NarrativeEffect) {
+        if self.claim_first_refusal(effect) {
+            return;
+        }
         match effect {
             NarrativeEffect::None => {}
             NarrativeEffect::Money { amount } => {
@@ -44,8 +50,12 @@ impl GameplayState {
                 tenant_b_id,
                 change,
             } => {
-                self.tenant_network
-                    .apply_relationship_change(*tenant_a_id, *tenant_b_id, *change);
+                self.tenant_network.apply_relationship_change(
+                    *tenant_a_id,
+                    *tenant_b_id,
+                    *change,
+                    self.current_tick,
+                );
             }
             NarrativeEffect::MoveOut { tenant_id } => {
                 if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == *tenant_id) {
@@ -55,6 +65,38 @@ impl GameplayState {
             NarrativeEffect::SellBuilding { building_id } => {
                 self.sell_building_from_event(*building_id);
             }
+            NarrativeEffect::RecognizeTenantUnion => self.recognize_tenant_union(),
+            NarrativeEffect::RaiseAllRents { percent } => self.raise_all_rents(*percent),
+            NarrativeEffect::SellToTenant {
+                building_id,
+                tenant_id,
+                price,
+            } => self.sell_to_tenant(*building_id, *tenant_id, *price),
+            NarrativeEffect::ContestFirstRefusal { building_id, price } => {
+                self.contest_first_refusal(*building_id, *price);
+            }
+            NarrativeEffect::UtilityRateChange {
+                multiplier,
+                duration_ticks,
+            } => self.start_utility_rate_spike(*multiplier, *duration_ticks),
+            NarrativeEffect::SpecialAssessmentResponse {
+                neighborhood_id,
+                amount,
+                response,
+            } => self.respond_to_special_assessment(*neighborhood_id, *amount, *response),
+            NarrativeEffect::GentrificationChange { change } => {
+                self.adjust_gentrification(*change);
+            }
+            NarrativeEffect::LandmarkDesignation {
+                building_id,
+                accepted,
+            } => self.answer_landmark_proposal(*building_id, *accepted),
+            NarrativeEffect::TenantBuyoutVote { building_id, offer } => {
+                self.hold_buyout_vote(*building_id, *offer);
+            }
+            NarrativeEffect::UnionDemandAnswered { met } => {
+                self.record_union_demand_answer(*met);
+            }
             NarrativeEffect::Multiple { effects } => {
                 for effect in effects {
                     self.apply_narrative_effect(effect);
@@ -74,12 +116,10 @@ impl GameplayState {
                 }
             }
             NarrativeEffect::BuildingHappiness {
-                building_id: _,
+                building_id,
                 change,
             } => {
-                // Only the active building is simulated at a time, so a
-                // building-wide morale swing applies to its current tenants.
-                for tenant in &mut self.tenants {
+                for tenant in self.tenants_at_mut(*building_id as usize) {
                     tenant.happiness = (tenant.happiness + change).clamp(0, 100);
                 }
             }
@@ -103,12 +143,47 @@ impl GameplayState {
                         (neighborhood.stats.rent_demand + change).clamp(0.5, 2.0);
                 }
             }
+            NarrativeEffect::TransitImprovement {
+                neighborhood_id,
+                change,
+            } => {
+                if let Some(neighborhood) = self
+                    .city
+                    .neighborhoods
+                    .iter_mut()
+                    .find(|n| n.id == *neighborhood_id)
+                {
+                    neighborhood.stats.transit_access =
+                        (neighborhood.stats.transit_access + change).clamp(0, 100);
+                }
+            }
+            NarrativeEffect::SchoolRating {
+                neighborhood_id,
+                change,
+            } => {
+                if let Some(neighborhood) = self
+                    .city
+                    .neighborhoods
+                    .iter_mut()
+                    .find(|n| n.id == *neighborhood_id)
+                {
+                    neighborhood.stats.school_rating =
+                        (neighborhood.stats.school_rating + change).clamp(0, 100);
+                }
+            }
             NarrativeEffect::TriggerInspection { building_id: _ } => {
                 // A complaint-driven inspection of the active building, billed
                 // immediately (outside the monthly billing pass).
                 self.execute_inspection(crate::consequences::InspectionTrigger::TenantComplaint);
                 self.bill_outstanding_fines();
             }
+            NarrativeEffect::BuildingAppealChange {
+                building_id: _,
+                change,
+            } => {
+                self.building.press_coverage = (self.building.press_coverage + change)
+                    .clamp(-MAX_PRESS_COVERAGE, MAX_PRESS_COVERAGE);
+            }
             NarrativeEffect::PropertyValue {
                 building_id: _,
                 change_percent,
@@ -123,20 +198,31 @@ impl GameplayState {
         }
     }
 
-    fn sell_building_from_event(&mut self, building_id: u32) {
+    pub(super) fn sell_building_from_event(&mut self, building_id: u32) {
         let index = building_id as usize;
 
+        let sold_active = index == self.city.active_building_index;
         if index < self.city.buildings.len() {
             self.city.buildings.remove(index);
 
+            // Everything keyed by building index forgets the sold building
+            // and follows the later ones down a place.
             for neighborhood in &mut self.city.neighborhoods {
-                neighborhood.building_ids.retain(|&id| id != building_id);
-                for id in &mut neighborhood.building_ids {
-                    if *id > building_id {
-                        *id -= 1;
-                    }
-                }
+                neighborhood.building_ids = neighborhood
+                    .building_ids
+                    .iter()
+                    .filter_map(|&id| building_id_after_sale(id, building_id))
+                    .collect();
             }
+            self.compliance.forget_building(building_id);
+            self.mailbox.forget_building(building_id);
+            self.tenant_union = self
+                .tenant_union
+                .and_then(|id| building_id_after_sale(id, building_id));
+            self.rent_strike = self.rent_strike.take().and_then(|mut strike| {
+                strike.building_id = building_id_after_sale(strike.building_id, building_id)?;
+                Some(strike)
+            });
         }
 
         if self.city.buildings.is_empty() {
@@ -147,13 +233,16 @@ impl GameplayState {
             });
             self.view_mode = ViewMode::CareerSummary;
         } else {
-            self.city.active_building_index = 0;
-            self.sync_building();
-            self.floating_texts.spawn(
-                "Building Sold!",
-                vec2(screen_width() / 2.0, screen_height() / 2.0),
-                colors::POSITIVE(),
-            );
+            if sold_active {
+                // The sold building's tenants go with it.
+                self.city.active_building_index = 0;
+                self.sync_building();
+                self.tenants = std::mem::take(&mut self.building.resident_tenants);
+                self.save_building_to_city();
+            } else if index < self.city.active_building_index {
+                self.city.active_building_index -= 1;
+            }
+            self.spawn_center_text("Building Sold!", 0.0, 0.0, colors::POSITIVE());
         }
     }
 }
@@ -162,6 +251,7 @@ impl GameplayState {
 mod tests {
     use super::*;
     use crate::state::GameplayState;
+    use crate::tenant::{Tenant, TenantArchetype};
 
     #[test]
     fn neighborhood_reputation_effect_moves_reputation() {
@@ -200,6 +290,18 @@ mod tests {
         assert!(state.city.neighborhoods[0].stats.rent_demand > before);
     }
 
+    #[test]
+    fn transit_improvement_effect_raises_transit_access() {
+        let mut state = GameplayState::new();
+        let nid = state.city.neighborhoods[0].id;
+        state.city.neighborhoods[0].stats.transit_access = 50;
+        state.apply_narrative_effect(&NarrativeEffect::TransitImprovement {
+            neighborhood_id: nid,
+            change: 15,
+        });
+        assert_eq!(state.city.neighborhoods[0].stats.transit_access, 65);
+    }
+
     #[test]
     fn property_value_effect_scales_rent_ceiling() {
         let mut state = GameplayState::new();
@@ -227,6 +329,27 @@ mod tests {
         assert!(state.tenants.iter().all(|t| t.happiness == 45));
     }
 
+    #[test]
+    fn building_happiness_effect_reaches_a_stored_building() {
+        let mut state = GameplayState::new();
+        let mut tenant = Tenant::new(900, "Elsewhere", TenantArchetype::Family);
+        tenant.happiness = 50;
+        state.tenants = vec![tenant];
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+
+        state.apply_narrative_effect(&NarrativeEffect::BuildingHappiness {
+            building_id: 0,
+            change: -5,
+        });
+
+        assert_eq!(state.city.buildings[0].resident_tenants[0].happiness, 45);
+    }
+
     #[test]
     fn reputation_change_moves_active_neighborhood() {
         // Use the non-UI mutation helper: apply_reputation_change also pushes
@@ -242,14 +365,24 @@ mod tests {
 
     #[test]
     fn same_seed_reproduces_initial_state() {
-        use crate::data::config::load_config;
+        use crate::data::config::{load_config, DifficultyPreset};
         use crate::data::templates::load_templates;
 
         let Some(template) = load_templates().and_then(|t| t.templates.into_iter().next()) else {
             return;
         };
-        let a = GameplayState::new_with_template_seed(load_config(), template.clone(), 777);
-        let b = GameplayState::new_with_template_seed(load_config(), template, 777);
+        let a = GameplayState::new_with_template_seed(
+            load_config(),
+            template.clone(),
+            DifficultyPreset::Normal,
+            777,
+        );
+        let b = GameplayState::new_with_template_seed(
+            load_config(),
+            template,
+            DifficultyPreset::Normal,
+            777,
+        );
 
         assert_eq!(a.seed, 777);
         assert_eq!(a.next_tenant_id, b.next_tenant_id);
@@ -289,7 +422,7 @@ mod tests {
 
     #[test]
     fn historic_building_carries_extra_preservation_regulation() {
-        use crate::data::config::load_config;
+        use crate::data::config::{load_config, DifficultyPreset};
         use crate::data::templates::load_templates;
 
         let templates = load_templates().map(|t| t.templates).unwrap_or_default();
@@ -299,8 +432,10 @@ mod tests {
             return;
         };
 
-        let hstate = GameplayState::new_with_template(load_config(), historic);
-        let pstate = GameplayState::new_with_template(load_config(), plain);
+        let hstate =
+            GameplayState::new_with_template(load_config(), historic, DifficultyPreset::Normal);
+        let pstate =
+            GameplayState::new_with_template(load_config(), plain, DifficultyPreset::Normal);
         let reg_count = |s: &GameplayState| {
             s.compliance
                 .building_regulations
@@ -342,4 +477,29 @@ mod tests {
         assert!(low < 1.0, "poor reputation should suppress applicants");
         assert!(high > 1.0, "strong reputation should draw applicants");
     }
+
+    #[test]
+    fn selling_a_building_moves_later_buildings_records_down() {
+        let mut state = GameplayState::new();
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+        state.tenant_union = Some(1);
+        state.rent_strike = Some(crate::consequences::RentStrike::against(
+            &state.building,
+            1,
+            &state.config.gentrification,
+        ));
+        state.compliance.fire_safety_scores.insert(1, 55);
+
+        state.sell_building_from_event(0);
+
+        assert_eq!(state.city.active_building_index, 0);
+        assert_eq!(state.tenant_union, Some(0));
+        assert_eq!(state.rent_strike.as_ref().map(|s| s.building_id), Some(0));
+        assert_eq!(state.compliance.fire_safety_scores.get(&0), Some(&55));
+    }
 }
@@ -0,0 +1,222 @@
+// Evicting paying tenants. Any documented reason works until the city passes
+// a just-cause law; after that the reason has to hold up, or the eviction is
+// blocked and fined. Displaced tenants are owed relocation assistance, which
+// a later city law makes mandatory.
+
+use crate::consequences::EvictionReason;
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::NarrativeEvent;
+use crate::simulation::GameEvent;
+use macroquad_toolkit::rng;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// The city may pass a just-cause eviction law; once passed it stays.
+    pub(super) fn check_just_cause_law(&mut self) {
+        if self.compliance.just_cause_eviction_active
+            || rng::gen_range(0, 100) >= self.config.regulations.just_cause_chance_percent
+        {
+            return;
+        }
+        self.compliance.just_cause_eviction_active = true;
+        self.narrative_events.add_event(NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "City Passes Just-Cause Eviction Law",
+            &format!(
+                "Landlords now need a documented reason to evict. Filing without \
+                 one is blocked and fined ${}.",
+                self.config.regulations.just_cause_violation_fine
+            ),
+        ));
+    }
+
+    /// The city may make relocation assistance mandatory; once passed it stays.
+    pub(super) fn check_relocation_law(&mut self) {
+        if self.compliance.relocation_assistance_required
+            || rng::gen_range(0, 100) >= self.config.regulations.relocation_law_chance_percent
+        {
+            return;
+        }
+        self.compliance.relocation_assistance_required = true;
+        self.narrative_events.add_event(NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "City Requires Relocation Assistance",
+            &format!(
+                "Tenants displaced by eviction or condo conversion are now owed {} \
+                 months of rent. Skipping it draws a ${} fine.",
+                self.config.economy.relocation_assistance_months,
+                self.config.regulations.relocation_bypass_fine
+            ),
+        ));
+    }
+
+    /// Pay (or skip) relocation assistance for the tenant about to be
+    /// displaced from `apartment_id`. Skipping is fined under the law.
+    pub(super) fn settle_relocation(&mut self, apartment_id: u32, pay: bool) -> Result<(), String> {
+        let Some(apt) = self.building.get_apartment(apartment_id) else {
+            return Ok(());
+        };
+        let cost = apt.relocation_assistance(self.config.economy.relocation_assistance_months);
+        if cost == 0 {
+            return Ok(());
+        }
+        if pay {
+            let unit = apt.unit_number.clone();
+            if !self.funds.deduct_expense(Transaction::expense(
+                TransactionType::RelocationAssistance,
+                cost,
+                &format!("Relocation assistance (Unit {})", unit),
+                self.current_tick,
+            )) {
+                return Err(format!("Need ${} for relocation aid", cost));
+            }
+            return Ok(());
+        }
+        let config = self.config.regulations.clone();
+        let fine = self.compliance.cite_relocation_bypass(&config);
+        if fine > 0 {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "Tenant displaced without relocation assistance. ${} fine.",
+                        fine
+                    ),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+        }
+        Ok(())
+    }
+
+    pub(super) fn try_evict_tenant(
+        &mut self,
+        apartment_id: u32,
+        reason: EvictionReason,
+    ) -> Result<(), String> {
+        let apt = self
+            .building
+            .get_apartment(apartment_id)
+            .ok_or("No such unit")?;
+        let tenant_id = apt.tenant_id.ok_or("No tenant to evict")?;
+        let tenant = self
+            .tenants
+            .iter()
+            .find(|t| t.id == tenant_id)
+            .ok_or("No tenant to evict")?;
+
+        if self.compliance.just_cause_eviction_active
+            && !reason.is_valid(tenant, apt, &self.building)
+        {
+            let config = self.config.regulations.clone();
+            let fine = self.compliance.cite_invalid_eviction(&config);
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "Eviction of {} blocked: no {} on file. ${} fine.",
+                        tenant.name,
+                        reason.documentation().to_lowercase(),
+                        fine
+                    ),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+            return Err(format!("{}: cause not documented", reason.label()));
+        }
+
+        let cost = self.config.economy.tenant_eviction_cost;
+        let relocation =
+            apt.relocation_assistance(self.config.economy.relocation_assistance_months);
+        let name = tenant.name.clone();
+        if self.funds.balance < cost + relocation {
+            return Err(format!("Need ${} to evict", cost + relocation));
+        }
+        self.funds.deduct_expense(Transaction::expense(
+            TransactionType::LegalFees,
+            cost,
+            &format!("Eviction legal fees ({})", name),
+            self.current_tick,
+        ));
+        self.settle_relocation(apartment_id, true)?;
+
+        if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
+            apt.move_out(self.current_tick);
+        }
+        self.tenants.retain(|t| t.id != tenant_id);
+        self.tenant_stories.remove(&tenant_id);
+        let penalty = self.config.happiness.eviction_penalty;
+        for tenant in &mut self.tenants {
+            tenant.happiness = (tenant.happiness - penalty).max(0);
+        }
+        self.event_log.log(
+            GameEvent::TenantMovedOut {
+                message: format!("{} was evicted ({}).", name, reason.label()),
+            },
+            self.current_tick,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn just_cause_law_blocks_and_fines_an_undocumented_eviction() {
+        let mut state = GameplayState::new();
+        let tenant = state
+            .tenants
+            .first_mut()
+            .expect("the starting building has a tenant");
+        tenant.outstanding_balance = 0;
+        let tenant_id = tenant.id;
+        let apt_id = tenant.apartment_id.unwrap();
+        state.compliance.just_cause_eviction_active = true;
+
+        assert!(state
+            .try_evict_tenant(apt_id, EvictionReason::NonPayment)
+            .is_err());
+        assert_eq!(
+            state.compliance.unpaid_fines,
+            state.config.regulations.just_cause_violation_fine
+        );
+        assert!(state.tenants.iter().any(|t| t.id == tenant_id));
+
+        state.tenants[0].outstanding_balance = 300;
+        state.funds.balance = 100_000;
+        let rent = state.building.get_apartment(apt_id).unwrap().rent_price;
+        let relocation = rent * state.config.economy.relocation_assistance_months as i32;
+        assert!(state
+            .try_evict_tenant(apt_id, EvictionReason::NonPayment)
+            .is_ok());
+        assert!(!state.tenants.iter().any(|t| t.id == tenant_id));
+        assert_eq!(
+            state.funds.balance,
+            100_000 - state.config.economy.tenant_eviction_cost - relocation
+        );
+    }
+
+    #[test]
+    fn skipping_relocation_aid_is_fined_only_under_the_law() {
+        let mut state = GameplayState::new();
+        let apt_id = state
+            .tenants
+            .first()
+            .and_then(|t| t.apartment_id)
+            .expect("the starting tenant has a unit");
+        assert!(state.settle_relocation(apt_id, false).is_ok());
+        assert_eq!(state.compliance.unpaid_fines, 0);
+
+        state.compliance.relocation_assistance_required = true;
+        assert!(state.settle_relocation(apt_id, false).is_ok());
+        assert_eq!(
+            state.compliance.unpaid_fines,
+            state.config.regulations.relocation_bypass_fine
+        );
+    }
+}
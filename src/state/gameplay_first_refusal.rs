@@ -0,0 +1,194 @@
+// Right of first refusal. Once the city grants it, selling a building with a
+// qualifying long-term tenant puts the sale on hold: the landlord sells to
+// the tenant instead, or pays to contest the claim and sells a few months
+// later.
+
+use macroquad_toolkit::rng;
+
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::events::NarrativeEffect;
+use crate::narrative::{first_refusal_invoked, NarrativeEvent};
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// The city may grant tenants first refusal; once granted it stays. Also
+    /// completes contested sales once the court rules.
+    pub(super) fn check_first_refusal_law(&mut self) {
+        // Sell the highest index first so a sale doesn't shift the others.
+        let mut sales = self.compliance.take_resolved_sales(self.current_tick);
+        sales.sort_by_key(|sale| std::cmp::Reverse(sale.building_id));
+        for sale in sales {
+            self.complete_building_sale(sale.building_id, sale.price);
+        }
+        if self.compliance.right_of_first_refusal_active
+            || rng::gen_range(0, 100) >= self.config.regulations.first_refusal_law_chance_percent
+        {
+            return;
+        }
+        self.compliance.right_of_first_refusal_active = true;
+        self.narrative_events.add_event(NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "Tenants Win Right of First Refusal",
+            "Tenants who have lived in a building for three years and pay reliably \
+             must now be offered the chance to buy it before it's sold.",
+        ));
+    }
+
+    /// Put a building sale on hold if a tenant can claim first refusal on it.
+    /// Returns true if the sale was diverted to the tenant's claim.
+    pub(super) fn claim_first_refusal(&mut self, effect: &NarrativeEffect) -> bool {
+        let (building_id, listed_price) = match effect {
+            NarrativeEffect::SellBuilding { building_id } => (*building_id, None),
+            NarrativeEffect::Multiple { effects } => {
+                let Some(building_id) = effects.iter().find_map(|e| match e {
+                    NarrativeEffect::SellBuilding { building_id } => Some(*building_id),
+                    _ => None,
+                }) else {
+                    return false;
+                };
+                let price = effects
+                    .iter()
+                    .map(|e| match e {
+                        NarrativeEffect::Money { amount } => *amount,
+                        _ => 0,
+                    })
+                    .sum();
+                (building_id, Some(price))
+            }
+            _ => return false,
+        };
+
+        let index = building_id as usize;
+        let Some(building) = self.building_at(index) else {
+            return false;
+        };
+        let Some(tenant) = self.compliance.first_refusal_tenant(
+            building,
+            self.tenants_at(index),
+            &self.config.regulations,
+        ) else {
+            return false;
+        };
+        let appraised_value = self
+            .city
+            .neighborhood_for_building(index)
+            .map(|n| crate::city::appraise_building(building, n, &self.config))
+            .unwrap_or(0);
+        let event = first_refusal_invoked(
+            self.current_tick,
+            building_id,
+            &building.name,
+            tenant.id,
+            &tenant.name,
+            appraised_value,
+            listed_price.unwrap_or(appraised_value),
+            self.config.economy.legal_contest_cost,
+        );
+        self.narrative_events.add_event(event);
+        true
+    }
+
+    /// Sell a building to the tenant who claimed first refusal. The tenant
+    /// takes over the whole building, so it leaves the portfolio like any
+    /// other sale; no condo unit is kept on the books for them.
+    pub(super) fn sell_to_tenant(&mut self, building_id: u32, tenant_id: u32, price: i32) {
+        let name = self
+            .tenants_at(building_id as usize)
+            .iter()
+            .find(|t| t.id == tenant_id)
+            .map_or_else(|| "A tenant".to_string(), |t| t.name.clone());
+        self.event_log.log(
+            GameEvent::Notification {
+                message: format!("{} bought the building for ${}.", name, price),
+                level: crate::simulation::NotificationLevel::Info,
+            },
+            self.current_tick,
+        );
+        self.complete_building_sale(building_id, price);
+    }
+
+    /// Pay to fight a first refusal claim; the original sale closes once the
+    /// court rules.
+    pub(super) fn contest_first_refusal(&mut self, building_id: u32, price: i32) {
+        self.funds.apply_required_expense(Transaction::expense(
+            TransactionType::LegalFees,
+            self.config.economy.legal_contest_cost,
+            "Contesting right of first refusal",
+            self.current_tick,
+        ));
+        self.compliance.contest_sale(
+            building_id,
+            price,
+            self.current_tick,
+            &self.config.regulations,
+        );
+        self.spawn_center_text("Sale delayed by court", 0.0, 0.0, colors::WARNING());
+    }
+
+    fn complete_building_sale(&mut self, building_id: u32, price: i32) {
+        self.funds.add_income(Transaction::income(
+            TransactionType::AssetSale,
+            price,
+            "Building sale",
+            self.current_tick,
+        ));
+        self.sell_building_from_event(building_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualifying_tenant_puts_the_sale_on_hold() {
+        let mut state = GameplayState::new();
+        state.compliance.right_of_first_refusal_active = true;
+        let apartment_id = state.building.apartments[0].id;
+        let mut tenant =
+            crate::tenant::Tenant::new(900, "Dana", crate::tenant::TenantArchetype::Professional);
+        tenant.apartment_id = Some(apartment_id);
+        tenant.months_residing = 48;
+        tenant.rent_reliability = 90;
+        state.building.apartments[0].tenant_id = Some(900);
+        state.tenants.push(tenant);
+        let buildings = state.city.buildings.len();
+
+        let sale = NarrativeEffect::Multiple {
+            effects: vec![
+                NarrativeEffect::Money { amount: 100_000 },
+                NarrativeEffect::SellBuilding {
+                    building_id: state.city.active_building_index as u32,
+                },
+            ],
+        };
+        state.apply_narrative_effect(&sale);
+        assert_eq!(state.city.buildings.len(), buildings);
+    }
+
+    #[test]
+    fn a_tenant_of_a_stored_building_can_claim() {
+        let mut state = GameplayState::new();
+        state.compliance.right_of_first_refusal_active = true;
+        let apartment_id = state.building.apartments[0].id;
+        let mut tenant =
+            crate::tenant::Tenant::new(900, "Dana", crate::tenant::TenantArchetype::Professional);
+        tenant.apartment_id = Some(apartment_id);
+        tenant.months_residing = 48;
+        tenant.rent_reliability = 90;
+        state.building.apartments[0].tenant_id = Some(900);
+        state.tenants.push(tenant);
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+
+        assert!(state.claim_first_refusal(&NarrativeEffect::SellBuilding { building_id: 0 }));
+    }
+}
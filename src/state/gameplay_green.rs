@@ -0,0 +1,58 @@
+// Green building certification: announce the building's first certification
+// once, tracked through a building flag like the aging events.
+
+use crate::building::{green_certification_level, GreenCertificationLevel};
+use crate::narrative::events::NarrativeEventType;
+use crate::narrative::NarrativeEvent;
+
+use super::gameplay::GameplayState;
+
+const GREEN_CERTIFIED_FLAG: &str = "green_certified";
+
+impl GameplayState {
+    /// Called after a building upgrade: the first time the building reaches
+    /// Bronze, the news picks it up.
+    pub(super) fn check_green_certification(&mut self) {
+        let level = green_certification_level(&self.building);
+        if level < GreenCertificationLevel::Bronze
+            || !self.building.flags.insert(GREEN_CERTIFIED_FLAG.to_string())
+        {
+            return;
+        }
+        let mut event = NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "Building Receives Green Certification",
+            &format!(
+                "{} has been awarded {} green certification for its solar panels \
+                 and community garden. Tenants are proud to call it home.",
+                self.building.name,
+                level.name()
+            ),
+        );
+        event.event_type = NarrativeEventType::BuildingMilestone;
+        self.narrative_events.add_event(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn certification_is_announced_once() {
+        let mut state = GameplayState::new();
+        let before = state.narrative_events.events.len();
+
+        state.check_green_certification();
+        assert_eq!(state.narrative_events.events.len(), before);
+
+        state.building.flags.insert("solar_panels".to_string());
+        state.building.flags.insert("community_garden".to_string());
+        state.check_green_certification();
+        state.building.flags.insert("hvac".to_string());
+        state.check_green_certification();
+
+        assert_eq!(state.narrative_events.events.len(), before + 1);
+    }
+}
@@ -1,12 +1,20 @@
 // Building inspections and the regulatory fines they produce.
 
+use crate::building::WATER_DAMAGE_FLAG;
+use crate::consequences::FireSafetyOutcome;
+use crate::data::config::RegulationsConfig;
 use crate::economy::{Transaction, TransactionType};
+use crate::narrative::catastrophic_fire;
+use crate::narrative::{MailItem, NarrativeEvent};
 use crate::simulation::GameEvent;
+use crate::tenant::matching::ADA_COMPLIANT_FLAG;
 use crate::ui::colors;
-use macroquad::prelude::*;
 
 use super::gameplay::GameplayState;
 
+/// Condition every unit and the hallway lose in a fire.
+const FIRE_DAMAGE: i32 = 30;
+
 impl GameplayState {
     /// Run any scheduled or random building inspections for the active building,
     /// bill the resulting fines, and reflect the outcome in reputation. A
@@ -18,9 +26,14 @@ impl GameplayState {
         let due = self.compliance.has_due_inspection(building_id);
         let random_check = macroquad_toolkit::rng::gen_range(0, 100)
             < self.config.regulations.random_inspection_chance_percent;
+        let complaint_check = self
+            .compliance
+            .take_complaint_inspection(building_id, self.current_tick);
 
-        if due || random_check {
-            let trigger = if due {
+        if due || random_check || complaint_check {
+            let trigger = if complaint_check {
+                crate::consequences::InspectionTrigger::TenantComplaint
+            } else if due {
                 crate::consequences::InspectionTrigger::Scheduled
             } else {
                 crate::consequences::InspectionTrigger::Random
@@ -42,10 +55,21 @@ impl GameplayState {
             .average_condition()
             .min(self.building.hallway_condition);
         let config = self.config.regulations.clone();
+        let ada_required = self.active_building_requires_ada(&config);
+        self.compliance.set_ada_required(building_id, ada_required);
 
+        let poor = self.config.thresholds.poor_condition;
+        let poor_units: Vec<_> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|apt| apt.condition <= poor)
+            .collect();
         let inspection = self.compliance.run_inspection(
             building_id,
             inspection_score,
+            self.building.flags.contains(ADA_COMPLIANT_FLAG),
+            &poor_units,
             self.current_tick,
             trigger,
             &config,
@@ -64,21 +88,330 @@ impl GameplayState {
                 },
                 self.current_tick,
             );
-            self.floating_texts.spawn(
+            self.spawn_center_text(
                 format!("Inspection: {} cited!", citations),
-                vec2(screen_width() / 2.0, screen_height() / 2.0),
+                0.0,
+                0.0,
                 colors::NEGATIVE(),
             );
         } else if !inspection.results.is_empty() {
             self.adjust_active_neighborhood_reputation(config.neighborhood_reputation_gain);
-            self.floating_texts.spawn(
-                "Inspection passed",
-                vec2(screen_width() / 2.0, screen_height() / 2.0),
-                colors::POSITIVE(),
+            self.spawn_center_text("Inspection passed", 0.0, 0.0, colors::POSITIVE());
+        }
+
+        if !inspection.results.is_empty() {
+            self.compliance
+                .reset_complaints(self.city.active_building_index as u32);
+            self.check_lead_paint_discovery();
+        }
+    }
+
+    /// Tally this month's noise and condition complaints per apartment. Once
+    /// one apartment has complained often enough, the city schedules an
+    /// inspection and writes to say so; the letter is notice only, the visit
+    /// is already booked.
+    pub(super) fn record_tenant_complaints(&mut self, events: &[GameEvent]) {
+        let building_id = self.city.active_building_index as u32;
+        let threshold = self.config.thresholds.inspection_trigger_threshold;
+        for event in events {
+            let apartment_id = match event {
+                GameEvent::NoiseComplaint { tenant_id, .. } => self
+                    .tenants
+                    .iter()
+                    .find(|t| t.id == *tenant_id)
+                    .and_then(|t| t.apartment_id),
+                GameEvent::ConditionComplaint { apartment_unit, .. } => self
+                    .building
+                    .apartments
+                    .iter()
+                    .find(|apt| &apt.unit_number == apartment_unit)
+                    .map(|apt| apt.id),
+                _ => None,
+            };
+            let Some(apartment_id) = apartment_id else {
+                continue;
+            };
+            if !self.compliance.record_complaint(
+                building_id,
+                apartment_id,
+                threshold,
+                self.current_tick,
+            ) {
+                continue;
+            }
+            self.mailbox.receive(MailItem::city_notice(
+                0,
+                self.current_tick,
+                "Inspection Scheduled due to complaints",
+                &format!(
+                    "We have received repeated complaints from tenants of {}. \
+                     An inspector will visit next month.",
+                    self.building.name
+                ),
+                None,
+            ));
+        }
+    }
+
+    /// Advance inspection timers and fire safety upkeep, surfacing fire safety
+    /// citations and any building that burned for lack of it.
+    pub(super) fn tick_compliance(&mut self) {
+        let config = self.config.regulations.clone();
+        let active_id = self.city.active_building_index as u32;
+        for outcome in self.compliance.tick(self.current_tick, &config) {
+            match outcome {
+                FireSafetyOutcome::Cited { building_id, fine } => {
+                    let name = self.building_name(building_id, active_id);
+                    self.event_log.log(
+                        GameEvent::Notification {
+                            message: format!(
+                                "{} failed its annual fire safety check: ${} fine, {} months to fix.",
+                                name, fine, config.fire_safety_fix_deadline_months
+                            ),
+                            level: crate::simulation::NotificationLevel::Warning,
+                        },
+                        self.current_tick,
+                    );
+                }
+                FireSafetyOutcome::Fire { building_id } => {
+                    let name = self.building_name(building_id, active_id);
+                    let mut event = catastrophic_fire(self.current_tick, building_id, &name);
+                    // Only the active building is fully simulated; elsewhere
+                    // the fire is news.
+                    if building_id == active_id {
+                        for apt in &mut self.building.apartments {
+                            apt.condition = (apt.condition - FIRE_DAMAGE).max(0);
+                        }
+                        self.building.hallway_condition =
+                            (self.building.hallway_condition - FIRE_DAMAGE).max(0);
+                        self.apply_narrative_effect(&event.default_effect);
+                    } else {
+                        event.default_effect = crate::narrative::events::NarrativeEffect::None;
+                    }
+                    self.narrative_events.add_event(event);
+                }
+            }
+        }
+        self.check_repair_deadlines();
+        self.check_landlord_license();
+        self.check_fair_housing();
+        self.check_just_cause_law();
+        self.check_relocation_law();
+        self.check_rent_notice_law();
+        self.check_first_refusal_law();
+    }
+
+    /// Close repair orders for units fixed up and fine the overdue ones.
+    fn check_repair_deadlines(&mut self) {
+        let (resolved, fined) = self.compliance.tick_repair_deadlines(
+            self.city.active_building_index as u32,
+            &self.building.apartments,
+            self.current_tick,
+            self.config.thresholds.poor_condition,
+        );
+        if resolved > 0 {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!("{} health violation(s) repaired and closed.", resolved),
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+        }
+        if fined > 0 {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!("Overdue habitability repairs: ${} in fines.", fined),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
             );
         }
     }
 
+    /// Water damage left past its repair window draws a health citation.
+    pub(super) fn check_water_damage_deadline(&mut self) {
+        if !self
+            .building
+            .water_damage_overdue(self.current_tick, self.config.water_damage.repair_window)
+        {
+            return;
+        }
+        let config = self.config.regulations.clone();
+        let building_id = self.city.active_building_index as u32;
+        let poor = self.config.thresholds.poor_condition;
+        let damaged: Vec<_> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|apt| apt.flags.contains(WATER_DAMAGE_FLAG) && apt.condition <= poor)
+            .collect();
+        let fine = self.compliance.cite_health_violation(
+            building_id,
+            &damaged,
+            self.current_tick,
+            "Unrepaired water damage",
+            &config,
+        );
+        self.adjust_active_neighborhood_reputation(-config.neighborhood_reputation_penalty);
+        self.event_log.log(
+            GameEvent::Notification {
+                message: format!(
+                    "Health violation: water damage went unrepaired. ${} fine.",
+                    fine
+                ),
+                level: crate::simulation::NotificationLevel::Warning,
+            },
+            self.current_tick,
+        );
+    }
+
+    /// Newer buildings, and every building in the configured neighborhoods,
+    /// must be accessible.
+    fn active_building_requires_ada(&self, config: &RegulationsConfig) -> bool {
+        self.active_building_in(&config.ada_required_neighborhoods)
+            || self.building.construction_year > config.ada_required_built_after
+    }
+
+    /// Issue the city's energy mandate for eligible buildings, lift it once
+    /// the upgrade is in, and fine every month it stays overdue.
+    pub(super) fn check_energy_mandate(&mut self) {
+        let config = self.config.regulations.clone();
+        if self.compliance.energy_mandate_deadline.is_none() {
+            self.maybe_issue_energy_mandate(&config);
+            return;
+        }
+
+        // The mandated building may not be the active one; it's fined either way.
+        let required = self.compliance.energy_mandate_required_upgrade.clone();
+        let Some(building) = self.building_at(self.compliance.energy_mandate_building_id as usize)
+        else {
+            self.compliance.clear_energy_mandate();
+            return;
+        };
+        let building_name = building.name.clone();
+        if building.flags.contains(&required) {
+            self.compliance.clear_energy_mandate();
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: "Energy mandate met. No further fines.".to_string(),
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+            return;
+        }
+        if self.compliance.energy_mandate_overdue(self.current_tick) {
+            let fine = config.energy_mandate_monthly_fine;
+            self.funds.apply_required_expense(Transaction::expense(
+                TransactionType::ComplianceFine,
+                fine,
+                &format!("Energy mandate fine ({})", building_name),
+                self.current_tick,
+            ));
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "Energy mandate overdue at {}: ${} fine this month.",
+                        building_name, fine
+                    ),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+        }
+    }
+
+    fn maybe_issue_energy_mandate(&mut self, config: &RegulationsConfig) {
+        if self.building.flags.contains(&config.energy_mandate_upgrade)
+            || !self.active_building_in(&config.energy_mandate_neighborhoods)
+            || macroquad_toolkit::rng::gen_range(0, 100) >= config.energy_mandate_chance_percent
+        {
+            return;
+        }
+        let deadline = self.compliance.issue_energy_mandate(
+            self.city.active_building_index as u32,
+            self.current_tick,
+            &config.energy_mandate_upgrade,
+            config.energy_mandate_months,
+        );
+        let upgrade = config.energy_mandate_upgrade.to_uppercase();
+        self.narrative_events.add_event(NarrativeEvent::news(
+            0,
+            self.current_tick,
+            &format!(
+                "City Requires {} Installation by Month {}",
+                upgrade, deadline
+            ),
+            &format!(
+                "A new efficiency ordinance covers {}. Miss the deadline and \
+                 the city fines you ${} every month until it's installed.",
+                self.building.name, config.energy_mandate_monthly_fine
+            ),
+        ));
+    }
+
+    /// Enact the city's affordable unit mandate at random; once in force,
+    /// fine the active building every month it falls short.
+    pub(super) fn check_affordable_mandate(&mut self) {
+        let config = self.config.regulations.clone();
+        if !self.compliance.affordable_mandate_active() {
+            if macroquad_toolkit::rng::gen_range(0, 100) < config.affordable_mandate_chance_percent
+            {
+                self.compliance
+                    .enact_affordable_mandate(&self.config.gentrification);
+                let percent = (self.compliance.required_affordable_percent * 100.0) as i32;
+                self.narrative_events.add_event(NarrativeEvent::news(
+                    0,
+                    self.current_tick,
+                    "City Enacts Affordable Housing Mandate",
+                    &format!(
+                        "{}% of every building's units must now rent for ${} or less. \
+                         Landlords are fined each month for every unit short.",
+                        percent, self.compliance.affordable_rent_threshold
+                    ),
+                ));
+            }
+            return;
+        }
+        let fine = self
+            .compliance
+            .cite_affordable_shortfall(&self.building.apartments, &config);
+        if fine > 0 {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "Too few affordable units under the city mandate. ${} fine.",
+                        fine
+                    ),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+        }
+    }
+
+    fn active_building_in(&self, neighborhoods: &[String]) -> bool {
+        self.city
+            .neighborhood_for_building(self.city.active_building_index)
+            .is_some_and(|neighborhood| {
+                neighborhoods
+                    .iter()
+                    .any(|name| name == neighborhood.neighborhood_type.name())
+            })
+    }
+
+    fn building_name(&self, building_id: u32, active_id: u32) -> String {
+        if building_id == active_id {
+            return self.building.name.clone();
+        }
+        self.city
+            .buildings
+            .get(building_id as usize)
+            .map(|b| b.name.clone())
+            .unwrap_or_default()
+    }
+
     /// Charge any outstanding regulatory fines (from inspections or missed fix
     /// deadlines escalated in `ComplianceSystem::tick`) as a required expense, so
     /// persistent neglect can genuinely push a landlord toward bankruptcy.
@@ -95,3 +428,60 @@ impl GameplayState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affordable_mandate_fines_a_short_building() {
+        let mut state = GameplayState::new();
+        for apt in &mut state.building.apartments {
+            apt.rent_price = state.config.gentrification.affordable_threshold + 100;
+        }
+        state
+            .compliance
+            .enact_affordable_mandate(&state.config.gentrification);
+        state.check_affordable_mandate();
+        assert!(state.compliance.unpaid_fines > 0);
+    }
+
+    #[test]
+    fn overdue_energy_mandate_fines_until_installed() {
+        let mut state = GameplayState::new();
+        state.building.flags.remove("hvac");
+        let building_id = state.city.active_building_index as u32;
+        state
+            .compliance
+            .issue_energy_mandate(building_id, 0, "hvac", 1);
+        state.current_tick = 2;
+
+        let before = state.funds.balance;
+        state.check_energy_mandate();
+        let fine = state.config.regulations.energy_mandate_monthly_fine;
+        assert_eq!(state.funds.balance, before - fine);
+
+        state.building.flags.insert("hvac".to_string());
+        state.check_energy_mandate();
+        assert_eq!(state.funds.balance, before - fine);
+        assert_eq!(state.compliance.energy_mandate_deadline, None);
+    }
+
+    #[test]
+    fn repair_deadline_fines_until_the_unit_is_fixed() {
+        let mut state = GameplayState::new();
+        let config = state.config.regulations.clone();
+        state.building.apartments[0].condition = 10;
+        let apt = state.building.apartments[0].clone();
+        state.compliance.open_repair_deadline(0, &apt, 0, &config);
+
+        state.current_tick = config.repair_deadline_months + 1;
+        state.check_repair_deadlines();
+        assert_eq!(state.compliance.unpaid_fines, config.repair_fine_per_tick);
+
+        state.building.apartments[0].condition = 90;
+        state.check_repair_deadlines();
+        assert!(state.compliance.repair_deadlines.is_empty());
+        assert_eq!(state.compliance.unpaid_fines, config.repair_fine_per_tick);
+    }
+}
@@ -0,0 +1,153 @@
+// The building inspector's scheduled visits and the report cards they leave.
+
+use crate::economy::{Transaction, TransactionType, INSPECTION_DISCOUNT_FLAG};
+use crate::narrative::MailItem;
+use crate::simulation::GameEvent;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// On the inspector's visit month, grade the active building and mail the
+    /// report card. An A or B earns the graded building an insurance discount
+    /// until the next visit; a D or F draws a compliance fine.
+    pub(super) fn check_inspector_visit(&mut self) {
+        if !self.building_inspector.is_due(self.current_tick) {
+            return;
+        }
+        let building_id = self.city.active_building_index as u32;
+        let open_codes: Vec<&str> = self
+            .compliance
+            .get_regulations(building_id)
+            .map(|regulations| {
+                regulations
+                    .iter()
+                    .filter(|regulation| !regulation.compliant)
+                    .map(|regulation| regulation.regulation_type.name())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let report = self
+            .building_inspector
+            .inspect(
+                self.current_tick,
+                &self.building,
+                &self.tenants,
+                &open_codes,
+                self.config.thresholds.poor_condition,
+                &self.config.inspector,
+            )
+            .clone();
+        self.mailbox.receive(MailItem::inspection_report(
+            0,
+            self.current_tick,
+            &self.building_inspector.name,
+            &self.building.name,
+            &report,
+        ));
+
+        // The last visit's discount lapses wherever it was earned.
+        for building in &mut self.city.buildings {
+            building.flags.remove(INSPECTION_DISCOUNT_FLAG);
+        }
+        if report.earns_discount() {
+            self.building
+                .flags
+                .insert(INSPECTION_DISCOUNT_FLAG.to_string());
+        } else {
+            self.building.flags.remove(INSPECTION_DISCOUNT_FLAG);
+        }
+
+        if report.is_failing() {
+            let fine = self.config.inspector.failing_grade_fine;
+            self.funds.apply_required_expense(Transaction::expense(
+                TransactionType::ComplianceFine,
+                fine,
+                "Failing inspection grade",
+                self.current_tick,
+            ));
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "The inspector gave {} a {}. ${} fine.",
+                        self.building.name, report.grade, fine
+                    ),
+                    level: crate::simulation::NotificationLevel::Warning,
+                },
+                self.current_tick,
+            );
+        } else {
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "The inspector gave {} a {}.",
+                        self.building.name, report.grade
+                    ),
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+        }
+
+        let streak = self.building_inspector.passing_streak();
+        self.lifetime_stats.record_inspection_streak(streak);
+        for id in self.achievements.inspection_streak_unlocks(streak) {
+            self.unlock_achievement(&id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_card_sets_the_insurance_discount_or_fines() {
+        let mut state = GameplayState::new();
+        for apt in &mut state.building.apartments {
+            apt.condition = 95;
+        }
+        state.building.hallway_condition = 95;
+        state.building_inspector.next_visit_tick = state.current_tick;
+        let unread = state.mailbox.unread_count();
+        state.check_inspector_visit();
+        assert!(state.building.flags.contains(INSPECTION_DISCOUNT_FLAG));
+        assert_eq!(state.mailbox.unread_count(), unread + 1);
+
+        for apt in &mut state.building.apartments {
+            apt.condition = 10;
+        }
+        state.building.hallway_condition = 10;
+        state.building_inspector.next_visit_tick = state.current_tick;
+        let before = state.funds.balance;
+        state.check_inspector_visit();
+        assert!(!state.building.flags.contains(INSPECTION_DISCOUNT_FLAG));
+        assert_eq!(
+            state.funds.balance,
+            before - state.config.inspector.failing_grade_fine
+        );
+    }
+
+    #[test]
+    fn discount_lapses_when_another_building_is_graded() {
+        let mut state = GameplayState::new();
+        for apt in &mut state.building.apartments {
+            apt.condition = 95;
+        }
+        state.building.hallway_condition = 95;
+        state.building_inspector.next_visit_tick = state.current_tick;
+        state.check_inspector_visit();
+        assert!(state.building.flags.contains(INSPECTION_DISCOUNT_FLAG));
+
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+        state.building_inspector.next_visit_tick = state.current_tick;
+        state.check_inspector_visit();
+        assert!(!state.city.buildings[0]
+            .flags
+            .contains(INSPECTION_DISCOUNT_FLAG));
+    }
+}
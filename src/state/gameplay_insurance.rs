@@ -0,0 +1,87 @@
+// Insurance claims on building damage, and the yearly renewal that prices
+// the next year's premium from the claims record and the building's upkeep.
+
+use crate::economy::{OperatingCosts, CLAIMS_FREE_FLAG};
+use crate::narrative::MailItem;
+use crate::simulation::GameEvent;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Count this month's damage as insurance claims; any claim costs the
+    /// claims-free discount.
+    pub(super) fn record_insurance_claims(&mut self, events: &[GameEvent]) {
+        if !self.building.insurance_active {
+            return;
+        }
+        let claims = events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    GameEvent::PipeBurst { .. }
+                        | GameEvent::WaterDamage { .. }
+                        | GameEvent::BoilerFailure { .. }
+                        | GameEvent::StructuralIssue { .. }
+                )
+            })
+            .count() as u32;
+        if claims == 0 {
+            return;
+        }
+        self.claims_this_year += claims;
+        // Claims count against the next renewal; 0 means no claim yet.
+        self.last_claim_year = self.current_tick / 12 + 1;
+        self.building.flags.remove(CLAIMS_FREE_FLAG);
+    }
+
+    /// Reprice the policy for the coming year and mail the breakdown.
+    pub(super) fn renew_insurance(&mut self) {
+        let claims = std::mem::take(&mut self.claims_this_year);
+        if !self.building.insurance_active {
+            return;
+        }
+        if claims == 0 {
+            self.building.flags.insert(CLAIMS_FREE_FLAG.to_string());
+        }
+        let quote = OperatingCosts::insurance_quote(
+            &self.building,
+            &self.config.operating_costs,
+            self.config.thresholds.critical_condition,
+        );
+        let premium = (quote.premium() as f32 * self.inflation_multiplier).round() as i32;
+        self.mailbox.receive(MailItem::insurance_renewal(
+            0,
+            self.current_tick,
+            &self.building.name,
+            &quote,
+            premium,
+            claims,
+            (self.current_tick / 12).saturating_sub(self.last_claim_year),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_claim_free_year_earns_the_discount() {
+        let mut state = GameplayState::new();
+        state.building.insurance_active = true;
+        state.current_tick = 3;
+        state.record_insurance_claims(&[GameEvent::BoilerFailure { cost: 1_000 }]);
+        assert_eq!(state.claims_this_year, 1);
+
+        state.current_tick = 12;
+        state.renew_insurance();
+        assert!(!state.building.flags.contains(CLAIMS_FREE_FLAG));
+        assert_eq!(state.claims_this_year, 0);
+
+        state.current_tick = 24;
+        state.renew_insurance();
+        assert!(state.building.flags.contains(CLAIMS_FREE_FLAG));
+        assert_eq!(state.mailbox.recent(1)[0].subject, "Your Policy Renewal");
+    }
+}
@@ -0,0 +1,101 @@
+// Applicant interviews. Some applicants want to meet before signing: accepting
+// them opens a dialogue first, and the lease offer goes out once it's resolved.
+
+use crate::narrative::dialogue::DialogueType;
+use crate::narrative::TenantStory;
+use crate::tenant::Tenant;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Open the interview for an application that asked for one. Returns
+    /// false when no interview is needed and acceptance can go ahead.
+    pub(super) fn start_interview(&mut self, application_index: usize) -> bool {
+        let Some(app) = self.applications.get_mut(application_index) else {
+            return false;
+        };
+        if !app.interview_required {
+            return false;
+        }
+        app.interview_required = false;
+        let applicant = app.tenant.clone();
+        let story = self
+            .tenant_stories
+            .entry(applicant.id)
+            .or_insert_with(|| TenantStory::generate(applicant.id, &applicant.archetype));
+        self.dialogue_system.add_interview(&applicant, story);
+        true
+    }
+
+    /// The applicant whose interview `dialogue_id` is, if it is one.
+    pub(super) fn interview_applicant(&self, dialogue_id: u32) -> Option<u32> {
+        self.dialogue_system
+            .active_dialogues
+            .iter()
+            .find(|d| d.id == dialogue_id && d.dialogue_type == DialogueType::ApplicantInterview)
+            .map(|d| d.initiator_id)
+    }
+
+    /// After the interview, make the lease offer it was holding up.
+    pub(super) fn finish_interview(&mut self, applicant_id: u32) {
+        let Some(index) = self
+            .applications
+            .iter()
+            .position(|app| app.tenant.id == applicant_id)
+        else {
+            self.tenant_stories.remove(&applicant_id);
+            return;
+        };
+        let voucher = self.applications[index].has_housing_voucher();
+        self.accept_application(index, voucher);
+    }
+
+    /// Dialogue effects reach applicants still being interviewed as well as
+    /// housed tenants.
+    pub(super) fn tenant_or_applicant_mut(&mut self, tenant_id: u32) -> Option<&mut Tenant> {
+        if let Some(index) = self.tenants.iter().position(|t| t.id == tenant_id) {
+            return self.tenants.get_mut(index);
+        }
+        self.applications
+            .iter_mut()
+            .map(|app| &mut app.tenant)
+            .find(|t| t.id == tenant_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{matching::MatchResult, TenantApplication, TenantArchetype};
+    use crate::ui::UiAction;
+
+    #[test]
+    fn interview_precedes_acceptance_and_its_outcome_sticks_to_the_applicant() {
+        let mut state = GameplayState::new();
+        let tenant = Tenant::new(99, "Applicant", TenantArchetype::Professional);
+        let match_result = MatchResult {
+            score: 80,
+            meets_minimum: true,
+            ..Default::default()
+        };
+        let mut app =
+            TenantApplication::new(tenant, state.building.apartments[0].id, match_result, 0);
+        app.interview_required = true;
+        state.applications.push(app);
+
+        state.process_action(UiAction::AcceptApplication {
+            application_index: 0,
+        });
+        assert!(state.tenants.iter().all(|t| t.id != 99));
+        let dialogue = &state.dialogue_system.active_dialogues[0];
+        assert_eq!(dialogue.choices.len(), 4);
+        assert_eq!(state.interview_applicant(dialogue.id), Some(99));
+
+        state.apply_dialogue_effect(crate::narrative::dialogue::DialogueEffect::OpinionChange {
+            tenant_id: 99,
+            amount: 10,
+        });
+        assert_eq!(state.applications[0].tenant.landlord_opinion, 10);
+        assert!(!state.applications[0].interview_required);
+    }
+}
@@ -0,0 +1,113 @@
+// The local journalist: a story every few months when there's one to tell,
+// moving the building's appeal and prompting rival landlords to respond.
+
+use crate::narrative::{MailItem, PressSnapshot};
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Let last month's coverage fade, and file a new story when one's due.
+    pub(super) fn update_journalist(&mut self) {
+        self.building.press_coverage -= self.building.press_coverage.signum();
+
+        let config = self.config.journalist.clone();
+        if !self
+            .journalist
+            .article_due(self.current_tick, config.article_interval)
+        {
+            return;
+        }
+
+        let average_happiness = if self.tenants.is_empty() {
+            0
+        } else {
+            self.tenants.iter().map(|t| t.happiness).sum::<i32>() / self.tenants.len() as i32
+        };
+        let snapshot = PressSnapshot {
+            building_name: &self.building.name,
+            gentrification_score: self.gentrification.gentrification_score,
+            fully_occupied: self.building.vacancy_count() == 0,
+            average_happiness,
+        };
+        let Some(article) = self
+            .journalist
+            .file_article(self.current_tick, &snapshot, &config)
+            .cloned()
+        else {
+            return;
+        };
+
+        let effect = article.effect(self.city.active_building_index as u32);
+        self.apply_narrative_effect(&effect);
+        if let Some(neighborhood_id) = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+            .map(|neighborhood| neighborhood.id)
+        {
+            for rival in self
+                .city
+                .rivals
+                .iter_mut()
+                .filter(|rival| rival.neighborhood_id == neighborhood_id)
+            {
+                rival.react_to_press(article.appeal_change, &self.config.rivals);
+            }
+        }
+        self.mailbox.receive(MailItem::press_article(
+            0,
+            self.current_tick,
+            &self.journalist.name,
+            &article,
+        ));
+    }
+
+    pub(super) fn grant_exclusive_interview(&mut self) {
+        let interval = self.config.journalist.article_interval;
+        if !self.journalist.can_interview(self.current_tick, interval) {
+            return;
+        }
+        self.journalist
+            .grant_interview(self.current_tick, &self.config.journalist);
+        self.spawn_center_text("Interview granted", 0.0, 0.0, colors::POSITIVE());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displacement_story_hurts_appeal_and_emboldens_rivals() {
+        let mut state = GameplayState::new();
+        state.gentrification.gentrification_score = 90;
+        state.current_tick = state.config.journalist.article_interval;
+        let neighborhood_id = state
+            .city
+            .neighborhood_for_building(state.city.active_building_index)
+            .map(|n| n.id)
+            .unwrap();
+        let rival_appeal: Vec<i32> = state
+            .city
+            .rivals_in(neighborhood_id)
+            .iter()
+            .map(|r| r.appeal)
+            .collect();
+
+        state.update_journalist();
+
+        assert!(state.building.press_coverage < 0);
+        assert_eq!(
+            state.mailbox.recent(1)[0].subject,
+            "Developer Displaces Long-Term Residents"
+        );
+        for (rival, before) in state
+            .city
+            .rivals_in(neighborhood_id)
+            .iter()
+            .zip(rival_appeal)
+        {
+            assert!(rival.appeal >= before);
+        }
+    }
+}
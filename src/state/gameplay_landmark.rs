@@ -0,0 +1,89 @@
+// Landmark designation: a historic building handsome and old enough gets
+// asked to join the city's landmark list. A landmark earns a monthly grant
+// and keeps extra appeal, but its units can't be sold off.
+
+use crate::building::LANDMARK_PROPOSED_FLAG;
+use crate::city::NeighborhoodType;
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::landmark_proposal;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Monthly check for a historic building the city would like to preserve.
+    pub(super) fn check_landmark_designation(&mut self) {
+        let historic = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+            .is_some_and(|n| n.neighborhood_type == NeighborhoodType::Historic);
+        if !historic
+            || !self
+                .building
+                .landmark_eligible(self.current_tick, &self.config.landmarks)
+        {
+            return;
+        }
+        self.building
+            .flags
+            .insert(LANDMARK_PROPOSED_FLAG.to_string());
+        let event = landmark_proposal(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            &self.building.name,
+            self.config.economy.landmark_subsidy,
+        );
+        self.narrative_events.add_event(event);
+    }
+
+    /// Record the answer on the building the proposal was made for, which
+    /// may no longer be the active one.
+    pub(super) fn answer_landmark_proposal(&mut self, building_id: u32, accepted: bool) {
+        let current_tick = self.current_tick;
+        let config = self.config.landmarks.clone();
+        let Some(building) = self.building_at_mut(building_id as usize) else {
+            return;
+        };
+        building.answer_landmark_proposal(accepted, current_tick, &config);
+        if accepted {
+            self.spawn_center_text("Designated a landmark", 0.0, 30.0, colors::ACCENT());
+        }
+    }
+
+    /// Pay the preservation grant for every landmark the player owns.
+    pub(super) fn collect_landmark_subsidy(&mut self) {
+        let landmarks: Vec<String> = (0..self.city.buildings.len())
+            .filter_map(|index| self.building_at(index))
+            .filter(|building| building.is_landmark())
+            .map(|building| building.name.clone())
+            .collect();
+        for name in landmarks {
+            self.funds.add_income(Transaction::income(
+                TransactionType::Grant,
+                self.config.economy.landmark_subsidy,
+                &format!("Landmark preservation grant ({})", name),
+                self.current_tick,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn landmark_earns_a_monthly_grant() {
+        let mut state = GameplayState::new();
+        let before = state.funds.balance;
+        state.collect_landmark_subsidy();
+        assert_eq!(state.funds.balance, before);
+
+        state.answer_landmark_proposal(state.city.active_building_index as u32, true);
+        state.collect_landmark_subsidy();
+        assert_eq!(
+            state.funds.balance,
+            before + state.config.economy.landmark_subsidy
+        );
+    }
+}
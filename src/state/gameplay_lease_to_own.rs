@@ -0,0 +1,137 @@
+// Condo sales. A unit can go to an outside buyer, or (lease-to-own) to a
+// tenant who has rented long enough, at a discount. That tenant stays on as
+// an owner-occupier, paying HOA instead of rent, and if the player ever buys
+// the unit back they simply resume renting. Landmarks can't sell units.
+// Selling an occupied unit to an outsider displaces its tenant, who is owed
+// relocation assistance.
+
+use crate::economy::{Transaction, TransactionType};
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn sell_unit_as_condo(&mut self, apartment_id: u32, pay_relocation: bool) {
+        if self.building.is_landmark() {
+            self.spawn_center_text("Landmark units can't be sold", 0.0, 0.0, colors::NEGATIVE());
+            return;
+        }
+        if let Err(reason) = self.settle_relocation(apartment_id, pay_relocation) {
+            self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE());
+            return;
+        }
+        let market_multiplier = self.condo_sale_market_multiplier();
+        let base_value = self.appraised_unit_value(apartment_id);
+        let sale_price = (base_value as f32 * market_multiplier) as i32;
+
+        if let Some(apt) = self.building.get_apartment(apartment_id) {
+            if let Some(tenant_id) = apt.tenant_id {
+                self.tenants.retain(|t| t.id != tenant_id);
+                self.tenant_stories.remove(&tenant_id);
+            }
+        }
+
+        if self
+            .building
+            .convert_unit_to_condo(apartment_id, "New Owner", sale_price)
+        {
+            self.funds.add_income(Transaction::income(
+                TransactionType::AssetSale,
+                sale_price,
+                "Condo Sale",
+                self.current_tick,
+            ));
+            self.spawn_center_text(&format!("+${}", sale_price), 0.0, 0.0, colors::POSITIVE());
+            self.save_building_to_city();
+        }
+    }
+
+    pub(super) fn offer_purchase_option(&mut self, apartment_id: u32) {
+        if self.building.is_landmark() {
+            self.spawn_center_text("Landmark units can't be sold", 0.0, 0.0, colors::NEGATIVE());
+            return;
+        }
+        let Some(tenant_id) = self
+            .building
+            .get_apartment(apartment_id)
+            .and_then(|apt| apt.tenant_id)
+        else {
+            return;
+        };
+        let economy = &self.config.economy;
+        let Some(tenant) = self
+            .tenants
+            .iter_mut()
+            .find(|t| t.id == tenant_id && t.months_residing >= economy.lease_to_own_min_months)
+        else {
+            self.spawn_center_text(
+                "Tenant hasn't rented long enough",
+                0.0,
+                0.0,
+                colors::NEGATIVE(),
+            );
+            return;
+        };
+        let owner_name = tenant.name.clone();
+        let market_price = (self.appraised_unit_value(apartment_id) as f32
+            * self.condo_sale_market_multiplier()) as i32;
+        let sale_price = (market_price as f32 * economy.lease_to_own_price_factor) as i32;
+
+        if !self
+            .building
+            .sell_unit_to_tenant(apartment_id, tenant_id, &owner_name, sale_price)
+        {
+            return;
+        }
+        if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+            tenant.happiness = 100;
+        }
+        self.funds.add_income(Transaction::income(
+            TransactionType::AssetSale,
+            sale_price,
+            &format!("Condo sale to {}", owner_name),
+            self.current_tick,
+        ));
+        self.spawn_center_text(&format!("+${}", sale_price), 0.0, 0.0, colors::POSITIVE());
+        self.save_building_to_city();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{Tenant, TenantArchetype};
+
+    fn state_with_tenant(months_residing: u32) -> (GameplayState, u32) {
+        let mut state = GameplayState::new();
+        let apt_id = state.building.apartments[0].id;
+        let mut tenant = Tenant::new(900, "Long Timer", TenantArchetype::Family);
+        tenant.apartment_id = Some(apt_id);
+        tenant.months_residing = months_residing;
+        tenant.happiness = 60;
+        state.tenants.push(tenant);
+        state.building.apartments[0].move_in(900);
+        (state, apt_id)
+    }
+
+    #[test]
+    fn long_term_tenant_buys_their_unit() {
+        let (mut state, apt_id) = state_with_tenant(30);
+        let balance = state.funds.balance;
+
+        state.offer_purchase_option(apt_id);
+
+        assert!(state.building.is_unit_sold(apt_id));
+        assert!(state.funds.balance > balance);
+        assert!(state.building.owner_occupier_fees() > 0);
+        let tenant = state.tenants.iter().find(|t| t.id == 900).unwrap();
+        assert_eq!(tenant.happiness, 100);
+    }
+
+    #[test]
+    fn new_tenants_cannot_buy() {
+        let (mut state, apt_id) = state_with_tenant(3);
+        state.offer_purchase_option(apt_id);
+        assert!(!state.building.is_unit_sold(apt_id));
+    }
+}
@@ -0,0 +1,134 @@
+// Lease renewals. A few months before a lease ends the landlord is mailed
+// renewal terms; renewing sets the proposed rent and starts a new term. A
+// lease nobody renews goes month-to-month when it ends.
+
+use crate::narrative::MailItem;
+use crate::simulation::GameEvent;
+use crate::ui::UiAction;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Mail renewal offers for leases ending soon, and convert leases that
+    /// ended without renewal to month-to-month.
+    pub(super) fn check_lease_renewals(&mut self) {
+        let increase = self.config.economy.lease_renewal_increase;
+        let notice_months = self.config.economy.lease_renewal_notice_months;
+        let building_id = self.city.active_building_index as u32;
+        let mut offers = Vec::new();
+        let mut lapsed = Vec::new();
+        for tenant in &self.tenants {
+            let Some(end) = tenant.lease_end_tick() else {
+                continue;
+            };
+            let Some(apt) = tenant
+                .apartment_id
+                .and_then(|id| self.building.get_apartment(id))
+            else {
+                continue;
+            };
+            if self.current_tick >= end {
+                lapsed.push((tenant.id, tenant.name.clone(), apt.unit_number.clone()));
+            } else if end.checked_sub(notice_months) == Some(self.current_tick) {
+                let proposed_rent = (apt.rent_price as f32 * (1.0 + increase)).round() as i32;
+                offers.push(MailItem::lease_renewal(
+                    0,
+                    self.current_tick,
+                    tenant,
+                    building_id,
+                    apt.id,
+                    &apt.unit_number,
+                    apt.rent_price,
+                    proposed_rent,
+                    end,
+                ));
+            }
+        }
+
+        for offer in offers {
+            self.mailbox.receive(offer);
+        }
+        for (tenant_id, name, unit) in lapsed {
+            if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+                tenant.go_month_to_month();
+            }
+            self.mailbox.close_lease_renewal(tenant_id);
+            self.event_log.log(
+                GameEvent::Notification {
+                    message: format!(
+                        "{}'s lease on Unit {} was not renewed and is now month-to-month.",
+                        name, unit
+                    ),
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+        }
+    }
+
+    /// Renew a lease from its offer: set the proposed rent and start another
+    /// term of the same length where the current one ends. The offer can
+    /// only be taken up while its building is the active one.
+    pub(super) fn renew_lease(
+        &mut self,
+        building_id: u32,
+        tenant_id: u32,
+        apartment_id: u32,
+        new_rent: i32,
+    ) {
+        if building_id != self.city.active_building_index as u32 {
+            self.spawn_center_text(
+                "Open that building first",
+                0.0,
+                0.0,
+                crate::ui::colors::WARNING(),
+            );
+            return;
+        }
+        let Some(end) = self
+            .tenants
+            .iter()
+            .find(|t| t.id == tenant_id)
+            .and_then(|t| t.lease_end_tick())
+        else {
+            return;
+        };
+        self.process_action(UiAction::SetRent {
+            apartment_id,
+            new_rent,
+        });
+        if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+            let months = tenant.lease_duration_months;
+            tenant.sign_lease(end, months);
+        }
+        self.mailbox.close_lease_renewal(tenant_id);
+        self.spawn_center_text("Lease renewed", 0.0, 0.0, crate::ui::colors::POSITIVE());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unrenewed_lease_goes_month_to_month() {
+        let mut state = GameplayState::new();
+        let apartment_id = state.building.apartments[0].id;
+        let mut tenant =
+            crate::tenant::Tenant::new(900, "Pat", crate::tenant::TenantArchetype::Artist);
+        tenant.apartment_id = Some(apartment_id);
+        tenant.sign_lease(0, 12);
+        state.tenants.push(tenant);
+        let index = state.tenants.len() - 1;
+        let mail_before = state.mailbox.items.len();
+
+        state.current_tick = 12 - state.config.economy.lease_renewal_notice_months;
+        state.check_lease_renewals();
+        assert_eq!(state.mailbox.items.len(), mail_before + 1);
+
+        state.current_tick = 12;
+        state.check_lease_renewals();
+        assert_eq!(state.tenants[index].lease_end_tick(), None);
+        assert!(state.mailbox.items.last().unwrap().action.is_none());
+    }
+}
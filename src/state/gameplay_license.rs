@@ -0,0 +1,122 @@
+// Annual landlord license renewal. A building in good standing renews
+// automatically for the fee; one that isn't loses its license, is cited,
+// and can't take new tenants until it renews. A rival landlord is quick to
+// make sure the city notices.
+
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::NarrativeEvent;
+use crate::simulation::GameEvent;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Renew the license when it's due, or let it lapse. A lapsed license is
+    /// retried every month until the building qualifies again.
+    pub(super) fn check_landlord_license(&mut self) {
+        if !self.compliance.license_renewal_due(self.current_tick) {
+            return;
+        }
+        let config = self.config.regulations.clone();
+        let building_id = self.city.active_building_index as u32;
+        let blocker =
+            self.compliance
+                .license_renewal_blocker(building_id, self.funds.balance, &config);
+
+        let Some(reason) = blocker else {
+            let fee = self.config.economy.license_renewal_fee;
+            self.funds.apply_required_expense(Transaction::expense(
+                TransactionType::LicenseFee,
+                fee,
+                "Landlord license renewal",
+                self.current_tick,
+            ));
+            let message = if self.compliance.license_lapsed {
+                format!("Landlord license reinstated for ${}.", fee)
+            } else {
+                format!("Landlord license renewed for ${}.", fee)
+            };
+            self.compliance.renew_license(self.current_tick);
+            self.event_log.log(
+                GameEvent::Notification {
+                    message,
+                    level: crate::simulation::NotificationLevel::Info,
+                },
+                self.current_tick,
+            );
+            return;
+        };
+
+        if self.compliance.license_lapsed {
+            return;
+        }
+        let poor = self.config.thresholds.poor_condition;
+        let poor_units: Vec<_> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|apt| apt.condition <= poor)
+            .collect();
+        let fine =
+            self.compliance
+                .lapse_license(building_id, &poor_units, self.current_tick, &config);
+        self.event_log.log(
+            GameEvent::Notification {
+                message: format!(
+                    "Landlord license lapsed due to {}: ${} fine. No new tenants until it's renewed.",
+                    reason, fine
+                ),
+                level: crate::simulation::NotificationLevel::Warning,
+            },
+            self.current_tick,
+        );
+        self.report_lapsed_license();
+    }
+
+    /// A rival landlord in the neighborhood tips off the city.
+    fn report_lapsed_license(&mut self) {
+        let rival = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+            .and_then(|n| self.city.rivals_in(n.id).first().map(|r| r.owner.clone()))
+            .unwrap_or_else(|| "A rival landlord".to_string());
+        let news = NarrativeEvent::news(
+            0,
+            self.current_tick,
+            "Reported to the City",
+            &format!(
+                "{} has reported {} to the city for operating without a landlord \
+                 license. Inspectors will be watching until it's renewed.",
+                rival, self.building.name
+            ),
+        );
+        self.narrative_events.add_event(news);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn license_renews_for_the_fee_when_due() {
+        let mut state = GameplayState::new();
+        state.funds.balance = 5_000;
+        state.current_tick = state.compliance.landlord_license_renewal_tick;
+        state.check_landlord_license();
+        assert!(!state.compliance.license_lapsed);
+        assert_eq!(
+            state.funds.balance,
+            5_000 - state.config.economy.license_renewal_fee
+        );
+    }
+
+    #[test]
+    fn license_lapses_while_in_debt() {
+        let mut state = GameplayState::new();
+        state.funds.balance = -100;
+        state.current_tick = state.compliance.landlord_license_renewal_tick;
+        state.check_landlord_license();
+        assert!(state.compliance.license_lapsed);
+        assert!(state.compliance.unpaid_fines > 0);
+    }
+}
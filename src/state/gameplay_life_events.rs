@@ -3,7 +3,6 @@
 //! job, job loss, new baby, retirement…) that applies concrete gameplay
 //! consequences and surfaces to the player.
 
-use macroquad::prelude::*;
 use macroquad_toolkit::rng;
 
 use crate::narrative::{LifeChangeType, StoryImpact};
@@ -62,9 +61,10 @@ impl GameplayState {
                 },
                 self.current_tick,
             );
-            self.floating_texts.spawn(
+            self.spawn_center_text(
                 format!("{}: {}", name, description),
-                vec2(screen_width() / 2.0, screen_height() / 2.0 - 40.0),
+                0.0,
+                -40.0,
                 colors::ACCENT(),
             );
         }
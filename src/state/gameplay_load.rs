@@ -0,0 +1,84 @@
+// Repairing a loaded save: records older saves never had, and links
+// between the city, its buildings and compliance that must always hold.
+
+use crate::city::{NeighborhoodType, RivalBuilding};
+use crate::narrative::TenantStory;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn ensure_city_integrity(&mut self) {
+        if self.city.rivals.is_empty() {
+            self.city.rivals =
+                RivalBuilding::generate_for(&self.city.neighborhoods, &self.config.rivals);
+        }
+
+        if self.city.buildings.is_empty() {
+            self.city.buildings.push(self.building.clone());
+            self.city.active_building_index = 0;
+        }
+
+        if self.city.active_building_index >= self.city.buildings.len() {
+            self.city.active_building_index = 0;
+        }
+
+        for building_id in 0..self.city.buildings.len() as u32 {
+            let already_linked = self
+                .city
+                .neighborhoods
+                .iter()
+                .any(|neighborhood| neighborhood.building_ids.contains(&building_id));
+
+            if already_linked {
+                continue;
+            }
+
+            if let Some(neighborhood) = self
+                .city
+                .neighborhoods
+                .iter_mut()
+                .find(|neighborhood| neighborhood.can_add_building())
+            {
+                neighborhood.add_building(building_id);
+            }
+        }
+
+        self.city.total_buildings_managed = self
+            .city
+            .total_buildings_managed
+            .max(self.city.buildings.len() as u32);
+    }
+
+    pub(super) fn ensure_compliance_for_buildings(&mut self) {
+        let missing: Vec<(u32, bool)> = (0..self.city.buildings.len() as u32)
+            .filter(|building_id| {
+                !self
+                    .compliance
+                    .building_regulations
+                    .contains_key(building_id)
+            })
+            .map(|building_id| {
+                let is_historic = self
+                    .city
+                    .neighborhood_for_building(building_id as usize)
+                    .is_some_and(|neighborhood| {
+                        matches!(neighborhood.neighborhood_type, NeighborhoodType::Historic)
+                    });
+                (building_id, is_historic)
+            })
+            .collect();
+
+        for (building_id, is_historic) in missing {
+            self.compliance
+                .init_building_regulations(building_id, is_historic);
+        }
+    }
+
+    pub(super) fn ensure_tenant_stories(&mut self) {
+        for tenant in &self.tenants {
+            self.tenant_stories
+                .entry(tenant.id)
+                .or_insert_with(|| TenantStory::generate(tenant.id, &tenant.archetype));
+        }
+    }
+}
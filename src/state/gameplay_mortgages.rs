@@ -0,0 +1,162 @@
+// Buying buildings outright or on a mortgage, paying the loans off month by
+// month, and repricing variable-rate loans as the city economy moves.
+
+use crate::city::{FinancingOption, NeighborhoodType};
+use crate::economy::{ActiveMortgage, Transaction, TransactionType};
+use crate::narrative::MailItem;
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Buy a market listing, in cash when `financing` is `None`. On a
+    /// mortgage only the down payment is due now, at today's market rate.
+    pub(super) fn purchase_listing(&mut self, listing_id: u32, financing: Option<FinancingOption>) {
+        let Some(listing) = self
+            .city
+            .market
+            .listings
+            .iter()
+            .find(|l| l.id == listing_id)
+            .cloned()
+        else {
+            return;
+        };
+        let upfront = financing.as_ref().map_or(listing.asking_price, |option| {
+            option.upfront_cost(listing.asking_price)
+        });
+        if self.funds.balance < upfront {
+            return;
+        }
+
+        let building = listing.to_building();
+        let neighborhood_id = listing.neighborhood_id;
+        let Ok(building_id) = self.city.add_building(building, neighborhood_id) else {
+            return;
+        };
+        self.funds.deduct_expense(Transaction::expense(
+            TransactionType::BuildingPurchase,
+            upfront,
+            "Building Purchase",
+            self.current_tick,
+        ));
+
+        let loan = match financing {
+            Some(FinancingOption::Mortgage { term_months, .. }) => {
+                Some((term_months, self.current_interest_rate, false))
+            }
+            Some(FinancingOption::FixedRateMortgage { term_months, .. }) => Some((
+                term_months,
+                self.current_interest_rate + self.config.mortgage.fixed_rate_premium,
+                true,
+            )),
+            _ => None,
+        };
+        if let Some((term_months, rate, fixed_rate)) = loan {
+            self.active_mortgages.push(ActiveMortgage::new(
+                &listing.name,
+                (listing.asking_price - upfront) as f32,
+                rate,
+                term_months,
+                fixed_rate,
+            ));
+        }
+
+        let is_historic = self.city.neighborhoods.iter().any(|n| {
+            n.id == neighborhood_id && matches!(n.neighborhood_type, NeighborhoodType::Historic)
+        });
+        self.compliance
+            .init_building_regulations(building_id, is_historic);
+
+        self.city.market.listings.retain(|l| l.id != listing_id);
+
+        self.spawn_center_text("Building Purchased!", 0.0, 0.0, colors::POSITIVE());
+
+        self.event_log.log(
+            GameEvent::UpgradeCompleted {
+                description: "Purchased new building".to_string(),
+                cost: upfront,
+            },
+            self.current_tick,
+        );
+    }
+
+    /// This month's payment on every loan; paid-off loans are closed.
+    pub(super) fn pay_mortgages(&mut self) {
+        for mortgage in &mut self.active_mortgages {
+            let payment = mortgage.make_payment();
+            if payment > 0 {
+                self.funds.apply_required_expense(Transaction::expense(
+                    TransactionType::Mortgage,
+                    payment,
+                    &format!("Mortgage: {}", mortgage.building_name),
+                    self.current_tick,
+                ));
+            }
+        }
+        self.active_mortgages
+            .retain(|mortgage| !mortgage.is_paid_off());
+    }
+
+    /// Follow the city economy: once the market rate has moved far enough,
+    /// reprice variable loans and let the player know.
+    pub(super) fn update_interest_rate(&mut self) {
+        let config = &self.config.mortgage;
+        let rate = config.rate_for(self.city.economy_health);
+        if (rate - self.current_interest_rate).abs() <= config.rate_change_threshold {
+            return;
+        }
+        self.current_interest_rate = rate;
+
+        let mut repriced = false;
+        for mortgage in &mut self.active_mortgages {
+            repriced |= mortgage.reprice(rate);
+        }
+        if !repriced {
+            return;
+        }
+        let payments = self
+            .active_mortgages
+            .iter()
+            .map(|mortgage| mortgage.monthly_payment)
+            .sum();
+        self.mailbox.receive(MailItem::mortgage_rate_change(
+            0,
+            self.current_tick,
+            rate,
+            payments,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_weaker_economy_raises_variable_payments() {
+        let mut state = GameplayState::new();
+        state
+            .active_mortgages
+            .push(ActiveMortgage::new("Variable", 80_000.0, 0.06, 120, false));
+        state
+            .active_mortgages
+            .push(ActiveMortgage::new("Fixed", 80_000.0, 0.06, 120, true));
+        let before: Vec<i32> = state
+            .active_mortgages
+            .iter()
+            .map(|m| m.monthly_payment)
+            .collect();
+
+        state.city.economy_health = 0.6;
+        state.update_interest_rate();
+
+        assert!(state.current_interest_rate > 0.06);
+        assert!(state.active_mortgages[0].monthly_payment > before[0]);
+        assert_eq!(state.active_mortgages[1].monthly_payment, before[1]);
+        assert!(state.mailbox.recent(1)[0]
+            .subject
+            .starts_with("Your mortgage rate has been adjusted"));
+    }
+}
@@ -3,10 +3,15 @@
 
 use macroquad_toolkit::rng;
 
+use crate::narrative::StoryImpact;
 use crate::simulation::TickResult;
 
 use super::gameplay::GameplayState;
 
+/// Happiness credited to the story of a tenant who left their unit in better
+/// condition than they found it.
+const CAREFUL_MOVE_OUT_HAPPINESS: i32 = 3;
+
 impl GameplayState {
     pub(super) fn generate_monthly_narrative(&mut self, result: &TickResult) {
         self.narrative_events.generate_events(
@@ -14,6 +19,14 @@ impl GameplayState {
             &self.city.neighborhoods,
             &self.city.buildings,
             &self.tenants,
+            self.gentrification.gentrification_score,
+            &self.config.probability_scaling,
+            self.tenant_union,
+        );
+        self.narrative_events.generate_special_assessments(
+            self.current_tick,
+            &self.city.neighborhoods,
+            &self.config.special_assessments,
         );
 
         let expenses = self
@@ -107,6 +120,20 @@ impl GameplayState {
         }
     }
 
+    /// Note in their stories that these departed tenants left their units
+    /// better than the move-in inspection found them.
+    pub(super) fn record_careful_move_outs(&mut self, tenant_ids: &[u32]) {
+        for tenant_id in tenant_ids {
+            if let Some(story) = self.tenant_stories.get_mut(tenant_id) {
+                story.add_event(
+                    self.current_tick,
+                    "Left the unit in better condition than they found it",
+                    StoryImpact::Happiness(CAREFUL_MOVE_OUT_HAPPINESS),
+                );
+            }
+        }
+    }
+
     pub(super) fn expire_narrative_events(&mut self) {
         let expired_effects = self.narrative_events.expire_due_events(self.current_tick);
         for effect in expired_effects {
@@ -128,6 +155,8 @@ mod tests {
             events: Vec::new(),
             rent_collected: 10,
             tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
             new_applications: 0,
             outcome: None,
         });
@@ -136,6 +165,8 @@ mod tests {
             events: Vec::new(),
             rent_collected: 1234,
             tenants_moved_out: Vec::new(),
+            left_unit_improved: Vec::new(),
+            abandoned: Vec::new(),
             new_applications: 0,
             outcome: None,
         };
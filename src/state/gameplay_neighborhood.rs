@@ -2,7 +2,6 @@
 // market conditions derived from it.
 
 use crate::ui::colors;
-use macroquad::prelude::*;
 
 use super::gameplay::GameplayState;
 
@@ -44,6 +43,32 @@ impl GameplayState {
         (economy * (1.0 + gentrification * boom_bonus)).clamp(0.4, 2.5)
     }
 
+    /// A unit's share of the active building's appraisal, weighted by each
+    /// unit's own market value. Falls back to the unit's standalone value
+    /// when the building isn't placed in a neighborhood.
+    pub(super) fn appraised_unit_value(&self, apartment_id: u32) -> i32 {
+        let Some(apt) = self.building.get_apartment(apartment_id) else {
+            return 10_000;
+        };
+        let Some(neighborhood) = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+        else {
+            return apt.market_value();
+        };
+        let appraisal = crate::city::appraise_building(&self.building, neighborhood, &self.config);
+        let total_value: i32 = self
+            .building
+            .apartments
+            .iter()
+            .map(|a| a.market_value())
+            .sum();
+        if total_value <= 0 {
+            return apt.market_value();
+        }
+        (appraisal as f32 * apt.market_value() as f32 / total_value as f32) as i32
+    }
+
     /// Reputation of the neighborhood the active building sits in (0–100),
     /// defaulting to the neutral 50 when the building isn't placed yet.
     pub(super) fn active_neighborhood_reputation(&self) -> i32 {
@@ -52,7 +77,16 @@ impl GameplayState {
             .neighborhoods
             .iter()
             .find(|n| n.building_ids.contains(&building_id))
-            .map(|n| n.reputation)
+            .map(|n| n.effective_reputation())
+            .unwrap_or(50)
+    }
+
+    /// School rating of the active building's neighborhood, defaulting to the
+    /// neutral 50 when the building isn't placed yet.
+    pub(super) fn active_neighborhood_school_rating(&self) -> i32 {
+        self.city
+            .neighborhood_for_building(self.city.active_building_index)
+            .map(|n| n.stats.school_rating)
             .unwrap_or(50)
     }
 
@@ -89,10 +123,6 @@ impl GameplayState {
         } else {
             colors::NEGATIVE()
         };
-        self.floating_texts.spawn(
-            format!("Rep {:+}", delta),
-            vec2(screen_width() / 2.0, screen_height() / 2.0 + 60.0),
-            color,
-        );
+        self.spawn_center_text(format!("Rep {:+}", delta), 0.0, 60.0, color);
     }
 }
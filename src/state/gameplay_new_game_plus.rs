@@ -0,0 +1,117 @@
+// New Game+: what a fully completed building earns for the next one, and
+// applying those bonuses when that next run starts.
+
+use crate::building::ApartmentSize;
+use crate::data::config::{UpgradeEffect, UpgradeTarget};
+use crate::save::NewGamePlusModifiers;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Did the building finish its term fully let (when the rules require
+    /// it) with tenants at least as happy as a victory asks?
+    pub(super) fn met_win_condition(&self) -> bool {
+        let rules = &self.config.win_conditions;
+        let occupied = !rules.full_occupancy_required || self.building.vacancy_count() == 0;
+        let avg_happiness = if self.tenants.is_empty() {
+            0
+        } else {
+            self.tenants.iter().map(|t| t.happiness).sum::<i32>() / self.tenants.len() as i32
+        };
+        occupied && avg_happiness >= self.config.happiness.min_for_victory
+    }
+
+    /// Modifiers earned by this run. Only called once the building has met
+    /// its win condition in full, never on bankruptcy or selling out.
+    pub(super) fn earned_new_game_plus(&self) -> NewGamePlusModifiers {
+        let rules = &self.config.win_conditions;
+        let starting_funds_bonus =
+            (self.funds.balance.max(0) as f32 * rules.new_game_plus_funds_share) as i32;
+        let reputation_head_start = ((self.active_neighborhood_reputation() - 50).max(0) as f32
+            * rules.new_game_plus_reputation_share) as i32;
+
+        // An apartment upgrade counts as unlocked once some unit shows all of
+        // its effects by the end of the run.
+        let mut unlocked_upgrades: Vec<String> = self
+            .config
+            .upgrades
+            .iter()
+            .filter(|(_, def)| def.target == UpgradeTarget::Apartment)
+            .filter(|(_, def)| {
+                self.building.apartments.iter().any(|apt| {
+                    def.effects.iter().all(|effect| match effect {
+                        UpgradeEffect::SetFlag(flag) => apt.flags.contains(flag),
+                        UpgradeEffect::RemoveFlag(flag) => !apt.flags.contains(flag),
+                        UpgradeEffect::SetDesign(design) => format!("{:?}", apt.design) == *design,
+                        UpgradeEffect::SetSize(size) => {
+                            ApartmentSize::from_name(size).as_ref() == Some(&apt.size)
+                        }
+                        // Not visible on the unit itself.
+                        UpgradeEffect::ModifyStat { .. }
+                        | UpgradeEffect::InstallAmenity(_)
+                        | UpgradeEffect::AddParkingSpots { .. }
+                        | UpgradeEffect::SetExteriorTheme(_) => true,
+                    })
+                })
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        unlocked_upgrades.sort();
+
+        NewGamePlusModifiers {
+            starting_funds_bonus,
+            reputation_head_start,
+            unlocked_upgrades,
+        }
+    }
+
+    /// Claim the bonus waiting for `template_id`. It's spent on the run it
+    /// starts, so it's removed from the saved progress.
+    pub(super) fn take_new_game_plus(template_id: &str) -> Option<NewGamePlusModifiers> {
+        use crate::save::{load_player_progress, save_player_progress};
+
+        let mut progress = load_player_progress();
+        let modifiers = progress.new_game_plus.remove(template_id)?;
+        let _ = save_player_progress(&progress);
+        Some(modifiers)
+    }
+
+    pub(super) fn apply_new_game_plus(&mut self, modifiers: NewGamePlusModifiers) {
+        self.funds.balance += modifiers.starting_funds_bonus;
+        self.adjust_active_neighborhood_reputation(modifiers.reputation_head_start);
+        self.new_game_plus = Some(modifiers);
+    }
+
+    /// Upgrade ids carried over by New Game+, for the upgrade list.
+    pub(super) fn unlocked_upgrades(&self) -> &[String] {
+        self.new_game_plus
+            .as_ref()
+            .map(|m| m.unlocked_upgrades.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_game_plus_needs_a_full_and_happy_building() {
+        let mut state = GameplayState::new();
+        assert!(!state.tenants.is_empty());
+        assert!(state.building.vacancy_count() > 0);
+        for tenant in &mut state.tenants {
+            tenant.happiness = 100;
+        }
+        state.config.win_conditions.full_occupancy_required = true;
+        assert!(!state.met_win_condition());
+
+        state.config.win_conditions.full_occupancy_required = false;
+        assert!(state.met_win_condition());
+
+        for tenant in &mut state.tenants {
+            tenant.happiness = state.config.happiness.min_for_victory - 1;
+        }
+        assert!(!state.met_win_condition());
+    }
+}
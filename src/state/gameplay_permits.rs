@@ -0,0 +1,141 @@
+// Building permits for major unit renovations. Filing costs a fee and the
+// city takes a few months to approve; fast-tracking doubles the fee for
+// approval next month. The permit is used up when the upgrade goes in.
+
+use crate::building::upgrades::UpgradeAction;
+use crate::consequences::PermitType;
+use crate::economy::{Transaction, TransactionType};
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn submit_permit_application(
+        &mut self,
+        upgrade_id: &str,
+        apartment_id: u32,
+        fast_track: bool,
+    ) {
+        match self.try_submit_permit(upgrade_id, apartment_id, fast_track) {
+            Ok(approved) => self.spawn_center_text(
+                &format!("Permit filed: approved month {}", approved),
+                0.0,
+                0.0,
+                colors::POSITIVE(),
+            ),
+            Err(reason) => self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE()),
+        }
+    }
+
+    fn try_submit_permit(
+        &mut self,
+        upgrade_id: &str,
+        apartment_id: u32,
+        fast_track: bool,
+    ) -> Result<u32, String> {
+        let building_id = self.city.active_building_index as u32;
+        if self
+            .compliance
+            .permit_for(building_id, upgrade_id, apartment_id)
+            .is_some()
+        {
+            return Err("Permit already on file".to_string());
+        }
+        let unit = self
+            .building
+            .get_apartment(apartment_id)
+            .ok_or("No such unit")?
+            .unit_number
+            .clone();
+        let (permit_type, fee) = if fast_track {
+            (PermitType::FastTrack, self.config.economy.permit_fee * 2)
+        } else {
+            (PermitType::Standard, self.config.economy.permit_fee)
+        };
+        if !self.funds.deduct_expense(Transaction::expense(
+            TransactionType::PermitFee,
+            fee,
+            &format!("Building permit (Unit {})", unit),
+            self.current_tick,
+        )) {
+            return Err(format!("Need ${} for the permit", fee));
+        }
+        Ok(self.compliance.submit_permit(
+            building_id,
+            upgrade_id,
+            apartment_id,
+            permit_type,
+            self.current_tick,
+            self.config.economy.permit_processing_months,
+        ))
+    }
+
+    /// The upgrade ID and unit an upgrade needs a permit for, if it's a unit
+    /// upgrade expensive enough to require one.
+    fn permit_needed(&self, upgrade: &UpgradeAction) -> Option<(String, u32)> {
+        let UpgradeAction::Apply {
+            upgrade_id,
+            target_id: Some(apartment_id),
+        } = upgrade
+        else {
+            return None;
+        };
+        let cost = upgrade.cost(&self.building, &self.config.economy, &self.config.upgrades)?;
+        (cost > self.config.economy.permit_cost_threshold)
+            .then(|| (upgrade_id.clone(), *apartment_id))
+    }
+
+    /// Whether an upgrade is held up waiting on a permit.
+    pub(super) fn upgrade_awaiting_permit(&self, upgrade: &UpgradeAction) -> bool {
+        self.permit_needed(upgrade)
+            .is_some_and(|(upgrade_id, apartment_id)| {
+                !self
+                    .compliance
+                    .permit_for(
+                        self.city.active_building_index as u32,
+                        &upgrade_id,
+                        apartment_id,
+                    )
+                    .is_some_and(|p| p.is_approved(self.current_tick))
+            })
+    }
+
+    /// Use up the permit for an upgrade that was just installed.
+    pub(super) fn use_upgrade_permit(&mut self, upgrade: &UpgradeAction) {
+        if let Some((upgrade_id, apartment_id)) = self.permit_needed(upgrade) {
+            self.compliance.take_approved_permit(
+                self.city.active_building_index as u32,
+                &upgrade_id,
+                apartment_id,
+                self.current_tick,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn standard_permit_charges_the_fee_and_waits() {
+        let mut state = GameplayState::new();
+        let apartment_id = state.building.apartments[0].id;
+        state.funds.balance = 10_000;
+
+        let approved = state
+            .try_submit_permit("soundproofing", apartment_id, false)
+            .unwrap();
+        assert_eq!(
+            approved,
+            state.current_tick + state.config.economy.permit_processing_months
+        );
+        assert_eq!(
+            state.funds.balance,
+            10_000 - state.config.economy.permit_fee
+        );
+        assert!(state
+            .try_submit_permit("soundproofing", apartment_id, true)
+            .is_err());
+    }
+}
@@ -0,0 +1,94 @@
+// Unlocking buildings in the persistent player progress: the next building
+// in the campaign, or one named by a mission reward.
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Unlock a specific building (by its template `unlock_order`) in the
+    /// persistent player progress — used by `MissionReward::UnlockBuilding`.
+    pub(super) fn unlock_building_by_order(&self, unlock_order: u32) {
+        use crate::data::templates::load_templates;
+        use crate::save::{load_player_progress, save_player_progress};
+
+        let mut progress = load_player_progress();
+        if let Some(templates) = load_templates() {
+            if let Some(template) = templates
+                .templates
+                .iter()
+                .find(|t| t.unlock_order == unlock_order)
+            {
+                progress.unlock_building(&template.id);
+            }
+        }
+        let _ = save_player_progress(&progress);
+    }
+
+    /// Unlock the next building after a full-term victory on the current
+    /// one. New Game+ is only earned when the building also met the win
+    /// condition in full.
+    pub(super) fn unlock_next_building(&self) {
+        use crate::data::templates::load_templates;
+        use crate::save::{load_player_progress, save_player_progress};
+
+        let mut progress = load_player_progress();
+
+        // Mark current building as completed
+        progress.mark_completed(&self.current_building_id);
+
+        // Find the next building to unlock based on unlock_order
+        if let Some(templates) = load_templates() {
+            // Find current building's unlock_order
+            let current_order = templates
+                .templates
+                .iter()
+                .find(|t| t.id == self.current_building_id)
+                .map(|t| t.unlock_order)
+                .unwrap_or(0);
+
+            // Find the next building in sequence
+            if let Some(next_template) = templates
+                .templates
+                .iter()
+                .find(|t| t.unlock_order == current_order + 1)
+            {
+                progress.unlock_building(&next_template.id);
+                if self.met_win_condition() {
+                    progress
+                        .new_game_plus
+                        .insert(next_template.id.clone(), self.earned_new_game_plus());
+                }
+            }
+        }
+
+        // Save progress
+        let _ = save_player_progress(&progress);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::load_player_progress;
+    use crate::simulation::GameOutcome;
+    use crate::state::gameplay::ViewMode;
+
+    #[test]
+    fn a_full_term_victory_completes_the_building() {
+        let mut state = GameplayState::new();
+        state.funds.balance = 1_000_000;
+        state.config.win_conditions.game_duration_ticks = Some(state.current_tick + 2);
+
+        state.end_turn();
+        assert!(state.game_outcome.is_none());
+        state.end_turn();
+
+        assert!(matches!(
+            state.game_outcome,
+            Some(GameOutcome::Victory { .. })
+        ));
+        assert_eq!(state.view_mode, ViewMode::CareerSummary);
+        assert!(load_player_progress()
+            .completed_buildings
+            .contains(&state.current_building_id));
+    }
+}
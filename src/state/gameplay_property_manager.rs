@@ -0,0 +1,218 @@
+// The hired property manager: repairs run-down units, fills vacancies, and
+// keeps rents at market, depending on how much the player hands over. Their
+// salary comes out with the rest of the operating costs.
+
+use crate::building::{AutomationLevel, PropertyManager, UpgradeAction};
+use crate::city::MarketAnalyst;
+use crate::economy::process_upgrade;
+use crate::narrative::MailItem;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Hire a manager at `level`, or move the current one to it.
+    pub(super) fn hire_property_manager(&mut self, level: AutomationLevel) {
+        let manager = PropertyManager::hire(level, &self.config.property_manager);
+        let message = format!(
+            "{} manager hired: ${}/mo",
+            level.name(),
+            manager.monthly_salary
+        );
+        self.property_manager = Some(manager);
+        self.spawn_center_text(&message, 0.0, 0.0, colors::POSITIVE());
+    }
+
+    pub(super) fn dismiss_property_manager(&mut self) {
+        if self.property_manager.take().is_some() {
+            self.spawn_center_text("Property manager dismissed", 0.0, 0.0, colors::WARNING());
+        }
+    }
+
+    /// The manager's monthly rounds of every building, reported by mail when
+    /// they did anything. Applications are only taken for the active
+    /// building, so vacancies elsewhere wait for the player.
+    pub(super) fn run_property_manager(&mut self) {
+        let Some(manager) = self.property_manager.clone() else {
+            return;
+        };
+        let level = manager.automation_level;
+        let home = self.city.active_building_index;
+        let portfolio = self.city.buildings.len() > 1;
+        let mut activity = Vec::new();
+        for index in 0..self.city.buildings.len() {
+            self.activate_building(index);
+            let mut rounds = self.manager_repairs();
+            if level.accepts_applications() && index == home {
+                rounds.extend(self.manager_fills_vacancies());
+            }
+            if level.sets_rents() {
+                rounds.extend(self.manager_sets_rents());
+            }
+            if portfolio {
+                let name = &self.building.name;
+                rounds = rounds
+                    .into_iter()
+                    .map(|line| format!("{}: {}", name, line))
+                    .collect();
+            }
+            activity.extend(rounds);
+        }
+        self.activate_building(home);
+        if activity.is_empty() {
+            return;
+        }
+        self.mailbox.receive(MailItem::manager_report(
+            0,
+            self.current_tick,
+            &manager.name,
+            &activity,
+        ));
+    }
+
+    /// Bring units below the repair threshold back up, as far as funds allow.
+    fn manager_repairs(&mut self) -> Vec<String> {
+        let threshold = self.config.property_manager.repair_below_condition;
+        let target = self.config.property_manager.repair_to_condition;
+        let run_down: Vec<(u32, String, i32)> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|apt| apt.condition < threshold)
+            .map(|apt| (apt.id, apt.unit_number.clone(), target - apt.condition))
+            .collect();
+
+        let mut activity = Vec::new();
+        for (apartment_id, unit, amount) in run_down {
+            let upgrade = UpgradeAction::RepairApartment {
+                apartment_id,
+                amount,
+            };
+            match process_upgrade(
+                &upgrade,
+                &mut self.building,
+                &mut self.funds,
+                &self.config,
+                self.current_tick,
+            ) {
+                Ok(cost) => {
+                    activity.push(format!("Repaired Unit {} for ${}", unit, cost));
+                    self.lifetime_stats.record_upgrade();
+                }
+                Err(_) => activity.push(format!("Couldn't afford repairs to Unit {}", unit)),
+            }
+        }
+        activity
+    }
+
+    /// Move the best-scoring applicant into each vacant unit.
+    fn manager_fills_vacancies(&mut self) -> Vec<String> {
+        let vacant: Vec<(u32, String)> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|apt| apt.is_vacant())
+            .filter(|apt| {
+                self.applications
+                    .iter()
+                    .any(|app| app.apartment_id == apt.id)
+            })
+            .map(|apt| (apt.id, apt.unit_number.clone()))
+            .collect();
+
+        let mut activity = Vec::new();
+        for (apartment_id, unit) in vacant {
+            self.accept_best_application(apartment_id);
+            let signed = self
+                .building
+                .get_apartment(apartment_id)
+                .and_then(|apt| apt.tenant_id)
+                .and_then(|id| self.tenants.iter().find(|t| t.id == id));
+            if let Some(tenant) = signed {
+                activity.push(format!("Leased Unit {} to {}", unit, tenant.name));
+            }
+        }
+        activity
+    }
+
+    /// Move every unit to market rent. Raises wait out a rent freeze, and a
+    /// raise already under notice is left to run its course.
+    fn manager_sets_rents(&mut self) -> Vec<String> {
+        let rent_demand = self
+            .city
+            .neighborhood_for_building(self.city.active_building_index)
+            .map(|neighborhood| neighborhood.stats.rent_demand)
+            .unwrap_or(1.0);
+        let freeze = self.rent_freeze_active();
+
+        let mut activity = Vec::new();
+        for (apartment_id, rent) in MarketAnalyst::recommended_rents(&self.building, rent_demand) {
+            let Some(apt) = self.building.get_apartment(apartment_id) else {
+                continue;
+            };
+            let unit = apt.unit_number.clone();
+            let current = apt.rent_price;
+            if rent == current
+                || (rent > current && freeze)
+                || self
+                    .compliance
+                    .pending_rent_increase(self.city.active_building_index as u32, apartment_id)
+                    .is_some()
+            {
+                continue;
+            }
+            if self.change_rent(apartment_id, rent) {
+                activity.push(format!("Set Unit {} rent to ${}", unit, rent));
+            } else {
+                activity.push(format!("Gave Unit {} notice of rent ${}", unit, rent));
+            }
+        }
+        activity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_manager_repairs_run_down_units() {
+        let mut state = GameplayState::new();
+        state.funds.balance = 100_000;
+        let threshold = state.config.property_manager.repair_below_condition;
+        let id = state.building.apartments[0].id;
+        state.building.apartments[0].condition = threshold - 20;
+
+        state.run_property_manager();
+        assert_eq!(state.building.apartments[0].condition, threshold - 20);
+
+        state.hire_property_manager(AutomationLevel::Basic);
+        state.run_property_manager();
+        let apt = state.building.get_apartment(id).unwrap();
+        assert!(apt.condition >= threshold);
+        assert_eq!(
+            state.mailbox.recent(1)[0].subject,
+            "Property Manager's Report"
+        );
+    }
+
+    #[test]
+    fn manager_repairs_every_building() {
+        let mut state = GameplayState::new();
+        state.funds.balance = 100_000;
+        let threshold = state.config.property_manager.repair_below_condition;
+        state.building.apartments[0].condition = threshold - 20;
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+
+        state.hire_property_manager(AutomationLevel::Basic);
+        state.run_property_manager();
+
+        assert_eq!(state.city.active_building_index, 1);
+        assert!(state.city.buildings[0].apartments[0].condition >= threshold);
+    }
+}
@@ -0,0 +1,107 @@
+// Emergency rent freeze: the city occasionally blocks rent increases for a
+// few months. When it lifts the landlord decides whether to catch up at once
+// or hold rents where they are.
+
+use macroquad_toolkit::rng;
+
+use crate::narrative::{rent_freeze_declared, rent_freeze_lifted};
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Whether a city rent freeze currently blocks rent increases.
+    pub(super) fn rent_freeze_active(&self) -> bool {
+        self.rent_freeze_until_tick
+            .is_some_and(|until| until > self.current_tick)
+    }
+
+    /// Months left on the rent freeze, for the header badge.
+    pub(super) fn rent_freeze_months_left(&self) -> Option<u32> {
+        self.rent_freeze_until_tick
+            .filter(|_| self.rent_freeze_active())
+            .map(|until| until - self.current_tick)
+    }
+
+    /// Monthly check: lift an expired freeze, or roll for a new one.
+    pub(super) fn check_rent_freeze(&mut self) {
+        if let Some(until) = self.rent_freeze_until_tick {
+            if until <= self.current_tick {
+                self.rent_freeze_until_tick = None;
+                let event = rent_freeze_lifted(
+                    self.current_tick,
+                    self.city.active_building_index as u32,
+                    self.config.regulations.rent_freeze_catch_up_percent,
+                );
+                self.narrative_events.add_event(event);
+            }
+            return;
+        }
+        if rng::gen_range(0, 100) >= self.config.regulations.rent_freeze_chance_percent {
+            return;
+        }
+        self.declare_rent_freeze();
+    }
+
+    fn declare_rent_freeze(&mut self) {
+        let until = self.current_tick + self.config.regulations.rent_freeze_duration;
+        self.rent_freeze_until_tick = Some(until);
+        self.narrative_events
+            .add_event(rent_freeze_declared(self.current_tick, until));
+    }
+
+    /// Raise every unit's rent by `percent`, recording each change.
+    pub(super) fn raise_all_rents(&mut self, percent: f32) {
+        if self.rent_freeze_active() {
+            return;
+        }
+        for apt in &mut self.building.apartments {
+            let old_rent = apt.rent_price;
+            apt.rent_price = (old_rent as f32 * (1.0 + percent)).round() as i32;
+            if apt.rent_price != old_rent {
+                self.gentrification.record_rent_change(
+                    0,
+                    self.current_tick,
+                    old_rent,
+                    apt.rent_price,
+                    &self.config.gentrification,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freeze_blocks_raises_until_it_expires() {
+        let mut state = GameplayState::new();
+        state.declare_rent_freeze();
+        assert!(state.rent_freeze_active());
+        assert_eq!(
+            state.rent_freeze_months_left(),
+            Some(state.config.regulations.rent_freeze_duration)
+        );
+
+        let rents: Vec<i32> = state
+            .building
+            .apartments
+            .iter()
+            .map(|a| a.rent_price)
+            .collect();
+        state.raise_all_rents(0.1);
+        let after: Vec<i32> = state
+            .building
+            .apartments
+            .iter()
+            .map(|a| a.rent_price)
+            .collect();
+        assert_eq!(rents, after);
+
+        state.current_tick += state.config.regulations.rent_freeze_duration;
+        state.check_rent_freeze();
+        assert!(!state.rent_freeze_active());
+        assert_eq!(state.rent_freeze_until_tick, None);
+    }
+}
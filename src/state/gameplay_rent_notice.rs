@@ -0,0 +1,182 @@
+// Rent increase notice law. Once the city passes it, a large increase on an
+// occupied unit is announced rather than applied: the tenant keeps paying the
+// old rent for the notice period, and one priced out by the new rent may move
+// out before it takes effect.
+
+use macroquad_toolkit::rng;
+
+use crate::narrative::NarrativeEvent;
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Set a unit's rent now, recording the change for gentrification.
+    pub(super) fn set_rent(&mut self, apartment_id: u32, new_rent: i32) {
+        let Some(apt) = self.building.get_apartment_mut(apartment_id) else {
+            return;
+        };
+        let old_rent = apt.rent_price;
+        apt.rent_price = new_rent;
+
+        if old_rent != new_rent {
+            self.gentrification.record_rent_change(
+                0,
+                self.current_tick,
+                old_rent,
+                new_rent,
+                &self.config.gentrification,
+            );
+        }
+    }
+
+    /// Set a unit's rent, or announce it if the notice law requires. Returns
+    /// false if the increase was announced instead of applied.
+    pub(super) fn change_rent(&mut self, apartment_id: u32, new_rent: i32) -> bool {
+        let Some(apt) = self.building.get_apartment(apartment_id) else {
+            return false;
+        };
+        if apt.tenant_id.is_none()
+            || !self.compliance.rent_notice_needed(
+                apt.rent_price,
+                new_rent,
+                self.config.regulations.max_immediate_rent_increase,
+            )
+        {
+            self.compliance
+                .cancel_rent_increase(self.city.active_building_index as u32, apartment_id);
+            self.set_rent(apartment_id, new_rent);
+            return true;
+        }
+        self.compliance.schedule_rent_increase(
+            self.city.active_building_index as u32,
+            apartment_id,
+            new_rent,
+            self.current_tick,
+        );
+        self.spawn_center_text(
+            &format!(
+                "Increase effective in {} months",
+                self.compliance.rent_notice.rent_increase_notice_ticks
+            ),
+            0.0,
+            0.0,
+            colors::WARNING(),
+        );
+        false
+    }
+
+    /// The city may pass the notice law; once passed it stays. Announced
+    /// increases take effect when their notice runs out, unless the tenant
+    /// moves out first. Only the active building's increases are settled;
+    /// the rest wait until their building is active again.
+    pub(super) fn check_rent_notice_law(&mut self) {
+        if !self.compliance.rent_notice.rent_increase_notice_required {
+            if rng::gen_range(0, 100) < self.config.regulations.rent_notice_law_chance_percent {
+                self.compliance.rent_notice.rent_increase_notice_required = true;
+                self.narrative_events.add_event(NarrativeEvent::news(
+                    0,
+                    self.current_tick,
+                    "City Requires Notice for Rent Increases",
+                    &format!(
+                        "Rent increases over {:.0}% on occupied units now need {} months' \
+                         notice before they take effect.",
+                        self.config.regulations.max_immediate_rent_increase * 100.0,
+                        self.compliance.rent_notice.rent_increase_notice_ticks
+                    ),
+                ));
+            }
+            return;
+        }
+
+        self.let_priced_out_tenants_leave();
+        if self.rent_freeze_active() {
+            return;
+        }
+        let building_id = self.city.active_building_index as u32;
+        for increase in self
+            .compliance
+            .take_due_rent_increases(building_id, self.current_tick)
+        {
+            self.set_rent(increase.apartment_id, increase.new_rent);
+        }
+    }
+
+    /// Tenants who can't afford an announced increase may move out during
+    /// the notice period.
+    fn let_priced_out_tenants_leave(&mut self) {
+        let leave_chance = self.config.regulations.rent_notice_leave_chance_percent;
+        let building_id = self.city.active_building_index as u32;
+        let mut leaving = Vec::new();
+        for increase in self
+            .compliance
+            .rent_notice
+            .pending_rent_increases
+            .iter()
+            .filter(|p| p.building_id == building_id)
+        {
+            let Some(tenant_id) = self
+                .building
+                .get_apartment(increase.apartment_id)
+                .and_then(|apt| apt.tenant_id)
+            else {
+                continue;
+            };
+            let Some(tenant) = self.tenants.iter().find(|t| t.id == tenant_id) else {
+                continue;
+            };
+            if increase.new_rent > tenant.archetype.preferences().ideal_rent_max
+                && rng::gen_range(0, 100) < leave_chance
+            {
+                leaving.push((increase.apartment_id, tenant_id, tenant.name.clone()));
+            }
+        }
+
+        for (apartment_id, tenant_id, name) in leaving {
+            self.compliance
+                .cancel_rent_increase(building_id, apartment_id);
+            if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
+                apt.move_out(self.current_tick);
+            }
+            self.tenants.retain(|t| t.id != tenant_id);
+            self.tenant_stories.remove(&tenant_id);
+            self.event_log.log(
+                GameEvent::TenantMovedOut {
+                    message: format!(
+                        "{} moved out rather than pay the announced rent increase.",
+                        name
+                    ),
+                },
+                self.current_tick,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_increase_on_occupied_unit_is_announced() {
+        let mut state = GameplayState::new();
+        state.compliance.rent_notice.rent_increase_notice_required = true;
+        let apt = &mut state.building.apartments[0];
+        apt.tenant_id = Some(900);
+        let (apartment_id, rent) = (apt.id, apt.rent_price);
+
+        assert!(!state.change_rent(apartment_id, rent * 2));
+        assert_eq!(state.building.apartments[0].rent_price, rent);
+        let building_id = state.city.active_building_index as u32;
+        assert!(state
+            .compliance
+            .pending_rent_increase(building_id, apartment_id)
+            .is_some());
+
+        state.current_tick += state.compliance.rent_notice.rent_increase_notice_ticks;
+        state.rent_freeze_until_tick = None;
+        state.check_rent_notice_law();
+        assert_eq!(state.building.apartments[0].rent_price, rent * 2);
+    }
+}
@@ -0,0 +1,271 @@
+// Rent strikes: a union ignored twice running stops paying until the
+// landlord meets its demands or the strike runs out. A strike belongs to one
+// building and only runs while that building is the active one.
+
+use crate::building::RENT_STRIKE_FLAG;
+use crate::consequences::{RentStrike, StrikeDemand};
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::rent_strike;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn record_union_demand_answer(&mut self, met: bool) {
+        if met {
+            self.union_demands_ignored = 0;
+            return;
+        }
+        self.union_demands_ignored += 1;
+        self.start_overdue_rent_strike();
+    }
+
+    /// Walk out once enough demands have been ignored, as soon as the
+    /// union's building is the active one.
+    pub(super) fn start_overdue_rent_strike(&mut self) {
+        if self.union_demands_ignored >= self.config.gentrification.strike_after_ignored_demands
+            && self.rent_strike.is_none()
+            && self.union_building_active()
+        {
+            self.start_rent_strike();
+        }
+    }
+
+    /// The strike, if it is against the active building.
+    pub(super) fn active_rent_strike(&self) -> Option<&RentStrike> {
+        self.rent_strike
+            .as_ref()
+            .filter(|strike| strike.building_id as usize == self.city.active_building_index)
+    }
+
+    fn start_rent_strike(&mut self) {
+        let strike = RentStrike::against(
+            &self.building,
+            self.city.active_building_index as u32,
+            &self.config.gentrification,
+        );
+        let demands: Vec<String> = strike.demands.iter().map(StrikeDemand::label).collect();
+        self.rent_strike = Some(strike);
+        self.join_rent_strike();
+
+        let event = rent_strike(self.current_tick, &self.building.name, &demands);
+        self.narrative_events.add_event(event);
+        self.spawn_center_text("Rent strike!", 0.0, 30.0, colors::NEGATIVE());
+    }
+
+    /// Half of the units not yet on strike (the unhappiest first, and at
+    /// least one) stop paying rent.
+    fn join_rent_strike(&mut self) {
+        let mut holdouts: Vec<(i32, u32)> = self
+            .building
+            .apartments
+            .iter()
+            .filter(|a| !a.on_rent_strike() && !self.building.is_unit_sold(a.id))
+            .filter_map(|a| {
+                let tenant = self.tenants.iter().find(|t| Some(t.id) == a.tenant_id)?;
+                Some((tenant.happiness, a.id))
+            })
+            .collect();
+        holdouts.sort();
+        let joining = holdouts.len().div_ceil(2);
+        for (_, apt_id) in holdouts.into_iter().take(joining) {
+            if let Some(apt) = self.building.get_apartment_mut(apt_id) {
+                apt.flags.insert(RENT_STRIKE_FLAG.to_string());
+            }
+        }
+    }
+
+    pub(super) fn tick_rent_strike(&mut self) {
+        let active = self.city.active_building_index;
+        let Some(strike) = self
+            .rent_strike
+            .as_mut()
+            .filter(|strike| strike.building_id as usize == active)
+        else {
+            return;
+        };
+        if strike.tick() {
+            self.end_rent_strike();
+            self.spawn_center_text("The rent strike is over", 0.0, 30.0, colors::ACCENT());
+        }
+    }
+
+    fn end_rent_strike(&mut self) {
+        let Some(strike) = self.rent_strike.take() else {
+            return;
+        };
+        self.union_demands_ignored = 0;
+        let building = self.building_at_mut(strike.building_id as usize);
+        for apt in building.into_iter().flat_map(|b| &mut b.apartments) {
+            apt.flags.remove(RENT_STRIKE_FLAG);
+        }
+    }
+
+    pub(super) fn answer_strike_demands(&mut self, accept_all: bool) {
+        if self.active_rent_strike().is_none() {
+            return;
+        }
+        if accept_all {
+            match self.meet_strike_demands() {
+                Ok(()) => self.spawn_center_text("Strike settled", 0.0, 0.0, colors::POSITIVE()),
+                Err(reason) => self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE()),
+            }
+        } else if let Some(strike) = self.rent_strike.as_mut() {
+            strike.ticks_until_resolution += self.config.gentrification.rent_strike_extension;
+            self.join_rent_strike();
+            self.spawn_center_text("More tenants join the strike", 0.0, 0.0, colors::NEGATIVE());
+        }
+    }
+
+    fn meet_strike_demands(&mut self) -> Result<(), String> {
+        let Some(strike) = self.active_rent_strike() else {
+            return Err("No strike to settle".to_string());
+        };
+        let demands = strike.demands.clone();
+
+        let repair_cost = demands
+            .iter()
+            .find_map(|d| match d {
+                StrikeDemand::RepairBuilding { min_condition } => {
+                    Some(self.strike_repair_cost(*min_condition))
+                }
+                _ => None,
+            })
+            .unwrap_or(0);
+        if repair_cost > 0
+            && !self.funds.deduct_expense(Transaction::expense(
+                TransactionType::RepairCost,
+                repair_cost,
+                "Repairs demanded by the rent strike",
+                self.current_tick,
+            ))
+        {
+            return Err(format!("Repairs would cost ${}", repair_cost));
+        }
+
+        for demand in &demands {
+            match demand {
+                StrikeDemand::ReduceRent { max_rent } => {
+                    for apt in &mut self.building.apartments {
+                        apt.rent_price = apt.rent_price.min(*max_rent);
+                    }
+                }
+                StrikeDemand::RepairBuilding { min_condition } => {
+                    for apt in &mut self.building.apartments {
+                        apt.condition = apt.condition.max(*min_condition);
+                    }
+                    self.building.hallway_condition =
+                        self.building.hallway_condition.max(*min_condition);
+                }
+                // A promise, kept by not pushing anyone out
+                StrikeDemand::StopEvictions => {}
+            }
+        }
+
+        let settlement = self.config.gentrification.strike_settlement_happiness;
+        for tenant in &mut self.tenants {
+            tenant.happiness = (tenant.happiness + settlement).clamp(0, 100);
+        }
+        self.end_rent_strike();
+        self.tenant_union = None;
+        Ok(())
+    }
+
+    fn strike_repair_cost(&self, min_condition: i32) -> i32 {
+        let economy = &self.config.economy;
+        let unit_points: i32 = self
+            .building
+            .apartments
+            .iter()
+            .map(|a| (min_condition - a.condition).max(0))
+            .sum();
+        let hallway_points = (min_condition - self.building.hallway_condition).max(0);
+        unit_points * economy.repair_cost_per_point
+            + hallway_points * economy.hallway_repair_cost_per_point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{Tenant, TenantArchetype};
+
+    fn striking_state() -> GameplayState {
+        let mut state = GameplayState::new();
+        state.tenants.clear();
+        for apt in &mut state.building.apartments {
+            apt.tenant_id = None;
+            apt.condition = 90;
+        }
+        state.building.hallway_condition = 90;
+        for i in 0..4 {
+            let apt_id = state.building.apartments[i].id;
+            let mut tenant = Tenant::new(800 + i as u32, "Striker", TenantArchetype::Student);
+            tenant.apartment_id = Some(apt_id);
+            tenant.happiness = 30;
+            state.building.apartments[i].move_in(tenant.id);
+            state.tenants.push(tenant);
+        }
+        state.tenant_union = Some(state.city.active_building_index as u32);
+        state.record_union_demand_answer(false);
+        state.record_union_demand_answer(false);
+        state
+    }
+
+    fn striking_units(state: &GameplayState) -> usize {
+        state
+            .building
+            .apartments
+            .iter()
+            .filter(|a| a.on_rent_strike())
+            .count()
+    }
+
+    #[test]
+    fn second_ignored_demand_starts_a_strike() {
+        let state = striking_state();
+        assert!(state.rent_strike.is_some());
+        assert_eq!(striking_units(&state), 2);
+    }
+
+    #[test]
+    fn rejecting_demands_spreads_the_strike() {
+        let mut state = striking_state();
+        state.answer_strike_demands(false);
+        assert_eq!(striking_units(&state), 3);
+        assert_eq!(
+            state.rent_strike.as_ref().unwrap().ticks_until_resolution,
+            state.config.gentrification.rent_strike_duration
+                + state.config.gentrification.rent_strike_extension
+        );
+    }
+
+    #[test]
+    fn meeting_demands_ends_strike_and_union() {
+        let mut state = striking_state();
+        state.answer_strike_demands(true);
+        assert!(state.rent_strike.is_none());
+        assert_eq!(state.tenant_union, None);
+        assert_eq!(striking_units(&state), 0);
+        assert!(state.tenants.iter().all(|t| t.happiness == 50));
+    }
+
+    #[test]
+    fn strike_only_runs_while_its_building_is_active() {
+        let mut state = striking_state();
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+
+        assert!(state.active_rent_strike().is_none());
+        state.tick_rent_strike();
+        state.answer_strike_demands(false);
+        assert_eq!(
+            state.rent_strike.as_ref().unwrap().ticks_until_resolution,
+            state.config.gentrification.rent_strike_duration
+        );
+    }
+}
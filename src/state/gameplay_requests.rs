@@ -0,0 +1,218 @@
+// Negotiating tenant requests. Pet requests can be approved on condition of a
+// deposit, quiet hours or a trial period; trial approvals are revoked when
+// they run out, and a noise complaint next to a quiet-hours pet counts
+// against its owner. Home business requests can be countered with limited
+// hours, which the tenant may turn down.
+
+use crate::building::PET_NOISE_RESTRICTED_FLAG;
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::{
+    ApprovedCondition, RequestCondition, StoryImpact, TenantRequest, REJECTED_COUNTER_HAPPINESS_HIT,
+};
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+use macroquad_toolkit::rng;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Approve a tenant's pending pet request with `condition` attached.
+    /// Other requests only take a plain approve or deny.
+    pub(super) fn approve_request_with_condition(
+        &mut self,
+        tenant_id: u32,
+        condition: RequestCondition,
+    ) {
+        let Some(story) = self.tenant_stories.get_mut(&tenant_id) else {
+            return;
+        };
+        if !matches!(story.pending_request, Some(TenantRequest::Pet { .. })) {
+            return;
+        }
+        let Some(request) = story.pending_request.take() else {
+            return;
+        };
+        let effect = condition.approval_effect(&request);
+        story.add_event(
+            self.current_tick,
+            "Request approved with conditions",
+            effect.clone(),
+        );
+        story.approved_conditions.push(ApprovedCondition {
+            request,
+            condition: condition.clone(),
+            approved_tick: self.current_tick,
+        });
+
+        match condition {
+            RequestCondition::PetDeposit(amount) => {
+                if let Some(tenant) = self.tenants.iter_mut().find(|t| t.id == tenant_id) {
+                    tenant.security_deposit += amount;
+                    self.funds.add_income(Transaction::income(
+                        TransactionType::PetDeposit,
+                        amount,
+                        &format!("Pet deposit ({})", tenant.name),
+                        self.current_tick,
+                    ));
+                }
+            }
+            RequestCondition::NoiseRestriction => {
+                let apartment_id = self
+                    .tenants
+                    .iter()
+                    .find(|t| t.id == tenant_id)
+                    .and_then(|t| t.apartment_id);
+                if let Some(apt) = apartment_id.and_then(|id| self.building.get_apartment_mut(id)) {
+                    apt.flags.insert(PET_NOISE_RESTRICTED_FLAG.to_string());
+                }
+            }
+            RequestCondition::TimeLimit(_) => {}
+        }
+        self.apply_story_impact(tenant_id, effect);
+    }
+
+    /// Propose `modified_request` in place of the tenant's pending home
+    /// business request. The tenant weighs it by their opinion of the
+    /// landlord and how much it cuts back; either way the request is settled.
+    pub(super) fn counter_offer(&mut self, tenant_id: u32, modified_request: TenantRequest) {
+        let Some(opinion) = self
+            .tenants
+            .iter()
+            .find(|t| t.id == tenant_id)
+            .map(|t| t.landlord_opinion)
+        else {
+            return;
+        };
+        let Some(story) = self.tenant_stories.get_mut(&tenant_id) else {
+            return;
+        };
+        if !matches!(
+            story.pending_request,
+            Some(TenantRequest::HomeBusiness { .. })
+        ) {
+            return;
+        }
+        story.pending_request = None;
+
+        let accepted =
+            rng::gen_range(0.0, 1.0) < modified_request.counter_acceptance_chance(opinion);
+        let (description, effect) = if accepted {
+            (
+                "Accepted the landlord's counter offer",
+                modified_request.approval_effect(),
+            )
+        } else {
+            (
+                "Turned down the landlord's counter offer",
+                StoryImpact::Happiness(-REJECTED_COUNTER_HAPPINESS_HIT),
+            )
+        };
+        story.add_event(self.current_tick, description, effect.clone());
+        self.apply_story_impact(tenant_id, effect);
+
+        let (text, color) = if accepted {
+            ("Counter offer accepted", colors::POSITIVE())
+        } else {
+            ("Counter offer rejected", colors::NEGATIVE())
+        };
+        self.spawn_center_text(text, 0.0, 0.0, color);
+    }
+
+    /// Revoke trial approvals that have run out, as if the request had been
+    /// denied.
+    pub(super) fn expire_request_conditions(&mut self) {
+        let mut revoked = Vec::new();
+        for (&tenant_id, story) in &mut self.tenant_stories {
+            let (expired, kept): (Vec<_>, Vec<_>) = story
+                .approved_conditions
+                .drain(..)
+                .partition(|c| c.expired(self.current_tick));
+            story.approved_conditions = kept;
+            for condition in expired {
+                let effect = condition.request.denial_effect();
+                story.add_event(self.current_tick, "Trial approval ended", effect.clone());
+                revoked.push((tenant_id, effect));
+            }
+        }
+        for (tenant_id, effect) in revoked {
+            self.apply_story_impact(tenant_id, effect);
+        }
+    }
+
+    /// A noise complaint on the same floor as a quiet-hours pet breaks the
+    /// condition: the owner hears about it.
+    pub(super) fn check_pet_noise_restrictions(&mut self, events: &[GameEvent]) {
+        for event in events {
+            let GameEvent::NoiseComplaint { tenant_id, .. } = event else {
+                continue;
+            };
+            let Some(complainant) = self.tenants.iter().find(|t| t.id == *tenant_id) else {
+                continue;
+            };
+            let complainant_name = complainant.name.clone();
+            let Some(home) = complainant
+                .apartment_id
+                .and_then(|id| self.building.get_apartment(id))
+            else {
+                continue;
+            };
+            let owners: Vec<u32> = self
+                .building
+                .apartments
+                .iter()
+                .filter(|apt| apt.floor == home.floor && apt.id != home.id)
+                .filter(|apt| apt.flags.contains(PET_NOISE_RESTRICTED_FLAG))
+                .filter_map(|apt| apt.tenant_id)
+                .collect();
+            for owner_id in owners {
+                let Some(owner) = self.tenants.iter().find(|t| t.id == owner_id) else {
+                    continue;
+                };
+                self.event_log.log(
+                    GameEvent::Notification {
+                        message: format!(
+                            "{} complained about {}'s pet despite the quiet hours.",
+                            complainant_name, owner.name
+                        ),
+                        level: crate::simulation::NotificationLevel::Warning,
+                    },
+                    self.current_tick,
+                );
+                let impact =
+                    StoryImpact::Happiness(-self.config.happiness.pet_noise_complaint_penalty);
+                if let Some(story) = self.tenant_stories.get_mut(&owner_id) {
+                    story.add_event(
+                        self.current_tick,
+                        "Neighbors complained about the pet",
+                        impact.clone(),
+                    );
+                }
+                self.apply_story_impact(owner_id, impact);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pet_deposit_is_collected_and_held() {
+        let mut state = GameplayState::new();
+        let tenant = crate::tenant::Tenant::new(1, "Pat", crate::tenant::TenantArchetype::Artist);
+        let mut story = crate::narrative::TenantStory::generate(1, &tenant.archetype);
+        story.pending_request = Some(TenantRequest::Pet {
+            pet_type: "dog".to_string(),
+        });
+        state.tenant_stories.insert(1, story);
+        state.tenants.push(tenant);
+        let before = state.funds.balance;
+
+        state.approve_request_with_condition(1, RequestCondition::PetDeposit(300));
+        assert_eq!(state.funds.balance, before + 300);
+        assert_eq!(state.tenants.last().unwrap().security_deposit, 300);
+        assert!(state.tenant_stories[&1].pending_request.is_none());
+        assert_eq!(state.tenant_stories[&1].approved_conditions.len(), 1);
+    }
+}
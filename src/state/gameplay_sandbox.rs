@@ -0,0 +1,59 @@
+// Sandbox mode: no win or loss, and optionally a bottomless bank account.
+
+use crate::economy::{Transaction, TransactionType};
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+/// Infinite funds keep the balance at least this high.
+const SANDBOX_FUNDS_FLOOR: i32 = 100_000;
+
+impl GameplayState {
+    pub(super) fn toggle_sandbox_funds(&mut self) {
+        if !self.sandbox_mode {
+            return;
+        }
+        self.sandbox_infinite_funds = !self.sandbox_infinite_funds;
+        let label = if self.sandbox_infinite_funds {
+            "Infinite funds on"
+        } else {
+            "Infinite funds off"
+        };
+        self.spawn_center_text(label, 0.0, 0.0, colors::ACCENT());
+        self.top_up_sandbox_funds();
+    }
+
+    /// Refill the balance to the sandbox floor when infinite funds are on.
+    pub(super) fn top_up_sandbox_funds(&mut self) {
+        if !self.sandbox_mode || !self.sandbox_infinite_funds {
+            return;
+        }
+        let shortfall = SANDBOX_FUNDS_FLOOR - self.funds.balance;
+        if shortfall > 0 {
+            self.funds.add_income(Transaction::income(
+                TransactionType::Grant,
+                shortfall,
+                "Sandbox top-up",
+                self.current_tick,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infinite_funds_only_work_in_sandbox() {
+        let mut state = GameplayState::new();
+        state.funds.balance = 10;
+        state.toggle_sandbox_funds();
+        assert!(!state.sandbox_infinite_funds);
+
+        state.sandbox_mode = true;
+        state.toggle_sandbox_funds();
+        assert!(state.sandbox_infinite_funds);
+        assert_eq!(state.funds.balance, SANDBOX_FUNDS_FLOOR);
+    }
+}
@@ -0,0 +1,167 @@
+// Scenario mode: building a game from a scenario's state patch and checking
+// its objectives against the clock each month.
+
+use crate::data::config::{DifficultyPreset, GameConfig};
+use crate::data::templates::BuildingTemplate;
+use crate::narrative::scenarios::merge_json;
+use crate::narrative::{ActiveScenario, Scenario, TutorialMilestone};
+use crate::simulation::GameOutcome;
+use crate::ui::colors;
+
+use super::gameplay::{generate_run_seed, GameplayState, ViewMode};
+use super::mission_system::{goal_met, MonthSignals};
+
+impl GameplayState {
+    /// A fresh game on `template` with the scenario's patch applied on top.
+    pub(super) fn new_scenario(
+        config: GameConfig,
+        template: BuildingTemplate,
+        scenario: &Scenario,
+    ) -> Result<Self, String> {
+        // Built straight from the seed so a scenario never claims the
+        // campaign's pending New Game+ bonus.
+        let base = Self::new_with_template_seed(
+            config,
+            template,
+            DifficultyPreset::Normal,
+            generate_run_seed(),
+        );
+        let mut json = serde_json::to_value(&base).map_err(|e| e.to_string())?;
+        merge_json(&mut json, &scenario.initial_state_patch);
+        let mut state: Self = serde_json::from_value(json).map_err(|e| e.to_string())?;
+        state.post_load();
+        state.clear_squatted_units();
+
+        // Scenarios are for players who know the ropes.
+        state.tutorial.active = false;
+        state.tutorial.current_milestone = Some(TutorialMilestone::Complete);
+        state.tutorial.pending_messages.clear();
+
+        state.scenario = Some(ActiveScenario::start(scenario, state.current_tick));
+        Ok(state)
+    }
+
+    /// A patch that hands units to squatters displaces whoever the template
+    /// had living there.
+    fn clear_squatted_units(&mut self) {
+        for apt in &mut self.building.apartments {
+            if !apt.has_squatter() {
+                continue;
+            }
+            if let Some(tenant_id) = apt.tenant_id.take() {
+                self.tenants.retain(|t| t.id != tenant_id);
+                self.tenant_stories.remove(&tenant_id);
+            }
+        }
+        self.save_building_to_city();
+    }
+
+    /// Mark newly met objectives, then end the scenario on success or when
+    /// the clock runs out.
+    pub(super) fn check_scenario_progress(&mut self) {
+        let Some(scenario) = &self.scenario else {
+            return;
+        };
+        if self.game_outcome.is_some() {
+            return;
+        }
+        let signals = MonthSignals::gather(self);
+        let started = scenario.started_tick;
+        let mut objectives = scenario.objectives.clone();
+        let mut newly_met = Vec::new();
+        for (i, objective) in objectives.iter_mut().enumerate() {
+            if !scenario.completed[i] && goal_met(objective, started, self, &signals) {
+                newly_met.push(i);
+            }
+        }
+
+        let Some(scenario) = &mut self.scenario else {
+            return;
+        };
+        scenario.objectives = objectives;
+        for &i in &newly_met {
+            scenario.completed[i] = true;
+        }
+        let outcome = if scenario.is_complete() {
+            Some(GameOutcome::Victory {
+                score: scenario.score(self.current_tick),
+                months: self.current_tick - scenario.started_tick,
+                total_income: self.funds.total_income,
+            })
+        } else if scenario.is_out_of_time(self.current_tick) {
+            Some(GameOutcome::ScenarioFailed {
+                score: scenario.score(self.current_tick),
+            })
+        } else {
+            None
+        };
+
+        if !newly_met.is_empty() && outcome.is_none() {
+            self.spawn_center_text("Objective complete!", 0.0, -30.0, colors::POSITIVE());
+        }
+        if outcome.is_some() {
+            self.game_outcome = outcome;
+            self.view_mode = ViewMode::CareerSummary;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::narrative::load_scenarios;
+    use crate::simulation::GameOutcome;
+    use crate::state::GameplayState;
+
+    #[test]
+    fn scenario_patch_applies_to_the_new_game() {
+        let base = GameplayState::new();
+        let scenario = load_scenarios()
+            .into_iter()
+            .find(|s| s.id == "shoestring")
+            .expect("the shoestring scenario");
+        let template = crate::data::templates::load_templates()
+            .and_then(|t| t.templates.into_iter().next())
+            .expect("a building template");
+        let state = GameplayState::new_scenario(base.config.clone(), template, &scenario)
+            .expect("scenario builds");
+        assert_eq!(state.funds.balance, 1000);
+        assert!(!state.tutorial.active);
+        assert!(state.scenario.is_some());
+    }
+
+    #[test]
+    fn squatted_units_start_without_the_template_tenant() {
+        let base = GameplayState::new();
+        let scenario = load_scenarios()
+            .into_iter()
+            .find(|s| s.id == "the_hoarder")
+            .expect("the hoarder scenario");
+        let template = crate::data::templates::load_templates()
+            .and_then(|t| t.templates.into_iter().next())
+            .expect("a building template");
+        let state = GameplayState::new_scenario(base.config.clone(), template, &scenario)
+            .expect("scenario builds");
+        assert!(state.tenants.is_empty());
+        assert!(state
+            .building
+            .apartments
+            .iter()
+            .all(|apt| apt.tenant_id.is_none()));
+    }
+
+    #[test]
+    fn running_out_of_time_fails_the_scenario() {
+        let mut state = GameplayState::new();
+        let scenario = load_scenarios()
+            .into_iter()
+            .find(|s| s.id == "shoestring")
+            .expect("the shoestring scenario");
+        state.scenario = Some(crate::narrative::ActiveScenario::start(&scenario, 0));
+        state.current_tick = scenario.time_limit_ticks.unwrap_or(0);
+        state.check_scenario_progress();
+        assert!(matches!(
+            state.game_outcome,
+            Some(GameOutcome::ScenarioFailed { .. })
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+// Neighborhood special assessments: paying the bill, spreading it over a few
+// months, or fighting it at a hearing.
+
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::{special_assessment_improvements, AssessmentResponse};
+use crate::ui::colors;
+use macroquad_toolkit::rng;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    pub(super) fn respond_to_special_assessment(
+        &mut self,
+        neighborhood_id: u32,
+        amount: i32,
+        response: AssessmentResponse,
+    ) {
+        match response {
+            AssessmentResponse::Pay => self.pay_special_assessment(neighborhood_id, amount),
+            AssessmentResponse::Finance => {
+                self.compliance.finance_assessment(
+                    neighborhood_id,
+                    amount,
+                    self.config.special_assessments.finance_months,
+                );
+                self.apply_narrative_effect(&special_assessment_improvements(
+                    neighborhood_id,
+                    &self.config.special_assessments,
+                ));
+            }
+            AssessmentResponse::Challenge => {
+                self.compliance.challenge_assessment(
+                    neighborhood_id,
+                    amount,
+                    self.current_tick,
+                    self.config.special_assessments.challenge_months,
+                );
+                self.spawn_center_text("Assessment challenged", 0.0, 0.0, colors::WARNING());
+            }
+        }
+    }
+
+    /// Settle challenged assessments whose hearing has come: some bills are
+    /// reduced, the rest are due in full.
+    pub(super) fn resolve_assessment_challenges(&mut self) {
+        let config = self.config.special_assessments.clone();
+        for challenge in self.compliance.take_resolved_assessments(self.current_tick) {
+            let amount = if rng::gen_range(0.0, 1.0) < config.challenge_success_chance {
+                self.spawn_center_text("Assessment reduced", 0.0, 0.0, colors::POSITIVE());
+                (challenge.amount as f32 * config.reduced_fraction).round() as i32
+            } else {
+                self.spawn_center_text("Assessment upheld", 0.0, 0.0, colors::NEGATIVE());
+                challenge.amount
+            };
+            self.pay_special_assessment(challenge.neighborhood_id, amount);
+        }
+    }
+
+    /// This month's installment on each financed assessment.
+    pub(super) fn pay_assessment_installments(&mut self) {
+        for (neighborhood_id, payment) in self.compliance.take_assessment_installments() {
+            let name = self.special_assessment_name(neighborhood_id);
+            self.funds.apply_required_expense(Transaction::expense(
+                TransactionType::PropertyTax,
+                payment,
+                &format!("{} installment", name),
+                self.current_tick,
+            ));
+        }
+    }
+
+    fn pay_special_assessment(&mut self, neighborhood_id: u32, amount: i32) {
+        let name = self.special_assessment_name(neighborhood_id);
+        self.funds.apply_required_expense(Transaction::expense(
+            TransactionType::PropertyTax,
+            amount,
+            &name,
+            self.current_tick,
+        ));
+        self.apply_narrative_effect(&special_assessment_improvements(
+            neighborhood_id,
+            &self.config.special_assessments,
+        ));
+    }
+
+    fn special_assessment_name(&self, neighborhood_id: u32) -> String {
+        let neighborhood = self
+            .city
+            .neighborhoods
+            .iter()
+            .find(|n| n.id == neighborhood_id)
+            .map_or("Neighborhood", |n| n.name.as_str());
+        format!("{} special assessment", neighborhood)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn financing_spreads_the_bill_and_still_improves_the_neighborhood() {
+        let mut state = GameplayState::new();
+        let neighborhood_id = state.city.neighborhoods[0].id;
+        let transit = state.city.neighborhoods[0].stats.transit_access;
+        let balance = state.funds.balance;
+
+        state.respond_to_special_assessment(neighborhood_id, 3_000, AssessmentResponse::Finance);
+
+        assert_eq!(state.funds.balance, balance);
+        assert!(state.active_mortgages.is_empty());
+        let debt = &state.compliance.special_assessments.financed_assessments[0];
+        assert_eq!(debt.monthly_payment, 500);
+        assert_eq!(
+            debt.months_remaining,
+            state.config.special_assessments.finance_months
+        );
+
+        state.pay_assessment_installments();
+        assert_eq!(state.funds.balance, balance - 500);
+        assert_eq!(
+            state.city.neighborhoods[0].stats.transit_access,
+            (transit + 5).min(100)
+        );
+    }
+}
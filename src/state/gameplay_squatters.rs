@@ -0,0 +1,96 @@
+// Squatters: units left empty too long get moved into. They have no lease,
+// so removing one is cheap and quick, but left alone they wreck the place.
+
+use crate::building::SQUATTER_EVICTION_FLAG;
+use crate::consequences::EvictionReason;
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::NarrativeEvent;
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Turn this tick's squatter discoveries into news the player can act on.
+    pub(super) fn report_squatters(&mut self, events: &[GameEvent]) {
+        for event in events {
+            let GameEvent::SquatterFound { apartment_unit } = event else {
+                continue;
+            };
+            let news = NarrativeEvent::news(
+                0,
+                self.current_tick,
+                &format!("Squatter Found in Unit {}", apartment_unit),
+                &format!(
+                    "Someone has moved into the empty Unit {} without a lease. \
+                     Have them removed before they do real damage.",
+                    apartment_unit
+                ),
+            );
+            self.narrative_events.add_event(news);
+        }
+    }
+
+    pub(super) fn begin_eviction(&mut self, apartment_id: u32, reason: Option<EvictionReason>) {
+        let result = match reason {
+            Some(reason) => self.try_evict_tenant(apartment_id, reason),
+            None => self.try_begin_eviction(apartment_id),
+        };
+        match result {
+            Ok(()) => self.spawn_center_text("Eviction filed", 0.0, 0.0, colors::POSITIVE()),
+            Err(reason) => self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE()),
+        }
+    }
+
+    /// Squatters have no lease, so removal is a flat fee and done by next
+    /// month. Paying tenants can't be evicted this way.
+    fn try_begin_eviction(&mut self, apartment_id: u32) -> Result<(), String> {
+        let apt = self
+            .building
+            .get_apartment(apartment_id)
+            .ok_or("No such unit")?;
+        if !apt.has_squatter() {
+            return Err("No squatter to evict".to_string());
+        }
+        if apt.flags.contains(SQUATTER_EVICTION_FLAG) {
+            return Err("Eviction already under way".to_string());
+        }
+        let cost = self.config.economy.squatter_removal_cost;
+        let unit = apt.unit_number.clone();
+        if !self.funds.deduct_expense(Transaction::expense(
+            TransactionType::SquatterRemoval,
+            cost,
+            &format!("Squatter removal (Unit {})", unit),
+            self.current_tick,
+        )) {
+            return Err(format!("Need ${} to evict", cost));
+        }
+        if let Some(apt) = self.building.get_apartment_mut(apartment_id) {
+            apt.flags.insert(SQUATTER_EVICTION_FLAG.to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eviction_only_applies_to_squatters() {
+        let mut state = GameplayState::new();
+        let apt_id = state.building.apartments[0].id;
+        assert!(state.try_begin_eviction(apt_id).is_err());
+
+        state.building.apartments[0]
+            .flags
+            .insert("squatter".to_string());
+        let before = state.funds.balance;
+        assert!(state.try_begin_eviction(apt_id).is_ok());
+        assert_eq!(
+            state.funds.balance,
+            before - state.config.economy.squatter_removal_cost
+        );
+        assert!(state.try_begin_eviction(apt_id).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+// Yearly property tax reassessment of every building the player owns, and
+// appeals against steep increases.
+
+use crate::city::appraise_building;
+use crate::economy::{Transaction, TransactionType};
+use crate::narrative::MailItem;
+use crate::simulation::GameEvent;
+use crate::ui::colors;
+use macroquad_toolkit::rng;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Re-appraise every building once a year and mail each result.
+    pub(super) fn check_tax_assessment(&mut self) {
+        let config = &self.config.operating_costs;
+        if !self
+            .compliance
+            .assessment_due(self.current_tick, config.assessment_interval_months)
+        {
+            return;
+        }
+        for index in 0..self.city.buildings.len() {
+            let (Some(building), Some(neighborhood)) = (
+                self.building_at(index),
+                self.city.neighborhood_for_building(index),
+            ) else {
+                continue;
+            };
+            let value = appraise_building(building, neighborhood, &self.config);
+            let name = building.name.clone();
+            let building_id = index as u32;
+            let assessment = self.compliance.reassess(
+                building_id,
+                value,
+                self.current_tick,
+                config.assessment_appeal_threshold,
+            );
+
+            self.event_log.log(
+                GameEvent::PropertyTaxAssessment {
+                    old_value: assessment.previous_value,
+                    new_value: assessment.value,
+                },
+                self.current_tick,
+            );
+            self.mailbox.receive(MailItem::tax_assessment(
+                0,
+                self.current_tick,
+                building_id,
+                &name,
+                &assessment,
+                config.assessment_appeal_fee,
+            ));
+        }
+    }
+
+    /// Pay the filing fee and take the assessment to appeal; some appeals
+    /// restore the previous value.
+    pub(super) fn contest_tax_assessment(&mut self, building_id: u32) {
+        let fee = self.config.operating_costs.assessment_appeal_fee;
+        let open = self
+            .compliance
+            .tax_assessments
+            .property_assessments
+            .get(&building_id)
+            .is_some_and(|assessment| assessment.appeal_open);
+        if !open || !self.funds.can_afford(fee) {
+            return;
+        }
+        self.funds.deduct_expense(Transaction::expense(
+            TransactionType::LegalFees,
+            fee,
+            "Tax assessment appeal",
+            self.current_tick,
+        ));
+
+        let won =
+            rng::gen_range(0.0, 1.0) < self.config.operating_costs.assessment_appeal_success_chance;
+        self.compliance.settle_assessment_appeal(building_id, won);
+        if won {
+            self.spawn_center_text(
+                "Appeal won: assessment reverted",
+                0.0,
+                0.0,
+                colors::POSITIVE(),
+            );
+        } else {
+            self.spawn_center_text("Appeal denied", 0.0, 0.0, colors::NEGATIVE());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgrades_raise_the_next_assessment() {
+        let mut state = GameplayState::new();
+        state.current_tick = 12;
+        state.check_tax_assessment();
+        let building_id = state.city.active_building_index as u32;
+        let first = state.compliance.tax_assessments.property_assessments[&building_id].value;
+
+        for apt in &mut state.building.apartments {
+            apt.condition = 100;
+        }
+        state.building.hallway_condition = 100;
+        state.current_tick = 24;
+        state.check_tax_assessment();
+
+        assert!(state.compliance.tax_assessments.property_assessments[&building_id].value > first);
+        assert!(state.compliance.property_tax_factor(building_id) > 1.0);
+        assert_eq!(
+            state.mailbox.recent(1)[0].subject,
+            "Property Tax Assessment"
+        );
+    }
+
+    #[test]
+    fn every_building_is_reassessed_not_just_the_active_one() {
+        let mut state = GameplayState::new();
+        let annex = state
+            .city
+            .neighborhoods
+            .iter()
+            .find(|n| n.can_add_building())
+            .map(|n| n.id)
+            .and_then(|id| {
+                state
+                    .city
+                    .add_building(crate::building::Building::new("Annex", 1, 2), id)
+                    .ok()
+            })
+            .expect("a neighborhood has room for the annex");
+
+        state.current_tick = state.config.operating_costs.assessment_interval_months;
+        state.check_tax_assessment();
+
+        let assessments = &state.compliance.tax_assessments.property_assessments;
+        assert!(assessments.contains_key(&(state.city.active_building_index as u32)));
+        assert!(assessments.contains_key(&annex));
+    }
+}
@@ -0,0 +1,228 @@
+// Moving between the player's buildings: switching which one is active, and
+// transferring a tenant. Only the active building is live in `self.building`
+// and `self.tenants`; every other building keeps its own tenants on its roster
+// until it is switched to.
+
+use crate::building::Building;
+use crate::tenant::Tenant;
+use crate::ui::{colors, Selection, TransferTarget};
+
+use super::gameplay::GameplayState;
+
+/// Happiness a tenant loses to the upheaval of moving.
+const TRANSFER_HAPPINESS_PENALTY: i32 = 10;
+
+impl GameplayState {
+    pub(super) fn switch_to_building(&mut self, index: usize) {
+        self.activate_building(index);
+        self.selection = Selection::None;
+
+        self.spawn_center_text("Building Changed", 0.0, 0.0, colors::ACCENT());
+    }
+
+    /// Make building `index` the live one, parking the current tenants on
+    /// their building's roster and bringing the new building's tenants in.
+    pub(super) fn activate_building(&mut self, index: usize) {
+        if index == self.city.active_building_index || index >= self.city.buildings.len() {
+            return;
+        }
+        // The tenant network is city-wide, so relationships (including
+        // cross-building ones) carry over untouched.
+        self.building.resident_tenants = std::mem::take(&mut self.tenants);
+        self.save_building_to_city();
+        self.city.switch_building(index);
+        self.sync_building();
+        self.tenants = std::mem::take(&mut self.building.resident_tenants);
+        self.save_building_to_city();
+    }
+
+    /// The live copy of building `index`: `self.building` when it is the
+    /// active one, otherwise the city's.
+    pub(super) fn building_at(&self, index: usize) -> Option<&Building> {
+        if index == self.city.active_building_index {
+            Some(&self.building)
+        } else {
+            self.city.buildings.get(index)
+        }
+    }
+
+    pub(super) fn building_at_mut(&mut self, index: usize) -> Option<&mut Building> {
+        if index == self.city.active_building_index {
+            Some(&mut self.building)
+        } else {
+            self.city.buildings.get_mut(index)
+        }
+    }
+
+    /// The tenants living in building `index`: the live roster when it is
+    /// the active one, otherwise the roster stored with it.
+    pub(super) fn tenants_at(&self, index: usize) -> &[Tenant] {
+        if index == self.city.active_building_index {
+            &self.tenants
+        } else {
+            self.city
+                .buildings
+                .get(index)
+                .map(|building| building.resident_tenants.as_slice())
+                .unwrap_or_default()
+        }
+    }
+
+    pub(super) fn tenants_at_mut(&mut self, index: usize) -> &mut [Tenant] {
+        if index == self.city.active_building_index {
+            &mut self.tenants
+        } else {
+            self.city
+                .buildings
+                .get_mut(index)
+                .map(|building| building.resident_tenants.as_mut_slice())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Does any building other than the active one still have tenants?
+    pub(super) fn has_tenants_elsewhere(&self) -> bool {
+        let active = self.city.active_building_index;
+        self.city
+            .buildings
+            .iter()
+            .enumerate()
+            .any(|(index, building)| index != active && !building.resident_tenants.is_empty())
+    }
+
+    /// First vacant unit in another of the player's buildings, if any.
+    pub(super) fn transfer_target(&self) -> Option<TransferTarget> {
+        let active = self.city.active_building_index;
+        self.city
+            .buildings
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != active)
+            .find_map(|(index, building)| {
+                let apt = building
+                    .apartments
+                    .iter()
+                    .find(|apt| apt.is_vacant() && !building.is_unit_sold(apt.id))?;
+                Some(TransferTarget {
+                    building_index: index,
+                    apartment_id: apt.id,
+                    label: format!("{} #{}", building.name, apt.unit_number),
+                })
+            })
+    }
+
+    pub(super) fn transfer_tenant(
+        &mut self,
+        tenant_id: u32,
+        target_building_index: usize,
+        target_apartment_id: u32,
+    ) {
+        match self.try_transfer_tenant(tenant_id, target_building_index, target_apartment_id) {
+            Ok(()) => {
+                self.selection = Selection::None;
+                self.spawn_center_text("Tenant transferred", 0.0, 0.0, colors::POSITIVE());
+            }
+            Err(reason) => self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE()),
+        }
+    }
+
+    fn try_transfer_tenant(
+        &mut self,
+        tenant_id: u32,
+        target_building_index: usize,
+        target_apartment_id: u32,
+    ) -> Result<(), String> {
+        // Everything in `city.buildings` belongs to the player, so a valid
+        // index is all the ownership check there is.
+        let source_index = self.city.active_building_index;
+        if target_building_index == source_index {
+            return Err("Tenant already lives here".to_string());
+        }
+        let target = self
+            .city
+            .buildings
+            .get(target_building_index)
+            .ok_or("Building not found")?;
+        let target_apt = target
+            .get_apartment(target_apartment_id)
+            .ok_or("Unit not found")?;
+        if !target_apt.is_vacant() || target.is_unit_sold(target_apartment_id) {
+            return Err("Unit is not vacant".to_string());
+        }
+        let position = self
+            .tenants
+            .iter()
+            .position(|t| t.id == tenant_id)
+            .ok_or("Tenant not found")?;
+        let source_apt = self
+            .building
+            .apartments
+            .iter_mut()
+            .find(|apt| apt.tenant_id == Some(tenant_id))
+            .ok_or("Tenant not found")?;
+        source_apt.move_out(self.current_tick);
+
+        let mut tenant = self.tenants.remove(position);
+        tenant.apartment_id = Some(target_apartment_id);
+        tenant.happiness = (tenant.happiness - TRANSFER_HAPPINESS_PENALTY).max(0);
+        let target = &mut self.city.buildings[target_building_index];
+        if let Some(apt) = target.get_apartment_mut(target_apartment_id) {
+            apt.move_in(tenant_id);
+        }
+        target.resident_tenants.push(tenant);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{Tenant, TenantArchetype};
+
+    fn state_with_two_buildings() -> GameplayState {
+        let mut state = GameplayState::new();
+        state.save_building_to_city();
+        state.city.buildings.push(Building::new("Annex", 1, 2));
+        state
+    }
+
+    #[test]
+    fn tenant_moves_to_the_other_building() {
+        let mut state = state_with_two_buildings();
+        let source_apt = state.building.apartments[0].id;
+        let mut tenant = Tenant::new(900, "Mover", TenantArchetype::Student);
+        tenant.happiness = 70;
+        tenant.apartment_id = Some(source_apt);
+        state.tenants.push(tenant);
+        state.building.apartments[0].move_in(900);
+
+        let target = state.transfer_target().expect("annex has a vacancy");
+        state
+            .try_transfer_tenant(900, target.building_index, target.apartment_id)
+            .expect("transfer succeeds");
+
+        assert!(state.building.apartments[0].is_vacant());
+        assert!(!state.tenants.iter().any(|t| t.id == 900));
+        let annex = &state.city.buildings[target.building_index];
+        let apt = annex.get_apartment(target.apartment_id).unwrap();
+        assert_eq!(apt.tenant_id, Some(900));
+
+        state.activate_building(target.building_index);
+        let tenant = state.tenants.iter().find(|t| t.id == 900).unwrap();
+        assert_eq!(tenant.apartment_id, Some(target.apartment_id));
+        assert_eq!(tenant.happiness, 70 - TRANSFER_HAPPINESS_PENALTY);
+        assert!(state.has_tenants_elsewhere());
+    }
+
+    #[test]
+    fn occupied_target_unit_is_refused() {
+        let mut state = state_with_two_buildings();
+        state.building.apartments[0].move_in(900);
+        let annex = state.city.buildings.len() - 1;
+        let target_apt = state.city.buildings[annex].apartments[0].id;
+        state.city.buildings[annex].apartments[0].move_in(901);
+
+        assert!(state.try_transfer_tenant(900, annex, target_apt).is_err());
+        assert_eq!(state.building.apartments[0].tenant_id, Some(900));
+    }
+}
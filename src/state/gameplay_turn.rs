@@ -3,12 +3,18 @@ use macroquad_toolkit::rng;
 // neighborhood, and awards halves of the turn live in sibling modules.
 
 use crate::economy::{Transaction, TransactionType};
-use crate::simulation::{advance_tick, ActiveWorldEvent, ActiveWorldEventKind, GameEvent};
+use crate::narrative::notifications::GameNotification;
+use crate::simulation::{
+    advance_tick, ActiveWorldEvent, ActiveWorldEventKind, CostAdjustments, GameEvent, GameOutcome,
+};
 use crate::ui::colors;
 use macroquad::prelude::*;
 
 use super::gameplay::{GameplayState, ViewMode};
 
+/// Building flag set by the backup generator upgrade.
+const BACKUP_GENERATOR_FLAG: &str = "backup_generator";
+
 impl GameplayState {
     /// End the current turn and advance time.
     pub fn end_turn(&mut self) {
@@ -17,6 +23,7 @@ impl GameplayState {
         self.has_ever_had_tenant |= !self.tenants.is_empty();
 
         let reputation_multiplier = self.application_reputation_multiplier();
+        let school_rating = self.active_neighborhood_school_rating();
 
         let result = advance_tick(
             &mut self.building,
@@ -27,37 +34,85 @@ impl GameplayState {
             &mut self.event_log,
             &mut self.current_tick,
             &mut self.next_tenant_id,
-            self.has_ever_had_tenant,
+            // Tenants in the player's other buildings still count.
+            self.has_ever_had_tenant && !self.has_tenants_elsewhere(),
+            self.sandbox_mode,
             reputation_multiplier,
+            school_rating,
+            CostAdjustments {
+                manager_salary: self
+                    .property_manager
+                    .as_ref()
+                    .map_or(0, |manager| manager.monthly_salary),
+                inflation_multiplier: self.inflation_multiplier,
+                utility_rate_multiplier: self.utility_rate_multiplier,
+                property_tax_factor: self
+                    .compliance
+                    .property_tax_factor(self.city.active_building_index as u32),
+            },
             &self.config,
         );
 
+        self.notifications.restore_snoozed(self.current_tick);
+        self.top_up_sandbox_funds();
+        self.lifetime_stats.record_month(
+            result.rent_collected,
+            self.building.occupancy_count(),
+            self.city.buildings.len(),
+            self.gentrification.gentrification_score,
+            self.current_tick,
+        );
         self.game_outcome = result.outcome.clone();
         self.spawn_tick_feedback(&result.events);
         self.register_active_world_events(&result.events);
+        self.record_tenant_complaints(&result.events);
+        self.record_insurance_claims(&result.events);
+        self.check_pet_noise_restrictions(&result.events);
+        self.record_careful_move_outs(&result.left_unit_improved);
+        self.handle_abandonments(&result.abandoned);
+        self.report_squatters(&result.events);
         self.apply_active_world_events();
         self.apply_active_tax_breaks();
+        self.check_inspector_visit();
+        self.check_tax_assessment();
+        self.resolve_assessment_challenges();
+        self.pay_assessment_installments();
+        self.update_utility_rates();
+        self.run_property_manager();
+        self.update_journalist();
+        self.pay_mortgages();
         self.update_city_systems();
+        self.update_interest_rate();
+        self.update_market_analyst();
         self.collect_portfolio_passive_income();
+        self.collect_landmark_subsidy();
         self.generate_monthly_narrative(&result);
         self.generate_tenant_life_events();
+        self.check_lease_renewals();
         self.auto_approve_manager_requests();
+        self.expire_request_conditions();
         self.expire_narrative_events();
         self.sync_building();
         self.missions.generate_available_missions(self.current_tick);
 
         if self.current_tick.is_multiple_of(12) && self.current_tick > 0 {
             self.check_annual_awards();
+            self.inflation_multiplier *= 1.0 + self.config.operating_costs.annual_inflation_rate;
+            // Quote the renewal at the prices it will actually be billed at.
+            self.renew_insurance();
         }
+        self.tick_rent_strike();
+        self.generate_union_demand();
 
         self.apply_monthly_social_happiness();
         self.log_monthly_status();
         self.update_context_hints();
-        self.check_game_completion();
+        self.check_game_completion(result.outcome.as_ref());
         // Record the tick result before evaluating missions so goals like
         // PerfectCollection can inspect this month's rent outcome.
         self.last_tick_result = Some(result);
         self.update_missions();
+        self.check_scenario_progress();
         self.autosave_current_game();
     }
 
@@ -84,21 +139,17 @@ impl GameplayState {
         }
     }
 
+    /// Float `text` over the middle of the screen, offset by the given
+    /// amount.
     pub(super) fn spawn_center_text(
         &mut self,
-        text: &str,
+        text: impl Into<String>,
         offset_x: f32,
         offset_y: f32,
         color: Color,
     ) {
-        self.floating_texts.spawn(
-            text,
-            vec2(
-                screen_width() / 2.0 + offset_x,
-                screen_height() / 2.0 + offset_y,
-            ),
-            color,
-        );
+        self.pending_center_texts
+            .push((text.into(), vec2(offset_x, offset_y), color));
     }
 
     fn register_active_world_events(&mut self, events: &[GameEvent]) {
@@ -107,6 +158,9 @@ impl GameplayState {
                 GameEvent::Heatwave { tick_duration } => {
                     self.add_active_world_event(ActiveWorldEventKind::Heatwave, *tick_duration);
                 }
+                GameEvent::PowerOutage { duration_ticks } => {
+                    self.add_active_world_event(ActiveWorldEventKind::PowerOutage, *duration_ticks);
+                }
                 GameEvent::Gentrification { tick_duration, .. } => {
                     self.add_active_world_event(
                         ActiveWorldEventKind::Gentrification,
@@ -138,6 +192,7 @@ impl GameplayState {
 
     fn apply_active_world_events(&mut self) {
         let mut heatwave_active = false;
+        let mut outage_active = false;
         let mut gentrification_active = false;
 
         for event in &mut self.active_world_events {
@@ -145,6 +200,9 @@ impl GameplayState {
                 ActiveWorldEventKind::Heatwave => {
                     heatwave_active = true;
                 }
+                ActiveWorldEventKind::PowerOutage => {
+                    outage_active = true;
+                }
                 ActiveWorldEventKind::Gentrification => {
                     gentrification_active = true;
                 }
@@ -158,6 +216,21 @@ impl GameplayState {
             }
         }
 
+        if outage_active {
+            let has_generator = self.building.flags.contains(BACKUP_GENERATOR_FLAG);
+            let outages = &self.config.power_outages;
+            for tenant in &mut self.tenants {
+                let penalty = if has_generator {
+                    outages.generator_happiness_penalty
+                } else if tenant.archetype == crate::tenant::TenantArchetype::Professional {
+                    outages.happiness_penalty + outages.work_from_home_penalty
+                } else {
+                    outages.happiness_penalty
+                };
+                tenant.happiness = (tenant.happiness - penalty).max(0);
+            }
+        }
+
         if gentrification_active {
             self.gentrification.gentrification_score = (self.gentrification.gentrification_score
                 + 1)
@@ -174,18 +247,26 @@ impl GameplayState {
             .retain(|event| event.remaining_ticks > 0);
     }
 
+    /// Is the power out this month? Drives the header's outage icon.
+    pub(super) fn power_outage_active(&self) -> bool {
+        self.active_world_events
+            .iter()
+            .any(|event| event.kind == ActiveWorldEventKind::PowerOutage)
+    }
+
     fn update_city_systems(&mut self) {
         self.save_building_to_city();
-        self.city.tick();
+        self.city.tick(&self.config);
 
-        let (rel_changes, rel_events) = self.tenant_network.tick(
+        let (rel_changes, rel_events) = self.tenant_network.tick_all_buildings(
             &self.tenants,
-            &self.building,
+            &self.city,
             &self.config.relationships,
             &self.relationship_events_config,
             self.current_tick,
         );
-        self.notifications.add_relationship_changes(rel_changes);
+        self.notifications
+            .add_relationship_changes(rel_changes, self.current_tick);
         for mut event in rel_events {
             event.month = self.current_tick;
             if event.requires_response {
@@ -202,8 +283,16 @@ impl GameplayState {
             }
         }
 
-        self.compliance.tick(self.current_tick);
+        self.tick_compliance();
+        self.check_water_damage_deadline();
         self.run_due_inspections();
+        self.check_energy_mandate();
+        self.check_affordable_mandate();
+        self.check_structural_weakening();
+        self.check_hallway_art_praise();
+        self.check_landmark_designation();
+        self.check_voucher_program();
+        self.check_rent_freeze();
         self.gentrification
             .update_affordable_units(&self.building.apartments, &self.config.gentrification);
     }
@@ -321,33 +410,62 @@ impl GameplayState {
         );
     }
 
-    fn check_game_completion(&mut self) {
-        let duration = self.config.win_conditions.game_duration_ticks.unwrap_or(36);
-        if self.current_tick < duration || self.game_outcome.is_some() {
+    /// A campaign building is finished once it lasts its full term: move on
+    /// to the next one. Scenarios end on their own objectives and don't
+    /// advance the campaign; sandbox games don't end at all.
+    fn check_game_completion(&mut self, outcome: Option<&GameOutcome>) {
+        if self.scenario.is_some() || self.sandbox_mode {
+            return;
+        }
+        if !matches!(outcome, Some(GameOutcome::Victory { .. })) {
             return;
         }
-
-        self.game_outcome = Some(crate::simulation::GameOutcome::Victory {
-            score: 0,
-            months: self.current_tick,
-            total_income: self.funds.total_income,
-        });
-        self.view_mode = ViewMode::CareerSummary;
         self.unlock_next_building();
+        self.view_mode = ViewMode::CareerSummary;
         self.check_final_achievements();
     }
 
-    fn check_final_achievements(&mut self) {
+    pub(super) fn check_final_achievements(&mut self) {
         let new_unlocks = self.achievements.check_new_unlocks(
             &self.city,
             &self.building,
             &self.tenants,
             &self.funds,
+            &self.lifetime_stats,
             self.current_tick,
             &self.config,
         );
         for id in new_unlocks {
-            self.achievements.unlock(&id);
+            self.unlock_achievement(&id);
+        }
+    }
+
+    /// Unlock an achievement and tell the player about it.
+    pub(super) fn unlock_achievement(&mut self, id: &str) {
+        self.achievements.unlock(id);
+        let Some(achievement) = self.achievements.get(id) else {
+            return;
+        };
+        let hidden = achievement.hidden;
+        let message = if hidden {
+            format!("HIDDEN ACHIEVEMENT UNLOCKED: {}", achievement.name)
+        } else {
+            format!("Achievement: {}", achievement.name)
+        };
+        let mut notification = GameNotification::positive("🏆", &message);
+        notification.description = Some(achievement.description.clone());
+        self.notifications.push(notification, self.current_tick);
+        self.spawn_center_text(&message, 0.0, -60.0, colors::PRIMARY());
+        if hidden {
+            // A little burst of sparkles around the banner for secret finds.
+            for (dx, dy) in [
+                (-140.0, -80.0),
+                (140.0, -80.0),
+                (-90.0, -30.0),
+                (90.0, -30.0),
+            ] {
+                self.spawn_center_text("✨", dx, dy, colors::PRIMARY());
+            }
         }
     }
 }
@@ -0,0 +1,186 @@
+// The tenant union: formed out of an organized council in one building, it
+// makes monthly demands (see gameplay_rent_strike for what happens when
+// they're ignored).
+
+use crate::narrative::events::NarrativeEventType;
+use crate::narrative::{buyout_approved, buyout_rejected, council_demand, union_formation};
+use crate::ui::colors;
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// The council has organized; ask the landlord how to answer it.
+    pub(super) fn form_tenant_union(&mut self) {
+        self.tenant_union = Some(self.city.active_building_index as u32);
+        let config = &self.config.gentrification;
+        let event = union_formation(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            &self.building.name,
+            config.union_dialogue_cost,
+        );
+        self.narrative_events.add_event(event);
+    }
+
+    /// Is the active building the one with a union?
+    pub(super) fn union_building_active(&self) -> bool {
+        self.tenant_union == Some(self.city.active_building_index as u32)
+    }
+
+    /// While its building is the active one, the union sends a fresh list of
+    /// demands each month, unless the last one is still waiting on an answer.
+    pub(super) fn generate_union_demand(&mut self) {
+        if !self.union_building_active() {
+            return;
+        }
+        self.start_overdue_rent_strike();
+        if self.rent_strike.is_some() || self.union_demand_pending() {
+            return;
+        }
+        let config = &self.config.gentrification;
+        let event = council_demand(
+            self.current_tick,
+            self.city.active_building_index as u32,
+            &self.building.name,
+            config.union_demand_cost,
+        );
+        self.narrative_events.add_event(event);
+    }
+
+    fn union_demand_pending(&self) -> bool {
+        let events = &self.narrative_events;
+        events.pending_events.iter().any(|id| {
+            events
+                .events
+                .iter()
+                .any(|e| e.id == *id && e.event_type == NarrativeEventType::TenantCouncilDemand)
+        })
+    }
+
+    /// The tenants of the building on offer vote on it. Voting it down
+    /// declines it outright; voting for it leaves the landlord to sell or pay
+    /// to resist.
+    pub(super) fn hold_buyout_vote(&mut self, building_id: u32, offer: i32) {
+        let Some(name) = self
+            .building_at(building_id as usize)
+            .map(|building| building.name.clone())
+        else {
+            return;
+        };
+        let vote = self
+            .tenant_network
+            .buyout_vote(self.tenants_at(building_id as usize));
+        let event = if vote.passed() {
+            buyout_approved(
+                self.current_tick,
+                building_id,
+                &name,
+                offer,
+                self.config.economy.relocation_resistance_cost,
+            )
+        } else {
+            buyout_rejected(self.current_tick, building_id, &name)
+        };
+        let immediate = (!event.requires_response).then(|| event.default_effect.clone());
+        self.narrative_events.add_event(event);
+        if let Some(effect) = immediate {
+            self.apply_narrative_effect(&effect);
+        }
+    }
+
+    pub(super) fn recognize_tenant_union(&mut self) {
+        self.gentrification.union_recognized = true;
+        self.spawn_center_text("Union recognized", 0.0, 30.0, colors::ACCENT());
+    }
+
+    pub(super) fn adjust_gentrification(&mut self, change: i32) {
+        self.gentrification
+            .adjust_score(change, &self.config.gentrification);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{Tenant, TenantArchetype};
+
+    #[test]
+    fn union_demands_wait_for_an_answer() {
+        let mut state = GameplayState::new();
+        state.narrative_events.pending_events.clear();
+        state.form_tenant_union();
+        assert_eq!(state.tenant_union, Some(0));
+
+        // The formation event is still open, so no demand piles on top of it
+        let before = state.narrative_events.events.len();
+        state.generate_union_demand();
+        assert_eq!(state.narrative_events.events.len(), before);
+
+        state.narrative_events.pending_events.clear();
+        state.generate_union_demand();
+        assert_eq!(state.narrative_events.events.len(), before + 1);
+    }
+
+    #[test]
+    fn content_tenants_vote_a_sale_down() {
+        let mut state = GameplayState::new();
+        let mut tenant = Tenant::new(900, "Content", TenantArchetype::Family);
+        tenant.landlord_opinion = 20;
+        state.tenants = vec![tenant];
+        state.tenants[0].happiness = 50;
+        state.hold_buyout_vote(0, 500_000);
+
+        let last = state.narrative_events.events.last().unwrap();
+        assert_eq!(last.headline, "Tenants Vote Down the Sale");
+        assert!(state.tenants.iter().all(|t| t.happiness == 60));
+    }
+
+    #[test]
+    fn recognizing_the_union_sticks() {
+        let mut state = GameplayState::new();
+        state.recognize_tenant_union();
+        assert!(state.gentrification.union_recognized);
+    }
+
+    #[test]
+    fn union_stays_with_its_building() {
+        let mut state = GameplayState::new();
+        state.narrative_events.pending_events.clear();
+        state.form_tenant_union();
+        state.narrative_events.pending_events.clear();
+
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+        assert!(!state.union_building_active());
+        let before = state.narrative_events.events.len();
+        state.generate_union_demand();
+        assert_eq!(state.narrative_events.events.len(), before);
+
+        state.activate_building(0);
+        state.generate_union_demand();
+        assert_eq!(state.narrative_events.events.len(), before + 1);
+    }
+
+    #[test]
+    fn only_the_building_on_offer_votes() {
+        let mut state = GameplayState::new();
+        let mut bitter = Tenant::new(900, "Bitter", TenantArchetype::Family);
+        bitter.landlord_opinion = -20;
+        state.tenants = vec![bitter];
+        state.save_building_to_city();
+        state
+            .city
+            .buildings
+            .push(crate::building::Building::new("Annex", 1, 2));
+        state.activate_building(1);
+
+        state.hold_buyout_vote(0, 500_000);
+
+        let last = state.narrative_events.events.last().unwrap();
+        assert_eq!(last.headline, "Tenants Vote to Sell");
+    }
+}
@@ -0,0 +1,86 @@
+// Reshaping apartment units. Renaming uses a small keyboard buffer that stays
+// active until the player confirms with Enter or backs out with Escape.
+
+use crate::economy::{Transaction, TransactionType};
+use crate::ui::{colors, Selection};
+use macroquad::prelude::*;
+
+use super::gameplay::GameplayState;
+
+const MAX_UNIT_NUMBER_LEN: usize = 6;
+
+impl GameplayState {
+    pub(super) fn begin_unit_rename(&mut self, apartment_id: u32) {
+        let Some(apt) = self.building.get_apartment(apartment_id) else {
+            return;
+        };
+        self.text_input_buffer = apt.unit_number.clone();
+        self.renaming_unit = Some(apartment_id);
+        // Drop keystrokes queued before the field had focus.
+        while get_char_pressed().is_some() {}
+    }
+
+    pub(super) fn rename_unit(&mut self, apartment_id: u32, new_number: &str) {
+        match self.building.rename_unit(apartment_id, new_number) {
+            Ok(()) => {
+                self.renaming_unit = None;
+                self.text_input_buffer.clear();
+            }
+            Err(reason) => self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE()),
+        }
+    }
+
+    pub(super) fn merge_units(&mut self, apt_a_id: u32, apt_b_id: u32) {
+        if let Err(reason) = self.building.can_merge(apt_a_id, apt_b_id) {
+            self.spawn_center_text(&reason, 0.0, 0.0, colors::NEGATIVE());
+            return;
+        }
+        if !self.funds.deduct_expense(Transaction::expense(
+            TransactionType::UpgradeCost,
+            self.config.economy.unit_merge_cost,
+            "Unit merge",
+            self.current_tick,
+        )) {
+            self.spawn_center_text("Can't afford merge", 0.0, 0.0, colors::NEGATIVE());
+            return;
+        }
+        let Ok(merged_id) = self.building.merge_units(apt_a_id, apt_b_id) else {
+            return;
+        };
+        // Applicants for the old units have nothing left to move into.
+        self.applications
+            .retain(|app| app.apartment_id != apt_a_id && app.apartment_id != apt_b_id);
+        self.selection = Selection::Apartment(merged_id);
+        self.spawn_center_text("Units merged", 0.0, 0.0, colors::POSITIVE());
+    }
+
+    /// Per-frame keyboard handling while a unit number is being edited.
+    pub(super) fn update_unit_rename_input(&mut self) {
+        let Some(apartment_id) = self.renaming_unit else {
+            return;
+        };
+        // Selecting anything else abandons the edit.
+        if !matches!(self.selection, Selection::Apartment(id) if id == apartment_id)
+            || is_key_pressed(KeyCode::Escape)
+        {
+            self.renaming_unit = None;
+            self.text_input_buffer.clear();
+            return;
+        }
+
+        while let Some(ch) = get_char_pressed() {
+            if (ch.is_ascii_alphanumeric() || ch == '-')
+                && self.text_input_buffer.len() < MAX_UNIT_NUMBER_LEN
+            {
+                self.text_input_buffer.push(ch.to_ascii_uppercase());
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            self.text_input_buffer.pop();
+        }
+        if is_key_pressed(KeyCode::Enter) {
+            let new_number = self.text_input_buffer.clone();
+            self.rename_unit(apartment_id, &new_number);
+        }
+    }
+}
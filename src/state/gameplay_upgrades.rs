@@ -0,0 +1,52 @@
+// Side effects of an installed upgrade that reach beyond the building itself:
+// neighborhood reputation, certification, and compliance.
+
+use crate::building::UpgradeAction;
+use crate::data::config::UpgradeEffect;
+use crate::economy::SOLAR_PANELS_FLAG;
+
+use super::gameplay::GameplayState;
+
+/// `ModifyStat` key for upgrades that raise the building's fire safety score.
+const FIRE_SAFETY_STAT: &str = "fire_safety";
+
+impl GameplayState {
+    /// Called once `upgrade` has been paid for and applied to the building.
+    /// `had_solar` is whether the building had solar panels beforehand.
+    pub(super) fn after_upgrade_installed(&mut self, upgrade: &UpgradeAction, had_solar: bool) {
+        if !had_solar && self.building.flags.contains(SOLAR_PANELS_FLAG) {
+            let bonus = self.config.gentrification.green_building_reputation_bonus;
+            self.adjust_active_neighborhood_reputation(bonus);
+        }
+        self.check_green_certification();
+
+        let fire_safety = self.upgrade_fire_safety(upgrade);
+        if fire_safety != 0 {
+            let building_id = self.city.active_building_index as u32;
+            self.compliance
+                .improve_fire_safety(building_id, fire_safety, &self.config.regulations);
+        }
+    }
+
+    /// Total fire safety an upgrade's `ModifyStat` effects add.
+    fn upgrade_fire_safety(&self, upgrade: &UpgradeAction) -> i32 {
+        let UpgradeAction::Apply { upgrade_id, .. } = upgrade else {
+            return 0;
+        };
+        self.config
+            .upgrades
+            .get(upgrade_id)
+            .map(|def| {
+                def.effects
+                    .iter()
+                    .map(|effect| match effect {
+                        UpgradeEffect::ModifyStat { stat, amount } if stat == FIRE_SAFETY_STAT => {
+                            *amount
+                        }
+                        _ => 0,
+                    })
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+}
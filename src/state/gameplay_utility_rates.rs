@@ -0,0 +1,59 @@
+// Utility rate spikes from heatwaves and winter storms, and their return to
+// normal once the weather breaks.
+
+use super::gameplay::GameplayState;
+
+impl GameplayState {
+    /// Restore normal rates once a spike has run its course; otherwise roll
+    /// for a new one.
+    pub(super) fn update_utility_rates(&mut self) {
+        if let Some(restore_tick) = self.utility_rate_restore_tick {
+            if self.current_tick >= restore_tick {
+                self.utility_rate_multiplier = 1.0;
+                self.utility_rate_restore_tick = None;
+            }
+            return;
+        }
+        if let Some(effect) = self
+            .narrative_events
+            .generate_utility_rate_event(self.current_tick, &self.config.utility_spikes)
+        {
+            self.apply_narrative_effect(&effect);
+        }
+    }
+
+    pub(super) fn start_utility_rate_spike(&mut self, multiplier: f32, duration_ticks: u32) {
+        self.utility_rate_multiplier = multiplier;
+        self.utility_rate_restore_tick = Some(self.current_tick + duration_ticks);
+    }
+
+    pub(super) fn utility_rates_elevated(&self) -> bool {
+        self.utility_rate_multiplier > 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::narrative::events::NarrativeEffect;
+
+    #[test]
+    fn rates_return_to_normal_after_the_spike() {
+        let mut state = GameplayState::new();
+        state.current_tick = 4;
+        state.apply_narrative_effect(&NarrativeEffect::UtilityRateChange {
+            multiplier: 1.5,
+            duration_ticks: 2,
+        });
+        assert!(state.utility_rates_elevated());
+
+        state.current_tick = 5;
+        state.update_utility_rates();
+        assert_eq!(state.utility_rate_multiplier, 1.5);
+
+        state.current_tick = 6;
+        state.update_utility_rates();
+        assert!(!state.utility_rates_elevated());
+        assert_eq!(state.utility_rate_restore_tick, None);
+    }
+}
@@ -1,11 +1,13 @@
 //! Game view rendering - split from gameplay.rs for maintainability
 
 use crate::assets::AssetManager;
-use crate::narrative::NotificationCategory;
+use crate::narrative::{MailAction, NotificationCategory};
 use crate::ui::layout::HEADER_HEIGHT;
 use crate::ui::{
-    colors, draw_apartment_panel, draw_application_panel, draw_building_view, draw_hallway_panel,
-    draw_header, draw_notifications, draw_ownership_panel, Selection,
+    colors, draw_achievements_panel, draw_apartment_panel, draw_application_panel,
+    draw_building_view, draw_hallway_panel, draw_header, draw_minimap, draw_mission_panel,
+    draw_notifications, draw_ownership_panel, draw_strike_panel, HallwayPanelStatus, HeaderStatus,
+    Selection, UiAction,
 };
 use macroquad::prelude::*;
 
@@ -30,15 +32,19 @@ impl GameplayState {
                     &self.city,
                     self.city.active_building_index,
                     assets,
+                    &self.config,
                 ) {
                     self.handle_city_action(action);
                 }
             }
             ViewMode::Market => {
-                let listings: Vec<&crate::city::PropertyListing> =
-                    self.city.market.listings.iter().collect();
+                let listings = self
+                    .market_filter
+                    .apply(&self.city.market.listings, &self.city.neighborhoods);
                 if let Some(action) = crate::ui::city_view::draw_market_panel(
                     &listings,
+                    self.city.market.listings.len(),
+                    &self.market_filter,
                     &self.city.neighborhoods,
                     self.funds.balance,
                     assets,
@@ -49,6 +55,23 @@ impl GameplayState {
             ViewMode::Mail => {
                 self.draw_mail_view(assets);
             }
+            ViewMode::Achievements => {
+                if let Some(action) =
+                    draw_achievements_panel(&self.achievements, self.selected_achievement)
+                {
+                    self.pending_actions.push(action);
+                }
+            }
+            ViewMode::NotificationHistory => {
+                let (action, new_scroll) = crate::ui::draw_notification_history_panel(
+                    &self.notifications.history,
+                    self.panel_scroll_offset,
+                );
+                self.panel_scroll_offset = new_scroll;
+                if let Some(action) = action {
+                    self.pending_actions.push(action);
+                }
+            }
             ViewMode::CareerSummary => {
                 if let Some(action) = crate::ui::career_summary::draw_career_summary(self) {
                     self.pending_actions.push(action);
@@ -68,6 +91,12 @@ impl GameplayState {
             if let Some(action) = crate::ui::event_modal::draw_event_modal(event) {
                 self.pending_actions.push(action);
             }
+        } else if let Some(interview) = self.dialogue_system.active_dialogues.iter().find(|d| {
+            d.dialogue_type == crate::narrative::dialogue::DialogueType::ApplicantInterview
+        }) {
+            if let Some(action) = crate::ui::event_modal::draw_dialogue_modal(interview) {
+                self.pending_actions.push(action);
+            }
         }
 
         // Footer event log.
@@ -76,6 +105,10 @@ impl GameplayState {
         // Floating text
         self.floating_texts.draw();
 
+        if let Some(hint) = self.tutorial.visible_hint(self.current_tick) {
+            crate::ui::widgets::draw_corner_hint(hint);
+        }
+
         // Tutorial overlay (takes precedence)
         if self.tutorial.active && !self.tutorial.pending_messages.is_empty() {
             self.draw_tutorial_overlay(assets);
@@ -93,24 +126,38 @@ impl GameplayState {
 
     pub(super) fn draw_building_mode(&mut self, assets: &AssetManager) {
         // Draw Header
-        if let Some(action) = draw_header(
-            self.funds.balance,
-            self.current_tick,
-            &self.building.name,
-            self.building.occupancy_count(),
-            self.building.apartments.len(),
+        if let Some(action) = draw_header(&self.header_status(), assets) {
+            self.pending_actions.push(action);
+        }
+
+        // Draw Building View
+        if let Some(action) = draw_building_view(
+            &self.building,
+            self.city.active_building_index as u32,
+            &self.tenants,
+            &self.selection,
             assets,
+            self.config.economy.renovation_cost_per_unit,
         ) {
             self.pending_actions.push(action);
         }
 
-        // Draw Building View
-        if let Some(action) =
-            draw_building_view(&self.building, &self.tenants, &self.selection, assets)
-        {
+        if let Some(action) = draw_minimap(&self.city, self.show_minimap) {
             self.pending_actions.push(action);
         }
 
+        if let Some(strike) = self.active_rent_strike() {
+            let striking = self
+                .building
+                .apartments
+                .iter()
+                .filter(|a| a.on_rent_strike())
+                .count();
+            if let Some(action) = draw_strike_panel(strike, striking) {
+                self.pending_actions.push(action);
+            }
+        }
+
         // Slide the detail panel in from the right as the selection tween eases
         // to 1.0 (0 offset = settled in place).
         let panel_offset = (1.0 - self.panel_tween.current()) * 60.0;
@@ -118,6 +165,7 @@ impl GameplayState {
         match self.selection {
             Selection::Apartment(id) => {
                 if let Some(apt) = self.building.get_apartment(id) {
+                    let transfer_target = self.transfer_target();
                     let (action, new_scroll) = draw_apartment_panel(
                         apt,
                         &self.building,
@@ -129,6 +177,17 @@ impl GameplayState {
                         &self.config,
                         &self.tenant_network,
                         &self.tenant_stories,
+                        self.unlocked_upgrades(),
+                        self.show_relationship_history,
+                        (self.renaming_unit == Some(id)).then_some(self.text_input_buffer.as_str()),
+                        transfer_target.as_ref(),
+                        self.current_tick,
+                        self.compliance.just_cause_eviction_active,
+                        &self
+                            .compliance
+                            .permits_in(self.city.active_building_index as u32),
+                        self.compliance
+                            .pending_rent_increase(self.city.active_building_index as u32, id),
                     );
                     self.panel_scroll_offset = new_scroll;
                     if let Some(action) = action {
@@ -139,11 +198,14 @@ impl GameplayState {
             Selection::Hallway => {
                 let (action, new_scroll) = draw_hallway_panel(
                     &self.building,
+                    &self.tenants,
                     self.funds.balance,
                     panel_offset,
                     self.panel_scroll_offset,
                     assets,
                     &self.config,
+                    self.current_tick,
+                    &self.hallway_panel_status(),
                 );
                 self.panel_scroll_offset = new_scroll;
                 if let Some(action) = action {
@@ -151,16 +213,34 @@ impl GameplayState {
                 }
             }
             Selection::Applications(filter) => {
-                if let Some(action) =
-                    draw_application_panel(&self.applications, &self.building, filter, 0.0, assets)
-                {
+                if let Some(action) = draw_application_panel(
+                    &self.applications,
+                    &self.building,
+                    filter,
+                    self.sort_applications_by_score,
+                    0.0,
+                    assets,
+                    &self.config,
+                ) {
                     self.pending_actions.push(action);
                 }
             }
             Selection::Ownership => {
-                if let Some(action) =
-                    draw_ownership_panel(&self.building, self.condo_sale_market_multiplier())
-                {
+                if let Some(action) = draw_ownership_panel(
+                    &self.building,
+                    self.condo_sale_market_multiplier(),
+                    self.config.economy.relocation_assistance_months,
+                ) {
+                    self.pending_actions.push(action);
+                }
+            }
+            Selection::Missions => {
+                if let Some(action) = draw_mission_panel(
+                    &self.missions,
+                    &self.building,
+                    self.current_tick,
+                    self.config.gentrification.affordable_threshold,
+                ) {
                     self.pending_actions.push(action);
                 }
             }
@@ -169,7 +249,50 @@ impl GameplayState {
     }
 
     /// Draw mail view
-    pub(super) fn draw_mail_view(&self, assets: &AssetManager) {
+    /// The game and active-building state shown in the header.
+    fn header_status(&self) -> HeaderStatus<'_> {
+        HeaderStatus {
+            money: self.funds.balance,
+            tick: self.current_tick,
+            building_name: &self.building.name,
+            occupancy: self.building.occupancy_count(),
+            total_units: self.building.apartments.len(),
+            difficulty: self.difficulty,
+            power_out: self.power_outage_active(),
+            union_active: self.union_building_active(),
+            rent_freeze_months: self.rent_freeze_months_left(),
+            high_utility_rates: self.utility_rates_elevated(),
+            unread_notifications: self.notifications.unread_history,
+            sandbox_infinite_funds: self.sandbox_mode.then_some(self.sandbox_infinite_funds),
+        }
+    }
+
+    /// Compliance and management state the hallway panel reports on.
+    fn hallway_panel_status(&self) -> HallwayPanelStatus<'_> {
+        let building_id = self.city.active_building_index as u32;
+        HallwayPanelStatus {
+            fire_safety: self.compliance.fire_safety_score(building_id),
+            energy_mandate_months_left: self
+                .compliance
+                .energy_mandate_months_left(building_id, self.current_tick),
+            affordable_mandate: self
+                .compliance
+                .affordable_mandate_status(&self.building.apartments),
+            repair_deadlines: self.compliance.repair_deadlines_by_urgency(building_id),
+            license_months_left: self.compliance.license_months_left(self.current_tick),
+            license_lapsed: self.compliance.license_lapsed,
+            fair_housing_required: self
+                .compliance
+                .fair_housing
+                .fair_housing_required_archetype
+                .as_ref()
+                .map(|a| a.name()),
+            property_manager: self.property_manager.as_ref(),
+            cost_index: self.inflation_multiplier,
+        }
+    }
+
+    pub(super) fn draw_mail_view(&mut self, assets: &AssetManager) {
         // Use assets to check if textures are loaded
         let has_assets = assets.loaded;
         draw_rectangle(
@@ -225,6 +348,10 @@ impl GameplayState {
         let mail_height = 80.0;
 
         let mail_to_show = self.mailbox.recent(10);
+        let mut clicked = None;
+        let can_interview = self
+            .journalist
+            .can_interview(self.current_tick, self.config.journalist.article_interval);
 
         for (i, mail) in mail_to_show.iter().enumerate() {
             let y = start_y + i as f32 * (mail_height + 10.0);
@@ -287,6 +414,76 @@ impl GameplayState {
                     ..Default::default()
                 },
             );
+
+            if let Some(MailAction::RenewLease {
+                building_id,
+                tenant_id,
+                apartment_id,
+                proposed_rent,
+            }) = mail.action.clone()
+            {
+                let rect = Rect::new(screen_width() - 200.0, y + 40.0, 160.0, 30.0);
+                if crate::ui::widgets::button_at(
+                    rect,
+                    &format!("Renew at ${}", proposed_rent),
+                    building_id == self.city.active_building_index as u32,
+                    crate::ui::theme::Tone::Primary,
+                ) {
+                    clicked = Some(UiAction::RenewLease {
+                        building_id,
+                        tenant_id,
+                        apartment_id,
+                        new_rent: proposed_rent,
+                    });
+                }
+            }
+
+            if let Some(MailAction::ApplyRecommendedRents { building_id, rents }) =
+                mail.action.clone()
+            {
+                let rect = Rect::new(screen_width() - 260.0, y + 40.0, 220.0, 30.0);
+                if crate::ui::widgets::button_at(
+                    rect,
+                    "Apply recommended rents",
+                    building_id == self.city.active_building_index as u32,
+                    crate::ui::theme::Tone::Primary,
+                ) {
+                    clicked = Some(UiAction::AdjustAllRents { building_id, rents });
+                }
+            }
+
+            if let Some(MailAction::ContestTaxAssessment { building_id, fee }) = mail.action {
+                let rect = Rect::new(screen_width() - 260.0, y + 40.0, 220.0, 30.0);
+                let open = self
+                    .compliance
+                    .tax_assessments
+                    .property_assessments
+                    .get(&building_id)
+                    .is_some_and(|assessment| assessment.appeal_open);
+                if crate::ui::widgets::button_at(
+                    rect,
+                    &format!("Contest (${})", fee),
+                    open && self.funds.balance >= fee,
+                    crate::ui::theme::Tone::Primary,
+                ) {
+                    clicked = Some(UiAction::ContestTaxAssessment { building_id });
+                }
+            }
+
+            if matches!(mail.action, Some(MailAction::GrantExclusiveInterview)) {
+                let rect = Rect::new(screen_width() - 260.0, y + 40.0, 220.0, 30.0);
+                if crate::ui::widgets::button_at(
+                    rect,
+                    "Grant exclusive interview",
+                    can_interview,
+                    crate::ui::theme::Tone::Primary,
+                ) {
+                    clicked = Some(UiAction::GrantExclusiveInterview);
+                }
+            }
+        }
+        if let Some(action) = clicked {
+            self.pending_actions.push(action);
         }
 
         // Back hint
@@ -356,14 +553,16 @@ impl GameplayState {
         }
         btn_y += 50.0;
 
-        // Save button
-        if self.menu_button(btn_x, btn_y, btn_w, btn_h, "Save Game") {
+        // Save button (Ironman runs only autosave)
+        let can_save = self.difficulty.allows_manual_save();
+        let save_label = if can_save {
+            "Save Game"
+        } else {
+            "Ironman: Autosave"
+        };
+        if self.menu_button(btn_x, btn_y, btn_w, btn_h, save_label) && can_save {
             if crate::save::save_game(self).is_ok() {
-                self.floating_texts.spawn(
-                    "Game Saved!",
-                    vec2(screen_width() / 2.0, screen_height() / 2.0),
-                    colors::POSITIVE(),
-                );
+                self.spawn_center_text("Game Saved!", 0.0, 0.0, colors::POSITIVE());
             }
             self.show_pause_menu = false;
         }
@@ -467,8 +666,14 @@ impl GameplayState {
             body.push('\n');
             body.push_str(desc);
         }
-        if crate::ui::widgets::draw_toast(&icon, "", &body, kind, "OK") {
-            self.notifications.pop();
+        match crate::ui::widgets::draw_toast_with_secondary(&icon, &body, kind, "OK", "Later") {
+            Some(crate::ui::widgets::ToastResponse::Action) => {
+                self.notifications.pop();
+            }
+            Some(crate::ui::widgets::ToastResponse::Secondary) => {
+                self.notifications.snooze_current(self.current_tick);
+            }
+            None => {}
         }
     }
 }
@@ -1,7 +1,9 @@
-use super::{GameplayState, StateTransition};
+use super::{NewGameSetup, StateTransition};
 use crate::assets::AssetManager;
+use crate::data::config::DifficultyPreset;
 use crate::data::templates::{load_templates, BuildingTemplate};
-use crate::save::{has_save_game, load_game, load_player_progress, PlayerProgress};
+use crate::narrative::{load_scenarios, Scenario};
+use crate::save::{has_save_game, load_player_progress, PlayerProgress};
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
 
@@ -36,6 +38,59 @@ fn card_rect(i: usize, count: usize) -> Rect {
     )
 }
 
+/// "Change difficulty" button shown above the building grid.
+fn difficulty_back_rect() -> Rect {
+    Rect::new(GRID_EDGE_MARGIN, 20.0, 200.0, 36.0)
+}
+
+/// Button opposite the difficulty button: "Scenarios" on the difficulty
+/// screen, the "Skip Tutorial" toggle on the building screen (once the
+/// tutorial has been finished in an earlier campaign).
+fn top_right_rect() -> Rect {
+    Rect::new(screen_width() - GRID_EDGE_MARGIN - 200.0, 20.0, 200.0, 36.0)
+}
+
+fn difficulty_color(preset: DifficultyPreset) -> Color {
+    match preset {
+        DifficultyPreset::Easy => Color::from_rgba(80, 180, 80, 255),
+        DifficultyPreset::Normal => Color::from_rgba(200, 180, 60, 255),
+        DifficultyPreset::Hard => Color::from_rgba(200, 80, 80, 255),
+        DifficultyPreset::Ironman => Color::from_rgba(150, 100, 200, 255),
+    }
+}
+
+const SANDBOX_MAX_FLOORS: u32 = 10;
+const SANDBOX_MAX_UNITS_PER_FLOOR: u32 = 8;
+
+/// Minus and plus buttons for sandbox setting `row` (0 = floors, 1 = units
+/// per floor).
+fn sandbox_step_rects(row: usize) -> (Rect, Rect) {
+    let y = grid_top() + row as f32 * 60.0;
+    let cx = screen_width() / 2.0;
+    (
+        Rect::new(cx + 60.0, y, 40.0, 40.0),
+        Rect::new(cx + 110.0, y, 40.0, 40.0),
+    )
+}
+
+/// Seed field between the difficulty button and the tutorial toggle on the
+/// building screen.
+fn seed_rect() -> Rect {
+    Rect::new(screen_width() / 2.0 - 130.0, 20.0, 260.0, 36.0)
+}
+
+/// Longest seed that still fits a u64.
+const MAX_SEED_DIGITS: usize = 19;
+
+fn sandbox_start_rect() -> Rect {
+    Rect::new(
+        screen_width() / 2.0 - 100.0,
+        grid_top() + 140.0,
+        200.0,
+        45.0,
+    )
+}
+
 fn grid_bottom(count: usize) -> f32 {
     if count == 0 {
         return grid_top();
@@ -48,6 +103,17 @@ pub struct MenuState {
     has_save: bool,
     progress: PlayerProgress,
     templates: Vec<BuildingTemplate>,
+    /// Picked on the first screen; the building grid shows once it is set.
+    difficulty: Option<DifficultyPreset>,
+    skip_tutorial: bool,
+    scenarios: Vec<Scenario>,
+    /// Showing the scenario list instead of the difficulty grid.
+    browsing_scenarios: bool,
+    /// Floors and units per floor while setting up a sandbox game.
+    sandbox_setup: Option<(u32, u32)>,
+    /// Digits typed into the seed field; blank means a random seed.
+    seed_input: String,
+    seed_focused: bool,
 }
 
 impl MenuState {
@@ -58,27 +124,97 @@ impl MenuState {
             has_save: has_save_game(),
             progress: load_player_progress(),
             templates,
+            difficulty: None,
+            skip_tutorial: false,
+            scenarios: load_scenarios(),
+            browsing_scenarios: false,
+            sandbox_setup: None,
+            seed_input: String::new(),
+            seed_focused: false,
+        }
+    }
+
+    /// Number of cards in the current grid (difficulties, then buildings).
+    fn grid_len(&self) -> usize {
+        if self.browsing_scenarios {
+            self.scenarios.len()
+        } else if self.difficulty.is_some() {
+            self.templates.len()
+        } else {
+            DifficultyPreset::ALL.len()
         }
     }
 
     pub fn update(
         &mut self,
         _assets: &AssetManager,
-        config: &crate::data::config::GameConfig,
+        _config: &crate::data::config::GameConfig,
     ) -> Option<StateTransition> {
         let (mx, my) = mouse_position();
         let clicked = is_mouse_button_pressed(MouseButton::Left);
 
-        // Building cards
-        let count = self.templates.len();
-        for (i, template) in self.templates.iter().enumerate() {
-            let rect = card_rect(i, count);
-            let is_unlocked = self.progress.is_unlocked(&template.id);
+        let count = self.grid_len();
+        if self.browsing_scenarios {
+            return self.update_scenario_list(count, mx, my, clicked);
+        }
+        if self.sandbox_setup.is_some() {
+            return self.update_sandbox_setup(mx, my, clicked);
+        }
+        match self.difficulty {
+            Some(difficulty) => {
+                if clicked && difficulty_back_rect().contains(vec2(mx, my)) {
+                    self.difficulty = None;
+                    return None;
+                }
+                if clicked {
+                    self.seed_focused = seed_rect().contains(vec2(mx, my));
+                }
+                if self.seed_focused {
+                    self.type_seed();
+                }
+                if self.progress.tutorial_completed_before
+                    && clicked
+                    && top_right_rect().contains(vec2(mx, my))
+                {
+                    self.skip_tutorial = !self.skip_tutorial;
+                    return None;
+                }
 
-            if is_unlocked && clicked && rect.contains(vec2(mx, my)) {
-                // Start game with this building template
-                let state = GameplayState::new_with_template(config.clone(), template.clone());
-                return Some(StateTransition::ToGameplay(state));
+                // Building cards
+                for (i, template) in self.templates.iter().enumerate() {
+                    let rect = card_rect(i, count);
+                    let is_unlocked = self.progress.is_unlocked(&template.id);
+
+                    if is_unlocked && clicked && rect.contains(vec2(mx, my)) {
+                        // Pick a save slot, then start with this building template
+                        return Some(StateTransition::ToSlotSelect(Some(NewGameSetup {
+                            template: template.clone(),
+                            difficulty,
+                            skip_tutorial: self.skip_tutorial,
+                            scenario: None,
+                            sandbox: false,
+                            seed: self.seed_input.parse().ok(),
+                        })));
+                    }
+                }
+            }
+            None => {
+                if !self.scenarios.is_empty() && clicked && top_right_rect().contains(vec2(mx, my))
+                {
+                    self.browsing_scenarios = true;
+                    return None;
+                }
+                if clicked && difficulty_back_rect().contains(vec2(mx, my)) {
+                    self.sandbox_setup = Some((3, 2));
+                    return None;
+                }
+                // Difficulty cards
+                for (i, preset) in DifficultyPreset::ALL.iter().enumerate() {
+                    if clicked && card_rect(i, count).contains(vec2(mx, my)) {
+                        self.difficulty = Some(*preset);
+                        return None;
+                    }
+                }
             }
         }
 
@@ -90,11 +226,7 @@ impl MenuState {
             let btn_y = grid_bottom(count) + 40.0;
 
             if clicked && mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h {
-                if let Ok(state) = load_game() {
-                    return Some(StateTransition::ToGameplay(state));
-                } else {
-                    eprintln!("Failed to load save");
-                }
+                return Some(StateTransition::ToSlotSelect(None));
             }
         }
 
@@ -120,6 +252,83 @@ impl MenuState {
         None
     }
 
+    fn type_seed(&mut self) {
+        while let Some(ch) = get_char_pressed() {
+            if ch.is_ascii_digit() && self.seed_input.len() < MAX_SEED_DIGITS {
+                self.seed_input.push(ch);
+            }
+        }
+        if is_key_pressed(KeyCode::Backspace) {
+            self.seed_input.pop();
+        }
+    }
+
+    fn update_scenario_list(
+        &mut self,
+        count: usize,
+        mx: f32,
+        my: f32,
+        clicked: bool,
+    ) -> Option<StateTransition> {
+        if !clicked {
+            return None;
+        }
+        if difficulty_back_rect().contains(vec2(mx, my)) {
+            self.browsing_scenarios = false;
+            return None;
+        }
+        // Scenarios play on the first building with their own patch on top.
+        let template = self.templates.first()?;
+        let index =
+            (0..self.scenarios.len()).find(|&i| card_rect(i, count).contains(vec2(mx, my)))?;
+        Some(StateTransition::ToSlotSelect(Some(NewGameSetup {
+            template: template.clone(),
+            difficulty: DifficultyPreset::Normal,
+            skip_tutorial: false,
+            scenario: Some(self.scenarios[index].clone()),
+            sandbox: false,
+            seed: None,
+        })))
+    }
+
+    fn update_sandbox_setup(&mut self, mx: f32, my: f32, clicked: bool) -> Option<StateTransition> {
+        let (floors, units) = self.sandbox_setup.as_mut()?;
+        if !clicked {
+            return None;
+        }
+        let mouse = vec2(mx, my);
+        if difficulty_back_rect().contains(mouse) {
+            self.sandbox_setup = None;
+            return None;
+        }
+        for (row, (value, max)) in [
+            (floors, SANDBOX_MAX_FLOORS),
+            (units, SANDBOX_MAX_UNITS_PER_FLOOR),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (minus, plus) = sandbox_step_rects(row);
+            if minus.contains(mouse) {
+                *value = value.saturating_sub(1).max(1);
+            } else if plus.contains(mouse) {
+                *value = (*value + 1).min(max);
+            }
+        }
+        let (floors, units) = self.sandbox_setup?;
+        if sandbox_start_rect().contains(mouse) {
+            return Some(StateTransition::ToSlotSelect(Some(NewGameSetup {
+                template: BuildingTemplate::sandbox(floors, units),
+                difficulty: DifficultyPreset::Normal,
+                skip_tutorial: false,
+                scenario: None,
+                sandbox: true,
+                seed: None,
+            })));
+        }
+        None
+    }
+
     pub fn draw(&self, assets: &AssetManager) {
         // Background
         if let Some(bg) = assets.get_texture("title_background") {
@@ -163,7 +372,15 @@ impl MenuState {
         }
 
         // Section title
-        let section_title = "Select Building";
+        let section_title = if self.browsing_scenarios {
+            "Select Scenario"
+        } else if self.sandbox_setup.is_some() {
+            "Sandbox Setup"
+        } else if self.difficulty.is_some() {
+            "Select Building"
+        } else {
+            "Select Difficulty"
+        };
         let section_size = 28.0;
         let section_width = measure_ui_text(section_title, None, section_size as u16, 1.0).width;
         draw_ui_text(
@@ -176,8 +393,270 @@ impl MenuState {
 
         let (mx, my) = mouse_position();
 
-        // Draw building cards
-        let count = self.templates.len();
+        let count = self.grid_len();
+        match self.difficulty {
+            _ if self.browsing_scenarios => self.draw_scenario_cards(count, mx, my),
+            _ if self.sandbox_setup.is_some() => self.draw_sandbox_setup(mx, my),
+            Some(difficulty) => {
+                self.draw_difficulty_back(difficulty, mx, my);
+                self.draw_seed_field(mx, my);
+                if self.progress.tutorial_completed_before {
+                    self.draw_skip_tutorial_toggle(mx, my);
+                }
+                self.draw_building_cards(count, mx, my);
+            }
+            None => {
+                self.draw_difficulty_cards(count, mx, my);
+                if !self.scenarios.is_empty() {
+                    draw_menu_button(top_right_rect(), "Scenarios", mx, my);
+                }
+                draw_menu_button(difficulty_back_rect(), "Sandbox", mx, my);
+            }
+        }
+
+        // Continue button (if save exists)
+        if self.has_save && self.sandbox_setup.is_none() {
+            let btn_w = 200.0;
+            let btn_h = 45.0;
+            let btn_x = screen_width() / 2.0 - btn_w / 2.0;
+            let btn_y = grid_bottom(count) + 40.0;
+
+            let hovered = mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h;
+            let bg = if hovered {
+                Color::from_rgba(60, 100, 60, 255)
+            } else {
+                Color::from_rgba(50, 80, 50, 255)
+            };
+
+            draw_rectangle(btn_x, btn_y, btn_w, btn_h, bg);
+            draw_rectangle_lines(
+                btn_x,
+                btn_y,
+                btn_w,
+                btn_h,
+                2.0,
+                Color::from_rgba(80, 140, 80, 255),
+            );
+
+            let label = "Load Game";
+            let label_width = measure_ui_text(label, None, 18, 1.0).width;
+            draw_ui_text(
+                label,
+                btn_x + (btn_w - label_width) / 2.0,
+                btn_y + 28.0,
+                18.0,
+                WHITE,
+            );
+        }
+
+        // Quit button — native only (see update()).
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let quit_btn_w = 150.0;
+            let quit_btn_h = 40.0;
+            let quit_btn_x = screen_width() / 2.0 - quit_btn_w / 2.0;
+            let quit_btn_y = screen_height() - 80.0;
+
+            let quit_hovered = mx >= quit_btn_x
+                && mx <= quit_btn_x + quit_btn_w
+                && my >= quit_btn_y
+                && my <= quit_btn_y + quit_btn_h;
+            let quit_bg = if quit_hovered {
+                Color::from_rgba(100, 60, 60, 255)
+            } else {
+                Color::from_rgba(70, 45, 45, 255)
+            };
+
+            draw_rectangle(quit_btn_x, quit_btn_y, quit_btn_w, quit_btn_h, quit_bg);
+            draw_rectangle_lines(
+                quit_btn_x,
+                quit_btn_y,
+                quit_btn_w,
+                quit_btn_h,
+                2.0,
+                Color::from_rgba(140, 80, 80, 255),
+            );
+
+            let label = "Quit";
+            let label_width = measure_ui_text(label, None, 18, 1.0).width;
+            draw_ui_text(
+                label,
+                quit_btn_x + (quit_btn_w - label_width) / 2.0,
+                quit_btn_y + 26.0,
+                18.0,
+                WHITE,
+            );
+        }
+    }
+
+    fn draw_difficulty_back(&self, difficulty: DifficultyPreset, mx: f32, my: f32) {
+        let rect = difficulty_back_rect();
+        let bg = if rect.contains(vec2(mx, my)) {
+            Color::from_rgba(70, 80, 100, 255)
+        } else {
+            Color::from_rgba(50, 55, 65, 255)
+        };
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+        draw_rectangle_lines(
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+            2.0,
+            difficulty_color(difficulty),
+        );
+        draw_ui_text(
+            &format!("< Difficulty: {}", difficulty.name()),
+            rect.x + 12.0,
+            rect.y + 23.0,
+            16.0,
+            WHITE,
+        );
+    }
+
+    fn draw_skip_tutorial_toggle(&self, mx: f32, my: f32) {
+        let rect = top_right_rect();
+        let bg = if rect.contains(vec2(mx, my)) {
+            Color::from_rgba(70, 80, 100, 255)
+        } else {
+            Color::from_rgba(50, 55, 65, 255)
+        };
+        let border = if self.skip_tutorial {
+            Color::from_rgba(80, 180, 80, 255)
+        } else {
+            Color::from_rgba(100, 100, 100, 255)
+        };
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, border);
+        let label = if self.skip_tutorial {
+            "[x] Skip Tutorial"
+        } else {
+            "[ ] Skip Tutorial"
+        };
+        draw_ui_text(label, rect.x + 12.0, rect.y + 23.0, 16.0, WHITE);
+    }
+
+    fn draw_seed_field(&self, mx: f32, my: f32) {
+        let rect = seed_rect();
+        let label = if self.seed_input.is_empty() && !self.seed_focused {
+            "Seed: random (click to set)".to_string()
+        } else if self.seed_focused {
+            format!("Seed: {}_", self.seed_input)
+        } else {
+            format!("Seed: {}", self.seed_input)
+        };
+        draw_menu_button(rect, &label, mx, my);
+        if self.seed_focused {
+            draw_rectangle_lines(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                2.0,
+                Color::from_rgba(200, 180, 60, 255),
+            );
+        }
+    }
+
+    fn draw_sandbox_setup(&self, mx: f32, my: f32) {
+        let Some((floors, units)) = self.sandbox_setup else {
+            return;
+        };
+        draw_menu_button(difficulty_back_rect(), "< Back", mx, my);
+        let rows = [
+            format!("Floors: {}", floors),
+            format!("Units per floor: {}", units),
+        ];
+        for (row, label) in rows.iter().enumerate() {
+            let (minus, plus) = sandbox_step_rects(row);
+            draw_ui_text(
+                label,
+                screen_width() / 2.0 - 150.0,
+                minus.y + 26.0,
+                20.0,
+                WHITE,
+            );
+            draw_menu_button(minus, " -", mx, my);
+            draw_menu_button(plus, " +", mx, my);
+        }
+        draw_menu_button(sandbox_start_rect(), "Start Sandbox", mx, my);
+    }
+
+    fn draw_scenario_cards(&self, count: usize, mx: f32, my: f32) {
+        draw_menu_button(difficulty_back_rect(), "< Back", mx, my);
+        for (i, scenario) in self.scenarios.iter().enumerate() {
+            let rect = card_rect(i, count);
+            let bg = if rect.contains(vec2(mx, my)) {
+                Color::from_rgba(70, 80, 100, 255)
+            } else {
+                Color::from_rgba(50, 55, 65, 255)
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+            draw_rectangle_lines(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                3.0,
+                Color::from_rgba(150, 100, 200, 255),
+            );
+            draw_ui_text(&scenario.name, rect.x + 15.0, rect.y + 30.0, 22.0, WHITE);
+            let limit = match scenario.time_limit_ticks {
+                Some(months) => format!("{} months", months),
+                None => "No time limit".to_string(),
+            };
+            draw_ui_text(
+                &format!("{} · {} objectives", limit, scenario.objectives.len()),
+                rect.x + 15.0,
+                rect.y + 52.0,
+                14.0,
+                Color::from_rgba(200, 180, 60, 255),
+            );
+            let desc = if scenario.description.chars().count() > 40 {
+                let truncated: String = scenario.description.chars().take(37).collect();
+                format!("{}...", truncated)
+            } else {
+                scenario.description.clone()
+            };
+            draw_ui_text(
+                &desc,
+                rect.x + 15.0,
+                rect.y + 75.0,
+                12.0,
+                Color::from_rgba(180, 180, 180, 255),
+            );
+        }
+    }
+
+    fn draw_difficulty_cards(&self, count: usize, mx: f32, my: f32) {
+        for (i, preset) in DifficultyPreset::ALL.iter().enumerate() {
+            let rect = card_rect(i, count);
+            let bg = if rect.contains(vec2(mx, my)) {
+                Color::from_rgba(70, 80, 100, 255)
+            } else {
+                Color::from_rgba(50, 55, 65, 255)
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+            draw_rectangle_lines(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                3.0,
+                difficulty_color(*preset),
+            );
+            draw_ui_text(preset.name(), rect.x + 15.0, rect.y + 30.0, 22.0, WHITE);
+            draw_ui_text(
+                preset.description(),
+                rect.x + 15.0,
+                rect.y + 60.0,
+                12.0,
+                Color::from_rgba(180, 180, 180, 255),
+            );
+        }
+    }
+
+    fn draw_building_cards(&self, count: usize, mx: f32, my: f32) {
         for (i, template) in self.templates.iter().enumerate() {
             let rect = card_rect(i, count);
             let (x, y) = (rect.x, rect.y);
@@ -266,79 +745,24 @@ impl MenuState {
                 );
             }
         }
-
-        // Continue button (if save exists)
-        if self.has_save {
-            let btn_w = 200.0;
-            let btn_h = 45.0;
-            let btn_x = screen_width() / 2.0 - btn_w / 2.0;
-            let btn_y = grid_bottom(count) + 40.0;
-
-            let hovered = mx >= btn_x && mx <= btn_x + btn_w && my >= btn_y && my <= btn_y + btn_h;
-            let bg = if hovered {
-                Color::from_rgba(60, 100, 60, 255)
-            } else {
-                Color::from_rgba(50, 80, 50, 255)
-            };
-
-            draw_rectangle(btn_x, btn_y, btn_w, btn_h, bg);
-            draw_rectangle_lines(
-                btn_x,
-                btn_y,
-                btn_w,
-                btn_h,
-                2.0,
-                Color::from_rgba(80, 140, 80, 255),
-            );
-
-            let label = "Continue Saved Game";
-            let label_width = measure_ui_text(label, None, 18, 1.0).width;
-            draw_ui_text(
-                label,
-                btn_x + (btn_w - label_width) / 2.0,
-                btn_y + 28.0,
-                18.0,
-                WHITE,
-            );
-        }
-
-        // Quit button — native only (see update()).
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            let quit_btn_w = 150.0;
-            let quit_btn_h = 40.0;
-            let quit_btn_x = screen_width() / 2.0 - quit_btn_w / 2.0;
-            let quit_btn_y = screen_height() - 80.0;
-
-            let quit_hovered = mx >= quit_btn_x
-                && mx <= quit_btn_x + quit_btn_w
-                && my >= quit_btn_y
-                && my <= quit_btn_y + quit_btn_h;
-            let quit_bg = if quit_hovered {
-                Color::from_rgba(100, 60, 60, 255)
-            } else {
-                Color::from_rgba(70, 45, 45, 255)
-            };
-
-            draw_rectangle(quit_btn_x, quit_btn_y, quit_btn_w, quit_btn_h, quit_bg);
-            draw_rectangle_lines(
-                quit_btn_x,
-                quit_btn_y,
-                quit_btn_w,
-                quit_btn_h,
-                2.0,
-                Color::from_rgba(140, 80, 80, 255),
-            );
-
-            let label = "Quit";
-            let label_width = measure_ui_text(label, None, 18, 1.0).width;
-            draw_ui_text(
-                label,
-                quit_btn_x + (quit_btn_w - label_width) / 2.0,
-                quit_btn_y + 26.0,
-                18.0,
-                WHITE,
-            );
-        }
     }
 }
+
+/// Plain menu button in the style of the difficulty button.
+fn draw_menu_button(rect: Rect, label: &str, mx: f32, my: f32) {
+    let bg = if rect.contains(vec2(mx, my)) {
+        Color::from_rgba(70, 80, 100, 255)
+    } else {
+        Color::from_rgba(50, 55, 65, 255)
+    };
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+    draw_rectangle_lines(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        2.0,
+        Color::from_rgba(100, 100, 100, 255),
+    );
+    draw_ui_text(label, rect.x + 12.0, rect.y + 23.0, 16.0, WHITE);
+}
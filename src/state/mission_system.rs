@@ -2,7 +2,6 @@ use super::GameplayState;
 use crate::narrative::{ActiveTaxBreak, MissionGoal, MissionReward, MissionStatus};
 use crate::simulation::GameEvent;
 use crate::ui::colors;
-use macroquad::prelude::*;
 
 /// System for handling mission updates and rewards
 pub fn update_missions(state: &mut GameplayState) {
@@ -10,27 +9,7 @@ pub fn update_missions(state: &mut GameplayState) {
 
     // Snapshot this month's building-wide signals up front so per-mission
     // evaluation can read them without borrowing conflicts.
-    let avg_happiness = if state.tenants.is_empty() {
-        0.0
-    } else {
-        state
-            .tenants
-            .iter()
-            .map(|t| t.happiness as f32)
-            .sum::<f32>()
-            / state.tenants.len() as f32
-    };
-    // "Perfect collection" = at least one tenant and no missed-rent event this
-    // month.
-    let perfect_collection = !state.tenants.is_empty()
-        && state.last_tick_result.as_ref().is_some_and(|r| {
-            !r.events
-                .iter()
-                .any(|e| matches!(e, GameEvent::RentMissed { .. }))
-        });
-    let building_fully_repaired = !state.building.apartments.is_empty()
-        && state.building.apartments.iter().all(|a| a.condition >= 90)
-        && state.building.hallway_condition >= 90;
+    let signals = MonthSignals::gather(state);
 
     // Check for expirations (expired missions are marked as such)
     state.missions.check_expirations(current_month);
@@ -43,11 +22,7 @@ pub fn update_missions(state: &mut GameplayState) {
                 && state.city.buildings.is_empty()
             {
                 mission.fail();
-                state.floating_texts.spawn(
-                    "Mission Failed!",
-                    vec2(screen_width() / 2.0, screen_height() / 2.0),
-                    colors::NEGATIVE(),
-                );
+                state.spawn_center_text("Mission Failed!", 0.0, 0.0, colors::NEGATIVE());
             }
         }
     }
@@ -61,80 +36,23 @@ pub fn update_missions(state: &mut GameplayState) {
         .collect();
 
     for mission_id in active_mission_ids {
-        let mut completed = false;
         let mut reward = None;
         let mut legacy_info: Option<(String, String)> = None;
 
+        let Some(mission) = state.missions.missions.iter().find(|m| m.id == mission_id) else {
+            continue;
+        };
+        let mut goal = mission.goal.clone();
+        let started_month = mission.started_month.unwrap_or(current_month);
+        let completed = goal_met(&mut goal, started_month, state, &signals);
+
         if let Some(mission) = state
             .missions
             .missions
             .iter_mut()
             .find(|m| m.id == mission_id)
         {
-            match &mut mission.goal {
-                MissionGoal::HouseTenants { count, archetype } => {
-                    let current_count = state
-                        .tenants
-                        .iter()
-                        .filter(|t| {
-                            archetype
-                                .as_ref()
-                                .is_none_or(|arch| t.archetype.name() == arch)
-                        })
-                        .count();
-                    if current_count as u32 >= *count {
-                        completed = true;
-                    }
-                }
-                MissionGoal::ReachOccupancy { percentage } => {
-                    let total = state.building.apartments.len();
-                    let occupied = state.building.occupancy_count();
-                    if total > 0 && (occupied as f32 / total as f32) >= *percentage {
-                        completed = true;
-                    }
-                }
-                MissionGoal::AcquireBuilding => {
-                    if state.city.buildings.len() > 1 {
-                        // Started with 1.
-                        completed = true;
-                    }
-                }
-                MissionGoal::MaintainHappiness {
-                    threshold,
-                    months,
-                    current_months,
-                } => {
-                    // Accrue consecutive months at/above the happiness threshold;
-                    // a bad month resets the streak.
-                    if !state.tenants.is_empty() && avg_happiness >= *threshold {
-                        *current_months += 1;
-                    } else {
-                        *current_months = 0;
-                    }
-                    if *current_months >= *months {
-                        completed = true;
-                    }
-                }
-                MissionGoal::PerfectCollection {
-                    months,
-                    current_months,
-                } => {
-                    if perfect_collection {
-                        *current_months += 1;
-                    } else {
-                        *current_months = 0;
-                    }
-                    if *current_months >= *months {
-                        completed = true;
-                    }
-                }
-                MissionGoal::FullRepair { building_id: _ } => {
-                    if building_fully_repaired {
-                        completed = true;
-                    }
-                }
-            }
-
+            mission.goal = goal;
             if completed {
                 mission.complete();
                 reward = Some(mission.reward.clone());
@@ -144,11 +62,20 @@ pub fn update_missions(state: &mut GameplayState) {
 
         // Record legacy (outside the mutable borrow)
         if let Some((title, description)) = legacy_info {
+            state.lifetime_stats.record_mission_completed();
             state.missions.record_legacy_event(
                 current_month,
                 &format!("Mission Complete: {}", title),
                 &format!("Completed objective: {}", description),
             );
+            if let Some(next) = state.missions.unlock_follow_up(mission_id, current_month) {
+                state.spawn_center_text(
+                    format!("New mission: {}", next),
+                    0.0,
+                    -30.0,
+                    colors::ACCENT(),
+                );
+            }
         }
 
         // Grant reward
@@ -163,19 +90,11 @@ pub fn update_missions(state: &mut GameplayState) {
                     );
                     state.funds.add_income(t);
 
-                    state.floating_texts.spawn(
-                        format!("+${}", amount),
-                        vec2(screen_width() / 2.0, screen_height() / 2.0 + 30.0),
-                        colors::POSITIVE(),
-                    );
+                    state.spawn_center_text(format!("+${}", amount), 0.0, 30.0, colors::POSITIVE());
                 }
                 MissionReward::UnlockBuilding(unlock_order) => {
                     state.unlock_building_by_order(unlock_order);
-                    state.floating_texts.spawn(
-                        "New property unlocked!",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0 + 30.0),
-                        colors::ACCENT(),
-                    );
+                    state.spawn_center_text("New property unlocked!", 0.0, 30.0, colors::ACCENT());
                 }
                 MissionReward::Reputation(amount) => {
                     // Reward reputation in the active building's neighborhood.
@@ -185,13 +104,14 @@ pub fn update_missions(state: &mut GameplayState) {
                     state
                         .active_tax_breaks
                         .push(ActiveTaxBreak::new(months, percentage));
-                    state.floating_texts.spawn(
+                    state.spawn_center_text(
                         format!(
                             "Tax Break! {}% for {} months",
                             (percentage * 100.0) as i32,
                             months
                         ),
-                        vec2(screen_width() / 2.0, screen_height() / 2.0 + 30.0),
+                        0.0,
+                        30.0,
                         colors::POSITIVE(),
                     );
                 }
@@ -200,6 +120,140 @@ pub fn update_missions(state: &mut GameplayState) {
     }
 }
 
+/// Building-wide signals for the month, gathered once before any goal is
+/// checked.
+pub(super) struct MonthSignals {
+    avg_happiness: f32,
+    perfect_collection: bool,
+    building_fully_repaired: bool,
+}
+
+impl MonthSignals {
+    pub(super) fn gather(state: &GameplayState) -> Self {
+        let avg_happiness = if state.tenants.is_empty() {
+            0.0
+        } else {
+            state
+                .tenants
+                .iter()
+                .map(|t| t.happiness as f32)
+                .sum::<f32>()
+                / state.tenants.len() as f32
+        };
+        // "Perfect collection" = at least one tenant and no missed-rent event this
+        // month.
+        let perfect_collection = !state.tenants.is_empty()
+            && state.last_tick_result.as_ref().is_some_and(|r| {
+                !r.events
+                    .iter()
+                    .any(|e| matches!(e, GameEvent::RentMissed { .. }))
+            });
+        let building_fully_repaired = !state.building.apartments.is_empty()
+            && state.building.apartments.iter().all(|a| a.condition >= 90)
+            && state.building.hallway_condition >= 90;
+
+        Self {
+            avg_happiness,
+            perfect_collection,
+            building_fully_repaired,
+        }
+    }
+}
+
+/// Whether `goal` is met this month. Streak goals advance (or reset) their
+/// counters, so call this once per goal per month.
+pub(super) fn goal_met(
+    goal: &mut MissionGoal,
+    started_month: u32,
+    state: &GameplayState,
+    signals: &MonthSignals,
+) -> bool {
+    let mut completed = false;
+    match goal {
+        MissionGoal::HouseTenants { count, archetype } => {
+            let current_count = state
+                .tenants
+                .iter()
+                .filter(|t| {
+                    archetype
+                        .as_ref()
+                        .is_none_or(|arch| t.archetype.name() == arch)
+                })
+                .count();
+            if current_count as u32 >= *count {
+                completed = true;
+            }
+        }
+        MissionGoal::ReachOccupancy { percentage } => {
+            let total = state.building.apartments.len();
+            let occupied = state.building.occupancy_count();
+            if total > 0 && (occupied as f32 / total as f32) >= *percentage {
+                completed = true;
+            }
+        }
+        MissionGoal::AcquireBuilding => {
+            if state.city.buildings.len() > 1 {
+                // Started with 1.
+                completed = true;
+            }
+        }
+        MissionGoal::MaintainHappiness {
+            threshold,
+            months,
+            current_months,
+        } => {
+            // Accrue consecutive months at/above the happiness threshold;
+            // a bad month resets the streak.
+            if !state.tenants.is_empty() && signals.avg_happiness >= *threshold {
+                *current_months += 1;
+            } else {
+                *current_months = 0;
+            }
+            if *current_months >= *months {
+                completed = true;
+            }
+        }
+        MissionGoal::PerfectCollection {
+            months,
+            current_months,
+        } => {
+            if signals.perfect_collection {
+                *current_months += 1;
+            } else {
+                *current_months = 0;
+            }
+            if *current_months >= *months {
+                completed = true;
+            }
+        }
+        MissionGoal::FullRepair { building_id: _ } => {
+            if signals.building_fully_repaired {
+                completed = true;
+            }
+        }
+        MissionGoal::SurviveMonths { months } => {
+            if state.current_tick.saturating_sub(started_month) >= *months {
+                completed = true;
+            }
+        }
+        MissionGoal::InstallUpgrade { flag } => {
+            if state.building.flags.contains(flag.as_str()) {
+                completed = true;
+            }
+        }
+        MissionGoal::ApplyForGrant { requirements, .. } => {
+            let threshold = state.config.gentrification.affordable_threshold;
+            if requirements
+                .iter()
+                .all(|r| r.is_met(&state.building, threshold))
+            {
+                completed = true;
+            }
+        }
+    }
+    completed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
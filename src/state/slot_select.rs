@@ -0,0 +1,274 @@
+use super::{GameplayState, StateTransition};
+use crate::data::config::{DifficultyPreset, GameConfig};
+use crate::data::templates::BuildingTemplate;
+use crate::narrative::Scenario;
+use crate::save::{slot_summary, SlotSummary, SAVE_SLOT_COUNT};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+const SLOT_W: f32 = 420.0;
+const SLOT_H: f32 = 90.0;
+const SLOT_SPACING: f32 = 16.0;
+
+/// Rect for slot card `i`, shared by hit-testing and rendering.
+fn slot_rect(i: u8) -> Rect {
+    Rect::new(
+        screen_width() / 2.0 - SLOT_W / 2.0,
+        screen_height() * 0.25 + i as f32 * (SLOT_H + SLOT_SPACING),
+        SLOT_W,
+        SLOT_H,
+    )
+}
+
+fn back_rect() -> Rect {
+    Rect::new(
+        screen_width() / 2.0 - 75.0,
+        screen_height() - 80.0,
+        150.0,
+        40.0,
+    )
+}
+
+/// Everything picked on the menu for a new game.
+pub struct NewGameSetup {
+    pub template: BuildingTemplate,
+    pub difficulty: DifficultyPreset,
+    /// Start without the tutorial (veterans only; see `PlayerProgress`).
+    pub skip_tutorial: bool,
+    /// Play this scenario instead of the campaign.
+    pub scenario: Option<Scenario>,
+    /// Free play with no win or loss conditions.
+    pub sandbox: bool,
+    /// Seed typed on the menu; `None` picks a fresh one.
+    pub seed: Option<u64>,
+}
+
+/// Sits between the menu and gameplay. With a `pending_game` the player is
+/// picking where a new game goes (any slot, overwriting); without one they
+/// are picking an existing save to load (occupied slots only).
+pub struct SlotSelectState {
+    pending_game: Option<NewGameSetup>,
+    slots: Vec<Option<SlotSummary>>,
+}
+
+impl SlotSelectState {
+    pub fn new(pending_game: Option<NewGameSetup>) -> Self {
+        Self {
+            pending_game,
+            slots: (0..SAVE_SLOT_COUNT).map(slot_summary).collect(),
+        }
+    }
+
+    fn is_selectable(&self, slot: u8) -> bool {
+        self.pending_game.is_some() || self.slots[slot as usize].is_some()
+    }
+
+    pub fn update(&mut self, config: &GameConfig) -> Option<StateTransition> {
+        if !is_mouse_button_pressed(MouseButton::Left) {
+            return None;
+        }
+        let (mx, my) = mouse_position();
+        let mouse = vec2(mx, my);
+
+        if back_rect().contains(mouse) {
+            return Some(StateTransition::ToMenu);
+        }
+
+        for slot in 0..SAVE_SLOT_COUNT {
+            if !slot_rect(slot).contains(mouse) || !self.is_selectable(slot) {
+                continue;
+            }
+            return Some(match &self.pending_game {
+                Some(setup) => {
+                    let mut state = match &setup.scenario {
+                        Some(scenario) => match GameplayState::new_scenario(
+                            config.clone(),
+                            setup.template.clone(),
+                            scenario,
+                        ) {
+                            Ok(state) => state,
+                            Err(e) => {
+                                eprintln!("Failed to start scenario {}: {}", scenario.id, e);
+                                return Some(StateTransition::ToMenu);
+                            }
+                        },
+                        None => GameplayState::new_with_chosen_seed(
+                            config.clone(),
+                            setup.template.clone(),
+                            setup.difficulty,
+                            setup.seed,
+                        ),
+                    };
+                    state.save_slot = slot;
+                    state.sandbox_mode = setup.sandbox;
+                    if setup.skip_tutorial {
+                        super::tutorial_system::skip_tutorial(&mut state);
+                    }
+                    StateTransition::ToGameplay(state)
+                }
+                None => StateTransition::ToGameplayFromSlot(slot),
+            });
+        }
+
+        None
+    }
+
+    pub fn draw(&self) {
+        clear_background(Color::from_rgba(25, 25, 30, 255));
+
+        let title = if self.pending_game.is_some() {
+            "Choose a Slot for the New Game"
+        } else {
+            "Load Game"
+        };
+        let title_size = 32.0;
+        let title_width = measure_ui_text(title, None, title_size as u16, 1.0).width;
+        draw_ui_text(
+            title,
+            screen_width() / 2.0 - title_width / 2.0,
+            screen_height() * 0.25 - 30.0,
+            title_size,
+            WHITE,
+        );
+
+        let (mx, my) = mouse_position();
+        let mouse = vec2(mx, my);
+
+        for (slot, summary) in (0..SAVE_SLOT_COUNT).zip(&self.slots) {
+            let rect = slot_rect(slot);
+            let selectable = self.is_selectable(slot);
+            let hovered = selectable && rect.contains(mouse);
+
+            let bg = if !selectable {
+                Color::from_rgba(40, 40, 45, 200)
+            } else if hovered {
+                Color::from_rgba(70, 80, 100, 255)
+            } else {
+                Color::from_rgba(50, 55, 65, 255)
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, bg);
+            draw_rectangle_lines(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                2.0,
+                Color::from_rgba(100, 110, 130, 255),
+            );
+
+            let dim = Color::from_rgba(180, 180, 180, 255);
+            draw_ui_text(
+                &format!("Slot {}", slot + 1),
+                rect.x + 15.0,
+                rect.y + 24.0,
+                14.0,
+                dim,
+            );
+
+            match summary {
+                Some(summary) => {
+                    draw_ui_text(
+                        &summary.building_name,
+                        rect.x + 15.0,
+                        rect.y + 50.0,
+                        22.0,
+                        WHITE,
+                    );
+                    let detail = if summary.saved_at > 0.0 {
+                        format!(
+                            "Month {}  •  Saved {}",
+                            summary.current_tick,
+                            format_timestamp(summary.saved_at)
+                        )
+                    } else {
+                        format!("Month {}", summary.current_tick)
+                    };
+                    draw_ui_text(&detail, rect.x + 15.0, rect.y + 74.0, 14.0, dim);
+                    if summary.new_game_plus {
+                        draw_ui_text(
+                            "New Game+",
+                            rect.x + rect.w - 190.0,
+                            rect.y + 24.0,
+                            14.0,
+                            Color::from_rgba(220, 190, 80, 255),
+                        );
+                    }
+                    if self.pending_game.is_some() {
+                        draw_ui_text(
+                            "Overwrite",
+                            rect.x + rect.w - 90.0,
+                            rect.y + 24.0,
+                            14.0,
+                            Color::from_rgba(200, 120, 100, 255),
+                        );
+                    }
+                }
+                None => {
+                    draw_ui_text(
+                        "Empty",
+                        rect.x + 15.0,
+                        rect.y + 50.0,
+                        22.0,
+                        Color::from_rgba(110, 110, 110, 255),
+                    );
+                }
+            }
+        }
+
+        let back = back_rect();
+        let back_bg = if back.contains(mouse) {
+            Color::from_rgba(80, 80, 90, 255)
+        } else {
+            Color::from_rgba(60, 60, 70, 255)
+        };
+        draw_rectangle(back.x, back.y, back.w, back.h, back_bg);
+        let label = "Back";
+        let label_width = measure_ui_text(label, None, 18, 1.0).width;
+        draw_ui_text(
+            label,
+            back.x + (back.w - label_width) / 2.0,
+            back.y + 26.0,
+            18.0,
+            WHITE,
+        );
+    }
+}
+
+/// Format Unix-epoch seconds as "YYYY-MM-DD HH:MM" (UTC). There is no date
+/// crate in the dependency tree, so this uses the days-to-civil conversion.
+fn format_timestamp(epoch_seconds: f64) -> String {
+    let secs = epoch_seconds.max(0.0) as i64;
+    let days = secs.div_euclid(86_400);
+    let secs_of_day = secs.rem_euclid(86_400);
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        (secs_of_day % 3_600) / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0), "1970-01-01 00:00");
+        // 2024-02-29 13:45:00 UTC (leap day)
+        assert_eq!(format_timestamp(1_709_214_300.0), "2024-02-29 13:45");
+    }
+}
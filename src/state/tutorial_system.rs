@@ -1,10 +1,13 @@
 use super::GameplayState;
 use crate::narrative::TutorialMilestone;
+use crate::tenant::generate_applications;
 use crate::ui::colors;
-use macroquad::prelude::*;
 
 /// System for handling tutorial updates and milestones
 pub fn update_tutorial(state: &mut GameplayState) {
+    let hint = state.tutorial.get_hint(&state.selection, state);
+    state.tutorial.refresh_hint(hint, state.current_tick);
+
     // Skip if tutorial is complete
     if state.tutorial.is_complete() {
         return;
@@ -29,18 +32,6 @@ pub fn update_tutorial(state: &mut GameplayState) {
         }
     }
 
-    // Display hint for current milestone if stuck for a while
-    if let Some(hint) = state.tutorial.get_hint() {
-        // Show hint as floating text occasionally (every 5 ticks if no progress)
-        if state.current_tick.is_multiple_of(5) && state.current_tick > 0 {
-            state.floating_texts.spawn(
-                hint,
-                vec2(screen_width() / 2.0, screen_height() - 100.0),
-                colors::TEXT_DIM(),
-            );
-        }
-    }
-
     if let Some(milestone) = &state.tutorial.current_milestone {
         match milestone {
             TutorialMilestone::InheritedMess => {
@@ -53,11 +44,7 @@ pub fn update_tutorial(state: &mut GameplayState) {
                     state
                         .tutorial
                         .complete_milestone(TutorialMilestone::InheritedMess);
-                    state.floating_texts.spawn(
-                        "Tutorial: Cleaned Up!",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::POSITIVE(),
-                    );
+                    state.spawn_center_text("Tutorial: Cleaned Up!", 0.0, 0.0, colors::POSITIVE());
                 }
             }
             TutorialMilestone::FirstResident => {
@@ -68,9 +55,10 @@ pub fn update_tutorial(state: &mut GameplayState) {
                     state
                         .tutorial
                         .complete_milestone(TutorialMilestone::FirstResident);
-                    state.floating_texts.spawn(
+                    state.spawn_center_text(
                         "Tutorial: First Resident!",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0 + 30.0),
+                        0.0,
+                        30.0,
                         colors::POSITIVE(),
                     );
 
@@ -81,11 +69,7 @@ pub fn update_tutorial(state: &mut GameplayState) {
                     }
 
                     // Visual cue
-                    state.floating_texts.spawn(
-                        "⚠ LEAK DETECTED!",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0 + 60.0),
-                        colors::NEGATIVE(),
-                    );
+                    state.spawn_center_text("⚠ LEAK DETECTED!", 0.0, 60.0, colors::NEGATIVE());
                 }
             }
             TutorialMilestone::TheLeak => {
@@ -99,16 +83,82 @@ pub fn update_tutorial(state: &mut GameplayState) {
                     state
                         .tutorial
                         .complete_milestone(TutorialMilestone::TheLeak);
-                    state.floating_texts.spawn(
-                        "Tutorial Complete!",
-                        vec2(screen_width() / 2.0, screen_height() / 2.0),
-                        colors::POSITIVE(),
-                    );
+                    state.spawn_center_text("Tutorial Complete!", 0.0, 0.0, colors::POSITIVE());
 
                     // Messages are already in pending_messages and will be shown by the tutorial overlay
+                    record_tutorial_completed();
                 }
             }
             TutorialMilestone::Complete => {}
         }
     }
 }
+
+/// Applicants waiting on day one of a game that skips the tutorial.
+const STARTER_APPLICATIONS: usize = 3;
+
+/// Remember in player progress that the tutorial has been finished, so later
+/// campaigns can skip it.
+fn record_tutorial_completed() {
+    use crate::save::{load_player_progress, save_player_progress};
+
+    let mut progress = load_player_progress();
+    if !progress.tutorial_completed_before {
+        progress.tutorial_completed_before = true;
+        let _ = save_player_progress(&progress);
+    }
+}
+
+/// Start a game past the tutorial: no milestones, and the applicants the
+/// tutorial would have walked the player toward are already waiting.
+pub fn skip_tutorial(state: &mut GameplayState) {
+    state.tutorial.active = false;
+    state.tutorial.current_milestone = Some(TutorialMilestone::Complete);
+    state.tutorial.pending_messages.clear();
+
+    for apt in state.building.apartments.iter_mut() {
+        if apt.is_vacant() {
+            apt.is_listed_for_lease = true;
+        }
+    }
+
+    // Generation is chance-based per listing; retry a bounded number of times.
+    let reputation_multiplier = state.application_reputation_multiplier();
+    let school_rating = state.active_neighborhood_school_rating();
+    for _ in 0..20 {
+        if state.applications.len() >= STARTER_APPLICATIONS {
+            break;
+        }
+        let new_applications = generate_applications(
+            &state.building,
+            &state.applications,
+            state.current_tick,
+            &mut state.next_tenant_id,
+            reputation_multiplier,
+            school_rating,
+            &state.config,
+        );
+        state.applications.extend(new_applications);
+    }
+    state.applications.truncate(STARTER_APPLICATIONS);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skipping_the_tutorial_disables_milestones() {
+        let mut state = GameplayState::new();
+        let condition = state.building.hallway_condition;
+        skip_tutorial(&mut state);
+
+        assert!(!state.tutorial.active);
+        assert_eq!(
+            state.tutorial.current_milestone,
+            Some(TutorialMilestone::Complete)
+        );
+        assert!(state.applications.len() <= STARTER_APPLICATIONS);
+        assert_eq!(state.building.hallway_condition, condition);
+    }
+}
@@ -8,13 +8,18 @@
 
 mod application;
 mod archetype;
+mod guarantor;
 pub mod happiness;
 pub mod matching;
 mod tenant;
 pub mod vetting;
 
 pub use archetype::{ArchetypePreferences, TenantArchetype};
+pub use guarantor::{cover_missed_rent, GuarantorInfo};
 pub use happiness::calculate_happiness;
-pub use tenant::Tenant;
+pub use tenant::{EmergencyContact, Tenant};
 // pub use matching::MatchResult;
-pub use application::{generate_applications, process_departures, TenantApplication};
+pub use application::{
+    best_application, file_applications, generate_applications, process_departures,
+    promote_waitlisted, Abandonment, TenantApplication,
+};
@@ -1,6 +1,8 @@
-use super::{matching::MatchResult, Tenant, TenantArchetype};
-use crate::building::Building;
-use crate::data::config::{GameConfig, TenantRiskConfig};
+use super::matching::{evaluate_lease_offer, LeaseOffer, MatchResult};
+use super::{Tenant, TenantArchetype};
+use crate::building::{Apartment, Building};
+use crate::data::config::{GameConfig, TenantRiskConfig, VettingConfig};
+use crate::economy::{PlayerFunds, Transaction, TransactionType};
 use macroquad_toolkit::rng;
 use serde::{Deserialize, Serialize};
 
@@ -47,6 +49,15 @@ pub struct TenantApplication {
     // Vetting state (hidden stats revealed after checks)
     pub revealed_reliability: bool, // Credit check done?
     pub revealed_behavior: bool,    // Background check done?
+    #[serde(default)]
+    pub reference: Option<super::vetting::ReferenceResult>, // Reference check done?
+    /// Applicant wants to meet before signing; accepting opens an interview.
+    #[serde(default)]
+    pub interview_required: bool,
+    /// Hidden: the credit history is forged. Only running both the credit
+    /// and background checks exposes the contradiction.
+    #[serde(default)]
+    pub fraud_risk: bool,
 }
 
 impl TenantApplication {
@@ -58,12 +69,75 @@ impl TenantApplication {
             tick_created: tick,
             revealed_reliability: false,
             revealed_behavior: false,
+            reference: None,
+            interview_required: false,
+            fraud_risk: false,
         }
     }
 
+    pub fn has_housing_voucher(&self) -> bool {
+        self.tenant.has_housing_voucher
+    }
+
+    pub fn guarantor(&self) -> Option<&super::GuarantorInfo> {
+        self.tenant.guarantor.as_ref()
+    }
+
+    /// A voucher holder who would take the unit; while the city's voucher
+    /// program runs, turning one away is discrimination.
+    pub fn voucher_qualifies(&self) -> bool {
+        self.has_housing_voucher() && self.match_result.meets_minimum
+    }
+
     pub fn is_expired_after(&self, current_tick: u32, expire_after_ticks: u32) -> bool {
         current_tick > self.tick_created + expire_after_ticks
     }
+
+    /// Both checks are back and disagree: spotless credit alongside a poor
+    /// rental history, the signature of a forged credit report.
+    pub fn records_inconsistent(&self, config: &VettingConfig) -> bool {
+        self.revealed_reliability
+            && self.revealed_behavior
+            && self.tenant.rent_reliability >= config.fraud_credit_score_min
+            && self.tenant.behavior_score < config.fraud_behavior_score_max
+    }
+
+    /// Match score weighted by the chance the applicant signs a standard
+    /// lease at `rent_price`, used to rank the pool for one unit.
+    pub fn ranking_score(&self, rent_price: i32, config: &GameConfig) -> i32 {
+        let offer = LeaseOffer::from_config(rent_price, &config.matching.lease_defaults);
+        let acceptance = evaluate_lease_offer(
+            &self.tenant,
+            &offer,
+            &config.happiness,
+            &config.matching.lease_acceptance,
+        );
+        (self.match_result.score as f32 * acceptance).round() as i32
+    }
+}
+
+/// Index of the highest-ranked pending application for `apartment_id`.
+pub fn best_application(
+    applications: &[TenantApplication],
+    building: &Building,
+    apartment_id: u32,
+    config: &GameConfig,
+) -> Option<usize> {
+    let rent = building.get_apartment(apartment_id)?.rent_price;
+    applications
+        .iter()
+        .enumerate()
+        .filter(|(_, app)| app.apartment_id == apartment_id)
+        .max_by_key(|(_, app)| app.ranking_score(rent, config))
+        .map(|(i, _)| i)
+}
+
+/// Make an applicant a fraud: an excellent-looking credit score over a
+/// poor rental history.
+fn forge_credit_history(application: &mut TenantApplication, config: &VettingConfig) {
+    application.fraud_risk = true;
+    application.tenant.rent_reliability = rng::gen_range(config.fraud_credit_score_min, 96);
+    application.tenant.behavior_score = rng::gen_range(5, config.fraud_behavior_score_max);
 }
 
 /// Generate new tenant applications for listed apartments
@@ -73,6 +147,7 @@ pub fn generate_applications(
     current_tick: u32,
     next_tenant_id: &mut u32,
     reputation_multiplier: f32,
+    school_rating: i32,
     config: &GameConfig,
 ) -> Vec<TenantApplication> {
     let mut new_applications = Vec::new();
@@ -81,7 +156,7 @@ pub fn generate_applications(
     let listed_apartments: Vec<&_> = building
         .vacant_apartments()
         .into_iter()
-        .filter(|a| a.is_listed_for_lease)
+        .filter(|a| a.is_listed_for_lease && !a.has_squatter())
         .collect();
 
     if listed_apartments.is_empty() {
@@ -104,12 +179,16 @@ pub fn generate_applications(
         1.0
     };
 
+    let season = crate::simulation::Season::from_tick(current_tick);
+    let seasonal_multiplier = config.seasonal_modifiers.application_multipliers[season.index()];
+
     // 2. Generate applications for EACH listed apartment
     for apt in listed_apartments {
         // Base probability per apartment
         let appeal_divisor = config.applications.appeal_bonus_divisor.max(1) as f32;
         let appeal_factor = (building_appeal as f32 / appeal_divisor).max(0.5);
         let chance = config.applications.base_per_vacancy
+            * seasonal_multiplier
             * appeal_factor
             * marketing_multiplier
             * open_house_multiplier
@@ -121,33 +200,54 @@ pub fn generate_applications(
             let archetype = pick_archetype_with_preference(
                 &building.marketing_strategy,
                 apt.preferred_archetype.as_ref(),
+                school_rating,
             );
 
+            // Nobody applies for a unit they could never pay for.
+            if apt.rent_price > archetype.max_affordable_rent(&config.happiness) {
+                continue;
+            }
+
             // Generate tenant
             let mut tenant = Tenant::generate(*next_tenant_id, archetype);
             apply_applicant_risk_profile(&mut tenant, &config.tenant_risk);
+            if tenant.archetype == TenantArchetype::Student
+                && rng::gen_range(0, 100) < config.applications.student_guarantor_chance_percent
+            {
+                tenant.guarantor = Some(super::GuarantorInfo::generate(&tenant.name));
+            }
+            if rng::gen_range(0, 100) < config.applications.voucher_holder_chance_percent {
+                tenant.has_housing_voucher = true;
+                tenant.voucher_amount =
+                    (tenant.rent_tolerance as f32 * config.applications.voucher_share) as i32;
+            }
             *next_tenant_id += 1;
 
             // Check match
             let apt_slice = [apt];
-            if let Some((_, match_result)) =
-                super::matching::find_best_match(&tenant, &apt_slice, &config.matching)
-            {
-                // Check dupes
-                let already_applied =
-                    existing_applications.iter().any(|app| {
-                        app.apartment_id == apt.id && app.tenant.archetype == tenant.archetype
-                    }) || new_applications.iter().any(|app: &TenantApplication| {
-                        app.apartment_id == apt.id && app.tenant.archetype == tenant.archetype
-                    });
+            if let Some((_, match_result)) = super::matching::find_best_match(
+                &tenant,
+                &apt_slice,
+                super::matching::BuildingFeatures::of(building),
+                &config.matching,
+            ) {
+                // Check dupes, including the waitlist
+                let same_applicant = |app: &TenantApplication| {
+                    app.apartment_id == apt.id && app.tenant.archetype == tenant.archetype
+                };
+                let already_applied = existing_applications.iter().any(same_applicant)
+                    || building.waitlisted_applications.iter().any(same_applicant)
+                    || new_applications.iter().any(same_applicant);
 
                 if !already_applied {
-                    new_applications.push(TenantApplication::new(
-                        tenant,
-                        apt.id,
-                        match_result,
-                        current_tick,
-                    ));
+                    let mut application =
+                        TenantApplication::new(tenant, apt.id, match_result, current_tick);
+                    application.interview_required =
+                        rng::gen_range(0, 100) < config.applications.interview_chance_percent;
+                    if rng::gen_range(0, 100) < config.vetting.fraud_chance_percent {
+                        forge_credit_history(&mut application, &config.vetting);
+                    }
+                    new_applications.push(application);
                 }
             }
         }
@@ -156,9 +256,104 @@ pub fn generate_applications(
     new_applications
 }
 
+/// File new applications into the active pool. A unit that already has
+/// `max_per_unit` pending applications sends the rest to the waitlist.
+pub fn file_applications(
+    new_applications: Vec<TenantApplication>,
+    applications: &mut Vec<TenantApplication>,
+    building: &mut Building,
+    max_per_unit: usize,
+) {
+    for app in new_applications {
+        let pending = applications
+            .iter()
+            .filter(|a| a.apartment_id == app.apartment_id)
+            .count();
+        if pending >= max_per_unit {
+            building.waitlisted_applications.push(app);
+        } else {
+            applications.push(app);
+        }
+    }
+}
+
+/// Move the best-matched waitlisted applicant for each listed vacancy with
+/// room into the active pool. Returns the promoted applications' indices.
+pub fn promote_waitlisted(
+    applications: &mut Vec<TenantApplication>,
+    building: &mut Building,
+    max_per_unit: usize,
+    current_tick: u32,
+) -> Vec<usize> {
+    let mut promoted = Vec::new();
+    let listed: Vec<u32> = building
+        .vacant_apartments()
+        .into_iter()
+        .filter(|a| a.is_listed_for_lease)
+        .map(|a| a.id)
+        .collect();
+    for apartment_id in listed {
+        let pending = applications
+            .iter()
+            .filter(|a| a.apartment_id == apartment_id)
+            .count();
+        if pending >= max_per_unit {
+            continue;
+        }
+        let Some(best) = building
+            .waitlisted_applications
+            .iter()
+            .enumerate()
+            .filter(|(_, app)| app.apartment_id == apartment_id)
+            .max_by_key(|(_, app)| app.match_result.score)
+            .map(|(i, _)| i)
+        else {
+            continue;
+        };
+        let mut app = building.waitlisted_applications.remove(best);
+        // The expiry clock starts when the application becomes active.
+        app.tick_created = current_tick;
+        applications.push(app);
+        promoted.push(applications.len() - 1);
+    }
+    promoted
+}
+
+/// Percentage points the local school rating adds to (or takes from) the
+/// chance an applicant is a Family: +1 per 5 points above 70, -1 per 5 below 50.
+fn school_family_shift(school_rating: i32) -> i32 {
+    if school_rating > 70 {
+        (school_rating - 70) / 5
+    } else if school_rating < 50 {
+        -((50 - school_rating) / 5)
+    } else {
+        0
+    }
+}
+
+/// Re-weight the Family entry so its share of the pool moves by `shift`
+/// percentage points, leaving the other archetypes' relative odds intact.
+fn apply_school_shift(weights: &mut [(TenantArchetype, u32)], shift: i32) {
+    if shift == 0 {
+        return;
+    }
+    let total: u32 = weights.iter().map(|(_, weight)| *weight).sum();
+    let Some(family_index) = weights
+        .iter()
+        .position(|(archetype, _)| *archetype == TenantArchetype::Family)
+    else {
+        return;
+    };
+    let others = (total - weights[family_index].1) as f32;
+    let share = weights[family_index].1 as f32 / total.max(1) as f32;
+    let target = (share + shift as f32 / 100.0).clamp(0.0, 0.95);
+    weights[family_index].1 = (target * others / (1.0 - target)).round() as u32;
+}
+
 fn pick_archetype_with_preference(
     marketing: &crate::building::MarketingType,
     preference: Option<&TenantArchetype>,
+    school_rating: i32,
 ) -> TenantArchetype {
     // If preference exists, 80% chance to pick it
     if let Some(pref) = preference {
@@ -206,6 +401,7 @@ fn pick_archetype_with_preference(
         };
         *weight *= multiplier;
     }
+    apply_school_shift(&mut weighted_archetypes, school_family_shift(school_rating));
 
     let total_weight: u32 = weighted_archetypes.iter().map(|(_, weight)| *weight).sum();
     let mut roll = rng::gen_range(0, total_weight.max(1));
@@ -219,19 +415,50 @@ fn pick_archetype_with_preference(
     TenantArchetype::Student
 }
 
+/// Outcome of a month's move-outs.
+pub struct Departures {
+    pub notifications: Vec<String>,
+    /// Departed tenants who left their unit in better condition than the
+    /// move-in inspection recorded.
+    pub left_unit_improved: Vec<u32>,
+    pub abandoned: Vec<Abandonment>,
+}
+
+/// A tenant who walked out at rock-bottom happiness without a word,
+/// leaving their belongings in the unit.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Abandonment {
+    pub tenant_id: u32,
+    pub tenant_name: String,
+    pub unit_number: String,
+    pub contact: Option<super::EmergencyContact>,
+}
+
 /// Process tenant decisions to leave
 pub fn process_departures(
     tenants: &mut Vec<Tenant>,
     building: &mut Building,
+    funds: &mut PlayerFunds,
     config: &crate::data::config::HappinessConfig,
-) -> Vec<String> {
+    deposit_deduction_per_point: i32,
+    current_tick: u32,
+) -> Departures {
     let mut notifications = Vec::new();
+    let mut left_unit_improved = Vec::new();
+    let mut abandoned = Vec::new();
     let mut departing_ids = Vec::new();
+    let ada_compliant = building.flags.contains(super::matching::ADA_COMPLIANT_FLAG);
 
     for tenant in tenants.iter_mut() {
+        // Elderly tenants are slower to leave a building they can get around.
+        let leave_chance = if ada_compliant && tenant.archetype == TenantArchetype::Elderly {
+            config.leave_chance_percent * (100 - config.ada_elderly_leave_reduction_percent) / 100
+        } else {
+            config.leave_chance_percent
+        };
         // Roll once — will_leave is probabilistic, so reuse the result rather
         // than re-rolling it for the early-warning check below.
-        let leaving = tenant.will_leave(config.leave_threshold, config.leave_chance_percent);
+        let leaving = tenant.will_leave(config.leave_threshold, leave_chance);
 
         if tenant.is_unhappy(config.unhappy_threshold) && !leaving {
             notifications.push(format!("{} is unhappy and may leave soon!", tenant.name));
@@ -241,10 +468,35 @@ pub fn process_departures(
             notifications.push(format!("{} has moved out!", tenant.name));
             departing_ids.push(tenant.id);
 
-            // Clear apartment
+            // Settle the deposit against the move-in inspection, then clear
+            // the apartment
             if let Some(apt_id) = tenant.apartment_id {
                 if let Some(apt) = building.get_apartment_mut(apt_id) {
-                    apt.move_out();
+                    if apt.condition_change().is_some_and(|change| change < 0) {
+                        notifications.push(format!(
+                            "{} left the unit in better condition.",
+                            tenant.name
+                        ));
+                        left_unit_improved.push(tenant.id);
+                    }
+                    if tenant.happiness <= 0 {
+                        abandoned.push(Abandonment {
+                            tenant_id: tenant.id,
+                            tenant_name: tenant.name.clone(),
+                            unit_number: apt.unit_number.clone(),
+                            contact: tenant.emergency_contact.clone(),
+                        });
+                    }
+                    if let Some(notice) = settle_deposit(
+                        tenant,
+                        apt,
+                        funds,
+                        deposit_deduction_per_point,
+                        current_tick,
+                    ) {
+                        notifications.push(notice);
+                    }
+                    apt.move_out(current_tick);
                 }
             }
 
@@ -254,7 +506,42 @@ pub fn process_departures(
     }
 
     tenants.retain(|t| !departing_ids.contains(&t.id));
-    notifications
+    Departures {
+        notifications,
+        left_unit_improved,
+        abandoned,
+    }
+}
+
+/// Return a departing tenant's deposit, less `per_point` for each point of
+/// condition lost since move-in. Returns a note if anything was withheld.
+fn settle_deposit(
+    tenant: &mut Tenant,
+    apt: &Apartment,
+    funds: &mut PlayerFunds,
+    per_point: i32,
+    current_tick: u32,
+) -> Option<String> {
+    let deposit = std::mem::take(&mut tenant.security_deposit);
+    if deposit <= 0 {
+        return None;
+    }
+    let wear = apt.condition_change().unwrap_or(0).max(0);
+    let withheld = (wear * per_point).min(deposit);
+    if deposit > withheld {
+        funds.apply_required_expense(Transaction::expense(
+            TransactionType::DepositRefund,
+            deposit - withheld,
+            &format!("Deposit refund ({})", tenant.name),
+            current_tick,
+        ));
+    }
+    (withheld > 0).then(|| {
+        format!(
+            "Kept ${} of {}'s deposit for wear on Unit {} (-{}% condition).",
+            withheld, tenant.name, apt.unit_number, wear
+        )
+    })
 }
 
 #[cfg(test)]
@@ -286,4 +573,138 @@ mod tests {
         apply_risk_rent_premium(&mut tenant, &cfg);
         assert_eq!(tenant.rent_tolerance, base);
     }
+
+    #[test]
+    fn no_applications_above_every_rent_cap() {
+        let mut config = GameConfig::default();
+        config.applications.base_per_vacancy = 100.0;
+        for cap in config.happiness.archetype_rent_caps.values_mut() {
+            *cap = 400;
+        }
+        let mut building = Building::new("Test", 1, 1);
+        for apt in &mut building.apartments {
+            apt.is_listed_for_lease = true;
+            apt.rent_price = 500;
+        }
+        let mut next_id = 1;
+        let apps = generate_applications(&building, &[], 0, &mut next_id, 1.0, 50, &config);
+        assert!(apps.is_empty());
+        // Turned away before an applicant was even drawn up.
+        assert_eq!(next_id, 1);
+    }
+
+    #[test]
+    fn school_rating_shifts_family_share() {
+        assert_eq!(school_family_shift(90), 4);
+        assert_eq!(school_family_shift(60), 0);
+        assert_eq!(school_family_shift(30), -4);
+
+        let weights = || {
+            vec![
+                (TenantArchetype::Student, 50),
+                (TenantArchetype::Family, 20),
+                (TenantArchetype::Elderly, 30),
+            ]
+        };
+        let family_share = |w: &[(TenantArchetype, u32)]| {
+            let total: u32 = w.iter().map(|(_, weight)| *weight).sum();
+            w[1].1 as f32 / total as f32
+        };
+
+        let mut good_schools = weights();
+        apply_school_shift(&mut good_schools, 4);
+        assert!((family_share(&good_schools) - 0.24).abs() < 0.01);
+
+        let mut poor_schools = weights();
+        apply_school_shift(&mut poor_schools, -4);
+        assert!((family_share(&poor_schools) - 0.16).abs() < 0.01);
+    }
+
+    #[test]
+    fn deposit_is_withheld_for_wear_since_move_in() {
+        let mut building = Building::new("Test", 1, 1);
+        building.apartments[0].move_in(1);
+        building.apartments[0].condition -= 10;
+        let mut tenant = Tenant::new(1, "Sam", TenantArchetype::Student);
+        tenant.security_deposit = 300;
+        let mut funds = PlayerFunds::new(1000);
+
+        let note = settle_deposit(&mut tenant, &building.apartments[0], &mut funds, 20, 1);
+        assert!(note.is_some());
+        assert_eq!(funds.balance, 1000 - 100);
+        assert_eq!(tenant.security_deposit, 0);
+    }
+
+    #[test]
+    fn full_units_waitlist_new_applicants_until_a_slot_opens() {
+        let mut building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        building.apartments[0].is_listed_for_lease = true;
+        let application = |id: u32, score: i32| {
+            let tenant = Tenant::new(id, "Applicant", TenantArchetype::Student);
+            let match_result = MatchResult {
+                score,
+                meets_minimum: true,
+                reasons: Vec::new(),
+                ..Default::default()
+            };
+            TenantApplication::new(tenant, apt_id, match_result, 0)
+        };
+        let mut applications = Vec::new();
+
+        file_applications(
+            vec![application(1, 50), application(2, 60), application(3, 90)],
+            &mut applications,
+            &mut building,
+            2,
+        );
+        assert_eq!(applications.len(), 2);
+        assert_eq!(building.waitlisted_applications.len(), 1);
+
+        applications.clear();
+        let promoted = promote_waitlisted(&mut applications, &mut building, 2, 5);
+        assert_eq!(promoted, vec![0]);
+        assert_eq!(applications[0].match_result.score, 90);
+        assert_eq!(applications[0].tick_created, 5);
+        assert!(building.waitlisted_applications.is_empty());
+    }
+
+    #[test]
+    fn forged_credit_only_shows_once_both_checks_are_in() {
+        let tenant = Tenant::new(1, "Forger", TenantArchetype::Professional);
+        let mut app = TenantApplication::new(tenant, 1, MatchResult::default(), 0);
+        let config = VettingConfig::default();
+        forge_credit_history(&mut app, &config);
+        assert!(app.fraud_risk);
+
+        app.revealed_reliability = true;
+        assert!(!app.records_inconsistent(&config));
+        app.revealed_behavior = true;
+        assert!(app.records_inconsistent(&config));
+    }
+
+    #[test]
+    fn best_application_skips_applicants_who_cannot_afford_the_rent() {
+        let mut building = Building::new("Test", 1, 1);
+        let apt_id = building.apartments[0].id;
+        building.apartments[0].rent_price = 900;
+        let application = |id: u32, score: i32, tolerance: i32| {
+            let mut tenant = Tenant::new(id, "Applicant", TenantArchetype::Professional);
+            tenant.rent_tolerance = tolerance;
+            let match_result = MatchResult {
+                score,
+                meets_minimum: true,
+                ..Default::default()
+            };
+            TenantApplication::new(tenant, apt_id, match_result, 0)
+        };
+        let applications = vec![application(1, 90, 500), application(2, 60, 2000)];
+        let config = GameConfig::default();
+
+        assert_eq!(applications[0].ranking_score(900, &config), 0);
+        assert_eq!(
+            best_application(&applications, &building, apt_id, &config),
+            Some(1)
+        );
+    }
 }
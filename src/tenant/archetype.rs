@@ -1,3 +1,4 @@
+use crate::data::config::HappinessConfig;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -10,6 +11,9 @@ pub enum TenantArchetype {
 }
 
 impl TenantArchetype {
+    /// How many archetypes there are.
+    pub const COUNT: usize = 5;
+
     pub fn name(&self) -> &'static str {
         match self {
             TenantArchetype::Student => "Student",
@@ -48,14 +52,23 @@ impl TenantArchetype {
     pub fn preferences(&self) -> ArchetypePreferences {
         // Try to load from JSON registry
         let registry = crate::data::archetypes::archetypes();
-        if let Some(definition) = registry.get(self.id()) {
-            return crate::data::archetypes::ArchetypeRegistry::to_preferences(
-                &definition.preferences,
-            );
+        match registry.get(self.id()) {
+            Some(definition) => {
+                crate::data::archetypes::ArchetypeRegistry::to_preferences(&definition.preferences)
+            }
+            // Fallback to hardcoded values
+            None => self.default_preferences(),
         }
+    }
 
-        // Fallback to hardcoded values
-        self.default_preferences()
+    /// The hard affordability cap, tuned in config rather than per archetype
+    /// file. Uncapped if the config leaves this archetype out.
+    pub fn max_affordable_rent(&self, config: &HappinessConfig) -> i32 {
+        config
+            .archetype_rent_caps
+            .get(self.id())
+            .copied()
+            .unwrap_or(i32::MAX)
     }
 
     /// Hardcoded default preferences (fallback if JSON fails to load)
@@ -70,6 +83,10 @@ impl TenantArchetype {
                 ideal_rent_max: 750,
                 min_acceptable_condition: 30,
                 prefers_quiet: false,
+                wants_parking: false,
+                wants_laundry: true,
+                wants_elevator: false,
+                storage_interest: 0.2,
                 preferred_design: None,
                 hates_design: None,
             },
@@ -82,6 +99,10 @@ impl TenantArchetype {
                 ideal_rent_max: 1200,
                 min_acceptable_condition: 60,
                 prefers_quiet: true,
+                wants_parking: true,
+                wants_laundry: false,
+                wants_elevator: false,
+                storage_interest: 0.3,
                 preferred_design: None,
                 hates_design: None,
             },
@@ -94,6 +115,10 @@ impl TenantArchetype {
                 ideal_rent_max: 900,
                 min_acceptable_condition: 40,
                 prefers_quiet: false,
+                wants_parking: false,
+                wants_laundry: false,
+                wants_elevator: false,
+                storage_interest: 0.4,
                 preferred_design: Some(crate::building::DesignType::Cozy),
                 hates_design: Some(crate::building::DesignType::Bare),
             },
@@ -106,6 +131,10 @@ impl TenantArchetype {
                 ideal_rent_max: 1100,
                 min_acceptable_condition: 50,
                 prefers_quiet: true,
+                wants_parking: true,
+                wants_laundry: true,
+                wants_elevator: true,
+                storage_interest: 0.9,
                 preferred_design: Some(crate::building::DesignType::Practical),
                 hates_design: None,
             },
@@ -118,6 +147,10 @@ impl TenantArchetype {
                 ideal_rent_max: 800,
                 min_acceptable_condition: 45,
                 prefers_quiet: true,
+                wants_parking: false,
+                wants_laundry: false,
+                wants_elevator: true,
+                storage_interest: 0.8,
                 preferred_design: None,
                 hates_design: Some(crate::building::DesignType::Bare), // Wants some comfort
             },
@@ -137,6 +170,14 @@ pub struct ArchetypePreferences {
     pub ideal_rent_max: i32,
     pub min_acceptable_condition: i32,
     pub prefers_quiet: bool,
+    /// Scores a building with a free parking spot higher.
+    pub wants_parking: bool,
+    /// Scores a building with a laundry room higher.
+    pub wants_laundry: bool,
+    /// Scores a building with an elevator higher.
+    pub wants_elevator: bool,
+    /// How much storage lockers matter (0.0 - 1.0).
+    pub storage_interest: f32,
 
     // Design preferences
     pub preferred_design: Option<crate::building::DesignType>,
@@ -0,0 +1,92 @@
+use super::Tenant;
+use crate::economy::{PlayerFunds, Transaction, TransactionType};
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+const GUARANTOR_FIRST_NAMES: [&str; 8] = [
+    "Linda", "Mark", "Susan", "Paul", "Karen", "Greg", "Diane", "Tom",
+];
+
+const GUARANTOR_RELATIONSHIPS: [&str; 4] = ["Parent", "Grandparent", "Aunt", "Uncle"];
+
+/// Someone who co-signs a student's lease and pays if they can't.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GuarantorInfo {
+    pub name: String,
+    /// Monthly income the guarantor can commit; half of it counts toward the
+    /// student's rent budget.
+    pub income: i32,
+    pub relationship: String,
+    pub credit_score: i32,
+    /// Paid last month's missed rent; the guarantor covers one month in a row.
+    #[serde(default)]
+    pub covered_last_month: bool,
+}
+
+impl GuarantorInfo {
+    /// A relative sharing the student's last initial.
+    pub fn generate(student_name: &str) -> Self {
+        let first = rng::choose(&GUARANTOR_FIRST_NAMES)
+            .copied()
+            .unwrap_or("Pat");
+        let last = student_name.split_whitespace().last().unwrap_or("X.");
+        Self {
+            name: format!("{} {}", first, last),
+            income: rng::gen_range(200, 801),
+            relationship: rng::choose(&GUARANTOR_RELATIONSHIPS)
+                .copied()
+                .unwrap_or("Parent")
+                .to_string(),
+            credit_score: rng::gen_range(50, 96),
+            covered_last_month: false,
+        }
+    }
+
+    /// What the guarantor adds to the student's rent budget.
+    pub fn rent_support(&self) -> i32 {
+        self.income / 2
+    }
+}
+
+/// Bill a tenant's guarantor for rent they missed. The guarantor pays one
+/// month; a second miss in a row is the tenant's own.
+pub fn cover_missed_rent(
+    tenant: &mut Tenant,
+    amount: i32,
+    funds: &mut PlayerFunds,
+    current_tick: u32,
+) -> bool {
+    let Some(guarantor) = tenant.guarantor.as_mut() else {
+        return false;
+    };
+    if guarantor.covered_last_month || amount <= 0 {
+        return false;
+    }
+    guarantor.covered_last_month = true;
+    funds.add_income(Transaction::income(
+        TransactionType::RentIncome,
+        amount,
+        &format!("Guarantor {} for {}", guarantor.name, tenant.name),
+        current_tick,
+    ));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::TenantArchetype;
+
+    #[test]
+    fn guarantor_covers_one_missed_month_at_a_time() {
+        let mut tenant = Tenant::new(1, "Alex B.", TenantArchetype::Student);
+        tenant.guarantor = Some(GuarantorInfo::generate(&tenant.name));
+        assert!(tenant.guarantor.as_ref().unwrap().name.ends_with("B."));
+        let mut funds = PlayerFunds::new(0);
+
+        assert!(cover_missed_rent(&mut tenant, 500, &mut funds, 1));
+        assert_eq!(funds.balance, 500);
+        assert!(!cover_missed_rent(&mut tenant, 500, &mut funds, 2));
+        assert_eq!(funds.balance, 500);
+    }
+}
@@ -1,5 +1,5 @@
 use super::{ArchetypePreferences, Tenant};
-use crate::building::{Apartment, Building, DesignType, NoiseLevel};
+use crate::building::{green_certification_level, Apartment, Building, DesignType, NoiseLevel};
 
 /// All factors that influence happiness
 #[derive(Clone, Debug)]
@@ -12,6 +12,13 @@ pub struct HappinessFactors {
     pub hallway_factor: i32,   // Building shared space condition
     pub tenure_bonus: i32,     // Small bonus for long-term residents
     pub staff_factor: i32,     // Security/manager presence
+    pub amenity_factor: i32,   // Shared gym/pool/community room
+    pub climate_factor: i32,   // HVAC comfort (or its absence) in summer/winter
+    pub elevator_factor: i32,  // Elevator access for those who need it
+    pub green_factor: i32,     // Green building certification
+    pub storage_factor: i32,   // Storage lockers, less any overcharge
+    pub art_factor: i32,       // Hallway art, for those who appreciate it
+    pub lease_factor: i32,     // Month-to-month insecurity
 }
 
 impl HappinessFactors {
@@ -23,12 +30,23 @@ impl HappinessFactors {
             + self.design_factor
             + self.hallway_factor
             + self.tenure_bonus
-            + self.staff_factor)
+            + self.staff_factor
+            + self.amenity_factor
+            + self.climate_factor
+            + self.elevator_factor
+            + self.green_factor
+            + self.storage_factor
+            + self.art_factor
+            + self.lease_factor)
             .clamp(0, 100)
     }
 }
 
-use crate::data::config::{HappinessConfig, StaffEffectsConfig};
+use crate::data::config::{AmenityConfig, HappinessConfig, StaffEffectsConfig};
+use crate::simulation::Season;
+
+use super::matching::ELEVATOR_FLAG;
+use std::collections::HashMap;
 
 /// Calculate happiness factors for a tenant in their apartment
 pub fn calculate_happiness(
@@ -37,24 +55,110 @@ pub fn calculate_happiness(
     building: &Building,
     config: &HappinessConfig,
     staff: &StaffEffectsConfig,
+    amenities: &HashMap<String, AmenityConfig>,
+    season: Season,
+    standard_locker_rent: i32,
 ) -> HappinessFactors {
     let prefs = tenant.archetype.preferences();
+    let has_elevator = building.flags.contains(ELEVATOR_FLAG);
+
+    let mut noise_factor = calculate_noise_factor(
+        &apartment.effective_noise(),
+        tenant.noise_tolerance,
+        &prefs,
+        config,
+    );
+    // Insulated windows take the edge off street noise.
+    if apartment.street_noise_insulated() && noise_factor < 0 {
+        noise_factor /= 2;
+    }
+    // Upper floors stop hearing stair traffic once there's an elevator.
+    if has_elevator && apartment.floor > 2 && noise_factor < 0 {
+        noise_factor /= 2;
+    }
 
     HappinessFactors {
         base_happiness: config.base,
         rent_factor: calculate_rent_factor(apartment.rent_price, &prefs, config),
         condition_factor: calculate_condition_factor(apartment.condition, &prefs, config),
-        noise_factor: calculate_noise_factor(
-            &apartment.effective_noise(),
-            tenant.noise_tolerance,
-            &prefs,
-            config,
-        ),
+        noise_factor,
         design_factor: calculate_design_factor(&apartment.design, &prefs, config),
         hallway_factor: calculate_hallway_factor(building.hallway_condition, config),
         tenure_bonus: calculate_tenure_bonus(tenant.months_residing, config),
         staff_factor: calculate_staff_factor(building, staff),
+        amenity_factor: calculate_amenity_factor(building, amenities),
+        climate_factor: calculate_climate_factor(building, season, config),
+        elevator_factor: if has_elevator {
+            config
+                .elevator_happiness_bonus
+                .get(tenant.archetype.id())
+                .copied()
+                .unwrap_or(0)
+        } else {
+            0
+        },
+        green_factor: green_certification_level(building).tier() * config.green_certification_bonus,
+        storage_factor: calculate_storage_factor(building, &prefs, config, standard_locker_rent),
+        art_factor: if building.has_hallway_art() {
+            config
+                .hallway_art_happiness_bonus
+                .get(tenant.archetype.id())
+                .copied()
+                .unwrap_or(0)
+        } else {
+            0
+        },
+        lease_factor: if tenant.is_month_to_month {
+            config.month_to_month_penalty
+        } else {
+            0
+        },
+    }
+}
+
+/// Lockers please tenants who care about storage, unless the rent on them
+/// runs past what they consider fair.
+fn calculate_storage_factor(
+    building: &Building,
+    prefs: &ArchetypePreferences,
+    config: &HappinessConfig,
+    standard_rent: i32,
+) -> i32 {
+    if !building.has_storage_lockers() {
+        return 0;
     }
+    // Until the player sets a rent, lockers go at the standard (fair) rate.
+    let overcharge = (building.storage_locker_rent(standard_rent) - standard_rent).max(0)
+        / config.storage_overcharge_step.max(1);
+    ((config.storage_happiness_bonus - overcharge) as f32 * prefs.storage_interest).round() as i32
+}
+
+/// Summer heat and winter cold: HVAC turns them into a comfort bonus,
+/// without it tenants suffer a small penalty. Spring and fall are neutral.
+fn calculate_climate_factor(building: &Building, season: Season, config: &HappinessConfig) -> i32 {
+    match season {
+        Season::Summer | Season::Winter if building.flags.contains("hvac") => {
+            config.hvac_comfort_bonus
+        }
+        Season::Summer | Season::Winter => config.extreme_weather_penalty,
+        Season::Spring | Season::Fall => 0,
+    }
+}
+
+/// Happiness every tenant draws from the building's shared amenities, each
+/// scaled by how well it has been kept up.
+fn calculate_amenity_factor(
+    building: &Building,
+    amenities: &HashMap<String, AmenityConfig>,
+) -> i32 {
+    building
+        .amenities
+        .iter()
+        .filter_map(|amenity| {
+            let config = amenities.get(amenity.amenity_type.key())?;
+            Some(config.happiness_bonus * amenity.condition / 100)
+        })
+        .sum()
 }
 
 /// Happiness contribution from on-site staff. Persisted through the happiness
@@ -243,4 +347,46 @@ mod tests {
             staff.security_happiness_bonus + staff.manager_happiness_bonus
         );
     }
+
+    #[test]
+    fn climate_factor_rewards_hvac_in_summer_and_winter() {
+        let mut building = Building::new("Test", 1, 1);
+        let config = crate::data::config::GameConfig::default().happiness;
+
+        assert_eq!(
+            calculate_climate_factor(&building, Season::Spring, &config),
+            0
+        );
+        assert_eq!(
+            calculate_climate_factor(&building, Season::Winter, &config),
+            config.extreme_weather_penalty
+        );
+
+        building.flags.insert("hvac".to_string());
+        assert_eq!(
+            calculate_climate_factor(&building, Season::Summer, &config),
+            config.hvac_comfort_bonus
+        );
+    }
+
+    #[test]
+    fn overpriced_lockers_sour_storage_minded_tenants() {
+        let mut building = Building::new("Test", 1, 1);
+        let game_config = crate::data::config::GameConfig::default();
+        let config = game_config.happiness;
+        let standard = game_config.economy.storage_locker_income;
+        let family = crate::tenant::TenantArchetype::Family.preferences();
+
+        assert_eq!(
+            calculate_storage_factor(&building, &family, &config, standard),
+            0
+        );
+
+        building.flags.insert("storage_lockers".to_string());
+        let fair = calculate_storage_factor(&building, &family, &config, standard);
+        assert!(fair > 0);
+
+        building.storage_locker_rent = Some(standard * 4);
+        assert!(calculate_storage_factor(&building, &family, &config, standard) < 0);
+    }
 }
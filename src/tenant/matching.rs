@@ -1,22 +1,60 @@
-use super::{happiness, Tenant};
-use crate::building::Apartment;
-use crate::data::config::MatchingConfig;
+use super::{happiness, Tenant, TenantArchetype};
+use crate::building::{AmenityType, Apartment, Building};
+use crate::data::config::{HappinessConfig, MatchingConfig};
 
 /// Result of matching a tenant to an apartment
 use serde::{Deserialize, Serialize};
 
 /// Result of matching a tenant to an apartment
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct MatchResult {
     pub score: i32,           // 0-100, higher = better match
     pub meets_minimum: bool,  // Would tenant even consider this?
     pub reasons: Vec<String>, // Why this score
+    /// Points the rent contributed, for the application breakdown bars.
+    #[serde(default)]
+    pub rent_fit: i32,
+    /// Points the unit's condition contributed.
+    #[serde(default)]
+    pub condition_fit: i32,
+    /// Points from archetype preferences: noise, design, amenities, size.
+    #[serde(default)]
+    pub preference_fit: i32,
+}
+
+/// Building-wide features some archetypes look for when applying.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuildingFeatures {
+    /// A parking spot is free to rent.
+    pub parking_available: bool,
+    pub has_laundry: bool,
+    pub has_elevator: bool,
+    pub ada_compliant: bool,
+    pub has_storage_lockers: bool,
+}
+
+impl BuildingFeatures {
+    pub fn of(building: &Building) -> Self {
+        Self {
+            parking_available: building.has_vacant_parking(),
+            has_laundry: building.has_amenity(AmenityType::Laundry),
+            has_elevator: building.flags.contains(ELEVATOR_FLAG),
+            ada_compliant: building.flags.contains(ADA_COMPLIANT_FLAG),
+            has_storage_lockers: building.has_storage_lockers(),
+        }
+    }
 }
 
-/// Calculate how well a tenant matches an apartment
+/// Building flag set by the elevator upgrade.
+pub const ELEVATOR_FLAG: &str = "elevator";
+/// Building flag set by the ADA accessibility upgrade.
+pub const ADA_COMPLIANT_FLAG: &str = "ada_compliant";
+
+/// Calculate how well a tenant matches an apartment.
 pub fn calculate_match_score(
     tenant: &Tenant,
     apartment: &Apartment,
+    features: BuildingFeatures,
     config: &MatchingConfig,
 ) -> MatchResult {
     let mut score = config.base_score;
@@ -34,7 +72,8 @@ pub fn calculate_match_score(
     }
 
     // Rent scoring
-    let rent_diff = prefs.ideal_rent_max - apartment.rent_price;
+    let before_rent = score;
+    let rent_diff = prefs.ideal_rent_max + guarantor_support(tenant) - apartment.rent_price;
     if rent_diff > config.rent_great_threshold {
         score += config.rent_great_bonus;
         reasons.push("Great price".to_string());
@@ -48,8 +87,15 @@ pub fn calculate_match_score(
         score += config.rent_unaffordable_penalty;
         reasons.push("Cannot afford established budget".to_string());
     }
+    if tenant.archetype == TenantArchetype::Student && tenant.guarantor.is_none() {
+        score += config.no_guarantor_penalty;
+        reasons.push("No guarantor".to_string());
+    }
+
+    let rent_fit = score - before_rent;
 
     // Condition scoring
+    let before_condition = score;
     if apartment.condition >= config.condition_excellent_threshold {
         let bonus = (config.condition_excellent_bonus as f32 * prefs.condition_sensitivity) as i32;
         score += bonus;
@@ -64,7 +110,10 @@ pub fn calculate_match_score(
         reasons.push("Poor condition".to_string());
     }
 
+    let condition_fit = score - before_condition;
+
     // Noise scoring
+    let before_preferences = score;
     match apartment.effective_noise() {
         crate::building::NoiseLevel::Low => {
             if prefs.prefers_quiet {
@@ -74,7 +123,10 @@ pub fn calculate_match_score(
             }
         }
         crate::building::NoiseLevel::High => {
-            let penalty = (config.noise_loud_penalty as f32 * prefs.noise_sensitivity) as i32;
+            let mut penalty = (config.noise_loud_penalty as f32 * prefs.noise_sensitivity) as i32;
+            if apartment.street_noise_insulated() {
+                penalty /= 2;
+            }
             score -= penalty;
             reasons.push("Too noisy".to_string());
         }
@@ -89,6 +141,34 @@ pub fn calculate_match_score(
         }
     }
 
+    if prefs.wants_parking && features.parking_available {
+        score += config.parking_bonus;
+        reasons.push("Parking available".to_string());
+    }
+
+    if prefs.wants_laundry && features.has_laundry {
+        score += config.laundry_bonus;
+        reasons.push("Laundry in building".to_string());
+    }
+
+    if features.has_storage_lockers {
+        let bonus = (config.storage_bonus as f32 * prefs.storage_interest) as i32;
+        if bonus > 0 {
+            score += bonus;
+            reasons.push("Storage lockers".to_string());
+        }
+    }
+
+    if prefs.wants_elevator && features.has_elevator {
+        score += config.elevator_bonus;
+        reasons.push("Elevator access".to_string());
+    }
+
+    if tenant.archetype == TenantArchetype::Elderly && features.ada_compliant {
+        score += config.ada_elderly_bonus;
+        reasons.push("Accessible building".to_string());
+    }
+
     // Size bonus (everyone likes more space)
     match apartment.size {
         crate::building::ApartmentSize::Small => {}
@@ -101,8 +181,8 @@ pub fn calculate_match_score(
             reasons.push("Great space".to_string());
         }
         crate::building::ApartmentSize::Penthouse => {
-            score += config.size_medium_bonus + 15;
-            reasons.push("Luxurious space".to_string());
+            score += config.penthouse_bonus;
+            reasons.push("Luxury penthouse".to_string());
         }
     }
 
@@ -110,19 +190,34 @@ pub fn calculate_match_score(
         score: score.clamp(0, 100),
         meets_minimum,
         reasons,
+        rent_fit,
+        condition_fit,
+        preference_fit: score - before_preferences,
     }
 }
 
+/// Rent a guarantor lets the tenant stretch to.
+fn guarantor_support(tenant: &Tenant) -> i32 {
+    tenant
+        .guarantor
+        .as_ref()
+        .map_or(0, |guarantor| guarantor.rent_support())
+}
+
 /// Find the best apartment match for a tenant from available options
 pub fn find_best_match<'a>(
     tenant: &Tenant,
     apartments: &'a [&'a Apartment],
+    features: BuildingFeatures,
     config: &MatchingConfig,
 ) -> Option<(&'a Apartment, MatchResult)> {
     apartments
         .iter()
         .filter(|apt| apt.is_vacant())
-        .map(|apt| (*apt, calculate_match_score(tenant, apt, config)))
+        .map(|apt| {
+            let result = calculate_match_score(tenant, apt, features, config);
+            (*apt, result)
+        })
         // No longer filtering by meets_minimum - allow all applicants
         .max_by_key(|(_, result)| result.score)
 }
@@ -156,15 +251,21 @@ use crate::data::config::LeaseAcceptanceConfig;
 pub fn evaluate_lease_offer(
     tenant: &Tenant,
     offer: &LeaseOffer,
+    happiness: &HappinessConfig,
     config: &LeaseAcceptanceConfig,
 ) -> f32 {
     let prefs = tenant.archetype.preferences();
     let mut probability = 1.0;
 
-    // 1. Rent affordability (Hard limit)
-    if offer.rent_price > tenant.rent_tolerance {
+    // 1. Rent affordability (Hard limit), with a guarantor's backing
+    let support = guarantor_support(tenant);
+    if offer.rent_price > tenant.rent_tolerance + support {
         return 0.0;
     }
+    let rent_cap = tenant.archetype.max_affordable_rent(happiness);
+    if offer.rent_price > rent_cap.saturating_add(support) {
+        probability -= config.over_rent_cap_penalty;
+    }
 
     // 2. Security Deposit Impact
     // High deposit hurts budget-conscious tenants (Students, Artists)
@@ -204,7 +305,7 @@ pub fn evaluate_lease_offer(
     }
 
     // 5. Rent Value (Deal vs Rip-off)
-    let rent_diff = prefs.ideal_rent_max - offer.rent_price;
+    let rent_diff = prefs.ideal_rent_max + support - offer.rent_price;
     if rent_diff < 0 {
         // Slightly above comfortable max (but below absolute tolerance)
         probability -= config.expensive_penalty;
@@ -23,6 +23,71 @@ pub struct Tenant {
 
     // Relationship with landlord
     pub landlord_opinion: i32, // -100 to 100, affects negotiations
+
+    // Housing voucher: the city pays `voucher_amount` of the rent every month
+    #[serde(default)]
+    pub has_housing_voucher: bool,
+    #[serde(default)]
+    pub voucher_amount: i32,
+
+    /// Rent missed and never made up
+    #[serde(default)]
+    pub outstanding_balance: i32,
+    /// Deposits held for this tenant, such as a pet deposit.
+    #[serde(default)]
+    pub security_deposit: i32,
+
+    // Lease
+    /// Month the current lease term began.
+    #[serde(default)]
+    pub lease_start_tick: u32,
+    /// Length of the current (or last) lease term.
+    #[serde(default)]
+    pub lease_duration_months: u32,
+    /// Lease ended without renewal; pays the month-to-month premium.
+    #[serde(default)]
+    pub is_month_to_month: bool,
+    /// Got in on forged credit; never actually pays.
+    #[serde(default)]
+    pub is_fraudulent: bool,
+    /// Co-signer on a student's lease.
+    #[serde(default)]
+    pub guarantor: Option<super::GuarantorInfo>,
+    /// Monthly income confirmed by an income check.
+    #[serde(default)]
+    pub verified_income: Option<i32>,
+    /// Who to reach if the tenant can't be, collected with the application.
+    #[serde(default)]
+    pub emergency_contact: Option<EmergencyContact>,
+}
+
+const CONTACT_FIRST_NAMES: [&str; 8] = [
+    "Dana", "Chris", "Robin", "Lee", "Maria", "John", "Ruth", "Sam",
+];
+
+const CONTACT_RELATIONSHIPS: [&str; 4] = ["Sibling", "Parent", "Friend", "Cousin"];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EmergencyContact {
+    pub name: String,
+    pub phone: String,
+    pub relationship: String,
+}
+
+impl EmergencyContact {
+    /// The contact an applicant writes down on their form.
+    pub fn generate() -> Self {
+        let first = rng::choose(&CONTACT_FIRST_NAMES).copied().unwrap_or("Pat");
+        let last = (b'A' + rng::gen_range(0, 26) as u8) as char;
+        Self {
+            name: format!("{} {}.", first, last),
+            phone: format!("555-{:04}", rng::gen_range(0, 10000)),
+            relationship: rng::choose(&CONTACT_RELATIONSHIPS)
+                .copied()
+                .unwrap_or("Friend")
+                .to_string(),
+        }
+    }
 }
 
 impl Tenant {
@@ -50,9 +115,33 @@ impl Tenant {
             landlord_opinion: 0,
             rent_reliability: base_reliability,
             behavior_score: base_behavior,
+            has_housing_voucher: false,
+            voucher_amount: 0,
+            outstanding_balance: 0,
+            security_deposit: 0,
+            lease_start_tick: 0,
+            lease_duration_months: 0,
+            is_month_to_month: false,
+            is_fraudulent: false,
+            guarantor: None,
+            verified_income: None,
+            emergency_contact: None,
         }
     }
 
+    /// What the tenant actually earns a month. Hidden until verified: people
+    /// budget about a third of income for rent, reliable payers a bit less.
+    pub fn monthly_income(&self) -> i32 {
+        self.rent_tolerance * (250 + self.rent_reliability) / 100
+    }
+
+    /// Share of verified income that `rent` takes.
+    pub fn rent_to_income_ratio(&self, rent: i32) -> Option<f32> {
+        self.verified_income
+            .filter(|income| *income > 0)
+            .map(|income| rent as f32 / income as f32)
+    }
+
     /// Create a tenant with some randomization
     pub fn generate(id: u32, archetype: TenantArchetype) -> Self {
         let name = generate_random_name(&archetype);
@@ -117,6 +206,24 @@ impl Tenant {
         self.months_residing = 0;
     }
 
+    /// Start a lease term of `months` at `current_tick`.
+    pub fn sign_lease(&mut self, current_tick: u32, months: u32) {
+        self.lease_start_tick = current_tick;
+        self.lease_duration_months = months;
+        self.is_month_to_month = false;
+    }
+
+    /// Month the lease ends, or `None` when renting month-to-month.
+    pub fn lease_end_tick(&self) -> Option<u32> {
+        (self.lease_duration_months > 0 && !self.is_month_to_month)
+            .then(|| self.lease_start_tick + self.lease_duration_months)
+    }
+
+    /// Let the lease lapse into month-to-month.
+    pub fn go_month_to_month(&mut self) {
+        self.is_month_to_month = true;
+    }
+
     /// Move out of current apartment
     pub fn move_out(&mut self) {
         self.apartment_id = None;
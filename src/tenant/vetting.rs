@@ -1,11 +1,32 @@
 use super::application::TenantApplication;
 use crate::data::config::VettingConfig;
 use crate::economy::{PlayerFunds, Transaction, TransactionType};
+use macroquad_toolkit::rng;
+use serde::{Deserialize, Serialize};
+
+/// How far a previous landlord's ratings can stray from the truth.
+const REFERENCE_RATING_SPREAD: i32 = 10;
+
+const POSITIVE_REFERENCE_NOTES: [&str; 4] = [
+    "Always paid on time. I'd rent to them again.",
+    "Left the place spotless when they moved out.",
+    "Never heard a complaint from the neighbors.",
+    "Reported repairs promptly and was easy to deal with.",
+];
+
+const NEGATIVE_REFERENCE_NOTES: [&str; 4] = [
+    "Rent was late more often than not.",
+    "We had to keep most of the deposit for damage.",
+    "Neighbors complained about noise at all hours.",
+    "Would not rent to them again.",
+];
 
 /// Results of a credit check
 pub struct CreditCheckResult {
     pub reliability_score: i32,
     pub recommendation: String,
+    /// Checked alongside the applicant when someone co-signs.
+    pub guarantor_credit_score: Option<i32>,
 }
 
 /// Results of a background check
@@ -14,6 +35,21 @@ pub struct BackgroundCheckResult {
     pub history_notes: String,
 }
 
+/// Results of an income verification
+pub struct IncomeVerResult {
+    pub monthly_income: i32,
+    pub rent_to_income_ratio: f32,
+    pub recommendation: String,
+}
+
+/// What an applicant's previous landlord says about them
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReferenceResult {
+    pub prev_landlord_note: String,
+    pub payment_reliability: i32,
+    pub cleanliness_rating: i32,
+}
+
 /// Perform a credit check on a tenant applicant
 pub fn perform_credit_check(
     application: &mut TenantApplication,
@@ -55,6 +91,7 @@ pub fn perform_credit_check(
     Some(CreditCheckResult {
         reliability_score: score,
         recommendation,
+        guarantor_credit_score: application.guarantor().map(|g| g.credit_score),
     })
 }
 
@@ -101,3 +138,148 @@ pub fn perform_background_check(
         history_notes,
     })
 }
+
+/// Call the applicant's previous landlord. The ratings are a rough take on
+/// the hidden reliability and behavior scores, and the note is drawn from
+/// good or bad experiences weighted by how the tenant actually behaves.
+pub fn perform_reference_check(
+    application: &mut TenantApplication,
+    funds: &mut PlayerFunds,
+    config: &VettingConfig,
+    current_tick: u32,
+) -> Option<ReferenceResult> {
+    if application.reference.is_some() {
+        return None; // Already checked
+    }
+
+    if !funds.deduct_expense(Transaction::expense(
+        TransactionType::Vetting,
+        config.reference_check_cost,
+        "Reference Check",
+        current_tick,
+    )) {
+        return None; // Cannot afford
+    }
+
+    let tenant = &application.tenant;
+    let notes = if rng::gen_range(0, 100) < tenant.behavior_score {
+        &POSITIVE_REFERENCE_NOTES
+    } else {
+        &NEGATIVE_REFERENCE_NOTES
+    };
+    let result = ReferenceResult {
+        prev_landlord_note: rng::choose(notes).copied().unwrap_or_default().to_string(),
+        payment_reliability: rough_rating(tenant.rent_reliability),
+        cleanliness_rating: rough_rating(tenant.behavior_score),
+    };
+    application.reference = Some(result.clone());
+    Some(result)
+}
+
+/// Confirm the applicant's income and how much of it the unit's rent would
+/// take. The verified figure stays on the tenant after move-in.
+pub fn perform_income_verification(
+    application: &mut TenantApplication,
+    rent_price: i32,
+    funds: &mut PlayerFunds,
+    config: &VettingConfig,
+    current_tick: u32,
+) -> Option<IncomeVerResult> {
+    if application.tenant.verified_income.is_some() {
+        return None; // Already checked
+    }
+
+    if !funds.deduct_expense(Transaction::expense(
+        TransactionType::Vetting,
+        config.income_check_cost,
+        "Income Verification",
+        current_tick,
+    )) {
+        return None; // Cannot afford
+    }
+
+    let monthly_income = application.tenant.monthly_income();
+    application.tenant.verified_income = Some(monthly_income);
+    let ratio = application
+        .tenant
+        .rent_to_income_ratio(rent_price)
+        .unwrap_or(f32::INFINITY);
+    let recommendation = if ratio > config.max_acceptable_ratio {
+        format!(
+            "Warning: rent would take {:.0}% of income (limit {:.0}%).",
+            ratio * 100.0,
+            config.max_acceptable_ratio * 100.0
+        )
+    } else {
+        "Income comfortably covers the rent.".to_string()
+    };
+
+    Some(IncomeVerResult {
+        monthly_income,
+        rent_to_income_ratio: ratio,
+        recommendation,
+    })
+}
+
+fn rough_rating(score: i32) -> i32 {
+    (score + rng::gen_range(-REFERENCE_RATING_SPREAD, REFERENCE_RATING_SPREAD + 1)).clamp(0, 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tenant::{matching::MatchResult, Tenant, TenantArchetype};
+
+    fn reference_for(rent_reliability: i32, behavior_score: i32) -> TenantApplication {
+        let mut tenant = Tenant::new(1, "Applicant", TenantArchetype::Professional);
+        tenant.rent_reliability = rent_reliability;
+        tenant.behavior_score = behavior_score;
+        TenantApplication::new(tenant, 1, MatchResult::default(), 0)
+    }
+
+    #[test]
+    fn model_tenant_gets_a_glowing_reference() {
+        let mut app = reference_for(100, 100);
+        let mut funds = PlayerFunds::new(1000);
+        let config = VettingConfig::default();
+
+        let result =
+            perform_reference_check(&mut app, &mut funds, &config, 0).expect("check should run");
+        assert!(POSITIVE_REFERENCE_NOTES.contains(&result.prev_landlord_note.as_str()));
+        assert!(result.payment_reliability >= 100 - REFERENCE_RATING_SPREAD);
+        assert!(result.cleanliness_rating >= 100 - REFERENCE_RATING_SPREAD);
+        assert_eq!(funds.balance, 1000 - config.reference_check_cost);
+        assert!(app.reference.is_some());
+        assert!(perform_reference_check(&mut app, &mut funds, &config, 0).is_none());
+    }
+
+    #[test]
+    fn troublesome_tenant_gets_a_warning_reference() {
+        let mut app = reference_for(0, 0);
+        let mut funds = PlayerFunds::new(1000);
+
+        let result = perform_reference_check(&mut app, &mut funds, &VettingConfig::default(), 0)
+            .expect("check should run");
+        assert!(NEGATIVE_REFERENCE_NOTES.contains(&result.prev_landlord_note.as_str()));
+        assert!(result.payment_reliability <= REFERENCE_RATING_SPREAD);
+        assert!(result.cleanliness_rating <= REFERENCE_RATING_SPREAD);
+    }
+
+    #[test]
+    fn income_check_warns_when_rent_takes_too_much() {
+        let mut tenant = Tenant::new(1, "Earner", TenantArchetype::Professional);
+        tenant.rent_tolerance = 1000;
+        tenant.rent_reliability = 50;
+        let mut app = TenantApplication::new(tenant, 1, MatchResult::default(), 0);
+        let mut funds = PlayerFunds::new(100);
+        let config = VettingConfig::default();
+
+        let result = perform_income_verification(&mut app, 1500, &mut funds, &config, 0)
+            .expect("check should run");
+        assert_eq!(result.monthly_income, 3000);
+        assert!((result.rent_to_income_ratio - 0.5).abs() < f32::EPSILON);
+        assert!(result.recommendation.starts_with("Warning"));
+        assert_eq!(app.tenant.verified_income, Some(3000));
+        assert!(perform_income_verification(&mut app, 1500, &mut funds, &config, 0).is_none());
+    }
+}
@@ -10,6 +10,7 @@
 pub mod theme;
 pub mod widgets;
 
+mod achievements_panel;
 mod apartment_panel;
 mod apartment_panel_sections;
 mod application_panel;
@@ -21,21 +22,33 @@ mod common;
 pub mod event_modal; // Phase 4 event modal
 mod hallway_panel;
 mod header;
+mod minimap;
+mod mission_panel;
+mod notification_history_panel;
 mod notifications;
 pub mod ownership_panel; // Phase 3 ownership
+mod request_panel;
+mod strike_panel;
 mod tenant_panel;
+mod vacancy_panel;
 
+pub use achievements_panel::draw_achievements_panel;
 pub use apartment_panel::draw_apartment_panel;
 pub use building_view::draw_building_view;
 pub use common::*;
-pub use hallway_panel::draw_hallway_panel;
+pub use hallway_panel::{draw_hallway_panel, HallwayPanelStatus};
 pub use ownership_panel::draw_ownership_panel;
 
 pub use application_panel::draw_application_panel;
-pub use header::draw_header;
+pub use header::{draw_header, HeaderStatus};
 pub use macroquad_toolkit::fx::FloatingTextLayer;
 pub use macroquad_toolkit::math::Tween;
+pub use minimap::draw_minimap;
+pub use mission_panel::draw_mission_panel;
+pub use notification_history_panel::draw_notification_history_panel;
 pub use notifications::draw_notifications;
+pub use strike_panel::draw_strike_panel;
+pub use tenant_panel::TransferTarget;
 
 use serde::{Deserialize, Serialize};
 
@@ -49,9 +62,12 @@ pub enum Selection {
     Applications(Option<u32>), // Show pending applications (Optionally filtered by apartment)
     Hallway,                   // Hallway details
     Ownership,                 // Ownership View
+    Missions,                  // Active mission log
 }
 
 use crate::building::UpgradeAction;
+use crate::consequences::EvictionReason;
+use crate::narrative::{RequestCondition, TenantRequest};
 
 /// UI action intents (returned to game logic)
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -61,10 +77,18 @@ pub enum UiAction {
     SelectApplications(Option<u32>),
     SelectHallway,
     SelectOwnership,
+    SelectMissions,
     ClearSelection,
 
     // Generic Upgrade Action
     UpgradeAction(UpgradeAction),
+    /// File a building permit for a major unit upgrade, optionally
+    /// fast-tracked at double the fee.
+    SubmitPermitApplication {
+        upgrade_id: String,
+        apartment_id: u32,
+        fast_track: bool,
+    },
 
     SetRent {
         apartment_id: u32,
@@ -78,6 +102,16 @@ pub enum UiAction {
     RejectApplication {
         application_index: usize,
     },
+    /// Accept a housing voucher holder; the city guarantees part of the rent
+    AcceptVoucherApplication {
+        application_index: usize,
+    },
+    /// Accept whichever pending application ranks highest for the unit.
+    AcceptBestApplication {
+        apartment_id: u32,
+    },
+    /// Switch the application list between arrival order and ranking.
+    ToggleApplicationSort,
 
     // Game flow
     EndTurn,
@@ -90,6 +124,27 @@ pub enum UiAction {
     CloseMarket,
     OpenMail,
     CloseMail,
+    OpenAchievements,
+    CloseAchievements,
+    OpenNotificationHistory,
+    /// Sandbox-only debug switch for a bottomless bank account.
+    ToggleSandboxFunds,
+    CloseNotificationHistory,
+    /// Pin (or with `None`, clear) the tooltip for an achievement tile.
+    InspectAchievement {
+        index: Option<usize>,
+    },
+    ToggleMinimap,
+    ToggleRelationshipHistory,
+    BeginRenameUnit(u32),
+    MergeUnits {
+        apt_a_id: u32,
+        apt_b_id: u32,
+    },
+    RenameUnit {
+        apartment_id: u32,
+        new_number: String,
+    },
 
     // Phase 3: Multi-building
     SwitchBuilding {
@@ -98,18 +153,80 @@ pub enum UiAction {
     PurchaseBuilding {
         listing_id: u32,
     },
+    /// Buy a listing with a mortgage, paying only the down payment now
+    FinanceBuilding {
+        listing_id: u32,
+        financing: crate::city::FinancingOption,
+    },
+    /// Move a tenant into a vacant unit in another owned building.
+    TransferTenant {
+        tenant_id: u32,
+        target_building_index: usize,
+        target_apartment_id: u32,
+    },
 
     // Phase 3: Tenant requests
     ApproveRequest {
         tenant_id: u32,
     },
+    /// Approve a pet request with a condition attached.
+    ApproveRequestWithCondition {
+        tenant_id: u32,
+        condition: RequestCondition,
+    },
     DenyRequest {
         tenant_id: u32,
     },
+    /// Renew a tenant's lease from their renewal offer mail, setting the
+    /// unit's rent to the proposed amount.
+    RenewLease {
+        building_id: u32,
+        tenant_id: u32,
+        apartment_id: u32,
+        new_rent: i32,
+    },
+    /// Propose modified terms back to a tenant instead of denying them.
+    CounterOffer {
+        tenant_id: u32,
+        modified_request: TenantRequest,
+    },
+    MediateTenants {
+        tenant_a_id: u32,
+        tenant_b_id: u32,
+    },
+    AssignParking {
+        spot_id: u32,
+        tenant_id: u32,
+    },
+    RevokeParking {
+        spot_id: u32,
+    },
+    BeginRenovation {
+        building_id: u32,
+        budget: i32,
+    },
+
+    /// Evict a unit's tenant for a documented reason, or have a squatter
+    /// removed (no reason needed).
+    BeginEviction {
+        apartment_id: u32,
+        eviction_reason: Option<EvictionReason>,
+    },
+    /// Settle a rent strike by meeting every demand, or reject them all.
+    MeetStrikeDemands {
+        accept_all: bool,
+    },
 
     // Phase 3: Ownership
+    /// Sell a long-term tenant the unit they rent, at a discount.
+    OfferPurchaseOption {
+        apartment_id: u32,
+    },
+    /// Sell a unit to an outside buyer. An occupied unit's tenant is
+    /// displaced; `pay_relocation` covers their relocation assistance.
     SellUnitAsCondo {
         apartment_id: u32,
+        pay_relocation: bool,
     },
     BuybackCondo {
         apartment_id: u32,
@@ -136,6 +253,16 @@ pub enum UiAction {
     BackgroundCheck {
         application_index: usize,
     },
+    ReferenceCheck {
+        application_index: usize,
+    },
+    IncomeCheck {
+        application_index: usize,
+    },
+    /// Fill in the applicant's emergency contact on their form.
+    CollectEmergencyContact {
+        application_index: usize,
+    },
 
     // Leasing
     ListApartment {
@@ -149,4 +276,22 @@ pub enum UiAction {
         apartment_id: u32,
         amount: i32,
     },
+    /// Set several units' rents at once, as (apartment ID, rent).
+    AdjustAllRents {
+        building_id: u32,
+        rents: Vec<(u32, i32)>,
+    },
+    /// Monthly rent charged for each storage locker
+    SetStorageLockerRent(i32),
+    /// Hire a property manager, or change the current one's level
+    HirePropertyManager {
+        level: crate::building::AutomationLevel,
+    },
+    DismissPropertyManager,
+    /// Give the journalist an exclusive interview to warm their coverage
+    GrantExclusiveInterview,
+    /// Pay to appeal a building's latest property tax reassessment
+    ContestTaxAssessment {
+        building_id: u32,
+    },
 }
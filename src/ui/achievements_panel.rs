@@ -0,0 +1,230 @@
+//! Full-screen trophy case: every achievement as a tile, unlocked ones lit.
+//! Clicking a tile pins a tooltip with its description and unlock condition.
+
+use super::theme::{color, scale, space, Tone};
+use super::widgets::{button_at, draw_card, wrap};
+use super::UiAction;
+use crate::narrative::achievements::Achievement;
+use crate::narrative::AchievementSystem;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text, progress_bar, truncate_text_to_width};
+
+const TILE_W: f32 = 180.0;
+const TILE_H: f32 = 110.0;
+const TILE_GAP: f32 = 12.0;
+const TOOLTIP_W: f32 = 280.0;
+
+/// Title shown on a tile: hidden achievements stay "???" until unlocked.
+fn tile_title(achievement: &Achievement, unlocked: bool) -> &str {
+    if unlocked || !achievement.hidden {
+        &achievement.name
+    } else {
+        "???"
+    }
+}
+
+pub fn draw_achievements_panel(
+    achievements: &AchievementSystem,
+    selected: Option<usize>,
+) -> Option<UiAction> {
+    let mut action = None;
+    let w = screen_width();
+    let h = screen_height();
+    draw_rectangle(0.0, 0.0, w, h, color::BACKGROUND());
+
+    draw_ui_text(
+        "Trophies",
+        space::XL,
+        space::XL + scale::TITLE,
+        scale::TITLE,
+        color::TEXT_BRIGHT(),
+    );
+
+    // Progress bar "X/Y Unlocked"
+    let total = achievements.list.len();
+    let unlocked_count = achievements
+        .list
+        .iter()
+        .filter(|a| achievements.is_unlocked(&a.id))
+        .count();
+    let bar_y = space::XL * 2.0 + scale::TITLE;
+    let bar_w = (w - space::XL * 2.0).min(400.0);
+    progress_bar(
+        space::XL,
+        bar_y,
+        bar_w,
+        16.0,
+        unlocked_count as f32,
+        total.max(1) as f32,
+        color::PRIMARY(),
+    );
+    draw_ui_text(
+        &format!("{}/{} Unlocked", unlocked_count, total),
+        space::XL + bar_w + space::MD,
+        bar_y + 13.0,
+        scale::BODY,
+        color::TEXT(),
+    );
+
+    let back_w = 120.0;
+    if button_at(
+        Rect::new(w - back_w - space::XL, space::XL, back_w, 36.0),
+        "Back",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::CloseAchievements);
+    }
+
+    // Tile grid
+    let grid_y = bar_y + 16.0 + space::XL;
+    let cols = (((w - space::XL * 2.0 + TILE_GAP) / (TILE_W + TILE_GAP)) as usize).max(1);
+    let (mx, my) = mouse_position();
+    let mouse = vec2(mx, my);
+    let clicked = is_mouse_button_pressed(MouseButton::Left);
+    let mut selected_rect = None;
+
+    for (i, achievement) in achievements.list.iter().enumerate() {
+        let rect = Rect::new(
+            space::XL + (i % cols) as f32 * (TILE_W + TILE_GAP),
+            grid_y + (i / cols) as f32 * (TILE_H + TILE_GAP),
+            TILE_W,
+            TILE_H,
+        );
+        let unlocked = achievements.is_unlocked(&achievement.id);
+        let is_selected = selected == Some(i);
+
+        if unlocked {
+            draw_card(rect, is_selected);
+        } else {
+            let fill = if achievement.hidden {
+                color::BACKGROUND()
+            } else {
+                color::SURFACE_ALT()
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, fill);
+            draw_rectangle_lines(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                if is_selected { 2.0 } else { 1.0 },
+                if is_selected {
+                    color::ACCENT()
+                } else {
+                    color::BORDER()
+                },
+            );
+        }
+
+        let (icon, icon_color, text_color) = if unlocked {
+            ("🏆", color::PRIMARY(), color::TEXT_BRIGHT())
+        } else if achievement.hidden {
+            ("?", color::BORDER_STRONG(), color::TEXT_DIM())
+        } else {
+            ("🔒", color::TEXT_DIM(), color::TEXT_DIM())
+        };
+        let icon_size = 28.0;
+        let icon_w = measure_ui_text(icon, None, icon_size as u16, 1.0).width;
+        draw_ui_text(
+            icon,
+            rect.x + (rect.w - icon_w) / 2.0,
+            rect.y + space::MD + icon_size,
+            icon_size,
+            icon_color,
+        );
+
+        let title = truncate_text_to_width(
+            tile_title(achievement, unlocked),
+            rect.w - space::MD * 2.0,
+            scale::BODY,
+        );
+        let title_w = measure_ui_text(&title, None, scale::BODY as u16, 1.0).width;
+        draw_ui_text(
+            &title,
+            rect.x + (rect.w - title_w) / 2.0,
+            rect.y + rect.h - space::LG,
+            scale::BODY,
+            text_color,
+        );
+
+        if is_selected {
+            selected_rect = Some(rect);
+        }
+        if clicked && rect.contains(mouse) {
+            // Clicking the pinned tile again closes its tooltip.
+            action = Some(UiAction::InspectAchievement {
+                index: (!is_selected).then_some(i),
+            });
+        }
+    }
+
+    // Tooltip for the selected tile, drawn last so it sits above the grid.
+    if let (Some(index), Some(rect)) = (selected, selected_rect) {
+        let achievement = &achievements.list[index];
+        let unlocked = achievements.is_unlocked(&achievement.id);
+        draw_tooltip(achievement, unlocked, rect);
+    }
+
+    action
+}
+
+fn draw_tooltip(achievement: &Achievement, unlocked: bool, tile: Rect) {
+    let revealed = unlocked || !achievement.hidden;
+    let mut lines = Vec::new();
+    if revealed {
+        lines.extend(wrap(
+            &achievement.description,
+            TOOLTIP_W - space::MD * 2.0,
+            scale::BODY,
+        ));
+        lines.push(String::new());
+        lines.push(format!("Unlock: {}", achievement.condition.describe()));
+    } else {
+        lines.push("Keep playing to discover this one.".to_string());
+    }
+    lines.push(if unlocked {
+        "Unlocked".to_string()
+    } else {
+        "Locked".to_string()
+    });
+
+    let line_h = scale::BODY * 1.35;
+    let title_h = scale::HEADING * 1.5;
+    let h = space::MD * 2.0 + title_h + lines.len() as f32 * line_h;
+    // Prefer the right side of the tile; flip left near the screen edge.
+    let x = if tile.right() + space::SM + TOOLTIP_W <= screen_width() {
+        tile.right() + space::SM
+    } else {
+        (tile.x - space::SM - TOOLTIP_W).max(0.0)
+    };
+    let y = tile.y.min(screen_height() - h).max(0.0);
+
+    draw_rectangle(x, y, TOOLTIP_W, h, color::SURFACE());
+    draw_rectangle_lines(x, y, TOOLTIP_W, h, 1.0, color::BORDER_STRONG());
+
+    draw_ui_text(
+        tile_title(achievement, unlocked),
+        x + space::MD,
+        y + space::MD + scale::HEADING,
+        scale::HEADING,
+        color::TEXT_BRIGHT(),
+    );
+    let last = lines.len() - 1;
+    for (i, line) in lines.iter().enumerate() {
+        let line_color = if i != last {
+            color::TEXT()
+        } else if unlocked {
+            color::POSITIVE()
+        } else {
+            color::TEXT_DIM()
+        };
+        draw_ui_text(
+            line,
+            x + space::MD,
+            y + space::MD + title_h + (i as f32 + 1.0) * line_h - 4.0,
+            scale::BODY,
+            line_color,
+        );
+    }
+}
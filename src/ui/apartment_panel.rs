@@ -1,14 +1,17 @@
 use crate::assets::AssetManager;
 use crate::building::{Apartment, Building};
-use crate::consequences::TenantNetwork;
+use crate::consequences::{PendingPermit, PendingRentIncrease, TenantNetwork};
 use crate::narrative::TenantStory;
 use crate::tenant::Tenant;
 use macroquad::prelude::*;
 use std::collections::HashMap;
 
 use super::apartment_panel_sections::{draw_apartment_stats, draw_sold_condo_panel, draw_upgrades};
-use super::tenant_panel::draw_tenant_info;
+use super::tenant_panel::{draw_tenant_info, TransferTarget};
+use super::theme::{color, scale, space, Tone};
+use super::widgets::button_at;
 use super::{common::*, UiAction};
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
 
 pub fn draw_apartment_panel(
     apt: &Apartment,
@@ -21,6 +24,14 @@ pub fn draw_apartment_panel(
     config: &crate::data::config::GameConfig,
     tenant_network: &TenantNetwork,
     stories: &HashMap<u32, TenantStory>,
+    unlocked_upgrades: &[String],
+    history_expanded: bool,
+    rename_buffer: Option<&str>,
+    transfer_target: Option<&TransferTarget>,
+    current_tick: u32,
+    just_cause_active: bool,
+    permits: &[PendingPermit],
+    pending_rent_increase: Option<&PendingRentIncrease>,
 ) -> (Option<UiAction>, f32) {
     let mut action = None;
     let mut new_scroll = scroll_offset;
@@ -54,6 +65,9 @@ pub fn draw_apartment_panel(
         panel_h,
         &format!("Unit {}", apt.unit_number),
     );
+    if let Some(act) = draw_unit_number_editor(apt, rename_buffer, panel_x, panel_y, panel_w) {
+        action = Some(act);
+    }
 
     let mouse = mouse_position();
     let is_hovering = mouse.0 >= panel_x
@@ -93,6 +107,52 @@ pub fn draw_apartment_panel(
         content_bottom,
         tenant_network,
         stories,
+        history_expanded,
+        transfer_target,
+        just_cause_active,
+        current_tick,
+        config,
+    ) {
+        action = Some(act);
+    }
+
+    draw_vacancy_tax_status(
+        apt,
+        &config.vacancy_tax,
+        current_tick,
+        content_x,
+        &mut y,
+        content_top,
+        content_bottom,
+    );
+
+    if let Some(increase) = pending_rent_increase {
+        if y + scale::LABEL > content_top && y < content_bottom {
+            draw_ui_text(
+                &format!(
+                    "Rent ${}: increase effective in {} months",
+                    increase.new_rent,
+                    increase.effective_tick.saturating_sub(current_tick)
+                ),
+                content_x,
+                y,
+                scale::LABEL,
+                color::WARNING(),
+            );
+        }
+        y += 22.0;
+    }
+
+    if let Some(act) = draw_merge_option(
+        apt,
+        building,
+        money,
+        config.economy.unit_merge_cost,
+        content_x,
+        &mut y,
+        panel_w,
+        content_top,
+        content_bottom,
     ) {
         action = Some(act);
     }
@@ -108,6 +168,9 @@ pub fn draw_apartment_panel(
         content_bottom,
         new_scroll,
         config,
+        unlocked_upgrades,
+        permits,
+        current_tick,
     );
     if let Some(act) = upgrade_action {
         action = Some(act);
@@ -116,3 +179,138 @@ pub fn draw_apartment_panel(
 
     (action, new_scroll)
 }
+
+/// Vacant units: how long until the city's vacancy tax kicks in, or that it
+/// already has.
+fn draw_vacancy_tax_status(
+    apt: &Apartment,
+    config: &crate::data::config::VacancyTaxConfig,
+    current_tick: u32,
+    content_x: f32,
+    y: &mut f32,
+    content_top: f32,
+    content_bottom: f32,
+) {
+    let Some(ticks) = apt.ticks_vacant(current_tick) else {
+        return;
+    };
+    let (text, text_color) = if apt.vacancy_taxed(current_tick, config) {
+        (
+            format!("Vacancy tax: ${}/month", config.vacancy_tax_per_unit),
+            color::NEGATIVE(),
+        )
+    } else {
+        (
+            format!(
+                "Vacancy tax starts in {} months",
+                config.vacancy_tax_trigger_ticks - ticks
+            ),
+            color::TEXT_DIM(),
+        )
+    };
+    if *y + scale::LABEL > content_top && *y < content_bottom {
+        draw_ui_text(&text, content_x, *y, scale::LABEL, text_color);
+    }
+    *y += 22.0;
+}
+
+/// "Merge with adjacent" button, shown only when a vacant neighbor exists.
+fn draw_merge_option(
+    apt: &Apartment,
+    building: &Building,
+    money: i32,
+    merge_cost: i32,
+    content_x: f32,
+    y: &mut f32,
+    panel_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let neighbor = building.get_apartment(building.mergeable_neighbor(apt.id)?)?;
+    let btn_h = 34.0;
+    let label = format!(
+        "Merge with adjacent {} — ${}",
+        neighbor.unit_number, merge_cost
+    );
+    let clicked = *y + btn_h > content_top
+        && *y < content_bottom
+        && button(
+            content_x,
+            *y,
+            panel_w - 30.0,
+            btn_h,
+            &label,
+            money >= merge_cost,
+        );
+    *y += btn_h + 12.0;
+    clicked.then_some(UiAction::MergeUnits {
+        apt_a_id: apt.id,
+        apt_b_id: neighbor.id,
+    })
+}
+
+/// Clicking the panel title edits the unit number; while editing, the title
+/// is replaced by the input field and a Save button.
+fn draw_unit_number_editor(
+    apt: &Apartment,
+    rename_buffer: Option<&str>,
+    panel_x: f32,
+    panel_y: f32,
+    panel_w: f32,
+) -> Option<UiAction> {
+    let header_h = 38.0;
+    let Some(buffer) = rename_buffer else {
+        let title = format!("Unit {}", apt.unit_number);
+        let title_w = measure_ui_text(&title, None, scale::HEADING as u16, 1.0).width;
+        let (x, y) = (panel_x + space::PAD, panel_y + space::XS);
+        if is_hovered(x, y, title_w, header_h - space::SM) {
+            draw_line(
+                x,
+                panel_y + header_h - space::SM,
+                x + title_w,
+                panel_y + header_h - space::SM,
+                1.0,
+                color::TEXT_DIM(),
+            );
+        }
+        return was_clicked(x, y, title_w, header_h - space::SM)
+            .then_some(UiAction::BeginRenameUnit(apt.id));
+    };
+
+    // Cover the static title with the input field.
+    let field = Rect::new(
+        panel_x + space::SM,
+        panel_y + space::XS,
+        panel_w * 0.5,
+        header_h - space::SM,
+    );
+    draw_rectangle(field.x, field.y, field.w, field.h, color::SURFACE_ALT());
+    draw_rectangle_lines(field.x, field.y, field.w, field.h, 2.0, color::PRIMARY());
+    let caret = if (get_time() * 2.0) as i64 % 2 == 0 {
+        "_"
+    } else {
+        ""
+    };
+    draw_ui_text(
+        &format!("Unit {}{}", buffer, caret),
+        field.x + space::SM,
+        field.y + field.h / 2.0 + scale::HEADING / 2.0 - 2.0,
+        scale::HEADING,
+        color::TEXT_BRIGHT(),
+    );
+    draw_ui_text(
+        "Enter to save, Esc to cancel",
+        field.x + field.w + space::SM,
+        field.y + field.h / 2.0 + scale::LABEL / 2.0,
+        scale::LABEL,
+        color::TEXT_DIM(),
+    );
+
+    let save = Rect::new(panel_x + panel_w - 70.0 - space::SM, field.y, 70.0, field.h);
+    button_at(save, "Save", !buffer.trim().is_empty(), Tone::Primary).then(|| {
+        UiAction::RenameUnit {
+            apartment_id: apt.id,
+            new_number: buffer.to_string(),
+        }
+    })
+}
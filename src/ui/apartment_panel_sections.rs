@@ -1,5 +1,7 @@
 use crate::assets::AssetManager;
+use crate::building::upgrades::UpgradeAction;
 use crate::building::{Apartment, ApartmentSize, Building, DesignType, NoiseLevel};
+use crate::consequences::{PendingPermit, PermitType};
 use macroquad::prelude::*;
 
 use super::{common::*, UiAction};
@@ -195,6 +197,9 @@ pub(super) fn draw_upgrades(
     content_bottom: f32,
     current_scroll: f32,
     config: &crate::data::config::GameConfig,
+    unlocked_upgrades: &[String],
+    permits: &[PendingPermit],
+    current_tick: u32,
 ) -> (Option<UiAction>, f32) {
     let w = panel_w - 30.0;
     if *y > content_top && *y < content_bottom {
@@ -209,7 +214,12 @@ pub(super) fn draw_upgrades(
 
     let btn_w = w;
     let btn_h = 34.0;
-    let available = crate::building::upgrades::available_apartment_upgrades(apt, &config.upgrades);
+    let available = crate::building::upgrades::available_apartment_upgrades(
+        apt,
+        building,
+        &config.upgrades,
+        unlocked_upgrades,
+    );
 
     let upgrades_start_y = *y;
     let mut total_upgrade_height = 0.0;
@@ -230,17 +240,84 @@ pub(super) fn draw_upgrades(
     for upgrade in available {
         if let Some(cost) = upgrade.cost(building, &config.economy, &config.upgrades) {
             let can_afford = money >= cost;
-            let label = format!(
-                "{} — ${}",
-                upgrade.label(building, &config.ui, &config.upgrades),
-                cost
-            );
+            let name = upgrade.label(building, &config.ui, &config.upgrades);
+            let visible = *y + btn_h > content_top && *y < content_bottom;
+            let permit = match &upgrade {
+                UpgradeAction::Apply { upgrade_id, .. }
+                    if cost > config.economy.permit_cost_threshold =>
+                {
+                    Some((
+                        upgrade_id.clone(),
+                        permits
+                            .iter()
+                            .find(|p| p.upgrade_id == *upgrade_id && p.apartment_id == apt.id),
+                    ))
+                }
+                _ => None,
+            };
 
-            if *y + btn_h > content_top
-                && *y < content_bottom
-                && button(content_x, *y, btn_w, btn_h, &label, can_afford)
-            {
-                action = Some(UiAction::UpgradeAction(upgrade));
+            match permit {
+                Some((_, Some(filed))) if !filed.is_approved(current_tick) => {
+                    let label = match filed.permit_type {
+                        PermitType::FastTrack => {
+                            format!(
+                                "{} — fast-tracked, ready month {}",
+                                name, filed.approved_tick
+                            )
+                        }
+                        PermitType::Standard => format!(
+                            "{} — permit filed month {}, ready month {}",
+                            name, filed.submitted_tick, filed.approved_tick
+                        ),
+                    };
+                    if visible {
+                        button(content_x, *y, btn_w, btn_h, &label, false);
+                    }
+                }
+                Some((upgrade_id, None)) => {
+                    let fee = config.economy.permit_fee;
+                    let fast_w = btn_w * 0.35;
+                    let label = format!("{} — permit ${}", name, fee);
+                    let fast_label = format!("Fast-track ${}", fee * 2);
+                    if visible
+                        && button(
+                            content_x,
+                            *y,
+                            btn_w - fast_w - 6.0,
+                            btn_h,
+                            &label,
+                            money >= fee,
+                        )
+                    {
+                        action = Some(UiAction::SubmitPermitApplication {
+                            upgrade_id: upgrade_id.clone(),
+                            apartment_id: apt.id,
+                            fast_track: false,
+                        });
+                    }
+                    if visible
+                        && button(
+                            content_x + btn_w - fast_w,
+                            *y,
+                            fast_w,
+                            btn_h,
+                            &fast_label,
+                            money >= fee * 2,
+                        )
+                    {
+                        action = Some(UiAction::SubmitPermitApplication {
+                            upgrade_id,
+                            apartment_id: apt.id,
+                            fast_track: true,
+                        });
+                    }
+                }
+                _ => {
+                    let label = format!("{} — ${}", name, cost);
+                    if visible && button(content_x, *y, btn_w, btn_h, &label, can_afford) {
+                        action = Some(UiAction::UpgradeAction(upgrade));
+                    }
+                }
             }
             *y += btn_h + 8.0;
         }
@@ -1,6 +1,7 @@
 use super::{common::*, UiAction};
 use crate::assets::AssetManager;
 use crate::building::Building;
+use crate::data::config::{GameConfig, MatchingConfig};
 use crate::tenant::TenantApplication;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text;
@@ -9,9 +10,14 @@ pub fn draw_application_panel(
     applications: &[TenantApplication],
     building: &Building,
     filter_apartment_id: Option<u32>,
+    sort_by_score: bool,
     offset_x: f32,
     assets: &AssetManager,
+    config: &GameConfig,
 ) -> Option<UiAction> {
+    use crate::ui::theme::Tone;
+    use crate::ui::widgets::button_at;
+
     let panel_rect = application_panel_rect(offset_x)?;
     panel(
         panel_rect.x,
@@ -23,17 +29,40 @@ pub fn draw_application_panel(
 
     let content_x = panel_rect.x + 15.0;
     let mut y = panel_rect.y + 50.0;
-    let filtered_apps: Vec<(usize, &TenantApplication)> = applications
+    let mut filtered_apps: Vec<(usize, &TenantApplication)> = applications
         .iter()
         .enumerate()
         .filter(|(_, app)| filter_apartment_id.is_none_or(|id| app.apartment_id == id))
         .collect();
 
+    let waitlisted: Vec<&TenantApplication> = building
+        .waitlisted_applications
+        .iter()
+        .filter(|app| filter_apartment_id.is_none_or(|id| app.apartment_id == id))
+        .collect();
+
     if filtered_apps.is_empty() {
         draw_empty_applications(content_x, y, filter_apartment_id);
+        draw_waitlist(&waitlisted, building, content_x, y + 60.0, panel_rect);
         return None;
     }
 
+    let ranking = |app: &TenantApplication| {
+        building
+            .get_apartment(app.apartment_id)
+            .map_or(0, |apt| app.ranking_score(apt.rent_price, config))
+    };
+    // The top-ranked applicant for each unit gets the badge.
+    let best: Vec<usize> = filtered_apps
+        .iter()
+        .filter_map(|(_, app)| {
+            crate::tenant::best_application(applications, building, app.apartment_id, config)
+        })
+        .collect();
+    if sort_by_score {
+        filtered_apps.sort_by_key(|(_, app)| std::cmp::Reverse(ranking(app)));
+    }
+
     draw_ui_text(
         &format!("{} pending", filtered_apps.len()),
         content_x,
@@ -41,9 +70,33 @@ pub fn draw_application_panel(
         16.0,
         colors::TEXT_DIM(),
     );
+    let mut action = None;
+    let right = panel_rect.x + panel_rect.w - 15.0;
+    let sort_label = if sort_by_score {
+        "Sort: Score"
+    } else {
+        "Sort: Newest"
+    };
+    if button_at(
+        Rect::new(right - 110.0, y - 18.0, 110.0, 24.0),
+        sort_label,
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::ToggleApplicationSort);
+    }
+    if let Some(apartment_id) = filter_apartment_id {
+        if button_at(
+            Rect::new(right - 226.0, y - 18.0, 110.0, 24.0),
+            "Accept Best",
+            true,
+            Tone::Positive,
+        ) {
+            action = Some(UiAction::AcceptBestApplication { apartment_id });
+        }
+    }
     y += 25.0;
 
-    let mut action = None;
     for (index, application) in filtered_apps {
         if y > panel_rect.y + panel_rect.h - 60.0 {
             draw_ui_text(
@@ -59,21 +112,72 @@ pub fn draw_application_panel(
         let (card_action, card_h) = draw_application_card(
             index,
             application,
+            best.contains(&index),
             building,
             content_x,
             y,
             panel_rect.w - 30.0,
             assets,
+            config,
         );
         if card_action.is_some() {
             action = card_action;
         }
         y += card_h + 12.0;
     }
+    draw_waitlist(&waitlisted, building, content_x, y, panel_rect);
 
     action
 }
 
+/// Applicants waiting for a slot to open on a unit, best match first.
+fn draw_waitlist(
+    waitlisted: &[&TenantApplication],
+    building: &Building,
+    content_x: f32,
+    mut y: f32,
+    panel_rect: Rect,
+) {
+    let bottom = panel_rect.y + panel_rect.h - 20.0;
+    if waitlisted.is_empty() || y > bottom {
+        return;
+    }
+    draw_ui_text(
+        &format!("Waitlist ({})", waitlisted.len()),
+        content_x,
+        y,
+        16.0,
+        colors::TEXT_DIM(),
+    );
+    y += 22.0;
+
+    let mut sorted = waitlisted.to_vec();
+    sorted.sort_by_key(|app| std::cmp::Reverse(app.match_result.score));
+    for app in sorted {
+        if y > bottom {
+            break;
+        }
+        let unit = building
+            .get_apartment(app.apartment_id)
+            .map(|a| a.unit_number.as_str())
+            .unwrap_or_default();
+        draw_ui_text(
+            &format!(
+                "{} ({}) -> Unit {}, match {}%",
+                app.tenant.name,
+                app.tenant.archetype.name(),
+                unit,
+                app.match_result.score
+            ),
+            content_x,
+            y,
+            14.0,
+            colors::TEXT(),
+        );
+        y += 20.0;
+    }
+}
+
 fn application_panel_rect(offset_x: f32) -> Option<Rect> {
     let panel_x = screen_width() * layout::PANEL_SPLIT() + layout::PADDING() + offset_x;
     if panel_x > screen_width() {
@@ -125,11 +229,13 @@ fn draw_empty_applications(content_x: f32, y: f32, filter_apartment_id: Option<u
 fn draw_application_card(
     index: usize,
     application: &TenantApplication,
+    is_best: bool,
     building: &Building,
     x: f32,
     y: f32,
     width: f32,
     assets: &AssetManager,
+    config: &GameConfig,
 ) -> (Option<UiAction>, f32) {
     use crate::ui::theme::Tone;
     use crate::ui::widgets::button_at;
@@ -147,13 +253,13 @@ fn draw_application_card(
     let gap = 6.0;
     let right = x + width - 8.0;
 
-    // Adaptive grid: 4 across when there's room, otherwise 2x2.
-    let cols = if right - text_x >= 4.0 * 74.0 + 3.0 * gap {
-        4
+    // Adaptive grid: all 7 across when there's room, otherwise 4 per row.
+    let cols = if right - text_x >= 7.0 * 90.0 + 6.0 * gap {
+        7
     } else {
-        2
+        4
     };
-    let rows = 4_usize.div_ceil(cols);
+    let rows = 7_usize.div_ceil(cols);
     let bw = ((right - text_x) - (cols - 1) as f32 * gap) / cols as f32;
     let card_h = 88.0 + rows as f32 * (bh + gap) + 4.0;
 
@@ -172,16 +278,48 @@ fn draw_application_card(
         );
     }
     draw_application_text(application, building, text_x, y);
+    draw_fit_bars(application, x + width - 88.0, y + 10.0, &config.matching);
+    if is_best {
+        let badge = Rect::new(x + width - 92.0, y - 8.0, 84.0, 16.0);
+        draw_rectangle(badge.x, badge.y, badge.w, badge.h, GOLD);
+        draw_ui_text("Best Match", badge.x + 8.0, badge.y + 12.0, 13.0, BLACK);
+    }
+    if application.records_inconsistent(&config.vetting) {
+        let badge = Rect::new(x + width - 252.0, y - 8.0, 152.0, 16.0);
+        draw_rectangle(badge.x, badge.y, badge.w, badge.h, colors::NEGATIVE());
+        draw_ui_text(
+            "⚠ Inconsistent Records",
+            badge.x + 8.0,
+            badge.y + 12.0,
+            13.0,
+            WHITE,
+        );
+    }
 
-    let specs: [(&str, bool, Tone, UiAction); 4] = [
+    let accept = if application.has_housing_voucher() {
+        (
+            "Accept (Voucher)",
+            UiAction::AcceptVoucherApplication {
+                application_index: index,
+            },
+        )
+    } else {
         (
             "Accept",
-            true,
-            Tone::Positive,
             UiAction::AcceptApplication {
                 application_index: index,
             },
-        ),
+        )
+    };
+    let vetting = &config.vetting;
+    let reference_label = format!("Ref (${})", vetting.reference_check_cost);
+    let income_label = format!("Income Check (${})", vetting.income_check_cost);
+    let contact_label = match &application.tenant.emergency_contact {
+        Some(contact) => format!("Contact: {}", contact.relationship),
+        None => "Add Contact".to_string(),
+    };
+    let specs: [(&str, bool, Tone, UiAction); 7] = [
+        (accept.0, true, Tone::Positive, accept.1),
         (
             "Reject",
             true,
@@ -206,6 +344,30 @@ fn draw_application_card(
                 application_index: index,
             },
         ),
+        (
+            &reference_label,
+            application.reference.is_none(),
+            Tone::Secondary,
+            UiAction::ReferenceCheck {
+                application_index: index,
+            },
+        ),
+        (
+            &income_label,
+            application.tenant.verified_income.is_none(),
+            Tone::Secondary,
+            UiAction::IncomeCheck {
+                application_index: index,
+            },
+        ),
+        (
+            &contact_label,
+            application.tenant.emergency_contact.is_none(),
+            Tone::Secondary,
+            UiAction::CollectEmergencyContact {
+                application_index: index,
+            },
+        ),
     ];
 
     let mut action = None;
@@ -222,6 +384,34 @@ fn draw_application_card(
     (action, card_h)
 }
 
+/// Small bars showing how rent, condition and archetype preferences each
+/// contributed to the match score; negative contributions draw red.
+fn draw_fit_bars(application: &TenantApplication, x: f32, y: f32, matching: &MatchingConfig) {
+    let result = &application.match_result;
+    let bars = [
+        ("Rent", result.rent_fit, matching.rent_great_bonus),
+        (
+            "Cond",
+            result.condition_fit,
+            matching.condition_excellent_bonus,
+        ),
+        ("Pref", result.preference_fit, 30),
+    ];
+    for (i, (label, value, max)) in bars.into_iter().enumerate() {
+        let by = y + i as f32 * 14.0;
+        draw_ui_text(label, x, by + 9.0, 11.0, colors::TEXT_DIM());
+        let (bar_x, bar_w) = (x + 30.0, 50.0);
+        draw_rectangle(bar_x, by, bar_w, 8.0, colors::SURFACE_ALT());
+        let fill = (value.abs() as f32 / max.max(1) as f32).min(1.0);
+        let color = if value >= 0 {
+            colors::POSITIVE()
+        } else {
+            colors::NEGATIVE()
+        };
+        draw_rectangle(bar_x, by, bar_w * fill, 8.0, color);
+    }
+}
+
 fn draw_application_text(
     application: &TenantApplication,
     building: &Building,
@@ -242,6 +432,23 @@ fn draw_application_text(
         14.0,
         colors::TEXT_DIM(),
     );
+    if application.has_housing_voucher() {
+        draw_ui_text(
+            &format!("Voucher ${}", application.tenant.voucher_amount),
+            text_x + 240.0,
+            y + 22.0,
+            14.0,
+            colors::POSITIVE(),
+        );
+    } else if let Some(guarantor) = application.guarantor() {
+        draw_ui_text(
+            &format!("{} ${}/mo", guarantor.relationship, guarantor.income),
+            text_x + 240.0,
+            y + 22.0,
+            14.0,
+            colors::POSITIVE(),
+        );
+    }
 
     if let Some(apartment) = building.get_apartment(application.apartment_id) {
         draw_ui_text(
@@ -276,7 +483,13 @@ fn draw_application_text(
     draw_ui_text(fit_text, text_x + 240.0, y + 42.0, 14.0, colors::TEXT_DIM());
 
     let credit_text = if application.revealed_reliability {
-        format!("Credit: {}", application.tenant.rent_reliability)
+        match application.guarantor() {
+            Some(guarantor) => format!(
+                "Credit: {} (G {})",
+                application.tenant.rent_reliability, guarantor.credit_score
+            ),
+            None => format!("Credit: {}", application.tenant.rent_reliability),
+        }
     } else {
         "Credit: ?".to_string()
     };
@@ -294,4 +507,19 @@ fn draw_application_text(
         14.0,
         colors::TEXT_DIM(),
     );
+
+    let reference_text = match &application.reference {
+        Some(reference) => format!(
+            "Ref: pay {} / clean {}",
+            reference.payment_reliability, reference.cleanliness_rating
+        ),
+        None => "Ref: ?".to_string(),
+    };
+    draw_ui_text(
+        &reference_text,
+        text_x + 240.0,
+        y + 67.0,
+        14.0,
+        colors::TEXT_DIM(),
+    );
 }
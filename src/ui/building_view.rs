@@ -3,15 +3,18 @@ use super::widgets::button_at;
 use super::{common::*, Selection, UiAction};
 use crate::assets::AssetManager;
 use crate::building::{Apartment, ApartmentSize, Building, DesignType, NoiseLevel};
+use crate::tenant::matching::ELEVATOR_FLAG;
 use crate::tenant::Tenant;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
 
 pub fn draw_building_view(
     building: &Building,
+    building_id: u32,
     tenants: &[Tenant],
     selection: &Selection,
     assets: &AssetManager,
+    renovation_cost_per_unit: i32,
 ) -> Option<UiAction> {
     let mut action = None;
 
@@ -20,8 +23,12 @@ pub fn draw_building_view(
     let view_x = 0.0;
     let view_y = layout::HEADER_HEIGHT();
 
-    // Background - Building Exterior
-    if let Some(tex) = assets.get_texture("building_exterior") {
+    // Background - Building Exterior, falling back to the classic art for
+    // themes without their own texture.
+    let exterior = assets
+        .get_texture(building.exterior_theme.texture_id())
+        .or_else(|| assets.get_texture("building_exterior"));
+    if let Some(tex) = exterior {
         draw_texture_ex(
             tex,
             view_x,
@@ -53,6 +60,7 @@ pub fn draw_building_view(
     let center_x = view_x + view_width / 2.0;
     let start_x = view_x + (view_width - total_width) / 2.0;
     let start_y = view_y + view_height - 80.0; // Start from bottom
+    let has_elevator = building.flags.contains(ELEVATOR_FLAG);
 
     // Draw floors (bottom to top)
     for floor in 1..=max_floor {
@@ -66,6 +74,9 @@ pub fn draw_building_view(
             scale::LABEL,
             color::TEXT_DIM(),
         );
+        if has_elevator {
+            draw_elevator_icon(start_x - 96.0, floor_y + layout::UNIT_HEIGHT() / 2.0 - 12.0);
+        }
 
         // Draw units on this floor
         let floor_apartments: Vec<_> = building
@@ -168,6 +179,21 @@ pub fn draw_building_view(
         color::TEXT_BRIGHT(),
     );
 
+    if building.is_landmark() {
+        let label_w = measure_ui_text("HALLWAY", None, scale::LABEL as u16, 1.0).width;
+        draw_landmark_plaque(
+            start_x + space::MD + label_w + space::SM,
+            hallway_y + (hallway_h - 16.0) / 2.0,
+        );
+    }
+
+    if building.has_hallway_art() {
+        draw_art_icon(
+            start_x + hallway_width - 134.0,
+            hallway_y + (hallway_h - 16.0) / 2.0,
+        );
+    }
+
     let cond_color = condition_color(building.hallway_condition);
     progress_bar(
         start_x + hallway_width - 110.0,
@@ -203,6 +229,104 @@ pub fn draw_building_view(
         action = Some(UiAction::SelectOwnership);
     }
 
+    if building.renovation_required {
+        let banner = Rect::new(
+            start_x,
+            btn_y + btn_h + space::SM,
+            total_width.max(420.0),
+            64.0,
+        );
+        if let Some(renovation_action) =
+            draw_renovation_banner(building, building_id, banner, renovation_cost_per_unit)
+        {
+            action = Some(renovation_action);
+        }
+    }
+
+    action
+}
+
+/// Banner over a condemned building: either the renovation offer or the
+/// progress of the one underway.
+fn draw_renovation_banner(
+    building: &Building,
+    building_id: u32,
+    rect: Rect,
+    cost_per_unit: i32,
+) -> Option<UiAction> {
+    let mut action = None;
+    draw_rectangle(
+        rect.x,
+        rect.y,
+        rect.w,
+        rect.h,
+        Color::new(0.35, 0.08, 0.08, 0.9),
+    );
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, color::NEGATIVE());
+    draw_ui_text(
+        "RENOVATION REQUIRED",
+        rect.x + space::MD,
+        rect.y + space::MD + scale::BODY,
+        scale::BODY,
+        color::TEXT_BRIGHT(),
+    );
+
+    let row_y = rect.y + rect.h - 30.0;
+    if let Some(renovation) = &building.renovation {
+        progress_bar(
+            rect.x + space::MD,
+            row_y + 8.0,
+            rect.w * 0.5,
+            14.0,
+            renovation.progress() * 100.0,
+            100.0,
+            color::POSITIVE(),
+        );
+        draw_ui_text(
+            &format!(
+                "${} budget · {} months left",
+                renovation.budget, renovation.months_remaining
+            ),
+            rect.x + rect.w * 0.5 + space::LG,
+            row_y + 8.0 + scale::LABEL,
+            scale::LABEL,
+            color::TEXT(),
+        );
+    } else {
+        let standard = building.renovation_standard_cost(cost_per_unit);
+        draw_ui_text(
+            "No tenants will apply until it's fixed up.",
+            rect.x + space::MD,
+            row_y + 8.0 + scale::LABEL,
+            scale::LABEL,
+            color::TEXT_DIM(),
+        );
+        let btn_w = 150.0;
+        let rush_x = rect.x + rect.w - btn_w - space::SM;
+        let standard_x = rush_x - btn_w - space::SM;
+        if button_at(
+            Rect::new(standard_x, row_y, btn_w, 26.0),
+            &format!("Renovate ${}", standard),
+            true,
+            Tone::Primary,
+        ) {
+            action = Some(UiAction::BeginRenovation {
+                building_id,
+                budget: standard,
+            });
+        }
+        if button_at(
+            Rect::new(rush_x, row_y, btn_w, 26.0),
+            &format!("Rush ${}", standard * 2),
+            true,
+            Tone::Danger,
+        ) {
+            action = Some(UiAction::BeginRenovation {
+                building_id,
+                budget: standard * 2,
+            });
+        }
+    }
     action
 }
 
@@ -466,3 +590,47 @@ fn draw_apartment_unit_sized(
 
     None
 }
+
+/// Framed picture with a little mountain, drawn in the hallway when the art
+/// installation is up.
+fn draw_art_icon(x: f32, y: f32) {
+    let (w, h) = (18.0, 16.0);
+    draw_rectangle(x, y, w, h, color::SURFACE_HEADER());
+    draw_rectangle_lines(x, y, w, h, 2.0, color::ACCENT());
+    draw_triangle(
+        vec2(x + 3.0, y + h - 3.0),
+        vec2(x + 8.0, y + 5.0),
+        vec2(x + 13.0, y + h - 3.0),
+        color::POSITIVE(),
+    );
+    draw_circle(x + w - 5.0, y + 5.0, 2.0, color::ACCENT());
+}
+
+/// Round bronze plaque on the hallway wall of a designated landmark.
+fn draw_landmark_plaque(x: f32, y: f32) {
+    let r = 8.0;
+    let bronze = Color::new(0.72, 0.46, 0.22, 1.0);
+    draw_circle(x + r, y + r, r, bronze);
+    draw_circle_lines(x + r, y + r, r - 2.5, 1.0, color::SURFACE_HEADER());
+    draw_rectangle(x + r - 3.0, y + r - 1.0, 6.0, 2.0, color::SURFACE_HEADER());
+}
+
+/// Small elevator car with up/down arrows, drawn beside each floor label.
+fn draw_elevator_icon(x: f32, y: f32) {
+    let (w, h) = (12.0, 16.0);
+    draw_rectangle(x, y, w, h, color::SURFACE_HEADER());
+    draw_rectangle_lines(x, y, w, h, 1.0, color::BORDER_STRONG());
+    let cx = x + w / 2.0;
+    draw_triangle(
+        vec2(cx, y + 2.0),
+        vec2(cx - 3.0, y + 6.0),
+        vec2(cx + 3.0, y + 6.0),
+        color::TEXT_DIM(),
+    );
+    draw_triangle(
+        vec2(cx, y + h - 2.0),
+        vec2(cx - 3.0, y + h - 6.0),
+        vec2(cx + 3.0, y + h - 6.0),
+        color::TEXT_DIM(),
+    );
+}
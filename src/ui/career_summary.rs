@@ -1,3 +1,4 @@
+use crate::simulation::GameOutcome;
 use crate::state::GameplayState;
 use crate::ui::theme::{color, scale, space, Tone};
 use crate::ui::widgets::{button_at, draw_card};
@@ -23,7 +24,7 @@ pub fn draw_career_summary(state: &GameplayState) -> Option<UiAction> {
         .city
         .neighborhoods
         .iter()
-        .map(|n| n.reputation)
+        .map(|n| n.effective_reputation())
         .sum::<i32>()
         / state.city.neighborhoods.len().max(1) as i32;
     let achievements_unlocked = state.achievements.unlocked.len();
@@ -69,7 +70,42 @@ pub fn draw_career_summary(state: &GameplayState) -> Option<UiAction> {
         30.0,
         colors::TEXT(),
     );
-    y += 60.0;
+    y += 40.0;
+
+    // The seed lets another player start this exact run.
+    draw_text_centered(
+        &format!("Seed: {}", state.seed),
+        cx,
+        y,
+        20.0,
+        colors::TEXT_DIM(),
+    );
+    y += 40.0;
+
+    if let Some(scenario) = &state.scenario {
+        let (result, scenario_score, tint) = match &state.game_outcome {
+            Some(GameOutcome::Victory { score, .. }) => ("Complete", *score, colors::POSITIVE()),
+            Some(GameOutcome::ScenarioFailed { score }) => {
+                ("Out of time", *score, colors::NEGATIVE())
+            }
+            _ => (
+                "Unfinished",
+                scenario.score(state.current_tick),
+                colors::WARNING(),
+            ),
+        };
+        draw_text_centered(
+            &format!(
+                "Scenario: {} — {} (score {})",
+                scenario.name, result, scenario_score
+            ),
+            cx,
+            y,
+            26.0,
+            tint,
+        );
+        y += 50.0;
+    }
 
     // Stats Grid
     let stats_y = y;
@@ -115,6 +151,52 @@ pub fn draw_career_summary(state: &GameplayState) -> Option<UiAction> {
 
     y += 100.0;
 
+    // Career history: cumulative totals rather than the ending state
+    let lifetime = &state.lifetime_stats;
+    draw_text_centered("Career History", cx, y, 30.0, colors::TEXT_BRIGHT());
+    y += 40.0;
+    let history_x = cx - col_w * 3.0; // 6 columns
+    let history = [
+        (
+            "Rent Collected",
+            format!("${}", lifetime.total_rent_collected),
+            colors::POSITIVE(),
+        ),
+        (
+            "Tenants Housed",
+            format!("{}", lifetime.total_tenants_housed),
+            colors::TEXT(),
+        ),
+        (
+            "Peak Occupancy",
+            format!(
+                "{} (month {})",
+                lifetime.highest_occupancy, lifetime.highest_occupancy_tick
+            ),
+            colors::TEXT(),
+        ),
+        (
+            "Most Buildings",
+            format!("{}", lifetime.max_buildings_owned),
+            colors::ACCENT(),
+        ),
+        (
+            "Upgrades",
+            format!("{}", lifetime.total_upgrades_applied),
+            colors::TEXT_BRIGHT(),
+        ),
+        (
+            "Worst Gentrification",
+            format!("{}", lifetime.worst_gentrification_score),
+            colors::WARNING(),
+        ),
+    ];
+    for (i, (label, value, tint)) in history.iter().enumerate() {
+        draw_stat(label, value, history_x + col_w * i as f32, y, *tint);
+    }
+
+    y += 100.0;
+
     // Achievements
     draw_text_centered("Achievements Unlocked", cx, y, 30.0, colors::TEXT_BRIGHT());
     y += 40.0;
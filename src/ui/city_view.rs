@@ -1,14 +1,21 @@
 use super::city_view_widgets::{
-    draw_button_icon, draw_button_mini, draw_listing_card, draw_progress_bar,
+    draw_button_icon, draw_button_mini, draw_listing_card, draw_market_filter_bar,
+    draw_progress_bar, draw_reputation_tooltip, draw_rival_marker, draw_rival_tooltip,
+    rival_marker_rect,
 };
 use crate::assets::AssetManager;
-use crate::city::{City, Neighborhood, NeighborhoodType, PropertyListing};
+use crate::building::green_certification_level;
+use crate::city::{
+    appraise_building, City, FinancingOption, MarketFilter, Neighborhood, NeighborhoodType,
+    PropertyListing, RivalBuilding,
+};
+use crate::data::config::GameConfig;
 use crate::narrative::NarrativeEventSystem;
 use crate::ui::colors;
 use crate::ui::theme::scale;
-use crate::ui::widgets::{draw_card, draw_panel};
+use crate::ui::widgets::{draw_card, draw_green_certification_badge, draw_panel};
 use macroquad::prelude::*;
-use macroquad_toolkit::ui::{draw_ui_text, draw_ui_text_ex};
+use macroquad_toolkit::ui::{draw_ui_text, draw_ui_text_ex, measure_ui_text};
 
 fn text_params(font_size: f32, color: Color) -> TextParams<'static> {
     TextParams {
@@ -39,6 +46,10 @@ pub fn draw_city_map(
     let padding = 10.0;
 
     let mut action = None;
+    let mut hovered_neighborhood = None;
+    let mut hovered_rival = None;
+    let mouse = mouse_position();
+    let mouse_point = vec2(mouse.0, mouse.1);
 
     for (i, neighborhood) in city.neighborhoods.iter().enumerate() {
         let col = i % 2;
@@ -47,13 +58,24 @@ pub fn draw_city_map(
         let x = grid_x + col as f32 * (cell_width + padding);
         let y = grid_y + row as f32 * (cell_height + padding);
 
+        if Rect::new(x, y, cell_width, cell_height).contains(mouse_point) {
+            hovered_neighborhood = Some(neighborhood);
+        }
+
+        let rivals = city.rivals_in(neighborhood.id);
+        for (slot, rival) in rivals.iter().enumerate() {
+            if rival_marker_rect(x, y, cell_height, slot).contains(mouse_point) {
+                hovered_rival = Some(*rival);
+            }
+        }
+
         if let Some(a) = draw_neighborhood_cell(
             neighborhood,
             x,
             y,
             cell_width,
             cell_height,
-            city,
+            &rivals,
             assets,
             narrative,
         ) {
@@ -61,9 +83,28 @@ pub fn draw_city_map(
         }
     }
 
+    // Drawn after every cell so neighboring cells can't paint over it.
+    if let Some(rival) = hovered_rival {
+        draw_rival_tooltip(rival, city.active_building());
+    } else if let Some(neighborhood) = hovered_neighborhood {
+        draw_reputation_tooltip(
+            neighborhood,
+            narrative,
+            has_unread_event(narrative, neighborhood.id),
+        );
+    }
+
     action
 }
 
+/// Whether the neighborhood has unread news waiting.
+fn has_unread_event(narrative: &NarrativeEventSystem, neighborhood_id: u32) -> bool {
+    narrative
+        .events
+        .iter()
+        .any(|e| !e.read && e.related_neighborhood_id == Some(neighborhood_id))
+}
+
 /// Draw a single neighborhood cell
 fn draw_neighborhood_cell(
     neighborhood: &Neighborhood,
@@ -71,7 +112,7 @@ fn draw_neighborhood_cell(
     y: f32,
     width: f32,
     height: f32,
-    _city: &City,
+    rivals: &[&RivalBuilding],
     assets: &AssetManager,
     narrative: &NarrativeEventSystem,
 ) -> Option<CityMapAction> {
@@ -175,11 +216,16 @@ fn draw_neighborhood_cell(
         text_params(scale::CAPTION, colors::TEXT_DIM()),
     );
 
+    // Competing landlords' buildings
+    for (slot, rival) in rivals.iter().enumerate() {
+        draw_rival_marker(rival, rival_marker_rect(x, y, height, slot));
+    }
+
     // Reputation bar
     let bar_y = y + height - 25.0;
     let bar_width = width - 16.0;
     draw_ui_text_ex(
-        &format!("Rep: {}", neighborhood.reputation),
+        &format!("Rep: {}", neighborhood.effective_reputation()),
         x + 8.0,
         bar_y - 3.0,
         text_params(scale::CAPTION, colors::TEXT_DIM()),
@@ -189,17 +235,12 @@ fn draw_neighborhood_cell(
         bar_y,
         bar_width,
         8.0,
-        neighborhood.reputation as f32 / 100.0,
+        neighborhood.effective_reputation() as f32 / 100.0,
         colors::POSITIVE(),
     );
 
     // Event indicator
-    let has_event = narrative
-        .events
-        .iter()
-        .any(|e| !e.read && e.related_neighborhood_id == Some(neighborhood.id));
-
-    if has_event {
+    if has_unread_event(narrative, neighborhood.id) {
         let icon_x = x + width - 30.0;
         let icon_y = y + 30.0;
         draw_circle(icon_x, icon_y, 12.0, colors::ACCENT());
@@ -225,6 +266,7 @@ pub fn draw_portfolio_panel(
     city: &City,
     selected_building: usize,
     assets: &AssetManager,
+    config: &GameConfig,
 ) -> Option<CityMapAction> {
     let panel_x = screen_width() * 0.5 + 10.0;
     let panel_y = 80.0;
@@ -238,7 +280,7 @@ pub fn draw_portfolio_panel(
 
     let mut action = None;
     let mut y = content.y;
-    let item_height = 80.0;
+    let item_height = 96.0;
 
     for (index, building, neighborhood_name) in city.buildings_with_info() {
         let is_selected = index == selected_building;
@@ -279,6 +321,13 @@ pub fn draw_portfolio_panel(
                 },
             ),
         );
+        let name_w = measure_ui_text(&building.name, None, scale::HEADING as u16, 1.0).width;
+        draw_green_certification_badge(
+            item_x + 10.0 + name_w + 8.0,
+            y + 7.0,
+            18.0,
+            green_certification_level(building),
+        );
 
         // Location
         draw_ui_text_ex(
@@ -307,6 +356,18 @@ pub fn draw_portfolio_panel(
             ),
         );
 
+        if let Some(neighborhood) = city.neighborhood_for_building(index) {
+            draw_ui_text_ex(
+                &format!(
+                    "Appraised Value: ${}",
+                    appraise_building(building, neighborhood, config)
+                ),
+                item_x + 10.0,
+                y + 76.0,
+                text_params(scale::LABEL, colors::ACCENT()),
+            );
+        }
+
         // Click to select
         let mouse = mouse_position();
         let hovered = mouse.0 >= item_x
@@ -338,9 +399,12 @@ pub fn draw_portfolio_panel(
     action
 }
 
-/// Draw property market listings
+/// Draw property market listings. `listings` is already filtered and sorted;
+/// `total_listings` is the unfiltered count for the "Showing N of M" label.
 pub fn draw_market_panel(
     listings: &[&PropertyListing],
+    total_listings: usize,
+    filter: &MarketFilter,
     neighborhoods: &[Neighborhood],
     player_funds: i32,
     assets: &AssetManager,
@@ -366,8 +430,10 @@ pub fn draw_market_panel(
         text_params(scale::LABEL, colors::POSITIVE()),
     );
 
-    let mut action = None;
-    let start_y = content.y;
+    let mut action =
+        draw_market_filter_bar(content.x, content.y, filter, listings.len(), total_listings)
+            .map(CityMapAction::SetMarketFilter);
+    let start_y = content.y + 40.0;
     let listing_height = 120.0;
     let listing_width = (content.w - 20.0) / 2.0;
 
@@ -418,5 +484,7 @@ pub enum CityMapAction {
     OpenMarket,
     CloseMarket,
     PurchaseBuilding(u32),
+    FinanceBuilding(u32, FinancingOption),
     EnterBuilding(usize),
+    SetMarketFilter(MarketFilter),
 }
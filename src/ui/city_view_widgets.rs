@@ -1,5 +1,10 @@
 use crate::assets::AssetManager;
-use crate::city::{Neighborhood, NeighborhoodType, PropertyListing};
+use crate::building::Building;
+use crate::city::{
+    BuildingCondition, FinancingOption, MarketFilter, Neighborhood, NeighborhoodType,
+    PropertyListing, RivalBuilding,
+};
+use crate::narrative::NarrativeEventSystem;
 use crate::ui::colors;
 use crate::ui::theme::{self, scale, Tone};
 use macroquad::prelude::*;
@@ -29,6 +34,223 @@ pub(super) fn draw_listing_card(
     draw_listing_purchase(listing, x, y, width, height, player_funds)
 }
 
+/// Hover tooltip breaking a neighborhood's reputation into its components,
+/// plus the latest headlines about it when it has unread news.
+pub(super) fn draw_reputation_tooltip(
+    neighborhood: &Neighborhood,
+    narrative: &NarrativeEventSystem,
+    show_events: bool,
+) {
+    let stats = &neighborhood.stats;
+    let rows = [
+        ("Your standing", neighborhood.reputation),
+        ("Crime", stats.crime_penalty()),
+        ("Transit access", stats.transit_bonus()),
+        ("Gentrification", stats.gentrification_contribution()),
+        ("Your buildings", neighborhood.building_count_modifier()),
+    ];
+    let headlines: Vec<&str> = if show_events {
+        narrative
+            .events
+            .iter()
+            .rev()
+            .filter(|e| e.related_neighborhood_id == Some(neighborhood.id))
+            .take(2)
+            .map(|e| e.headline.as_str())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let row_h = 16.0;
+    let width = 240.0;
+    let events_h = if headlines.is_empty() {
+        0.0
+    } else {
+        row_h * (headlines.len() as f32 + 1.0) + theme::space::SM
+    };
+    let height = row_h * (rows.len() as f32 + 2.0) + events_h + theme::space::MD;
+
+    // Follow the cursor, flipping left/up near the screen edges.
+    let (mx, my) = mouse_position();
+    let x = if mx + 16.0 + width > screen_width() {
+        mx - width - 8.0
+    } else {
+        mx + 16.0
+    };
+    let y = (my + 16.0).min(screen_height() - height - 8.0);
+
+    let style = SurfaceStyle::new(colors::SURFACE()).with_border(1.0, colors::BORDER_STRONG());
+    draw_surface(Rect::new(x, y, width, height), &style);
+
+    let pad = theme::space::SM;
+    let mut row_y = y + pad + row_h - 4.0;
+    draw_ui_text_ex(
+        "Reputation Breakdown",
+        x + pad,
+        row_y,
+        text_params(scale::LABEL as u16, colors::TEXT_BRIGHT()),
+    );
+    for (label, value) in rows {
+        row_y += row_h;
+        draw_ui_text_ex(
+            label,
+            x + pad,
+            row_y,
+            text_params(scale::CAPTION as u16, colors::TEXT_DIM()),
+        );
+        let value_color = match value {
+            v if v > 0 => colors::POSITIVE(),
+            v if v < 0 => colors::NEGATIVE(),
+            _ => colors::TEXT(),
+        };
+        draw_ui_text_ex(
+            &format!("{:+}", value),
+            x + width - 44.0,
+            row_y,
+            text_params(scale::CAPTION as u16, value_color),
+        );
+    }
+    row_y += row_h;
+    draw_ui_text_ex(
+        &format!("Total: {}", neighborhood.effective_reputation()),
+        x + pad,
+        row_y,
+        text_params(scale::LABEL as u16, colors::TEXT()),
+    );
+    draw_ui_text_ex(
+        &format!("Schools: {}", stats.school_rating),
+        x + width - 80.0,
+        row_y,
+        text_params(scale::CAPTION as u16, colors::TEXT_DIM()),
+    );
+
+    if headlines.is_empty() {
+        return;
+    }
+    row_y += row_h + theme::space::SM;
+    draw_ui_text_ex(
+        "Recent Events",
+        x + pad,
+        row_y,
+        text_params(scale::LABEL as u16, colors::ACCENT()),
+    );
+    for headline in headlines {
+        row_y += row_h;
+        let line = macroquad_toolkit::ui::truncate_text_to_width(
+            headline,
+            width - pad * 2.0,
+            scale::CAPTION,
+        );
+        draw_ui_text_ex(
+            &line,
+            x + pad,
+            row_y,
+            text_params(scale::CAPTION as u16, colors::TEXT()),
+        );
+    }
+}
+
+/// Where the `slot`-th rival marker sits inside a neighborhood cell: a row
+/// just above the reputation bar.
+pub(super) fn rival_marker_rect(cell_x: f32, cell_y: f32, cell_height: f32, slot: usize) -> Rect {
+    Rect::new(
+        cell_x + 8.0 + slot as f32 * 84.0,
+        cell_y + cell_height - 58.0,
+        78.0,
+        20.0,
+    )
+}
+
+/// A rival building: red chip with a factory-roof glyph (player buildings
+/// never use red) and the name cut to 8 characters.
+pub(super) fn draw_rival_marker(rival: &RivalBuilding, rect: Rect) {
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, colors::NEGATIVE());
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, colors::BORDER_STRONG());
+
+    let base = rect.y + rect.h - 5.0;
+    let left = rect.x + 5.0;
+    draw_triangle(
+        vec2(left, base),
+        vec2(left + 10.0, base),
+        vec2(left, base - 10.0),
+        colors::TEXT_BRIGHT(),
+    );
+
+    let short: String = rival.name.chars().take(8).collect();
+    draw_ui_text_ex(
+        &short,
+        rect.x + 19.0,
+        base,
+        text_params(scale::CAPTION as u16, colors::TEXT_BRIGHT()),
+    );
+}
+
+/// Hover tooltip comparing a rival building with the player's active one.
+pub(super) fn draw_rival_tooltip(rival: &RivalBuilding, player_building: Option<&Building>) {
+    let (player_appeal, player_rent) = match player_building {
+        Some(building) if !building.apartments.is_empty() => (
+            Some(building.building_appeal()),
+            Some(
+                building
+                    .apartments
+                    .iter()
+                    .map(|a| a.rent_price)
+                    .sum::<i32>()
+                    / building.apartments.len() as i32,
+            ),
+        ),
+        _ => (None, None),
+    };
+
+    let width = 230.0;
+    let height = 84.0;
+    let (mx, my) = mouse_position();
+    let x = if mx + 16.0 + width > screen_width() {
+        mx - width - 8.0
+    } else {
+        mx + 16.0
+    };
+    let y = (my + 16.0).min(screen_height() - height - 8.0);
+
+    let style = SurfaceStyle::new(colors::SURFACE()).with_border(1.0, colors::NEGATIVE());
+    draw_surface(Rect::new(x, y, width, height), &style);
+
+    let pad = theme::space::SM;
+    draw_ui_text_ex(
+        &rival.name,
+        x + pad,
+        y + 18.0,
+        text_params(scale::LABEL as u16, colors::TEXT_BRIGHT()),
+    );
+    draw_ui_text_ex(
+        &format!("Owned by {}", rival.owner),
+        x + pad,
+        y + 34.0,
+        text_params(scale::CAPTION as u16, colors::TEXT_DIM()),
+    );
+    let appeal_line = match player_appeal {
+        Some(yours) => format!("Appeal: {} (yours: {})", rival.appeal, yours),
+        None => format!("Appeal: {}", rival.appeal),
+    };
+    draw_ui_text_ex(
+        &appeal_line,
+        x + pad,
+        y + 52.0,
+        text_params(scale::CAPTION as u16, colors::TEXT()),
+    );
+    let rent_line = match player_rent {
+        Some(yours) => format!("Avg rent: ${} (yours: ${})", rival.average_rent, yours),
+        None => format!("Avg rent: ${}", rival.average_rent),
+    };
+    draw_ui_text_ex(
+        &rent_line,
+        x + pad,
+        y + 70.0,
+        text_params(scale::CAPTION as u16, colors::TEXT()),
+    );
+}
+
 pub(super) fn draw_progress_bar(
     x: f32,
     y: f32,
@@ -64,6 +286,105 @@ pub(super) fn draw_button_mini(label: &str, x: f32, y: f32, width: f32, height:
     )
 }
 
+/// Price caps the "Max price" filter cycles through (`None` = any price).
+const PRICE_CAPS: [Option<i32>; 4] = [None, Some(100_000), Some(250_000), Some(500_000)];
+
+/// Draw the market's filter/sort bar. Each button cycles one setting; the
+/// updated filter is returned when any of them is clicked.
+pub(super) fn draw_market_filter_bar(
+    x: f32,
+    y: f32,
+    filter: &MarketFilter,
+    shown: usize,
+    total: usize,
+) -> Option<MarketFilter> {
+    let btn_h = 26.0;
+    let btn_w = 150.0;
+    let gap = theme::space::SM;
+    let mut next = filter.clone();
+    let mut changed = false;
+
+    let type_label = match &filter.neighborhood_type {
+        Some(t) => format!("Area: {}", t.name()),
+        None => "Area: Any".to_string(),
+    };
+    if draw_filter_button(&type_label, x, y, btn_w, btn_h) {
+        next.neighborhood_type = next_neighborhood_type(&filter.neighborhood_type);
+        changed = true;
+    }
+
+    let price_label = match filter.max_price {
+        Some(max) => format!("Max: ${}k", max / 1000),
+        None => "Max: Any".to_string(),
+    };
+    if draw_filter_button(&price_label, x + (btn_w + gap), y, btn_w, btn_h) {
+        let index = PRICE_CAPS
+            .iter()
+            .position(|cap| *cap == filter.max_price)
+            .unwrap_or(0);
+        next.max_price = PRICE_CAPS[(index + 1) % PRICE_CAPS.len()];
+        changed = true;
+    }
+
+    let condition_label = match &filter.min_condition {
+        Some(c) => format!("Min: {}", c.name()),
+        None => "Min: Any".to_string(),
+    };
+    if draw_filter_button(&condition_label, x + (btn_w + gap) * 2.0, y, btn_w, btn_h) {
+        next.min_condition = next_min_condition(&filter.min_condition);
+        changed = true;
+    }
+
+    let sort_label = format!("Sort: {}", filter.sort.label());
+    if draw_filter_button(&sort_label, x + (btn_w + gap) * 3.0, y, btn_w, btn_h) {
+        next.sort = filter.sort.next();
+        changed = true;
+    }
+
+    draw_ui_text_ex(
+        &format!("Showing {} of {}", shown, total),
+        x + (btn_w + gap) * 4.0,
+        y + btn_h / 2.0 + scale::LABEL / 2.0 - 1.0,
+        text_params(scale::LABEL as u16, colors::TEXT_DIM()),
+    );
+
+    changed.then_some(next)
+}
+
+fn draw_filter_button(label: &str, x: f32, y: f32, width: f32, height: f32) -> bool {
+    let style = theme::button_style(Tone::Secondary);
+    macroquad_toolkit::ui::button_rect_enabled_styled_ex(
+        Rect::new(x, y, width, height),
+        label,
+        true,
+        &style,
+        macroquad_toolkit::ui::TextStyle::new(scale::CAPTION, style.text_color),
+        macroquad_toolkit::ui::ButtonTrigger::Press,
+    )
+}
+
+fn next_neighborhood_type(current: &Option<NeighborhoodType>) -> Option<NeighborhoodType> {
+    match current {
+        None => Some(NeighborhoodType::Downtown),
+        Some(NeighborhoodType::Downtown) => Some(NeighborhoodType::Suburbs),
+        Some(NeighborhoodType::Suburbs) => Some(NeighborhoodType::Industrial),
+        Some(NeighborhoodType::Industrial) => Some(NeighborhoodType::Historic),
+        Some(NeighborhoodType::Historic) => None,
+    }
+}
+
+fn next_min_condition(current: &Option<BuildingCondition>) -> Option<BuildingCondition> {
+    match current {
+        None => Some(BuildingCondition::Poor),
+        Some(BuildingCondition::Condemned) | Some(BuildingCondition::Poor) => {
+            Some(BuildingCondition::Fair)
+        }
+        Some(BuildingCondition::Fair) => Some(BuildingCondition::Good),
+        Some(BuildingCondition::Good) => Some(BuildingCondition::Excellent),
+        Some(BuildingCondition::Excellent) => None,
+    }
+}
+
 fn draw_listing_background(
     x: f32,
     y: f32,
@@ -181,10 +502,29 @@ fn draw_listing_purchase(
         text_params(scale::HEADING as u16, price_color(listing, player_funds)),
     );
 
+    draw_price_sparkline(listing, x + 130.0, y + height - 26.0);
+
     if can_afford && draw_button_mini("Buy", btn_x, btn_y, btn_width, 22.0) {
         return Some(CityMapAction::PurchaseBuilding(listing.id));
     }
 
+    // Mortgage options sit to the left of Buy, shown once the down payment
+    // is within reach.
+    let mut loan_x = btn_x;
+    for option in listing.available_financing.iter().filter(|option| {
+        matches!(
+            option,
+            FinancingOption::Mortgage { .. } | FinancingOption::FixedRateMortgage { .. }
+        )
+    }) {
+        loan_x -= btn_width + 6.0;
+        if player_funds >= option.upfront_cost(listing.asking_price)
+            && draw_button_mini(option.label(), loan_x, btn_y, btn_width, 22.0)
+        {
+            return Some(CityMapAction::FinanceBuilding(listing.id, option.clone()));
+        }
+    }
+
     if !can_afford {
         draw_ui_text_ex(
             "Can't afford",
@@ -197,6 +537,51 @@ fn draw_listing_purchase(
     None
 }
 
+/// Draw the last six asking prices as a sparkline, plus the change since the
+/// listing went up. Nothing is drawn until the price has moved at least once.
+fn draw_price_sparkline(listing: &PropertyListing, x: f32, y: f32) {
+    if listing.price_history.is_empty() {
+        return;
+    }
+    let mut points: Vec<i32> = listing.price_history.clone();
+    points.push(listing.asking_price);
+    let points = &points[points.len().saturating_sub(6)..];
+
+    let width = 60.0;
+    let height = 16.0;
+    let max = *points.iter().max().unwrap_or(&1) as f32;
+    let min = *points.iter().min().unwrap_or(&0) as f32;
+    let range = (max - min).max(1.0);
+    let step = width / (points.len() - 1).max(1) as f32;
+    let change = listing.percent_change_since_listed();
+    let line_color = if change <= 0.0 {
+        colors::POSITIVE()
+    } else {
+        colors::NEGATIVE()
+    };
+
+    for (i, pair) in points.windows(2).enumerate() {
+        let y0 = y + height - (pair[0] as f32 - min) / range * height;
+        let y1 = y + height - (pair[1] as f32 - min) / range * height;
+        draw_line(
+            x + i as f32 * step,
+            y0,
+            x + (i + 1) as f32 * step,
+            y1,
+            1.5,
+            line_color,
+        );
+    }
+
+    let arrow = if change <= 0.0 { "↓" } else { "↑" };
+    draw_ui_text_ex(
+        &format!("{} {:.0}% since listed", arrow, change.abs()),
+        x + width + 6.0,
+        y + height - 2.0,
+        text_params(scale::CAPTION as u16, line_color),
+    );
+}
+
 fn price_color(listing: &PropertyListing, player_funds: i32) -> Color {
     if player_funds >= listing.asking_price {
         colors::POSITIVE()
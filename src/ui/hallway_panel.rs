@@ -1,18 +1,59 @@
 use crate::assets::AssetManager;
-use crate::building::Building;
+use crate::building::{
+    green_certification_level, AutomationLevel, Building, GreenCertificationLevel, PropertyManager,
+};
+use crate::consequences::{RepairDeadline, SECURITY_CAMERAS_FLAG};
+use crate::data::config::{GameConfig, PropertyManagerConfig};
+use crate::economy::{OperatingCosts, SOLAR_PANELS_FLAG};
+use crate::tenant::Tenant;
 use macroquad::prelude::*;
 
+use super::theme::{scale, space};
 use super::{common::*, UiAction};
-use macroquad_toolkit::ui::draw_ui_text;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+/// Dollars each click of the storage locker rent buttons moves it.
+const STORAGE_RENT_STEP: i32 = 5;
+
+/// Compliance standing and management state shown in the hallway panel.
+pub struct HallwayPanelStatus<'a> {
+    pub fire_safety: i32,
+    pub energy_mandate_months_left: Option<i64>,
+    /// Affordable units set aside and required, while the mandate applies.
+    pub affordable_mandate: Option<(u32, u32)>,
+    /// Open repair orders, most urgent first.
+    pub repair_deadlines: Vec<&'a RepairDeadline>,
+    pub license_months_left: i64,
+    pub license_lapsed: bool,
+    /// Archetype the fair housing office expects to see housed next.
+    pub fair_housing_required: Option<&'a str>,
+    pub property_manager: Option<&'a PropertyManager>,
+    /// Inflation multiplier applied to quoted costs.
+    pub cost_index: f32,
+}
 
 pub fn draw_hallway_panel(
     building: &Building,
+    tenants: &[Tenant],
     money: i32,
     offset_x: f32,
     scroll_offset: f32,
     _assets: &AssetManager,
-    config: &crate::data::config::GameConfig,
+    config: &GameConfig,
+    current_tick: u32,
+    status: &HallwayPanelStatus,
 ) -> (Option<UiAction>, f32) {
+    let HallwayPanelStatus {
+        fire_safety,
+        energy_mandate_months_left,
+        affordable_mandate,
+        ref repair_deadlines,
+        license_months_left,
+        license_lapsed,
+        fair_housing_required,
+        property_manager,
+        cost_index,
+    } = *status;
     let mut action = None;
     let mut new_scroll = scroll_offset;
 
@@ -87,7 +128,273 @@ pub fn draw_hallway_panel(
             colors::ACCENT(),
         );
     }
-    y += 50.0;
+    y += 24.0;
+
+    // Inflation since the first month, applied to taxes, utilities,
+    // insurance and staff pay.
+    if y + 14.0 > content_top && y < content_bottom {
+        draw_ui_text(
+            &format!("Cost index: {:.2}x", cost_index),
+            content_x,
+            y,
+            14.0,
+            colors::TEXT_DIM(),
+        );
+    }
+    y += 22.0;
+
+    if y + 14.0 > content_top && y < content_bottom {
+        let fire_color = if fire_safety < config.regulations.fire_safety_pass_threshold {
+            colors::NEGATIVE()
+        } else {
+            colors::TEXT_DIM()
+        };
+        draw_ui_text(
+            &format!("Fire Safety: {}%", fire_safety),
+            content_x,
+            y,
+            14.0,
+            fire_color,
+        );
+    }
+    y += 22.0;
+
+    if let Some(months) = energy_mandate_months_left {
+        if y + 14.0 > content_top && y < content_bottom {
+            let upgrade = config.regulations.energy_mandate_upgrade.to_uppercase();
+            let (text, color) = if months < 0 {
+                (
+                    format!(
+                        "{} mandate overdue: ${}/month fine",
+                        upgrade, config.regulations.energy_mandate_monthly_fine
+                    ),
+                    colors::NEGATIVE(),
+                )
+            } else {
+                (
+                    format!("{} required by city: {} months left", upgrade, months),
+                    colors::WARNING(),
+                )
+            };
+            draw_ui_text(&text, content_x, y, 14.0, color);
+        }
+        y += 22.0;
+    }
+
+    if let Some((affordable, required)) = affordable_mandate {
+        if y + 14.0 > content_top && y < content_bottom {
+            let color = if affordable >= required {
+                colors::POSITIVE()
+            } else {
+                colors::NEGATIVE()
+            };
+            draw_ui_text(
+                &format!("Affordable: {}/{} Required", affordable, required),
+                content_x,
+                y,
+                14.0,
+                color,
+            );
+        }
+        y += 22.0;
+    }
+
+    if y + 14.0 > content_top && y < content_bottom {
+        let (text, color) = if license_lapsed {
+            (
+                "Landlord license lapsed: no new tenants".to_string(),
+                colors::NEGATIVE(),
+            )
+        } else {
+            let color = if license_months_left <= 2 {
+                colors::WARNING()
+            } else {
+                colors::TEXT_DIM()
+            };
+            (
+                format!("License renewal in {} months", license_months_left),
+                color,
+            )
+        };
+        draw_ui_text(&text, content_x, y, 14.0, color);
+    }
+    y += 22.0;
+
+    if y + 14.0 > content_top && y < content_bottom {
+        let (text, color) = match fair_housing_required {
+            Some(archetype) => (
+                format!(
+                    "Fair Housing: under investigation, accept the next qualified {}",
+                    archetype.to_lowercase()
+                ),
+                colors::NEGATIVE(),
+            ),
+            None => (
+                "Fair Housing: in good standing".to_string(),
+                colors::POSITIVE(),
+            ),
+        };
+        draw_ui_text(&text, content_x, y, 14.0, color);
+    }
+    y += 22.0;
+
+    // Repair orders, most urgent first
+    for deadline in repair_deadlines {
+        if y + 14.0 > content_top && y < content_bottom {
+            let months = deadline.months_left(current_tick);
+            let (text, color) = if months < 0 {
+                (
+                    format!(
+                        "Unit {} repair overdue: ${} fined so far",
+                        deadline.unit_number, deadline.total_fines
+                    ),
+                    colors::NEGATIVE(),
+                )
+            } else {
+                (
+                    format!(
+                        "Unit {} must be repaired: {} months left",
+                        deadline.unit_number, months
+                    ),
+                    colors::WARNING(),
+                )
+            };
+            draw_ui_text(&text, content_x, y, 14.0, color);
+        }
+        y += 22.0;
+    }
+
+    if y + 14.0 > content_top && y < content_bottom {
+        draw_ui_text(
+            &format!(
+                "Built {} ({} years old) · {} exterior",
+                building.construction_year,
+                building.age(current_tick),
+                building.exterior_theme.name()
+            ),
+            content_x,
+            y,
+            14.0,
+            colors::TEXT_DIM(),
+        );
+    }
+    y += 26.0;
+
+    if building.flags.contains("hvac") {
+        let chip_h = 20.0;
+        if y + chip_h > content_top && y < content_bottom {
+            crate::ui::widgets::draw_badge(
+                content_x,
+                y,
+                chip_h,
+                "Climate Controlled",
+                colors::PRIMARY(),
+                colors::TEXT_BRIGHT(),
+            );
+        }
+        y += chip_h + 14.0;
+    }
+
+    if building.flags.contains(SECURITY_CAMERAS_FLAG) {
+        let chip_h = 20.0;
+        if y + chip_h > content_top && y < content_bottom {
+            crate::ui::widgets::draw_badge(
+                content_x,
+                y,
+                chip_h,
+                "🎥 Monitored",
+                colors::SURFACE_ALT(),
+                colors::TEXT_BRIGHT(),
+            );
+        }
+        y += chip_h + 14.0;
+    }
+
+    let certification = green_certification_level(building);
+    if certification != GreenCertificationLevel::None {
+        let chip_h = 20.0;
+        if y + chip_h > content_top && y < content_bottom {
+            crate::ui::widgets::draw_green_certification_badge(content_x, y, chip_h, certification);
+        }
+        y += chip_h + 14.0;
+    }
+
+    if let Some(line) = solar_summary(building, config) {
+        if y + 14.0 > content_top && y < content_bottom {
+            draw_ui_text(&line, content_x, y, 14.0, colors::TEXT_DIM());
+        }
+        y += 26.0;
+    }
+
+    if !building.amenities.is_empty() {
+        if y + 14.0 > content_top && y < content_bottom {
+            draw_ui_text("AMENITIES", content_x, y, 14.0, colors::TEXT_DIM());
+        }
+        y += 12.0;
+        // One chip per amenity, tinted by its condition.
+        let chip_h = 20.0;
+        let mut chip_x = content_x;
+        for amenity in &building.amenities {
+            let label = format!("{} {}%", amenity.amenity_type.name(), amenity.condition);
+            let chip_w =
+                measure_ui_text(&label, None, scale::LABEL as u16, 1.0).width + space::MD * 2.0;
+            if chip_x + chip_w > content_x + content_w {
+                chip_x = content_x;
+                y += chip_h + 6.0;
+            }
+            if y + chip_h > content_top && y < content_bottom {
+                crate::ui::widgets::draw_badge(
+                    chip_x,
+                    y,
+                    chip_h,
+                    &label,
+                    condition_color(amenity.condition),
+                    colors::TEXT_BRIGHT(),
+                );
+            }
+            chip_x += chip_w + 6.0;
+        }
+        y += chip_h + 30.0;
+    }
+
+    if building.has_storage_lockers() {
+        if let Some(act) = draw_storage_lockers(
+            building,
+            config.economy.storage_locker_income,
+            content_x,
+            &mut y,
+            content_top,
+            content_bottom,
+        ) {
+            action = Some(act);
+        }
+    }
+
+    if !building.parking.is_empty() {
+        if let Some(act) = draw_parking(
+            building,
+            tenants,
+            content_x,
+            &mut y,
+            content_w,
+            content_top,
+            content_bottom,
+        ) {
+            action = Some(act);
+        }
+    }
+
+    if let Some(act) = draw_property_manager(
+        property_manager,
+        &config.property_manager,
+        content_x,
+        &mut y,
+        content_w,
+        content_top,
+        content_bottom,
+    ) {
+        action = Some(act);
+    }
 
     if y + 14.0 > content_top && y < content_bottom {
         draw_ui_text("STAFF", content_x, y, 14.0, colors::TEXT_DIM());
@@ -234,3 +541,237 @@ pub fn draw_hallway_panel(
 
     (action, new_scroll)
 }
+
+/// Spot occupancy, monthly income, and an assign/revoke button per spot.
+fn draw_parking(
+    building: &Building,
+    tenants: &[Tenant],
+    content_x: f32,
+    y: &mut f32,
+    content_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let mut action = None;
+    let rented = building.parking.iter().filter(|s| !s.is_vacant()).count();
+
+    if *y + 14.0 > content_top && *y < content_bottom {
+        draw_ui_text("PARKING", content_x, *y, 14.0, colors::TEXT_DIM());
+    }
+    *y += 22.0;
+    if *y + 16.0 > content_top && *y < content_bottom {
+        draw_ui_text(
+            &format!(
+                "{}/{} spots rented · ${}/mo",
+                rented,
+                building.parking.len(),
+                building.parking_income()
+            ),
+            content_x,
+            *y,
+            16.0,
+            colors::TEXT(),
+        );
+    }
+    *y += 12.0;
+
+    // Next tenant to offer a vacant spot to: those who want parking first.
+    let candidate = tenants
+        .iter()
+        .filter(|t| {
+            building
+                .apartments
+                .iter()
+                .any(|apt| apt.tenant_id == Some(t.id))
+                && building.parking_spot_of(t.id).is_none()
+        })
+        .max_by_key(|t| t.archetype.preferences().wants_parking);
+
+    let btn_w = 150.0;
+    for spot in &building.parking {
+        let holder = spot
+            .assigned_tenant_id
+            .and_then(|id| tenants.iter().find(|t| t.id == id));
+        if *y + 30.0 > content_top && *y < content_bottom {
+            let label = match holder {
+                Some(tenant) => format!("{}  {}", spot.spot_number, tenant.name),
+                None => format!("{}  Vacant (${}/mo)", spot.spot_number, spot.monthly_fee),
+            };
+            draw_ui_text(&label, content_x, *y + 20.0, 14.0, colors::TEXT());
+
+            let btn_x = content_x + content_w - btn_w;
+            match (holder, candidate) {
+                (Some(_), _) => {
+                    if button(btn_x, *y, btn_w, 30.0, "Revoke", true) {
+                        action = Some(UiAction::RevokeParking { spot_id: spot.id });
+                    }
+                }
+                (None, Some(tenant)) => {
+                    if button(
+                        btn_x,
+                        *y,
+                        btn_w,
+                        30.0,
+                        &format!("Assign {}", tenant.name),
+                        true,
+                    ) {
+                        action = Some(UiAction::AssignParking {
+                            spot_id: spot.id,
+                            tenant_id: tenant.id,
+                        });
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+        *y += 36.0;
+    }
+    *y += 20.0;
+    action
+}
+
+/// The hired manager, if any, with a button to hire or switch to each
+/// automation level and one to let them go.
+fn draw_property_manager(
+    manager: Option<&PropertyManager>,
+    config: &PropertyManagerConfig,
+    content_x: f32,
+    y: &mut f32,
+    content_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let mut action = None;
+
+    if *y + 14.0 > content_top && *y < content_bottom {
+        draw_ui_text("PROPERTY MANAGER", content_x, *y, 14.0, colors::TEXT_DIM());
+    }
+    *y += 25.0;
+    if *y + 16.0 > content_top && *y < content_bottom {
+        let status = match manager {
+            Some(manager) => format!(
+                "{} · {} · ${}/mo",
+                manager.name,
+                manager.automation_level.name(),
+                manager.monthly_salary
+            ),
+            None => "None hired".to_string(),
+        };
+        draw_ui_text(&status, content_x, *y, 16.0, colors::TEXT());
+    }
+    *y += 15.0;
+
+    let current = manager.map(|m| m.automation_level);
+    for level in AutomationLevel::ALL {
+        if current == Some(level) {
+            continue;
+        }
+        let verb = if current.is_some() {
+            "Switch to"
+        } else {
+            "Hire"
+        };
+        let duties = match level {
+            AutomationLevel::Basic => "repairs",
+            AutomationLevel::Advanced => "repairs, leasing",
+            AutomationLevel::Full => "repairs, leasing, rents",
+        };
+        let label = format!(
+            "{} {} manager: {} (${}/mo)",
+            verb,
+            level.name(),
+            duties,
+            level.salary(config)
+        );
+        if *y + 30.0 > content_top
+            && *y < content_bottom
+            && button(content_x, *y, content_w, 30.0, &label, true)
+        {
+            action = Some(UiAction::HirePropertyManager { level });
+        }
+        *y += 36.0;
+    }
+    if manager.is_some() {
+        if *y + 30.0 > content_top
+            && *y < content_bottom
+            && button(content_x, *y, content_w, 30.0, "Dismiss manager", true)
+        {
+            action = Some(UiAction::DismissPropertyManager);
+        }
+        *y += 36.0;
+    }
+    *y += 20.0;
+    action
+}
+
+/// Locker rent with -/+ controls and the monthly takings it brings in.
+fn draw_storage_lockers(
+    building: &Building,
+    standard_rent: i32,
+    content_x: f32,
+    y: &mut f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let mut action = None;
+    let rent = building.storage_locker_rent(standard_rent);
+
+    if *y + 14.0 > content_top && *y < content_bottom {
+        draw_ui_text("STORAGE LOCKERS", content_x, *y, 14.0, colors::TEXT_DIM());
+    }
+    *y += 26.0;
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text(
+            &format!(
+                "${}/locker · ${}/mo",
+                rent,
+                building.storage_income(standard_rent)
+            ),
+            content_x,
+            *y,
+            16.0,
+            colors::TEXT(),
+        );
+        let btn_size = 25.0;
+        if button(
+            content_x + 190.0,
+            *y - 18.0,
+            btn_size,
+            btn_size,
+            "-",
+            rent > 0,
+        ) {
+            action = Some(UiAction::SetStorageLockerRent(rent - STORAGE_RENT_STEP));
+        }
+        if button(content_x + 220.0, *y - 18.0, btn_size, btn_size, "+", true) {
+            action = Some(UiAction::SetStorageLockerRent(rent + STORAGE_RENT_STEP));
+        }
+    }
+    *y += 20.0;
+    if *y + 14.0 > content_top && *y < content_bottom {
+        draw_ui_text(
+            "Higher rent earns more but annoys families and seniors",
+            content_x,
+            *y,
+            14.0,
+            colors::TEXT_DIM(),
+        );
+    }
+    *y += 30.0;
+    action
+}
+
+/// Solar panel savings once installed, or the payback period if not.
+fn solar_summary(building: &Building, config: &GameConfig) -> Option<String> {
+    let utilities = OperatingCosts::calculate_utilities(building, &config.operating_costs);
+    let savings = OperatingCosts::potential_solar_savings(building, utilities, &config.economy);
+    if building.flags.contains(SOLAR_PANELS_FLAG) {
+        return Some(format!("Solar panels save ${}/mo", savings));
+    }
+    let cost = config.upgrades.get("solar_panels")?.cost;
+    if savings <= 0 {
+        return Some("Solar panels: no savings while utilities aren't included".to_string());
+    }
+    let months = (cost + savings - 1) / savings;
+    Some(format!("Solar panels: payback in {} months", months))
+}
@@ -1,12 +1,31 @@
 use super::theme::{color, scale, space, Tone};
-use super::widgets::{button_at, button_width};
+use super::widgets::{button_at, button_width, draw_badge};
 use super::{common::*, UiAction};
 use crate::assets::AssetManager;
+use crate::data::config::DifficultyPreset;
+use crate::simulation::Season;
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::{
     draw_surface, draw_ui_text, measure_ui_text, truncate_text_to_width, SurfaceStyle,
 };
 
+/// Everything the header reports about the game and the active building.
+pub struct HeaderStatus<'a> {
+    pub money: i32,
+    pub tick: u32,
+    pub building_name: &'a str,
+    pub occupancy: usize,
+    pub total_units: usize,
+    pub difficulty: DifficultyPreset,
+    pub power_out: bool,
+    pub union_active: bool,
+    pub rent_freeze_months: Option<u32>,
+    pub high_utility_rates: bool,
+    pub unread_notifications: usize,
+    /// `Some` in sandbox mode, holding whether infinite funds are on.
+    pub sandbox_infinite_funds: Option<bool>,
+}
+
 /// Draw a stat chip (optional icon + label) at `x`, vertically centered in the
 /// header. Returns the chip width so callers can flow chips without overlap.
 fn stat_chip(
@@ -54,14 +73,21 @@ fn stat_chip(
     w
 }
 
-pub fn draw_header(
-    money: i32,
-    tick: u32,
-    building_name: &str,
-    occupancy: usize,
-    total_units: usize,
-    assets: &AssetManager,
-) -> Option<UiAction> {
+pub fn draw_header(status: &HeaderStatus, assets: &AssetManager) -> Option<UiAction> {
+    let HeaderStatus {
+        money,
+        tick,
+        building_name,
+        occupancy,
+        total_units,
+        difficulty,
+        power_out,
+        union_active,
+        rent_freeze_months,
+        high_utility_rates,
+        unread_notifications,
+        sandbox_infinite_funds,
+    } = *status;
     let mut action = None;
     let w = screen_width();
     let h = layout::HEADER_HEIGHT();
@@ -87,6 +113,56 @@ pub fn draw_header(
     let hint = "Space";
     let hint_w = measure_ui_text(hint, None, scale::CAPTION as u16, 1.0).width;
     let hint_x = btn_x - hint_w - space::MD;
+
+    // Trophies and missions buttons left of the hint.
+    let trophies_w = button_width("Trophies", btn_h);
+    let trophies_x = hint_x - trophies_w - space::MD;
+    if button_at(
+        Rect::new(trophies_x, btn_y, trophies_w, btn_h),
+        "Trophies",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::OpenAchievements);
+    }
+    let missions_w = button_width("Active Missions", btn_h);
+    let missions_x = trophies_x - missions_w - space::SM;
+    if button_at(
+        Rect::new(missions_x, btn_y, missions_w, btn_h),
+        "Active Missions",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::SelectMissions);
+    }
+    // Notification history bell, badged with the unread count.
+    let bell_w = btn_h;
+    let bell_x = missions_x - bell_w - space::SM;
+    if button_at(
+        Rect::new(bell_x, btn_y, bell_w, btn_h),
+        "🔔",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::OpenNotificationHistory);
+    }
+    if unread_notifications > 0 {
+        let badge = if unread_notifications > 9 {
+            "9+".to_string()
+        } else {
+            unread_notifications.to_string()
+        };
+        let (bx, by, r) = (bell_x + bell_w - 4.0, btn_y + 4.0, 8.0);
+        draw_circle(bx, by, r, color::NEGATIVE());
+        let tw = measure_ui_text(&badge, None, scale::CAPTION as u16, 1.0).width;
+        draw_ui_text(
+            &badge,
+            bx - tw / 2.0,
+            by + scale::CAPTION / 3.0,
+            scale::CAPTION,
+            color::TEXT_BRIGHT(),
+        );
+    }
     draw_ui_text(
         hint,
         hint_x,
@@ -95,8 +171,8 @@ pub fn draw_header(
         color::TEXT_DIM(),
     );
 
-    // Stat cluster: money / month / occupancy chips, flowed right-to-left so
-    // they hug the button and never collide with the building name.
+    // Stat cluster: difficulty / money / month / occupancy chips, flowed right-to-left so
+    // they hug the buttons and never collide with the building name.
     let money_color = if money < 0 {
         color::NEGATIVE()
     } else if money < 500 {
@@ -105,12 +181,20 @@ pub fn draw_header(
         color::POSITIVE()
     };
     let money_label = macroquad_toolkit::ui::format_money(money as i64);
-    let month_label = format!("Month {}", tick);
+    let season = Season::from_tick(tick);
+    let month_label = format!("Month {} · {}", tick, season.name());
     let occ_label = format!("{}/{}", occupancy, total_units);
+    let difficulty_color = match difficulty {
+        DifficultyPreset::Easy => color::POSITIVE(),
+        DifficultyPreset::Normal => color::TEXT_DIM(),
+        DifficultyPreset::Hard => color::WARNING(),
+        DifficultyPreset::Ironman => color::NEGATIVE(),
+    };
 
     // Measure chip widths (mirror stat_chip's math) to place them.
     let chip_gap = space::SM;
-    let chips: [(Option<&Texture2D>, &str, Color); 3] = [
+    let chips: [(Option<&Texture2D>, &str, Color); 4] = [
+        (None, difficulty.name(), difficulty_color),
         (assets.get_texture("icon_money"), &money_label, money_color),
         (
             assets.get_texture("icon_calendar"),
@@ -132,9 +216,90 @@ pub fn draw_header(
         })
         .collect();
     let cluster_w: f32 = widths.iter().sum::<f32>() + chip_gap * (chips.len() as f32 - 1.0);
-    let cluster_right = hint_x - space::MD;
+    // Sandbox games get a debug toggle for infinite funds.
+    let mut controls_left = bell_x;
+    if let Some(infinite) = sandbox_infinite_funds {
+        let label = if infinite { "∞ $: On" } else { "∞ $: Off" };
+        let funds_w = button_width(label, btn_h);
+        controls_left -= funds_w + space::SM;
+        if button_at(
+            Rect::new(controls_left, btn_y, funds_w, btn_h),
+            label,
+            true,
+            Tone::Secondary,
+        ) {
+            action = Some(UiAction::ToggleSandboxFunds);
+        }
+    }
+    let cluster_right = controls_left - space::MD;
     let mut cx = (cluster_right - cluster_w).max(0.0);
-    let cluster_left = cx;
+    // Season glyph: a filled dot in the season's color just left of the cluster.
+    let glyph_r = 6.0;
+    draw_circle(cx - space::SM - glyph_r, h / 2.0, glyph_r, season.color());
+    let mut cluster_left = cx - space::SM - glyph_r * 2.0;
+    if power_out {
+        cluster_left -= space::SM + draw_power_cut_icon(cluster_left - space::SM, h / 2.0);
+    }
+    if sandbox_infinite_funds.is_some() {
+        let label = "SANDBOX";
+        let badge_h = 24.0;
+        let badge_w =
+            measure_ui_text(label, None, scale::LABEL as u16, 1.0).width + space::MD * 2.0;
+        cluster_left -= space::SM + badge_w;
+        draw_badge(
+            cluster_left,
+            (h - badge_h) / 2.0,
+            badge_h,
+            label,
+            color::PRIMARY(),
+            color::TEXT_BRIGHT(),
+        );
+    }
+    if union_active {
+        let label = "Union Active";
+        let badge_h = 24.0;
+        let badge_w =
+            measure_ui_text(label, None, scale::LABEL as u16, 1.0).width + space::MD * 2.0;
+        cluster_left -= space::SM + badge_w;
+        draw_badge(
+            cluster_left,
+            (h - badge_h) / 2.0,
+            badge_h,
+            label,
+            color::WARNING(),
+            color::TEXT_BRIGHT(),
+        );
+    }
+    if let Some(months) = rent_freeze_months {
+        let label = format!("Rent Freeze: {} mo", months);
+        let badge_h = 24.0;
+        let badge_w =
+            measure_ui_text(&label, None, scale::LABEL as u16, 1.0).width + space::MD * 2.0;
+        cluster_left -= space::SM + badge_w;
+        draw_badge(
+            cluster_left,
+            (h - badge_h) / 2.0,
+            badge_h,
+            &label,
+            color::ACCENT(),
+            color::TEXT_BRIGHT(),
+        );
+    }
+    if high_utility_rates {
+        let label = "⚡ High Utility Rates";
+        let badge_h = 24.0;
+        let badge_w =
+            measure_ui_text(label, None, scale::LABEL as u16, 1.0).width + space::MD * 2.0;
+        cluster_left -= space::SM + badge_w;
+        draw_badge(
+            cluster_left,
+            (h - badge_h) / 2.0,
+            badge_h,
+            label,
+            color::WARNING(),
+            color::TEXT_BRIGHT(),
+        );
+    }
     for (i, (icon, label, text_color)) in chips.iter().enumerate() {
         stat_chip(cx, *icon, label, *text_color, h);
         cx += widths[i] + chip_gap;
@@ -154,3 +319,24 @@ pub fn draw_header(
 
     action
 }
+
+/// Lightning bolt struck through in red, right-aligned to `right`. Returns
+/// its width.
+fn draw_power_cut_icon(right: f32, center_y: f32) -> f32 {
+    let (w, h) = (12.0, 18.0);
+    let (x, y) = (right - w, center_y - h / 2.0);
+    draw_triangle(
+        vec2(x + w * 0.7, y),
+        vec2(x, y + h * 0.6),
+        vec2(x + w * 0.5, y + h * 0.5),
+        color::WARNING(),
+    );
+    draw_triangle(
+        vec2(x + w * 0.3, y + h),
+        vec2(x + w, y + h * 0.4),
+        vec2(x + w * 0.5, y + h * 0.5),
+        color::WARNING(),
+    );
+    draw_line(x - 2.0, y + h, x + w + 2.0, y, 2.0, color::NEGATIVE());
+    w
+}
@@ -0,0 +1,83 @@
+//! Corner mini-map drawn over the building view: a simplified city grid that
+//! jumps to the full city map when clicked.
+
+use super::layout::FOOTER_HEIGHT;
+use super::theme::{color, scale, space, Tone};
+use super::widgets::button_at;
+use super::UiAction;
+use crate::city::City;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_surface, draw_ui_text, SurfaceStyle};
+
+const MINIMAP_WIDTH: f32 = 200.0;
+const MINIMAP_HEIGHT: f32 = 150.0;
+const TOGGLE_HEIGHT: f32 = 20.0;
+
+/// Draw the mini-map in the bottom-left corner, plus the small toggle button
+/// above it. When `visible` is false only the toggle is drawn.
+pub fn draw_minimap(city: &City, visible: bool) -> Option<UiAction> {
+    let x = space::PAD;
+    let y = screen_height() - FOOTER_HEIGHT() - MINIMAP_HEIGHT - space::PAD;
+
+    let toggle_label = if visible { "Hide Map" } else { "Show Map" };
+    let toggle = Rect::new(x, y - TOGGLE_HEIGHT - space::XS, 80.0, TOGGLE_HEIGHT);
+    if button_at(toggle, toggle_label, true, Tone::Secondary) {
+        return Some(UiAction::ToggleMinimap);
+    }
+
+    if !visible {
+        return None;
+    }
+
+    let rect = Rect::new(x, y, MINIMAP_WIDTH, MINIMAP_HEIGHT);
+    let style = SurfaceStyle::new(color::SURFACE()).with_border(1.0, color::BORDER_STRONG());
+    draw_surface(rect, &style);
+
+    // Same 2x2 neighborhood layout as the full city map.
+    let gap = space::XS;
+    let inner = Rect::new(
+        rect.x + gap,
+        rect.y + gap,
+        rect.w - gap * 2.0,
+        rect.h - gap * 2.0,
+    );
+    let cell_w = (inner.w - gap) / 2.0;
+    let cell_h = (inner.h - gap) / 2.0;
+
+    for (i, neighborhood) in city.neighborhoods.iter().enumerate() {
+        let cx = inner.x + (i % 2) as f32 * (cell_w + gap);
+        let cy = inner.y + (i / 2) as f32 * (cell_h + gap);
+        let base = neighborhood.neighborhood_type.color();
+        draw_rectangle(cx, cy, cell_w, cell_h, base.with_alpha(0.55));
+        draw_ui_text(
+            neighborhood.neighborhood_type.name(),
+            cx + space::XS,
+            cy + scale::CAPTION + 2.0,
+            scale::CAPTION,
+            color::TEXT_BRIGHT(),
+        );
+
+        // One dot per owned building, laid out along the bottom of the cell.
+        for (slot, &building_id) in neighborhood.building_ids.iter().enumerate() {
+            let dot_x = cx + 12.0 + slot as f32 * 14.0;
+            let dot_y = cy + cell_h - 12.0;
+            draw_circle(dot_x, dot_y, 4.0, color::TEXT_BRIGHT());
+
+            if building_id as usize == city.active_building_index {
+                let pulse = (get_time() * 4.0).sin() as f32 * 0.5 + 0.5;
+                draw_circle_lines(dot_x, dot_y, 6.0 + pulse * 3.0, 2.0, color::ACCENT());
+            }
+        }
+    }
+
+    let mouse = mouse_position();
+    let hovered = rect.contains(vec2(mouse.0, mouse.1));
+    if hovered {
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, color::PRIMARY());
+        if is_mouse_button_pressed(MouseButton::Left) {
+            return Some(UiAction::OpenCityMap);
+        }
+    }
+
+    None
+}
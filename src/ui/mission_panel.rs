@@ -0,0 +1,232 @@
+//! Mission log: active missions with a deadline countdown, plus the ones that
+//! ran out of time. Grant missions list each requirement with a check or cross.
+
+use super::theme::{color, scale, space, Tone};
+use super::widgets::{button_at, draw_card, draw_panel, wrap};
+use super::UiAction;
+use crate::building::Building;
+use crate::narrative::missions::Mission;
+use crate::narrative::{
+    GrantRequirement, MissionGoal, MissionManager, MissionReward, MissionStatus,
+};
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, measure_ui_text};
+
+const CARD_GAP: f32 = 10.0;
+
+/// Green with plenty of time, yellow for 3-6 months, red under 3.
+fn countdown_color(months_remaining: i64) -> Color {
+    if months_remaining > 6 {
+        color::POSITIVE()
+    } else if months_remaining >= 3 {
+        color::WARNING()
+    } else {
+        color::NEGATIVE()
+    }
+}
+
+fn reward_label(reward: &MissionReward) -> String {
+    match reward {
+        MissionReward::Money(amount) => format!("${}", amount),
+        MissionReward::TaxBreak { months, percentage } => format!(
+            "{}% tax break for {} months",
+            (percentage * 100.0) as i32,
+            months
+        ),
+        MissionReward::Reputation(amount) => format!("+{} reputation", amount),
+        MissionReward::UnlockBuilding(_) => "a new property".to_string(),
+    }
+}
+
+fn grant_requirements(mission: &Mission) -> &[GrantRequirement] {
+    match &mission.goal {
+        MissionGoal::ApplyForGrant { requirements, .. } => requirements,
+        _ => &[],
+    }
+}
+
+pub fn draw_mission_panel(
+    missions: &MissionManager,
+    building: &Building,
+    current_tick: u32,
+    affordable_threshold: i32,
+) -> Option<UiAction> {
+    let mut action = None;
+    let rect = Rect::new(
+        screen_width() * 0.5 + 10.0,
+        80.0,
+        screen_width() * 0.5 - 30.0,
+        screen_height() - 140.0,
+    );
+    let content = draw_panel(rect, "Active Missions");
+
+    let close_w = 80.0;
+    if button_at(
+        Rect::new(
+            rect.right() - close_w - space::PAD,
+            rect.y + 5.0,
+            close_w,
+            28.0,
+        ),
+        "Close",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::ClearSelection);
+    }
+
+    let active = missions.active_missions();
+    let failed = missions.failed_missions();
+    let mut y = content.y;
+
+    if active.is_empty() && failed.is_empty() {
+        draw_ui_text(
+            "No active missions. New requests arrive as the months go by.",
+            content.x,
+            y + scale::BODY,
+            scale::BODY,
+            color::TEXT_DIM(),
+        );
+        return action;
+    }
+
+    for mission in active.into_iter().chain(failed) {
+        if y > content.bottom() {
+            break;
+        }
+        let follow_up = mission
+            .follow_up_mission_id
+            .and_then(|id| missions.missions.iter().find(|m| m.id == id))
+            .map(|m| m.title.as_str());
+        y += draw_mission_card(
+            mission,
+            follow_up,
+            building,
+            current_tick,
+            affordable_threshold,
+            content.x,
+            y,
+            content.w,
+        ) + CARD_GAP;
+    }
+
+    action
+}
+
+/// Draw one mission card; returns its height.
+fn draw_mission_card(
+    mission: &Mission,
+    follow_up: Option<&str>,
+    building: &Building,
+    current_tick: u32,
+    affordable_threshold: i32,
+    x: f32,
+    y: f32,
+    w: f32,
+) -> f32 {
+    let inner_w = w - space::MD * 2.0;
+    let failed = mission.status != MissionStatus::Active;
+    let description = wrap(&mission.description, inner_w, scale::LABEL);
+    let line_h = scale::LABEL * 1.35;
+    let requirements = grant_requirements(mission);
+    let h = space::MD * 2.0
+        + scale::HEADING
+        + space::SM
+        + description.len() as f32 * line_h
+        + space::SM
+        + line_h
+        + requirements.len() as f32 * line_h
+        + if follow_up.is_some() { line_h } else { 0.0 };
+
+    draw_card(Rect::new(x, y, w, h), false);
+    if failed {
+        draw_rectangle_lines(x, y, w, h, 2.0, color::NEGATIVE());
+    }
+
+    let mut cy = y + space::MD + scale::HEADING;
+    draw_ui_text(
+        &mission.title,
+        x + space::MD,
+        cy,
+        scale::HEADING,
+        if failed {
+            color::NEGATIVE()
+        } else {
+            color::TEXT_BRIGHT()
+        },
+    );
+
+    // Countdown (or FAILED) right-aligned on the title line.
+    let (badge, badge_color) = if failed {
+        ("FAILED".to_string(), color::NEGATIVE())
+    } else {
+        match mission.months_remaining(current_tick) {
+            Some(months) => (
+                format!("{} months left", months.max(0)),
+                countdown_color(months),
+            ),
+            None => ("No deadline".to_string(), color::TEXT_DIM()),
+        }
+    };
+    let badge_w = measure_ui_text(&badge, None, scale::BODY as u16, 1.0).width;
+    draw_ui_text(
+        &badge,
+        x + w - space::MD - badge_w,
+        cy,
+        scale::BODY,
+        badge_color,
+    );
+
+    cy += space::SM;
+    for line in &description {
+        cy += line_h;
+        draw_ui_text(line, x + space::MD, cy, scale::LABEL, color::TEXT());
+    }
+
+    for requirement in requirements {
+        cy += line_h;
+        let (mark, mark_color) = if requirement.is_met(building, affordable_threshold) {
+            ("✓", color::POSITIVE())
+        } else {
+            ("✗", color::NEGATIVE())
+        };
+        draw_ui_text(
+            &format!("{} {}", mark, requirement.label()),
+            x + space::MD,
+            cy,
+            scale::LABEL,
+            mark_color,
+        );
+    }
+
+    cy += space::SM + line_h;
+    let footer = if failed {
+        format!("Penalty: forfeited {}", reward_label(&mission.reward))
+    } else {
+        format!("Reward: {}", reward_label(&mission.reward))
+    };
+    draw_ui_text(
+        &footer,
+        x + space::MD,
+        cy,
+        scale::LABEL,
+        if failed {
+            color::NEGATIVE()
+        } else {
+            color::TEXT_DIM()
+        },
+    );
+
+    if let Some(next) = follow_up {
+        cy += line_h;
+        draw_ui_text(
+            &format!("→ Unlocks: {}", next),
+            x + space::MD,
+            cy,
+            scale::LABEL,
+            color::ACCENT(),
+        );
+    }
+
+    h
+}
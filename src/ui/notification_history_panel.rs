@@ -0,0 +1,109 @@
+//! Full-screen log of dismissed notifications, newest first, so a toast
+//! clicked away in a hurry can still be read later.
+
+use super::theme::{color, scale, space, Tone};
+use super::widgets::button_at;
+use super::UiAction;
+use crate::narrative::notifications::GameNotification;
+use crate::narrative::NotificationCategory;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::{draw_ui_text, truncate_text_to_width};
+
+const ROW_H: f32 = 48.0;
+
+fn category_color(category: &NotificationCategory) -> Color {
+    match category {
+        NotificationCategory::Positive => color::POSITIVE(),
+        NotificationCategory::Warning => color::WARNING(),
+        NotificationCategory::Info => color::TEXT(),
+        NotificationCategory::Hint => color::ACCENT(),
+    }
+}
+
+/// Returns the clicked action (if any) and the updated scroll offset.
+pub fn draw_notification_history_panel(
+    history: &[GameNotification],
+    scroll: f32,
+) -> (Option<UiAction>, f32) {
+    let mut action = None;
+    let w = screen_width();
+    let h = screen_height();
+    draw_rectangle(0.0, 0.0, w, h, color::BACKGROUND());
+
+    draw_ui_text(
+        "Notification History",
+        space::XL,
+        space::XL + scale::TITLE,
+        scale::TITLE,
+        color::TEXT_BRIGHT(),
+    );
+
+    let back_w = 120.0;
+    if button_at(
+        Rect::new(w - back_w - space::XL, space::XL, back_w, 36.0),
+        "Back",
+        true,
+        Tone::Secondary,
+    ) {
+        action = Some(UiAction::CloseNotificationHistory);
+    }
+
+    let list_top = space::XL * 2.0 + scale::TITLE + space::MD;
+    let list_bottom = h - space::XL;
+    let list_w = w - space::XL * 2.0;
+
+    if history.is_empty() {
+        draw_ui_text(
+            "Nothing here yet.",
+            space::XL,
+            list_top + scale::BODY,
+            scale::BODY,
+            color::TEXT_DIM(),
+        );
+        return (action, 0.0);
+    }
+
+    let max_scroll = (history.len() as f32 * ROW_H - (list_bottom - list_top)).max(0.0);
+    let mut new_scroll = scroll;
+    let (_, my) = mouse_position();
+    if my >= list_top && my <= list_bottom {
+        new_scroll -= mouse_wheel().1 * 30.0;
+    }
+    new_scroll = new_scroll.clamp(0.0, max_scroll);
+
+    let mut y = list_top - new_scroll;
+    for notification in history.iter().rev() {
+        if y + ROW_H > list_top && y < list_bottom {
+            let tint = category_color(&notification.category);
+            draw_rectangle(space::XL, y + 4.0, 4.0, ROW_H - 8.0, tint);
+            draw_ui_text(
+                &format!("Month {}", notification.tick_received),
+                space::XL + space::MD,
+                y + 20.0,
+                scale::CAPTION,
+                color::TEXT_DIM(),
+            );
+            let line = format!("{} {}", notification.icon, notification.message);
+            let text_x = space::XL + space::MD + 90.0;
+            draw_ui_text(
+                &truncate_text_to_width(&line, list_w - (text_x - space::XL), scale::BODY),
+                text_x,
+                y + 20.0,
+                scale::BODY,
+                tint,
+            );
+            if let Some(desc) = &notification.description {
+                draw_ui_text(
+                    &truncate_text_to_width(desc, list_w - (text_x - space::XL), scale::CAPTION),
+                    text_x,
+                    y + 38.0,
+                    scale::CAPTION,
+                    color::TEXT_DIM(),
+                );
+            }
+        }
+        y += ROW_H;
+    }
+
+    (action, new_scroll)
+}
@@ -1,10 +1,14 @@
 use crate::building::ownership::OwnershipType;
-use crate::building::Building;
+use crate::building::{Apartment, Building};
 use crate::ui::{colors, UiAction};
 use macroquad::prelude::*;
 use macroquad_toolkit::ui::draw_ui_text_ex;
 
-pub fn draw_ownership_panel(building: &Building, market_multiplier: f32) -> Option<UiAction> {
+pub fn draw_ownership_panel(
+    building: &Building,
+    market_multiplier: f32,
+    relocation_assistance_months: u32,
+) -> Option<UiAction> {
     let panel_x = screen_width() * 0.5 + 10.0;
     let panel_y = 80.0;
     let panel_width = screen_width() * 0.5 - 30.0;
@@ -123,39 +127,18 @@ pub fn draw_ownership_panel(building: &Building, market_multiplier: f32) -> Opti
                     },
                 );
 
-                // Status
-                let status = if apt.is_vacant() {
-                    "Vacant"
-                } else {
-                    "Occupied"
-                };
-                draw_ui_text_ex(
-                    status,
-                    panel_x + 100.0,
-                    y + 20.0,
-                    TextParams {
-                        font_size: 14,
-                        color: if apt.is_vacant() {
-                            colors::POSITIVE()
-                        } else {
-                            colors::WARNING()
-                        },
-                        ..Default::default()
-                    },
-                );
-
                 // Sell Button - use calculated market value
                 let sale_price = (apt.market_value() as f32 * market_multiplier) as i32;
-
-                if crate::ui::widgets::button_at(
-                    Rect::new(panel_x + panel_width - 160.0, y + 4.0, 148.0, 24.0),
+                if let Some(act) = draw_sale_row(
+                    apt,
                     &format!("Sell Condo (${})", sale_price),
-                    true,
-                    crate::ui::theme::Tone::Positive,
+                    panel_x,
+                    panel_width,
+                    y,
+                    148.0,
+                    relocation_assistance_months,
                 ) {
-                    action = Some(UiAction::SellUnitAsCondo {
-                        apartment_id: apt.id,
-                    });
+                    action = Some(act);
                 }
 
                 y += 35.0;
@@ -239,39 +222,18 @@ pub fn draw_ownership_panel(building: &Building, market_multiplier: f32) -> Opti
                         },
                     );
 
-                    // Status
-                    let status = if apt.is_vacant() {
-                        "Vacant"
-                    } else {
-                        "Occupied"
-                    };
-                    draw_ui_text_ex(
-                        status,
-                        panel_x + 100.0,
-                        y + 20.0,
-                        TextParams {
-                            font_size: 14,
-                            color: if apt.is_vacant() {
-                                colors::POSITIVE()
-                            } else {
-                                colors::WARNING()
-                            },
-                            ..Default::default()
-                        },
-                    );
-
                     // Sell Button
                     let sale_price = (apt.market_value() as f32 * market_multiplier) as i32;
-
-                    if crate::ui::widgets::button_at(
-                        Rect::new(panel_x + panel_width - 140.0, y + 4.0, 128.0, 24.0),
+                    if let Some(act) = draw_sale_row(
+                        apt,
                         &format!("Sell (${})", sale_price),
-                        true,
-                        crate::ui::theme::Tone::Positive,
+                        panel_x,
+                        panel_width,
+                        y,
+                        128.0,
+                        relocation_assistance_months,
                     ) {
-                        action = Some(UiAction::SellUnitAsCondo {
-                            apartment_id: apt.id,
-                        });
+                        action = Some(act);
                     }
 
                     y += 35.0;
@@ -320,3 +282,64 @@ pub fn draw_ownership_panel(building: &Building, market_multiplier: f32) -> Opti
 
     action
 }
+
+/// Occupancy status and the sell button(s) for one unit row. Selling an
+/// occupied unit displaces its tenant: the main button pays their relocation
+/// assistance, and a second one sells without it.
+fn draw_sale_row(
+    apt: &Apartment,
+    sell_label: &str,
+    panel_x: f32,
+    panel_width: f32,
+    y: f32,
+    button_w: f32,
+    relocation_assistance_months: u32,
+) -> Option<UiAction> {
+    let relocation = apt.relocation_assistance(relocation_assistance_months);
+    let (status, status_color) = if apt.is_vacant() {
+        ("Vacant".to_string(), colors::POSITIVE())
+    } else {
+        (
+            format!("Occupied · relocation aid ${}", relocation),
+            colors::WARNING(),
+        )
+    };
+    draw_ui_text_ex(
+        &status,
+        panel_x + 100.0,
+        y + 20.0,
+        TextParams {
+            font_size: 14,
+            color: status_color,
+            ..Default::default()
+        },
+    );
+
+    let sell_x = panel_x + panel_width - button_w - 12.0;
+    let mut action = None;
+    if crate::ui::widgets::button_at(
+        Rect::new(sell_x, y + 4.0, button_w, 24.0),
+        sell_label,
+        true,
+        crate::ui::theme::Tone::Positive,
+    ) {
+        action = Some(UiAction::SellUnitAsCondo {
+            apartment_id: apt.id,
+            pay_relocation: true,
+        });
+    }
+    if relocation > 0
+        && crate::ui::widgets::button_at(
+            Rect::new(sell_x - 76.0, y + 4.0, 70.0, 24.0),
+            "No Aid",
+            true,
+            crate::ui::theme::Tone::Danger,
+        )
+    {
+        action = Some(UiAction::SellUnitAsCondo {
+            apartment_id: apt.id,
+            pay_relocation: false,
+        });
+    }
+    action
+}
@@ -0,0 +1,262 @@
+//! A tenant's pending request, and the conditions attached to ones already
+//! approved.
+
+use crate::narrative::{
+    RequestCondition, TenantRequest, TenantStory, COUNTER_OFFER_HOURS, PET_TRIAL_MONTHS,
+};
+use crate::tenant::Tenant;
+use macroquad::prelude::*;
+use std::collections::HashMap;
+
+use super::theme::scale;
+use super::{common::*, UiAction};
+use macroquad_toolkit::ui::{draw_ui_text, wrap_text_ex};
+
+pub(super) fn draw_pending_request(
+    tenant: &Tenant,
+    stories: &HashMap<u32, TenantStory>,
+    pet_deposit: i32,
+    content_x: f32,
+    y: &mut f32,
+    panel_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let story = stories.get(&tenant.id)?;
+    let request = story.pending_request.as_ref()?;
+    let w = panel_w - 30.0;
+
+    // Divider + section header.
+    if *y > content_top && *y < content_bottom {
+        draw_line(content_x, *y, content_x + w, *y, 1.0, colors::BORDER());
+    }
+    *y += 14.0;
+    if *y + 18.0 > content_top && *y < content_bottom {
+        crate::ui::widgets::section_label(content_x, *y, "PENDING REQUEST");
+    }
+    *y += 22.0;
+
+    // Wrapped request text.
+    let req_text = request_text(request);
+    for line in wrap_text_ex(&req_text, w, None, scale::BODY) {
+        if *y + scale::BODY > content_top && *y < content_bottom {
+            draw_ui_text(
+                &line,
+                content_x,
+                *y + scale::BODY,
+                scale::BODY,
+                colors::TEXT(),
+            );
+        }
+        *y += scale::BODY * 1.35;
+    }
+    *y += 4.0;
+
+    let effect_text = approval_effect_text(request);
+    if !effect_text.is_empty() {
+        if *y + 16.0 > content_top && *y < content_bottom {
+            draw_ui_text(
+                &format!("Effect: {}", effect_text),
+                content_x,
+                *y + scale::LABEL,
+                scale::LABEL,
+                colors::ACCENT(),
+            );
+        }
+        *y += 24.0;
+    }
+
+    *y += 6.0;
+    let btn_w = ((w - 10.0) / 2.0).min(140.0);
+    if crate::ui::widgets::button_at(
+        Rect::new(content_x, *y, btn_w, 32.0),
+        "Approve",
+        true,
+        crate::ui::theme::Tone::Positive,
+    ) {
+        return Some(UiAction::ApproveRequest {
+            tenant_id: tenant.id,
+        });
+    }
+    if crate::ui::widgets::button_at(
+        Rect::new(content_x + btn_w + 10.0, *y, btn_w, 32.0),
+        "Deny",
+        true,
+        crate::ui::theme::Tone::Danger,
+    ) {
+        return Some(UiAction::DenyRequest {
+            tenant_id: tenant.id,
+        });
+    }
+    *y += 38.0;
+
+    match request {
+        TenantRequest::Pet { .. } => draw_pet_conditions(tenant, pet_deposit, content_x, y, w),
+        TenantRequest::HomeBusiness { business_type, .. } => {
+            draw_counter_offers(tenant, business_type, content_x, y, w)
+        }
+        _ => None,
+    }
+}
+
+/// Counter offers limiting a home business's hours, each with the chance
+/// the tenant takes it.
+fn draw_counter_offers(
+    tenant: &Tenant,
+    business_type: &str,
+    content_x: f32,
+    y: &mut f32,
+    w: f32,
+) -> Option<UiAction> {
+    let count = COUNTER_OFFER_HOURS.len() as f32;
+    let btn_w = (w - 10.0 * (count - 1.0)) / count;
+    let mut action = None;
+    for (i, hours) in COUNTER_OFFER_HOURS.into_iter().enumerate() {
+        let counter = TenantRequest::HomeBusiness {
+            business_type: business_type.to_string(),
+            weekly_hours: Some(hours),
+        };
+        let chance = counter.counter_acceptance_chance(tenant.landlord_opinion);
+        let label = format!("Counter: {}h/week ({:.0}%)", hours, chance * 100.0);
+        if crate::ui::widgets::button_at(
+            Rect::new(content_x + i as f32 * (btn_w + 10.0), *y, btn_w, 28.0),
+            &label,
+            true,
+            crate::ui::theme::Tone::Secondary,
+        ) {
+            action = Some(UiAction::CounterOffer {
+                tenant_id: tenant.id,
+                modified_request: counter,
+            });
+        }
+    }
+    *y += 34.0;
+    action
+}
+
+/// Approve-with-conditions buttons for a pet request.
+fn draw_pet_conditions(
+    tenant: &Tenant,
+    deposit: i32,
+    content_x: f32,
+    y: &mut f32,
+    w: f32,
+) -> Option<UiAction> {
+    let options = [
+        (
+            format!("${} deposit", deposit),
+            RequestCondition::PetDeposit(deposit),
+        ),
+        (
+            "Quiet hours".to_string(),
+            RequestCondition::NoiseRestriction,
+        ),
+        (
+            format!("{}-month trial", PET_TRIAL_MONTHS),
+            RequestCondition::TimeLimit(PET_TRIAL_MONTHS),
+        ),
+    ];
+    let btn_w = (w - 20.0) / 3.0;
+    let mut action = None;
+    for (i, (label, condition)) in options.into_iter().enumerate() {
+        if crate::ui::widgets::button_at(
+            Rect::new(content_x + i as f32 * (btn_w + 10.0), *y, btn_w, 28.0),
+            &label,
+            true,
+            crate::ui::theme::Tone::Secondary,
+        ) {
+            action = Some(UiAction::ApproveRequestWithCondition {
+                tenant_id: tenant.id,
+                condition,
+            });
+        }
+    }
+    *y += 34.0;
+    action
+}
+
+/// Conditions on requests this tenant already had approved.
+pub(super) fn draw_request_conditions(
+    story: Option<&TenantStory>,
+    current_tick: u32,
+    content_x: f32,
+    y: &mut f32,
+    content_top: f32,
+    content_bottom: f32,
+) {
+    let Some(story) = story.filter(|s| !s.approved_conditions.is_empty()) else {
+        return;
+    };
+    for condition in &story.approved_conditions {
+        if *y + scale::LABEL > content_top && *y < content_bottom {
+            draw_ui_text(
+                &condition.status(current_tick),
+                content_x,
+                *y + scale::LABEL,
+                scale::LABEL,
+                colors::TEXT_DIM(),
+            );
+        }
+        *y += scale::LABEL * 1.35;
+    }
+    *y += 6.0;
+}
+
+fn request_text(request: &TenantRequest) -> String {
+    match request {
+        TenantRequest::Pet { pet_type } => format!("Can I keep a {}?", pet_type),
+        TenantRequest::TemporaryGuest {
+            guest_name,
+            duration_months,
+        } => format!("Can {} stay for {} months?", guest_name, duration_months),
+        TenantRequest::HomeBusiness {
+            business_type,
+            weekly_hours: None,
+        } => format!("Can I start a {} business?", business_type),
+        TenantRequest::HomeBusiness {
+            business_type,
+            weekly_hours: Some(hours),
+        } => format!(
+            "Can I start a {} business, {} hours a week?",
+            business_type, hours
+        ),
+        TenantRequest::Modification { description } => format!("Can I {}?", description),
+        TenantRequest::Sublease => "Can I sublease a room?".to_string(),
+    }
+}
+
+fn approval_effect_text(request: &TenantRequest) -> String {
+    let effect = request.approval_effect();
+    let mut effect_text = String::new();
+    let mut stack = vec![effect];
+
+    while let Some(effect) = stack.pop() {
+        match effect {
+            crate::narrative::StoryImpact::Happiness(amount) => {
+                append_effect_text(&mut effect_text, &format!("Happiness {:+}", amount));
+            }
+            crate::narrative::StoryImpact::SetApartmentFlag(flag) => {
+                if flag == "high_noise" {
+                    append_effect_text(&mut effect_text, "Noise Increases");
+                } else {
+                    append_effect_text(&mut effect_text, &format!("Flag: {}", flag));
+                }
+            }
+            crate::narrative::StoryImpact::Multiple(list) => {
+                for item in list.iter().rev() {
+                    stack.push(item.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    effect_text
+}
+
+fn append_effect_text(effect_text: &mut String, value: &str) {
+    if !effect_text.is_empty() {
+        effect_text.push_str(", ");
+    }
+    effect_text.push_str(value);
+}
@@ -0,0 +1,79 @@
+//! Rent strike side panel: what the striking tenants want, how long they'll
+//! hold out, and the two ways to answer them.
+
+use super::theme::{color, scale, space, Tone};
+use super::widgets::{button_at, draw_panel, wrap};
+use super::{common::*, UiAction};
+use crate::consequences::RentStrike;
+use macroquad::prelude::*;
+use macroquad_toolkit::ui::draw_ui_text;
+
+const PANEL_W: f32 = 300.0;
+const BUTTON_H: f32 = 32.0;
+
+pub fn draw_strike_panel(strike: &RentStrike, striking_units: usize) -> Option<UiAction> {
+    let mut action = None;
+    let line_h = scale::LABEL * 1.35;
+    let inner_w = PANEL_W - space::PAD * 2.0;
+    let lines: Vec<Vec<String>> = strike
+        .demands
+        .iter()
+        .map(|d| wrap(&format!("• {}", d.label()), inner_w, scale::LABEL))
+        .collect();
+    let line_count: usize = lines.iter().map(Vec::len).sum();
+    let h = 38.0
+        + space::SM
+        + line_h * 2.0
+        + space::SM
+        + line_count as f32 * line_h
+        + space::MD
+        + BUTTON_H * 2.0
+        + space::SM
+        + space::PAD;
+
+    let rect = Rect::new(space::LG, layout::HEADER_HEIGHT() + space::MD, PANEL_W, h);
+    let content = draw_panel(rect, "Rent Strike");
+
+    let mut y = content.y + line_h;
+    draw_ui_text(
+        &format!("{} units withholding rent", striking_units),
+        content.x,
+        y,
+        scale::LABEL,
+        color::NEGATIVE(),
+    );
+    y += line_h;
+    draw_ui_text(
+        &format!("Holding out {} more months", strike.ticks_until_resolution),
+        content.x,
+        y,
+        scale::LABEL,
+        color::TEXT_DIM(),
+    );
+    y += space::SM;
+    for line in lines.iter().flatten() {
+        y += line_h;
+        draw_ui_text(line, content.x, y, scale::LABEL, color::TEXT());
+    }
+
+    y += space::MD;
+    if button_at(
+        Rect::new(content.x, y, content.w, BUTTON_H),
+        "Meet All Demands",
+        true,
+        Tone::Positive,
+    ) {
+        action = Some(UiAction::MeetStrikeDemands { accept_all: true });
+    }
+    y += BUTTON_H + space::SM;
+    if button_at(
+        Rect::new(content.x, y, content.w, BUTTON_H),
+        "Reject Demands",
+        true,
+        Tone::Danger,
+    ) {
+        action = Some(UiAction::MeetStrikeDemands { accept_all: false });
+    }
+
+    action
+}
@@ -1,15 +1,26 @@
 use crate::assets::AssetManager;
 use crate::building::Apartment;
-use crate::consequences::TenantNetwork;
-use crate::narrative::{TenantRequest, TenantStory};
+use crate::consequences::{EvictionReason, TenantNetwork};
+use crate::data::config::{EconomyConfig, GameConfig};
+use crate::narrative::TenantStory;
 use crate::tenant::Tenant;
 use macroquad::prelude::*;
 use std::collections::HashMap;
 
+use super::request_panel::{draw_pending_request, draw_request_conditions};
 use super::theme::scale;
+use super::vacancy_panel::draw_vacant_unit_actions;
 use super::{common::*, UiAction};
 use macroquad_toolkit::ui::{draw_ui_text, wrap_text_ex};
 
+/// A vacant unit in another building the tenant could be moved into.
+pub struct TransferTarget {
+    pub building_index: usize,
+    pub apartment_id: u32,
+    /// Building name and unit number, for the button.
+    pub label: String,
+}
+
 pub(super) fn draw_tenant_info(
     apt: &Apartment,
     tenants: &[Tenant],
@@ -21,6 +32,11 @@ pub(super) fn draw_tenant_info(
     content_bottom: f32,
     network: &TenantNetwork,
     stories: &HashMap<u32, TenantStory>,
+    history_expanded: bool,
+    transfer_target: Option<&TransferTarget>,
+    just_cause_active: bool,
+    current_tick: u32,
+    config: &GameConfig,
 ) -> Option<UiAction> {
     if *y > content_top && *y < content_bottom {
         draw_line(
@@ -34,9 +50,9 @@ pub(super) fn draw_tenant_info(
     }
     *y += 15.0;
 
-    if let Some(tenant_id) = apt.tenant_id {
+    if apt.tenant_id.is_some() {
         return draw_occupied_tenant_info(
-            tenant_id,
+            apt,
             tenants,
             assets,
             content_x,
@@ -46,14 +62,27 @@ pub(super) fn draw_tenant_info(
             content_bottom,
             network,
             stories,
+            history_expanded,
+            transfer_target,
+            just_cause_active,
+            current_tick,
+            config,
         );
     }
 
-    draw_vacant_unit_actions(apt, content_x, y, panel_w, content_top, content_bottom)
+    draw_vacant_unit_actions(
+        apt,
+        content_x,
+        y,
+        panel_w,
+        content_top,
+        content_bottom,
+        config.economy.squatter_removal_cost,
+    )
 }
 
 fn draw_occupied_tenant_info(
-    tenant_id: u32,
+    apt: &Apartment,
     tenants: &[Tenant],
     assets: &AssetManager,
     content_x: f32,
@@ -63,8 +92,13 @@ fn draw_occupied_tenant_info(
     content_bottom: f32,
     network: &TenantNetwork,
     stories: &HashMap<u32, TenantStory>,
+    history_expanded: bool,
+    transfer_target: Option<&TransferTarget>,
+    just_cause_active: bool,
+    current_tick: u32,
+    config: &GameConfig,
 ) -> Option<UiAction> {
-    let tenant = tenants.iter().find(|t| t.id == tenant_id)?;
+    let tenant = tenants.iter().find(|t| Some(t.id) == apt.tenant_id)?;
     let w = panel_w - 30.0;
 
     if *y + 22.0 > content_top && *y < content_bottom {
@@ -118,6 +152,15 @@ fn draw_occupied_tenant_info(
             scale::LABEL,
             colors::TEXT_DIM(),
         );
+        if tenant.has_housing_voucher {
+            draw_ui_text(
+                &format!("Voucher ${}", tenant.voucher_amount),
+                text_x + 110.0,
+                row_top + 40.0,
+                scale::LABEL,
+                colors::POSITIVE(),
+            );
+        }
         draw_relationship_icons(tenant.id, network, text_x, row_top + 50.0);
     }
     *y += 88.0;
@@ -144,10 +187,166 @@ fn draw_occupied_tenant_info(
     }
     *y += 26.0;
 
+    let lease = if tenant.is_month_to_month {
+        Some(("Month-to-Month".to_string(), colors::WARNING()))
+    } else {
+        tenant
+            .lease_end_tick()
+            .map(|end| (format!("Ends month {}", end), colors::TEXT_DIM()))
+    };
+    if let Some((status, color)) = lease {
+        if *y + 20.0 > content_top && *y < content_bottom {
+            crate::ui::widgets::kv_row(content_x, *y, w, "Lease", &status, color);
+        }
+        *y += 26.0;
+    }
+
+    if let Some(contact) = &tenant.emergency_contact {
+        if *y + 20.0 > content_top && *y < content_bottom {
+            crate::ui::widgets::kv_row(
+                content_x,
+                *y,
+                w,
+                "Emergency contact",
+                &format!(
+                    "{} ({}) {}",
+                    contact.name, contact.relationship, contact.phone
+                ),
+                colors::TEXT_DIM(),
+            );
+        }
+        *y += 26.0;
+    }
+
+    if let Some(ratio) = tenant.rent_to_income_ratio(apt.rent_price) {
+        if *y + 20.0 > content_top && *y < content_bottom {
+            let color = if ratio > config.vetting.max_acceptable_ratio {
+                colors::WARNING()
+            } else {
+                colors::TEXT_DIM()
+            };
+            crate::ui::widgets::kv_row(
+                content_x,
+                *y,
+                w,
+                "Rent-to-income",
+                &format!("{:.0}%", ratio * 100.0),
+                color,
+            );
+        }
+        *y += 26.0;
+    }
+
+    if let Some(condition) = apt.move_in_condition {
+        if *y + 20.0 > content_top && *y < content_bottom {
+            crate::ui::widgets::kv_row(
+                content_x,
+                *y,
+                w,
+                "Condition at move-in",
+                &format!("{}% (rent ${})", condition, apt.move_in_rent),
+                colors::TEXT_DIM(),
+            );
+        }
+        *y += 26.0;
+    }
+
+    let economy = &config.economy;
+    if tenant.months_residing >= economy.lease_to_own_min_months {
+        if *y + 30.0 > content_top
+            && *y < content_bottom
+            && crate::ui::widgets::button_at(
+                Rect::new(content_x, *y, w, 30.0),
+                &format!(
+                    "Offer Purchase Option ({:.0}% of value)",
+                    economy.lease_to_own_price_factor * 100.0
+                ),
+                true,
+                crate::ui::theme::Tone::Secondary,
+            )
+        {
+            return Some(UiAction::OfferPurchaseOption {
+                apartment_id: tenant.apartment_id?,
+            });
+        }
+        *y += 36.0;
+    }
+
+    if let Some(target) = transfer_target {
+        if *y + 30.0 > content_top
+            && *y < content_bottom
+            && crate::ui::widgets::button_at(
+                Rect::new(content_x, *y, w, 30.0),
+                &format!("Transfer to {}", target.label),
+                true,
+                crate::ui::theme::Tone::Secondary,
+            )
+        {
+            return Some(UiAction::TransferTenant {
+                tenant_id: tenant.id,
+                target_building_index: target.building_index,
+                target_apartment_id: target.apartment_id,
+            });
+        }
+        *y += 36.0;
+    }
+
+    if let Some(action) = draw_eviction_options(
+        apt,
+        tenant,
+        just_cause_active,
+        &config.economy,
+        content_x,
+        y,
+        panel_w,
+        content_top,
+        content_bottom,
+    ) {
+        return Some(action);
+    }
+
+    if let Some(action) = draw_feud_mediation(
+        tenant,
+        tenants,
+        network,
+        config.economy.mediation_cost,
+        content_x,
+        y,
+        panel_w,
+        content_top,
+        content_bottom,
+    ) {
+        return Some(action);
+    }
+
+    if let Some(action) = draw_relationship_history(
+        tenant,
+        tenants,
+        network,
+        history_expanded,
+        content_x,
+        y,
+        panel_w,
+        content_top,
+        content_bottom,
+    ) {
+        return Some(action);
+    }
+
+    draw_request_conditions(
+        stories.get(&tenant.id),
+        current_tick,
+        content_x,
+        y,
+        content_top,
+        content_bottom,
+    );
+
     // Pending request as its own section, below the tenant info.
     draw_pending_request(
         tenant,
         stories,
+        config.economy.pet_deposit,
         content_x,
         y,
         panel_w,
@@ -156,6 +355,78 @@ fn draw_occupied_tenant_info(
     )
 }
 
+/// One button per eviction reason. Under a just-cause law each shows the
+/// documentation it needs.
+fn draw_eviction_options(
+    apt: &Apartment,
+    tenant: &Tenant,
+    just_cause_active: bool,
+    economy: &EconomyConfig,
+    content_x: f32,
+    y: &mut f32,
+    panel_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    let w = panel_w - 30.0;
+    let relocation = apt.relocation_assistance(economy.relocation_assistance_months);
+    if *y + 22.0 > content_top && *y < content_bottom {
+        crate::ui::widgets::section_label(
+            content_x,
+            *y,
+            &format!(
+                "EVICT (${} fees + ${} relocation aid)",
+                economy.tenant_eviction_cost, relocation
+            ),
+        );
+    }
+    *y += 22.0;
+    if tenant.outstanding_balance > 0 {
+        if *y + 14.0 > content_top && *y < content_bottom {
+            draw_ui_text(
+                &format!("Owes ${} in unpaid rent", tenant.outstanding_balance),
+                content_x,
+                *y + 12.0,
+                scale::LABEL,
+                colors::WARNING(),
+            );
+        }
+        *y += 20.0;
+    }
+
+    let mut action = None;
+    for reason in EvictionReason::ALL {
+        if *y + 28.0 > content_top
+            && *y < content_bottom
+            && crate::ui::widgets::button_at(
+                Rect::new(content_x, *y, w, 28.0),
+                reason.label(),
+                true,
+                crate::ui::theme::Tone::Danger,
+            )
+        {
+            action = Some(UiAction::BeginEviction {
+                apartment_id: apt.id,
+                eviction_reason: Some(reason),
+            });
+        }
+        *y += 32.0;
+        if just_cause_active {
+            if *y + 14.0 > content_top && *y < content_bottom {
+                draw_ui_text(
+                    &format!("Requires: {}", reason.documentation()),
+                    content_x,
+                    *y + 10.0,
+                    scale::LABEL,
+                    colors::TEXT_DIM(),
+                );
+            }
+            *y += 18.0;
+        }
+    }
+    action
+}
+
 fn draw_relationship_icons(tenant_id: u32, network: &TenantNetwork, text_x: f32, icon_y: f32) {
     use crate::consequences::RelationshipType;
     let relationships: Vec<_> = network
@@ -201,135 +472,165 @@ fn draw_relationship_icons(tenant_id: u32, network: &TenantNetwork, text_x: f32,
     }
 }
 
-fn draw_pending_request(
+/// One "Mediate" row per neighbor this tenant is feuding with.
+fn draw_feud_mediation(
     tenant: &Tenant,
-    stories: &HashMap<u32, TenantStory>,
+    tenants: &[Tenant],
+    network: &TenantNetwork,
+    cost: i32,
     content_x: f32,
     y: &mut f32,
     panel_w: f32,
     content_top: f32,
     content_bottom: f32,
 ) -> Option<UiAction> {
-    let story = stories.get(&tenant.id)?;
-    let request = story.pending_request.as_ref()?;
-    let w = panel_w - 30.0;
+    use crate::consequences::RelationshipType;
+    let rivals: Vec<&Tenant> = network
+        .relationships
+        .iter()
+        .filter(|rel| rel.relationship_type == RelationshipType::Hostile)
+        .filter_map(|rel| {
+            if rel.tenant_a_id == tenant.id {
+                Some(rel.tenant_b_id)
+            } else if rel.tenant_b_id == tenant.id {
+                Some(rel.tenant_a_id)
+            } else {
+                None
+            }
+        })
+        .filter_map(|id| tenants.iter().find(|t| t.id == id))
+        .collect();
+    if rivals.is_empty() {
+        return None;
+    }
 
-    // Divider + section header.
+    let w = panel_w - 30.0;
     if *y > content_top && *y < content_bottom {
         draw_line(content_x, *y, content_x + w, *y, 1.0, colors::BORDER());
     }
     *y += 14.0;
     if *y + 18.0 > content_top && *y < content_bottom {
-        crate::ui::widgets::section_label(content_x, *y, "PENDING REQUEST");
+        crate::ui::widgets::section_label(content_x, *y, "FEUDS");
     }
     *y += 22.0;
 
-    // Wrapped request text.
-    let req_text = request_text(request);
-    for line in wrap_text_ex(&req_text, w, None, scale::BODY) {
-        if *y + scale::BODY > content_top && *y < content_bottom {
+    let btn_w = 130.0;
+    for rival in rivals {
+        if *y + 30.0 > content_top && *y < content_bottom {
             draw_ui_text(
-                &line,
+                &format!("Feuding with {}", rival.name),
                 content_x,
-                *y + scale::BODY,
+                *y + 20.0,
                 scale::BODY,
-                colors::TEXT(),
+                colors::NEGATIVE(),
             );
+            if crate::ui::widgets::button_at(
+                Rect::new(content_x + w - btn_w, *y, btn_w, 30.0),
+                &format!("Mediate (${})", cost),
+                true,
+                crate::ui::theme::Tone::Secondary,
+            ) {
+                return Some(UiAction::MediateTenants {
+                    tenant_a_id: tenant.id,
+                    tenant_b_id: rival.id,
+                });
+            }
         }
-        *y += scale::BODY * 1.35;
+        *y += 36.0;
     }
-    *y += 4.0;
+    None
+}
 
-    let effect_text = approval_effect_text(request);
-    if !effect_text.is_empty() {
-        if *y + 16.0 > content_top && *y < content_bottom {
-            draw_ui_text(
-                &format!("Effect: {}", effect_text),
-                content_x,
-                *y + scale::LABEL,
-                scale::LABEL,
-                colors::ACCENT(),
-            );
-        }
-        *y += 24.0;
+/// Collapsible log of the last few turning points across all of this
+/// tenant's relationships.
+fn draw_relationship_history(
+    tenant: &Tenant,
+    tenants: &[Tenant],
+    network: &TenantNetwork,
+    expanded: bool,
+    content_x: f32,
+    y: &mut f32,
+    panel_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    use crate::consequences::RelationshipType;
+    const SHOWN_ENTRIES: usize = 5;
+    let mut entries: Vec<(u32, String, Color)> = network
+        .relationships
+        .iter()
+        .filter(|rel| rel.tenant_a_id == tenant.id || rel.tenant_b_id == tenant.id)
+        .flat_map(|rel| {
+            let other_id = if rel.tenant_a_id == tenant.id {
+                rel.tenant_b_id
+            } else {
+                rel.tenant_a_id
+            };
+            let other = tenants
+                .iter()
+                .find(|t| t.id == other_id)
+                .map_or("a former tenant", |t| t.name.as_str());
+            rel.history.iter().map(move |entry| {
+                let line_color = match entry.relationship_delta {
+                    Some(RelationshipType::Hostile) => colors::NEGATIVE(),
+                    Some(RelationshipType::Neutral) | None => colors::TEXT_DIM(),
+                    Some(_) => colors::POSITIVE(),
+                };
+                (
+                    entry.tick,
+                    format!("Month {}: {} ({})", entry.tick, entry.description, other),
+                    line_color,
+                )
+            })
+        })
+        .collect();
+    if entries.is_empty() {
+        return None;
     }
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.0));
 
-    *y += 6.0;
-    let btn_w = ((w - 10.0) / 2.0).min(140.0);
-    if crate::ui::widgets::button_at(
-        Rect::new(content_x, *y, btn_w, 32.0),
-        "Approve",
-        true,
-        crate::ui::theme::Tone::Positive,
-    ) {
-        return Some(UiAction::ApproveRequest {
-            tenant_id: tenant.id,
-        });
-    }
-    if crate::ui::widgets::button_at(
-        Rect::new(content_x + btn_w + 10.0, *y, btn_w, 32.0),
-        "Deny",
-        true,
-        crate::ui::theme::Tone::Danger,
-    ) {
-        return Some(UiAction::DenyRequest {
-            tenant_id: tenant.id,
-        });
+    let w = panel_w - 30.0;
+    let mut action = None;
+    if *y > content_top && *y < content_bottom {
+        draw_line(content_x, *y, content_x + w, *y, 1.0, colors::BORDER());
     }
-
-    *y += 38.0;
-    None
-}
-
-fn request_text(request: &TenantRequest) -> String {
-    match request {
-        TenantRequest::Pet { pet_type } => format!("Can I keep a {}?", pet_type),
-        TenantRequest::TemporaryGuest {
-            guest_name,
-            duration_months,
-        } => format!("Can {} stay for {} months?", guest_name, duration_months),
-        TenantRequest::HomeBusiness { business_type } => {
-            format!("Can I start a {} business?", business_type)
+    *y += 8.0;
+    if *y + 26.0 > content_top && *y < content_bottom {
+        let label = if expanded {
+            "- Relationship History"
+        } else {
+            "+ Relationship History"
+        };
+        if crate::ui::widgets::button_at(
+            Rect::new(content_x, *y, w, 26.0),
+            label,
+            true,
+            crate::ui::theme::Tone::Secondary,
+        ) {
+            action = Some(UiAction::ToggleRelationshipHistory);
         }
-        TenantRequest::Modification { description } => format!("Can I {}?", description),
-        TenantRequest::Sublease => "Can I sublease a room?".to_string(),
     }
-}
-
-fn approval_effect_text(request: &TenantRequest) -> String {
-    let effect = request.approval_effect();
-    let mut effect_text = String::new();
-    let mut stack = vec![effect];
+    *y += 32.0;
+    if !expanded {
+        return action;
+    }
 
-    while let Some(effect) = stack.pop() {
-        match effect {
-            crate::narrative::StoryImpact::Happiness(amount) => {
-                append_effect_text(&mut effect_text, &format!("Happiness {:+}", amount));
+    for (_, line, line_color) in entries.iter().take(SHOWN_ENTRIES) {
+        for wrapped in wrap_text_ex(line, w, None, scale::LABEL) {
+            if *y + scale::LABEL > content_top && *y < content_bottom {
+                draw_ui_text(
+                    &wrapped,
+                    content_x,
+                    *y + scale::LABEL,
+                    scale::LABEL,
+                    *line_color,
+                );
             }
-            crate::narrative::StoryImpact::SetApartmentFlag(flag) => {
-                if flag == "high_noise" {
-                    append_effect_text(&mut effect_text, "Noise Increases");
-                } else {
-                    append_effect_text(&mut effect_text, &format!("Flag: {}", flag));
-                }
-            }
-            crate::narrative::StoryImpact::Multiple(list) => {
-                for item in list.iter().rev() {
-                    stack.push(item.clone());
-                }
-            }
-            _ => {}
+            *y += scale::LABEL * 1.35;
         }
     }
-
-    effect_text
-}
-
-fn append_effect_text(effect_text: &mut String, value: &str) {
-    if !effect_text.is_empty() {
-        effect_text.push_str(", ");
-    }
-    effect_text.push_str(value);
+    *y += 6.0;
+    action
 }
 
 fn draw_tenant_happiness(
@@ -358,161 +659,3 @@ fn draw_tenant_happiness(
     }
     *y += 28.0;
 }
-
-fn draw_vacant_unit_actions(
-    apt: &Apartment,
-    content_x: f32,
-    y: &mut f32,
-    panel_w: f32,
-    content_top: f32,
-    content_bottom: f32,
-) -> Option<UiAction> {
-    if *y > content_top && *y < content_bottom {
-        draw_ui_text("VACANT", content_x, *y, 18.0, colors::WARNING());
-    }
-    *y += 25.0;
-
-    let btn_w = panel_w - 30.0;
-
-    if apt.is_listed_for_lease {
-        return draw_listed_vacancy_actions(apt, content_x, y, btn_w, content_top, content_bottom);
-    }
-
-    draw_unlisted_vacancy_actions(apt, content_x, y, btn_w, content_top, content_bottom)
-}
-
-fn draw_listed_vacancy_actions(
-    apt: &Apartment,
-    content_x: f32,
-    y: &mut f32,
-    btn_w: f32,
-    content_top: f32,
-    content_bottom: f32,
-) -> Option<UiAction> {
-    if *y > content_top && *y < content_bottom {
-        draw_ui_text("Status: LISTED", content_x, *y, 16.0, colors::POSITIVE());
-    }
-    *y += 20.0;
-
-    if *y > content_top && *y < content_bottom {
-        let target_text = if let Some(pref) = &apt.preferred_archetype {
-            format!("Target: {}", pref.name())
-        } else {
-            "Target: Open (Any)".to_string()
-        };
-        draw_ui_text(&target_text, content_x, *y, 14.0, colors::TEXT());
-    }
-    *y += 30.0;
-
-    if *y + 30.0 > content_top
-        && *y < content_bottom
-        && button(content_x, *y, btn_w, 30.0, "View Applications", true)
-    {
-        return Some(UiAction::SelectApplications(Some(apt.id)));
-    }
-    *y += 35.0;
-
-    if *y + 30.0 > content_top
-        && *y < content_bottom
-        && button(content_x, *y, btn_w, 30.0, "Unlist Property", true)
-    {
-        return Some(UiAction::UnlistApartment {
-            apartment_id: apt.id,
-        });
-    }
-    *y += 40.0;
-
-    None
-}
-
-fn draw_unlisted_vacancy_actions(
-    apt: &Apartment,
-    content_x: f32,
-    y: &mut f32,
-    btn_w: f32,
-    content_top: f32,
-    content_bottom: f32,
-) -> Option<UiAction> {
-    if *y > content_top && *y < content_bottom {
-        draw_ui_text(
-            "Status: OFF MARKET",
-            content_x,
-            *y,
-            14.0,
-            colors::TEXT_DIM(),
-        );
-    }
-    *y += 30.0;
-
-    if *y > content_top && *y < content_bottom {
-        draw_ui_text(
-            &format!("Rent: ${}", apt.rent_price),
-            content_x,
-            *y,
-            20.0,
-            colors::TEXT(),
-        );
-
-        let btn_size = 25.0;
-        if button(content_x + 120.0, *y - 18.0, btn_size, btn_size, "-", true) {
-            return Some(UiAction::AdjustRent {
-                apartment_id: apt.id,
-                amount: -50,
-            });
-        }
-        if button(content_x + 150.0, *y - 18.0, btn_size, btn_size, "+", true) {
-            return Some(UiAction::AdjustRent {
-                apartment_id: apt.id,
-                amount: 50,
-            });
-        }
-    }
-    *y += 40.0;
-
-    if *y > content_top && *y < content_bottom {
-        draw_ui_text("List for Lease:", content_x, *y, 14.0, colors::ACCENT());
-    }
-    *y += 20.0;
-
-    if *y + 30.0 > content_top
-        && *y < content_bottom
-        && button(content_x, *y, btn_w, 30.0, "Any Tenant", true)
-    {
-        return Some(UiAction::ListApartment {
-            apartment_id: apt.id,
-            preference: None,
-        });
-    }
-    *y += 35.0;
-
-    let tenant_types = [
-        (crate::tenant::TenantArchetype::Student, "Student"),
-        (crate::tenant::TenantArchetype::Professional, "Pro"),
-        (crate::tenant::TenantArchetype::Artist, "Artist"),
-        (crate::tenant::TenantArchetype::Family, "Family"),
-        (crate::tenant::TenantArchetype::Elderly, "Elderly"),
-    ];
-    let small_btn_w = (btn_w - 10.0) / 2.0;
-
-    for (index, (archetype, label)) in tenant_types.iter().enumerate() {
-        let col = index % 2;
-        let x = content_x + col as f32 * (small_btn_w + 10.0);
-
-        if *y + 25.0 > content_top
-            && *y < content_bottom
-            && button(x, *y, small_btn_w, 25.0, label, true)
-        {
-            return Some(UiAction::ListApartment {
-                apartment_id: apt.id,
-                preference: Some(archetype.clone()),
-            });
-        }
-
-        if col == 1 || index == tenant_types.len() - 1 {
-            *y += 30.0;
-        }
-    }
-    *y += 10.0;
-
-    None
-}
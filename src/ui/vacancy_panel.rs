@@ -0,0 +1,211 @@
+//! Vacant unit actions: listing, unlisting and clearing out squatters.
+
+use crate::building::Apartment;
+
+use super::{common::*, UiAction};
+use macroquad_toolkit::ui::draw_ui_text;
+
+pub(super) fn draw_vacant_unit_actions(
+    apt: &Apartment,
+    content_x: f32,
+    y: &mut f32,
+    panel_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+    squatter_removal_cost: i32,
+) -> Option<UiAction> {
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text("VACANT", content_x, *y, 18.0, colors::WARNING());
+    }
+    *y += 25.0;
+
+    let btn_w = panel_w - 30.0;
+
+    if apt.has_squatter() {
+        return draw_squatter_actions(
+            apt,
+            content_x,
+            y,
+            btn_w,
+            content_top,
+            content_bottom,
+            squatter_removal_cost,
+        );
+    }
+
+    if apt.is_listed_for_lease {
+        return draw_listed_vacancy_actions(apt, content_x, y, btn_w, content_top, content_bottom);
+    }
+
+    draw_unlisted_vacancy_actions(apt, content_x, y, btn_w, content_top, content_bottom)
+}
+
+fn draw_squatter_actions(
+    apt: &Apartment,
+    content_x: f32,
+    y: &mut f32,
+    btn_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+    removal_cost: i32,
+) -> Option<UiAction> {
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text("Status: SQUATTER", content_x, *y, 16.0, colors::NEGATIVE());
+    }
+    *y += 30.0;
+
+    let evicting = apt.flags.contains(crate::building::SQUATTER_EVICTION_FLAG);
+    let label = if evicting {
+        "Eviction under way".to_string()
+    } else {
+        format!("Evict Squatter (${})", removal_cost)
+    };
+    if *y + 30.0 > content_top
+        && *y < content_bottom
+        && button(content_x, *y, btn_w, 30.0, &label, !evicting)
+    {
+        return Some(UiAction::BeginEviction {
+            apartment_id: apt.id,
+            eviction_reason: None,
+        });
+    }
+    *y += 40.0;
+
+    None
+}
+
+fn draw_listed_vacancy_actions(
+    apt: &Apartment,
+    content_x: f32,
+    y: &mut f32,
+    btn_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text("Status: LISTED", content_x, *y, 16.0, colors::POSITIVE());
+    }
+    *y += 20.0;
+
+    if *y > content_top && *y < content_bottom {
+        let target_text = if let Some(pref) = &apt.preferred_archetype {
+            format!("Target: {}", pref.name())
+        } else {
+            "Target: Open (Any)".to_string()
+        };
+        draw_ui_text(&target_text, content_x, *y, 14.0, colors::TEXT());
+    }
+    *y += 30.0;
+
+    if *y + 30.0 > content_top
+        && *y < content_bottom
+        && button(content_x, *y, btn_w, 30.0, "View Applications", true)
+    {
+        return Some(UiAction::SelectApplications(Some(apt.id)));
+    }
+    *y += 35.0;
+
+    if *y + 30.0 > content_top
+        && *y < content_bottom
+        && button(content_x, *y, btn_w, 30.0, "Unlist Property", true)
+    {
+        return Some(UiAction::UnlistApartment {
+            apartment_id: apt.id,
+        });
+    }
+    *y += 40.0;
+
+    None
+}
+
+fn draw_unlisted_vacancy_actions(
+    apt: &Apartment,
+    content_x: f32,
+    y: &mut f32,
+    btn_w: f32,
+    content_top: f32,
+    content_bottom: f32,
+) -> Option<UiAction> {
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text(
+            "Status: OFF MARKET",
+            content_x,
+            *y,
+            14.0,
+            colors::TEXT_DIM(),
+        );
+    }
+    *y += 30.0;
+
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text(
+            &format!("Rent: ${}", apt.rent_price),
+            content_x,
+            *y,
+            20.0,
+            colors::TEXT(),
+        );
+
+        let btn_size = 25.0;
+        if button(content_x + 120.0, *y - 18.0, btn_size, btn_size, "-", true) {
+            return Some(UiAction::AdjustRent {
+                apartment_id: apt.id,
+                amount: -50,
+            });
+        }
+        if button(content_x + 150.0, *y - 18.0, btn_size, btn_size, "+", true) {
+            return Some(UiAction::AdjustRent {
+                apartment_id: apt.id,
+                amount: 50,
+            });
+        }
+    }
+    *y += 40.0;
+
+    if *y > content_top && *y < content_bottom {
+        draw_ui_text("List for Lease:", content_x, *y, 14.0, colors::ACCENT());
+    }
+    *y += 20.0;
+
+    if *y + 30.0 > content_top
+        && *y < content_bottom
+        && button(content_x, *y, btn_w, 30.0, "Any Tenant", true)
+    {
+        return Some(UiAction::ListApartment {
+            apartment_id: apt.id,
+            preference: None,
+        });
+    }
+    *y += 35.0;
+
+    let tenant_types = [
+        (crate::tenant::TenantArchetype::Student, "Student"),
+        (crate::tenant::TenantArchetype::Professional, "Pro"),
+        (crate::tenant::TenantArchetype::Artist, "Artist"),
+        (crate::tenant::TenantArchetype::Family, "Family"),
+        (crate::tenant::TenantArchetype::Elderly, "Elderly"),
+    ];
+    let small_btn_w = (btn_w - 10.0) / 2.0;
+
+    for (index, (archetype, label)) in tenant_types.iter().enumerate() {
+        let col = index % 2;
+        let x = content_x + col as f32 * (small_btn_w + 10.0);
+
+        if *y + 25.0 > content_top
+            && *y < content_bottom
+            && button(x, *y, small_btn_w, 25.0, label, true)
+        {
+            return Some(UiAction::ListApartment {
+                apartment_id: apt.id,
+                preference: Some(archetype.clone()),
+            });
+        }
+
+        if col == 1 || index == tenant_types.len() - 1 {
+            *y += 30.0;
+        }
+    }
+    *y += 10.0;
+
+    None
+}
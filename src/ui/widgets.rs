@@ -12,6 +12,7 @@ use macroquad_toolkit::ui::{
 };
 
 use super::theme::{self, color, scale, space, Tone};
+use crate::building::GreenCertificationLevel;
 
 /// Approximate line height for a given font size.
 pub fn line_height(size: f32) -> f32 {
@@ -126,6 +127,24 @@ pub fn draw_badge(x: f32, y: f32, height: f32, text: &str, fill: Color, text_col
     w
 }
 
+/// Badge for a building's green certification, tinted by tier. Draws nothing
+/// and returns 0.0 for an uncertified building.
+pub fn draw_green_certification_badge(
+    x: f32,
+    y: f32,
+    height: f32,
+    level: GreenCertificationLevel,
+) -> f32 {
+    let fill = match level {
+        GreenCertificationLevel::None => return 0.0,
+        GreenCertificationLevel::Bronze => Color::new(0.72, 0.46, 0.22, 1.0),
+        GreenCertificationLevel::Silver => Color::new(0.68, 0.71, 0.75, 1.0),
+        GreenCertificationLevel::Gold => Color::new(0.88, 0.72, 0.22, 1.0),
+    };
+    let label = format!("{} Green Certified", level.name());
+    draw_badge(x, y, height, &label, fill, color::BACKGROUND())
+}
+
 /// Category for the shared bottom toast.
 #[derive(Clone, Copy)]
 pub enum ToastKind {
@@ -156,6 +175,66 @@ pub fn draw_toast(
     kind: ToastKind,
     action_label: &str,
 ) -> bool {
+    let panel = draw_toast_panel(icon, title, body, kind);
+
+    // Action button, bottom-right.
+    if action_label.is_empty() {
+        return false;
+    }
+    let btn_h = 34.0;
+    let btn_w = button_width(action_label, btn_h).max(96.0);
+    let btn_x = panel.x + panel.w - btn_w - space::LG;
+    let btn_y = panel.y + panel.h - btn_h - space::MD;
+    button_at(
+        Rect::new(btn_x, btn_y, btn_w, btn_h),
+        action_label,
+        true,
+        Tone::Primary,
+    )
+}
+
+/// Which of a two-button toast's buttons was clicked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastResponse {
+    Action,
+    Secondary,
+}
+
+/// A toast with a secondary button to the left of the main action.
+pub fn draw_toast_with_secondary(
+    icon: &str,
+    body: &str,
+    kind: ToastKind,
+    action_label: &str,
+    secondary_label: &str,
+) -> Option<ToastResponse> {
+    let panel = draw_toast_panel(icon, "", body, kind);
+
+    let btn_h = 34.0;
+    let btn_y = panel.y + panel.h - btn_h - space::MD;
+    let action_w = button_width(action_label, btn_h).max(96.0);
+    let action_x = panel.x + panel.w - action_w - space::LG;
+    let secondary_w = button_width(secondary_label, btn_h).max(96.0);
+    let secondary_x = action_x - secondary_w - space::SM;
+    if button_at(
+        Rect::new(secondary_x, btn_y, secondary_w, btn_h),
+        secondary_label,
+        true,
+        Tone::Secondary,
+    ) {
+        return Some(ToastResponse::Secondary);
+    }
+    button_at(
+        Rect::new(action_x, btn_y, action_w, btn_h),
+        action_label,
+        true,
+        Tone::Primary,
+    )
+    .then_some(ToastResponse::Action)
+}
+
+/// Draw the toast surface, icon and text; returns the panel rect.
+fn draw_toast_panel(icon: &str, title: &str, body: &str, kind: ToastKind) -> Rect {
     let accent = kind.accent();
     let panel_w = (screen_width() * 0.6).clamp(420.0, 680.0);
     let text_x_pad = if icon.is_empty() { space::LG } else { 74.0 };
@@ -206,18 +285,32 @@ pub fn draw_toast(
         y += line_height(scale::BODY);
     }
 
-    // Action button, bottom-right.
-    if action_label.is_empty() {
-        return false;
+    Rect::new(panel_x, panel_y, panel_w, panel_h)
+}
+
+/// Small dismiss-free hint card tucked into the bottom-right corner, above the
+/// footer, so it never covers the building.
+pub fn draw_corner_hint(text: &str) {
+    let panel_w = 300.0;
+    let lines = wrap(text, panel_w - space::MD * 2.0, scale::CAPTION);
+    let panel_h = lines.len() as f32 * line_height(scale::CAPTION) + space::MD * 2.0;
+    let panel_x = screen_width() - panel_w - space::LG;
+    let panel_y = screen_height() - super::common::layout::FOOTER_HEIGHT() - panel_h - space::MD;
+
+    let style = macroquad_toolkit::ui::SurfaceStyle::new(color::SURFACE())
+        .with_border(1.0, color::BORDER())
+        .with_left_accent(4.0, color::ACCENT());
+    draw_surface(Rect::new(panel_x, panel_y, panel_w, panel_h), &style);
+
+    let mut y = panel_y + space::MD;
+    for line in &lines {
+        draw_ui_text(
+            line,
+            panel_x + space::MD,
+            y + scale::CAPTION,
+            scale::CAPTION,
+            color::TEXT(),
+        );
+        y += line_height(scale::CAPTION);
     }
-    let btn_h = 34.0;
-    let btn_w = button_width(action_label, btn_h).max(96.0);
-    let btn_x = panel_x + panel_w - btn_w - space::LG;
-    let btn_y = panel_y + panel_h - btn_h - space::MD;
-    button_at(
-        Rect::new(btn_x, btn_y, btn_w, btn_h),
-        action_label,
-        true,
-        Tone::Primary,
-    )
 }